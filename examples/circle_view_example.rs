@@ -13,7 +13,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Send the request and print the response
     match view.list_wallets(params).await {
         Ok(response) => {
-            println!("Successfully listed wallets: {:#?}", response.wallets);
+            println!("Successfully listed wallets: {:#?}", response.items);
         }
         Err(e) => {
             eprintln!("Error listing wallets: {}", e);
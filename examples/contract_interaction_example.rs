@@ -12,7 +12,6 @@ use inf_circle_sdk::{
     types::Blockchain,
 };
 use std::env;
-use uuid::Uuid;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -38,12 +37,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let wallets = view.list_wallets(params).await?;
 
-    if wallets.wallets.is_empty() {
+    if wallets.items.is_empty() {
         println!("❌ No wallets found. Create a wallet first using circle_ops_example.rs");
         return Ok(());
     }
 
-    let wallet = &wallets.wallets[0];
+    let wallet = &wallets.items[0];
     println!("Using Wallet: {} ({})", wallet.id, wallet.address);
 
     // Use USDC contract on Sepolia for demonstration
@@ -84,7 +83,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let execute_builder = CreateContractExecutionTransactionRequestBuilder::new(
         wallet.id.clone(),
         usdc_contract.to_string(),
-        Uuid::new_v4().to_string(),
     )
     .abi_function_signature("approve(address,uint256)".to_string())
     .abi_parameters(vec![
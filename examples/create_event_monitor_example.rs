@@ -127,11 +127,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     match client.list_event_monitors(None).await {
         Ok(response) => {
-            println!(
-                "✅ Found {} event monitors total",
-                response.event_monitors.len()
-            );
-            for (i, monitor) in response.event_monitors.iter().enumerate().take(5) {
+            println!("✅ Found {} event monitors total", response.items.len());
+            for (i, monitor) in response.items.iter().enumerate().take(5) {
                 println!(
                     "   {}. ID: {}, Contract: {}, Event: {}",
                     i + 1,
@@ -140,8 +137,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     monitor.event_signature
                 );
             }
-            if response.event_monitors.len() > 5 {
-                println!("   ... and {} more", response.event_monitors.len() - 5);
+            if response.items.len() > 5 {
+                println!("   ... and {} more", response.items.len() - 5);
             }
         }
         Err(e) => {
@@ -165,10 +162,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Ok(response) => {
             println!(
                 "✅ Found {} event monitors for contract {}",
-                response.event_monitors.len(),
+                response.items.len(),
                 contract_address
             );
-            for monitor in &response.event_monitors {
+            for monitor in &response.items {
                 println!(
                     "   - Event: {}, Enabled: {}",
                     monitor.event_signature, monitor.is_enabled
@@ -185,10 +182,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     match client.list_event_logs(None).await {
         Ok(response) => {
-            println!("✅ Found {} event logs total", response.event_logs.len());
+            println!("✅ Found {} event logs total", response.items.len());
 
             // Show first 3 event logs as samples
-            for (i, log) in response.event_logs.iter().enumerate().take(3) {
+            for (i, log) in response.items.iter().enumerate().take(3) {
                 println!("\n   Event Log #{}:", i + 1);
                 println!("   - ID: {}", log.id);
                 println!("   - Contract: {}", log.contract_address);
@@ -202,11 +199,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 println!("   - Confirmed: {}", log.first_confirm_date);
             }
 
-            if response.event_logs.len() > 3 {
-                println!(
-                    "\n   ... and {} more event logs",
-                    response.event_logs.len() - 3
-                );
+            if response.items.len() > 3 {
+                println!("\n   ... and {} more event logs", response.items.len() - 3);
             }
         }
         Err(e) => {
@@ -232,11 +226,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Ok(response) => {
             println!(
                 "✅ Found {} event logs for contract {}",
-                response.event_logs.len(),
+                response.items.len(),
                 contract_address
             );
 
-            for log in &response.event_logs {
+            for log in &response.items {
                 println!(
                     "   - Event: {} (Block {})",
                     log.event_signature, log.block_height
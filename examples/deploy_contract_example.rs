@@ -1,6 +1,7 @@
 use inf_circle_sdk::{
     circle_ops::circler_ops::CircleOps,
-    contract::ops::deploy_contract::DeployContractRequestBuilder, types::Blockchain,
+    contract::ops::deploy_contract::DeployContractRequestBuilder, dev_wallet::dto::FeeLevel,
+    types::Blockchain,
 };
 use std::env;
 
@@ -40,7 +41,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Create deployment request
     let contract_name = format!(
         "MyContract{}",
-        uuid::Uuid::new_v4().to_string().replace("-", "")[..8].to_string()
+        &uuid::Uuid::new_v4().to_string().replace("-", "")[..8]
     );
 
     let deployment = ops
@@ -53,7 +54,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 Blockchain::EthSepolia,
             )
             .description("Deployed via Circle Rust SDK".to_string())
-            .fee_level("MEDIUM".to_string())
+            .fee_level(FeeLevel::Medium)
             .ref_id(format!("deploy-{}", uuid::Uuid::new_v4())),
         )
         .await?;
@@ -39,7 +39,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     println!("✅ Fee Estimation (No Constructor):");
     println!("   Low:");
-    println!("      Gas Limit: {}", fee_estimate.low.gas_limit);
+    if let Some(gas_limit) = &fee_estimate.low.gas_limit {
+        println!("      Gas Limit: {}", gas_limit);
+    }
     if let Some(network_fee) = &fee_estimate.low.network_fee {
         println!("      Network Fee: {} ETH", network_fee);
     }
@@ -49,7 +51,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!();
 
     println!("   Medium:");
-    println!("      Gas Limit: {}", fee_estimate.medium.gas_limit);
+    if let Some(gas_limit) = &fee_estimate.medium.gas_limit {
+        println!("      Gas Limit: {}", gas_limit);
+    }
     if let Some(network_fee) = &fee_estimate.medium.network_fee {
         println!("      Network Fee: {} ETH", network_fee);
     }
@@ -59,7 +63,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!();
 
     println!("   High:");
-    println!("      Gas Limit: {}", fee_estimate.high.gas_limit);
+    if let Some(gas_limit) = &fee_estimate.high.gas_limit {
+        println!("      Gas Limit: {}", gas_limit);
+    }
     if let Some(network_fee) = &fee_estimate.high.network_fee {
         println!("      Network Fee: {} ETH", network_fee);
     }
@@ -93,7 +99,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         fee_estimate_with_constructor.medium.network_fee
     );
     println!(
-        "   Medium Gas Limit: {}",
+        "   Medium Gas Limit: {:?}",
         fee_estimate_with_constructor.medium.gas_limit
     );
 
@@ -34,10 +34,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let existing = view.list_contracts(Some(check_params)).await?;
 
-    if !existing.contracts.is_empty() {
+    if !existing.items.is_empty() {
         println!("   ℹ️  Contract already imported!");
-        println!("      Contract ID: {:?}", existing.contracts[0].id);
-        println!("      Name: {:?}", existing.contracts[0].name);
+        println!("      Contract ID: {:?}", existing.items[0].id);
+        println!("      Name: {:?}", existing.items[0].name);
     } else {
         let import_builder = ImportContractRequestBuilder::new(
             Blockchain::EthSepolia,
@@ -89,9 +89,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("\n3️⃣  Listing all imported contracts...");
 
     let all_contracts = view.list_contracts(None).await?;
-    println!("   ✅ Total contracts: {}", all_contracts.contracts.len());
+    println!("   ✅ Total contracts: {}", all_contracts.items.len());
 
-    for (i, contract) in all_contracts.contracts.iter().take(5).enumerate() {
+    for (i, contract) in all_contracts.items.iter().take(5).enumerate() {
         println!(
             "      {}. {} - {:?}",
             i + 1,
@@ -100,8 +100,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         );
     }
 
-    if all_contracts.contracts.len() > 5 {
-        println!("      ... and {} more", all_contracts.contracts.len() - 5);
+    if all_contracts.items.len() > 5 {
+        println!("      ... and {} more", all_contracts.items.len() - 5);
     }
 
     println!("\n💡 Why Import Contracts?");
@@ -35,12 +35,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let wallets = view.list_wallets(params).await?;
 
-    if wallets.wallets.is_empty() {
+    if wallets.items.is_empty() {
         println!("❌ No wallets found. Create a wallet first using circle_ops_example.rs");
         return Ok(());
     }
 
-    let wallet = &wallets.wallets[0];
+    let wallet = &wallets.items[0];
     println!("Using Wallet:");
     println!("  ID: {}", wallet.id);
     println!("  Address: {}", wallet.address);
@@ -44,13 +44,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let wallets = view.list_wallets(params).await?;
 
-    if wallets.wallets.len() < 2 {
+    if wallets.items.len() < 2 {
         println!("❌ Need at least 2 wallets. Run circle_ops_example.rs first.");
         return Ok(());
     }
 
-    let source_wallet = &wallets.wallets[0];
-    let dest_wallet = &wallets.wallets[1];
+    let source_wallet = &wallets.items[0];
+    let dest_wallet = &wallets.items[1];
 
     println!(
         "Using source wallet: {} ({})",
@@ -68,11 +68,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     println!(
         "   Found {} pending/queued transactions",
-        transactions.transactions.len()
+        transactions.items.len()
     );
 
-    if !transactions.transactions.is_empty() {
-        let pending_tx = &transactions.transactions[0];
+    if !transactions.items.is_empty() {
+        let pending_tx = &transactions.items[0];
         println!("\n🔍 Example pending transaction:");
         println!("   ID: {}", pending_tx.id);
         println!("   State: {}", pending_tx.state);
@@ -81,11 +81,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         println!("\n⚡ Example 1: Accelerating transaction...");
         println!("   This speeds up confirmation by increasing gas fees.");
 
-        let accelerate_builder = AccelerateTransactionRequestBuilder::new(
-            pending_tx.id.clone(),
-            Uuid::new_v4().to_string(),
-        )
-        .build();
+        let accelerate_builder =
+            AccelerateTransactionRequestBuilder::new(pending_tx.id.clone()).build();
 
         match ops.accelerate_dev_transaction(accelerate_builder).await {
             Ok(response) => {
@@ -102,9 +99,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         println!("\n❌ Example 2: Canceling a transaction...");
         println!("   This attempts to cancel by submitting a higher-fee replacement.");
 
-        let cancel_builder =
-            CancelTransactionRequestBuilder::new(pending_tx.id.clone(), Uuid::new_v4().to_string())
-                .build();
+        let cancel_builder = CancelTransactionRequestBuilder::new(pending_tx.id.clone()).build();
 
         match ops.cancel_dev_transaction(cancel_builder).await {
             Ok(response) => {
@@ -37,14 +37,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let wallets = view.list_wallets(params).await?;
 
-    if wallets.wallets.len() < 2 {
+    if wallets.items.len() < 2 {
         println!("❌ Need at least 2 wallets for transfer example.");
         println!("   Run circle_ops_example.rs to create wallets first.");
         return Ok(());
     }
 
-    let source_wallet = &wallets.wallets[0];
-    let dest_wallet = &wallets.wallets[1];
+    let source_wallet = &wallets.items[0];
+    let dest_wallet = &wallets.items[1];
 
     println!("Source Wallet:");
     println!("  ID: {}", source_wallet.id);
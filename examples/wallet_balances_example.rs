@@ -30,15 +30,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .build();
 
     let wallets = view.list_wallets(params).await?;
-    println!("✅ Found {} wallets\n", wallets.wallets.len());
+    println!("✅ Found {} wallets\n", wallets.items.len());
 
-    if wallets.wallets.is_empty() {
+    if wallets.items.is_empty() {
         println!("No wallets found. Create wallets first using circle_ops_example.rs");
         return Ok(());
     }
 
     // Check balances and NFTs for each wallet
-    for (i, wallet) in wallets.wallets.iter().enumerate() {
+    for (i, wallet) in wallets.items.iter().enumerate() {
         println!("{}. Wallet: {} ({})", i + 1, wallet.id, wallet.address);
         println!("   ═══════════════════════════════════════");
 
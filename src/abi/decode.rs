@@ -0,0 +1,798 @@
+//! ABI Decoding
+//!
+//! This module decodes Solidity ABI encoded return data into Rust types through
+//! the [`AbiDecode`] trait. It is implemented for the primitive types, `String`,
+//! [`Bytes`], [`FixedBytes`], `Vec<T>`, `[T; N]` and tuples, and can be
+//! implemented for custom structs with the [`impl_abi_decode!`](crate::impl_abi_decode)
+//! macro.
+//!
+//! # Example
+//!
+//! ```rust
+//! use inf_circle_sdk::abi::{decode_hex, U256};
+//!
+//! let output = "0x00000000000000000000000000000000000000000000000000000000000f4240";
+//! let balance: U256 = decode_hex(output).unwrap();
+//! assert_eq!(balance, U256::from(1_000_000u64));
+//! ```
+
+use super::dto::{AbiValue, Address, Bytes, FixedBytes, FixedBytesValue, U256};
+use crate::helper::{CircleError, CircleResult};
+
+/// A type that can be decoded from Solidity ABI encoded data
+///
+/// Static types are decoded in place, while dynamic types (`string`, `bytes`,
+/// `T[]` and anything containing them) are reached through a 32-byte offset
+/// stored in the head of the enclosing tuple.
+pub trait AbiDecode: Sized {
+    /// Whether this type uses the dynamic (head/tail) encoding
+    const DYNAMIC: bool = false;
+
+    /// Number of bytes a static value of this type occupies in the head
+    fn head_size() -> usize {
+        32
+    }
+
+    /// Decode a value whose encoding starts at `offset` in `data`
+    fn decode_at(data: &[u8], offset: usize) -> CircleResult<Self>;
+
+    /// Decode a complete function return payload
+    ///
+    /// A single return value is decoded as a one-element tuple. Rust tuples
+    /// override this to decode multiple return values in order.
+    fn decode_params(data: &[u8]) -> CircleResult<Self> {
+        TupleDecoder::new(data, 0).next()
+    }
+}
+
+/// Sequential reader over the fields of an ABI encoded tuple
+///
+/// Used to implement [`AbiDecode`] for structs; see [`impl_abi_decode!`](crate::impl_abi_decode).
+pub struct TupleDecoder<'a> {
+    data: &'a [u8],
+    base: usize,
+    position: usize,
+}
+
+impl<'a> TupleDecoder<'a> {
+    /// Create a decoder for a tuple whose head starts at `base`
+    pub fn new(data: &'a [u8], base: usize) -> Self {
+        Self {
+            data,
+            base,
+            position: base,
+        }
+    }
+
+    /// Decode the next field of the tuple
+    #[allow(clippy::should_implement_trait)]
+    pub fn next<T: AbiDecode>(&mut self) -> CircleResult<T> {
+        if T::DYNAMIC {
+            let offset = read_usize(self.data, self.position)?;
+            self.position += 32;
+            T::decode_at(self.data, checked_offset(self.base, offset)?)
+        } else {
+            let value = T::decode_at(self.data, self.position)?;
+            self.position = self.position.saturating_add(T::head_size());
+            Ok(value)
+        }
+    }
+}
+
+/// Decode ABI encoded function return data
+///
+/// # Arguments
+/// * `data` - The raw return data
+///
+/// # Returns
+/// * `CircleResult<T>` - The decoded value, or `CircleError::Abi` if the data is malformed
+pub fn decode<T: AbiDecode>(data: &[u8]) -> CircleResult<T> {
+    T::decode_params(data)
+}
+
+/// Decode `0x`-prefixed hex return data, such as `QueryContractResponse.output_data`
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::abi::{decode_hex, U256};
+///
+/// let (ok, amount): (bool, U256) = decode_hex(
+///     "0x0000000000000000000000000000000000000000000000000000000000000001\
+///        000000000000000000000000000000000000000000000000000000000000002a",
+/// )
+/// .unwrap();
+/// assert!(ok);
+/// assert_eq!(amount, U256::from(42u64));
+/// ```
+pub fn decode_hex<T: AbiDecode>(data: &str) -> CircleResult<T> {
+    let digits = data.strip_prefix("0x").unwrap_or(data);
+    let bytes = hex::decode(digits)
+        .map_err(|e| CircleError::Abi(format!("Invalid hex output data: {}", e)))?;
+    decode(&bytes)
+}
+
+/// Add an offset or length read from the data to a position
+///
+/// Offsets come from the data being decoded, so a malformed payload can
+/// name one that does not fit in `usize` arithmetic.
+fn checked_offset(position: usize, offset: usize) -> CircleResult<usize> {
+    position.checked_add(offset).ok_or_else(|| {
+        CircleError::Abi(format!(
+            "ABI offset or length {} at {} is out of range",
+            offset, position
+        ))
+    })
+}
+
+fn read_word(data: &[u8], offset: usize) -> CircleResult<&[u8]> {
+    let end = checked_offset(offset, 32)?;
+    data.get(offset..end).ok_or_else(|| {
+        CircleError::Abi(format!(
+            "ABI data too short: need 32 bytes at offset {}, have {}",
+            offset,
+            data.len()
+        ))
+    })
+}
+
+fn read_usize(data: &[u8], offset: usize) -> CircleResult<usize> {
+    let word = read_word(data, offset)?;
+    if word[..24].iter().any(|b| *b != 0) {
+        return Err(CircleError::Abi(format!(
+            "ABI offset or length at {} is out of range",
+            offset
+        )));
+    }
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&word[24..]);
+    usize::try_from(u64::from_be_bytes(bytes))
+        .map_err(|_| CircleError::Abi("ABI offset or length is out of range".to_string()))
+}
+
+fn read_bytes(data: &[u8], offset: usize) -> CircleResult<&[u8]> {
+    let len = read_usize(data, offset)?;
+    let start = offset + 32;
+    data.get(start..checked_offset(start, len)?)
+        .ok_or_else(|| CircleError::Abi(format!("ABI data too short for {} byte value", len)))
+}
+
+/// Read the element count of a dynamic array whose length word is at `offset`
+///
+/// Every element takes at least one 32-byte word, so a count larger than
+/// the remaining data allows is rejected before anything is allocated.
+fn read_count(data: &[u8], offset: usize) -> CircleResult<usize> {
+    let len = read_usize(data, offset)?;
+    let remaining = data.len().saturating_sub(offset + 32);
+    if len > remaining / 32 {
+        return Err(CircleError::Abi(format!(
+            "ABI data too short for {} array elements",
+            len
+        )));
+    }
+    Ok(len)
+}
+
+impl AbiDecode for U256 {
+    fn decode_at(data: &[u8], offset: usize) -> CircleResult<Self> {
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(read_word(data, offset)?);
+        Ok(U256::from_be_bytes(bytes))
+    }
+}
+
+impl AbiDecode for Address {
+    fn decode_at(data: &[u8], offset: usize) -> CircleResult<Self> {
+        let word = read_word(data, offset)?;
+        if word[..12].iter().any(|b| *b != 0) {
+            return Err(CircleError::Abi("Invalid address padding".to_string()));
+        }
+        let mut bytes = [0u8; 20];
+        bytes.copy_from_slice(&word[12..]);
+        Ok(Address::from_bytes(bytes))
+    }
+}
+
+impl AbiDecode for bool {
+    fn decode_at(data: &[u8], offset: usize) -> CircleResult<Self> {
+        match u8::decode_at(data, offset)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            other => Err(CircleError::Abi(format!("Invalid bool value: {}", other))),
+        }
+    }
+}
+
+macro_rules! impl_abi_decode_uint {
+    ($($ty:ty),*) => {
+        $(
+            impl AbiDecode for $ty {
+                fn decode_at(data: &[u8], offset: usize) -> CircleResult<Self> {
+                    const SIZE: usize = std::mem::size_of::<$ty>();
+                    let word = read_word(data, offset)?;
+                    if word[..32 - SIZE].iter().any(|b| *b != 0) {
+                        return Err(CircleError::Abi(format!(
+                            "Value does not fit in {}",
+                            stringify!($ty)
+                        )));
+                    }
+                    let mut bytes = [0u8; SIZE];
+                    bytes.copy_from_slice(&word[32 - SIZE..]);
+                    Ok(<$ty>::from_be_bytes(bytes))
+                }
+            }
+        )*
+    };
+}
+
+impl_abi_decode_uint!(u8, u16, u32, u64, u128);
+
+macro_rules! impl_abi_decode_int {
+    ($($ty:ty),*) => {
+        $(
+            impl AbiDecode for $ty {
+                fn decode_at(data: &[u8], offset: usize) -> CircleResult<Self> {
+                    const SIZE: usize = std::mem::size_of::<$ty>();
+                    let word = read_word(data, offset)?;
+                    let mut bytes = [0u8; SIZE];
+                    bytes.copy_from_slice(&word[32 - SIZE..]);
+                    let value = <$ty>::from_be_bytes(bytes);
+
+                    // The upper bytes must be a sign extension of the value
+                    let fill = if value < 0 { 0xff } else { 0x00 };
+                    if word[..32 - SIZE].iter().any(|b| *b != fill) {
+                        return Err(CircleError::Abi(format!(
+                            "Value does not fit in {}",
+                            stringify!($ty)
+                        )));
+                    }
+                    Ok(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_abi_decode_int!(i8, i16, i32, i64, i128);
+
+impl<const N: usize> AbiDecode for FixedBytes<N> {
+    fn decode_at(data: &[u8], offset: usize) -> CircleResult<Self> {
+        if N == 0 || N > 32 {
+            return Err(CircleError::Abi(format!("Invalid bytes{} type", N)));
+        }
+        let word = read_word(data, offset)?;
+        let mut bytes = [0u8; N];
+        bytes.copy_from_slice(&word[..N]);
+        Ok(FixedBytes(bytes))
+    }
+}
+
+impl AbiDecode for Bytes {
+    const DYNAMIC: bool = true;
+
+    fn decode_at(data: &[u8], offset: usize) -> CircleResult<Self> {
+        Ok(Bytes(read_bytes(data, offset)?.to_vec()))
+    }
+}
+
+impl AbiDecode for String {
+    const DYNAMIC: bool = true;
+
+    fn decode_at(data: &[u8], offset: usize) -> CircleResult<Self> {
+        String::from_utf8(read_bytes(data, offset)?.to_vec())
+            .map_err(|e| CircleError::Abi(format!("Invalid UTF-8 in string value: {}", e)))
+    }
+}
+
+impl<T: AbiDecode> AbiDecode for Vec<T> {
+    const DYNAMIC: bool = true;
+
+    fn decode_at(data: &[u8], offset: usize) -> CircleResult<Self> {
+        let len = read_count(data, offset)?;
+        let mut decoder = TupleDecoder::new(data, offset + 32);
+        (0..len).map(|_| decoder.next()).collect()
+    }
+}
+
+impl<T: AbiDecode, const N: usize> AbiDecode for [T; N] {
+    const DYNAMIC: bool = T::DYNAMIC;
+
+    fn head_size() -> usize {
+        N * T::head_size()
+    }
+
+    fn decode_at(data: &[u8], offset: usize) -> CircleResult<Self> {
+        let mut decoder = TupleDecoder::new(data, offset);
+        let values = (0..N)
+            .map(|_| decoder.next())
+            .collect::<CircleResult<Vec<T>>>()?;
+        values
+            .try_into()
+            .map_err(|_| CircleError::Abi("Fixed array length mismatch".to_string()))
+    }
+}
+
+macro_rules! impl_abi_decode_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: AbiDecode),+> AbiDecode for ($($name,)+) {
+            const DYNAMIC: bool = false $(|| $name::DYNAMIC)+;
+
+            fn head_size() -> usize {
+                0 $(+ $name::head_size())+
+            }
+
+            fn decode_at(data: &[u8], offset: usize) -> CircleResult<Self> {
+                let mut decoder = TupleDecoder::new(data, offset);
+                Ok(($(decoder.next::<$name>()?,)+))
+            }
+
+            fn decode_params(data: &[u8]) -> CircleResult<Self> {
+                Self::decode_at(data, 0)
+            }
+        }
+    };
+}
+
+impl_abi_decode_tuple!(A);
+impl_abi_decode_tuple!(A, B);
+impl_abi_decode_tuple!(A, B, C);
+impl_abi_decode_tuple!(A, B, C, D);
+impl_abi_decode_tuple!(A, B, C, D, E);
+impl_abi_decode_tuple!(A, B, C, D, E, F);
+impl_abi_decode_tuple!(A, B, C, D, E, F, G);
+impl_abi_decode_tuple!(A, B, C, D, E, F, G, H);
+
+/// Decode ABI encoded data into [`AbiValue`]s from a list of Solidity types
+///
+/// Unlike [`decode`], the types are only known at runtime, e.g. from a
+/// contract's ABI JSON. Types must be canonical (`uint256`, `(address,bytes)[]`,
+/// ...); `uint`/`int` are accepted as `uint256`/`int256`.
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::abi::{decode_values, encode, AbiValue, U256};
+///
+/// let data = encode(&[AbiValue::from(42u64), AbiValue::from("USDC")]);
+/// let values = decode_values(&["uint256", "string"], &data).unwrap();
+/// assert_eq!(values, [AbiValue::Uint(U256::from(42u64)), AbiValue::from("USDC")]);
+/// ```
+pub fn decode_values<S: AsRef<str>>(types: &[S], data: &[u8]) -> CircleResult<Vec<AbiValue>> {
+    let types = types
+        .iter()
+        .map(|ty| ParamType::parse(ty.as_ref()))
+        .collect::<CircleResult<Vec<_>>>()?;
+    decode_tuple(&types, data, 0)
+}
+
+/// Decode a single static value from a 32-byte word, such as an indexed event topic
+///
+/// Returns `None` for dynamic types and multi-word static types (tuples and
+/// fixed arrays), which are stored in topics as the Keccak-256 hash of their
+/// encoding and cannot be recovered.
+pub fn decode_word(ty: &str, word: &[u8; 32]) -> CircleResult<Option<AbiValue>> {
+    let ty = ParamType::parse(ty)?;
+    if ty.is_dynamic() || ty.head_size() != 32 {
+        return Ok(None);
+    }
+    ty.decode_at(word, 0).map(Some)
+}
+
+/// Check that a typed value can be passed as a parameter of the given Solidity type
+///
+/// Integers must fit the declared width (a `uint8` rejects 256), `bytesN` and
+/// `T[k]` values must have exactly the declared length, and arrays and tuples
+/// are checked element by element. Non-negative [`AbiValue::Uint`] values are
+/// also accepted for signed types.
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::abi::{check_value, AbiValue};
+///
+/// assert!(check_value("uint8", &AbiValue::from(255u64)).is_ok());
+/// assert!(check_value("uint8", &AbiValue::from(256u64)).is_err());
+/// assert!(check_value("address[]", &AbiValue::array(vec![])).is_ok());
+/// ```
+pub fn check_value(ty: &str, value: &AbiValue) -> CircleResult<()> {
+    if ParamType::parse(ty)?.accepts(value) {
+        Ok(())
+    } else {
+        Err(CircleError::Abi(format!(
+            "Expected a value of type {}, got {}",
+            ty.trim(),
+            value.type_name()
+        )))
+    }
+}
+
+/// A Solidity type parsed from its canonical name
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum ParamType {
+    Address,
+    Uint(usize),
+    Int(usize),
+    Bool,
+    FixedBytes(usize),
+    Bytes,
+    String,
+    Array(Box<ParamType>),
+    FixedArray(Box<ParamType>, usize),
+    Tuple(Vec<ParamType>),
+}
+
+impl ParamType {
+    fn parse(ty: &str) -> CircleResult<Self> {
+        let ty = ty.trim();
+        let invalid = || CircleError::Abi(format!("Unsupported ABI type: {}", ty));
+
+        if let Some(rest) = ty.strip_suffix(']') {
+            let open = rest.rfind('[').ok_or_else(invalid)?;
+            let inner = Box::new(Self::parse(&rest[..open])?);
+            let size = &rest[open + 1..];
+            return if size.is_empty() {
+                Ok(ParamType::Array(inner))
+            } else {
+                let size = size.parse().map_err(|_| invalid())?;
+                Ok(ParamType::FixedArray(inner, size))
+            };
+        }
+
+        if let Some(inner) = ty.strip_prefix('(').and_then(|t| t.strip_suffix(')')) {
+            return split_top_level(inner)
+                .into_iter()
+                .filter(|part| !part.trim().is_empty())
+                .map(Self::parse)
+                .collect::<CircleResult<Vec<_>>>()
+                .map(ParamType::Tuple);
+        }
+
+        match ty {
+            "address" => Ok(ParamType::Address),
+            "bool" => Ok(ParamType::Bool),
+            "string" => Ok(ParamType::String),
+            "bytes" => Ok(ParamType::Bytes),
+            "uint" => Ok(ParamType::Uint(256)),
+            "int" => Ok(ParamType::Int(256)),
+            _ => {
+                let sized = |prefix: &str, max: usize| {
+                    ty.strip_prefix(prefix)
+                        .and_then(|n| n.parse::<usize>().ok())
+                        .filter(|n| (1..=max).contains(n))
+                };
+                if let Some(n) = sized("bytes", 32) {
+                    Ok(ParamType::FixedBytes(n))
+                } else if let Some(bits) = sized("uint", 256) {
+                    Ok(ParamType::Uint(bits))
+                } else if let Some(bits) = sized("int", 256) {
+                    Ok(ParamType::Int(bits))
+                } else {
+                    Err(invalid())
+                }
+            }
+        }
+    }
+
+    fn is_dynamic(&self) -> bool {
+        match self {
+            ParamType::Bytes | ParamType::String | ParamType::Array(_) => true,
+            ParamType::FixedArray(inner, _) => inner.is_dynamic(),
+            ParamType::Tuple(types) => types.iter().any(ParamType::is_dynamic),
+            _ => false,
+        }
+    }
+
+    fn head_size(&self) -> usize {
+        match self {
+            _ if self.is_dynamic() => 32,
+            ParamType::FixedArray(inner, size) => size * inner.head_size(),
+            ParamType::Tuple(types) => types.iter().map(ParamType::head_size).sum(),
+            _ => 32,
+        }
+    }
+
+    fn accepts(&self, value: &AbiValue) -> bool {
+        match (self, value) {
+            (ParamType::Address, AbiValue::Address(_))
+            | (ParamType::Bool, AbiValue::Bool(_))
+            | (ParamType::Bytes, AbiValue::Bytes(_))
+            | (ParamType::String, AbiValue::String(_)) => true,
+            (ParamType::Uint(bits), AbiValue::Uint(v)) => leading_bits(v, false) >= 256 - bits,
+            // A non-negative unsigned value needs one extra bit for the sign
+            (ParamType::Int(bits), AbiValue::Uint(v)) => leading_bits(v, false) > 256 - bits,
+            (ParamType::Int(bits), AbiValue::Int(v)) => {
+                let negative = v.to_be_bytes()[0] & 0x80 != 0;
+                leading_bits(v, negative) > 256 - bits
+            }
+            (ParamType::FixedBytes(size), AbiValue::FixedBytes(bytes)) => bytes.len() == *size,
+            (ParamType::Array(inner), AbiValue::Array(values)) => {
+                values.iter().all(|v| inner.accepts(v))
+            }
+            (ParamType::FixedArray(inner, size), AbiValue::FixedArray(values)) => {
+                values.len() == *size && values.iter().all(|v| inner.accepts(v))
+            }
+            (ParamType::Tuple(types), AbiValue::Tuple(values)) => {
+                types.len() == values.len() && types.iter().zip(values).all(|(t, v)| t.accepts(v))
+            }
+            _ => false,
+        }
+    }
+
+    fn decode_at(&self, data: &[u8], offset: usize) -> CircleResult<AbiValue> {
+        Ok(match self {
+            ParamType::Address => AbiValue::Address(Address::decode_at(data, offset)?),
+            ParamType::Uint(_) => AbiValue::Uint(U256::decode_at(data, offset)?),
+            ParamType::Int(_) => AbiValue::Int(U256::decode_at(data, offset)?),
+            ParamType::Bool => AbiValue::Bool(bool::decode_at(data, offset)?),
+            ParamType::FixedBytes(size) => AbiValue::FixedBytes(FixedBytesValue::new_unchecked(
+                read_word(data, offset)?[..*size].to_vec(),
+            )),
+            ParamType::Bytes => AbiValue::Bytes(read_bytes(data, offset)?.to_vec()),
+            ParamType::String => AbiValue::String(String::decode_at(data, offset)?),
+            ParamType::Array(inner) => {
+                let len = read_count(data, offset)?;
+                let types = vec![(**inner).clone(); len];
+                AbiValue::Array(decode_tuple(&types, data, offset + 32)?)
+            }
+            ParamType::FixedArray(inner, size) => {
+                let types = vec![(**inner).clone(); *size];
+                AbiValue::FixedArray(decode_tuple(&types, data, offset)?)
+            }
+            ParamType::Tuple(types) => AbiValue::Tuple(decode_tuple(types, data, offset)?),
+        })
+    }
+}
+
+/// Decode the fields of a tuple whose head starts at `base`
+fn decode_tuple(types: &[ParamType], data: &[u8], base: usize) -> CircleResult<Vec<AbiValue>> {
+    let mut position = base;
+    types
+        .iter()
+        .map(|ty| {
+            if ty.is_dynamic() {
+                let offset = read_usize(data, position)?;
+                position += 32;
+                ty.decode_at(data, checked_offset(base, offset)?)
+            } else {
+                let value = ty.decode_at(data, position)?;
+                position = position.saturating_add(ty.head_size());
+                Ok(value)
+            }
+        })
+        .collect()
+}
+
+/// Number of leading bits of a 256-bit word equal to `bit`
+fn leading_bits(value: &U256, bit: bool) -> usize {
+    let mut count = 0;
+    for byte in value.to_be_bytes() {
+        let byte = if bit { !byte } else { byte };
+        count += byte.leading_zeros() as usize;
+        if byte != 0 {
+            break;
+        }
+    }
+    count
+}
+
+/// Split a tuple's component list at top-level commas
+fn split_top_level(list: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in list.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(&list[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&list[start..]);
+    parts
+}
+
+/// Implement [`AbiDecode`](crate::abi::AbiDecode) for a struct that mirrors a Solidity tuple
+///
+/// Fields are decoded in declaration order. The struct is treated as a single
+/// tuple value; to decode several return values of a function, use a Rust tuple
+/// or list the fields of a struct whose members are all static types.
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::abi::{decode_hex, Address, U256};
+/// use inf_circle_sdk::impl_abi_decode;
+///
+/// struct Reserves {
+///     reserve0: U256,
+///     reserve1: U256,
+///     last_update: u32,
+/// }
+///
+/// impl_abi_decode!(Reserves {
+///     reserve0: U256,
+///     reserve1: U256,
+///     last_update: u32,
+/// });
+///
+/// let output = format!("0x{}{}{}", "00".repeat(31) + "01", "00".repeat(31) + "02", "00".repeat(31) + "03");
+/// let reserves: Reserves = decode_hex(&output).unwrap();
+/// assert_eq!(reserves.reserve1, U256::from(2u64));
+/// assert_eq!(reserves.last_update, 3);
+/// ```
+#[macro_export]
+macro_rules! impl_abi_decode {
+    ($name:ident { $($field:ident : $ty:ty),+ $(,)? }) => {
+        impl $crate::abi::AbiDecode for $name {
+            const DYNAMIC: bool = false $(|| <$ty as $crate::abi::AbiDecode>::DYNAMIC)+;
+
+            fn head_size() -> usize {
+                0 $(+ <$ty as $crate::abi::AbiDecode>::head_size())+
+            }
+
+            fn decode_at(data: &[u8], offset: usize) -> $crate::helper::CircleResult<Self> {
+                let mut decoder = $crate::abi::TupleDecoder::new(data, offset);
+                Ok(Self {
+                    $($field: decoder.next::<$ty>()?,)+
+                })
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::{encode, AbiValue};
+
+    #[test]
+    fn test_decode_round_trip_with_dynamic_values() {
+        let to: Address = "0x742d35cc6634c0532925a3b844bc9e7595f0beb0"
+            .parse()
+            .unwrap();
+        let encoded = encode(&[
+            AbiValue::from(to),
+            AbiValue::from("USD Coin"),
+            AbiValue::array(vec![AbiValue::from(2u64), AbiValue::from(3u64)]),
+            AbiValue::bytes(vec![0xde, 0xad]),
+        ]);
+
+        let (address, name, values, bytes): (Address, String, Vec<u64>, Bytes) =
+            decode(&encoded).unwrap();
+        assert_eq!(address, to);
+        assert_eq!(name, "USD Coin");
+        assert_eq!(values, vec![2, 3]);
+        assert_eq!(bytes.0, vec![0xde, 0xad]);
+    }
+
+    #[test]
+    fn test_decode_single_dynamic_return() {
+        // A function returning a single `string` is encoded as a one-element tuple
+        let encoded = encode(&[AbiValue::from("USDC")]);
+        let symbol: String = decode(&encoded).unwrap();
+        assert_eq!(symbol, "USDC");
+    }
+
+    #[test]
+    fn test_decode_signed_and_range_checks() {
+        let encoded = encode(&[AbiValue::from(-5i64)]);
+        assert_eq!(decode::<i64>(&encoded).unwrap(), -5);
+        assert_eq!(decode::<i128>(&encoded).unwrap(), -5);
+        assert!(decode::<u64>(&encoded).is_err());
+
+        let encoded = encode(&[AbiValue::from(300u64)]);
+        assert!(decode::<u8>(&encoded).is_err());
+    }
+
+    #[test]
+    fn test_decode_fixed_array_and_fixed_bytes() {
+        let encoded = encode(&[
+            AbiValue::fixed_array(vec![AbiValue::from(7u64), AbiValue::from(8u64)]),
+            AbiValue::fixed_bytes(vec![0xab; 4]).unwrap(),
+        ]);
+        let (values, tag): ([u32; 2], FixedBytes<4>) = decode(&encoded).unwrap();
+        assert_eq!(values, [7, 8]);
+        assert_eq!(tag.0, [0xab; 4]);
+    }
+
+    #[test]
+    fn test_check_value_widths_and_shapes() {
+        assert!(check_value("uint256", &AbiValue::Uint(U256::MAX)).is_ok());
+        assert!(check_value("uint16", &AbiValue::from(65_536u64)).is_err());
+        assert!(check_value("int8", &AbiValue::from(-128i64)).is_ok());
+        assert!(check_value("int8", &AbiValue::from(-129i64)).is_err());
+        assert!(check_value("int8", &AbiValue::from(127u64)).is_ok());
+        assert!(check_value("int8", &AbiValue::from(128u64)).is_err());
+
+        let pair = AbiValue::fixed_array(vec![AbiValue::from(1u64), AbiValue::from(2u64)]);
+        assert!(check_value("uint8[2]", &pair).is_ok());
+        assert!(check_value("uint8[3]", &pair).is_err());
+        assert!(check_value("uint8[]", &pair).is_err());
+        assert!(check_value(
+            "(address,bytes4)",
+            &AbiValue::tuple(vec![
+                AbiValue::from(Address::default()),
+                AbiValue::fixed_bytes(vec![0; 4]).unwrap(),
+            ])
+        )
+        .is_ok());
+        assert!(matches!(
+            check_value("address", &AbiValue::from("0x00")),
+            Err(CircleError::Abi(_))
+        ));
+    }
+
+    #[test]
+    fn test_decode_truncated_data() {
+        assert!(matches!(
+            decode_hex::<U256>("0x0001"),
+            Err(CircleError::Abi(_))
+        ));
+    }
+
+    #[test]
+    fn test_decode_rejects_huge_offsets_and_lengths() {
+        let huge = format!("{:0>64}", "ffffffffffffffff");
+        let zero = format!("{:0>64}", "0");
+        let small = format!("{:0>64}", "20");
+
+        // Offset word near usize::MAX
+        let output = format!("0x{}{}", huge, zero);
+        assert!(matches!(
+            decode_hex::<String>(&output),
+            Err(CircleError::Abi(_))
+        ));
+        assert!(matches!(
+            decode_hex::<(U256, Bytes)>(&format!("0x{}{}{}", zero, huge, zero)),
+            Err(CircleError::Abi(_))
+        ));
+
+        // Length word near usize::MAX behind a valid offset
+        let output = format!("0x{}{}", small, huge);
+        assert!(matches!(
+            decode_hex::<Bytes>(&output),
+            Err(CircleError::Abi(_))
+        ));
+        assert!(matches!(
+            decode_hex::<Vec<U256>>(&output),
+            Err(CircleError::Abi(_))
+        ));
+        let bytes = hex::decode(&output[2..]).unwrap();
+        assert!(matches!(
+            decode_values(&["uint256[]"], &bytes),
+            Err(CircleError::Abi(_))
+        ));
+        assert!(matches!(
+            decode_values(
+                &["string"],
+                &hex::decode(format!("{}{}", huge, zero)).unwrap()
+            ),
+            Err(CircleError::Abi(_))
+        ));
+    }
+
+    #[test]
+    fn test_decode_values_from_type_names() {
+        let to: Address = "0x742d35cc6634c0532925a3b844bc9e7595f0beb0"
+            .parse()
+            .unwrap();
+        let orders = AbiValue::array(vec![
+            AbiValue::tuple(vec![AbiValue::from(to), AbiValue::from("a")]),
+            AbiValue::tuple(vec![AbiValue::from(to), AbiValue::from("b")]),
+        ]);
+        let pair = AbiValue::fixed_array(vec![AbiValue::from(1u64), AbiValue::from(2u64)]);
+        let encoded = encode(&[orders.clone(), pair.clone(), AbiValue::from(true)]);
+
+        let values = decode_values(&["(address,string)[]", "uint64[2]", "bool"], &encoded).unwrap();
+        assert_eq!(values, [orders, pair, AbiValue::Bool(true)]);
+
+        assert!(matches!(
+            decode_values(&["uint257"], &encoded),
+            Err(CircleError::Abi(_))
+        ));
+    }
+}
@@ -0,0 +1,468 @@
+//! ABI Data Transfer Objects
+//!
+//! This module contains the typed values used to build Solidity ABI payloads,
+//! including a 256-bit unsigned integer, a 20-byte address, byte strings and
+//! the [`AbiValue`] tree that mirrors Solidity's type system.
+
+use crate::helper::{CircleError, CircleResult};
+use std::fmt;
+use std::str::FromStr;
+
+/// 256-bit unsigned integer stored as 32 big-endian bytes
+///
+/// `uint256` values routinely exceed the range of `i64`/`u128`, so this type keeps
+/// the full width and renders as a decimal string when sent to the Circle API.
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::abi::dto::U256;
+///
+/// let amount: U256 = "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(amount, U256::MAX);
+/// assert_eq!(U256::from(1_000_000u64).to_string(), "1000000");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct U256([u8; 32]);
+
+impl U256 {
+    /// The value zero
+    pub const ZERO: U256 = U256([0u8; 32]);
+
+    /// The largest representable value (2^256 - 1)
+    pub const MAX: U256 = U256([0xffu8; 32]);
+
+    /// Create a value from 32 big-endian bytes
+    pub fn from_be_bytes(bytes: [u8; 32]) -> Self {
+        U256(bytes)
+    }
+
+    /// Return the 32 big-endian bytes of this value
+    pub fn to_be_bytes(&self) -> [u8; 32] {
+        self.0
+    }
+
+    /// Whether this value is zero
+    pub fn is_zero(&self) -> bool {
+        self.0.iter().all(|b| *b == 0)
+    }
+
+    /// Parse a decimal string (e.g., "1000000")
+    pub fn from_dec_str(s: &str) -> CircleResult<Self> {
+        if s.is_empty() {
+            return Err(CircleError::Abi("Empty decimal string".to_string()));
+        }
+
+        let mut bytes = [0u8; 32];
+        for c in s.chars() {
+            let digit = c.to_digit(10).ok_or_else(|| {
+                CircleError::Abi(format!("Invalid decimal digit '{}' in {}", c, s))
+            })?;
+
+            // bytes = bytes * 10 + digit
+            let mut carry = digit;
+            for byte in bytes.iter_mut().rev() {
+                let value = (*byte as u32) * 10 + carry;
+                *byte = (value & 0xff) as u8;
+                carry = value >> 8;
+            }
+            if carry != 0 {
+                return Err(CircleError::Abi(format!("Value {} overflows uint256", s)));
+            }
+        }
+
+        Ok(U256(bytes))
+    }
+
+    /// Parse a hexadecimal string, with or without the `0x` prefix
+    pub fn from_hex_str(s: &str) -> CircleResult<Self> {
+        let digits = s.strip_prefix("0x").unwrap_or(s);
+        if digits.is_empty() || digits.len() > 64 {
+            return Err(CircleError::Abi(format!(
+                "Invalid uint256 hex string: {}",
+                s
+            )));
+        }
+
+        let padded = format!("{:0>64}", digits);
+        let decoded = hex::decode(&padded)
+            .map_err(|e| CircleError::Abi(format!("Invalid uint256 hex string {}: {}", s, e)))?;
+
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(&decoded);
+        Ok(U256(bytes))
+    }
+
+    /// Two's complement negation (wrapping)
+    pub(crate) fn wrapping_neg(self) -> Self {
+        let mut bytes = self.0.map(|b| !b);
+        for byte in bytes.iter_mut().rev() {
+            let (value, overflow) = byte.overflowing_add(1);
+            *byte = value;
+            if !overflow {
+                break;
+            }
+        }
+        U256(bytes)
+    }
+
+    /// Render this word as a signed (two's complement) decimal string
+    pub(crate) fn to_signed_string(self) -> String {
+        if self.0[0] & 0x80 != 0 {
+            format!("-{}", self.wrapping_neg())
+        } else {
+            self.to_string()
+        }
+    }
+
+    /// Convert to `u128` if the value fits
+    pub fn to_u128(&self) -> Option<u128> {
+        if self.0[..16].iter().any(|b| *b != 0) {
+            return None;
+        }
+        let mut low = [0u8; 16];
+        low.copy_from_slice(&self.0[16..]);
+        Some(u128::from_be_bytes(low))
+    }
+}
+
+impl From<u64> for U256 {
+    fn from(value: u64) -> Self {
+        U256::from(value as u128)
+    }
+}
+
+impl From<u128> for U256 {
+    fn from(value: u128) -> Self {
+        let mut bytes = [0u8; 32];
+        bytes[16..].copy_from_slice(&value.to_be_bytes());
+        U256(bytes)
+    }
+}
+
+impl FromStr for U256 {
+    type Err = CircleError;
+
+    /// Parse either a `0x`-prefixed hex string or a decimal string
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with("0x") {
+            U256::from_hex_str(s)
+        } else {
+            U256::from_dec_str(s)
+        }
+    }
+}
+
+impl fmt::Display for U256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return f.write_str("0");
+        }
+
+        // Repeated division by 10 over the big-endian bytes
+        let mut value = self.0;
+        let mut digits = Vec::new();
+        while value.iter().any(|b| *b != 0) {
+            let mut remainder = 0u32;
+            for byte in value.iter_mut() {
+                let current = (remainder << 8) | *byte as u32;
+                *byte = (current / 10) as u8;
+                remainder = current % 10;
+            }
+            digits.push(char::from(b'0' + remainder as u8));
+        }
+
+        let s: String = digits.iter().rev().collect();
+        f.write_str(&s)
+    }
+}
+
+/// 20-byte EVM address
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::abi::dto::Address;
+///
+/// let address: Address = "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb0".parse().unwrap();
+/// assert_eq!(address.to_string(), "0x742d35cc6634c0532925a3b844bc9e7595f0beb0");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Address([u8; 20]);
+
+impl Address {
+    /// Create an address from its raw bytes
+    pub fn from_bytes(bytes: [u8; 20]) -> Self {
+        Address(bytes)
+    }
+
+    /// Return the raw bytes of this address
+    pub fn as_bytes(&self) -> &[u8; 20] {
+        &self.0
+    }
+}
+
+impl FromStr for Address {
+    type Err = CircleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s.strip_prefix("0x").unwrap_or(s);
+        if digits.len() != 40 {
+            return Err(CircleError::Abi(format!("Invalid address length: {}", s)));
+        }
+
+        let decoded = hex::decode(digits)
+            .map_err(|e| CircleError::Abi(format!("Invalid address {}: {}", s, e)))?;
+
+        let mut bytes = [0u8; 20];
+        bytes.copy_from_slice(&decoded);
+        Ok(Address(bytes))
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{}", hex::encode(self.0))
+    }
+}
+
+/// Dynamic byte string (`bytes`)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Bytes(pub Vec<u8>);
+
+/// Fixed-size byte array (`bytesN`, 1 <= N <= 32)
+///
+/// Converting one with another `N` into an [`AbiValue`] fails to compile:
+///
+/// ```rust,compile_fail
+/// use inf_circle_sdk::abi::dto::{AbiValue, FixedBytes};
+///
+/// let too_long = AbiValue::from(FixedBytes([0u8; 33]));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedBytes<const N: usize>(pub [u8; N]);
+
+impl<const N: usize> FixedBytes<N> {
+    const VALID_LENGTH: () = assert!(N >= 1 && N <= 32, "bytesN must be 1 to 32 bytes");
+}
+
+/// Contents of [`AbiValue::FixedBytes`]: between 1 and 32 bytes
+///
+/// Only built through [`AbiValue::fixed_bytes`] and `From<FixedBytes<N>>`,
+/// which check the length, so every value encodes to a single word.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FixedBytesValue(Vec<u8>);
+
+impl FixedBytesValue {
+    /// Wrap bytes whose length the caller has already checked
+    pub(crate) fn new_unchecked(bytes: Vec<u8>) -> Self {
+        debug_assert!((1..=32).contains(&bytes.len()));
+        Self(bytes)
+    }
+
+    /// The bytes
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Take the bytes
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl std::ops::Deref for FixedBytesValue {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// A typed Solidity ABI value
+///
+/// Each variant maps to one Solidity type. Values are encoded with
+/// [`encode`](crate::abi::handler::encode) or converted into
+/// [`AbiParameter`](crate::dev_wallet::dto::AbiParameter) for the Circle API
+/// without losing precision.
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::abi::dto::{AbiValue, Address, U256};
+///
+/// let to: Address = "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb0".parse().unwrap();
+/// let params = vec![
+///     AbiValue::from(to),
+///     AbiValue::from(U256::from(1_000_000u64)),
+///     AbiValue::tuple(vec![AbiValue::from(true), AbiValue::from("memo")]),
+/// ];
+/// assert_eq!(params[2].type_name(), "(bool,string)");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AbiValue {
+    /// `address`
+    Address(Address),
+    /// `uint256` (other `uintN` widths share the same 32-byte encoding)
+    Uint(U256),
+    /// `int256`, stored as a two's complement 32-byte word
+    Int(U256),
+    /// `bool`
+    Bool(bool),
+    /// `bytesN` where N is the number of bytes (1..=32); see [`AbiValue::fixed_bytes`]
+    FixedBytes(FixedBytesValue),
+    /// `bytes`
+    Bytes(Vec<u8>),
+    /// `string`
+    String(String),
+    /// `T[]`
+    Array(Vec<AbiValue>),
+    /// `T[k]`
+    FixedArray(Vec<AbiValue>),
+    /// `(T1,T2,...)`
+    Tuple(Vec<AbiValue>),
+}
+
+impl AbiValue {
+    /// Create a dynamic array (`T[]`)
+    pub fn array(values: Vec<AbiValue>) -> Self {
+        AbiValue::Array(values)
+    }
+
+    /// Create a fixed-size array (`T[k]`)
+    pub fn fixed_array(values: Vec<AbiValue>) -> Self {
+        AbiValue::FixedArray(values)
+    }
+
+    /// Create a tuple (`(T1,T2,...)`)
+    pub fn tuple(values: Vec<AbiValue>) -> Self {
+        AbiValue::Tuple(values)
+    }
+
+    /// Create a `bytes` value
+    pub fn bytes(bytes: impl Into<Vec<u8>>) -> Self {
+        AbiValue::Bytes(bytes.into())
+    }
+
+    /// Create a `bytesN` value
+    ///
+    /// Returns an error if the input is empty or longer than 32 bytes.
+    pub fn fixed_bytes(bytes: impl Into<Vec<u8>>) -> CircleResult<Self> {
+        let bytes = bytes.into();
+        if bytes.is_empty() || bytes.len() > 32 {
+            return Err(CircleError::Abi(format!(
+                "bytesN must be between 1 and 32 bytes, got {}",
+                bytes.len()
+            )));
+        }
+        Ok(AbiValue::FixedBytes(FixedBytesValue(bytes)))
+    }
+
+    /// Whether this value uses the dynamic (head/tail) encoding
+    pub fn is_dynamic(&self) -> bool {
+        match self {
+            AbiValue::Bytes(_) | AbiValue::String(_) | AbiValue::Array(_) => true,
+            AbiValue::FixedArray(values) | AbiValue::Tuple(values) => {
+                values.iter().any(AbiValue::is_dynamic)
+            }
+            _ => false,
+        }
+    }
+
+    /// Canonical Solidity type name of this value (e.g., "uint256", "(address,bytes)[2]")
+    ///
+    /// Element types of empty arrays cannot be inferred and are rendered as `uint256`.
+    pub fn type_name(&self) -> String {
+        match self {
+            AbiValue::Address(_) => "address".to_string(),
+            AbiValue::Uint(_) => "uint256".to_string(),
+            AbiValue::Int(_) => "int256".to_string(),
+            AbiValue::Bool(_) => "bool".to_string(),
+            AbiValue::FixedBytes(bytes) => format!("bytes{}", bytes.len()),
+            AbiValue::Bytes(_) => "bytes".to_string(),
+            AbiValue::String(_) => "string".to_string(),
+            AbiValue::Array(values) => format!("{}[]", element_type_name(values)),
+            AbiValue::FixedArray(values) => {
+                format!("{}[{}]", element_type_name(values), values.len())
+            }
+            AbiValue::Tuple(values) => {
+                let inner: Vec<String> = values.iter().map(AbiValue::type_name).collect();
+                format!("({})", inner.join(","))
+            }
+        }
+    }
+}
+
+fn element_type_name(values: &[AbiValue]) -> String {
+    values
+        .first()
+        .map(AbiValue::type_name)
+        .unwrap_or_else(|| "uint256".to_string())
+}
+
+impl From<Address> for AbiValue {
+    fn from(value: Address) -> Self {
+        AbiValue::Address(value)
+    }
+}
+
+impl From<U256> for AbiValue {
+    fn from(value: U256) -> Self {
+        AbiValue::Uint(value)
+    }
+}
+
+impl From<u64> for AbiValue {
+    fn from(value: u64) -> Self {
+        AbiValue::Uint(U256::from(value))
+    }
+}
+
+impl From<u128> for AbiValue {
+    fn from(value: u128) -> Self {
+        AbiValue::Uint(U256::from(value))
+    }
+}
+
+impl From<i64> for AbiValue {
+    fn from(value: i64) -> Self {
+        // Sign-extend into a 32-byte two's complement word
+        let mut bytes = if value < 0 { [0xffu8; 32] } else { [0u8; 32] };
+        bytes[24..].copy_from_slice(&value.to_be_bytes());
+        AbiValue::Int(U256::from_be_bytes(bytes))
+    }
+}
+
+impl From<Bytes> for AbiValue {
+    fn from(value: Bytes) -> Self {
+        AbiValue::Bytes(value.0)
+    }
+}
+
+impl<const N: usize> From<FixedBytes<N>> for AbiValue {
+    fn from(value: FixedBytes<N>) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = FixedBytes::<N>::VALID_LENGTH;
+        AbiValue::FixedBytes(FixedBytesValue(value.0.to_vec()))
+    }
+}
+
+impl From<bool> for AbiValue {
+    fn from(value: bool) -> Self {
+        AbiValue::Bool(value)
+    }
+}
+
+impl From<String> for AbiValue {
+    fn from(value: String) -> Self {
+        AbiValue::String(value)
+    }
+}
+
+impl From<&str> for AbiValue {
+    fn from(value: &str) -> Self {
+        AbiValue::String(value.to_string())
+    }
+}
@@ -0,0 +1,267 @@
+//! ABI Helper Functions
+//!
+//! This module implements Solidity ABI encoding for [`AbiValue`] trees and the
+//! Keccak-256 based function selector derivation.
+
+use sha3::{Digest, Keccak256};
+
+use super::dto::AbiValue;
+
+/// Compute the Keccak-256 hash of the input
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::abi::keccak256;
+///
+/// let hash = keccak256(b"");
+/// assert_eq!(
+///     hex::encode(hash),
+///     "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+/// );
+/// ```
+pub fn keccak256(data: impl AsRef<[u8]>) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(data.as_ref());
+    hasher.finalize().into()
+}
+
+/// Compute the 4-byte function selector for a function signature
+///
+/// The signature must be in canonical form without spaces or parameter names,
+/// e.g. `"transfer(address,uint256)"`.
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::abi::function_selector;
+///
+/// assert_eq!(function_selector("transfer(address,uint256)"), [0xa9, 0x05, 0x9c, 0xbb]);
+/// ```
+pub fn function_selector(signature: &str) -> [u8; 4] {
+    let hash = keccak256(signature.as_bytes());
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+/// ABI-encode a list of values as a tuple
+///
+/// This produces the same bytes as Solidity's `abi.encode(...)`.
+///
+/// # Arguments
+/// * `values` - The values to encode, in parameter order
+///
+/// # Returns
+/// * `Vec<u8>` - The encoded bytes
+pub fn encode(values: &[AbiValue]) -> Vec<u8> {
+    encode_sequence(values)
+}
+
+/// ABI-encode a function call into `0x`-prefixed call data
+///
+/// The result can be passed to
+/// [`CreateContractExecutionTransactionRequestBuilder::call_data`](crate::dev_wallet::ops::create_contract_transaction::CreateContractExecutionTransactionRequestBuilder::call_data).
+///
+/// # Arguments
+/// * `signature` - Canonical function signature (e.g., "transfer(address,uint256)")
+/// * `values` - The function arguments
+///
+/// # Returns
+/// * `String` - Hex-encoded call data with 0x prefix
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::abi::{encode_function_call, dto::{AbiValue, Address, U256}};
+///
+/// let to: Address = "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb0".parse().unwrap();
+/// let call_data = encode_function_call(
+///     "transfer(address,uint256)",
+///     &[AbiValue::from(to), AbiValue::from(U256::from(1_000_000u64))],
+/// );
+/// assert!(call_data.starts_with("0xa9059cbb"));
+/// ```
+pub fn encode_function_call(signature: &str, values: &[AbiValue]) -> String {
+    let mut data = function_selector(signature).to_vec();
+    data.extend(encode(values));
+    format!("0x{}", hex::encode(data))
+}
+
+/// Encode a sequence of values using the head/tail layout shared by tuples and arrays
+fn encode_sequence(values: &[AbiValue]) -> Vec<u8> {
+    let head_size: usize = values.iter().map(head_len).sum();
+
+    let mut head = Vec::with_capacity(head_size);
+    let mut tail = Vec::new();
+
+    for value in values {
+        if value.is_dynamic() {
+            head.extend_from_slice(&usize_word(head_size + tail.len()));
+            tail.extend(encode_value(value));
+        } else {
+            head.extend(encode_value(value));
+        }
+    }
+
+    head.extend(tail);
+    head
+}
+
+/// Size a value occupies in the head of its enclosing sequence
+fn head_len(value: &AbiValue) -> usize {
+    if value.is_dynamic() {
+        return 32;
+    }
+    match value {
+        AbiValue::FixedArray(values) | AbiValue::Tuple(values) => values.iter().map(head_len).sum(),
+        _ => 32,
+    }
+}
+
+fn encode_value(value: &AbiValue) -> Vec<u8> {
+    match value {
+        AbiValue::Address(address) => {
+            let mut word = [0u8; 32];
+            word[12..].copy_from_slice(address.as_bytes());
+            word.to_vec()
+        }
+        AbiValue::Uint(value) | AbiValue::Int(value) => value.to_be_bytes().to_vec(),
+        AbiValue::Bool(value) => usize_word(*value as usize).to_vec(),
+        AbiValue::FixedBytes(bytes) => pad_right(bytes),
+        AbiValue::Bytes(bytes) => {
+            let mut out = usize_word(bytes.len()).to_vec();
+            out.extend(pad_right(bytes));
+            out
+        }
+        AbiValue::String(s) => {
+            let mut out = usize_word(s.len()).to_vec();
+            out.extend(pad_right(s.as_bytes()));
+            out
+        }
+        AbiValue::Array(values) => {
+            let mut out = usize_word(values.len()).to_vec();
+            out.extend(encode_sequence(values));
+            out
+        }
+        AbiValue::FixedArray(values) | AbiValue::Tuple(values) => encode_sequence(values),
+    }
+}
+
+fn usize_word(value: usize) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&(value as u64).to_be_bytes());
+    word
+}
+
+fn pad_right(bytes: &[u8]) -> Vec<u8> {
+    let mut out = bytes.to_vec();
+    let padded_len = bytes.len().div_ceil(32) * 32;
+    out.resize(padded_len, 0);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::dto::{Address, U256};
+
+    #[test]
+    fn test_u256_round_trip() {
+        let max = U256::MAX.to_string();
+        assert_eq!(
+            max,
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+        );
+        assert_eq!(max.parse::<U256>().unwrap(), U256::MAX);
+        assert_eq!("0xff".parse::<U256>().unwrap(), U256::from(255u64));
+        assert!(format!("{}0", max).parse::<U256>().is_err());
+        assert_eq!(U256::ZERO.to_string(), "0");
+    }
+
+    #[test]
+    fn test_signed_int_rendering() {
+        let AbiValue::Int(word) = AbiValue::from(-42i64) else {
+            panic!("expected int");
+        };
+        assert_eq!(word.to_signed_string(), "-42");
+    }
+
+    #[test]
+    fn test_encode_transfer_call() {
+        let to: Address = "0x742d35cc6634c0532925a3b844bc9e7595f0beb0"
+            .parse()
+            .unwrap();
+        let data = encode_function_call(
+            "transfer(address,uint256)",
+            &[AbiValue::from(to), AbiValue::from(1_000_000u64)],
+        );
+
+        assert_eq!(
+            data,
+            "0xa9059cbb\
+             000000000000000000000000742d35cc6634c0532925a3b844bc9e7595f0beb0\
+             00000000000000000000000000000000000000000000000000000000000f4240"
+        );
+    }
+
+    #[test]
+    fn test_encode_dynamic_values() {
+        // abi.encode(uint256(1), "abc", uint256[](2, 3))
+        let encoded = encode(&[
+            AbiValue::from(1u64),
+            AbiValue::from("abc"),
+            AbiValue::array(vec![AbiValue::from(2u64), AbiValue::from(3u64)]),
+        ]);
+
+        let expected = [
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "0000000000000000000000000000000000000000000000000000000000000060",
+            "00000000000000000000000000000000000000000000000000000000000000a0",
+            "0000000000000000000000000000000000000000000000000000000000000003",
+            "6162630000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000002",
+            "0000000000000000000000000000000000000000000000000000000000000002",
+            "0000000000000000000000000000000000000000000000000000000000000003",
+        ]
+        .concat();
+        assert_eq!(hex::encode(encoded), expected);
+    }
+
+    #[test]
+    fn test_static_tuple_is_inlined() {
+        // abi.encode((uint256(1), true), bytes2(0x1234))
+        let encoded = encode(&[
+            AbiValue::tuple(vec![AbiValue::from(1u64), AbiValue::from(true)]),
+            AbiValue::fixed_bytes(vec![0x12, 0x34]).unwrap(),
+        ]);
+
+        assert_eq!(encoded.len(), 96);
+        assert_eq!(encoded[63], 1);
+        assert_eq!(&encoded[64..66], &[0x12, 0x34]);
+    }
+
+    #[test]
+    fn test_fixed_bytes_length_is_checked() {
+        assert!(AbiValue::fixed_bytes(Vec::new()).is_err());
+        assert!(AbiValue::fixed_bytes(vec![0u8; 33]).is_err());
+
+        let value = AbiValue::from(crate::abi::dto::FixedBytes([0xabu8; 32]));
+        assert_eq!(value.type_name(), "bytes32");
+        assert_eq!(encode(&[value]), vec![0xabu8; 32]);
+    }
+
+    #[test]
+    fn test_type_name() {
+        let value = AbiValue::fixed_array(vec![
+            AbiValue::tuple(vec![
+                AbiValue::from(Address::default()),
+                AbiValue::bytes(vec![1u8]),
+            ]),
+            AbiValue::tuple(vec![
+                AbiValue::from(Address::default()),
+                AbiValue::bytes(vec![2u8]),
+            ]),
+        ]);
+        assert_eq!(value.type_name(), "(address,bytes)[2]");
+        assert!(value.is_dynamic());
+    }
+}
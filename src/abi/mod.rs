@@ -0,0 +1,71 @@
+//! Solidity ABI Support
+//!
+//! This module provides typed Solidity ABI values and a self-contained encoder
+//! and decoder, so contract calls can be built from `U256`, `Address`, bytes,
+//! tuples and fixed arrays without loss of precision, and query results can be
+//! decoded back into Rust types.
+//!
+//! # Main Components
+//!
+//! - [`dto`]: Typed ABI values ([`U256`], [`Address`], [`AbiValue`])
+//! - [`handler`]: Encoding functions and Keccak-256 helpers
+//! - [`decode`]: The [`AbiDecode`] trait for decoding return data, and [`decode_values`] for types only known at runtime
+//!
+//! # Example - Encode Call Data
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::abi::{encode_function_call, AbiValue, Address, U256};
+//! use inf_circle_sdk::dev_wallet::ops::create_contract_transaction::CreateContractExecutionTransactionRequestBuilder;
+//!
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let to: Address = "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb0".parse()?;
+//! let amount: U256 = "1000000000000000000000000000000".parse()?;
+//!
+//! let call_data = encode_function_call(
+//!     "transfer(address,uint256)",
+//!     &[AbiValue::from(to), AbiValue::from(amount)],
+//! );
+//!
+//! let builder = CreateContractExecutionTransactionRequestBuilder::new(
+//!     "wallet-id".to_string(),
+//!     "0xContractAddress".to_string(),
+//! )
+//! .call_data(call_data)
+//! .build();
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Example - Decode Query Output
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::abi::U256;
+//! use inf_circle_sdk::circle_view::circle_view::CircleView;
+//! use inf_circle_sdk::contract::views::query_contract_view::QueryContractViewBodyBuilder;
+//! use inf_circle_sdk::types::Blockchain;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let view = CircleView::new()?;
+//! let builder = QueryContractViewBodyBuilder::new(
+//!     Blockchain::EthSepolia,
+//!     "0x1c7D4B196Cb0C7B01d743Fbc6116a902379C7238".to_string(),
+//! )
+//! .abi_function_signature("totalSupply()".to_string());
+//!
+//! let response = view.query_contract(builder).await?;
+//! let total_supply: U256 = response.decode_output()?;
+//! println!("Total supply: {}", total_supply);
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod decode;
+pub mod dto;
+pub mod handler;
+
+// Re-export commonly used items
+pub use decode::{
+    check_value, decode, decode_hex, decode_values, decode_word, AbiDecode, TupleDecoder,
+};
+pub use dto::{AbiValue, Address, Bytes, FixedBytes, FixedBytesValue, U256};
+pub use handler::{encode, encode_function_call, function_selector, keccak256};
@@ -0,0 +1,167 @@
+//! Account Snapshot Data Structures
+//!
+//! Slim, stable records of the Circle resources an operator needs to rebuild
+//! or audit an account. Fields that change on their own (update dates,
+//! balances) are left out so two snapshots of an unchanged account compare
+//! equal apart from `exported_at`.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::contract::dto::{Contract, EventMonitor, NotificationSubscription};
+use crate::dev_wallet::dto::DevWallet;
+use crate::types::Blockchain;
+
+/// Snapshot format version, bumped on incompatible changes
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// Point-in-time inventory of a Circle account
+///
+/// Every list is sorted by ID.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountSnapshot {
+    /// Snapshot format version
+    pub version: u32,
+    /// When the snapshot was taken
+    pub exported_at: DateTime<Utc>,
+    /// Wallet sets that own at least one wallet
+    pub wallet_sets: Vec<WalletSetRecord>,
+    pub wallets: Vec<WalletRecord>,
+    pub contracts: Vec<ContractRecord>,
+    pub event_monitors: Vec<EventMonitorRecord>,
+    pub subscriptions: Vec<SubscriptionRecord>,
+}
+
+/// A wallet set and the number of wallets in it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletSetRecord {
+    pub id: String,
+    pub wallet_count: usize,
+}
+
+/// A developer-controlled wallet
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletRecord {
+    pub id: String,
+    pub wallet_set_id: String,
+    pub address: String,
+    pub blockchain: Blockchain,
+    pub state: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ref_id: Option<String>,
+}
+
+impl From<&DevWallet> for WalletRecord {
+    fn from(wallet: &DevWallet) -> Self {
+        Self {
+            id: wallet.id.clone(),
+            wallet_set_id: wallet.wallet_set_id.clone(),
+            address: wallet.address.clone(),
+            blockchain: wallet.blockchain.clone(),
+            state: wallet.state.clone(),
+            name: wallet.name.clone(),
+            ref_id: wallet.ref_id.clone(),
+        }
+    }
+}
+
+/// A deployed or imported contract
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractRecord {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blockchain: Option<Blockchain>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ref_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+impl From<&Contract> for ContractRecord {
+    fn from(contract: &Contract) -> Self {
+        Self {
+            id: contract.id.clone().unwrap_or_default(),
+            address: contract
+                .contract_address
+                .clone()
+                .or_else(|| contract.address.clone()),
+            blockchain: contract.blockchain.clone(),
+            name: contract.name.clone(),
+            ref_id: contract.ref_id.clone(),
+            status: contract.status.clone(),
+        }
+    }
+}
+
+/// A contract event monitor
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventMonitorRecord {
+    pub id: String,
+    pub blockchain: Blockchain,
+    pub contract_address: String,
+    pub event_signature: String,
+    pub is_enabled: bool,
+}
+
+impl From<&EventMonitor> for EventMonitorRecord {
+    fn from(monitor: &EventMonitor) -> Self {
+        Self {
+            id: monitor.id.clone(),
+            blockchain: monitor.blockchain.clone(),
+            contract_address: monitor.contract_address.clone(),
+            event_signature: monitor.event_signature.clone(),
+            is_enabled: monitor.is_enabled,
+        }
+    }
+}
+
+/// A webhook notification subscription
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriptionRecord {
+    pub id: String,
+    pub name: String,
+    pub endpoint: String,
+    pub enabled: bool,
+    /// Subscribed notification types, e.g. `transactions.outbound`
+    pub notification_types: Vec<String>,
+}
+
+impl From<&NotificationSubscription> for SubscriptionRecord {
+    fn from(subscription: &NotificationSubscription) -> Self {
+        Self {
+            id: subscription.id.clone(),
+            name: subscription.name.clone(),
+            endpoint: subscription.endpoint.clone(),
+            enabled: subscription.enabled,
+            notification_types: subscription
+                .notification_types
+                .iter()
+                .map(|kind| kind.as_str().to_string())
+                .collect(),
+        }
+    }
+}
+
+/// An [`AccountSnapshot`] with an HMAC-SHA256 signature
+///
+/// The signature covers the compact JSON encoding of `snapshot`, so the
+/// artifact can be stored as pretty-printed JSON and still verified.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignedAccountSnapshot {
+    pub snapshot: AccountSnapshot,
+    /// Hex-encoded HMAC-SHA256 of the snapshot
+    pub signature: String,
+}
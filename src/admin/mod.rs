@@ -0,0 +1,282 @@
+//! Account Administration
+//!
+//! This module exports the state of a Circle account (wallet sets, wallets,
+//! contracts, event monitors and webhook subscriptions) as a signed JSON
+//! snapshot. The snapshot is a disaster-recovery artifact: store it offsite,
+//! verify its signature before trusting it, and compare it with a fresh export
+//! to see what changed on the live account.
+//!
+//! # Main Components
+//!
+//! - [`export_account_state`]: Read the account and produce a [`SignedAccountSnapshot`]
+//! - [`dto`]: The snapshot and its per-resource records
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::admin::export_account_state;
+//! use inf_circle_sdk::circle_view::circle_view::CircleView;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let view = CircleView::new()?;
+//! let signing_key = std::env::var("SNAPSHOT_SIGNING_KEY")?;
+//!
+//! let signed = export_account_state(&view, signing_key.as_bytes()).await?;
+//! std::fs::write("circle-account.json", serde_json::to_string_pretty(&signed)?)?;
+//!
+//! // Later, before relying on a stored snapshot
+//! let stored: inf_circle_sdk::admin::SignedAccountSnapshot =
+//!     serde_json::from_str(&std::fs::read_to_string("circle-account.json")?)?;
+//! assert!(stored.verify(signing_key.as_bytes()));
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod dto;
+
+use std::collections::BTreeMap;
+
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::circle_view::circle_view::CircleView;
+use crate::contract::dto::{ListContractsParams, ListEventMonitorsParams};
+use crate::dev_wallet::views::list_wallets::ListDevWalletsParamsBuilder;
+use crate::helper::{CircleError, CircleResult, PaginationParams};
+
+pub use dto::{
+    AccountSnapshot, ContractRecord, EventMonitorRecord, SignedAccountSnapshot, SubscriptionRecord,
+    WalletRecord, WalletSetRecord, SNAPSHOT_VERSION,
+};
+
+/// Page size used when listing resources
+const PAGE_SIZE: u32 = 50;
+
+/// Export a signed snapshot of the account `view` reads from
+///
+/// Lists every wallet, contract, event monitor and notification subscription,
+/// following pagination to the end. Wallet sets are derived from the wallets.
+///
+/// # Arguments
+///
+/// * `view` - Client used for the read requests
+/// * `signing_key` - Secret key for the HMAC-SHA256 signature
+///
+/// # Errors
+///
+/// Returns an error if any list request fails.
+pub async fn export_account_state(
+    view: &CircleView,
+    signing_key: &[u8],
+) -> CircleResult<SignedAccountSnapshot> {
+    let mut wallets = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let mut params = ListDevWalletsParamsBuilder::new().page_size(PAGE_SIZE);
+        if let Some(cursor) = cursor.take() {
+            params = params.page_after(cursor);
+        }
+
+        let page = view.list_wallets(params.build()).await?.items;
+        wallets.extend(page.iter().map(WalletRecord::from));
+        match page.last() {
+            Some(last) if page.len() as u32 == PAGE_SIZE => cursor = Some(last.id.clone()),
+            _ => break,
+        }
+    }
+
+    let mut contracts = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let params = ListContractsParams {
+            pagination: page_params(cursor.take()),
+            ..Default::default()
+        };
+
+        let page = view.list_contracts(Some(params)).await?.items;
+        contracts.extend(page.iter().map(ContractRecord::from));
+        match page.last().and_then(|last| last.id.clone()) {
+            Some(last_id) if page.len() as u32 == PAGE_SIZE => cursor = Some(last_id),
+            _ => break,
+        }
+    }
+
+    let mut event_monitors = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let params = ListEventMonitorsParams {
+            pagination: page_params(cursor.take()),
+            ..Default::default()
+        };
+
+        let page = view.list_event_monitors(Some(params)).await?.items;
+        event_monitors.extend(page.iter().map(EventMonitorRecord::from));
+        match page.last() {
+            Some(last) if page.len() as u32 == PAGE_SIZE => cursor = Some(last.id.clone()),
+            _ => break,
+        }
+    }
+
+    let subscriptions = view
+        .list_notification_subscriptions()
+        .await?
+        .iter()
+        .map(SubscriptionRecord::from)
+        .collect();
+
+    let mut wallet_counts: BTreeMap<String, usize> = BTreeMap::new();
+    for wallet in &wallets {
+        *wallet_counts
+            .entry(wallet.wallet_set_id.clone())
+            .or_default() += 1;
+    }
+
+    let mut snapshot = AccountSnapshot {
+        version: SNAPSHOT_VERSION,
+        exported_at: Utc::now(),
+        wallet_sets: wallet_counts
+            .into_iter()
+            .map(|(id, wallet_count)| WalletSetRecord { id, wallet_count })
+            .collect(),
+        wallets,
+        contracts,
+        event_monitors,
+        subscriptions,
+    };
+    snapshot.wallets.sort_by(|a, b| a.id.cmp(&b.id));
+    snapshot.contracts.sort_by(|a, b| a.id.cmp(&b.id));
+    snapshot.event_monitors.sort_by(|a, b| a.id.cmp(&b.id));
+    snapshot.subscriptions.sort_by(|a, b| a.id.cmp(&b.id));
+
+    snapshot.sign(signing_key)
+}
+
+fn page_params(cursor: Option<String>) -> PaginationParams {
+    PaginationParams {
+        page_after: cursor,
+        page_before: None,
+        page_size: Some(PAGE_SIZE),
+    }
+}
+
+fn snapshot_mac(snapshot: &AccountSnapshot, key: &[u8]) -> CircleResult<Hmac<Sha256>> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key)
+        .map_err(|e| CircleError::Config(format!("Invalid snapshot signing key: {}", e)))?;
+    mac.update(&serde_json::to_vec(snapshot)?);
+    Ok(mac)
+}
+
+impl AccountSnapshot {
+    /// Sign the snapshot with HMAC-SHA256 under `key`
+    pub fn sign(self, key: &[u8]) -> CircleResult<SignedAccountSnapshot> {
+        let signature = hex::encode(snapshot_mac(&self, key)?.finalize().into_bytes());
+        Ok(SignedAccountSnapshot {
+            snapshot: self,
+            signature,
+        })
+    }
+}
+
+impl SignedAccountSnapshot {
+    /// Check that the snapshot was signed with `key` and not modified since
+    pub fn verify(&self, key: &[u8]) -> bool {
+        let Ok(signature) = hex::decode(&self.signature) else {
+            return false;
+        };
+        snapshot_mac(&self.snapshot, key)
+            .map(|mac| mac.verify_slice(&signature).is_ok())
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{fixtures, MockCircle};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_export_account_state() {
+        let mut circle = MockCircle::start().await;
+        let mut second = fixtures::dev_wallet();
+        second.id = "00000000-0000-0000-0000-000000000000".to_string();
+        second.ref_id = Some("treasury".to_string());
+        circle
+            .mock_wallets(&[fixtures::dev_wallet(), second.clone()])
+            .await;
+        circle
+            .respond(
+                "GET",
+                "/v1/w3s/contracts",
+                &json!({ "contracts": [fixtures::contract()] }),
+            )
+            .await;
+        circle
+            .respond(
+                "GET",
+                "/v1/w3s/contracts/monitors",
+                &json!({ "eventMonitors": [fixtures::event_monitor()] }),
+            )
+            .await;
+        circle
+            .respond(
+                "GET",
+                "/v2/notifications/subscriptions",
+                &[fixtures::notification_subscription()],
+            )
+            .await;
+
+        let signed = export_account_state(&circle.view(), b"offsite-key")
+            .await
+            .unwrap();
+        let snapshot = &signed.snapshot;
+
+        assert_eq!(snapshot.version, SNAPSHOT_VERSION);
+        assert_eq!(
+            snapshot.wallet_sets,
+            vec![WalletSetRecord {
+                id: fixtures::WALLET_SET_ID.to_string(),
+                wallet_count: 2,
+            }]
+        );
+        assert_eq!(snapshot.wallets[0].id, second.id);
+        assert_eq!(snapshot.wallets[0].ref_id.as_deref(), Some("treasury"));
+        assert_eq!(snapshot.wallets[1].address, fixtures::WALLET_ADDRESS);
+        assert_eq!(snapshot.contracts[0].id, fixtures::CONTRACT_ID);
+        assert_eq!(snapshot.event_monitors.len(), 1);
+        assert_eq!(snapshot.subscriptions.len(), 1);
+
+        assert!(signed.verify(b"offsite-key"));
+        assert!(!signed.verify(b"wrong-key"));
+    }
+
+    #[test]
+    fn test_verify_detects_tampering() {
+        let snapshot = AccountSnapshot {
+            version: SNAPSHOT_VERSION,
+            exported_at: Utc::now(),
+            wallet_sets: Vec::new(),
+            wallets: Vec::new(),
+            contracts: Vec::new(),
+            event_monitors: Vec::new(),
+            subscriptions: Vec::new(),
+        };
+        let signed = snapshot.sign(b"key").unwrap();
+
+        let round_trip: SignedAccountSnapshot =
+            serde_json::from_str(&serde_json::to_string_pretty(&signed).unwrap()).unwrap();
+        assert!(round_trip.verify(b"key"));
+
+        let mut tampered = round_trip.clone();
+        tampered.snapshot.wallet_sets.push(WalletSetRecord {
+            id: "injected".to_string(),
+            wallet_count: 1,
+        });
+        assert!(!tampered.verify(b"key"));
+
+        let mut garbled = round_trip;
+        garbled.signature = "not hex".to_string();
+        assert!(!garbled.verify(b"key"));
+    }
+}
@@ -0,0 +1,202 @@
+//! Aptos Data Transfer Objects
+//!
+//! This module contains the data structures used for Aptos operations,
+//! including network identifiers, addresses, public keys and REST API
+//! response types.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha3::{Digest, Sha3_256};
+
+use crate::helper::CircleError;
+
+/// Aptos network identifier
+///
+/// `Custom` points at any fullnode REST endpoint (including the `/v1` suffix).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AptosNetwork {
+    Mainnet,
+    Testnet,
+    Custom(String),
+}
+
+impl AptosNetwork {
+    /// Get the fullnode REST API URL for this network
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use inf_circle_sdk::aptos::dto::AptosNetwork;
+    ///
+    /// assert_eq!(AptosNetwork::Mainnet.rest_url(), "https://fullnode.mainnet.aptoslabs.com/v1");
+    /// assert_eq!(AptosNetwork::Testnet.rest_url(), "https://fullnode.testnet.aptoslabs.com/v1");
+    /// ```
+    pub fn rest_url(&self) -> &str {
+        match self {
+            AptosNetwork::Mainnet => "https://fullnode.mainnet.aptoslabs.com/v1",
+            AptosNetwork::Testnet => "https://fullnode.testnet.aptoslabs.com/v1",
+            AptosNetwork::Custom(url) => url.trim_end_matches('/'),
+        }
+    }
+
+    /// Chain ID transactions on this network must carry
+    ///
+    /// Returns `None` for custom networks, whose chain ID must be set explicitly.
+    pub fn chain_id(&self) -> Option<u8> {
+        match self {
+            AptosNetwork::Mainnet => Some(1),
+            AptosNetwork::Testnet => Some(2),
+            AptosNetwork::Custom(_) => None,
+        }
+    }
+}
+
+/// 32-byte Aptos account address
+///
+/// Parses both the long form and the short form used for framework
+/// addresses (`0x1`), and always displays the long form.
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::aptos::dto::AptosAddress;
+///
+/// let framework: AptosAddress = "0x1".parse().unwrap();
+/// assert_eq!(
+///     framework.to_string(),
+///     "0x0000000000000000000000000000000000000000000000000000000000000001"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct AptosAddress([u8; 32]);
+
+impl AptosAddress {
+    /// Create an address from its raw bytes
+    pub fn from_bytes(bytes: [u8; 32]) -> Self {
+        AptosAddress(bytes)
+    }
+
+    /// Return the raw bytes of this address
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl FromStr for AptosAddress {
+    type Err = CircleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s.strip_prefix("0x").unwrap_or(s);
+        if digits.is_empty() || digits.len() > 64 {
+            return Err(CircleError::Config(format!("Invalid Aptos address: {}", s)));
+        }
+
+        let padded = format!("{:0>64}", digits);
+        let decoded = hex::decode(padded)
+            .map_err(|e| CircleError::Config(format!("Invalid Aptos address {}: {}", s, e)))?;
+
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(&decoded);
+        Ok(AptosAddress(bytes))
+    }
+}
+
+impl fmt::Display for AptosAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{}", hex::encode(self.0))
+    }
+}
+
+/// Ed25519 public key of an Aptos wallet
+///
+/// Circle returns Aptos wallet public keys as `0x`-prefixed hex in
+/// `initialPublicKey`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AptosPublicKey([u8; 32]);
+
+impl AptosPublicKey {
+    /// Return the raw bytes of this public key
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Authentication key of a single-signer Ed25519 account
+    ///
+    /// `sha3_256(public_key ++ 0x00)`, where `0x00` is the Ed25519 scheme.
+    pub fn authentication_key(&self) -> [u8; 32] {
+        let mut hasher = Sha3_256::new();
+        hasher.update(self.0);
+        hasher.update([0x00]);
+        hasher.finalize().into()
+    }
+
+    /// Address of an account created from this key (its authentication key)
+    ///
+    /// Accounts that rotated their key keep their original address, so prefer
+    /// the wallet address Circle reports when it is available.
+    pub fn address(&self) -> AptosAddress {
+        AptosAddress(self.authentication_key())
+    }
+}
+
+impl FromStr for AptosPublicKey {
+    type Err = CircleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s.strip_prefix("0x").unwrap_or(s);
+        let decoded = hex::decode(digits)
+            .map_err(|e| CircleError::Config(format!("Invalid Aptos public key {}: {}", s, e)))?;
+        let bytes: [u8; 32] = decoded.try_into().map_err(|bytes: Vec<u8>| {
+            CircleError::Config(format!(
+                "Invalid Aptos public key {}: expected 32 bytes, got {}",
+                s,
+                bytes.len()
+            ))
+        })?;
+        Ok(AptosPublicKey(bytes))
+    }
+}
+
+impl fmt::Display for AptosPublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{}", hex::encode(self.0))
+    }
+}
+
+/// Account information from `GET /accounts/{address}`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AptosAccount {
+    /// Next sequence number to use for a transaction from this account
+    #[serde(with = "u64_string")]
+    pub sequence_number: u64,
+    /// Current authentication key
+    pub authentication_key: String,
+}
+
+/// A Move resource stored under an account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AptosAccountResource {
+    /// Fully qualified resource type, e.g. `0x1::account::Account`
+    #[serde(rename = "type")]
+    pub resource_type: String,
+    /// Resource contents as returned by the REST API
+    pub data: Value,
+}
+
+/// The REST API encodes u64 values as decimal strings
+mod u64_string {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&value.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,209 @@
+//! Aptos Helper Functions
+//!
+//! This module provides utility functions for working with Aptos, including
+//! account and resource queries over the fullnode REST API and public key
+//! parsing.
+
+use reqwest::Response;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+
+use crate::helper::{CircleError, CircleResult};
+
+use super::dto::{AptosAccount, AptosAccountResource, AptosAddress, AptosNetwork, AptosPublicKey};
+
+/// Decode a REST API response, surfacing the API's error message on failure
+async fn decode<T: DeserializeOwned>(response: Response) -> CircleResult<T> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response.json().await?);
+    }
+
+    let body: Value = response.json().await.unwrap_or_default();
+    Err(CircleError::Api {
+        status: status.as_u16(),
+        request_id: None,
+        message: format!(
+            "Aptos REST error: {}",
+            body["message"].as_str().unwrap_or("unknown error")
+        ),
+    })
+}
+
+/// Get an account's sequence number and authentication key
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use inf_circle_sdk::aptos::{get_aptos_account, dto::AptosNetwork};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let account = get_aptos_account("0x1", &AptosNetwork::Testnet).await?;
+/// println!("Sequence number: {}", account.sequence_number);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_aptos_account(
+    address: &str,
+    network: &AptosNetwork,
+) -> CircleResult<AptosAccount> {
+    let address: AptosAddress = address.parse()?;
+    let url = format!("{}/accounts/{}", network.rest_url(), address);
+    decode(reqwest::get(url).await?).await
+}
+
+/// List all Move resources stored under an account
+pub async fn get_aptos_account_resources(
+    address: &str,
+    network: &AptosNetwork,
+) -> CircleResult<Vec<AptosAccountResource>> {
+    let address: AptosAddress = address.parse()?;
+    let url = format!("{}/accounts/{}/resources", network.rest_url(), address);
+    decode(reqwest::get(url).await?).await
+}
+
+/// Get a single Move resource stored under an account
+///
+/// # Arguments
+/// * `address` - Account address
+/// * `resource_type` - Fully qualified type, e.g. `"0x1::account::Account"`
+/// * `network` - The Aptos network to query
+pub async fn get_aptos_account_resource(
+    address: &str,
+    resource_type: &str,
+    network: &AptosNetwork,
+) -> CircleResult<AptosAccountResource> {
+    let address: AptosAddress = address.parse()?;
+    let url = format!(
+        "{}/accounts/{}/resource/{}",
+        network.rest_url(),
+        address,
+        urlencoding::encode(resource_type)
+    );
+    decode(reqwest::get(url).await?).await
+}
+
+/// Get an account's balance of `coin_type` in base units
+///
+/// Calls the `0x1::coin::balance` view function, which covers both coin
+/// stores and migrated fungible asset stores.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use inf_circle_sdk::aptos::{get_aptos_coin_balance, dto::AptosNetwork};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let octas = get_aptos_coin_balance(
+///     "0x1",
+///     "0x1::aptos_coin::AptosCoin",
+///     &AptosNetwork::Testnet,
+/// ).await?;
+/// println!("Balance: {} octas", octas);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_aptos_coin_balance(
+    address: &str,
+    coin_type: &str,
+    network: &AptosNetwork,
+) -> CircleResult<String> {
+    let address: AptosAddress = address.parse()?;
+    let body = json!({
+        "function": "0x1::coin::balance",
+        "type_arguments": [coin_type],
+        "arguments": [address.to_string()],
+    });
+
+    let response = reqwest::Client::new()
+        .post(format!("{}/view", network.rest_url()))
+        .json(&body)
+        .send()
+        .await?;
+    let values: Vec<Value> = decode(response).await?;
+
+    values
+        .first()
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| CircleError::Api {
+            status: 500,
+            request_id: None,
+            message: "Unexpected coin::balance response from Aptos REST API".to_string(),
+        })
+}
+
+/// Parse an Aptos public key string into an [`AptosPublicKey`]
+///
+/// Accepts the hex format Circle returns (with or without `0x`).
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::aptos::parse_aptos_public_key;
+///
+/// let key = parse_aptos_public_key(
+///     "0x0000000000000000000000000000000000000000000000000000000000000001",
+/// ).unwrap();
+/// println!("Account address: {}", key.address());
+/// ```
+pub fn parse_aptos_public_key(public_key: &str) -> CircleResult<AptosPublicKey> {
+    public_key.trim().parse()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha3::{Digest, Sha3_256};
+
+    #[test]
+    fn test_parse_aptos_public_key() {
+        let hex_key = format!("0x{}", "ab".repeat(32));
+        let key = parse_aptos_public_key(&hex_key).unwrap();
+        assert_eq!(key.to_string(), hex_key);
+
+        let mut preimage = vec![0xab; 32];
+        preimage.push(0x00);
+        let expected: [u8; 32] = Sha3_256::digest(&preimage).into();
+        assert_eq!(key.address().as_bytes(), &expected);
+
+        assert!(parse_aptos_public_key("0xabcd").is_err());
+        assert!(parse_aptos_public_key("not hex").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_aptos_account_and_errors() {
+        let mut server = mockito::Server::new_async().await;
+        let address = format!("0x{}", "0".repeat(63) + "1");
+        server
+            .mock("GET", format!("/accounts/{}", address).as_str())
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"sequence_number":"42","authentication_key":"0x01"}"#)
+            .create_async()
+            .await;
+        server
+            .mock(
+                "GET",
+                "/accounts/0x00000000000000000000000000000000000000000000000000000000000000ff",
+            )
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"message":"Account not found","error_code":"account_not_found"}"#)
+            .create_async()
+            .await;
+
+        let network = AptosNetwork::Custom(format!("{}/", server.url()));
+        let account = get_aptos_account("0x1", &network).await.unwrap();
+        assert_eq!(account.sequence_number, 42);
+
+        match get_aptos_account("0xff", &network).await {
+            Err(CircleError::Api {
+                status, message, ..
+            }) => {
+                assert_eq!(status, 404);
+                assert!(message.contains("Account not found"));
+            }
+            other => panic!("expected API error, got {:?}", other.map(|_| ())),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+//! Aptos Support
+//!
+//! This module provides building blocks for Aptos wallets: BCS-serialized
+//! transaction payloads (entry function calls, coin transfers) for Circle's
+//! sign transaction endpoint, public key parsing, and account and resource
+//! queries over the Aptos REST API.
+//!
+//! # Main Components
+//!
+//! - [`dto`]: Data transfer objects (network identifiers, addresses, public keys, REST types)
+//! - [`handler`]: REST helpers for accounts, resources and coin balances
+//! - [`transaction`]: Entry function payloads and `RawTransaction` serialization
+//!
+//! # Example - Get Coin Balance
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::aptos::{get_aptos_coin_balance, dto::AptosNetwork};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let octas = get_aptos_coin_balance(
+//!     "0x1",
+//!     "0x1::aptos_coin::AptosCoin",
+//!     &AptosNetwork::Testnet,
+//! ).await?;
+//! println!("Balance: {} octas", octas);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Example - Sign a Coin Transfer
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::{
+//!     aptos::{coin_transfer, dto::AptosNetwork, get_aptos_account, AptosTransactionBuilder},
+//!     circle_ops::circler_ops::CircleOps,
+//! };
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let ops = CircleOps::new(None)?;
+//! let network = AptosNetwork::Testnet;
+//! let wallet_address = "0x1234";
+//! let account = get_aptos_account(wallet_address, &network).await?;
+//!
+//! let payload = coin_transfer("0x1::aptos_coin::AptosCoin", "0xcafe".parse()?, 1_000_000)?;
+//! let request = AptosTransactionBuilder::new(wallet_address.parse()?, account.sequence_number, payload)
+//!     .network(&network)
+//!     .into_sign_request("wallet-id".to_string())?;
+//!
+//! let response = ops.dev_sign_transaction(request).await?;
+//! println!("Signature: {}", response.signature);
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod dto;
+pub mod handler;
+pub mod transaction;
+
+// Re-export commonly used items
+pub use dto::{AptosAccount, AptosAccountResource, AptosAddress, AptosNetwork, AptosPublicKey};
+pub use handler::{
+    get_aptos_account, get_aptos_account_resource, get_aptos_account_resources,
+    get_aptos_coin_balance, parse_aptos_public_key,
+};
+pub use transaction::{apt_transfer, coin_transfer, AptosEntryFunction, AptosTransactionBuilder};
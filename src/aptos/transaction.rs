@@ -0,0 +1,582 @@
+//! Aptos Transaction Building
+//!
+//! This module builds BCS-serialized Aptos `RawTransaction`s with entry
+//! function payloads, ready for Circle's sign transaction endpoint. Only the
+//! subset of BCS needed for entry function calls is implemented.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{
+    dev_wallet::ops::sign_transaction::SignTransactionRequestBuilder,
+    helper::{CircleError, CircleResult},
+};
+
+use super::dto::{AptosAddress, AptosNetwork};
+
+/// Move type argument of an entry function (`TypeTag`)
+///
+/// Parsed from the same notation the Aptos REST API uses.
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::aptos::transaction::TypeTag;
+///
+/// let coin: TypeTag = "0x1::aptos_coin::AptosCoin".parse().unwrap();
+/// let store: TypeTag = "0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin>".parse().unwrap();
+/// let bytes: TypeTag = "vector<u8>".parse().unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypeTag {
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    U256,
+    Address,
+    Signer,
+    Vector(Box<TypeTag>),
+    Struct(StructTag),
+}
+
+/// Fully qualified Move struct type, e.g. `0x1::aptos_coin::AptosCoin`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StructTag {
+    pub address: AptosAddress,
+    pub module: String,
+    pub name: String,
+    pub type_args: Vec<TypeTag>,
+}
+
+impl TypeTag {
+    fn serialize(&self, out: &mut Vec<u8>) {
+        // Variant indices of the Move `TypeTag` enum
+        match self {
+            TypeTag::Bool => out.push(0),
+            TypeTag::U8 => out.push(1),
+            TypeTag::U64 => out.push(2),
+            TypeTag::U128 => out.push(3),
+            TypeTag::Address => out.push(4),
+            TypeTag::Signer => out.push(5),
+            TypeTag::Vector(inner) => {
+                out.push(6);
+                inner.serialize(out);
+            }
+            TypeTag::Struct(tag) => {
+                out.push(7);
+                out.extend_from_slice(tag.address.as_bytes());
+                serialize_bytes(tag.module.as_bytes(), out);
+                serialize_bytes(tag.name.as_bytes(), out);
+                serialize_uleb128(tag.type_args.len(), out);
+                for arg in &tag.type_args {
+                    arg.serialize(out);
+                }
+            }
+            TypeTag::U16 => out.push(8),
+            TypeTag::U32 => out.push(9),
+            TypeTag::U256 => out.push(10),
+        }
+    }
+}
+
+impl FromStr for TypeTag {
+    type Err = CircleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let invalid = || CircleError::Config(format!("Invalid Move type: {}", s));
+
+        Ok(match s {
+            "bool" => TypeTag::Bool,
+            "u8" => TypeTag::U8,
+            "u16" => TypeTag::U16,
+            "u32" => TypeTag::U32,
+            "u64" => TypeTag::U64,
+            "u128" => TypeTag::U128,
+            "u256" => TypeTag::U256,
+            "address" => TypeTag::Address,
+            "signer" => TypeTag::Signer,
+            _ => {
+                if let Some(inner) = s.strip_prefix("vector<").and_then(|s| s.strip_suffix('>')) {
+                    return Ok(TypeTag::Vector(Box::new(inner.parse()?)));
+                }
+
+                let (path, type_args) = match s.find('<') {
+                    Some(open) => {
+                        let args = s[open + 1..].strip_suffix('>').ok_or_else(invalid)?;
+                        let args = split_type_args(args)
+                            .into_iter()
+                            .map(str::parse)
+                            .collect::<CircleResult<Vec<TypeTag>>>()?;
+                        (&s[..open], args)
+                    }
+                    None => (s, Vec::new()),
+                };
+
+                let mut parts = path.split("::");
+                let (Some(address), Some(module), Some(name), None) =
+                    (parts.next(), parts.next(), parts.next(), parts.next())
+                else {
+                    return Err(invalid());
+                };
+                TypeTag::Struct(StructTag {
+                    address: address.parse()?,
+                    module: module.to_string(),
+                    name: name.to_string(),
+                    type_args,
+                })
+            }
+        })
+    }
+}
+
+/// Split comma-separated type arguments, ignoring commas inside `<...>`
+fn split_type_args(args: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in args.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(&args[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&args[start..]);
+    parts
+}
+
+impl fmt::Display for TypeTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypeTag::Bool => f.write_str("bool"),
+            TypeTag::U8 => f.write_str("u8"),
+            TypeTag::U16 => f.write_str("u16"),
+            TypeTag::U32 => f.write_str("u32"),
+            TypeTag::U64 => f.write_str("u64"),
+            TypeTag::U128 => f.write_str("u128"),
+            TypeTag::U256 => f.write_str("u256"),
+            TypeTag::Address => f.write_str("address"),
+            TypeTag::Signer => f.write_str("signer"),
+            TypeTag::Vector(inner) => write!(f, "vector<{}>", inner),
+            TypeTag::Struct(tag) => {
+                write!(f, "{}::{}::{}", tag.address, tag.module, tag.name)?;
+                if !tag.type_args.is_empty() {
+                    let args: Vec<String> = tag.type_args.iter().map(ToString::to_string).collect();
+                    write!(f, "<{}>", args.join(", "))?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// An entry function call payload
+///
+/// Arguments are BCS-encoded in call order; the `arg_*` methods cover the
+/// common Move types and [`arg_raw`](Self::arg_raw) accepts pre-encoded values.
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::aptos::transaction::AptosEntryFunction;
+///
+/// let payload = AptosEntryFunction::new("0x1::aptos_account::transfer_coins")
+///     .unwrap()
+///     .type_arg("0x1::aptos_coin::AptosCoin".parse().unwrap())
+///     .arg_address("0xcafe".parse().unwrap())
+///     .arg_u64(1_000);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AptosEntryFunction {
+    pub module_address: AptosAddress,
+    pub module_name: String,
+    pub function: String,
+    pub type_args: Vec<TypeTag>,
+    pub args: Vec<Vec<u8>>,
+}
+
+impl AptosEntryFunction {
+    /// Create a call to `function`, given as `address::module::function`
+    pub fn new(function: &str) -> CircleResult<Self> {
+        let mut parts = function.split("::");
+        let (Some(address), Some(module), Some(name), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(CircleError::Config(format!(
+                "Invalid entry function {}: expected address::module::function",
+                function
+            )));
+        };
+
+        Ok(Self {
+            module_address: address.parse()?,
+            module_name: module.to_string(),
+            function: name.to_string(),
+            type_args: Vec::new(),
+            args: Vec::new(),
+        })
+    }
+
+    /// Append a type argument
+    pub fn type_arg(mut self, type_arg: TypeTag) -> Self {
+        self.type_args.push(type_arg);
+        self
+    }
+
+    /// Append an `address` argument
+    pub fn arg_address(self, address: AptosAddress) -> Self {
+        self.arg_raw(address.as_bytes().to_vec())
+    }
+
+    /// Append a `bool` argument
+    pub fn arg_bool(self, value: bool) -> Self {
+        self.arg_raw(vec![value as u8])
+    }
+
+    /// Append a `u8` argument
+    pub fn arg_u8(self, value: u8) -> Self {
+        self.arg_raw(vec![value])
+    }
+
+    /// Append a `u64` argument
+    pub fn arg_u64(self, value: u64) -> Self {
+        self.arg_raw(value.to_le_bytes().to_vec())
+    }
+
+    /// Append a `u128` argument
+    pub fn arg_u128(self, value: u128) -> Self {
+        self.arg_raw(value.to_le_bytes().to_vec())
+    }
+
+    /// Append a `0x1::string::String` argument
+    pub fn arg_string(self, value: &str) -> Self {
+        self.arg_bytes(value.as_bytes())
+    }
+
+    /// Append a `vector<u8>` argument
+    pub fn arg_bytes(self, value: &[u8]) -> Self {
+        let mut encoded = Vec::with_capacity(value.len() + 5);
+        serialize_bytes(value, &mut encoded);
+        self.arg_raw(encoded)
+    }
+
+    /// Append an argument that is already BCS-encoded
+    pub fn arg_raw(mut self, encoded: Vec<u8>) -> Self {
+        self.args.push(encoded);
+        self
+    }
+
+    /// BCS-encode as a `TransactionPayload::EntryFunction`
+    fn serialize(&self, out: &mut Vec<u8>) {
+        serialize_uleb128(2, out);
+        out.extend_from_slice(self.module_address.as_bytes());
+        serialize_bytes(self.module_name.as_bytes(), out);
+        serialize_bytes(self.function.as_bytes(), out);
+        serialize_uleb128(self.type_args.len(), out);
+        for type_arg in &self.type_args {
+            type_arg.serialize(out);
+        }
+        serialize_uleb128(self.args.len(), out);
+        for arg in &self.args {
+            serialize_bytes(arg, out);
+        }
+    }
+}
+
+/// Transfer `amount` octas of APT to `to`
+///
+/// Uses `0x1::aptos_account::transfer`, which creates the recipient account
+/// if it does not exist yet.
+pub fn apt_transfer(to: AptosAddress, amount: u64) -> AptosEntryFunction {
+    AptosEntryFunction::new("0x1::aptos_account::transfer")
+        .expect("framework function path is valid")
+        .arg_address(to)
+        .arg_u64(amount)
+}
+
+/// Transfer `amount` base units of the coin `coin_type` to `to`
+///
+/// Uses `0x1::aptos_account::transfer_coins<CoinType>`.
+///
+/// # Arguments
+/// * `coin_type` - Coin type, e.g. `"0x1::aptos_coin::AptosCoin"`
+/// * `to` - Recipient address
+/// * `amount` - Raw amount in base units
+pub fn coin_transfer(
+    coin_type: &str,
+    to: AptosAddress,
+    amount: u64,
+) -> CircleResult<AptosEntryFunction> {
+    Ok(
+        AptosEntryFunction::new("0x1::aptos_account::transfer_coins")?
+            .type_arg(coin_type.parse()?)
+            .arg_address(to)
+            .arg_u64(amount),
+    )
+}
+
+/// Default maximum gas units per transaction
+const DEFAULT_MAX_GAS_AMOUNT: u64 = 200_000;
+
+/// Default gas unit price in octas
+const DEFAULT_GAS_UNIT_PRICE: u64 = 100;
+
+/// Default validity window, long enough for Circle to sign the transaction
+const DEFAULT_EXPIRATION_SECS: u64 = 600;
+
+/// Builder for an unsigned Aptos `RawTransaction`
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use inf_circle_sdk::aptos::{
+///     apt_transfer, dto::AptosNetwork, get_aptos_account, AptosAddress, AptosTransactionBuilder,
+/// };
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let network = AptosNetwork::Testnet;
+/// let sender: AptosAddress = "0x1234".parse()?;
+/// let account = get_aptos_account(&sender.to_string(), &network).await?;
+///
+/// let sign_request = AptosTransactionBuilder::new(
+///     sender,
+///     account.sequence_number,
+///     apt_transfer("0xcafe".parse()?, 1_000_000),
+/// )
+/// .network(&network)
+/// .into_sign_request("wallet-id".to_string())?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct AptosTransactionBuilder {
+    pub sender: AptosAddress,
+    pub sequence_number: u64,
+    pub payload: AptosEntryFunction,
+    pub max_gas_amount: u64,
+    pub gas_unit_price: u64,
+    pub expiration_timestamp_secs: Option<u64>,
+    pub chain_id: Option<u8>,
+}
+
+impl AptosTransactionBuilder {
+    /// Create a new builder with required fields
+    ///
+    /// # Arguments
+    /// * `sender` - Address of the Circle wallet that signs the transaction
+    /// * `sequence_number` - Current sequence number of the sender account
+    /// * `payload` - Entry function to call
+    pub fn new(sender: AptosAddress, sequence_number: u64, payload: AptosEntryFunction) -> Self {
+        Self {
+            sender,
+            sequence_number,
+            payload,
+            max_gas_amount: DEFAULT_MAX_GAS_AMOUNT,
+            gas_unit_price: DEFAULT_GAS_UNIT_PRICE,
+            expiration_timestamp_secs: None,
+            chain_id: None,
+        }
+    }
+
+    /// Set the maximum gas units (default 200,000)
+    pub fn max_gas_amount(mut self, max_gas_amount: u64) -> Self {
+        self.max_gas_amount = max_gas_amount;
+        self
+    }
+
+    /// Set the gas unit price in octas (default 100)
+    pub fn gas_unit_price(mut self, gas_unit_price: u64) -> Self {
+        self.gas_unit_price = gas_unit_price;
+        self
+    }
+
+    /// Set the expiration as a Unix timestamp (default: 10 minutes after serialization)
+    pub fn expiration_timestamp_secs(mut self, expiration_timestamp_secs: u64) -> Self {
+        self.expiration_timestamp_secs = Some(expiration_timestamp_secs);
+        self
+    }
+
+    /// Set the chain ID
+    pub fn chain_id(mut self, chain_id: u8) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    /// Use the chain ID of `network`
+    pub fn network(mut self, network: &AptosNetwork) -> Self {
+        self.chain_id = network.chain_id().or(self.chain_id);
+        self
+    }
+
+    /// BCS-serialize the `RawTransaction`
+    pub fn serialize(&self) -> CircleResult<Vec<u8>> {
+        let chain_id = self.chain_id.ok_or_else(|| {
+            CircleError::Config("Aptos transaction requires a chain ID".to_string())
+        })?;
+        let expiration = self.expiration_timestamp_secs.unwrap_or_else(|| {
+            chrono::Utc::now().timestamp().max(0) as u64 + DEFAULT_EXPIRATION_SECS
+        });
+
+        let mut out = Vec::new();
+        out.extend_from_slice(self.sender.as_bytes());
+        out.extend_from_slice(&self.sequence_number.to_le_bytes());
+        self.payload.serialize(&mut out);
+        out.extend_from_slice(&self.max_gas_amount.to_le_bytes());
+        out.extend_from_slice(&self.gas_unit_price.to_le_bytes());
+        out.extend_from_slice(&expiration.to_le_bytes());
+        out.push(chain_id);
+        Ok(out)
+    }
+
+    /// Serialize as `0x`-prefixed hex, the format Circle expects
+    pub fn to_hex(&self) -> CircleResult<String> {
+        Ok(format!("0x{}", hex::encode(self.serialize()?)))
+    }
+
+    /// Create a sign transaction request for the Circle wallet `wallet_id`
+    pub fn into_sign_request(
+        self,
+        wallet_id: String,
+    ) -> CircleResult<SignTransactionRequestBuilder> {
+        SignTransactionRequestBuilder::new(wallet_id, Some(self.to_hex()?), None)
+    }
+}
+
+/// Append `value` as a ULEB128 integer (BCS lengths and enum variants)
+fn serialize_uleb128(mut value: usize, out: &mut Vec<u8>) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+/// Append a length-prefixed byte sequence
+fn serialize_bytes(bytes: &[u8], out: &mut Vec<u8>) {
+    serialize_uleb128(bytes.len(), out);
+    out.extend_from_slice(bytes);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn address(byte: u8) -> AptosAddress {
+        AptosAddress::from_bytes([byte; 32])
+    }
+
+    #[test]
+    fn test_uleb128() {
+        for (value, encoded) in [
+            (0usize, vec![0x00]),
+            (127, vec![0x7f]),
+            (128, vec![0x80, 0x01]),
+            (300, vec![0xac, 0x02]),
+            (16_384, vec![0x80, 0x80, 0x01]),
+        ] {
+            let mut out = Vec::new();
+            serialize_uleb128(value, &mut out);
+            assert_eq!(out, encoded);
+        }
+    }
+
+    #[test]
+    fn test_type_tag_round_trip() {
+        let tag: TypeTag = "0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin>"
+            .parse()
+            .unwrap();
+        let TypeTag::Struct(store) = &tag else {
+            panic!("expected struct tag");
+        };
+        assert_eq!(store.module, "coin");
+        assert_eq!(store.name, "CoinStore");
+        assert_eq!(store.type_args.len(), 1);
+        assert_eq!(tag.to_string().parse::<TypeTag>().unwrap(), tag);
+
+        let pair: TypeTag = "0x1::pool::Pool<0x1::a::A, vector<u8>>".parse().unwrap();
+        let TypeTag::Struct(pool) = pair else {
+            panic!("expected struct tag");
+        };
+        assert_eq!(pool.type_args[1], TypeTag::Vector(Box::new(TypeTag::U8)));
+
+        assert!("0x1::coin".parse::<TypeTag>().is_err());
+    }
+
+    #[test]
+    fn test_serialize_apt_transfer() {
+        let builder =
+            AptosTransactionBuilder::new(address(0xaa), 7, apt_transfer(address(0xbb), 1_000))
+                .max_gas_amount(2_000)
+                .gas_unit_price(100)
+                .expiration_timestamp_secs(1_700_000_000)
+                .chain_id(2);
+        let bytes = builder.serialize().unwrap();
+
+        let mut expected = vec![0xaa; 32];
+        expected.extend_from_slice(&7u64.to_le_bytes());
+        expected.push(2); // EntryFunction payload
+        expected.extend_from_slice(&{
+            let mut framework = [0u8; 32];
+            framework[31] = 1;
+            framework
+        });
+        expected.push(13);
+        expected.extend_from_slice(b"aptos_account");
+        expected.push(8);
+        expected.extend_from_slice(b"transfer");
+        expected.push(0); // no type arguments
+        expected.push(2); // two arguments
+        expected.push(32);
+        expected.extend_from_slice(&[0xbb; 32]);
+        expected.push(8);
+        expected.extend_from_slice(&1_000u64.to_le_bytes());
+        expected.extend_from_slice(&2_000u64.to_le_bytes());
+        expected.extend_from_slice(&100u64.to_le_bytes());
+        expected.extend_from_slice(&1_700_000_000u64.to_le_bytes());
+        expected.push(2);
+
+        assert_eq!(bytes, expected);
+        assert_eq!(
+            builder.to_hex().unwrap(),
+            format!("0x{}", hex::encode(&expected))
+        );
+    }
+
+    #[test]
+    fn test_coin_transfer_type_argument() {
+        let payload = coin_transfer("0x1::aptos_coin::AptosCoin", address(0xbb), 5).unwrap();
+        let mut out = Vec::new();
+        payload.type_args[0].serialize(&mut out);
+
+        let mut expected = vec![7];
+        expected.extend_from_slice(&{
+            let mut framework = [0u8; 32];
+            framework[31] = 1;
+            framework
+        });
+        expected.push(10);
+        expected.extend_from_slice(b"aptos_coin");
+        expected.push(9);
+        expected.extend_from_slice(b"AptosCoin");
+        expected.push(0);
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn test_serialize_requires_chain_id() {
+        let builder = AptosTransactionBuilder::new(address(1), 0, apt_transfer(address(2), 1));
+        assert!(matches!(builder.serialize(), Err(CircleError::Config(_))));
+    }
+}
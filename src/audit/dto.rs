@@ -0,0 +1,96 @@
+//! Audit Data Transfer Objects
+//!
+//! This module contains the record written by the audit [`Exporter`](super::Exporter)
+//! for every write operation.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::helper::CircleResult;
+
+/// One NDJSON line in the audit log
+///
+/// The request payload is never stored. Instead, `payload_sha256` holds the
+/// SHA-256 of the JSON body with `entitySecretCiphertext` removed, which is
+/// enough to prove what was sent without leaking secrets.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditRecord {
+    /// When the operation completed
+    pub timestamp: DateTime<Utc>,
+
+    /// HTTP method (POST, PUT, PATCH)
+    pub method: String,
+
+    /// API endpoint path
+    pub endpoint: String,
+
+    /// Hex-encoded SHA-256 of the redacted request payload
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload_sha256: Option<String>,
+
+    /// Idempotency key sent with the request, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<String>,
+
+    /// Identifier of the created or updated resource
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_id: Option<String>,
+
+    /// State of the resource returned by the API
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_state: Option<String>,
+
+    /// Error message if the operation failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl AuditRecord {
+    /// Build a record from a request payload and the API result
+    pub(crate) fn new(
+        method: &str,
+        endpoint: &str,
+        payload: Option<&Value>,
+        result: &CircleResult<Value>,
+    ) -> Self {
+        let (response_id, response_state, error) = match result {
+            Ok(data) => (
+                ["id", "contractId", "transactionId"]
+                    .iter()
+                    .find_map(|key| data.get(*key).and_then(Value::as_str))
+                    .map(str::to_string),
+                data.get("state")
+                    .and_then(Value::as_str)
+                    .map(str::to_string),
+                None,
+            ),
+            Err(e) => (None, None, Some(e.to_string())),
+        };
+
+        Self {
+            timestamp: Utc::now(),
+            method: method.to_string(),
+            endpoint: endpoint.to_string(),
+            payload_sha256: payload.map(redacted_payload_hash),
+            idempotency_key: payload
+                .and_then(|p| p.get("idempotencyKey"))
+                .and_then(Value::as_str)
+                .map(str::to_string),
+            response_id,
+            response_state,
+            error,
+        }
+    }
+}
+
+/// SHA-256 of the payload with the entity secret ciphertext removed
+fn redacted_payload_hash(payload: &Value) -> String {
+    let mut redacted = payload.clone();
+    if let Some(object) = redacted.as_object_mut() {
+        object.remove("entitySecretCiphertext");
+    }
+    hex::encode(Sha256::digest(redacted.to_string().as_bytes()))
+}
@@ -0,0 +1,146 @@
+//! Audit Exporter
+//!
+//! This module provides the [`Exporter`] that appends [`AuditRecord`]s as
+//! newline-delimited JSON to any writer.
+
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use super::dto::AuditRecord;
+use crate::helper::{CircleError, CircleResult};
+
+/// Append-only NDJSON audit log for `CircleOps` write operations
+///
+/// The exporter is cheap to clone; clones share the same underlying writer.
+/// Each record is written as a single line and flushed immediately.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use inf_circle_sdk::audit::Exporter;
+/// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let ops = CircleOps::new(None)?.with_audit_exporter(Exporter::to_file("circle-audit.jsonl")?);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Exporter {
+    writer: Arc<Mutex<Box<dyn Write + Send>>>,
+}
+
+impl Exporter {
+    /// Create an exporter writing to any `Write` implementation
+    pub fn new<W: Write + Send + 'static>(writer: W) -> Self {
+        Self {
+            writer: Arc::new(Mutex::new(Box::new(writer))),
+        }
+    }
+
+    /// Create an exporter appending to the file at `path`, creating it if needed
+    pub fn to_file(path: impl AsRef<Path>) -> CircleResult<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path.as_ref())
+            .map_err(|e| {
+                CircleError::Config(format!(
+                    "Failed to open audit log {}: {}",
+                    path.as_ref().display(),
+                    e
+                ))
+            })?;
+        Ok(Self::new(file))
+    }
+
+    /// Append a record as one NDJSON line
+    pub fn record(&self, record: &AuditRecord) -> CircleResult<()> {
+        let mut line = serde_json::to_vec(record)?;
+        line.push(b'\n');
+
+        let mut writer = self
+            .writer
+            .lock()
+            .map_err(|_| CircleError::Config("Audit log writer lock poisoned".to_string()))?;
+        writer
+            .write_all(&line)
+            .and_then(|_| writer.flush())
+            .map_err(|e| CircleError::Config(format!("Failed to write audit record: {}", e)))
+    }
+}
+
+impl std::fmt::Debug for Exporter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Exporter").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_records_are_redacted_ndjson() {
+        let buffer = SharedBuffer::default();
+        let exporter = Exporter::new(buffer.clone());
+
+        let payload = json!({
+            "idempotencyKey": "key-1",
+            "entitySecretCiphertext": "secret",
+            "walletId": "wallet-1"
+        });
+        let same_payload_other_secret = json!({
+            "idempotencyKey": "key-1",
+            "entitySecretCiphertext": "another-secret",
+            "walletId": "wallet-1"
+        });
+
+        let ok = Ok(json!({"id": "tx-1", "state": "INITIATED"}));
+        let record = AuditRecord::new("POST", "/v1/transfer", Some(&payload), &ok);
+        exporter.record(&record).unwrap();
+
+        let err = Err(CircleError::Api {
+            status: 400,
+            request_id: None,
+            message: "bad".to_string(),
+        });
+        let failed = AuditRecord::new(
+            "POST",
+            "/v1/transfer",
+            Some(&same_payload_other_secret),
+            &err,
+        );
+        exporter.record(&failed).unwrap();
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        let lines: Vec<AuditRecord> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(lines.len(), 2);
+        assert!(!output.contains("secret"));
+        assert_eq!(lines[0].idempotency_key.as_deref(), Some("key-1"));
+        assert_eq!(lines[0].response_id.as_deref(), Some("tx-1"));
+        assert_eq!(lines[0].response_state.as_deref(), Some("INITIATED"));
+        assert_eq!(lines[0].payload_sha256, lines[1].payload_sha256);
+        assert_eq!(lines[1].error.as_deref(), Some("API error: 400 - bad"));
+    }
+}
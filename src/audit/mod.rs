@@ -0,0 +1,32 @@
+//! Audit Logging
+//!
+//! This module provides an append-only audit trail for write operations. When an
+//! [`Exporter`] is attached to `CircleOps`, every POST, PUT and PATCH request
+//! produces one NDJSON line containing the timestamp, endpoint, a redacted
+//! payload hash, the idempotency key and the returned resource id and state.
+//!
+//! # Main Components
+//!
+//! - [`dto`]: The [`AuditRecord`] written for each operation
+//! - [`exporter`]: The [`Exporter`] that writes records to a file or writer
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::audit::Exporter;
+//! use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+//!
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let ops = CircleOps::new(None)?.with_audit_exporter(Exporter::to_file("circle-audit.jsonl")?);
+//!
+//! // Every write operation performed with `ops` is now appended to circle-audit.jsonl
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod dto;
+pub mod exporter;
+
+// Re-export commonly used items
+pub use dto::AuditRecord;
+pub use exporter::Exporter;
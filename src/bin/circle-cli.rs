@@ -0,0 +1,530 @@
+//! `circle-cli`: operational debugging against the Circle API
+//!
+//! A thin command-line front end over `CircleView`/`CircleOps`. It reads the
+//! same environment variables as the SDK (`CIRCLE_API_KEY`, `CIRCLE_BASE_URL`,
+//! `CIRCLE_ENTITY_SECRET`, `CIRCLE_PUBLIC_KEY`, a `.env` file is honoured) and
+//! prints every response as pretty JSON.
+//!
+//! Build with the `cli` feature:
+//!
+//! ```bash
+//! cargo run --features cli --bin circle-cli -- wallet list --blockchain ETH-SEPOLIA
+//! cargo run --features cli --bin circle-cli -- balance <wallet-id>
+//! ```
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use inf_circle_sdk::{
+    circle_ops::circler_ops::CircleOps,
+    circle_view::circle_view::CircleView,
+    contract::{
+        dto::{ListEventMonitorsParams, NotificationType},
+        ops::deploy_contract::DeployContractRequestBuilder,
+        views::{
+            create_event_monitor::CreateEventMonitorBodyBuilder,
+            create_notification_subscription::CreateNotificationSubscriptionBodyBuilder,
+            query_contract_view::QueryContractViewBodyBuilder,
+        },
+    },
+    dev_wallet::{
+        dto::{AccountType, FeeLevel},
+        ops::{
+            create_dev_wallet::CreateDevWalletRequestBuilder,
+            create_transfer_transaction::CreateTransferTransactionRequestBuilder,
+        },
+        views::{list_wallets::ListDevWalletsParamsBuilder, query::QueryParamsBuilder},
+    },
+    types::Blockchain,
+};
+use serde::Serialize;
+use serde_json::Value;
+use std::error::Error;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+type CliResult = Result<(), Box<dyn Error>>;
+
+/// Command-line client for the Circle Web3 Services API
+#[derive(Parser)]
+#[command(name = "circle-cli", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create and list developer-controlled wallets
+    #[command(subcommand)]
+    Wallet(WalletCommand),
+
+    /// Show the token balances of a wallet
+    Balance {
+        /// Wallet ID
+        wallet_id: String,
+
+        /// Include tokens with a zero balance
+        #[arg(long)]
+        include_all: bool,
+    },
+
+    /// Send a native or token transfer from a wallet
+    Transfer(TransferArgs),
+
+    /// Deploy and query contracts
+    #[command(subcommand)]
+    Contract(ContractCommand),
+
+    /// Manage contract event monitors
+    #[command(subcommand)]
+    Monitor(MonitorCommand),
+
+    /// Manage webhook notification subscriptions
+    #[command(subcommand)]
+    Webhook(WebhookCommand),
+}
+
+#[derive(Subcommand)]
+enum WalletCommand {
+    /// Create wallets in a wallet set
+    Create {
+        /// Wallet set to create the wallets in
+        #[arg(long, env = "CIRCLE_WALLET_SET_ID")]
+        wallet_set_id: String,
+
+        /// Blockchain to create the wallets on (repeatable)
+        #[arg(long = "blockchain", required = true)]
+        blockchains: Vec<Blockchain>,
+
+        /// Account type
+        #[arg(long, value_enum, default_value_t = AccountKind::Eoa)]
+        account_type: AccountKind,
+
+        /// Number of wallets per blockchain
+        #[arg(long, default_value_t = 1)]
+        count: u32,
+
+        /// Wallet name
+        #[arg(long)]
+        name: Option<String>,
+
+        /// Reference ID
+        #[arg(long)]
+        ref_id: Option<String>,
+    },
+
+    /// List wallets
+    List {
+        /// Filter by wallet set
+        #[arg(long)]
+        wallet_set_id: Option<String>,
+
+        /// Filter by blockchain
+        #[arg(long)]
+        blockchain: Option<Blockchain>,
+
+        /// Maximum number of wallets to return
+        #[arg(long)]
+        page_size: Option<u32>,
+    },
+}
+
+#[derive(Args)]
+struct TransferArgs {
+    /// Source wallet ID
+    #[arg(long)]
+    wallet_id: String,
+
+    /// Destination address
+    #[arg(long)]
+    destination: String,
+
+    /// Amount in token units (e.g. 0.001)
+    #[arg(long)]
+    amount: String,
+
+    /// Blockchain of the transfer
+    #[arg(long)]
+    blockchain: Blockchain,
+
+    /// Token contract address; omit for the native token
+    #[arg(long)]
+    token_address: Option<String>,
+
+    /// Fee level
+    #[arg(long, value_enum, default_value_t = FeeKind::Medium)]
+    fee_level: FeeKind,
+
+    /// Reference ID
+    #[arg(long)]
+    ref_id: Option<String>,
+
+    /// Idempotency key; a random UUID when omitted
+    #[arg(long)]
+    idempotency_key: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum ContractCommand {
+    /// Deploy a contract from a Foundry or Hardhat build artifact
+    Deploy {
+        /// Path to the artifact JSON
+        artifact: PathBuf,
+
+        /// Read the artifact in Hardhat layout instead of Foundry
+        #[arg(long)]
+        hardhat: bool,
+
+        /// Wallet ID to deploy from
+        #[arg(long)]
+        wallet_id: String,
+
+        /// Contract name (alphanumeric)
+        #[arg(long)]
+        name: String,
+
+        /// Blockchain to deploy on
+        #[arg(long)]
+        blockchain: Blockchain,
+
+        /// Constructor arguments as a JSON array
+        #[arg(long)]
+        args: Option<String>,
+
+        /// Fee level
+        #[arg(long, value_enum, default_value_t = FeeKind::Medium)]
+        fee_level: FeeKind,
+    },
+
+    /// Call a read-only contract function
+    Query {
+        /// Blockchain of the contract
+        #[arg(long)]
+        blockchain: Blockchain,
+
+        /// Contract address
+        #[arg(long)]
+        address: String,
+
+        /// Function signature, e.g. `balanceOf(address)`
+        #[arg(long)]
+        function: String,
+
+        /// Function arguments as a JSON array
+        #[arg(long)]
+        args: Option<String>,
+
+        /// Address to call from
+        #[arg(long)]
+        from: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum MonitorCommand {
+    /// List event monitors
+    List {
+        /// Filter by contract address
+        #[arg(long)]
+        contract_address: Option<String>,
+
+        /// Filter by blockchain
+        #[arg(long)]
+        blockchain: Option<Blockchain>,
+    },
+
+    /// Monitor a contract event
+    Create {
+        /// Blockchain of the contract
+        #[arg(long)]
+        blockchain: Blockchain,
+
+        /// Contract address
+        #[arg(long)]
+        contract_address: String,
+
+        /// Event signature, e.g. `Transfer(address,address,uint256)`
+        #[arg(long)]
+        event_signature: String,
+    },
+
+    /// Delete an event monitor
+    Delete {
+        /// Event monitor ID
+        id: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum WebhookCommand {
+    /// List webhook subscriptions
+    List,
+
+    /// Subscribe an endpoint to notifications
+    Create {
+        /// HTTPS endpoint receiving the notifications
+        endpoint: String,
+
+        /// Notification type, e.g. `transactions.*` (repeatable; all types when omitted)
+        #[arg(long = "type", value_parser = parse_notification_type)]
+        types: Vec<NotificationType>,
+    },
+
+    /// Delete a webhook subscription
+    Delete {
+        /// Subscription ID
+        id: String,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum AccountKind {
+    Eoa,
+    Sca,
+}
+
+impl From<AccountKind> for AccountType {
+    fn from(kind: AccountKind) -> Self {
+        match kind {
+            AccountKind::Eoa => AccountType::Eoa,
+            AccountKind::Sca => AccountType::Sca,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum FeeKind {
+    Low,
+    Medium,
+    High,
+}
+
+impl From<FeeKind> for FeeLevel {
+    fn from(kind: FeeKind) -> Self {
+        match kind {
+            FeeKind::Low => FeeLevel::Low,
+            FeeKind::Medium => FeeLevel::Medium,
+            FeeKind::High => FeeLevel::High,
+        }
+    }
+}
+
+fn parse_notification_type(value: &str) -> Result<NotificationType, String> {
+    match serde_json::from_value(Value::String(value.to_string())) {
+        Ok(NotificationType::Other(_)) | Err(_) => {
+            Err(format!("unknown notification type `{}`", value))
+        }
+        Ok(notification_type) => Ok(notification_type),
+    }
+}
+
+/// Parse an optional JSON array of ABI arguments
+fn parse_args(args: Option<String>) -> Result<Vec<Value>, Box<dyn Error>> {
+    match args {
+        Some(args) => Ok(serde_json::from_str(&args)
+            .map_err(|e| format!("--args must be a JSON array: {}", e))?),
+        None => Ok(Vec::new()),
+    }
+}
+
+fn print_json(value: &impl Serialize) -> CliResult {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() {
+    dotenv::dotenv().ok();
+
+    if let Err(e) = run(Cli::parse()).await {
+        eprintln!("error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+async fn run(cli: Cli) -> CliResult {
+    match cli.command {
+        Command::Wallet(command) => wallet(command).await,
+        Command::Balance {
+            wallet_id,
+            include_all,
+        } => {
+            let params = QueryParamsBuilder::new().include_all(include_all).build();
+            let balances = CircleView::new()?
+                .get_token_balances(&wallet_id, params)
+                .await?;
+            print_json(&balances)
+        }
+        Command::Transfer(args) => transfer(args).await,
+        Command::Contract(command) => contract(command).await,
+        Command::Monitor(command) => monitor(command).await,
+        Command::Webhook(command) => webhook(command).await,
+    }
+}
+
+async fn wallet(command: WalletCommand) -> CliResult {
+    match command {
+        WalletCommand::Create {
+            wallet_set_id,
+            blockchains,
+            account_type,
+            count,
+            name,
+            ref_id,
+        } => {
+            let mut builder = CreateDevWalletRequestBuilder::new(wallet_set_id, blockchains)?
+                .account_type(account_type.into())
+                .count(count);
+            if let Some(name) = name {
+                builder = builder.name(name);
+            }
+            if let Some(ref_id) = ref_id {
+                builder = builder.ref_id(ref_id);
+            }
+            let response = CircleOps::new(None)?
+                .create_dev_wallet(builder.build())
+                .await?;
+            print_json(&response)
+        }
+        WalletCommand::List {
+            wallet_set_id,
+            blockchain,
+            page_size,
+        } => {
+            let mut builder = ListDevWalletsParamsBuilder::new();
+            if let Some(wallet_set_id) = wallet_set_id {
+                builder = builder.wallet_set_id(wallet_set_id);
+            }
+            if let Some(blockchain) = blockchain {
+                builder = builder.blockchain(blockchain.as_str().to_string());
+            }
+            if let Some(page_size) = page_size {
+                builder = builder.page_size(page_size);
+            }
+            let wallets = CircleView::new()?.list_wallets(builder.build()).await?;
+            print_json(&wallets)
+        }
+    }
+}
+
+async fn transfer(args: TransferArgs) -> CliResult {
+    let mut builder = CreateTransferTransactionRequestBuilder::new()
+        .wallet_id(args.wallet_id)
+        .destination_address(args.destination)
+        .amounts([args.amount])
+        .blockchain(args.blockchain)
+        .fee_level(args.fee_level.into())
+        .idempotency_key(
+            args.idempotency_key
+                .unwrap_or_else(|| Uuid::new_v4().to_string()),
+        );
+    if let Some(token_address) = args.token_address {
+        builder = builder.token_address(token_address);
+    }
+    if let Some(ref_id) = args.ref_id {
+        builder = builder.ref_id(ref_id);
+    }
+
+    let response = CircleOps::new(None)?
+        .create_dev_transfer_transaction(builder.try_build()?)
+        .await?;
+    print_json(&response)
+}
+
+async fn contract(command: ContractCommand) -> CliResult {
+    match command {
+        ContractCommand::Deploy {
+            artifact,
+            hardhat,
+            wallet_id,
+            name,
+            blockchain,
+            args,
+            fee_level,
+        } => {
+            let builder = if hardhat {
+                DeployContractRequestBuilder::from_hardhat_artifact(
+                    artifact, wallet_id, name, blockchain,
+                )?
+            } else {
+                DeployContractRequestBuilder::from_foundry_artifact(
+                    artifact, wallet_id, name, blockchain,
+                )?
+            };
+            let builder = builder
+                .constructor_parameters(parse_args(args)?)
+                .fee_level(fee_level.into());
+            let response = CircleOps::new(None)?.deploy_contract(builder).await?;
+            print_json(&response)
+        }
+        ContractCommand::Query {
+            blockchain,
+            address,
+            function,
+            args,
+            from,
+        } => {
+            let mut builder = QueryContractViewBodyBuilder::new(blockchain, address)
+                .abi_function_signature(function)
+                .abi_parameters(parse_args(args)?);
+            if let Some(from) = from {
+                builder = builder.from_address(from);
+            }
+            let response = CircleView::new()?.query_contract(builder).await?;
+            print_json(&response)
+        }
+    }
+}
+
+async fn monitor(command: MonitorCommand) -> CliResult {
+    let view = CircleView::new()?;
+    match command {
+        MonitorCommand::List {
+            contract_address,
+            blockchain,
+        } => {
+            let params = ListEventMonitorsParams {
+                contract_address,
+                blockchain,
+                ..Default::default()
+            };
+            print_json(&view.list_event_monitors(Some(params)).await?)
+        }
+        MonitorCommand::Create {
+            blockchain,
+            contract_address,
+            event_signature,
+        } => {
+            let builder = CreateEventMonitorBodyBuilder::new(
+                Uuid::new_v4().to_string(),
+                event_signature,
+                contract_address,
+                blockchain,
+            );
+            print_json(&view.create_event_monitor(builder).await?)
+        }
+        MonitorCommand::Delete { id } => {
+            view.delete_event_monitor(&id).await?;
+            println!("deleted event monitor {}", id);
+            Ok(())
+        }
+    }
+}
+
+async fn webhook(command: WebhookCommand) -> CliResult {
+    let view = CircleView::new()?;
+    match command {
+        WebhookCommand::List => print_json(&view.list_notification_subscriptions().await?),
+        WebhookCommand::Create { endpoint, types } => {
+            let mut builder = CreateNotificationSubscriptionBodyBuilder::new(endpoint);
+            if !types.is_empty() {
+                builder = builder.notification_types(types);
+            }
+            print_json(&view.create_notification_subscription(builder).await?)
+        }
+        WebhookCommand::Delete { id } => {
+            view.delete_notification_subscription(&id).await?;
+            println!("deleted subscription {}", id);
+            Ok(())
+        }
+    }
+}
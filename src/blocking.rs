@@ -0,0 +1,702 @@
+//! Blocking Clients (`blocking` feature)
+//!
+//! [`CircleViewBlocking`] and [`CircleOpsBlocking`] wrap the async clients
+//! with a private single-threaded Tokio runtime so synchronous programs (CLI
+//! scripts, build tooling) can call the API without setting up their own
+//! runtime. Every async method on [`CircleView`] and [`CircleOps`] has a
+//! blocking counterpart with the same name and arguments; for anything else
+//! that takes a client (ERC-20 helpers, [`export_account_state`](crate::admin::export_account_state),
+//! ...) use `block_on`, which lends the wrapped client to a future.
+//!
+//! The blocking methods must not be called from inside an async runtime:
+//! Tokio panics when a runtime is blocked on from within another.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::blocking::CircleViewBlocking;
+//! use inf_circle_sdk::contract::erc20::Erc20Client;
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let view = CircleViewBlocking::new()?;
+//!
+//!     let wallet = view.get_wallet("wallet-id")?.wallet;
+//!     println!("{} on {}", wallet.address, wallet.blockchain);
+//!
+//!     let usdc = Erc20Client::new(
+//!         wallet.blockchain.clone(),
+//!         "0x1c7D4B196Cb0C7B01d743Fbc6116a902379C7238".to_string(),
+//!     );
+//!     let balance = view.block_on(|view| usdc.balance_of(view, &wallet.address))?;
+//!     println!("USDC balance: {}", balance);
+//!     Ok(())
+//! }
+//! ```
+
+use std::future::Future;
+use std::time::Duration;
+
+use reqwest::Method;
+use serde::Serialize;
+use serde_json::Value;
+use tokio::runtime::{Builder, Runtime};
+
+use crate::circle_ops::circler_ops::CircleOps;
+use crate::circle_ops::idempotency::PendingRequest;
+use crate::circle_ops::secret::SecretProvider;
+use crate::circle_view::circle_view::CircleView;
+use crate::compliance::{
+    dto::AddressScreeningResponse, ops::screen_address::ScreenAddressRequestBuilder,
+};
+use crate::contract::dto::{
+    Contract, ContractDeploymentResponse, ContractResponse, CreateNotificationSubscriptionResponse,
+    EventLog, EventMonitor, EventMonitorResponse, ListContractsParams, ListEventLogsParams,
+    ListEventMonitorsParams, NotificationSubscription, PingResponse, QueryContractResponse,
+    SubscriptionSpec, SubscriptionSyncReport, TemplateContractDeploymentResponse,
+    UpdateContractRequest, UpdateNotificationSubscriptionResponse,
+};
+use crate::contract::ops::{
+    archive_contract::ArchiveContractRequestBuilder, deploy_contract::DeployContractRequestBuilder,
+    deploy_contract_from_template::DeployContractFromTemplateRequestBuilder,
+    import_contract::ImportContractRequestBuilder,
+};
+use crate::contract::views::{
+    create_event_monitor::CreateEventMonitorBodyBuilder,
+    create_notification_subscription::CreateNotificationSubscriptionBodyBuilder,
+    estimate_contract_deployment::EstimateContractDeploymentBodyBuilder,
+    estimate_template_deployment_fee::EstimateTemplateDeploymentFeeBodyBuilder,
+    query_contract_view::QueryContractViewBodyBuilder,
+    update_event_monitor::UpdateEventMonitorBodyBuilder,
+    update_notification_subscription::UpdateNotificationSubscriptionBodyBuilder,
+};
+use crate::dev_wallet::batch::{TransferBatch, TransferBatchReport};
+use crate::dev_wallet::dto::{
+    AccelerateTransactionResponse, AccelerationFeeEstimate, CancelTransactionResponse,
+    CreateContractExecutionTransactionResponse, CreateTransferTransactionResponse,
+    CreateWalletUpgradeTransactionResponse, DevWallet, DevWalletResponse, DevWalletsResponse,
+    EntityPublicKeyResponse, EstimateContractExecutionFeeBody, EstimateTransferFeeRequest,
+    FeeSelection, ListDevWalletsParams, ListTransactionsParams, ListWalletsWithBalancesParams,
+    NftsResponse, QueryContractRequest, QueryParams, RequestTestnetTokensRequest,
+    SignDelegateResponse, SignTransactionResponse, SignatureResponse, Token, TokenBalancesResponse,
+    TokenResponse, Transaction, TransactionResponse, TransferWithEstimate, UpdateDevWalletRequest,
+    ValidateAddressBody, ValidateAddressResponse, WalletWithBalances,
+};
+use crate::dev_wallet::ops::{
+    accelerate_transaction::AccelerateTransactionRequestBuilder,
+    cancel_transaction::CancelTransactionRequestBuilder,
+    create_contract_transaction::CreateContractExecutionTransactionRequestBuilder,
+    create_dev_wallet::CreateDevWalletRequestBuilder,
+    create_dev_wallet_batch::{BatchWalletPlan, BatchWalletResult},
+    create_transfer_transaction::CreateTransferTransactionRequestBuilder,
+    create_wallet_upgrade_transaction::CreateWalletUpgradeTransactionRequestBuilder,
+    ensure_wallet::EnsureWalletOptions,
+    sign_data::SignDataRequestBuilder,
+    sign_delegate::SignDelegateRequestBuilder,
+    sign_message::SignMessageRequestBuilder,
+    sign_transaction::SignTransactionRequestBuilder,
+};
+use crate::dev_wallet::portfolio::Portfolio;
+use crate::fees::FeeEstimate;
+use crate::helper::{http_core::CircleHttpCore, page::Page, CircleError, CircleResult, WithMeta};
+use crate::payments::{
+    dto::{
+        BusinessBalances, BusinessTransfer, Deposit, ListPaymentsParams, Payout, WireBankAccount,
+        WireInstructions,
+    },
+    ops::{
+        create_business_transfer::CreateBusinessTransferRequestBuilder,
+        create_payout::CreatePayoutRequestBuilder,
+        create_wire_bank_account::CreateWireBankAccountRequestBuilder,
+    },
+};
+use crate::ramp::{
+    dto::{ListRampSessionsParams, RampSession, RampSessionResponse},
+    ops::create_ramp_session::CreateRampSessionRequestBuilder,
+};
+use crate::rates::dto::{
+    Currency, CurrencyPair, ExchangeQuote, ExchangeQuoteRequest, ExchangeRate,
+};
+use crate::travel_rule::{
+    dto::{TravelRuleIdentity, TravelRuleResponse},
+    ops::submit_travel_rule::SubmitTravelRuleRequestBuilder,
+};
+use crate::types::{Blockchain, Stablecoin};
+
+/// Build the single-threaded runtime backing a blocking client
+fn runtime() -> CircleResult<Runtime> {
+    Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| CircleError::Config(format!("Failed to start blocking runtime: {}", e)))
+}
+
+/// Generate blocking counterparts of async client methods
+///
+/// Each entry is the async method's signature without `async` and without
+/// the `CircleResult` wrapper on its return type.
+macro_rules! blocking_methods {
+    ($(
+        $(#[$meta:meta])*
+        fn $name:ident(&self $(, $arg:ident: $ty:ty)*) -> $ret:ty;
+    )*) => {
+        $(
+            $(#[$meta])*
+            pub fn $name(&self $(, $arg: $ty)*) -> CircleResult<$ret> {
+                self.runtime.block_on(self.inner.$name($($arg),*))
+            }
+        )*
+    };
+}
+
+/// Blocking wrapper around [`CircleView`]
+pub struct CircleViewBlocking {
+    inner: CircleView,
+    runtime: Runtime,
+}
+
+impl CircleViewBlocking {
+    /// Create a blocking read client from the environment (see [`CircleView::new`])
+    pub fn new() -> CircleResult<Self> {
+        Self::from_view(CircleView::new()?)
+    }
+
+    /// Wrap an existing async client
+    pub fn from_view(view: CircleView) -> CircleResult<Self> {
+        Ok(Self {
+            inner: view,
+            runtime: runtime()?,
+        })
+    }
+
+    /// The wrapped async client
+    pub fn inner(&self) -> &CircleView {
+        &self.inner
+    }
+
+    /// Run a future that borrows the wrapped client to completion
+    pub fn block_on<'a, F, Fut>(&'a self, f: F) -> Fut::Output
+    where
+        F: FnOnce(&'a CircleView) -> Fut,
+        Fut: Future,
+    {
+        self.runtime.block_on(f(&self.inner))
+    }
+
+    /// Blocking [`CircleView::request`]
+    pub fn request<T, R>(&self, method: Method, path: &str, body: Option<&T>) -> CircleResult<R>
+    where
+        T: Serialize,
+        R: for<'de> serde::Deserialize<'de>,
+    {
+        self.runtime
+            .block_on(self.inner.request(method, path, body))
+    }
+
+    /// Blocking [`CircleView::request_with_meta`]
+    pub fn request_with_meta<T, R>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&T>,
+    ) -> CircleResult<WithMeta<R>>
+    where
+        T: Serialize,
+        R: for<'de> serde::Deserialize<'de>,
+    {
+        self.runtime
+            .block_on(self.inner.request_with_meta(method, path, body))
+    }
+
+    /// Blocking [`CircleView::request_with_params`]
+    pub fn request_with_params<T, R>(&self, path: &str, params: &T) -> CircleResult<R>
+    where
+        T: Serialize,
+        R: for<'de> serde::Deserialize<'de>,
+    {
+        self.runtime
+            .block_on(self.inner.request_with_params(path, params))
+    }
+
+    /// Blocking [`CircleView::get`]
+    pub fn get<R>(&self, path: &str) -> CircleResult<R>
+    where
+        R: for<'de> serde::Deserialize<'de>,
+    {
+        self.runtime.block_on(self.inner.get(path))
+    }
+
+    /// Blocking [`CircleView::get_plain`]
+    pub fn get_plain<R>(&self, path: &str) -> CircleResult<R>
+    where
+        R: for<'de> serde::Deserialize<'de>,
+    {
+        self.runtime.block_on(self.inner.get_plain(path))
+    }
+
+    /// Blocking [`CircleView::get_with_params`]
+    pub fn get_with_params<T, R>(&self, path: &str, params: &T) -> CircleResult<R>
+    where
+        T: Serialize,
+        R: for<'de> serde::Deserialize<'de>,
+    {
+        self.runtime
+            .block_on(self.inner.get_with_params(path, params))
+    }
+
+    /// Blocking [`CircleView::post`]
+    pub fn post<T, R>(&self, path: &str, body: &T) -> CircleResult<R>
+    where
+        T: Serialize,
+        R: for<'de> serde::Deserialize<'de>,
+    {
+        self.runtime.block_on(self.inner.post(path, body))
+    }
+
+    /// Blocking [`CircleView::put`]
+    pub fn put<T, R>(&self, path: &str, body: &T) -> CircleResult<R>
+    where
+        T: Serialize,
+        R: for<'de> serde::Deserialize<'de>,
+    {
+        self.runtime.block_on(self.inner.put(path, body))
+    }
+
+    /// Blocking [`CircleView::patch`]
+    pub fn patch<T, R>(&self, path: &str, body: &T) -> CircleResult<R>
+    where
+        T: Serialize,
+        R: for<'de> serde::Deserialize<'de>,
+    {
+        self.runtime.block_on(self.inner.patch(path, body))
+    }
+
+    /// Blocking [`CircleView::delete`]
+    pub fn delete<R>(&self, path: &str) -> CircleResult<R>
+    where
+        R: for<'de> serde::Deserialize<'de>,
+    {
+        self.runtime.block_on(self.inner.delete(path))
+    }
+
+    /// Blocking [`CircleView::list_tokens`]
+    pub fn list_tokens<'a>(
+        &self,
+        token_ids: impl IntoIterator<Item = &'a str>,
+    ) -> CircleResult<Vec<Token>> {
+        self.runtime.block_on(self.inner.list_tokens(token_ids))
+    }
+
+    /// Blocking [`CircleView::get_portfolio`]
+    pub fn get_portfolio<I, S>(&self, wallet_ids: I) -> CircleResult<Portfolio>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.runtime.block_on(self.inner.get_portfolio(wallet_ids))
+    }
+
+    /// Blocking [`CircleView::get_portfolio_in_usd`]
+    pub fn get_portfolio_in_usd<I, S>(&self, wallet_ids: I) -> CircleResult<Portfolio>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.runtime
+            .block_on(self.inner.get_portfolio_in_usd(wallet_ids))
+    }
+
+    blocking_methods! {
+        /// Blocking [`CircleView::get_raw`]
+        fn get_raw(&self, path: &str, query: &[(&str, &str)]) -> Value;
+        /// Blocking [`CircleView::delete_no_content`]
+        fn delete_no_content(&self, path: &str) -> ();
+
+        /// Blocking [`CircleView::list_wallets`]
+        fn list_wallets(&self, params: ListDevWalletsParams) -> Page<DevWallet>;
+        /// Blocking [`CircleView::list_wallets_with_token_balances`]
+        fn list_wallets_with_token_balances(&self, params: ListWalletsWithBalancesParams) -> Page<WalletWithBalances>;
+        /// Blocking [`CircleView::get_wallet`]
+        fn get_wallet(&self, wallet_id: &str) -> DevWalletResponse;
+        /// Blocking [`CircleView::get_token_balances`]
+        fn get_token_balances(&self, wallet_id: &str, params: QueryParams) -> TokenBalancesResponse;
+        /// Blocking [`CircleView::get_nfts`]
+        fn get_nfts(&self, wallet_id: &str, params: QueryParams) -> NftsResponse;
+        /// Blocking [`CircleView::list_transactions`]
+        fn list_transactions(&self, params: ListTransactionsParams) -> Page<Transaction>;
+        /// Blocking [`CircleView::get_transaction`]
+        fn get_transaction(&self, tx_id: &str) -> TransactionResponse;
+        /// Blocking [`CircleView::get_token`]
+        fn get_token(&self, token_id: &str) -> TokenResponse;
+        /// Blocking [`CircleView::get_public_key`]
+        fn get_public_key(&self) -> EntityPublicKeyResponse;
+        /// Blocking [`CircleView::validate_address`]
+        fn validate_address(&self, body: ValidateAddressBody) -> ValidateAddressResponse;
+        /// Blocking [`CircleView::estimate_contract_execution_fee`]
+        fn estimate_contract_execution_fee(&self, request: EstimateContractExecutionFeeBody) -> FeeEstimate;
+        /// Blocking [`CircleView::estimate_transfer_fee`]
+        fn estimate_transfer_fee(&self, request: EstimateTransferFeeRequest) -> FeeEstimate;
+        /// Blocking [`CircleView::estimate_transaction_acceleration_fee`]
+        fn estimate_transaction_acceleration_fee(&self, tx_id: &str) -> AccelerationFeeEstimate;
+        /// Blocking [`CircleView::request_testnet_tokens`]
+        fn request_testnet_tokens(&self, request: RequestTestnetTokensRequest) -> ();
+
+        /// Blocking [`CircleView::list_contracts`]
+        fn list_contracts(&self, params: Option<ListContractsParams>) -> Page<Contract>;
+        /// Blocking [`CircleView::get_contract`]
+        fn get_contract(&self, contract_id: &str) -> ContractResponse;
+        /// Blocking [`CircleView::update_contract`]
+        fn update_contract(&self, contract_id: &str, request: UpdateContractRequest) -> ContractResponse;
+        /// Blocking [`CircleView::estimate_contract_deployment_fee`]
+        fn estimate_contract_deployment_fee(&self, builder: EstimateContractDeploymentBodyBuilder) -> FeeEstimate;
+        /// Blocking [`CircleView::estimate_template_deployment_fee`]
+        fn estimate_template_deployment_fee(&self, builder: EstimateTemplateDeploymentFeeBodyBuilder) -> FeeEstimate;
+        /// Blocking [`CircleView::query_contract`]
+        fn query_contract(&self, builder: QueryContractViewBodyBuilder) -> QueryContractResponse;
+        /// Blocking [`CircleView::list_notification_subscriptions`]
+        fn list_notification_subscriptions(&self) -> Vec<NotificationSubscription>;
+        /// Blocking [`CircleView::get_notification`]
+        fn get_notification(&self, notification_id: &str) -> NotificationSubscription;
+        /// Blocking [`CircleView::get_notification_sig_pub_key`]
+        fn get_notification_sig_pub_key(&self, public_key: &str) -> String;
+        /// Blocking [`CircleView::create_notification_subscription`]
+        fn create_notification_subscription(&self, builder: CreateNotificationSubscriptionBodyBuilder) -> CreateNotificationSubscriptionResponse;
+        /// Blocking [`CircleView::update_notification_subscription`]
+        fn update_notification_subscription(&self, builder: UpdateNotificationSubscriptionBodyBuilder) -> UpdateNotificationSubscriptionResponse;
+        /// Blocking [`CircleView::delete_notification_subscription`]
+        fn delete_notification_subscription(&self, notification_id: &str) -> ();
+        /// Blocking [`CircleView::sync_notification_subscriptions`]
+        fn sync_notification_subscriptions(&self, desired: Vec<SubscriptionSpec>) -> SubscriptionSyncReport;
+        /// Blocking [`CircleView::get_ping`]
+        fn get_ping(&self) -> PingResponse;
+        /// Blocking [`CircleView::create_event_monitor`]
+        fn create_event_monitor(&self, builder: CreateEventMonitorBodyBuilder) -> EventMonitorResponse;
+        /// Blocking [`CircleView::update_event_monitor`]
+        fn update_event_monitor(&self, builder: UpdateEventMonitorBodyBuilder) -> EventMonitorResponse;
+        /// Blocking [`CircleView::delete_event_monitor`]
+        fn delete_event_monitor(&self, monitor_id: &str) -> ();
+        /// Blocking [`CircleView::list_event_monitors`]
+        fn list_event_monitors(&self, params: Option<ListEventMonitorsParams>) -> Page<EventMonitor>;
+        /// Blocking [`CircleView::list_event_logs`]
+        fn list_event_logs(&self, params: Option<ListEventLogsParams>) -> Page<EventLog>;
+
+        /// Blocking [`CircleView::get_business_balances`]
+        fn get_business_balances(&self) -> BusinessBalances;
+        /// Blocking [`CircleView::get_wire_bank_account`]
+        fn get_wire_bank_account(&self, bank_account_id: &str) -> WireBankAccount;
+        /// Blocking [`CircleView::get_wire_instructions`]
+        fn get_wire_instructions(&self, bank_account_id: &str, currency: Currency) -> WireInstructions;
+        /// Blocking [`CircleView::list_deposits`]
+        fn list_deposits(&self, params: ListPaymentsParams) -> Page<Deposit>;
+        /// Blocking [`CircleView::list_payouts`]
+        fn list_payouts(&self, params: ListPaymentsParams) -> Page<Payout>;
+        /// Blocking [`CircleView::get_payout`]
+        fn get_payout(&self, payout_id: &str) -> Payout;
+        /// Blocking [`CircleView::list_business_transfers`]
+        fn list_business_transfers(&self, params: ListPaymentsParams) -> Page<BusinessTransfer>;
+        /// Blocking [`CircleView::get_business_transfer`]
+        fn get_business_transfer(&self, transfer_id: &str) -> BusinessTransfer;
+
+        /// Blocking [`CircleView::get_ramp_session`]
+        fn get_ramp_session(&self, session_id: &str) -> RampSessionResponse;
+        /// Blocking [`CircleView::list_ramp_sessions`]
+        fn list_ramp_sessions(&self, params: ListRampSessionsParams) -> Page<RampSession>;
+
+        /// Blocking [`CircleView::get_exchange_quote`]
+        fn get_exchange_quote(&self, request: ExchangeQuoteRequest) -> ExchangeQuote;
+        /// Blocking [`CircleView::get_exchange_rate`]
+        fn get_exchange_rate(&self, pair: &CurrencyPair) -> ExchangeRate;
+
+        /// Blocking [`CircleView::get_travel_rule`]
+        fn get_travel_rule(&self, transaction_id: &str) -> TravelRuleResponse;
+    }
+}
+
+/// Blocking wrapper around [`CircleOps`]
+pub struct CircleOpsBlocking {
+    inner: CircleOps,
+    runtime: Runtime,
+}
+
+impl CircleOpsBlocking {
+    /// Create a blocking write client from the environment (see [`CircleOps::new`])
+    pub fn new(entity_secret: Option<String>) -> CircleResult<Self> {
+        Self::from_ops(CircleOps::new(entity_secret)?)
+    }
+
+    /// Create a blocking write client from the environment, fetching Circle's
+    /// public key if it is not configured (see [`CircleOps::bootstrap`])
+    pub fn bootstrap(entity_secret: Option<String>) -> CircleResult<Self> {
+        let runtime = runtime()?;
+        let inner = runtime.block_on(CircleOps::bootstrap(entity_secret))?;
+        Ok(Self { inner, runtime })
+    }
+
+    /// Create a blocking write client on a shared core, fetching Circle's
+    /// public key (see [`CircleOps::from_core_fetching_public_key`])
+    pub fn from_core_fetching_public_key(
+        core: &CircleHttpCore,
+        entity_secret: String,
+    ) -> CircleResult<Self> {
+        let runtime = runtime()?;
+        let inner = runtime.block_on(CircleOps::from_core_fetching_public_key(
+            core,
+            entity_secret,
+        ))?;
+        Ok(Self { inner, runtime })
+    }
+
+    /// Create a blocking write client on a shared core with the entity secret
+    /// from `secrets` (see [`CircleOps::from_secret_provider`])
+    pub fn from_secret_provider(
+        core: &CircleHttpCore,
+        secrets: &impl SecretProvider,
+        public_key: String,
+    ) -> CircleResult<Self> {
+        let runtime = runtime()?;
+        let inner = runtime.block_on(CircleOps::from_secret_provider(core, secrets, public_key))?;
+        Ok(Self { inner, runtime })
+    }
+
+    /// Wrap an existing async client
+    pub fn from_ops(ops: CircleOps) -> CircleResult<Self> {
+        Ok(Self {
+            inner: ops,
+            runtime: runtime()?,
+        })
+    }
+
+    /// The wrapped async client
+    pub fn inner(&self) -> &CircleOps {
+        &self.inner
+    }
+
+    /// Run a future that borrows the wrapped client to completion
+    pub fn block_on<'a, F, Fut>(&'a self, f: F) -> Fut::Output
+    where
+        F: FnOnce(&'a CircleOps) -> Fut,
+        Fut: Future,
+    {
+        self.runtime.block_on(f(&self.inner))
+    }
+
+    /// Blocking [`CircleOps::request`]
+    pub fn request<T, R>(&self, method: Method, path: &str, body: Option<&T>) -> CircleResult<R>
+    where
+        T: Serialize,
+        R: for<'de> serde::Deserialize<'de>,
+    {
+        self.runtime
+            .block_on(self.inner.request(method, path, body))
+    }
+
+    /// Blocking [`CircleOps::request_with_meta`]
+    pub fn request_with_meta<T, R>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&T>,
+    ) -> CircleResult<WithMeta<R>>
+    where
+        T: Serialize,
+        R: for<'de> serde::Deserialize<'de>,
+    {
+        self.runtime
+            .block_on(self.inner.request_with_meta(method, path, body))
+    }
+
+    /// Blocking [`CircleOps::post`]
+    pub fn post<T, R>(&self, path: &str, body: &T) -> CircleResult<R>
+    where
+        T: Serialize,
+        R: for<'de> serde::Deserialize<'de>,
+    {
+        self.runtime.block_on(self.inner.post(path, body))
+    }
+
+    /// Blocking [`CircleOps::put`]
+    pub fn put<T, R>(&self, path: &str, body: &T) -> CircleResult<R>
+    where
+        T: Serialize,
+        R: for<'de> serde::Deserialize<'de>,
+    {
+        self.runtime.block_on(self.inner.put(path, body))
+    }
+
+    /// Blocking [`CircleOps::patch`]
+    pub fn patch<T, R>(&self, path: &str, body: &T) -> CircleResult<R>
+    where
+        T: Serialize,
+        R: for<'de> serde::Deserialize<'de>,
+    {
+        self.runtime.block_on(self.inner.patch(path, body))
+    }
+
+    /// Blocking [`CircleOps::transfer_stablecoin`]
+    pub fn transfer_stablecoin(
+        &self,
+        wallet_id: &str,
+        blockchain: Blockchain,
+        stablecoin: Stablecoin,
+        destination_address: &str,
+        amount: impl Into<String>,
+    ) -> CircleResult<CreateTransferTransactionResponse> {
+        self.runtime.block_on(self.inner.transfer_stablecoin(
+            wallet_id,
+            blockchain,
+            stablecoin,
+            destination_address,
+            amount,
+        ))
+    }
+
+    /// Blocking [`CircleOps::transfer_usdc`]
+    pub fn transfer_usdc(
+        &self,
+        wallet_id: &str,
+        blockchain: Blockchain,
+        destination_address: &str,
+        amount: impl Into<String>,
+    ) -> CircleResult<CreateTransferTransactionResponse> {
+        self.runtime.block_on(self.inner.transfer_usdc(
+            wallet_id,
+            blockchain,
+            destination_address,
+            amount,
+        ))
+    }
+
+    /// Blocking [`CircleOps::transfer_eurc`]
+    pub fn transfer_eurc(
+        &self,
+        wallet_id: &str,
+        blockchain: Blockchain,
+        destination_address: &str,
+        amount: impl Into<String>,
+    ) -> CircleResult<CreateTransferTransactionResponse> {
+        self.runtime.block_on(self.inner.transfer_eurc(
+            wallet_id,
+            blockchain,
+            destination_address,
+            amount,
+        ))
+    }
+
+    /// Blocking [`CircleOps::execute_transfer_batch`]
+    pub fn execute_transfer_batch(&self, batch: TransferBatch) -> TransferBatchReport {
+        self.runtime
+            .block_on(self.inner.execute_transfer_batch(batch))
+    }
+
+    blocking_methods! {
+        /// Blocking [`CircleOps::resume_pending`]
+        fn resume_pending(&self) -> Vec<(PendingRequest, CircleResult<Value>)>;
+        /// Blocking [`CircleOps::post_raw`]
+        fn post_raw(&self, path: &str, body: Value) -> Value;
+
+        /// Blocking [`CircleOps::create_dev_wallet`]
+        fn create_dev_wallet(&self, builder: CreateDevWalletRequestBuilder) -> DevWalletsResponse;
+        /// Blocking [`CircleOps::create_dev_wallet_batch`]
+        fn create_dev_wallet_batch(&self, plan: &BatchWalletPlan) -> BatchWalletResult;
+        /// Blocking [`CircleOps::ensure_wallet`]
+        fn ensure_wallet(&self, wallet_set_id: &str, blockchain: &Blockchain, ref_id: &str, options: EnsureWalletOptions) -> DevWallet;
+        /// Blocking [`CircleOps::update_dev_wallet`]
+        fn update_dev_wallet(&self, wallet_id: &str, request: UpdateDevWalletRequest) -> DevWalletResponse;
+        /// Blocking [`CircleOps::dev_sign_message`]
+        fn dev_sign_message(&self, builder: SignMessageRequestBuilder) -> SignatureResponse;
+        /// Blocking [`CircleOps::dev_sign_data`]
+        fn dev_sign_data(&self, builder: SignDataRequestBuilder) -> SignatureResponse;
+        /// Blocking [`CircleOps::dev_sign_transaction`]
+        fn dev_sign_transaction(&self, builder: SignTransactionRequestBuilder) -> SignTransactionResponse;
+        /// Blocking [`CircleOps::dev_sign_delegate`]
+        fn dev_sign_delegate(&self, builder: SignDelegateRequestBuilder) -> SignDelegateResponse;
+        /// Blocking [`CircleOps::create_dev_transfer_transaction`]
+        fn create_dev_transfer_transaction(&self, builder: CreateTransferTransactionRequestBuilder) -> CreateTransferTransactionResponse;
+        /// Blocking [`CircleOps::create_transfer_with_estimate`]
+        fn create_transfer_with_estimate(&self, builder: CreateTransferTransactionRequestBuilder, selection: FeeSelection) -> TransferWithEstimate;
+        /// Blocking [`CircleOps::dev_query_contract`]
+        fn dev_query_contract(&self, request: QueryContractRequest) -> QueryContractResponse;
+        /// Blocking [`CircleOps::create_dev_contract_execution_transaction`]
+        fn create_dev_contract_execution_transaction(&self, builder: CreateContractExecutionTransactionRequestBuilder) -> CreateContractExecutionTransactionResponse;
+        /// Blocking [`CircleOps::create_dev_wallet_upgrade_transaction`]
+        fn create_dev_wallet_upgrade_transaction(&self, builder: CreateWalletUpgradeTransactionRequestBuilder) -> CreateWalletUpgradeTransactionResponse;
+        /// Blocking [`CircleOps::cancel_dev_transaction`]
+        fn cancel_dev_transaction(&self, builder: CancelTransactionRequestBuilder) -> CancelTransactionResponse;
+        /// Blocking [`CircleOps::accelerate_dev_transaction`]
+        fn accelerate_dev_transaction(&self, builder: AccelerateTransactionRequestBuilder) -> AccelerateTransactionResponse;
+
+        /// Blocking [`CircleOps::deploy_contract_from_template`]
+        fn deploy_contract_from_template(&self, builder: DeployContractFromTemplateRequestBuilder) -> TemplateContractDeploymentResponse;
+        /// Blocking [`CircleOps::deploy_contract`]
+        fn deploy_contract(&self, builder: DeployContractRequestBuilder) -> ContractDeploymentResponse;
+        /// Blocking [`CircleOps::deploy_contract_from_template_and_wait`]
+        fn deploy_contract_from_template_and_wait(&self, view: &CircleView, builder: DeployContractFromTemplateRequestBuilder, timeout: Duration) -> Vec<Contract>;
+        /// Blocking [`CircleOps::deploy_contract_and_wait`]
+        fn deploy_contract_and_wait(&self, view: &CircleView, builder: DeployContractRequestBuilder, timeout: Duration) -> Contract;
+        /// Blocking [`CircleOps::import_contract`]
+        fn import_contract(&self, builder: ImportContractRequestBuilder) -> ContractResponse;
+        /// Blocking [`CircleOps::archive_contract`]
+        fn archive_contract(&self, view: &CircleView, builder: ArchiveContractRequestBuilder) -> Contract;
+        /// Blocking [`CircleOps::unarchive_contract`]
+        fn unarchive_contract(&self, view: &CircleView, builder: ArchiveContractRequestBuilder) -> Contract;
+        /// Blocking [`CircleOps::update_contract_abi`]
+        fn update_contract_abi(&self, contract_id: &str, abi_json: &str) -> Contract;
+
+        /// Blocking [`CircleOps::create_wire_bank_account`]
+        fn create_wire_bank_account(&self, builder: CreateWireBankAccountRequestBuilder) -> WireBankAccount;
+        /// Blocking [`CircleOps::create_payout`]
+        fn create_payout(&self, builder: CreatePayoutRequestBuilder) -> Payout;
+        /// Blocking [`CircleOps::create_business_transfer`]
+        fn create_business_transfer(&self, builder: CreateBusinessTransferRequestBuilder) -> BusinessTransfer;
+
+        /// Blocking [`CircleOps::create_ramp_session`]
+        fn create_ramp_session(&self, builder: CreateRampSessionRequestBuilder) -> RampSessionResponse;
+
+        /// Blocking [`CircleOps::submit_travel_rule`]
+        fn submit_travel_rule(&self, builder: SubmitTravelRuleRequestBuilder) -> TravelRuleResponse;
+        /// Blocking [`CircleOps::create_dev_transfer_with_travel_rule`]
+        fn create_dev_transfer_with_travel_rule(&self, builder: CreateTransferTransactionRequestBuilder, originator: TravelRuleIdentity, beneficiary: TravelRuleIdentity) -> (CreateTransferTransactionResponse, TravelRuleResponse);
+
+        /// Blocking [`CircleOps::screen_address`]
+        fn screen_address(&self, builder: ScreenAddressRequestBuilder) -> AddressScreeningResponse;
+        /// Blocking [`CircleOps::screen_transfer_destination`]
+        fn screen_transfer_destination(&self, builder: &CreateTransferTransactionRequestBuilder) -> AddressScreeningResponse;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dev_wallet::dto::CreateTransferTransactionResponse;
+    use crate::testing::{fixtures, MockCircle};
+
+    #[test]
+    fn test_blocking_clients() {
+        let setup = runtime().unwrap();
+        let mut circle = setup.block_on(MockCircle::start());
+        let wallet = fixtures::dev_wallet();
+        let transfer = CreateTransferTransactionResponse {
+            id: fixtures::TRANSACTION_ID.to_string(),
+            state: "INITIATED".to_string(),
+        };
+        setup.block_on(async {
+            circle.mock_wallet(&wallet).await;
+            circle.mock_transfer(&transfer).await;
+        });
+
+        let view = CircleViewBlocking::from_view(circle.view()).unwrap();
+        assert_eq!(view.get_wallet(&wallet.id).unwrap().wallet.id, wallet.id);
+        let fetched = view.block_on(|view| view.get_wallet(&wallet.id)).unwrap();
+        assert_eq!(fetched.wallet.address, wallet.address);
+
+        let ops = CircleOpsBlocking::from_ops(circle.ops()).unwrap();
+        let response = ops
+            .create_dev_transfer_transaction(
+                CreateTransferTransactionRequestBuilder::new()
+                    .wallet_id(wallet.id.clone())
+                    .destination_address(fixtures::WALLET_ADDRESS.to_string())
+                    .amounts(["1"]),
+            )
+            .unwrap();
+        assert_eq!(response.id, fixtures::TRANSACTION_ID);
+    }
+}
@@ -0,0 +1,275 @@
+//! Broadcast Helper Functions
+//!
+//! This module submits transactions signed through Circle's sign transaction
+//! endpoint to a JSON-RPC node of your choice.
+
+use base64::{engine::general_purpose, Engine as _};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+
+use crate::evm::transaction::parse_signed_evm_transaction;
+use crate::helper::{CircleError, CircleResult};
+use crate::solana::{
+    dto::SolanaNetwork, handler::send_solana_transaction,
+    transaction::parse_signed_solana_transaction,
+};
+use crate::types::Blockchain;
+
+/// Call a JSON-RPC method and deserialize its `result`
+async fn rpc_call<T: DeserializeOwned>(
+    rpc_url: &str,
+    chain: &str,
+    method: &str,
+    params: Value,
+) -> CircleResult<T> {
+    let body = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": method,
+        "params": params,
+    });
+
+    let response: Value = reqwest::Client::new()
+        .post(rpc_url)
+        .json(&body)
+        .send()
+        .await?
+        .json()
+        .await?;
+
+    if let Some(error) = response.get("error") {
+        // NEAR nodes may omit `message` and name the error under `cause`
+        let message = error["message"]
+            .as_str()
+            .or_else(|| error["cause"]["name"].as_str())
+            .unwrap_or("unknown error");
+        return Err(CircleError::Api {
+            status: 500,
+            request_id: None,
+            message: format!("{} RPC error calling {}: {}", chain, method, message),
+        });
+    }
+
+    serde_json::from_value(response["result"].clone()).map_err(CircleError::Json)
+}
+
+/// Submit a signed EVM transaction with `eth_sendRawTransaction`
+///
+/// # Arguments
+/// * `signed_transaction` - Hex signed transaction from `SignTransactionResponse`
+/// * `rpc_url` - JSON-RPC endpoint of a node on the transaction's chain
+///
+/// # Returns
+/// * `String` - The transaction hash reported by the node
+pub async fn broadcast_evm_transaction(
+    signed_transaction: &str,
+    rpc_url: &str,
+) -> CircleResult<String> {
+    let signed = parse_signed_evm_transaction(signed_transaction)?;
+    rpc_call(
+        rpc_url,
+        "EVM",
+        "eth_sendRawTransaction",
+        json!([signed.raw_transaction]),
+    )
+    .await
+}
+
+/// Submit a signed Solana transaction with `sendTransaction`
+///
+/// # Arguments
+/// * `signed_transaction` - Base64 signed transaction from `SignTransactionResponse`
+/// * `rpc_url` - JSON-RPC endpoint of a Solana node
+///
+/// # Returns
+/// * `String` - The transaction signature (base58), which is the transaction ID
+pub async fn broadcast_solana_transaction(
+    signed_transaction: &str,
+    rpc_url: &str,
+) -> CircleResult<String> {
+    let signed = parse_signed_solana_transaction(signed_transaction)?;
+    send_solana_transaction(&signed, &SolanaNetwork::Custom(rpc_url.to_string())).await
+}
+
+/// Submit a signed NEAR transaction with `broadcast_tx_async`
+///
+/// # Arguments
+/// * `signed_transaction` - Base64 Borsh-encoded signed transaction from `SignTransactionResponse`
+/// * `rpc_url` - JSON-RPC endpoint of a NEAR node
+///
+/// # Returns
+/// * `String` - The transaction hash (base58)
+pub async fn broadcast_near_transaction(
+    signed_transaction: &str,
+    rpc_url: &str,
+) -> CircleResult<String> {
+    let signed_transaction = signed_transaction.trim();
+    general_purpose::STANDARD
+        .decode(signed_transaction)
+        .map_err(|e| CircleError::Config(format!("Invalid base64 NEAR transaction: {}", e)))?;
+
+    rpc_call(
+        rpc_url,
+        "NEAR",
+        "broadcast_tx_async",
+        json!([signed_transaction]),
+    )
+    .await
+}
+
+/// Submit a transaction signed by Circle for a wallet on `blockchain`
+///
+/// Dispatches to the EVM, Solana or NEAR broadcast helper based on the
+/// blockchain family.
+///
+/// # Arguments
+/// * `blockchain` - Blockchain of the signing wallet
+/// * `signed_transaction` - `signed_transaction` from `SignTransactionResponse`
+/// * `rpc_url` - JSON-RPC endpoint of a node on that blockchain
+///
+/// # Returns
+/// * `String` - The transaction hash (the signature for Solana)
+///
+/// # Errors
+///
+/// Returns [`CircleError::Config`] for blockchains without a broadcast
+/// helper (Aptos and unrecognized identifiers) or malformed transactions,
+/// and [`CircleError::Api`] if the node rejects the transaction.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use inf_circle_sdk::broadcast::broadcast_signed_transaction;
+/// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+/// use inf_circle_sdk::dev_wallet::ops::sign_transaction::SignTransactionRequestBuilder;
+/// use inf_circle_sdk::types::Blockchain;
+///
+/// # async fn example(builder: SignTransactionRequestBuilder) -> Result<(), Box<dyn std::error::Error>> {
+/// let ops = CircleOps::new(None)?;
+/// let response = ops.dev_sign_transaction(builder).await?;
+///
+/// let tx_hash = broadcast_signed_transaction(
+///     &Blockchain::EthSepolia,
+///     &response.signed_transaction,
+///     "https://ethereum-sepolia-rpc.publicnode.com",
+/// ).await?;
+/// println!("Broadcast: {}", tx_hash);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn broadcast_signed_transaction(
+    blockchain: &Blockchain,
+    signed_transaction: &str,
+    rpc_url: &str,
+) -> CircleResult<String> {
+    match blockchain {
+        Blockchain::Sol | Blockchain::SolDevnet => {
+            broadcast_solana_transaction(signed_transaction, rpc_url).await
+        }
+        Blockchain::Near | Blockchain::NearTestnet => {
+            broadcast_near_transaction(signed_transaction, rpc_url).await
+        }
+        Blockchain::Aptos | Blockchain::AptosTestnet | Blockchain::Other(_) => Err(
+            CircleError::Config(format!("Broadcasting is not supported for {}", blockchain)),
+        ),
+        _ => broadcast_evm_transaction(signed_transaction, rpc_url).await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Matcher;
+
+    /// EIP-155 example transaction (nonce 9, 1 ether to 0x3535...35 on mainnet)
+    const EVM_SIGNED: &str = concat!(
+        "0xf86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a7640000",
+        "8025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aec",
+        "b703304b3800ccf555c9f3dc64214b297fb1966a3b6d83",
+    );
+    const EVM_HASH: &str = "0x33469b22e9f636356c4160a87eb19df52b7412e8eac32a4a55ffe88ea8350788";
+
+    #[tokio::test]
+    async fn test_broadcast_evm_transaction() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/")
+            .match_body(Matcher::PartialJson(json!({
+                "method": "eth_sendRawTransaction",
+                "params": [EVM_SIGNED],
+            })))
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "jsonrpc": "2.0", "id": 1, "result": EVM_HASH }).to_string())
+            .create_async()
+            .await;
+
+        let tx_hash =
+            broadcast_signed_transaction(&Blockchain::EthSepolia, EVM_SIGNED, &server.url())
+                .await
+                .unwrap();
+        assert_eq!(tx_hash, EVM_HASH);
+        mock.assert_async().await;
+
+        assert!(matches!(
+            broadcast_evm_transaction("0x1234", &server.url()).await,
+            Err(CircleError::Config(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_near_transaction() {
+        let mut server = mockito::Server::new_async().await;
+        let signed = general_purpose::STANDARD.encode([1u8, 2, 3]);
+        let mock = server
+            .mock("POST", "/")
+            .match_body(Matcher::PartialJson(json!({
+                "method": "broadcast_tx_async",
+                "params": [signed],
+            })))
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": 1,
+                    "result": "6zgh2u9DqHHiXzdy9ouTP7oGky2T4nugqzqt9wJZwNFm"
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let tx_hash =
+            broadcast_signed_transaction(&Blockchain::NearTestnet, &signed, &server.url())
+                .await
+                .unwrap();
+        assert_eq!(tx_hash, "6zgh2u9DqHHiXzdy9ouTP7oGky2T4nugqzqt9wJZwNFm");
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_rejections() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", "/")
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": 1,
+                    "error": { "code": -32000, "message": "nonce too low" }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let result = broadcast_evm_transaction(EVM_SIGNED, &server.url()).await;
+        assert!(
+            matches!(result, Err(CircleError::Api { message, .. }) if message.contains("nonce too low"))
+        );
+
+        let result =
+            broadcast_signed_transaction(&Blockchain::AptosTestnet, "0x00", &server.url()).await;
+        assert!(matches!(result, Err(CircleError::Config(_))));
+    }
+}
@@ -0,0 +1,48 @@
+//! Signed Transaction Broadcasting
+//!
+//! Circle's sign transaction endpoint returns a signed transaction but does
+//! not submit it. This module broadcasts signed EVM, Solana and NEAR
+//! transactions through a JSON-RPC endpoint you supply, handling each chain's
+//! encoding, and returns the transaction hash.
+//!
+//! # Main Components
+//!
+//! - [`handler`]: Per-chain broadcast helpers and [`broadcast_signed_transaction`]
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::{
+//!     broadcast::broadcast_signed_transaction,
+//!     circle_ops::circler_ops::CircleOps,
+//!     solana::{dto::SolanaNetwork, get_solana_latest_blockhash, system_transfer, SolanaTransactionBuilder},
+//!     types::Blockchain,
+//! };
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let ops = CircleOps::new(None)?;
+//! let rpc_url = "https://api.devnet.solana.com";
+//! let wallet = "4Nd1mBQtrMJVYVfKf2PJy9NZUZdTAsp7D4xWLs4gDB4T".parse()?;
+//! let recipient = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM".parse()?;
+//!
+//! let request = SolanaTransactionBuilder::new(wallet)
+//!     .recent_blockhash(get_solana_latest_blockhash(&SolanaNetwork::Devnet).await?)
+//!     .instruction(system_transfer(wallet, recipient, 1_000_000))
+//!     .into_sign_request("wallet-id".to_string())?;
+//! let response = ops.dev_sign_transaction(request).await?;
+//!
+//! let signature =
+//!     broadcast_signed_transaction(&Blockchain::SolDevnet, &response.signed_transaction, rpc_url)
+//!         .await?;
+//! println!("Submitted: {}", signature);
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod handler;
+
+// Re-export commonly used items
+pub use handler::{
+    broadcast_evm_transaction, broadcast_near_transaction, broadcast_signed_transaction,
+    broadcast_solana_transaction,
+};
@@ -0,0 +1,205 @@
+//! Entity secret ciphertext generation
+//!
+//! Every write request carries a fresh `entitySecretCiphertext`: Circle rejects
+//! a ciphertext it has already seen. `CircleOps` asks its
+//! [`CiphertextProvider`] for one per request. The default,
+//! [`RsaCiphertextProvider`], encrypts the entity secret with RSA-OAEP inline.
+//!
+//! [`CiphertextPool`] wraps any provider with a buffer of pre-generated
+//! ciphertexts, so bursts of writes don't pay the encryption latency on the
+//! request path. Each pooled ciphertext is handed out once. Implement the trait
+//! yourself to produce ciphertexts elsewhere, for example in an HSM.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//! use inf_circle_sdk::circle_ops::ciphertext::{CiphertextPool, RsaCiphertextProvider};
+//! use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let pool = CiphertextPool::new(
+//!     RsaCiphertextProvider::new(
+//!         std::env::var("CIRCLE_ENTITY_SECRET")?,
+//!         std::env::var("CIRCLE_PUBLIC_KEY")?,
+//!     ),
+//!     64,
+//! );
+//! pool.fill()?;
+//!
+//! // Top the pool up between bursts
+//! let refill = pool.clone();
+//! tokio::spawn(async move {
+//!     loop {
+//!         tokio::time::sleep(Duration::from_millis(500)).await;
+//!         let refill = refill.clone();
+//!         let _ = tokio::task::spawn_blocking(move || refill.fill()).await;
+//!     }
+//! });
+//!
+//! let ops = CircleOps::new(None)?.with_ciphertext_provider(pool);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use zeroize::Zeroizing;
+
+use crate::circle_ops::secret::SecretProvider;
+use crate::helper::{encrypt_entity_secret, CircleError, CircleResult};
+
+/// Source of entity secret ciphertexts for write requests
+///
+/// Every call must return a ciphertext that has not been returned before.
+pub trait CiphertextProvider: Send + Sync {
+    /// A fresh base64-encoded entity secret ciphertext
+    fn ciphertext(&self) -> CircleResult<String>;
+}
+
+/// Encrypts the entity secret with Circle's RSA public key on every call
+#[derive(Clone)]
+pub struct RsaCiphertextProvider {
+    entity_secret: Zeroizing<String>,
+    public_key: String,
+}
+
+impl RsaCiphertextProvider {
+    /// Create a provider from the hex-encoded entity secret and Circle's PEM public key
+    pub fn new(entity_secret: String, public_key: String) -> Self {
+        Self {
+            entity_secret: Zeroizing::new(entity_secret),
+            public_key,
+        }
+    }
+
+    /// Create a provider with the entity secret fetched once from `secrets`
+    pub async fn from_secret_provider(
+        secrets: &impl SecretProvider,
+        public_key: String,
+    ) -> CircleResult<Self> {
+        let secret = secrets.entity_secret().await?;
+        Ok(Self {
+            entity_secret: Zeroizing::new(hex::encode(&*secret)),
+            public_key,
+        })
+    }
+}
+
+impl CiphertextProvider for RsaCiphertextProvider {
+    fn ciphertext(&self) -> CircleResult<String> {
+        encrypt_entity_secret(&self.entity_secret, &self.public_key)
+            .map_err(|e| CircleError::Config(format!("Failed to encrypt entity secret: {}", e)))
+    }
+}
+
+/// Buffer of pre-generated ciphertexts in front of another provider
+///
+/// [`fill`](Self::fill) generates ciphertexts until the pool holds `capacity`
+/// of them; [`ciphertext`](CiphertextProvider::ciphertext) takes the oldest,
+/// and falls back to the wrapped provider when the pool is empty. Clones share
+/// the same buffer.
+#[derive(Clone)]
+pub struct CiphertextPool {
+    provider: Arc<dyn CiphertextProvider>,
+    ready: Arc<Mutex<VecDeque<String>>>,
+    capacity: usize,
+}
+
+impl CiphertextPool {
+    /// Create an empty pool holding up to `capacity` ciphertexts from `provider`
+    pub fn new(provider: impl CiphertextProvider + 'static, capacity: usize) -> Self {
+        Self {
+            provider: Arc::new(provider),
+            ready: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))),
+            capacity,
+        }
+    }
+
+    /// Generate ciphertexts until the pool is full, returning how many were added
+    ///
+    /// Encryption runs without holding the pool's lock, so requests keep
+    /// drawing from the pool while it is being filled. This call blocks for
+    /// the duration of the encryption; run it on a blocking thread from async
+    /// code.
+    pub fn fill(&self) -> CircleResult<usize> {
+        let mut added = 0;
+        while self.len() < self.capacity {
+            let ciphertext = self.provider.ciphertext()?;
+            self.ready()?.push_back(ciphertext);
+            added += 1;
+        }
+        Ok(added)
+    }
+
+    /// Number of ciphertexts ready to hand out
+    pub fn len(&self) -> usize {
+        self.ready.lock().map_or(0, |ready| ready.len())
+    }
+
+    /// Whether no ciphertext is ready
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Maximum number of ciphertexts the pool holds
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    fn ready(&self) -> CircleResult<std::sync::MutexGuard<'_, VecDeque<String>>> {
+        self.ready
+            .lock()
+            .map_err(|_| CircleError::Config("Ciphertext pool lock poisoned".to_string()))
+    }
+}
+
+impl CiphertextProvider for CiphertextPool {
+    fn ciphertext(&self) -> CircleResult<String> {
+        let pooled = self.ready()?.pop_front();
+        match pooled {
+            Some(ciphertext) => Ok(ciphertext),
+            None => self.provider.ciphertext(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{MOCK_ENTITY_SECRET, MOCK_PUBLIC_KEY};
+    use std::collections::HashSet;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct Counting(AtomicUsize);
+
+    impl CiphertextProvider for Counting {
+        fn ciphertext(&self) -> CircleResult<String> {
+            Ok(format!("ct-{}", self.0.fetch_add(1, Ordering::SeqCst)))
+        }
+    }
+
+    #[test]
+    fn test_pool_hands_out_each_ciphertext_once() {
+        let pool = CiphertextPool::new(Counting(AtomicUsize::new(0)), 3);
+        assert_eq!(pool.fill().unwrap(), 3);
+        assert_eq!(pool.fill().unwrap(), 0);
+
+        let drawn: Vec<_> = (0..5).map(|_| pool.ciphertext().unwrap()).collect();
+        assert_eq!(drawn, vec!["ct-0", "ct-1", "ct-2", "ct-3", "ct-4"]);
+        assert!(pool.is_empty());
+        assert_eq!(pool.fill().unwrap(), 3);
+    }
+
+    #[test]
+    fn test_rsa_provider_produces_unique_ciphertexts() {
+        let provider =
+            RsaCiphertextProvider::new(MOCK_ENTITY_SECRET.to_string(), MOCK_PUBLIC_KEY.to_string());
+        let ciphertexts: HashSet<_> = (0..3).map(|_| provider.ciphertext().unwrap()).collect();
+        assert_eq!(ciphertexts.len(), 3);
+
+        let invalid = RsaCiphertextProvider::new(String::new(), String::new());
+        assert!(matches!(invalid.ciphertext(), Err(CircleError::Config(_))));
+    }
+}
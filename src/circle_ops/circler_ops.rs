@@ -27,19 +27,36 @@
 //! ```
 
 use crate::{
-    encrypt_entity_secret,
-    helper::{get_env_var, CircleResult, HttpClient},
+    audit::{AuditRecord, Exporter},
+    circle_ops::{
+        ciphertext::{CiphertextProvider, RsaCiphertextProvider},
+        dry_run::DryRunLog,
+        idempotency::{is_settled, IdempotencyStore, PendingRequest},
+        maintenance::MaintenancePolicy,
+        secret::SecretProvider,
+    },
+    circle_view::circle_view::CircleView,
+    helper::{
+        environment::Environment, generate_uuid, get_env_var, http_core::CircleHttpCore,
+        metrics::MetricsRecorder, rate_limit::RateLimiter, sleep, CircleResult, HttpClient,
+        Instant, ResponseMeta, WithMeta, ENTITY_SECRET_PLACEHOLDER,
+    },
     CircleError,
 };
 use reqwest::Method;
 use serde::Serialize;
+use serde_json::Value;
+use std::{sync::Arc, time::Duration};
 
 /// CircleOps handles write operations (POST, PUT, PATCH) with entity secret authentication
 #[derive(Clone)]
 pub struct CircleOps {
     client: HttpClient,
-    entity_secret: String,
-    public_key: String,
+    ciphertexts: Arc<dyn CiphertextProvider>,
+    audit_exporter: Option<Exporter>,
+    maintenance_policy: Option<MaintenancePolicy>,
+    idempotency_store: Option<Arc<dyn IdempotencyStore>>,
+    dry_run: Option<DryRunLog>,
 }
 
 impl CircleOps {
@@ -49,13 +66,15 @@ impl CircleOps {
     /// environment variables:
     /// - `CIRCLE_API_KEY`: Your Circle API key (always required from environment)
     /// - `CIRCLE_BASE_URL`: Circle API base URL (e.g., https://api.circle.com)
+    /// - `CIRCLE_ENVIRONMENT`: `sandbox`, `production` or a URL; overrides `CIRCLE_BASE_URL`
+    ///   (see [`Environment::from_env`])
     /// - `CIRCLE_ENTITY_SECRET`: Hex-encoded entity secret for request signing (used if `entity_secret` parameter is `None`)
     /// - `CIRCLE_PUBLIC_KEY`: RSA public key in PEM format for encryption
     ///
     /// # Arguments
     ///
     /// * `entity_secret` - Optional entity secret. If `None`, reads from `CIRCLE_ENTITY_SECRET` environment variable.
-    ///                    If `Some(secret)`, uses the provided entity secret instead of the environment variable.
+    ///   If `Some(secret)`, uses the provided entity secret instead of the environment variable.
     ///
     /// # Returns
     ///
@@ -84,26 +103,408 @@ impl CircleOps {
     /// # }
     /// ```
     pub fn new(entity_secret: Option<String>) -> CircleResult<Self> {
-        dotenv::dotenv().ok(); // Load .env file if present
+        let (environment, api_key, entity_secret) = env_config(entity_secret)?;
+        let public_key = get_env_var("CIRCLE_PUBLIC_KEY")?;
 
-        let api_key = get_env_var("CIRCLE_API_KEY")?;
-        let base_url = get_env_var("CIRCLE_BASE_URL")?;
+        Ok(Self::with_environment(
+            environment,
+            api_key,
+            entity_secret,
+            public_key,
+        ))
+    }
 
-        let entity_secret = if let Some(entity_secret) = entity_secret {
-            entity_secret
-        } else {
-            get_env_var("CIRCLE_ENTITY_SECRET")?
-        };
+    /// Create a new CircleOps instance, fetching Circle's public key if it is not configured
+    ///
+    /// Reads the same environment variables as [`CircleOps::new`], except that
+    /// `CIRCLE_PUBLIC_KEY` is optional: when it is unset, the key is fetched
+    /// once with [`CircleView::get_public_key`] before the client is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a required environment variable is missing, or if
+    /// the public key has to be fetched and the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// // Only CIRCLE_API_KEY, CIRCLE_ENVIRONMENT and CIRCLE_ENTITY_SECRET are set
+    /// let ops = CircleOps::bootstrap(None).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn bootstrap(entity_secret: Option<String>) -> CircleResult<Self> {
+        let (environment, api_key, entity_secret) = env_config(entity_secret)?;
+        let core = CircleHttpCore::new(environment, api_key);
 
-        let public_key = get_env_var("CIRCLE_PUBLIC_KEY")?;
+        match get_env_var("CIRCLE_PUBLIC_KEY") {
+            Ok(public_key) => Ok(Self::from_core(&core, entity_secret, public_key)),
+            Err(_) => Self::from_core_fetching_public_key(&core, entity_secret).await,
+        }
+    }
 
-        let client = HttpClient::with_api_key(&base_url, api_key)?;
+    /// Create a CircleOps on a shared [`CircleHttpCore`] with Circle's public key fetched from the API
+    ///
+    /// # Arguments
+    ///
+    /// * `core` - The shared HTTP core
+    /// * `entity_secret` - Hex-encoded entity secret
+    pub async fn from_core_fetching_public_key(
+        core: &CircleHttpCore,
+        entity_secret: String,
+    ) -> CircleResult<Self> {
+        let public_key = CircleView::from_core(core).get_public_key().await?;
+        Ok(Self::from_core(core, entity_secret, public_key.public_key))
+    }
 
-        Ok(Self {
-            client,
+    /// Create a CircleOps for an [`Environment`] from explicit configuration
+    ///
+    /// Reads neither `.env` nor the process environment. In the sandbox,
+    /// request bodies naming a mainnet blockchain are refused; see
+    /// [`CircleOps::allow_mainnet_on_sandbox`].
+    ///
+    /// # Arguments
+    ///
+    /// * `environment` - Sandbox, production or a custom base URL
+    /// * `api_key` - Your Circle API key
+    /// * `entity_secret` - Hex-encoded entity secret
+    /// * `public_key` - Circle's RSA public key in PEM format
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+    /// use inf_circle_sdk::helper::environment::Environment;
+    ///
+    /// let ops = CircleOps::with_environment(
+    ///     Environment::Sandbox,
+    ///     "TEST_API_KEY:abc:def".to_string(),
+    ///     "entity-secret-hex".to_string(),
+    ///     "public-key-pem".to_string(),
+    /// );
+    /// assert!(ops.environment().is_sandbox());
+    /// ```
+    pub fn with_environment(
+        environment: Environment,
+        api_key: String,
+        entity_secret: String,
+        public_key: String,
+    ) -> Self {
+        Self::from_core(
+            &CircleHttpCore::new(environment, api_key),
             entity_secret,
             public_key,
-        })
+        )
+    }
+
+    /// Create a CircleOps on a shared [`CircleHttpCore`]
+    ///
+    /// The client reuses the core's connection pool, so a `CircleView` built
+    /// from the same core adds no connections of its own.
+    ///
+    /// # Arguments
+    ///
+    /// * `core` - The shared HTTP core
+    /// * `entity_secret` - Hex-encoded entity secret
+    /// * `public_key` - Circle's RSA public key in PEM format
+    pub fn from_core(core: &CircleHttpCore, entity_secret: String, public_key: String) -> Self {
+        Self {
+            client: core.http_client(),
+            ciphertexts: Arc::new(RsaCiphertextProvider::new(entity_secret, public_key)),
+            audit_exporter: None,
+            maintenance_policy: None,
+            idempotency_store: None,
+            dry_run: None,
+        }
+    }
+
+    /// Create a CircleOps on a shared [`CircleHttpCore`] with the entity secret from `secrets`
+    ///
+    /// The secret is fetched once, here; see [`secret`](crate::circle_ops::secret)
+    /// for the environment, AWS KMS and Vault providers.
+    pub async fn from_secret_provider(
+        core: &CircleHttpCore,
+        secrets: &impl SecretProvider,
+        public_key: String,
+    ) -> CircleResult<Self> {
+        let provider = RsaCiphertextProvider::from_secret_provider(secrets, public_key).await?;
+        Ok(Self::from_core(core, String::new(), String::new()).with_ciphertext_provider(provider))
+    }
+
+    /// Produce entity secret ciphertexts with `provider` instead of encrypting inline
+    ///
+    /// See [`ciphertext`](crate::circle_ops::ciphertext) for a pre-generating
+    /// pool and for plugging in an HSM.
+    pub fn with_ciphertext_provider(mut self, provider: impl CiphertextProvider + 'static) -> Self {
+        self.ciphertexts = Arc::new(provider);
+        self
+    }
+
+    /// The HTTP core behind this client, for building a `CircleView` that shares it
+    pub fn core(&self) -> CircleHttpCore {
+        CircleHttpCore::from_http_client(self.client.clone())
+    }
+
+    /// The environment this client talks to
+    pub fn environment(&self) -> &Environment {
+        self.client.environment()
+    }
+
+    /// Throttle this client's requests through a [`RateLimiter`]
+    ///
+    /// Pass clones of one limiter to several clients to throttle them
+    /// together, or attach it to a shared
+    /// [`CircleHttpCore`](CircleHttpCore::with_rate_limiter).
+    pub fn with_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.client = self.client.rate_limiter(limiter);
+        self
+    }
+
+    /// Report each of this client's requests to a [`MetricsRecorder`]
+    ///
+    /// The recorder is called with the endpoint template, status code and
+    /// latency of every call; see [`metrics`](crate::helper::metrics).
+    pub fn with_metrics_recorder(mut self, recorder: Arc<dyn MetricsRecorder>) -> Self {
+        self.client = self.client.metrics_recorder(recorder);
+        self
+    }
+
+    /// Abandon each of this client's requests that takes longer than `timeout`
+    ///
+    /// The timeout covers one HTTP exchange, from sending the request to
+    /// reading the response body; time spent waiting on a rate limiter or a
+    /// maintenance policy is not counted. A timed-out call fails with a
+    /// `CircleError::Http` for which [`CircleError::is_timeout`] is true.
+    /// To bound a single call end to end instead, wrap it in
+    /// [`with_timeout`](crate::helper::with_timeout).
+    ///
+    /// A write that timed out (or whose future was dropped) may still have
+    /// reached Circle. Resubmit it with the same idempotency key, or let an
+    /// [idempotency store](Self::with_idempotency_store) replay it; see
+    /// [cancellation](crate::helper::with_timeout#cancellation).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+    /// use inf_circle_sdk::dev_wallet::ops::create_transfer_transaction::CreateTransferTransactionRequestBuilder;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ops = CircleOps::new(None)?;
+    /// let transfer = CreateTransferTransactionRequestBuilder::new()
+    ///     .wallet_id("wallet-id".to_string())
+    ///     .token_id("usdc-token-id".to_string())
+    ///     .destination_address("0xRecipient".to_string())
+    ///     .amounts(["1"])
+    ///     .idempotency_key("transfer-42".to_string())
+    ///     .build();
+    ///
+    /// match ops
+    ///     .clone()
+    ///     .with_request_timeout(Duration::from_secs(5))
+    ///     .create_dev_transfer_transaction(transfer.clone())
+    ///     .await
+    /// {
+    ///     Err(error) if error.is_timeout() => {
+    ///         // Same idempotency key, so Circle creates the transfer at most once
+    ///         ops.create_dev_transfer_transaction(transfer).await?;
+    ///     }
+    ///     result => {
+    ///         result?;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.client = self.client.request_timeout(timeout);
+        self
+    }
+
+    /// Check this client's responses against the vendored Circle schemas
+    ///
+    /// Off by default. With it on, a response whose fields do not match
+    /// Circle's published schema fails with `CircleError::Schema`; see
+    /// [`schema`](crate::schema).
+    #[cfg(feature = "schema-validation")]
+    pub fn with_schema_validation(mut self) -> Self {
+        self.client = self.client.validate_schemas(true);
+        self
+    }
+
+    /// Allow mainnet blockchains in request bodies sent to the sandbox
+    ///
+    /// By default a sandbox client fails such requests with
+    /// `CircleError::Config` before sending them.
+    pub fn allow_mainnet_on_sandbox(mut self) -> Self {
+        self.client.allow_mainnet_on_sandbox();
+        self
+    }
+
+    /// Build an instance from an existing client and key material
+    #[cfg(any(test, feature = "testing"))]
+    pub(crate) fn from_parts(
+        client: HttpClient,
+        entity_secret: String,
+        public_key: String,
+    ) -> Self {
+        Self {
+            client,
+            ciphertexts: Arc::new(RsaCiphertextProvider::new(entity_secret, public_key)),
+            audit_exporter: None,
+            maintenance_policy: None,
+            idempotency_store: None,
+            dry_run: None,
+        }
+    }
+
+    /// Attach an audit exporter
+    ///
+    /// Once attached, every write operation appends one NDJSON record to the
+    /// exporter, whether the operation succeeds or fails. See [`crate::audit`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::audit::Exporter;
+    /// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ops = CircleOps::new(None)?.with_audit_exporter(Exporter::new(std::io::stdout()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_audit_exporter(mut self, exporter: Exporter) -> Self {
+        self.audit_exporter = Some(exporter);
+        self
+    }
+
+    /// Queue writes during Circle maintenance windows
+    ///
+    /// Without a policy, a 503 response fails the call immediately with
+    /// `CircleError::Maintenance`. With one, the write waits for the window to
+    /// end (bounded by the policy) and is resubmitted with the same
+    /// idempotency key. Held writes are resubmitted one at a time in the
+    /// order they were queued. See [`crate::circle_ops::maintenance`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_ops::{circler_ops::CircleOps, maintenance::MaintenancePolicy};
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ops = CircleOps::new(None)?.with_maintenance_policy(MaintenancePolicy::new(50));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_maintenance_policy(mut self, policy: MaintenancePolicy) -> Self {
+        self.maintenance_policy = Some(policy);
+        self
+    }
+
+    /// Persist in-flight writes so they can be resumed after a crash
+    ///
+    /// Every request carrying an idempotency key is recorded in the store
+    /// before it is sent and marked complete once Circle accepts or rejects
+    /// it. Requests whose outcome is unknown (network failure, 429, 5xx) stay
+    /// pending; call [`resume_pending`](Self::resume_pending) on startup to
+    /// resubmit them. See [`crate::circle_ops::idempotency`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_ops::{circler_ops::CircleOps, idempotency::FileIdempotencyStore};
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ops = CircleOps::new(None)?
+    ///     .with_idempotency_store(FileIdempotencyStore::open("circle-pending.jsonl")?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_idempotency_store(mut self, store: impl IdempotencyStore + 'static) -> Self {
+        self.idempotency_store = Some(Arc::new(store));
+        self
+    }
+
+    /// Build and record write requests instead of sending them
+    ///
+    /// While enabled, POST, PUT, PATCH and DELETE requests are validated and
+    /// serialized but not sent; they are captured in the [`DryRunLog`]
+    /// returned by [`dry_run_log`](Self::dry_run_log) and answered with a
+    /// synthesized response. Audit exporters and idempotency stores are not
+    /// touched. See [`crate::circle_ops::dry_run`].
+    pub fn dry_run(mut self, enabled: bool) -> Self {
+        self.dry_run = enabled.then(|| self.dry_run.take().unwrap_or_default());
+        self
+    }
+
+    /// The requests captured in dry-run mode; `None` when dry run is off
+    pub fn dry_run_log(&self) -> Option<&DryRunLog> {
+        self.dry_run.as_ref()
+    }
+
+    /// Resubmit every request left pending in the idempotency store
+    ///
+    /// Each request is sent again with its original idempotency key and a
+    /// freshly encrypted entity secret, so Circle returns the existing
+    /// resource instead of executing the operation twice.
+    ///
+    /// # Returns
+    ///
+    /// Returns each pending request with the outcome of its resubmission.
+    /// Without an idempotency store the list is empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store cannot be read. Failures of individual
+    /// requests are reported in the returned list.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_ops::{circler_ops::CircleOps, idempotency::InMemoryIdempotencyStore};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ops = CircleOps::new(None)?.with_idempotency_store(InMemoryIdempotencyStore::new());
+    ///
+    /// for (request, result) in ops.resume_pending().await? {
+    ///     if let Err(e) = result {
+    ///         eprintln!("{} is still pending: {}", request.idempotency_key, e);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn resume_pending(&self) -> CircleResult<Vec<(PendingRequest, CircleResult<Value>)>> {
+        let Some(store) = &self.idempotency_store else {
+            return Ok(Vec::new());
+        };
+
+        let mut results = Vec::new();
+        for pending in store.pending()? {
+            let result = self.resubmit(&pending).await;
+            results.push((pending, result));
+        }
+        Ok(results)
+    }
+
+    async fn resubmit(&self, pending: &PendingRequest) -> CircleResult<Value> {
+        let method = Method::from_bytes(pending.method.as_bytes())
+            .map_err(|_| CircleError::Config(format!("Invalid HTTP method: {}", pending.method)))?;
+
+        let mut payload = pending.payload.clone();
+        if let Some(ciphertext) = payload.get_mut("entitySecretCiphertext") {
+            if ciphertext == ENTITY_SECRET_PLACEHOLDER {
+                *ciphertext = Value::String(self.entity_secret()?);
+            }
+        }
+
+        self.request(method, &pending.endpoint, Some(&payload))
+            .await
     }
 
     /// Generic request method for write operations
@@ -111,6 +512,13 @@ impl CircleOps {
     /// This is an internal helper method used by other methods in this struct.
     /// Typically, you should use the specific methods like `post`, `put`, or `patch` instead.
     ///
+    /// If an audit exporter is attached, a record is written after the request
+    /// completes. A failure to write the record is returned as an error even if
+    /// the operation itself succeeded; retrying with the same idempotency key is safe.
+    ///
+    /// If an idempotency store is attached, the request is recorded before it
+    /// is sent and marked complete once its outcome is known.
+    ///
     /// # Arguments
     ///
     /// * `method` - HTTP method (POST, PUT, PATCH)
@@ -145,13 +553,140 @@ impl CircleOps {
         T: Serialize,
         R: for<'de> serde::Deserialize<'de>,
     {
-        let mut request = self.client.request(method, path)?;
+        Ok(self.request_with_meta(method, path, body).await?.data)
+    }
+
+    /// Like [`request`](Self::request), but also returns the response's status and request ID
+    ///
+    /// The meta carries Circle's request ID, which Circle support asks for
+    /// when investigating a specific call. Failed calls carry it in
+    /// [`CircleError::request_id`].
+    pub async fn request_with_meta<T, R>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&T>,
+    ) -> CircleResult<WithMeta<R>>
+    where
+        T: Serialize,
+        R: for<'de> serde::Deserialize<'de>,
+    {
+        let payload = body.map(serde_json::to_value).transpose()?;
+        if let Some(payload) = &payload {
+            self.client.check_payload(payload)?;
+        }
 
-        if let Some(body) = body {
-            request = request.json(body);
+        if let Some(log) = &self.dry_run {
+            if method != Method::GET {
+                return Ok(WithMeta {
+                    data: log.capture(method.as_str(), path, payload.as_ref())?,
+                    meta: ResponseMeta::default(),
+                });
+            }
         }
 
-        self.client.execute(request).await
+        let pending = match (&self.idempotency_store, &payload) {
+            (Some(store), Some(payload)) => {
+                let pending = PendingRequest::new(method.as_str(), path, payload);
+                if let Some(pending) = &pending {
+                    store.begin(pending)?;
+                }
+                pending
+            }
+            _ => None,
+        };
+
+        // Decode through serde_json::Value so the response id and state can be audited
+        let result = self.send::<Value>(&method, path, payload.clone()).await;
+        let meta = result.as_ref().ok().map(|response| response.meta.clone());
+        let result = result.map(|response| response.data);
+
+        if let Some(exporter) = &self.audit_exporter {
+            exporter.record(&AuditRecord::new(
+                method.as_str(),
+                path,
+                payload.as_ref(),
+                &result,
+            ))?;
+        }
+        if let (Some(store), Some(pending)) = (&self.idempotency_store, &pending) {
+            if is_settled(&result) {
+                store.complete(&pending.idempotency_key)?;
+            }
+        }
+
+        Ok(WithMeta {
+            data: serde_json::from_value(result?)?,
+            meta: meta.unwrap_or_default(),
+        })
+    }
+
+    /// Send a write request, holding it through maintenance windows if a policy is set
+    async fn send<R>(
+        &self,
+        method: &Method,
+        path: &str,
+        mut payload: Option<Value>,
+    ) -> CircleResult<WithMeta<R>>
+    where
+        R: for<'de> serde::Deserialize<'de>,
+    {
+        let mut ticket = None;
+        let mut started = None;
+
+        // Writes already held for a window go first, so queue behind them
+        if let Some(policy) = &self.maintenance_policy {
+            if policy.queued() > 0 {
+                let now = *started.insert(Instant::now());
+                let (joined, _) = policy
+                    .join(now)
+                    .await
+                    .ok_or(CircleError::Maintenance { retry_after: None })?;
+                ticket = Some(joined);
+            }
+        }
+
+        loop {
+            let mut request = self.client.request(method.clone(), path)?;
+            if let Some(payload) = &payload {
+                request = request.json(payload);
+            }
+
+            let retry_after = match self.client.execute_with_meta(request).await {
+                Err(CircleError::Maintenance { retry_after }) => retry_after,
+                result => return result,
+            };
+
+            let Some(policy) = &self.maintenance_policy else {
+                return Err(CircleError::Maintenance { retry_after });
+            };
+            let started = *started.get_or_insert_with(Instant::now);
+
+            // The writes ahead of this one already waited out the window
+            let mut waited_for_turn = false;
+            if ticket.is_none() {
+                let (joined, waited) = policy
+                    .join(started)
+                    .await
+                    .ok_or(CircleError::Maintenance { retry_after })?;
+                ticket = Some(joined);
+                waited_for_turn = waited;
+            }
+            if !waited_for_turn {
+                let Some(delay) = policy.next_delay(retry_after, started.elapsed()) else {
+                    return Err(CircleError::Maintenance { retry_after });
+                };
+                sleep(delay).await;
+            }
+
+            // Circle rejects reused ciphertexts, so every attempt needs a fresh one
+            if let Some(ciphertext) = payload
+                .as_mut()
+                .and_then(|payload| payload.get_mut("entitySecretCiphertext"))
+            {
+                *ciphertext = Value::String(self.entity_secret()?);
+            }
+        }
     }
 
     /// POST request helper
@@ -256,11 +791,59 @@ impl CircleOps {
         self.request(Method::PATCH, path, Some(body)).await
     }
 
+    /// POST arbitrary JSON to an endpoint the SDK does not model yet
+    ///
+    /// A fresh `entitySecretCiphertext` is set on the body, and an
+    /// `idempotencyKey` is generated if the body has none. Everything else
+    /// (authentication, the sandbox mainnet guard, dry-run, auditing and
+    /// maintenance handling) applies as for typed calls. Returns the response's
+    /// `data` field.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Config` if `body` is not a JSON object.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ops = CircleOps::new(None)?;
+    ///
+    /// let response = ops
+    ///     .post_raw(
+    ///         "/v1/w3s/developer/some-new-endpoint",
+    ///         serde_json::json!({ "walletId": "wallet-id" }),
+    ///     )
+    ///     .await?;
+    /// println!("{}", response);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn post_raw(&self, path: &str, mut body: Value) -> CircleResult<Value> {
+        let Some(fields) = body.as_object_mut() else {
+            return Err(CircleError::Config(
+                "Raw request body must be a JSON object".to_string(),
+            ));
+        };
+        fields.insert(
+            "entitySecretCiphertext".to_string(),
+            Value::String(self.entity_secret()?),
+        );
+        fields
+            .entry("idempotencyKey")
+            .or_insert_with(|| Value::String(generate_uuid()));
+
+        self.post(path, &body).await
+    }
+
     /// Get encrypted entity secret
     ///
     /// Encrypts the entity secret using RSA-OAEP with SHA-256 and returns the ciphertext.
     /// This is used internally by write operations to authenticate requests.
-    /// A fresh encryption is generated each time this method is called.
+    /// A fresh ciphertext is returned each time this method is called, taken from
+    /// the [ciphertext provider](Self::with_ciphertext_provider) if one is set.
     ///
     /// # Returns
     ///
@@ -285,9 +868,290 @@ impl CircleOps {
     /// # }
     /// ```
     pub fn entity_secret(&self) -> CircleResult<String> {
-        let entity_secret_ciphertext = encrypt_entity_secret(&self.entity_secret, &self.public_key)
-            .map_err(|e| CircleError::Config(format!("Failed to encrypt entity secret: {}", e)))?;
+        self.ciphertexts.ciphertext()
+    }
+}
+
+/// Environment, API key and entity secret from `.env` and the process environment
+fn env_config(entity_secret: Option<String>) -> CircleResult<(Environment, String, String)> {
+    dotenv::dotenv().ok(); // Load .env file if present
+
+    let api_key = get_env_var("CIRCLE_API_KEY")?;
+    let environment = Environment::from_env()?;
+
+    let entity_secret = if let Some(entity_secret) = entity_secret {
+        entity_secret
+    } else {
+        get_env_var("CIRCLE_ENTITY_SECRET")?
+    };
+    Ok((environment, api_key, entity_secret))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::circle_ops::maintenance::MIN_RETRY_DELAY;
+    use serde_json::json;
+
+    fn ops_for(server: &mockito::Server) -> CircleOps {
+        CircleOps::from_parts(
+            HttpClient::with_api_key(&server.url(), "test-key".to_string()).unwrap(),
+            String::new(),
+            String::new(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_sandbox_refuses_mainnet_blockchains() {
+        let ops = CircleOps::with_environment(
+            Environment::Sandbox,
+            "test-key".to_string(),
+            String::new(),
+            String::new(),
+        );
+        let body = json!({"idempotencyKey": "k", "blockchain": "ETH"});
+
+        let result: CircleResult<Value> = ops.post("/v1/w3s/developer/wallets", &body).await;
+        assert!(matches!(result, Err(CircleError::Config(message)) if message.contains("ETH")));
+
+        let ops = ops.allow_mainnet_on_sandbox();
+        assert!(ops.client.check_payload(&body).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_raw_requests() {
+        let mut circle = crate::testing::MockCircle::start().await;
+        let post = circle
+            .server()
+            .mock("POST", "/v1/w3s/developer/new-endpoint")
+            .match_body(mockito::Matcher::PartialJson(
+                json!({"walletId": "wallet-1"}),
+            ))
+            .with_status(201)
+            .with_body_from_request(|request| {
+                let body: Value = serde_json::from_slice(request.body().unwrap()).unwrap();
+                json!({ "data": body }).to_string().into()
+            })
+            .create_async()
+            .await;
+        let get = circle
+            .server()
+            .mock("GET", "/v1/w3s/new-resource")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("pageSize".into(), "5".into()),
+                mockito::Matcher::UrlEncoded("blockchain".into(), "ETH-SEPOLIA".into()),
+            ]))
+            .with_status(200)
+            .with_body(r#"{"data":{"items":[]}}"#)
+            .create_async()
+            .await;
+
+        let sent = circle
+            .ops()
+            .post_raw(
+                "/v1/w3s/developer/new-endpoint",
+                json!({"walletId": "wallet-1"}),
+            )
+            .await
+            .unwrap();
+        assert!(sent["entitySecretCiphertext"]
+            .as_str()
+            .is_some_and(|c| !c.is_empty()));
+        assert!(sent["idempotencyKey"].is_string());
+
+        let not_object = circle.ops().post_raw("/v1/w3s/x", json!([1])).await;
+        assert!(matches!(not_object, Err(CircleError::Config(_))));
+
+        let fetched = circle
+            .view()
+            .get_raw(
+                "/v1/w3s/new-resource",
+                &[("pageSize", "5"), ("blockchain", "ETH-SEPOLIA")],
+            )
+            .await
+            .unwrap();
+        assert_eq!(fetched, json!({"items": []}));
+
+        post.assert_async().await;
+        get.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_maintenance_surfaces_without_policy() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", "/v1/w3s/transactions/transfer")
+            .with_status(503)
+            .with_header("retry-after", "120")
+            .create_async()
+            .await;
+
+        let result: CircleResult<Value> = ops_for(&server)
+            .post(
+                "/v1/w3s/transactions/transfer",
+                &json!({"idempotencyKey": "k"}),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(CircleError::Maintenance {
+                retry_after: Some(delay)
+            }) if delay == Duration::from_secs(120)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_maintenance_policy_resubmits_after_window() {
+        let mut server = mockito::Server::new_async().await;
+        let unavailable = server
+            .mock("POST", "/v1/w3s/transactions/transfer")
+            .with_status(503)
+            .with_header("retry-after", "0")
+            .expect(1)
+            .create_async()
+            .await;
+        let available = server
+            .mock("POST", "/v1/w3s/transactions/transfer")
+            .match_body(mockito::Matcher::PartialJson(
+                json!({"idempotencyKey": "k"}),
+            ))
+            .with_status(201)
+            .with_body(r#"{"data":{"id":"tx-1","state":"INITIATED"}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let policy = MaintenancePolicy::new(1);
+        let ops = ops_for(&server).with_maintenance_policy(policy.clone());
+        let started = std::time::Instant::now();
+        let response: Value = ops
+            .post(
+                "/v1/w3s/transactions/transfer",
+                &json!({"idempotencyKey": "k"}),
+            )
+            .await
+            .unwrap();
+
+        // `Retry-After: 0` must not resubmit in a tight loop
+        assert!(started.elapsed() >= MIN_RETRY_DELAY);
+        assert_eq!(response["id"], "tx-1");
+        assert_eq!(policy.queued(), 0);
+        unavailable.assert_async().await;
+        available.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_idempotency_store_resumes_unsettled_writes() {
+        let mut server = mockito::Server::new_async().await;
+        let failed = server
+            .mock("POST", "/v1/w3s/transactions/transfer")
+            .with_status(500)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let ops = ops_for(&server).with_idempotency_store(
+            crate::circle_ops::idempotency::InMemoryIdempotencyStore::new(),
+        );
+        let result: CircleResult<Value> = ops
+            .post(
+                "/v1/w3s/transactions/transfer",
+                &json!({"idempotencyKey": "k", "amounts": ["1"]}),
+            )
+            .await;
+        assert!(result.is_err());
+        failed.assert_async().await;
+        failed.remove_async().await;
+
+        let accepted = server
+            .mock("POST", "/v1/w3s/transactions/transfer")
+            .match_body(mockito::Matcher::PartialJson(
+                json!({"idempotencyKey": "k", "amounts": ["1"]}),
+            ))
+            .with_status(201)
+            .with_body(r#"{"data":{"id":"tx-1","state":"INITIATED"}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let resumed = ops.resume_pending().await.unwrap();
+        assert_eq!(resumed.len(), 1);
+        assert_eq!(resumed[0].0.idempotency_key, "k");
+        assert_eq!(resumed[0].1.as_ref().unwrap()["id"], "tx-1");
+        accepted.assert_async().await;
+
+        assert!(ops.resume_pending().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dropped_write_stays_pending() {
+        let mut server = mockito::Server::new_async().await;
+        let slow = server
+            .mock("POST", "/v1/w3s/transactions/transfer")
+            .with_status(201)
+            .with_body_from_request(|_| {
+                std::thread::sleep(Duration::from_millis(300));
+                br#"{"data":{"id":"tx-1","state":"INITIATED"}}"#.to_vec()
+            })
+            .expect(1)
+            .create_async()
+            .await;
+
+        let ops = ops_for(&server).with_idempotency_store(
+            crate::circle_ops::idempotency::InMemoryIdempotencyStore::new(),
+        );
+        let result: CircleResult<Value> = crate::helper::with_timeout(
+            Duration::from_millis(50),
+            ops.post(
+                "/v1/w3s/transactions/transfer",
+                &json!({"idempotencyKey": "k", "amounts": ["1"]}),
+            ),
+        )
+        .await;
+        assert!(result.unwrap_err().is_timeout());
+        // The abandoned request still reached Circle
+        slow.assert_async().await;
+        slow.remove_async().await;
+
+        let replayed = server
+            .mock("POST", "/v1/w3s/transactions/transfer")
+            .match_body(mockito::Matcher::PartialJson(
+                json!({"idempotencyKey": "k"}),
+            ))
+            .with_status(201)
+            .with_body(r#"{"data":{"id":"tx-1","state":"INITIATED"}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let resumed = ops.resume_pending().await.unwrap();
+        assert_eq!(resumed.len(), 1);
+        assert_eq!(resumed[0].1.as_ref().unwrap()["id"], "tx-1");
+        replayed.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_from_core_fetching_public_key() {
+        use crate::testing::{MockCircle, MOCK_ENTITY_SECRET, MOCK_PUBLIC_KEY};
+
+        let mut circle = MockCircle::start().await;
+        let key = circle
+            .respond(
+                "GET",
+                "/v1/w3s/config/entity/publicKey",
+                &json!({ "publicKey": MOCK_PUBLIC_KEY }),
+            )
+            .await;
 
-        Ok(entity_secret_ciphertext)
+        let ops = CircleOps::from_core_fetching_public_key(
+            &circle.ops().core(),
+            MOCK_ENTITY_SECRET.to_string(),
+        )
+        .await
+        .unwrap();
+        key.assert_async().await;
+        // The fetched key encrypts the entity secret
+        assert!(ops.ciphertexts.ciphertext().is_ok());
     }
 }
@@ -0,0 +1,202 @@
+//! Dry-run mode for write operations
+//!
+//! With [`CircleOps::dry_run`](crate::circle_ops::circler_ops::CircleOps::dry_run)
+//! enabled, write requests are built, validated and serialized as usual (the
+//! entity secret is encrypted and the sandbox mainnet guard applies), but
+//! instead of being sent they are recorded in a [`DryRunLog`] and answered
+//! with a synthesized response. CI pipelines can exercise transaction
+//! construction without moving funds or consuming idempotency keys.
+//!
+//! Synthesized responses carry a random `id` and the state `INITIATED`, which
+//! covers transfers, contract executions, deployments and the other
+//! transaction-creating calls. Calls whose response has a different shape
+//! (wallet creation, signing) fail with `CircleError::Config` in dry-run mode.
+//! GET requests are still sent.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+//! use inf_circle_sdk::dev_wallet::dto::FeeLevel;
+//! use inf_circle_sdk::dev_wallet::ops::create_transfer_transaction::CreateTransferTransactionRequestBuilder;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let ops = CircleOps::new(None)?.dry_run(true);
+//!
+//! let transfer = CreateTransferTransactionRequestBuilder::new()
+//!     .wallet_id("wallet-id".to_string())
+//!     .token_id("usdc-token-id".to_string())
+//!     .destination_address("0xRecipient".to_string())
+//!     .amounts(["1"])
+//!     .fee_level(FeeLevel::Medium)
+//!     .build();
+//! let response = ops.create_dev_transfer_transaction(transfer).await?;
+//! println!("would have created {}", response.id);
+//!
+//! for request in ops.dry_run_log().unwrap().requests() {
+//!     println!("{} {} {}", request.method, request.path, request.payload);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::helper::{generate_uuid, CircleError, CircleResult, ENTITY_SECRET_PLACEHOLDER};
+
+/// A write request that dry-run mode did not send
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DryRunRequest {
+    /// HTTP method (POST, PUT, PATCH, DELETE)
+    pub method: String,
+
+    /// API endpoint path
+    pub path: String,
+
+    /// Request body, with `entitySecretCiphertext` replaced by [`ENTITY_SECRET_PLACEHOLDER`]
+    pub payload: Value,
+
+    /// Idempotency key the request carried, if any
+    pub idempotency_key: Option<String>,
+}
+
+/// Write requests captured in dry-run mode
+///
+/// Cheap to clone; clones (and `CircleOps` clones) share the same log.
+#[derive(Debug, Clone, Default)]
+pub struct DryRunLog {
+    requests: Arc<Mutex<Vec<DryRunRequest>>>,
+}
+
+impl DryRunLog {
+    /// Create an empty log
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests captured so far, oldest first
+    pub fn requests(&self) -> Vec<DryRunRequest> {
+        self.requests
+            .lock()
+            .map(|requests| requests.clone())
+            .unwrap_or_default()
+    }
+
+    /// Remove and return the captured requests
+    pub fn take(&self) -> Vec<DryRunRequest> {
+        self.requests
+            .lock()
+            .map(|mut requests| std::mem::take(&mut *requests))
+            .unwrap_or_default()
+    }
+
+    /// Number of captured requests
+    pub fn len(&self) -> usize {
+        self.requests.lock().map_or(0, |requests| requests.len())
+    }
+
+    /// Whether no request has been captured
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Record a request and synthesize the response Circle would have sent
+    pub(crate) fn capture<R>(
+        &self,
+        method: &str,
+        path: &str,
+        payload: Option<&Value>,
+    ) -> CircleResult<R>
+    where
+        R: for<'de> Deserialize<'de>,
+    {
+        let mut payload = payload.cloned().unwrap_or(Value::Null);
+        if let Some(ciphertext) = payload.get_mut("entitySecretCiphertext") {
+            *ciphertext = Value::String(ENTITY_SECRET_PLACEHOLDER.to_string());
+        }
+        let idempotency_key = payload
+            .get("idempotencyKey")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+
+        if let Ok(mut requests) = self.requests.lock() {
+            requests.push(DryRunRequest {
+                method: method.to_string(),
+                path: path.to_string(),
+                payload,
+                idempotency_key,
+            });
+        }
+
+        synthesize(method, path)
+    }
+}
+
+/// Build a placeholder response, trying the shapes Circle's write endpoints return
+fn synthesize<R>(method: &str, path: &str) -> CircleResult<R>
+where
+    R: for<'de> Deserialize<'de>,
+{
+    let candidates = [
+        json!({ "id": generate_uuid(), "state": "INITIATED" }),
+        json!({}),
+        Value::Null,
+    ];
+    candidates
+        .into_iter()
+        .find_map(|candidate| serde_json::from_value(candidate).ok())
+        .ok_or_else(|| {
+            CircleError::Config(format!(
+                "Dry run cannot synthesize a response for {} {}",
+                method, path
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dev_wallet::dto::{CreateTransferTransactionResponse, DevWalletsResponse};
+    use crate::testing::MockCircle;
+
+    #[tokio::test]
+    async fn test_dry_run_captures_writes_without_sending() {
+        let mut circle = MockCircle::start().await;
+        let mock = circle
+            .server()
+            .mock("POST", mockito::Matcher::Any)
+            .expect(0)
+            .create_async()
+            .await;
+
+        let ops = circle.ops().dry_run(true);
+        let body = json!({
+            "idempotencyKey": "key-1",
+            "entitySecretCiphertext": "c2VjcmV0",
+            "walletId": "wallet-1",
+        });
+        let response: CreateTransferTransactionResponse = ops
+            .post("/v1/w3s/developer/transactions/transfer", &body)
+            .await
+            .unwrap();
+        assert_eq!(response.state, "INITIATED");
+
+        let wallets: CircleResult<DevWalletsResponse> =
+            ops.post("/v1/w3s/developer/wallets", &body).await;
+        assert!(matches!(wallets, Err(CircleError::Config(_))));
+
+        let requests = ops.dry_run_log().unwrap().take();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].idempotency_key.as_deref(), Some("key-1"));
+        assert_eq!(
+            requests[0].payload["entitySecretCiphertext"],
+            ENTITY_SECRET_PLACEHOLDER
+        );
+        assert!(ops.dry_run_log().unwrap().is_empty());
+        mock.assert_async().await;
+    }
+}
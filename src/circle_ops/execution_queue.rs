@@ -0,0 +1,424 @@
+//! Ordered contract executions
+//!
+//! Submitting several executions from the same wallet to the same contract
+//! concurrently lets them race for nonces and land on-chain in an arbitrary
+//! order. An [`ExecutionQueue`] runs executions one at a time per
+//! (wallet, contract) lane, in the order they were submitted, while executions
+//! on different lanes still run in parallel.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//! use inf_circle_sdk::circle_ops::{circler_ops::CircleOps, execution_queue::ExecutionQueue};
+//! use inf_circle_sdk::circle_view::circle_view::CircleView;
+//! use inf_circle_sdk::dev_wallet::dto::AbiParameter;
+//! use inf_circle_sdk::dev_wallet::ops::create_contract_transaction::CreateContractExecutionTransactionRequestBuilder;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let queue = ExecutionQueue::new(CircleOps::new(None)?).wait_until_sent(
+//!     CircleView::new()?,
+//!     Duration::from_secs(2),
+//!     Duration::from_secs(120),
+//! );
+//!
+//! let mut handles = Vec::new();
+//! for token_id in 1..=3 {
+//!     let queue = queue.clone();
+//!     handles.push(tokio::spawn(async move {
+//!         let mint = CreateContractExecutionTransactionRequestBuilder::new(
+//!             "wallet-id".to_string(),
+//!             "0xNftContract".to_string(),
+//!         )
+//!         .abi_function_signature("mint(uint256)".to_string())
+//!         .abi_parameters(vec![AbiParameter::String(token_id.to_string())]);
+//!         queue.execute(mint).await
+//!     }));
+//! }
+//! for handle in handles {
+//!     handle.await??;
+//! }
+//!
+//! for (lane, stats) in queue.stats() {
+//!     println!("{}: {} submitted, avg wait {:?}", lane, stats.submitted, stats.average_wait());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::circle_ops::circler_ops::CircleOps;
+use crate::circle_view::circle_view::CircleView;
+use crate::dev_wallet::dto::CreateContractExecutionTransactionResponse;
+use crate::dev_wallet::ops::create_contract_transaction::CreateContractExecutionTransactionRequestBuilder;
+use crate::helper::{sleep, CircleResult, Instant};
+
+/// Transaction states at which Circle has assigned a nonce (or given up)
+const SENT_OR_LATER: &[&str] = &[
+    "SENT",
+    "CONFIRMED",
+    "COMPLETE",
+    "FAILED",
+    "CANCELLED",
+    "DENIED",
+];
+
+/// A (wallet, contract) pair whose executions are serialized
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LaneKey {
+    /// Wallet executing the calls
+    pub wallet_id: String,
+
+    /// Contract being called (lowercased)
+    pub contract_address: String,
+}
+
+impl LaneKey {
+    fn new(wallet_id: &str, contract_address: &str) -> Self {
+        Self {
+            wallet_id: wallet_id.to_string(),
+            contract_address: contract_address.to_lowercase(),
+        }
+    }
+}
+
+impl fmt::Display for LaneKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.wallet_id, self.contract_address)
+    }
+}
+
+/// Queue metrics for one lane
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LaneStats {
+    /// Executions currently waiting for their turn
+    pub queued: usize,
+
+    /// Whether an execution is currently in progress
+    pub executing: bool,
+
+    /// Executions accepted by Circle
+    pub submitted: u64,
+
+    /// Executions rejected by Circle or that failed to send
+    pub failed: u64,
+
+    /// Executions whose transaction did not reach `SENT` before the settle timeout
+    pub settle_timeouts: u64,
+
+    /// Total time executions spent waiting for their turn
+    pub total_wait: Duration,
+
+    /// Longest time an execution waited for its turn
+    pub max_wait: Duration,
+
+    /// Total time from an execution's turn starting to its lane being released
+    pub total_latency: Duration,
+
+    /// Longest time from an execution's turn starting to its lane being released
+    pub max_latency: Duration,
+}
+
+impl LaneStats {
+    /// Average time an execution waited for its turn
+    pub fn average_wait(&self) -> Option<Duration> {
+        self.average(self.total_wait)
+    }
+
+    /// Average time from an execution's turn starting to its lane being released
+    pub fn average_latency(&self) -> Option<Duration> {
+        self.average(self.total_latency)
+    }
+
+    fn average(&self, total: Duration) -> Option<Duration> {
+        let count = (self.submitted + self.failed) as u32;
+        (count > 0).then(|| total / count)
+    }
+}
+
+#[derive(Default)]
+struct Lane {
+    turn: tokio::sync::Mutex<()>,
+    stats: Mutex<LaneStats>,
+}
+
+impl Lane {
+    fn update(&self, f: impl FnOnce(&mut LaneStats)) {
+        if let Ok(mut stats) = self.stats.lock() {
+            f(&mut stats);
+        }
+    }
+}
+
+/// Removes an execution from its lane's queue count, even if it is cancelled while waiting
+struct Waiting<'a>(&'a Lane);
+
+impl Drop for Waiting<'_> {
+    fn drop(&mut self) {
+        self.0.update(|stats| stats.queued -= 1);
+    }
+}
+
+#[derive(Clone)]
+struct SettlePolicy {
+    view: CircleView,
+    poll_interval: Duration,
+    timeout: Duration,
+}
+
+/// Serializes contract executions per (wallet, contract)
+///
+/// Executions on the same lane are submitted in FIFO order, one at a time.
+/// By default a lane is released as soon as Circle accepts the execution;
+/// with [`wait_until_sent`](Self::wait_until_sent) it is held until the
+/// transaction has been broadcast, so each call gets its nonce before the
+/// next one is submitted. The queue is cheap to clone; clones share lanes.
+#[derive(Clone)]
+pub struct ExecutionQueue {
+    ops: CircleOps,
+    settle: Option<SettlePolicy>,
+    lanes: Arc<Mutex<HashMap<LaneKey, Arc<Lane>>>>,
+}
+
+impl ExecutionQueue {
+    /// Create a queue that submits executions through `ops`
+    pub fn new(ops: CircleOps) -> Self {
+        Self {
+            ops,
+            settle: None,
+            lanes: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Hold each lane until the submitted transaction reaches `SENT` (or a final state)
+    ///
+    /// The transaction is polled through `view` every `poll_interval`. If it
+    /// has not been sent after `timeout`, the lane is released anyway and the
+    /// lane's `settle_timeouts` counter is incremented.
+    pub fn wait_until_sent(
+        mut self,
+        view: CircleView,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Self {
+        self.settle = Some(SettlePolicy {
+            view,
+            poll_interval,
+            timeout,
+        });
+        self
+    }
+
+    /// Submit a contract execution once all earlier executions on its lane are done
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from `CircleOps::create_dev_contract_execution_transaction`.
+    /// A failed execution releases the lane for the next one.
+    pub async fn execute(
+        &self,
+        builder: CreateContractExecutionTransactionRequestBuilder,
+    ) -> CircleResult<CreateContractExecutionTransactionResponse> {
+        let lane = self.lane(LaneKey::new(&builder.wallet_id, &builder.contract_address));
+
+        let enqueued = Instant::now();
+        lane.update(|stats| stats.queued += 1);
+        let waiting = Waiting(&lane);
+        let _turn = lane.turn.lock().await;
+        drop(waiting);
+        let wait = enqueued.elapsed();
+        lane.update(|stats| {
+            stats.executing = true;
+            stats.total_wait += wait;
+            stats.max_wait = stats.max_wait.max(wait);
+        });
+
+        let started = Instant::now();
+        let result = self
+            .ops
+            .create_dev_contract_execution_transaction(builder)
+            .await;
+        let settled = match (&result, &self.settle) {
+            (Ok(response), Some(policy)) => Self::wait_for_sent(policy, &response.id).await,
+            _ => true,
+        };
+        let latency = started.elapsed();
+
+        lane.update(|stats| {
+            stats.executing = false;
+            if result.is_ok() {
+                stats.submitted += 1;
+            } else {
+                stats.failed += 1;
+            }
+            if !settled {
+                stats.settle_timeouts += 1;
+            }
+            stats.total_latency += latency;
+            stats.max_latency = stats.max_latency.max(latency);
+        });
+
+        result
+    }
+
+    /// Metrics for one lane, if it has been used
+    pub fn lane_stats(&self, wallet_id: &str, contract_address: &str) -> Option<LaneStats> {
+        let key = LaneKey::new(wallet_id, contract_address);
+        let lanes = self.lanes.lock().ok()?;
+        let stats = lanes.get(&key)?.stats.lock().ok()?.clone();
+        Some(stats)
+    }
+
+    /// Metrics for every lane, ordered by lane
+    pub fn stats(&self) -> Vec<(LaneKey, LaneStats)> {
+        let Ok(lanes) = self.lanes.lock() else {
+            return Vec::new();
+        };
+        let mut stats: Vec<_> = lanes
+            .iter()
+            .filter_map(|(key, lane)| Some((key.clone(), lane.stats.lock().ok()?.clone())))
+            .collect();
+        stats.sort_by(|a, b| a.0.cmp(&b.0));
+        stats
+    }
+
+    /// Executions waiting across all lanes
+    pub fn queue_depth(&self) -> usize {
+        self.stats().iter().map(|(_, stats)| stats.queued).sum()
+    }
+
+    fn lane(&self, key: LaneKey) -> Arc<Lane> {
+        let mut lanes = self.lanes.lock().unwrap_or_else(|e| e.into_inner());
+        lanes.entry(key).or_default().clone()
+    }
+
+    /// Poll until the transaction is sent; `false` if the timeout passed first
+    async fn wait_for_sent(policy: &SettlePolicy, transaction_id: &str) -> bool {
+        let deadline = Instant::now() + policy.timeout;
+        loop {
+            if let Ok(response) = policy.view.get_transaction(transaction_id).await {
+                if SENT_OR_LATER.contains(&response.transaction.state.as_str()) {
+                    return true;
+                }
+            }
+            if Instant::now() + policy.poll_interval > deadline {
+                return false;
+            }
+            sleep(policy.poll_interval).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{fixtures, MockCircle};
+    use serde_json::{json, Value};
+
+    const EXECUTION_PATH: &str = "/v1/w3s/developer/transactions/contractExecution";
+
+    fn call(wallet_id: &str, n: u32) -> CreateContractExecutionTransactionRequestBuilder {
+        CreateContractExecutionTransactionRequestBuilder::new(
+            wallet_id.to_string(),
+            "0xContract".to_string(),
+        )
+        .call_data("0x".to_string())
+        .ref_id(format!("call-{}", n))
+    }
+
+    #[tokio::test]
+    async fn test_lane_runs_in_submission_order() {
+        let mut circle = MockCircle::start().await;
+        let arrivals = Arc::new(Mutex::new(Vec::new()));
+        let recorded = arrivals.clone();
+        circle
+            .server()
+            .mock("POST", EXECUTION_PATH)
+            .with_status(201)
+            .with_body_from_request(move |request| {
+                let body: Value = serde_json::from_slice(request.body().unwrap()).unwrap();
+                recorded
+                    .lock()
+                    .unwrap()
+                    .push(body["refId"].as_str().unwrap().to_string());
+                json!({"data": {"id": "tx", "state": "INITIATED"}})
+                    .to_string()
+                    .into()
+            })
+            .expect(4)
+            .create_async()
+            .await;
+
+        let queue = ExecutionQueue::new(circle.ops());
+        let handles: Vec<_> = [
+            ("wallet-a", 1),
+            ("wallet-a", 2),
+            ("wallet-a", 3),
+            ("wallet-b", 4),
+        ]
+        .into_iter()
+        .map(|(wallet_id, n)| {
+            let queue = queue.clone();
+            tokio::spawn(async move { queue.execute(call(wallet_id, n)).await })
+        })
+        .collect();
+        for handle in handles {
+            handle.await.unwrap().unwrap();
+        }
+
+        let arrivals = arrivals.lock().unwrap().clone();
+        let lane_a: Vec<_> = arrivals.iter().filter(|r| *r != "call-4").collect();
+        assert_eq!(lane_a, vec!["call-1", "call-2", "call-3"]);
+
+        let stats = queue.lane_stats("wallet-a", "0xCONTRACT").unwrap();
+        assert_eq!(stats.submitted, 3);
+        assert_eq!(stats.queued, 0);
+        assert!(!stats.executing);
+        assert_eq!(queue.stats().len(), 2);
+        assert_eq!(queue.queue_depth(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_sent_holds_lane() {
+        let mut circle = MockCircle::start().await;
+        circle
+            .respond(
+                "POST",
+                EXECUTION_PATH,
+                &fixtures::create_transaction_response(),
+            )
+            .await;
+        let mut queued = fixtures::transaction();
+        queued.state = "QUEUED".to_string();
+        let mut sent = fixtures::transaction();
+        sent.state = "SENT".to_string();
+        let path = format!("/v1/w3s/transactions/{}", fixtures::TRANSACTION_ID);
+        let mut transaction_mock = |transaction| {
+            circle
+                .server()
+                .mock("GET", path.as_str())
+                .with_status(200)
+                .with_body(json!({"data": {"transaction": transaction}}).to_string())
+                .expect(1)
+        };
+        let first = transaction_mock(queued).create_async().await;
+        let second = transaction_mock(sent).create_async().await;
+
+        let queue = ExecutionQueue::new(circle.ops()).wait_until_sent(
+            circle.view(),
+            Duration::from_millis(10),
+            Duration::from_secs(5),
+        );
+        queue.execute(call("wallet-a", 1)).await.unwrap();
+
+        first.assert_async().await;
+        second.assert_async().await;
+        let stats = queue.lane_stats("wallet-a", "0xcontract").unwrap();
+        assert_eq!(stats.settle_timeouts, 0);
+        assert_eq!(stats.submitted, 1);
+    }
+}
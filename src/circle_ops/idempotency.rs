@@ -0,0 +1,316 @@
+//! Idempotency key persistence for write operations
+//!
+//! Every write builder generates an idempotency key when the request is sent.
+//! To survive a crash between sending a request and seeing its response, an
+//! [`IdempotencyStore`] attached to `CircleOps` records each request (with the
+//! entity secret ciphertext redacted) before it is sent and marks it complete
+//! once Circle has given a definitive answer. After a restart,
+//! `CircleOps::resume_pending` resubmits whatever is still pending with the
+//! original key, so Circle executes each request at most once.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::circle_ops::{circler_ops::CircleOps, idempotency::FileIdempotencyStore};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let ops = CircleOps::new(None)?
+//!     .with_idempotency_store(FileIdempotencyStore::open("circle-pending.jsonl")?);
+//!
+//! // Finish anything a previous run left in flight before doing new work
+//! for (request, result) in ops.resume_pending().await? {
+//!     println!("{} {}: {:?}", request.method, request.endpoint, result.is_ok());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::helper::{CircleError, CircleResult, ENTITY_SECRET_PLACEHOLDER};
+
+/// A write request recorded before it was sent
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingRequest {
+    /// Idempotency key sent with the request
+    pub idempotency_key: String,
+
+    /// HTTP method (POST, PUT, PATCH)
+    pub method: String,
+
+    /// API endpoint path
+    pub endpoint: String,
+
+    /// Request body, with `entitySecretCiphertext` replaced by [`ENTITY_SECRET_PLACEHOLDER`]
+    pub payload: Value,
+
+    /// When the request was first recorded
+    pub created_at: DateTime<Utc>,
+}
+
+impl PendingRequest {
+    /// Record a request body, redacting the entity secret ciphertext
+    pub(crate) fn new(method: &str, endpoint: &str, payload: &Value) -> Option<Self> {
+        let idempotency_key = payload.get("idempotencyKey")?.as_str()?.to_string();
+
+        let mut payload = payload.clone();
+        if let Some(ciphertext) = payload.get_mut("entitySecretCiphertext") {
+            *ciphertext = Value::String(ENTITY_SECRET_PLACEHOLDER.to_string());
+        }
+
+        Some(Self {
+            idempotency_key,
+            method: method.to_string(),
+            endpoint: endpoint.to_string(),
+            payload,
+            created_at: Utc::now(),
+        })
+    }
+}
+
+/// Persistence hook for in-flight write requests
+///
+/// Implement this on top of your own database to make retries safe across
+/// process restarts. Implementations must tolerate `begin` being called again
+/// for a key that is already pending (it happens when a request is resumed).
+pub trait IdempotencyStore: Send + Sync {
+    /// Record a request that is about to be sent
+    fn begin(&self, request: &PendingRequest) -> CircleResult<()>;
+
+    /// Mark the request with `idempotency_key` as settled
+    fn complete(&self, idempotency_key: &str) -> CircleResult<()>;
+
+    /// All requests that were recorded but never completed, oldest first
+    fn pending(&self) -> CircleResult<Vec<PendingRequest>>;
+}
+
+/// In-process [`IdempotencyStore`], mainly useful for tests
+#[derive(Debug, Default)]
+pub struct InMemoryIdempotencyStore {
+    requests: Mutex<BTreeMap<String, PendingRequest>>,
+}
+
+impl InMemoryIdempotencyStore {
+    /// Create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn requests(
+        &self,
+    ) -> CircleResult<std::sync::MutexGuard<'_, BTreeMap<String, PendingRequest>>> {
+        self.requests
+            .lock()
+            .map_err(|_| CircleError::Config("Idempotency store lock poisoned".to_string()))
+    }
+}
+
+impl IdempotencyStore for InMemoryIdempotencyStore {
+    fn begin(&self, request: &PendingRequest) -> CircleResult<()> {
+        self.requests()?
+            .entry(request.idempotency_key.clone())
+            .or_insert_with(|| request.clone());
+        Ok(())
+    }
+
+    fn complete(&self, idempotency_key: &str) -> CircleResult<()> {
+        self.requests()?.remove(idempotency_key);
+        Ok(())
+    }
+
+    fn pending(&self) -> CircleResult<Vec<PendingRequest>> {
+        let mut pending: Vec<_> = self.requests()?.values().cloned().collect();
+        pending.sort_by_key(|request| request.created_at);
+        Ok(pending)
+    }
+}
+
+/// One line of the [`FileIdempotencyStore`] journal
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "camelCase")]
+enum JournalEntry {
+    Begin(PendingRequest),
+    #[serde(rename_all = "camelCase")]
+    Complete {
+        idempotency_key: String,
+    },
+}
+
+/// [`IdempotencyStore`] backed by an append-only NDJSON journal
+///
+/// Each `begin` and `complete` appends one line and flushes it, so the
+/// journal reflects every request sent before a crash. `pending` replays the
+/// journal; it grows over time and can be truncated whenever nothing is pending.
+#[derive(Debug)]
+pub struct FileIdempotencyStore {
+    path: PathBuf,
+    file: Mutex<File>,
+}
+
+impl FileIdempotencyStore {
+    /// Open (or create) the journal at `path`
+    pub fn open(path: impl AsRef<Path>) -> CircleResult<Self> {
+        let path = path.as_ref().to_path_buf();
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|e| {
+                CircleError::Config(format!(
+                    "Failed to open idempotency journal {}: {}",
+                    path.display(),
+                    e
+                ))
+            })?;
+
+        Ok(Self {
+            path,
+            file: Mutex::new(file),
+        })
+    }
+
+    fn append(&self, entry: &JournalEntry) -> CircleResult<()> {
+        let mut line = serde_json::to_vec(entry)?;
+        line.push(b'\n');
+
+        let mut file = self
+            .file
+            .lock()
+            .map_err(|_| CircleError::Config("Idempotency journal lock poisoned".to_string()))?;
+        file.write_all(&line)
+            .and_then(|_| file.flush())
+            .map_err(|e| CircleError::Config(format!("Failed to write idempotency journal: {}", e)))
+    }
+}
+
+impl IdempotencyStore for FileIdempotencyStore {
+    fn begin(&self, request: &PendingRequest) -> CircleResult<()> {
+        self.append(&JournalEntry::Begin(request.clone()))
+    }
+
+    fn complete(&self, idempotency_key: &str) -> CircleResult<()> {
+        self.append(&JournalEntry::Complete {
+            idempotency_key: idempotency_key.to_string(),
+        })
+    }
+
+    fn pending(&self) -> CircleResult<Vec<PendingRequest>> {
+        let file = File::open(&self.path).map_err(|e| {
+            CircleError::Config(format!(
+                "Failed to read idempotency journal {}: {}",
+                self.path.display(),
+                e
+            ))
+        })?;
+
+        let mut pending: Vec<PendingRequest> = Vec::new();
+        for line in BufReader::new(file).lines() {
+            let line = line.map_err(|e| {
+                CircleError::Config(format!("Failed to read idempotency journal: {}", e))
+            })?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str(&line)? {
+                JournalEntry::Begin(request) => {
+                    if !pending
+                        .iter()
+                        .any(|p| p.idempotency_key == request.idempotency_key)
+                    {
+                        pending.push(request);
+                    }
+                }
+                JournalEntry::Complete { idempotency_key } => {
+                    pending.retain(|p| p.idempotency_key != idempotency_key);
+                }
+            }
+        }
+        Ok(pending)
+    }
+}
+
+/// Whether Circle gave an answer that makes resubmitting pointless
+///
+/// Transport failures, rate limiting, maintenance and server errors leave the
+/// outcome unknown, so those requests stay pending.
+pub(crate) fn is_settled<T>(result: &CircleResult<T>) -> bool {
+    match result {
+        Ok(_) => true,
+        Err(CircleError::Api { status, .. }) => (400..500).contains(status) && *status != 429,
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn request(key: &str) -> PendingRequest {
+        PendingRequest::new(
+            "POST",
+            "/v1/w3s/developer/transactions/transfer",
+            &json!({"idempotencyKey": key, "entitySecretCiphertext": "secret", "amounts": ["1"]}),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_pending_request_redacts_ciphertext() {
+        let request = request("key-1");
+        assert_eq!(request.idempotency_key, "key-1");
+        assert_eq!(
+            request.payload["entitySecretCiphertext"],
+            ENTITY_SECRET_PLACEHOLDER
+        );
+        assert!(PendingRequest::new("POST", "/x", &json!({"name": "no key"})).is_none());
+    }
+
+    #[test]
+    fn test_file_store_replays_journal() {
+        let path = std::env::temp_dir().join(format!(
+            "inf-circle-idempotency-{}.jsonl",
+            crate::helper::generate_uuid()
+        ));
+        let store = FileIdempotencyStore::open(&path).unwrap();
+        store.begin(&request("a")).unwrap();
+        store.begin(&request("b")).unwrap();
+        store.begin(&request("a")).unwrap();
+        store.complete("a").unwrap();
+
+        let reopened = FileIdempotencyStore::open(&path).unwrap();
+        let pending = reopened.pending().unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].idempotency_key, "b");
+        assert!(!serde_json::to_string(&pending)
+            .unwrap()
+            .contains("secret\""));
+    }
+
+    #[test]
+    fn test_settled_outcomes() {
+        assert!(is_settled(&Ok(())));
+        let api = |status| CircleError::Api {
+            status,
+            request_id: None,
+            message: String::new(),
+        };
+        assert!(is_settled::<()>(&Err(api(409))));
+        assert!(!is_settled::<()>(&Err(api(429))));
+        assert!(!is_settled::<()>(&Err(api(500))));
+        assert!(!is_settled::<()>(&Err(CircleError::Maintenance {
+            retry_after: None
+        })));
+    }
+}
@@ -0,0 +1,245 @@
+//! Maintenance window handling for write operations
+//!
+//! Circle answers with HTTP 503 while the API is in a scheduled maintenance
+//! window. By default such responses surface immediately as
+//! [`CircleError::Maintenance`](crate::helper::CircleError::Maintenance). With
+//! a [`MaintenancePolicy`] attached to `CircleOps`, writes are instead held in a
+//! bounded in-memory queue and resubmitted once the advertised `Retry-After`
+//! delay has passed.
+//!
+//! The queue drains in submission order: only the write at its head retries,
+//! and the others follow once it settles. While writes are queued, new writes
+//! join the end of the queue instead of overtaking them.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//! use inf_circle_sdk::circle_ops::{circler_ops::CircleOps, maintenance::MaintenancePolicy};
+//!
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let ops = CircleOps::new(None)?.with_maintenance_policy(
+//!     MaintenancePolicy::new(100).max_wait(Duration::from_secs(30 * 60)),
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use std::pin::pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures_util::future::{select, Either};
+use tokio::sync::{Mutex, OwnedMutexGuard, OwnedSemaphorePermit, Semaphore};
+
+use crate::helper::{sleep, Instant};
+
+/// Shortest delay between two attempts of a queued write
+///
+/// Applies when Circle sends `Retry-After: 0` or the policy's default delay
+/// is zero, so a write never resubmits in a tight loop.
+pub const MIN_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// Policy for holding write operations during a Circle maintenance window
+///
+/// Queued writes are resubmitted with the same idempotency key and a freshly
+/// encrypted entity secret, so a request that reached Circle before the
+/// window started is never executed twice. The policy is cheap to clone;
+/// clones share the same queue capacity.
+#[derive(Clone, Debug)]
+pub struct MaintenancePolicy {
+    queue: Arc<Semaphore>,
+    turn: Arc<Mutex<()>>,
+    max_queued: usize,
+    max_wait: Duration,
+    default_retry_after: Duration,
+}
+
+impl MaintenancePolicy {
+    /// Create a policy holding at most `max_queued` writes at a time
+    ///
+    /// Writes hitting a maintenance window while the queue is full fail
+    /// immediately with `CircleError::Maintenance`.
+    pub fn new(max_queued: usize) -> Self {
+        Self {
+            queue: Arc::new(Semaphore::new(max_queued)),
+            turn: Arc::new(Mutex::new(())),
+            max_queued,
+            max_wait: Duration::from_secs(15 * 60),
+            default_retry_after: Duration::from_secs(30),
+        }
+    }
+
+    /// Set the total time a single write may wait before failing (15 minutes by default)
+    ///
+    /// The budget counts wall-clock time from the first maintenance response
+    /// (or from joining a non-empty queue), including time spent behind
+    /// writes queued earlier.
+    pub fn max_wait(mut self, max_wait: Duration) -> Self {
+        self.max_wait = max_wait;
+        self
+    }
+
+    /// Set the delay used when Circle does not send `Retry-After` (30 seconds by default)
+    ///
+    /// Delays shorter than [`MIN_RETRY_DELAY`] are raised to it.
+    pub fn default_retry_after(mut self, delay: Duration) -> Self {
+        self.default_retry_after = delay;
+        self
+    }
+
+    /// Maximum number of writes held at the same time
+    pub fn max_queued(&self) -> usize {
+        self.max_queued
+    }
+
+    /// Number of writes currently waiting for the window to end
+    pub fn queued(&self) -> usize {
+        self.max_queued - self.queue.available_permits()
+    }
+
+    /// Join the end of the queue and wait for this write's turn to retry
+    ///
+    /// Returns `None` if the queue is full or the wait budget, counted from
+    /// `started`, runs out first. The flag is true if writes queued earlier
+    /// held the turn, in which case they already waited out the window.
+    pub(crate) async fn join(&self, started: Instant) -> Option<(QueueTicket, bool)> {
+        let slot = self.queue.clone().try_acquire_owned().ok()?;
+        if let Ok(turn) = self.turn.clone().try_lock_owned() {
+            return Some((
+                QueueTicket {
+                    _slot: slot,
+                    _turn: turn,
+                },
+                false,
+            ));
+        }
+
+        let remaining = self.max_wait.checked_sub(started.elapsed())?;
+        // Tokio's mutex is fair, so turns are granted in the order writes joined
+        let turn = pin!(self.turn.clone().lock_owned());
+        let expired = pin!(sleep(remaining));
+        match select(turn, expired).await {
+            Either::Left((turn, _)) => Some((
+                QueueTicket {
+                    _slot: slot,
+                    _turn: turn,
+                },
+                true,
+            )),
+            Either::Right(_) => None,
+        }
+    }
+
+    /// Delay before the next attempt, or `None` if the wait budget is exhausted
+    ///
+    /// `elapsed` is the time since the write first hit the window.
+    pub(crate) fn next_delay(
+        &self,
+        retry_after: Option<Duration>,
+        elapsed: Duration,
+    ) -> Option<Duration> {
+        let remaining = self.max_wait.checked_sub(elapsed)?;
+        if remaining.is_zero() {
+            return None;
+        }
+        Some(
+            retry_after
+                .unwrap_or(self.default_retry_after)
+                .max(MIN_RETRY_DELAY)
+                .min(remaining),
+        )
+    }
+}
+
+/// A write's place in the maintenance queue, released when dropped
+pub(crate) struct QueueTicket {
+    _slot: OwnedSemaphorePermit,
+    _turn: OwnedMutexGuard<()>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_queue_is_bounded() {
+        let policy = MaintenancePolicy::new(1);
+        let ticket = policy.join(Instant::now()).await.unwrap();
+        assert_eq!(policy.queued(), 1);
+        assert!(policy.clone().join(Instant::now()).await.is_none());
+
+        drop(ticket);
+        assert_eq!(policy.queued(), 0);
+        assert!(policy.join(Instant::now()).await.is_some());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_turns_follow_join_order() {
+        let policy = MaintenancePolicy::new(3);
+        let (head, waited) = policy.join(Instant::now()).await.unwrap();
+        assert!(!waited);
+
+        let order = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut writes = Vec::new();
+        for id in 0..2 {
+            let (policy, order) = (policy.clone(), order.clone());
+            writes.push(tokio::spawn(async move {
+                let (_ticket, waited) = policy.join(Instant::now()).await.unwrap();
+                assert!(waited);
+                order.lock().unwrap().push(id);
+            }));
+            tokio::task::yield_now().await;
+        }
+
+        assert_eq!(policy.queued(), 3);
+        drop(head);
+        for write in writes {
+            write.await.unwrap();
+        }
+        assert_eq!(*order.lock().unwrap(), vec![0, 1]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_waiting_for_turn_respects_wait_budget() {
+        let policy = MaintenancePolicy::new(2).max_wait(Duration::from_secs(60));
+        let _head = policy.join(Instant::now()).await.unwrap();
+
+        assert!(policy.join(Instant::now()).await.is_none());
+        assert_eq!(policy.queued(), 1);
+    }
+
+    #[test]
+    fn test_next_delay_respects_wait_budget() {
+        let policy = MaintenancePolicy::new(1)
+            .max_wait(Duration::from_secs(60))
+            .default_retry_after(Duration::from_secs(10));
+
+        assert_eq!(
+            policy.next_delay(None, Duration::ZERO),
+            Some(Duration::from_secs(10))
+        );
+        assert_eq!(
+            policy.next_delay(Some(Duration::from_secs(120)), Duration::from_secs(30)),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            policy.next_delay(Some(Duration::from_secs(5)), Duration::from_secs(60)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_zero_retry_after_still_waits() {
+        let policy = MaintenancePolicy::new(1).default_retry_after(Duration::ZERO);
+
+        assert_eq!(
+            policy.next_delay(Some(Duration::ZERO), Duration::ZERO),
+            Some(MIN_RETRY_DELAY)
+        );
+        assert_eq!(
+            policy.next_delay(None, Duration::ZERO),
+            Some(MIN_RETRY_DELAY)
+        );
+    }
+}
@@ -1 +1,7 @@
+pub mod ciphertext;
 pub mod circler_ops;
+pub mod dry_run;
+pub mod execution_queue;
+pub mod idempotency;
+pub mod maintenance;
+pub mod secret;
@@ -0,0 +1,294 @@
+//! Entity secret sources
+//!
+//! A [`SecretProvider`] fetches the raw entity secret when a client is built,
+//! so the plaintext does not have to live in a process environment variable.
+//! [`RsaCiphertextProvider::from_secret_provider`] and
+//! [`CircleOps::from_secret_provider`] fetch it once and keep it in memory
+//! that is wiped on drop.
+//!
+//! Built-in providers:
+//! - [`EnvSecretProvider`]: a hex-encoded environment variable, as `CircleOps::new` reads
+//! - `AwsKmsSecretProvider` (`aws-kms` feature): a KMS-encrypted blob decrypted at startup
+//! - `VaultSecretProvider` (`vault` feature): a field of a HashiCorp Vault KV v2 secret
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+//! use inf_circle_sdk::circle_ops::secret::EnvSecretProvider;
+//! use inf_circle_sdk::helper::http_core::CircleHttpCore;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let core = CircleHttpCore::from_env()?;
+//! let ops = CircleOps::from_secret_provider(
+//!     &core,
+//!     &EnvSecretProvider::from_var("ENTITY_SECRET_FROM_SIDECAR"),
+//!     std::env::var("CIRCLE_PUBLIC_KEY")?,
+//! )
+//! .await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`RsaCiphertextProvider::from_secret_provider`]: crate::circle_ops::ciphertext::RsaCiphertextProvider::from_secret_provider
+//! [`CircleOps::from_secret_provider`]: crate::circle_ops::circler_ops::CircleOps::from_secret_provider
+
+use std::future::Future;
+
+use zeroize::Zeroizing;
+
+use crate::helper::{get_env_var, CircleError, CircleResult};
+
+/// Source of the raw entity secret
+pub trait SecretProvider: Send + Sync {
+    /// The 32-byte entity secret
+    fn entity_secret(&self) -> impl Future<Output = CircleResult<Zeroizing<Vec<u8>>>> + Send;
+}
+
+/// Decode a hex-encoded secret without leaving an unwiped copy behind
+fn decode_hex_secret(hex_secret: &str, source: &str) -> CircleResult<Zeroizing<Vec<u8>>> {
+    hex::decode(hex_secret.trim())
+        .map(Zeroizing::new)
+        .map_err(|e| {
+            CircleError::Config(format!("Entity secret from {} is not hex: {}", source, e))
+        })
+}
+
+/// Reads the hex-encoded entity secret from an environment variable
+#[derive(Debug, Clone)]
+pub struct EnvSecretProvider {
+    var: String,
+}
+
+impl EnvSecretProvider {
+    /// Read `CIRCLE_ENTITY_SECRET`
+    pub fn new() -> Self {
+        Self::from_var("CIRCLE_ENTITY_SECRET")
+    }
+
+    /// Read the named variable
+    pub fn from_var(var: impl Into<String>) -> Self {
+        Self { var: var.into() }
+    }
+}
+
+impl Default for EnvSecretProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SecretProvider for EnvSecretProvider {
+    async fn entity_secret(&self) -> CircleResult<Zeroizing<Vec<u8>>> {
+        let hex_secret = Zeroizing::new(get_env_var(&self.var)?);
+        decode_hex_secret(&hex_secret, &self.var)
+    }
+}
+
+/// Decrypts a KMS-encrypted entity secret (`aws-kms` feature)
+///
+/// Encrypt the raw 32 bytes once with `aws kms encrypt` and ship the
+/// resulting ciphertext blob with the service; only a role allowed to call
+/// `kms:Decrypt` on the key can recover the secret.
+#[cfg(feature = "aws-kms")]
+#[derive(Debug, Clone)]
+pub struct AwsKmsSecretProvider {
+    client: aws_sdk_kms::Client,
+    ciphertext_blob: Vec<u8>,
+    key_id: Option<String>,
+}
+
+#[cfg(feature = "aws-kms")]
+impl AwsKmsSecretProvider {
+    /// Create a provider decrypting `ciphertext_blob` with an existing KMS client
+    pub fn new(client: aws_sdk_kms::Client, ciphertext_blob: Vec<u8>) -> Self {
+        Self {
+            client,
+            ciphertext_blob,
+            key_id: None,
+        }
+    }
+
+    /// Create a provider with a KMS client configured from the standard AWS environment
+    ///
+    /// Uses the latest AWS SDK behavior version; build the client yourself and
+    /// call [`new`](Self::new) to pin another.
+    pub async fn from_env(ciphertext_blob: Vec<u8>) -> Self {
+        let config = aws_config::load_defaults(aws_config::BehaviorVersion::latest()).await;
+        Self::new(aws_sdk_kms::Client::new(&config), ciphertext_blob)
+    }
+
+    /// Require the blob to be encrypted under this key (ID, ARN or alias)
+    pub fn key_id(mut self, key_id: impl Into<String>) -> Self {
+        self.key_id = Some(key_id.into());
+        self
+    }
+}
+
+#[cfg(feature = "aws-kms")]
+impl SecretProvider for AwsKmsSecretProvider {
+    async fn entity_secret(&self) -> CircleResult<Zeroizing<Vec<u8>>> {
+        let output = self
+            .client
+            .decrypt()
+            .ciphertext_blob(aws_sdk_kms::primitives::Blob::new(
+                self.ciphertext_blob.clone(),
+            ))
+            .set_key_id(self.key_id.clone())
+            .send()
+            .await
+            .map_err(|e| CircleError::Config(format!("KMS decrypt failed: {}", e)))?;
+
+        let plaintext = output
+            .plaintext()
+            .ok_or_else(|| CircleError::Config("KMS decrypt returned no plaintext".to_string()))?;
+        Ok(Zeroizing::new(plaintext.as_ref().to_vec()))
+    }
+}
+
+/// Reads the hex-encoded entity secret from a Vault KV v2 secret (`vault` feature)
+#[cfg(feature = "vault")]
+#[derive(Clone)]
+pub struct VaultSecretProvider {
+    client: reqwest::Client,
+    address: String,
+    token: Zeroizing<String>,
+    mount: String,
+    path: String,
+    field: String,
+}
+
+#[cfg(feature = "vault")]
+impl VaultSecretProvider {
+    /// Read the `entity_secret` field of `path` under the `secret` mount
+    pub fn new(
+        address: impl Into<String>,
+        token: impl Into<String>,
+        path: impl Into<String>,
+    ) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            address: address.into().trim_end_matches('/').to_string(),
+            token: Zeroizing::new(token.into()),
+            mount: "secret".to_string(),
+            path: path.into(),
+            field: "entity_secret".to_string(),
+        }
+    }
+
+    /// Create a provider for `path` from `VAULT_ADDR` and `VAULT_TOKEN`
+    pub fn from_env(path: impl Into<String>) -> CircleResult<Self> {
+        Ok(Self::new(
+            get_env_var("VAULT_ADDR")?,
+            get_env_var("VAULT_TOKEN")?,
+            path,
+        ))
+    }
+
+    /// KV v2 mount the secret lives under (default `secret`)
+    pub fn mount(mut self, mount: impl Into<String>) -> Self {
+        self.mount = mount.into();
+        self
+    }
+
+    /// Field holding the hex-encoded entity secret (default `entity_secret`)
+    pub fn field(mut self, field: impl Into<String>) -> Self {
+        self.field = field.into();
+        self
+    }
+}
+
+#[cfg(feature = "vault")]
+impl SecretProvider for VaultSecretProvider {
+    async fn entity_secret(&self) -> CircleResult<Zeroizing<Vec<u8>>> {
+        let url = format!("{}/v1/{}/data/{}", self.address, self.mount, self.path);
+        let response = self
+            .client
+            .get(&url)
+            .header("X-Vault-Token", self.token.as_str())
+            .send()
+            .await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(CircleError::Config(format!(
+                "Vault returned {} for {}",
+                status, self.path
+            )));
+        }
+
+        let body = Zeroizing::new(response.bytes().await?.to_vec());
+        let mut secret: serde_json::Value = serde_json::from_slice(&body)?;
+        let field = secret
+            .get_mut("data")
+            .and_then(|kv| kv.get_mut("data"))
+            .and_then(|data| data.get_mut(&self.field))
+            .map(serde_json::Value::take);
+        let hex_secret = match field {
+            Some(serde_json::Value::String(hex_secret)) => Zeroizing::new(hex_secret),
+            _ => {
+                return Err(CircleError::Config(format!(
+                    "Vault secret {} has no string field {}",
+                    self.path, self.field
+                )))
+            }
+        };
+        decode_hex_secret(&hex_secret, "Vault")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::circle_ops::ciphertext::{CiphertextProvider, RsaCiphertextProvider};
+    use crate::testing::{MOCK_ENTITY_SECRET, MOCK_PUBLIC_KEY};
+
+    #[tokio::test]
+    async fn test_env_provider_feeds_rsa_provider() {
+        std::env::set_var("INF_CIRCLE_TEST_SECRET_PROVIDER", MOCK_ENTITY_SECRET);
+        let provider = EnvSecretProvider::from_var("INF_CIRCLE_TEST_SECRET_PROVIDER");
+        let secret = provider.entity_secret().await.unwrap();
+        assert_eq!(hex::encode(&*secret), MOCK_ENTITY_SECRET.to_lowercase());
+
+        let rsa =
+            RsaCiphertextProvider::from_secret_provider(&provider, MOCK_PUBLIC_KEY.to_string())
+                .await
+                .unwrap();
+        assert!(!rsa.ciphertext().unwrap().is_empty());
+
+        let missing = EnvSecretProvider::from_var("INF_CIRCLE_TEST_SECRET_MISSING");
+        assert!(matches!(
+            missing.entity_secret().await,
+            Err(CircleError::EnvVar(_))
+        ));
+    }
+
+    #[cfg(feature = "vault")]
+    #[tokio::test]
+    async fn test_vault_provider_reads_kv_field() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v1/kv/data/circle/prod")
+            .match_header("X-Vault-Token", "vault-token")
+            .with_status(200)
+            .with_body(
+                serde_json::json!({
+                    "data": { "data": { "entity_secret": MOCK_ENTITY_SECRET }, "metadata": {} }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let provider =
+            VaultSecretProvider::new(server.url(), "vault-token", "circle/prod").mount("kv");
+        let secret = provider.entity_secret().await.unwrap();
+        assert_eq!(hex::encode(&*secret), MOCK_ENTITY_SECRET.to_lowercase());
+        mock.assert_async().await;
+
+        let wrong_field = provider.field("missing");
+        assert!(matches!(
+            wrong_field.entity_secret().await,
+            Err(CircleError::Config(_))
+        ));
+    }
+}
@@ -0,0 +1,425 @@
+//! Response caching for CircleView
+//!
+//! Dashboards ask for the same wallet, contract and token metadata over and
+//! over, although it rarely changes. [`CachedCircleView`] wraps a
+//! [`CircleView`] and keeps those responses for a per-endpoint TTL, so
+//! repeated reads cost no API calls. Everything else is passed through to the
+//! wrapped view unchanged.
+//!
+//! Responses are stored as JSON in a [`CacheBackend`]. The default
+//! [`InMemoryCache`] is local to the process; implement the trait to share
+//! the cache between processes (Redis, memcached, ...).
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//! use inf_circle_sdk::circle_view::cache::{CachedCircleView, CachedEndpoint};
+//! use inf_circle_sdk::circle_view::circle_view::CircleView;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let view = CachedCircleView::new(CircleView::new()?)
+//!     .ttl(CachedEndpoint::Wallet, Duration::from_secs(60));
+//!
+//! // Only the first call reaches Circle
+//! for _ in 0..10 {
+//!     let wallet = view.get_wallet("wallet-id").await?;
+//!     println!("{}", wallet.wallet.address);
+//! }
+//!
+//! // Uncached endpoints are forwarded to the wrapped view
+//! let balances = view.get_token_balances("wallet-id", Default::default()).await?;
+//!
+//! // Drop a stale entry after changing the wallet elsewhere
+//! view.invalidate_wallet("wallet-id");
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Deref;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+use crate::circle_view::circle_view::CircleView;
+use crate::contract::dto::{ContractResponse, UpdateContractRequest};
+use crate::dev_wallet::dto::{DevWalletResponse, TokenResponse};
+use crate::helper::{CircleResult, Instant};
+
+/// Storage for cached responses
+///
+/// Keys are `"<endpoint>:<id>"` (see [`CachedEndpoint::key`]). Implementations
+/// must drop entries once their TTL has passed; `get` must never return an
+/// expired entry.
+pub trait CacheBackend: Send + Sync {
+    /// Look up a live entry
+    fn get(&self, key: &str) -> Option<Value>;
+
+    /// Store an entry for `ttl`
+    fn insert(&self, key: String, value: Value, ttl: Duration);
+
+    /// Remove one entry
+    fn remove(&self, key: &str);
+
+    /// Remove every entry whose key starts with `prefix`
+    fn remove_prefix(&self, prefix: &str);
+}
+
+/// Process-local [`CacheBackend`]
+#[derive(Default)]
+pub struct InMemoryCache {
+    entries: Mutex<HashMap<String, (Instant, Value)>>,
+}
+
+impl InMemoryCache {
+    /// Create an empty cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of stored entries, including expired ones not yet evicted
+    pub fn len(&self) -> usize {
+        self.entries.lock().map_or(0, |entries| entries.len())
+    }
+
+    /// Whether the cache holds no entries
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl CacheBackend for InMemoryCache {
+    fn get(&self, key: &str) -> Option<Value> {
+        let mut entries = self.entries.lock().ok()?;
+        match entries.get(key) {
+            Some((expires, value)) if *expires > Instant::now() => Some(value.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn insert(&self, key: String, value: Value, ttl: Duration) {
+        if let Ok(mut entries) = self.entries.lock() {
+            let now = Instant::now();
+            entries.retain(|_, (expires, _)| *expires > now);
+            entries.insert(key, (now + ttl, value));
+        }
+    }
+
+    fn remove(&self, key: &str) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.remove(key);
+        }
+    }
+
+    fn remove_prefix(&self, prefix: &str) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.retain(|key, _| !key.starts_with(prefix));
+        }
+    }
+}
+
+/// An endpoint whose responses [`CachedCircleView`] can cache
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CachedEndpoint {
+    /// `get_wallet` (default TTL 5 minutes)
+    Wallet,
+    /// `get_contract`, including the contract ABI (default TTL 10 minutes)
+    Contract,
+    /// `get_token` (default TTL 1 hour)
+    Token,
+    /// `get_notification_sig_pub_key` (default TTL 1 hour)
+    NotificationPublicKey,
+}
+
+impl CachedEndpoint {
+    /// Every cacheable endpoint
+    pub const ALL: [CachedEndpoint; 4] = [
+        CachedEndpoint::Wallet,
+        CachedEndpoint::Contract,
+        CachedEndpoint::Token,
+        CachedEndpoint::NotificationPublicKey,
+    ];
+
+    /// Prefix of this endpoint's cache keys
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            CachedEndpoint::Wallet => "wallet",
+            CachedEndpoint::Contract => "contract",
+            CachedEndpoint::Token => "token",
+            CachedEndpoint::NotificationPublicKey => "notification-public-key",
+        }
+    }
+
+    /// Cache key of one resource
+    pub fn key(&self, id: &str) -> String {
+        format!("{}:{}", self.prefix(), id)
+    }
+
+    fn default_ttl(&self) -> Duration {
+        match self {
+            CachedEndpoint::Wallet => Duration::from_secs(300),
+            CachedEndpoint::Contract => Duration::from_secs(600),
+            CachedEndpoint::Token | CachedEndpoint::NotificationPublicKey => {
+                Duration::from_secs(3600)
+            }
+        }
+    }
+}
+
+/// A [`CircleView`] that caches slowly changing responses
+///
+/// Cached reads are the inherent methods below; every other `CircleView`
+/// method is reachable through `Deref` and is never cached. Errors are not
+/// cached. The wrapper is cheap to clone; clones share the backend.
+#[derive(Clone)]
+pub struct CachedCircleView {
+    view: CircleView,
+    backend: Arc<dyn CacheBackend>,
+    ttls: HashMap<CachedEndpoint, Duration>,
+}
+
+impl fmt::Debug for CachedCircleView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachedCircleView")
+            .field("ttls", &self.ttls)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CachedCircleView {
+    /// Wrap `view` with an [`InMemoryCache`] and the default TTLs
+    pub fn new(view: CircleView) -> Self {
+        Self::with_backend(view, Arc::new(InMemoryCache::new()))
+    }
+
+    /// Wrap `view` with a custom backend and the default TTLs
+    pub fn with_backend(view: CircleView, backend: Arc<dyn CacheBackend>) -> Self {
+        Self {
+            view,
+            backend,
+            ttls: CachedEndpoint::ALL
+                .iter()
+                .map(|endpoint| (*endpoint, endpoint.default_ttl()))
+                .collect(),
+        }
+    }
+
+    /// Set how long an endpoint's responses are kept
+    pub fn ttl(mut self, endpoint: CachedEndpoint, ttl: Duration) -> Self {
+        self.ttls.insert(endpoint, ttl);
+        self
+    }
+
+    /// Stop caching an endpoint; its calls go straight to Circle
+    pub fn uncached(mut self, endpoint: CachedEndpoint) -> Self {
+        self.ttls.remove(&endpoint);
+        self
+    }
+
+    /// The wrapped view
+    pub fn view(&self) -> &CircleView {
+        &self.view
+    }
+
+    /// Get a wallet, from the cache if possible
+    pub async fn get_wallet(&self, wallet_id: &str) -> CircleResult<DevWalletResponse> {
+        self.cached(
+            CachedEndpoint::Wallet,
+            wallet_id,
+            self.view.get_wallet(wallet_id),
+        )
+        .await
+    }
+
+    /// Get a contract (with its ABI), from the cache if possible
+    pub async fn get_contract(&self, contract_id: &str) -> CircleResult<ContractResponse> {
+        self.cached(
+            CachedEndpoint::Contract,
+            contract_id,
+            self.view.get_contract(contract_id),
+        )
+        .await
+    }
+
+    /// Get a token, from the cache if possible
+    pub async fn get_token(&self, token_id: &str) -> CircleResult<TokenResponse> {
+        self.cached(
+            CachedEndpoint::Token,
+            token_id,
+            self.view.get_token(token_id),
+        )
+        .await
+    }
+
+    /// Get a notification signature public key, from the cache if possible
+    pub async fn get_notification_sig_pub_key(&self, public_key: &str) -> CircleResult<String> {
+        self.cached(
+            CachedEndpoint::NotificationPublicKey,
+            public_key,
+            self.view.get_notification_sig_pub_key(public_key),
+        )
+        .await
+    }
+
+    /// Update a contract and replace its cached entry with the result
+    pub async fn update_contract(
+        &self,
+        contract_id: &str,
+        request: UpdateContractRequest,
+    ) -> CircleResult<ContractResponse> {
+        self.invalidate_contract(contract_id);
+        let response = self.view.update_contract(contract_id, request).await?;
+        self.store(CachedEndpoint::Contract, contract_id, &response);
+        Ok(response)
+    }
+
+    /// Drop the cached entry for one wallet
+    pub fn invalidate_wallet(&self, wallet_id: &str) {
+        self.invalidate(CachedEndpoint::Wallet, wallet_id);
+    }
+
+    /// Drop the cached entry for one contract
+    pub fn invalidate_contract(&self, contract_id: &str) {
+        self.invalidate(CachedEndpoint::Contract, contract_id);
+    }
+
+    /// Drop the cached entry for one token
+    pub fn invalidate_token(&self, token_id: &str) {
+        self.invalidate(CachedEndpoint::Token, token_id);
+    }
+
+    /// Drop the cached entry for one resource of an endpoint
+    pub fn invalidate(&self, endpoint: CachedEndpoint, id: &str) {
+        self.backend.remove(&endpoint.key(id));
+    }
+
+    /// Drop every cached entry of an endpoint
+    pub fn invalidate_endpoint(&self, endpoint: CachedEndpoint) {
+        self.backend
+            .remove_prefix(&format!("{}:", endpoint.prefix()));
+    }
+
+    /// Drop every cached entry
+    pub fn clear(&self) {
+        for endpoint in CachedEndpoint::ALL {
+            self.invalidate_endpoint(endpoint);
+        }
+    }
+
+    async fn cached<R>(
+        &self,
+        endpoint: CachedEndpoint,
+        id: &str,
+        fetch: impl std::future::Future<Output = CircleResult<R>>,
+    ) -> CircleResult<R>
+    where
+        R: Serialize + DeserializeOwned,
+    {
+        if self.ttls.contains_key(&endpoint) {
+            let hit = self.backend.get(&endpoint.key(id));
+            if let Some(response) = hit.and_then(|value| serde_json::from_value(value).ok()) {
+                return Ok(response);
+            }
+        }
+        let response = fetch.await?;
+        self.store(endpoint, id, &response);
+        Ok(response)
+    }
+
+    fn store<R: Serialize>(&self, endpoint: CachedEndpoint, id: &str, response: &R) {
+        let Some(ttl) = self.ttls.get(&endpoint) else {
+            return;
+        };
+        if let Ok(value) = serde_json::to_value(response) {
+            self.backend.insert(endpoint.key(id), value, *ttl);
+        }
+    }
+}
+
+impl Deref for CachedCircleView {
+    type Target = CircleView;
+
+    fn deref(&self) -> &CircleView {
+        &self.view
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{fixtures, MockCircle};
+
+    #[tokio::test]
+    async fn test_cached_view_hits_and_invalidates() {
+        let mut circle = MockCircle::start().await;
+        let wallet = fixtures::dev_wallet();
+        let mock = circle
+            .server()
+            .mock("GET", format!("/v1/w3s/wallets/{}", wallet.id).as_str())
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::json!({ "data": { "wallet": wallet } }).to_string())
+            .expect(2)
+            .create_async()
+            .await;
+
+        let view = CachedCircleView::new(circle.view());
+        for _ in 0..3 {
+            let response = view.get_wallet(&wallet.id).await.unwrap();
+            assert_eq!(response.wallet.address, wallet.address);
+        }
+        view.invalidate_wallet(&wallet.id);
+        view.get_wallet(&wallet.id).await.unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_cached_view_honours_ttl_and_uncached() {
+        let mut circle = MockCircle::start().await;
+        let wallet = fixtures::dev_wallet();
+        let mock = circle
+            .server()
+            .mock("GET", format!("/v1/w3s/wallets/{}", wallet.id).as_str())
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::json!({ "data": { "wallet": wallet } }).to_string())
+            .expect(4)
+            .create_async()
+            .await;
+
+        let expiring = CachedCircleView::new(circle.view())
+            .ttl(CachedEndpoint::Wallet, Duration::from_millis(20));
+        expiring.get_wallet(&wallet.id).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        expiring.get_wallet(&wallet.id).await.unwrap();
+
+        let uncached = CachedCircleView::new(circle.view()).uncached(CachedEndpoint::Wallet);
+        uncached.get_wallet(&wallet.id).await.unwrap();
+        uncached.get_wallet(&wallet.id).await.unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[test]
+    fn test_in_memory_cache_removes_by_prefix() {
+        let cache = InMemoryCache::new();
+        let ttl = Duration::from_secs(60);
+        cache.insert(CachedEndpoint::Wallet.key("a"), Value::from(1), ttl);
+        cache.insert(CachedEndpoint::Token.key("a"), Value::from(2), ttl);
+
+        cache.remove_prefix("wallet:");
+
+        assert_eq!(cache.get("wallet:a"), None);
+        assert_eq!(cache.get("token:a"), Some(Value::from(2)));
+        assert_eq!(cache.len(), 1);
+    }
+}
@@ -27,9 +27,16 @@
 //! }
 //! ```
 
-use crate::helper::{build_query_params, get_env_var, CircleResult, HttpClient};
+use crate::helper::{
+    build_query_params, environment::Environment, get_env_var, http_core::CircleHttpCore,
+    metrics::MetricsRecorder, rate_limit::RateLimiter, response_request_id, CircleResult,
+    HttpClient, WithMeta,
+};
 use reqwest::Method;
 use serde::Serialize;
+use serde_json::Value;
+use std::sync::Arc;
+use std::time::Duration;
 
 /// CircleView handles read operations (GET) with base URL configuration
 #[derive(Clone)]
@@ -44,6 +51,8 @@ impl CircleView {
     /// environment variables:
     /// - `CIRCLE_API_KEY`: Your Circle API key
     /// - `CIRCLE_BASE_URL`: Circle API base URL (e.g., https://api.circle.com)
+    /// - `CIRCLE_ENVIRONMENT`: `sandbox`, `production` or a URL; overrides `CIRCLE_BASE_URL`
+    ///   (see [`Environment::from_env`])
     ///
     /// Unlike `CircleOps`, this does not require entity secret or public key since it only
     /// performs read operations.
@@ -74,12 +83,129 @@ impl CircleView {
         dotenv::dotenv().ok(); // Load .env file if present
 
         let api_key = get_env_var("CIRCLE_API_KEY")?;
-        let base_url = get_env_var("CIRCLE_BASE_URL")?;
-        let client = HttpClient::with_api_key(&base_url, api_key)?;
+        let environment = Environment::from_env()?;
 
+        Self::with_environment(environment, api_key)
+    }
+
+    /// Create a CircleView from explicit configuration
+    ///
+    /// Unlike [`CircleView::new`], this reads neither `.env` nor the process
+    /// environment, so it is the constructor to use where neither exists
+    /// (browsers and Workers with the `wasm` feature, or configuration loaded
+    /// from a secrets manager).
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - Circle API base URL (e.g., https://api.circle.com)
+    /// * `api_key` - Your Circle API key
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Url` if `base_url` is not a valid URL.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use inf_circle_sdk::circle_view::circle_view::CircleView;
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let view = CircleView::with_api_key("https://api.circle.com", "TEST_API_KEY:abc:def".to_string())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_api_key(base_url: &str, api_key: String) -> CircleResult<Self> {
+        let client = HttpClient::with_api_key(base_url, api_key)?;
         Ok(Self { client })
     }
 
+    /// Create a CircleView for an [`Environment`]
+    ///
+    /// Like [`CircleView::with_api_key`], this reads no environment variables.
+    /// In the sandbox, request bodies naming a mainnet blockchain are refused;
+    /// see [`CircleView::allow_mainnet_on_sandbox`].
+    pub fn with_environment(environment: Environment, api_key: String) -> CircleResult<Self> {
+        Ok(Self::from_core(&CircleHttpCore::new(environment, api_key)))
+    }
+
+    /// Create a CircleView on a shared [`CircleHttpCore`]
+    ///
+    /// The view reuses the core's connection pool, so a `CircleOps` built from
+    /// the same core adds no connections of its own.
+    pub fn from_core(core: &CircleHttpCore) -> Self {
+        Self {
+            client: core.http_client(),
+        }
+    }
+
+    /// The HTTP core behind this client, for building a `CircleOps` that shares it
+    pub fn core(&self) -> CircleHttpCore {
+        CircleHttpCore::from_http_client(self.client.clone())
+    }
+
+    /// The environment this client talks to
+    pub fn environment(&self) -> &Environment {
+        self.client.environment()
+    }
+
+    /// Throttle this client's requests through a [`RateLimiter`]
+    ///
+    /// Pass clones of one limiter to several clients to throttle them
+    /// together, or attach it to a shared
+    /// [`CircleHttpCore`](CircleHttpCore::with_rate_limiter).
+    pub fn with_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.client = self.client.rate_limiter(limiter);
+        self
+    }
+
+    /// Report each of this client's requests to a [`MetricsRecorder`]
+    ///
+    /// The recorder is called with the endpoint template, status code and
+    /// latency of every call; see [`metrics`](crate::helper::metrics).
+    pub fn with_metrics_recorder(mut self, recorder: Arc<dyn MetricsRecorder>) -> Self {
+        self.client = self.client.metrics_recorder(recorder);
+        self
+    }
+
+    /// Abandon each of this client's requests that takes longer than `timeout`
+    ///
+    /// The timeout covers one HTTP exchange, from sending the request to
+    /// reading the response body; time spent waiting on a rate limiter is not
+    /// counted. A timed-out call fails with a `CircleError::Http` for which
+    /// [`CircleError::is_timeout`](crate::helper::CircleError::is_timeout)
+    /// is true. To bound a single call end to end instead, wrap it in
+    /// [`with_timeout`](crate::helper::with_timeout).
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.client = self.client.request_timeout(timeout);
+        self
+    }
+
+    /// Check this client's responses against the vendored Circle schemas
+    ///
+    /// Off by default. With it on, a response whose fields do not match
+    /// Circle's published schema fails with `CircleError::Schema`; see
+    /// [`schema`](crate::schema).
+    #[cfg(feature = "schema-validation")]
+    pub fn with_schema_validation(mut self) -> Self {
+        self.client = self.client.validate_schemas(true);
+        self
+    }
+
+    /// Allow mainnet blockchains in request bodies sent to the sandbox
+    ///
+    /// By default a sandbox client fails such requests with
+    /// `CircleError::Config` before sending them.
+    pub fn allow_mainnet_on_sandbox(mut self) -> Self {
+        self.client.allow_mainnet_on_sandbox();
+        self
+    }
+
+    /// Build an instance from an existing client
+    #[cfg(any(test, feature = "testing"))]
+    pub(crate) fn from_client(client: HttpClient) -> Self {
+        Self { client }
+    }
+
     /// Generic request method for read operations
     ///
     /// This is an internal helper method used by other methods in this struct.
@@ -115,6 +241,41 @@ impl CircleView {
         path: &str,
         body: Option<&T>,
     ) -> CircleResult<R>
+    where
+        T: Serialize,
+        R: for<'de> serde::Deserialize<'de>,
+    {
+        Ok(self.request_with_meta(method, path, body).await?.data)
+    }
+
+    /// Like [`request`](Self::request), but also returns the response's status and request ID
+    ///
+    /// The meta carries Circle's request ID, which Circle support asks for
+    /// when investigating a specific call. Failed calls carry it in
+    /// [`CircleError::request_id`](crate::helper::CircleError::request_id).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_view::circle_view::CircleView;
+    /// use reqwest::Method;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let view = CircleView::new()?;
+    ///
+    /// let response = view
+    ///     .request_with_meta::<(), serde_json::Value>(Method::GET, "/v1/w3s/wallets", None)
+    ///     .await?;
+    /// println!("Request ID: {:?}", response.meta.request_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn request_with_meta<T, R>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&T>,
+    ) -> CircleResult<WithMeta<R>>
     where
         T: Serialize,
         R: for<'de> serde::Deserialize<'de>,
@@ -122,10 +283,11 @@ impl CircleView {
         let mut request = self.client.request(method, path)?;
 
         if let Some(body) = body {
+            self.client.check_payload(&serde_json::to_value(body)?)?;
             request = request.json(body);
         }
 
-        self.client.execute(request).await
+        self.client.execute_with_meta(request).await
     }
 
     /// GET request with query parameters
@@ -224,9 +386,10 @@ impl CircleView {
         R: for<'de> serde::Deserialize<'de>,
     {
         let request = self.client.request(Method::GET, path)?;
-        let response = request.send().await?;
+        let response = self.client.execute_raw(request).await?;
 
         let status = response.status();
+        let request_id = response_request_id(&response);
         let response_text = response.text().await?;
         println!("Response text: {}", response_text);
 
@@ -241,6 +404,7 @@ impl CircleView {
             };
             Err(CircleError::Api {
                 status: status.as_u16(),
+                request_id,
                 message: error_message,
             })
         }
@@ -280,6 +444,46 @@ impl CircleView {
         self.request_with_params(path, params).await
     }
 
+    /// GET an endpoint the SDK does not model yet, as raw JSON
+    ///
+    /// `query` pairs are URL-encoded and appended to the path; pairs with an
+    /// empty value are skipped. Returns the response's `data` field.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_view::circle_view::CircleView;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let view = CircleView::new()?;
+    ///
+    /// let response = view
+    ///     .get_raw("/v1/w3s/some-new-resource", &[("pageSize", "10")])
+    ///     .await?;
+    /// println!("{}", response);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_raw(&self, path: &str, query: &[(&str, &str)]) -> CircleResult<Value> {
+        let query_string = query
+            .iter()
+            .filter(|(_, value)| !value.is_empty())
+            .map(|(key, value)| {
+                format!(
+                    "{}={}",
+                    urlencoding::encode(key),
+                    urlencoding::encode(value)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("&");
+        if query_string.is_empty() {
+            self.get(path).await
+        } else {
+            self.get(&format!("{}?{}", path, query_string)).await
+        }
+    }
+
     /// POST request helper
     ///
     /// Sends a POST request to the specified endpoint with the given body.
@@ -433,9 +637,10 @@ impl CircleView {
         use crate::helper::{CircleError, CircleErrorResponse};
 
         let request = self.client.request(Method::DELETE, path)?;
-        let response = request.send().await?;
+        let response = self.client.execute_raw(request).await?;
 
         let status = response.status();
+        let request_id = response_request_id(&response);
         if status.is_success() {
             Ok(())
         } else {
@@ -449,6 +654,7 @@ impl CircleView {
 
             Err(CircleError::Api {
                 status: status.as_u16(),
+                request_id,
                 message: error_message,
             })
         }
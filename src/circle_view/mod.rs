@@ -1,2 +1,4 @@
 // Re-export public types from submodules
+pub mod cache;
+#[allow(clippy::module_inception)]
 pub mod circle_view;
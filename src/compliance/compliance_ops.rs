@@ -0,0 +1,170 @@
+//! Compliance operations for CircleOps
+
+use crate::{
+    circle_ops::circler_ops::CircleOps,
+    compliance::{dto::AddressScreeningResponse, ops::screen_address::ScreenAddressRequestBuilder},
+    dev_wallet::ops::create_transfer_transaction::CreateTransferTransactionRequestBuilder,
+    helper::{generate_uuid, CircleError, CircleResult},
+};
+
+impl CircleOps {
+    /// Screen a blockchain address against the entity's compliance rules
+    ///
+    /// # Arguments
+    ///
+    /// * `builder` - Address, blockchain and optional idempotency key
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+    /// use inf_circle_sdk::compliance::ops::screen_address::ScreenAddressRequestBuilder;
+    /// use inf_circle_sdk::types::Blockchain;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ops = CircleOps::new(None)?;
+    ///
+    /// let builder = ScreenAddressRequestBuilder::new(
+    ///     "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb0".to_string(),
+    ///     Blockchain::EthSepolia,
+    /// )
+    /// .build();
+    ///
+    /// let screening = ops.screen_address(builder).await?;
+    /// for signal in screening.risk_signals() {
+    ///     println!("{} ({}): {:?}", signal.source, signal.risk_score, signal.risk_categories);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn screen_address(
+        &self,
+        builder: ScreenAddressRequestBuilder,
+    ) -> CircleResult<AddressScreeningResponse> {
+        let request = builder.into_request(generate_uuid);
+        self.post("/v1/w3s/compliance/screening/addresses", &request)
+            .await
+    }
+
+    /// Screen the destination of a transfer before submitting it
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Config` if the builder has no destination address
+    /// or no explicit blockchain, since screening needs both.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+    /// use inf_circle_sdk::dev_wallet::ops::create_transfer_transaction::CreateTransferTransactionRequestBuilder;
+    ///
+    /// # async fn example(transfer: CreateTransferTransactionRequestBuilder) -> Result<(), Box<dyn std::error::Error>> {
+    /// let ops = CircleOps::new(None)?;
+    ///
+    /// let screening = ops.screen_transfer_destination(&transfer).await?;
+    /// if screening.is_approved() {
+    ///     ops.create_dev_transfer_transaction(transfer).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn screen_transfer_destination(
+        &self,
+        builder: &CreateTransferTransactionRequestBuilder,
+    ) -> CircleResult<AddressScreeningResponse> {
+        if builder.destination_address.is_empty() {
+            return Err(CircleError::Config(
+                "Transfer has no destination address to screen".to_string(),
+            ));
+        }
+        let blockchain = builder.blockchain.clone().ok_or_else(|| {
+            CircleError::Config("Screening a transfer requires an explicit blockchain".to_string())
+        })?;
+
+        self.screen_address(ScreenAddressRequestBuilder::new(
+            builder.destination_address.clone(),
+            blockchain,
+        ))
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        compliance::dto::ScreeningResult,
+        dev_wallet::ops::create_transfer_transaction::CreateTransferTransactionRequestBuilder,
+        helper::CircleError,
+        testing::{fixtures, MockCircle},
+        types::Blockchain,
+    };
+    use mockito::Matcher;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_screen_transfer_destination() {
+        let mut circle = MockCircle::start().await;
+        let mock = circle
+            .server()
+            .mock("POST", "/v1/w3s/compliance/screening/addresses")
+            .match_body(Matcher::PartialJson(json!({
+                "address": fixtures::WALLET_ADDRESS,
+                "chain": "ETH-SEPOLIA",
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "data": {
+                        "result": "DENIED",
+                        "address": fixtures::WALLET_ADDRESS,
+                        "chain": "ETH-SEPOLIA",
+                        "alertId": "alert-1",
+                        "decision": {
+                            "ruleName": "Sanctions",
+                            "actions": ["REVIEW"],
+                            "reasons": [{
+                                "source": "ADDRESS",
+                                "sourceValue": fixtures::WALLET_ADDRESS,
+                                "riskScore": "SEVERE",
+                                "riskCategories": ["SANCTIONS"],
+                                "type": "OWNERSHIP"
+                            }]
+                        }
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let transfer = CreateTransferTransactionRequestBuilder::new()
+            .wallet_id(fixtures::WALLET_ID.to_string())
+            .destination_address(fixtures::WALLET_ADDRESS.to_string())
+            .amounts(["1"])
+            .blockchain(Blockchain::EthSepolia);
+        let screening = circle
+            .ops()
+            .screen_transfer_destination(&transfer)
+            .await
+            .unwrap();
+
+        assert_eq!(screening.result, ScreeningResult::Denied);
+        assert!(!screening.is_approved());
+        assert_eq!(screening.risk_signals()[0].risk_categories, ["SANCTIONS"]);
+        mock.assert_async().await;
+
+        let without_chain = CreateTransferTransactionRequestBuilder {
+            blockchain: None,
+            ..transfer
+        };
+        assert!(matches!(
+            circle
+                .ops()
+                .screen_transfer_destination(&without_chain)
+                .await,
+            Err(CircleError::Config(_))
+        ));
+    }
+}
@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+
+pub use crate::dev_wallet::dto::{RiskSignal, TransactionScreeningEvaluation};
+use crate::types::Blockchain;
+
+/// Request structure for screening a blockchain address
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScreenAddressRequest {
+    /// UUID v4 for idempotency
+    pub idempotency_key: String,
+
+    /// Address to screen
+    pub address: String,
+
+    /// Blockchain the address belongs to
+    pub chain: Blockchain,
+}
+
+/// Outcome of an address screening
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ScreeningResult {
+    /// No rule matched; transfers to the address may proceed
+    Approved,
+    /// A rule matched; see the decision for the risk signals
+    Denied,
+    /// A result this SDK version does not know about
+    #[serde(other)]
+    Unknown,
+}
+
+/// Response structure for address screening
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressScreeningResponse {
+    /// Screening outcome
+    pub result: ScreeningResult,
+
+    /// The screened address
+    pub address: String,
+
+    /// Blockchain the address was screened on
+    pub chain: Blockchain,
+
+    /// Matched rule, actions and risk signals
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decision: Option<TransactionScreeningEvaluation>,
+
+    /// Compliance alert raised by a denial, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alert_id: Option<String>,
+}
+
+impl AddressScreeningResponse {
+    /// Whether the address passed screening
+    pub fn is_approved(&self) -> bool {
+        self.result == ScreeningResult::Approved
+    }
+
+    /// Risk signals behind the decision (empty when none were reported)
+    pub fn risk_signals(&self) -> &[RiskSignal] {
+        self.decision
+            .as_ref()
+            .and_then(|decision| decision.reasons.as_deref())
+            .unwrap_or_default()
+    }
+}
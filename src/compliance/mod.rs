@@ -0,0 +1,50 @@
+//! Compliance Screening
+//!
+//! This module exposes Circle's address screening endpoint so a destination
+//! address can be checked against the entity's compliance rules before a
+//! transfer is created. Denials carry the same [`RiskSignal`](dto::RiskSignal)
+//! data that Circle attaches to screened transactions.
+//!
+//! # Main Components
+//!
+//! - [`compliance_ops`]: Write operations (screen an address or a transfer's destination)
+//! - [`dto`]: Data transfer objects (screening requests and results)
+//! - [`ops`]: Builder modules for write operations
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::{
+//!     circle_ops::circler_ops::CircleOps,
+//!     dev_wallet::{dto::FeeLevel, ops::create_transfer_transaction::CreateTransferTransactionRequestBuilder},
+//!     helper::CircleError,
+//!     types::Blockchain,
+//! };
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let ops = CircleOps::new(None)?;
+//!
+//! let transfer = CreateTransferTransactionRequestBuilder::new()
+//!     .wallet_id("wallet-id".to_string())
+//!     .destination_address("0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb0".to_string())
+//!     .amounts(["10"])
+//!     .blockchain(Blockchain::EthSepolia)
+//!     .fee_level(FeeLevel::Medium)
+//!     .build();
+//!
+//! let screening = ops.screen_transfer_destination(&transfer).await?;
+//! if !screening.is_approved() {
+//!     return Err(CircleError::Config(format!(
+//!         "Destination failed screening: {:?}",
+//!         screening.risk_signals()
+//!     ))
+//!     .into());
+//! }
+//! ops.create_dev_transfer_transaction(transfer).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod compliance_ops;
+pub mod dto;
+pub mod ops;
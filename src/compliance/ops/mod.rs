@@ -0,0 +1 @@
+pub mod screen_address;
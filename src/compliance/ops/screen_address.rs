@@ -0,0 +1,80 @@
+use crate::compliance::dto::ScreenAddressRequest;
+use crate::helper::{preview_request, CircleResult, GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER};
+use crate::types::Blockchain;
+
+/// Builder for address screening requests
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use inf_circle_sdk::compliance::ops::screen_address::ScreenAddressRequestBuilder;
+/// use inf_circle_sdk::types::Blockchain;
+///
+/// let builder = ScreenAddressRequestBuilder::new(
+///     "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb0".to_string(),
+///     Blockchain::EthSepolia,
+/// )
+/// .build();
+/// ```
+#[derive(Clone, Debug)]
+pub struct ScreenAddressRequestBuilder {
+    pub address: String,
+    pub blockchain: Blockchain,
+    pub idempotency_key: Option<String>,
+}
+
+impl ScreenAddressRequestBuilder {
+    /// Create a new builder with required fields
+    ///
+    /// # Arguments
+    /// * `address` - The address to screen
+    /// * `blockchain` - Blockchain the address belongs to
+    pub fn new(address: String, blockchain: Blockchain) -> Self {
+        Self {
+            address,
+            blockchain,
+            idempotency_key: None,
+        }
+    }
+
+    /// Set a custom idempotency key
+    ///
+    /// If not set, a UUID is generated when the request is sent.
+    pub fn idempotency_key(mut self, key: String) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
+
+    /// Convert the builder into the API request body
+    ///
+    /// `generate_idempotency_key` is only called when no custom key was set.
+    pub(crate) fn into_request(
+        self,
+        generate_idempotency_key: impl FnOnce() -> String,
+    ) -> ScreenAddressRequest {
+        ScreenAddressRequest {
+            idempotency_key: self
+                .idempotency_key
+                .unwrap_or_else(generate_idempotency_key),
+            address: self.address,
+            chain: self.blockchain,
+        }
+    }
+
+    /// Preview the request body as pretty JSON
+    ///
+    /// When no idempotency key was set, the key is shown as
+    /// [`GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER`].
+    pub fn preview(&self) -> CircleResult<String> {
+        preview_request(
+            &self
+                .clone()
+                .into_request(|| GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER.to_string()),
+        )
+    }
+
+    /// Build the ScreenAddressRequestBuilder
+    pub fn build(self) -> ScreenAddressRequestBuilder {
+        self
+    }
+}
@@ -0,0 +1,764 @@
+//! Event and Function Signatures from Contract ABIs
+//!
+//! Circle stores a contract's ABI alongside the contract and also returns its
+//! `events` and `functions` entries separately. This module turns those JSON
+//! entries into typed [`EventSignature`] and [`FunctionSignature`] values with
+//! the canonical `Name(type,...)` form, the Keccak-256 event topic or function
+//! selector, and the named parameters, ready to use with event monitors,
+//! contract queries and log decoding. [`EventLog::decode`] uses them to turn
+//! raw log topics and data into named, typed parameters, and
+//! [`validate_constructor_values`] checks deployment arguments against the
+//! ABI's constructor. [`Contract::typed_abi`] gathers all of them into a
+//! [`ContractAbi`].
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::{
+//!     circle_view::circle_view::CircleView,
+//!     contract::abi::{extract_event_signatures, extract_function_signatures},
+//! };
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let view = CircleView::new()?;
+//! let contract = view.get_contract("contract-id").await?.contract;
+//!
+//! for event in extract_event_signatures(&contract) {
+//!     println!("{} -> topic {}", event.canonical, event.hash);
+//! }
+//! for function in extract_function_signatures(&contract) {
+//!     println!("{} -> selector {}", function.canonical, function.selector);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Example - Decode an Event Log
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::circle_view::circle_view::CircleView;
+//!
+//! # async fn example(abi_json: &str) -> Result<(), Box<dyn std::error::Error>> {
+//! let view = CircleView::new()?;
+//!
+//! for log in view.list_event_logs(None).await? {
+//!     let event = log.decode(abi_json)?;
+//!     println!("{}: {:?}", event.name, event.get("value"));
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use serde_json::Value;
+
+use crate::{
+    abi::{
+        check_value, decode_values, decode_word, encode, function_selector, keccak256, AbiValue,
+        FixedBytesValue,
+    },
+    contract::dto::{Contract, EventLog},
+    helper::{CircleError, CircleResult},
+};
+
+/// A named parameter of an ABI event or function
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AbiParam {
+    /// Parameter name (empty when the ABI leaves it unnamed)
+    pub name: String,
+
+    /// Canonical type, with tuples expanded (e.g. `(address,uint256)[]`)
+    pub ty: String,
+
+    /// Whether the parameter is an indexed event topic (always `false` for functions)
+    pub indexed: bool,
+}
+
+/// An event declared in a contract ABI
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventSignature {
+    /// Event name
+    pub name: String,
+
+    /// Event parameters in declaration order
+    pub inputs: Vec<AbiParam>,
+
+    /// Whether the event is anonymous (emitted without a signature topic)
+    pub anonymous: bool,
+
+    /// Canonical signature, e.g. `Transfer(address,address,uint256)`
+    pub canonical: String,
+
+    /// `0x`-prefixed Keccak-256 hash of the canonical signature (`topic0`)
+    pub hash: String,
+}
+
+/// A function declared in a contract ABI
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionSignature {
+    /// Function name
+    pub name: String,
+
+    /// Function parameters in declaration order
+    pub inputs: Vec<AbiParam>,
+
+    /// Return values in declaration order
+    pub outputs: Vec<AbiParam>,
+
+    /// `pure`, `view`, `nonpayable` or `payable`, when the ABI declares it
+    pub state_mutability: Option<String>,
+
+    /// Canonical signature, e.g. `transfer(address,uint256)`
+    pub canonical: String,
+
+    /// `0x`-prefixed 4-byte function selector
+    pub selector: String,
+}
+
+impl FunctionSignature {
+    /// Whether the function can be called with `query_contract` without a transaction
+    pub fn is_read_only(&self) -> bool {
+        matches!(self.state_mutability.as_deref(), Some("view" | "pure"))
+    }
+}
+
+/// A contract ABI parsed into its constructor, functions and events
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ContractAbi {
+    /// Constructor inputs (empty when the ABI declares no constructor)
+    pub constructor: Vec<AbiParam>,
+
+    /// Functions in declaration order
+    pub functions: Vec<FunctionSignature>,
+
+    /// Events in declaration order
+    pub events: Vec<EventSignature>,
+}
+
+impl ContractAbi {
+    /// Parse an ABI JSON array, or a string holding one
+    ///
+    /// Function and event entries that are not well-formed are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Json` if a string `abi` is not valid JSON and
+    /// `CircleError::Abi` if the ABI is not an array or its constructor entry
+    /// is malformed.
+    pub fn parse(abi: &Value) -> CircleResult<Self> {
+        let abi = match abi {
+            Value::String(json) => serde_json::from_str(json)?,
+            other => other.clone(),
+        };
+        if !abi.is_array() {
+            return Err(CircleError::Abi("ABI must be a JSON array".to_string()));
+        }
+        Ok(Self {
+            constructor: parse_constructor_inputs(&abi)?,
+            functions: parse_function_signatures(&abi),
+            events: parse_event_signatures(&abi),
+        })
+    }
+
+    /// Parse an ABI from its JSON text (see [`parse`](Self::parse))
+    pub fn from_json(abi_json: &str) -> CircleResult<Self> {
+        Self::parse(&serde_json::from_str(abi_json)?)
+    }
+
+    /// Whether the ABI declares no constructor inputs, functions or events
+    pub fn is_empty(&self) -> bool {
+        self.constructor.is_empty() && self.functions.is_empty() && self.events.is_empty()
+    }
+
+    /// The function with canonical signature `signature`, or else the first one named `signature`
+    ///
+    /// Pass the canonical form (e.g. `transfer(address,uint256)`) to pick
+    /// one overload of an overloaded function.
+    pub fn function(&self, signature: &str) -> Option<&FunctionSignature> {
+        self.functions
+            .iter()
+            .find(|function| function.canonical == signature)
+            .or_else(|| {
+                self.functions
+                    .iter()
+                    .find(|function| function.name == signature)
+            })
+    }
+
+    /// The event with canonical signature `signature`, or else the first one named `signature`
+    pub fn event(&self, signature: &str) -> Option<&EventSignature> {
+        self.events
+            .iter()
+            .find(|event| event.canonical == signature)
+            .or_else(|| self.events.iter().find(|event| event.name == signature))
+    }
+}
+
+impl Contract {
+    /// The contract's ABI, parsed
+    ///
+    /// Functions and events come from the same sources as
+    /// [`extract_function_signatures`] and [`extract_event_signatures`].
+    /// Returns `None` when Circle holds no usable ABI for the contract, as is
+    /// common for imported contracts; attach one with
+    /// [`CircleOps::update_contract_abi`](crate::circle_ops::circler_ops::CircleOps::update_contract_abi).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_view::circle_view::CircleView;
+    /// use inf_circle_sdk::contract::views::query_contract_view::QueryContractViewBodyBuilder;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let view = CircleView::new()?;
+    /// let contract = view.get_contract("contract-id").await?.contract;
+    ///
+    /// let abi = contract.typed_abi().ok_or("contract has no ABI")?;
+    /// let total_supply = abi.function("totalSupply").ok_or("not a token")?;
+    /// assert!(total_supply.is_read_only());
+    ///
+    /// let query = QueryContractViewBodyBuilder::new(
+    ///     contract.blockchain.clone().ok_or("no blockchain")?,
+    ///     contract.contract_address.clone().ok_or("not deployed")?,
+    /// )
+    /// .abi_function_signature(total_supply.canonical.clone())
+    /// .abi_parameters(vec![]);
+    /// let output = view.query_contract(query).await?;
+    /// println!("{:?}", output.output_values);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn typed_abi(&self) -> Option<ContractAbi> {
+        let constructor = contract_entries(self, None, "constructor")
+            .first()
+            .and_then(|entry| parse_params(entry.get("inputs")))
+            .unwrap_or_default();
+        let abi = ContractAbi {
+            constructor,
+            functions: extract_function_signatures(self),
+            events: extract_event_signatures(self),
+        };
+        (!abi.is_empty()).then_some(abi)
+    }
+}
+
+/// An event log decoded against its ABI
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedEvent {
+    /// Event name
+    pub name: String,
+
+    /// Canonical signature of the matched event
+    pub signature: String,
+
+    /// Parameters in declaration order, indexed and non-indexed
+    pub params: Vec<DecodedEventParam>,
+}
+
+impl DecodedEvent {
+    /// Value of the parameter called `name`
+    pub fn get(&self, name: &str) -> Option<&AbiValue> {
+        self.params
+            .iter()
+            .find(|param| param.name == name)
+            .map(|param| &param.value)
+    }
+}
+
+/// A decoded event parameter
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedEventParam {
+    /// Parameter name (empty when the ABI leaves it unnamed)
+    pub name: String,
+
+    /// Canonical Solidity type
+    pub ty: String,
+
+    /// Whether the value came from a topic rather than the log data
+    pub indexed: bool,
+
+    /// Decoded value
+    ///
+    /// Indexed `string`, `bytes`, array and tuple parameters are stored on
+    /// chain only as the Keccak-256 hash of their value, so they decode to the
+    /// 32-byte hash as [`AbiValue::FixedBytes`].
+    pub value: AbiValue,
+}
+
+impl EventLog {
+    /// Decode the log's topics and data against a contract ABI
+    ///
+    /// The event is selected by `topic0`. When several ABI events share a
+    /// signature but differ in which parameters are indexed (e.g. ERC-20 and
+    /// ERC-721 `Transfer`), the one matching the log's topic count is used.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Json` if `abi_json` is not valid JSON and
+    /// `CircleError::Abi` if no ABI event matches the log or its topics and
+    /// data do not fit the event's parameters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use inf_circle_sdk::abi::AbiValue;
+    /// # use inf_circle_sdk::contract::dto::EventLog;
+    /// # fn example(log: EventLog) -> Result<(), Box<dyn std::error::Error>> {
+    /// let abi_json = r#"[{"type":"event","name":"Transfer","inputs":[
+    ///     {"name":"from","type":"address","indexed":true},
+    ///     {"name":"to","type":"address","indexed":true},
+    ///     {"name":"value","type":"uint256","indexed":false}]}]"#;
+    ///
+    /// let event = log.decode(abi_json)?;
+    /// if let Some(AbiValue::Uint(value)) = event.get("value") {
+    ///     println!("{} transferred {}", event.name, value);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decode(&self, abi_json: &str) -> CircleResult<DecodedEvent> {
+        let abi: Value = serde_json::from_str(abi_json)?;
+        let topic0 = self
+            .topics
+            .first()
+            .map(String::as_str)
+            .unwrap_or(&self.event_signature_hash);
+
+        let candidates: Vec<EventSignature> = parse_event_signatures(&abi)
+            .into_iter()
+            .filter(|event| !event.anonymous && same_hex(&event.hash, topic0))
+            .collect();
+        let indexed_topics = self.topics.len().saturating_sub(1);
+        let event = candidates
+            .iter()
+            .find(|event| event.inputs.iter().filter(|p| p.indexed).count() == indexed_topics)
+            .or(candidates.first())
+            .ok_or_else(|| {
+                CircleError::Abi(format!("No event in the ABI matches topic {}", topic0))
+            })?;
+
+        let data = hex::decode(self.data.trim_start_matches("0x"))
+            .map_err(|e| CircleError::Abi(format!("Invalid hex log data: {}", e)))?;
+        let data_types: Vec<&str> = event
+            .inputs
+            .iter()
+            .filter(|p| !p.indexed)
+            .map(|p| p.ty.as_str())
+            .collect();
+        let mut data_values = decode_values(&data_types, &data)?.into_iter();
+        let mut topics = self.topics.iter().skip(1);
+
+        let params = event
+            .inputs
+            .iter()
+            .map(|param| {
+                let value = if param.indexed {
+                    let topic = topics.next().ok_or_else(|| {
+                        CircleError::Abi(format!(
+                            "Missing topic for indexed parameter {}",
+                            param.name
+                        ))
+                    })?;
+                    decode_topic(&param.ty, topic)?
+                } else {
+                    data_values.next().ok_or_else(|| {
+                        CircleError::Abi(format!("Missing data for parameter {}", param.name))
+                    })?
+                };
+                Ok(DecodedEventParam {
+                    name: param.name.clone(),
+                    ty: param.ty.clone(),
+                    indexed: param.indexed,
+                    value,
+                })
+            })
+            .collect::<CircleResult<Vec<_>>>()?;
+
+        Ok(DecodedEvent {
+            name: event.name.clone(),
+            signature: event.canonical.clone(),
+            params,
+        })
+    }
+}
+
+fn decode_topic(ty: &str, topic: &str) -> CircleResult<AbiValue> {
+    let word: [u8; 32] = hex::decode(topic.trim_start_matches("0x"))
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| CircleError::Abi(format!("Invalid log topic: {}", topic)))?;
+    Ok(decode_word(ty, &word)?
+        .unwrap_or_else(|| AbiValue::FixedBytes(FixedBytesValue::new_unchecked(word.to_vec()))))
+}
+
+fn same_hex(a: &str, b: &str) -> bool {
+    a.trim_start_matches("0x")
+        .eq_ignore_ascii_case(b.trim_start_matches("0x"))
+}
+
+/// Extract the events declared by a contract
+///
+/// Uses the contract's `events` entries when present, falling back to the
+/// event entries of its stored ABI (`abi` or `abiJson`). Entries that are not
+/// well-formed ABI JSON are skipped.
+pub fn extract_event_signatures(contract: &Contract) -> Vec<EventSignature> {
+    contract_entries(contract, contract.events.as_ref(), "event")
+        .iter()
+        .filter_map(parse_event)
+        .collect()
+}
+
+/// Extract the functions declared by a contract
+///
+/// Uses the contract's `functions` entries when present, falling back to the
+/// function entries of its stored ABI (`abi` or `abiJson`). Entries that are
+/// not well-formed ABI JSON are skipped.
+pub fn extract_function_signatures(contract: &Contract) -> Vec<FunctionSignature> {
+    contract_entries(contract, contract.functions.as_ref(), "function")
+        .iter()
+        .filter_map(parse_function)
+        .collect()
+}
+
+/// Parse the events of an ABI JSON array
+///
+/// Returns an empty list if `abi` is not an array.
+pub fn parse_event_signatures(abi: &Value) -> Vec<EventSignature> {
+    entries_of_kind(abi, "event", false)
+        .iter()
+        .filter_map(parse_event)
+        .collect()
+}
+
+/// Parse the functions of an ABI JSON array
+///
+/// Returns an empty list if `abi` is not an array.
+pub fn parse_function_signatures(abi: &Value) -> Vec<FunctionSignature> {
+    entries_of_kind(abi, "function", false)
+        .iter()
+        .filter_map(parse_function)
+        .collect()
+}
+
+/// Parse the constructor inputs of an ABI JSON array
+///
+/// Returns an empty list when the ABI declares no constructor, and an error if
+/// the constructor entry is malformed.
+pub fn parse_constructor_inputs(abi: &Value) -> CircleResult<Vec<AbiParam>> {
+    match entries_of_kind(abi, "constructor", false).first() {
+        Some(entry) => parse_params(entry.get("inputs"))
+            .ok_or_else(|| CircleError::Abi("Malformed constructor inputs in ABI".to_string())),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Check constructor arguments against the constructor declared in an ABI
+///
+/// The number of values must match the constructor inputs, and each value must
+/// be valid for its input type as defined by [`check_value`].
+pub fn validate_constructor_values(abi: &Value, values: &[AbiValue]) -> CircleResult<()> {
+    let inputs = parse_constructor_inputs(abi)?;
+    if inputs.len() != values.len() {
+        return Err(CircleError::Abi(format!(
+            "Constructor expects {} parameters, got {}",
+            inputs.len(),
+            values.len()
+        )));
+    }
+
+    for (index, (input, value)) in inputs.iter().zip(values).enumerate() {
+        check_value(&input.ty, value).map_err(|e| {
+            let name = if input.name.is_empty() {
+                format!("#{}", index)
+            } else {
+                input.name.clone()
+            };
+            CircleError::Abi(format!("Constructor parameter {}: {}", name, e))
+        })?;
+    }
+    Ok(())
+}
+
+/// ABI-encode constructor arguments after validating them against the ABI
+///
+/// The result is what gets appended to the creation bytecode, and is also what
+/// block explorers ask for when verifying a deployed contract.
+pub fn encode_constructor_args(abi: &Value, values: &[AbiValue]) -> CircleResult<Vec<u8>> {
+    validate_constructor_values(abi, values)?;
+    Ok(encode(values))
+}
+
+/// The `kind` entries of a contract, from its dedicated field or its stored ABI
+fn contract_entries(contract: &Contract, field: Option<&Value>, kind: &str) -> Vec<Value> {
+    // The dedicated fields may omit `type`, since every entry has the same kind
+    let entries = field.map(|v| entries_of_kind(v, kind, true));
+    if let Some(entries) = entries.filter(|e| !e.is_empty()) {
+        return entries;
+    }
+
+    let parsed_abi_json = contract
+        .abi_json
+        .as_deref()
+        .and_then(|json| serde_json::from_str::<Value>(json).ok());
+    contract
+        .abi
+        .iter()
+        .chain(parsed_abi_json.iter())
+        .map(|abi| entries_of_kind(abi, kind, false))
+        .find(|e| !e.is_empty())
+        .unwrap_or_default()
+}
+
+fn entries_of_kind(abi: &Value, kind: &str, allow_untyped: bool) -> Vec<Value> {
+    let abi = match abi {
+        Value::String(json) => serde_json::from_str(json).unwrap_or(Value::Null),
+        other => other.clone(),
+    };
+    let Value::Array(entries) = abi else {
+        return Vec::new();
+    };
+    entries
+        .into_iter()
+        .filter(|entry| match entry.get("type").and_then(Value::as_str) {
+            Some(ty) => ty == kind,
+            None => allow_untyped,
+        })
+        .collect()
+}
+
+fn parse_event(entry: &Value) -> Option<EventSignature> {
+    let name = entry.get("name")?.as_str()?.to_string();
+    let inputs = parse_params(entry.get("inputs"))?;
+    let canonical = canonical_signature(&name, &inputs);
+    Some(EventSignature {
+        hash: format!("0x{}", hex::encode(keccak256(canonical.as_bytes()))),
+        anonymous: entry
+            .get("anonymous")
+            .and_then(Value::as_bool)
+            .unwrap_or(false),
+        name,
+        inputs,
+        canonical,
+    })
+}
+
+fn parse_function(entry: &Value) -> Option<FunctionSignature> {
+    let name = entry.get("name")?.as_str()?.to_string();
+    let inputs = parse_params(entry.get("inputs"))?;
+    let outputs = parse_params(entry.get("outputs"))?;
+    let canonical = canonical_signature(&name, &inputs);
+    Some(FunctionSignature {
+        selector: format!("0x{}", hex::encode(function_selector(&canonical))),
+        state_mutability: entry
+            .get("stateMutability")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        name,
+        inputs,
+        outputs,
+        canonical,
+    })
+}
+
+/// Parse an `inputs`/`outputs` list; a missing list means no parameters
+fn parse_params(params: Option<&Value>) -> Option<Vec<AbiParam>> {
+    let Some(params) = params else {
+        return Some(Vec::new());
+    };
+    params
+        .as_array()?
+        .iter()
+        .map(|param| {
+            Some(AbiParam {
+                name: param
+                    .get("name")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+                ty: canonical_type(param)?,
+                indexed: param
+                    .get("indexed")
+                    .and_then(Value::as_bool)
+                    .unwrap_or(false),
+            })
+        })
+        .collect()
+}
+
+/// Canonical type of an ABI parameter, expanding `tuple` components
+fn canonical_type(param: &Value) -> Option<String> {
+    let ty = param.get("type")?.as_str()?;
+    match ty.strip_prefix("tuple") {
+        Some(suffix) => {
+            let components: Option<Vec<String>> = param
+                .get("components")?
+                .as_array()?
+                .iter()
+                .map(canonical_type)
+                .collect();
+            Some(format!("({}){}", components?.join(","), suffix))
+        }
+        None => Some(ty.to_string()),
+    }
+}
+
+fn canonical_signature(name: &str, params: &[AbiParam]) -> String {
+    let types: Vec<&str> = params.iter().map(|p| p.ty.as_str()).collect();
+    format!("{}({})", name, types.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures;
+    use serde_json::json;
+
+    #[test]
+    fn test_contract_abi_lookup_prefers_canonical_signature() {
+        let abi = ContractAbi::parse(&json!(r#"[
+            {"type":"constructor","inputs":[{"name":"owner","type":"address"}]},
+            {"type":"function","name":"safeTransferFrom","inputs":[
+                {"name":"from","type":"address"},{"name":"to","type":"address"},{"name":"id","type":"uint256"}]},
+            {"type":"function","name":"safeTransferFrom","inputs":[
+                {"name":"from","type":"address"},{"name":"to","type":"address"},{"name":"id","type":"uint256"},
+                {"name":"data","type":"bytes"}]},
+            {"type":"event","name":"Approval","inputs":[]}
+        ]"#))
+        .unwrap();
+
+        assert_eq!(abi.constructor[0].ty, "address");
+        assert_eq!(
+            abi.function("safeTransferFrom(address,address,uint256,bytes)")
+                .unwrap()
+                .inputs
+                .len(),
+            4
+        );
+        assert_eq!(abi.function("safeTransferFrom").unwrap().inputs.len(), 3);
+        assert!(abi.event("Approval()").is_some());
+        assert!(abi.function("approve").is_none());
+        assert!(ContractAbi::from_json("{}").is_err());
+    }
+
+    #[test]
+    fn test_extract_event_signatures_from_events_field() {
+        let mut contract = fixtures::contract();
+        contract.events = Some(json!([
+            {
+                "name": "Transfer",
+                "inputs": [
+                    {"name": "from", "type": "address", "indexed": true},
+                    {"name": "to", "type": "address", "indexed": true},
+                    {"name": "value", "type": "uint256", "indexed": false}
+                ]
+            },
+            {
+                "type": "event",
+                "name": "Filled",
+                "inputs": [{
+                    "name": "orders",
+                    "type": "tuple[]",
+                    "components": [
+                        {"name": "maker", "type": "address"},
+                        {"name": "amount", "type": "uint256"}
+                    ]
+                }]
+            }
+        ]));
+
+        let events = extract_event_signatures(&contract);
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].canonical, "Transfer(address,address,uint256)");
+        assert_eq!(
+            events[0].hash,
+            "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+        );
+        assert!(events[0].inputs[1].indexed);
+        assert_eq!(events[0].inputs[2].name, "value");
+        assert_eq!(events[1].canonical, "Filled((address,uint256)[])");
+    }
+
+    #[test]
+    fn test_extract_function_signatures_from_abi_json() {
+        let mut contract = fixtures::contract();
+        contract.abi_json = Some(
+            json!([
+                {"type": "constructor", "inputs": []},
+                {"type": "event", "name": "Paused", "inputs": []},
+                {
+                    "type": "function",
+                    "name": "transfer",
+                    "stateMutability": "nonpayable",
+                    "inputs": [
+                        {"name": "to", "type": "address"},
+                        {"name": "amount", "type": "uint256"}
+                    ],
+                    "outputs": [{"name": "", "type": "bool"}]
+                },
+                {
+                    "type": "function",
+                    "name": "totalSupply",
+                    "stateMutability": "view",
+                    "inputs": [],
+                    "outputs": [{"name": "", "type": "uint256"}]
+                }
+            ])
+            .to_string(),
+        );
+
+        let functions = extract_function_signatures(&contract);
+        assert_eq!(functions.len(), 2);
+        assert_eq!(functions[0].canonical, "transfer(address,uint256)");
+        assert_eq!(functions[0].selector, "0xa9059cbb");
+        assert!(!functions[0].is_read_only());
+        assert!(functions[1].is_read_only());
+
+        let events = extract_event_signatures(&contract);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].canonical, "Paused()");
+    }
+
+    #[test]
+    fn test_decode_event_log() {
+        let abi_json = json!([
+            {
+                "type": "event",
+                "name": "Transfer",
+                "inputs": [
+                    {"name": "from", "type": "address", "indexed": true},
+                    {"name": "to", "type": "address", "indexed": true},
+                    {"name": "tokenId", "type": "uint256", "indexed": true}
+                ]
+            },
+            {
+                "type": "event",
+                "name": "Transfer",
+                "inputs": [
+                    {"name": "from", "type": "address", "indexed": true},
+                    {"name": "to", "type": "address", "indexed": true},
+                    {"name": "value", "type": "uint256", "indexed": false}
+                ]
+            }
+        ])
+        .to_string();
+
+        let log = fixtures::event_log();
+        let event = log.decode(&abi_json).unwrap();
+
+        assert_eq!(event.signature, "Transfer(address,address,uint256)");
+        assert_eq!(event.params.len(), 3);
+        assert!(!event.params[2].indexed);
+        assert_eq!(
+            event.get("from"),
+            Some(&AbiValue::Address(
+                fixtures::WALLET_ADDRESS.parse().unwrap()
+            ))
+        );
+        assert_eq!(
+            event.get("value"),
+            Some(&AbiValue::Uint(crate::abi::U256::from(1_000_000u64)))
+        );
+
+        let unrelated = json!([{"type": "event", "name": "Paused", "inputs": []}]).to_string();
+        assert!(matches!(log.decode(&unrelated), Err(CircleError::Abi(_))));
+    }
+}
@@ -0,0 +1,53 @@
+//! Shared contract call helpers
+//!
+//! Internal helpers used by the token clients to query and execute contract
+//! functions with locally encoded call data.
+
+use crate::{
+    abi::{decode_hex, encode_function_call, AbiDecode, AbiValue},
+    circle_ops::circler_ops::CircleOps,
+    circle_view::circle_view::CircleView,
+    contract::views::query_contract_view::QueryContractViewBodyBuilder,
+    dev_wallet::{
+        dto::{CreateContractExecutionTransactionResponse, FeeConfig},
+        ops::create_contract_transaction::CreateContractExecutionTransactionRequestBuilder,
+    },
+    helper::CircleResult,
+    types::Blockchain,
+};
+
+/// Query a read-only function and decode its return value
+pub(crate) async fn query_decoded<T: AbiDecode>(
+    view: &CircleView,
+    blockchain: &Blockchain,
+    contract_address: &str,
+    signature: &str,
+    values: &[AbiValue],
+) -> CircleResult<T> {
+    let builder =
+        QueryContractViewBodyBuilder::new(blockchain.clone(), contract_address.to_string())
+            .call_data(encode_function_call(signature, values));
+
+    let response = view.query_contract(builder).await?;
+    decode_hex(&response.output_data)
+}
+
+/// Submit a state-changing function call from a developer wallet
+pub(crate) async fn execute_encoded(
+    ops: &CircleOps,
+    wallet_id: &str,
+    contract_address: &str,
+    fee: &FeeConfig,
+    signature: &str,
+    values: &[AbiValue],
+) -> CircleResult<CreateContractExecutionTransactionResponse> {
+    let builder = CreateContractExecutionTransactionRequestBuilder::new(
+        wallet_id.to_string(),
+        contract_address.to_string(),
+    )
+    .encoded_call(signature, values)
+    .fee(fee.clone())
+    .build();
+
+    ops.create_dev_contract_execution_transaction(builder).await
+}
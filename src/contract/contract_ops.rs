@@ -1,15 +1,22 @@
 //! Contract write operations for CircleOps
 
+use std::time::Duration;
+
 use crate::circle_ops::circler_ops::CircleOps;
+use crate::circle_view::circle_view::CircleView;
+use crate::contract::abi::ContractAbi;
 use crate::contract::dto::{
-    ContractDeploymentResponse, ContractResponse, DeployContractFromTemplateRequest,
-    DeployContractRequest, ImportContractRequest, TemplateContractDeploymentResponse,
+    ArchiveContractRequest, Contract, ContractDeploymentResponse, ContractResponse,
+    TemplateContractDeploymentResponse, UpdateContractAbiRequest,
 };
+use crate::contract::ops::archive_contract::ArchiveContractRequestBuilder;
 use crate::contract::ops::deploy_contract::DeployContractRequestBuilder;
 use crate::contract::ops::deploy_contract_from_template::DeployContractFromTemplateRequestBuilder;
 use crate::contract::ops::import_contract::ImportContractRequestBuilder;
-use crate::helper::CircleResult;
-use uuid::Uuid;
+use crate::helper::{generate_uuid, sleep, CircleError, CircleResult, Instant};
+
+/// Transaction states after which a deployment will never produce an address
+const FAILED_DEPLOYMENT_STATES: &[&str] = &["FAILED", "CANCELLED", "DENIED"];
 
 impl CircleOps {
     /// Deploy a contract from template
@@ -61,37 +68,18 @@ impl CircleOps {
         &self,
         builder: DeployContractFromTemplateRequestBuilder,
     ) -> CircleResult<TemplateContractDeploymentResponse> {
+        builder.validate()?;
+
         // Encrypt the entity secret (fresh encryption for each request)
         let entity_secret_ciphertext = self.entity_secret()?;
 
-        // Generate a new UUID for each request (or use custom one if provided)
-        let idempotency_key = builder
-            .idempotency_key
-            .unwrap_or_else(|| Uuid::new_v4().to_string());
-
-        let template_id = builder.template_id;
-
-        let request = DeployContractFromTemplateRequest {
-            entity_secret_ciphertext,
-            name: builder.name,
-            wallet_id: builder.wallet_id,
-            blockchain: builder.blockchain,
-            idempotency_key,
-            description: builder.description,
-            template_parameters: builder.template_parameters,
-            fee_level: builder.fee_level,
-            gas_limit: builder.gas_limit,
-            gas_price: builder.gas_price,
-            max_fee: builder.max_fee,
-            priority_fee: builder.priority_fee,
-            ref_id: builder.ref_id,
-        };
+        // The template ID goes in the path; the rest of the builder becomes the body
+        let path = format!("/v1/w3s/templates/{}/deploy", builder.template_id);
 
-        self.post(
-            format!("/v1/w3s/templates/{}/deploy", template_id).as_str(),
-            &request,
-        )
-        .await
+        // Use the custom idempotency key if provided, otherwise generate a new UUID
+        let request = builder.into_request(entity_secret_ciphertext, generate_uuid);
+
+        self.post(&path, &request).await
     }
 
     /// Deploy a contract from bytecode
@@ -143,36 +131,116 @@ impl CircleOps {
         &self,
         builder: DeployContractRequestBuilder,
     ) -> CircleResult<ContractDeploymentResponse> {
+        builder.validate()?;
+
         // Encrypt the entity secret (fresh encryption for each request)
         let entity_secret_ciphertext = self.entity_secret()?;
 
-        // Generate a new UUID for each request (or use custom one if provided)
-        let built = builder.build();
-        let idempotency_key = built
-            .idempotency_key
-            .unwrap_or_else(|| Uuid::new_v4().to_string());
-
-        let request = DeployContractRequest {
-            entity_secret_ciphertext,
-            bytecode: built.bytecode,
-            abi_json: built.abi_json,
-            wallet_id: built.wallet_id,
-            name: built.name,
-            blockchain: built.blockchain,
-            idempotency_key,
-            description: built.description,
-            constructor_parameters: built.constructor_parameters,
-            fee_level: built.fee_level,
-            gas_limit: built.gas_limit,
-            gas_price: built.gas_price,
-            max_fee: built.max_fee,
-            priority_fee: built.priority_fee,
-            ref_id: built.ref_id,
-        };
+        // Use the custom idempotency key if provided, otherwise generate a new UUID
+        let request = builder.into_request(entity_secret_ciphertext, generate_uuid);
 
         self.post("/v1/w3s/contracts/deploy", &request).await
     }
 
+    /// Deploy a contract from template and wait for its on-chain address
+    ///
+    /// Calls [`deploy_contract_from_template`](Self::deploy_contract_from_template),
+    /// then polls each created contract and the deployment transaction through
+    /// `view` (250ms doubling up to 2s) until every contract reports its
+    /// address. A template can deploy several contracts, so all of them are
+    /// returned, in the order of `contract_ids`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Config` if the deployment transaction fails, is
+    /// cancelled or denied, or if an address is still missing after `timeout`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+    /// use inf_circle_sdk::circle_view::circle_view::CircleView;
+    /// use inf_circle_sdk::contract::ops::deploy_contract_from_template::DeployContractFromTemplateRequestBuilder;
+    ///
+    /// # async fn example(builder: DeployContractFromTemplateRequestBuilder) -> Result<(), Box<dyn std::error::Error>> {
+    /// let ops = CircleOps::new(None)?;
+    /// let view = CircleView::new()?;
+    ///
+    /// let contracts = ops
+    ///     .deploy_contract_from_template_and_wait(&view, builder, Duration::from_secs(120))
+    ///     .await?;
+    /// for contract in contracts {
+    ///     println!("Deployed at {:?}", contract.contract_address);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn deploy_contract_from_template_and_wait(
+        &self,
+        view: &CircleView,
+        builder: DeployContractFromTemplateRequestBuilder,
+        timeout: Duration,
+    ) -> CircleResult<Vec<Contract>> {
+        let response = self.deploy_contract_from_template(builder).await?;
+        let deadline = Instant::now() + timeout;
+
+        let mut contracts = Vec::with_capacity(response.contract_ids.len());
+        for contract_id in &response.contract_ids {
+            contracts.push(
+                wait_for_deployment(view, &response.transaction_id, contract_id, deadline).await?,
+            );
+        }
+        Ok(contracts)
+    }
+
+    /// Deploy a contract from bytecode and wait for its on-chain address
+    ///
+    /// Calls [`deploy_contract`](Self::deploy_contract), then polls the
+    /// contract and the deployment transaction through `view` (250ms doubling
+    /// up to 2s) until the contract reports its address.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Config` if the deployment transaction fails, is
+    /// cancelled or denied, or if the address is still missing after `timeout`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+    /// use inf_circle_sdk::circle_view::circle_view::CircleView;
+    /// use inf_circle_sdk::contract::ops::deploy_contract::DeployContractRequestBuilder;
+    ///
+    /// # async fn example(builder: DeployContractRequestBuilder) -> Result<(), Box<dyn std::error::Error>> {
+    /// let ops = CircleOps::new(None)?;
+    /// let view = CircleView::new()?;
+    ///
+    /// let contract = ops
+    ///     .deploy_contract_and_wait(&view, builder, Duration::from_secs(120))
+    ///     .await?;
+    /// println!("Deployed at {:?}", contract.contract_address);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn deploy_contract_and_wait(
+        &self,
+        view: &CircleView,
+        builder: DeployContractRequestBuilder,
+        timeout: Duration,
+    ) -> CircleResult<Contract> {
+        let response = self.deploy_contract(builder).await?;
+        let deadline = Instant::now() + timeout;
+        wait_for_deployment(
+            view,
+            &response.transaction_id,
+            &response.contract_id,
+            deadline,
+        )
+        .await
+    }
+
     /// Import an existing contract
     ///
     /// Imports an existing deployed smart contract into your Circle account.
@@ -216,16 +284,341 @@ impl CircleOps {
         builder: ImportContractRequestBuilder,
     ) -> CircleResult<ContractResponse> {
         // Generate a new UUID for idempotency
-        let idempotency_key = Uuid::new_v4().to_string();
-
-        let request = ImportContractRequest {
-            blockchain: builder.blockchain,
-            address: builder.address,
-            name: builder.name,
-            idempotency_key,
-            description: builder.description,
-        };
+        let request = builder.into_request(generate_uuid());
 
         self.post("/v1/w3s/contracts/import", &request).await
     }
+
+    /// Archive a contract
+    ///
+    /// Archived contracts are hidden from default listings but are unaffected
+    /// on-chain. Circle does not support deleting contracts; archive them instead.
+    ///
+    /// The contract is fetched through `view` first. If the builder has an
+    /// expected address that does not match, nothing is changed; if the contract
+    /// is already archived, it is returned without another update.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Config` if the contract's address does not match the
+    /// builder's expected address.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+    /// use inf_circle_sdk::circle_view::circle_view::CircleView;
+    /// use inf_circle_sdk::contract::ops::archive_contract::ArchiveContractRequestBuilder;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ops = CircleOps::new(None)?;
+    /// let view = CircleView::new()?;
+    ///
+    /// let builder = ArchiveContractRequestBuilder::new("contract-id".to_string())
+    ///     .expected_address("0x1c7D4B196Cb0C7B01d743Fbc6116a902379C7238".to_string());
+    ///
+    /// let contract = ops.archive_contract(&view, builder).await?;
+    /// println!("Archived: {:?}", contract.archived);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn archive_contract(
+        &self,
+        view: &CircleView,
+        builder: ArchiveContractRequestBuilder,
+    ) -> CircleResult<Contract> {
+        self.set_contract_archived(view, builder, true).await
+    }
+
+    /// Unarchive a contract
+    ///
+    /// The counterpart of [`archive_contract`](Self::archive_contract), with the
+    /// same address check and no update if the contract is not archived.
+    pub async fn unarchive_contract(
+        &self,
+        view: &CircleView,
+        builder: ArchiveContractRequestBuilder,
+    ) -> CircleResult<Contract> {
+        self.set_contract_archived(view, builder, false).await
+    }
+
+    /// Store an ABI for a contract
+    ///
+    /// Imported contracts often have no ABI in Circle. Once one is stored,
+    /// [`Contract::typed_abi`] and the signature helpers in
+    /// [`abi`](crate::contract::abi) can be used with the contract. The ABI
+    /// is parsed before it is sent, and replaces any ABI already stored.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id` - The contract ID
+    /// * `abi_json` - The ABI as a JSON array string
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Json` or `CircleError::Abi` if `abi_json` is not
+    /// a well-formed ABI, without sending a request.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ops = CircleOps::new(None)?;
+    ///
+    /// let abi_json = std::fs::read_to_string("MyToken.abi.json")?;
+    /// let contract = ops.update_contract_abi("contract-id", &abi_json).await?;
+    /// for function in contract.typed_abi().map(|abi| abi.functions).unwrap_or_default() {
+    ///     println!("{} -> {}", function.canonical, function.selector);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_contract_abi(
+        &self,
+        contract_id: &str,
+        abi_json: &str,
+    ) -> CircleResult<Contract> {
+        ContractAbi::from_json(abi_json)?;
+
+        let path = format!("/v1/w3s/contracts/{}", contract_id);
+        let request = UpdateContractAbiRequest {
+            abi_json: abi_json.to_string(),
+        };
+        let response: ContractResponse = self.patch(&path, &request).await?;
+        Ok(response.contract)
+    }
+
+    async fn set_contract_archived(
+        &self,
+        view: &CircleView,
+        builder: ArchiveContractRequestBuilder,
+        archived: bool,
+    ) -> CircleResult<Contract> {
+        let contract = view.get_contract(&builder.contract_id).await?.contract;
+        builder.confirm(&contract)?;
+        if contract.archived.unwrap_or(false) == archived {
+            return Ok(contract);
+        }
+
+        let path = format!("/v1/w3s/contracts/{}", builder.contract_id);
+        let response: ContractResponse = self
+            .patch(&path, &ArchiveContractRequest { archived })
+            .await?;
+        Ok(response.contract)
+    }
+}
+
+/// Poll a deployment until the contract has an address or the deadline passes
+async fn wait_for_deployment(
+    view: &CircleView,
+    transaction_id: &str,
+    contract_id: &str,
+    deadline: Instant,
+) -> CircleResult<Contract> {
+    let mut delay = Duration::from_millis(250);
+    loop {
+        let contract = view.get_contract(contract_id).await?.contract;
+        let address = contract
+            .contract_address
+            .as_deref()
+            .or(contract.address.as_deref());
+        if address.is_some_and(|a| !a.is_empty()) {
+            return Ok(contract);
+        }
+
+        let transaction = view.get_transaction(transaction_id).await?.transaction;
+        if FAILED_DEPLOYMENT_STATES.contains(&transaction.state.as_str()) {
+            return Err(CircleError::Config(format!(
+                "Deployment transaction {} for contract {} ended in state {}: {}",
+                transaction_id,
+                contract_id,
+                transaction.state,
+                transaction
+                    .error_reason
+                    .as_deref()
+                    .unwrap_or("no reason given")
+            )));
+        }
+
+        let now = Instant::now();
+        if now >= deadline {
+            return Err(CircleError::Config(format!(
+                "Contract {} has no address yet; deployment transaction {} is {}",
+                contract_id, transaction_id, transaction.state
+            )));
+        }
+        sleep(delay.min(deadline - now)).await;
+        delay = (delay * 2).min(Duration::from_secs(2));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{fixtures, MockCircle};
+    use crate::types::Blockchain;
+    use serde_json::json;
+
+    fn builder() -> DeployContractRequestBuilder {
+        DeployContractRequestBuilder::new(
+            "0x6080".to_string(),
+            "[]".to_string(),
+            fixtures::WALLET_ID.to_string(),
+            "Test contract".to_string(),
+            Blockchain::EthSepolia,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_deploy_contract_and_wait_returns_deployed_contract() {
+        let mut circle = MockCircle::start().await;
+        circle
+            .respond(
+                "POST",
+                "/v1/w3s/contracts/deploy",
+                &json!({
+                    "contractId": fixtures::CONTRACT_ID,
+                    "transactionId": fixtures::TRANSACTION_ID,
+                }),
+            )
+            .await;
+        circle
+            .respond(
+                "GET",
+                &format!("/v1/w3s/contracts/{}", fixtures::CONTRACT_ID),
+                &json!({ "contract": fixtures::contract() }),
+            )
+            .await;
+
+        let contract = circle
+            .ops()
+            .deploy_contract_and_wait(&circle.view(), builder(), Duration::from_secs(5))
+            .await
+            .unwrap();
+        assert_eq!(
+            contract.contract_address.as_deref(),
+            Some(fixtures::CONTRACT_ADDRESS)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_archive_contract_checks_address_and_state() {
+        let mut circle = MockCircle::start().await;
+        let path = format!("/v1/w3s/contracts/{}", fixtures::CONTRACT_ID);
+        let mut contract = fixtures::contract();
+        contract.archived = Some(false);
+        circle
+            .respond("GET", &path, &json!({ "contract": contract }))
+            .await;
+        let mut archived = fixtures::contract();
+        archived.archived = Some(true);
+        let patch = circle
+            .server()
+            .mock("PATCH", path.as_str())
+            .match_body(mockito::Matcher::Json(json!({ "archived": true })))
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "data": { "contract": archived } }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let wrong_address = ArchiveContractRequestBuilder::new(fixtures::CONTRACT_ID.to_string())
+            .expected_address("0x0000000000000000000000000000000000000001".to_string());
+        let result = circle
+            .ops()
+            .archive_contract(&circle.view(), wrong_address)
+            .await;
+        assert!(matches!(result, Err(CircleError::Config(_))));
+
+        // Unarchiving a contract that is not archived makes no update
+        let builder = ArchiveContractRequestBuilder::new(fixtures::CONTRACT_ID.to_string())
+            .expected_address(fixtures::CONTRACT_ADDRESS.to_string());
+        let unchanged = circle
+            .ops()
+            .unarchive_contract(&circle.view(), builder.clone())
+            .await
+            .unwrap();
+        assert_eq!(unchanged.archived, Some(false));
+
+        let updated = circle
+            .ops()
+            .archive_contract(&circle.view(), builder)
+            .await
+            .unwrap();
+        assert_eq!(updated.archived, Some(true));
+        patch.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_update_contract_abi() {
+        let mut circle = MockCircle::start().await;
+        let path = format!("/v1/w3s/contracts/{}", fixtures::CONTRACT_ID);
+        let abi_json = r#"[{"type":"function","name":"totalSupply","inputs":[],"outputs":[{"name":"","type":"uint256"}],"stateMutability":"view"}]"#;
+        let mut updated = fixtures::contract();
+        updated.abi_json = Some(abi_json.to_string());
+        let patch = circle
+            .server()
+            .mock("PATCH", path.as_str())
+            .match_body(mockito::Matcher::Json(json!({ "abiJson": abi_json })))
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "data": { "contract": updated } }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        // Malformed ABIs are refused before sending
+        let result = circle
+            .ops()
+            .update_contract_abi(fixtures::CONTRACT_ID, r#"{"type":"function"}"#)
+            .await;
+        assert!(matches!(result, Err(CircleError::Abi(_))));
+
+        assert!(fixtures::contract().typed_abi().is_none());
+        let contract = circle
+            .ops()
+            .update_contract_abi(fixtures::CONTRACT_ID, abi_json)
+            .await
+            .unwrap();
+        let abi = contract.typed_abi().unwrap();
+        assert!(abi.function("totalSupply").unwrap().is_read_only());
+        patch.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_deploy_contract_and_wait_reports_failed_transaction() {
+        let mut circle = MockCircle::start().await;
+        circle
+            .respond(
+                "POST",
+                "/v1/w3s/contracts/deploy",
+                &json!({
+                    "contractId": fixtures::CONTRACT_ID,
+                    "transactionId": fixtures::TRANSACTION_ID,
+                }),
+            )
+            .await;
+        let mut pending = serde_json::to_value(fixtures::contract()).unwrap();
+        pending.as_object_mut().unwrap().remove("contractAddress");
+        circle
+            .respond(
+                "GET",
+                &format!("/v1/w3s/contracts/{}", fixtures::CONTRACT_ID),
+                &json!({ "contract": pending }),
+            )
+            .await;
+        let mut failed = fixtures::transaction();
+        failed.state = "FAILED".to_string();
+        failed.error_reason = Some("EXECUTION_REVERTED".to_string());
+        circle.mock_transaction(&failed).await;
+
+        let result = circle
+            .ops()
+            .deploy_contract_and_wait(&circle.view(), builder(), Duration::from_secs(5))
+            .await;
+        assert!(
+            matches!(result, Err(CircleError::Config(message)) if message.contains("EXECUTION_REVERTED"))
+        );
+    }
 }
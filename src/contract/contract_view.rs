@@ -1,8 +1,8 @@
 //! Contract read operations for CircleView
 use crate::contract::dto::{
     CreateNotificationSubscriptionResponse, EventLogsResponse, EventMonitorResponse,
-    EventMonitorsResponse, FeeEstimation, NotificationSubscription, PingResponse,
-    QueryContractResponse, UpdateNotificationSubscriptionResponse,
+    EventMonitorsResponse, NotificationSubscription, PingResponse, QueryContractResponse,
+    SubscriptionSpec, SubscriptionSyncReport, UpdateNotificationSubscriptionResponse,
 };
 use crate::contract::views::create_event_monitor::CreateEventMonitorBodyBuilder;
 use crate::contract::views::create_notification_subscription::CreateNotificationSubscriptionBodyBuilder;
@@ -11,7 +11,8 @@ use crate::contract::views::estimate_template_deployment_fee::EstimateTemplateDe
 use crate::contract::views::query_contract_view::QueryContractViewBodyBuilder;
 use crate::contract::views::update_event_monitor::UpdateEventMonitorBodyBuilder;
 use crate::contract::views::update_notification_subscription::UpdateNotificationSubscriptionBodyBuilder;
-use crate::helper::CircleResult;
+use crate::fees::FeeEstimate;
+use crate::helper::{event_signature_hash, page::Page, CircleError, CircleResult};
 use crate::{circle_view::circle_view::CircleView, contract::dto::UpdateContractRequest};
 // Re-use the Contract struct from CircleOps since it's the same
 pub use crate::contract::dto::{
@@ -25,6 +26,9 @@ impl CircleView {
     /// Retrieves a list of all contracts that fit the specified parameters.
     /// Supports filtering by blockchain, wallet set ID, and pagination.
     ///
+    /// Returns a [`Page`] with the cursors of the neighbouring pages; see
+    /// [`page`](crate::helper::page) for how they are derived.
+    ///
     /// # Arguments
     ///
     /// * `params` - Optional filter parameters for listing contracts
@@ -40,7 +44,7 @@ impl CircleView {
     ///
     /// // List all contracts
     /// let contracts = view.list_contracts(None).await?;
-    /// for contract in contracts.contracts {
+    /// for contract in contracts {
     ///     println!("Contract: {:?} - {:?}", contract.name, contract.address);
     /// }
     /// # Ok(())
@@ -49,11 +53,16 @@ impl CircleView {
     pub async fn list_contracts(
         &self,
         params: Option<ListContractsParams>,
-    ) -> CircleResult<ContractsResponse> {
-        match params {
-            Some(params) => self.get_with_params("/v1/w3s/contracts", &params).await,
-            None => self.get("/v1/w3s/contracts").await,
-        }
+    ) -> CircleResult<Page<Contract>> {
+        let pagination = params
+            .as_ref()
+            .map(|params| params.pagination.clone())
+            .unwrap_or_default();
+        let response: ContractsResponse = match &params {
+            Some(params) => self.get_with_params("/v1/w3s/contracts", params).await?,
+            None => self.get("/v1/w3s/contracts").await?,
+        };
+        Ok(Page::new(response.contracts, &pagination))
     }
 
     /// Get a specific contract
@@ -153,16 +162,16 @@ impl CircleView {
     ///     .wallet_id("wallet-id".to_string());
     ///
     /// let estimate = view.estimate_contract_deployment_fee(builder).await?;
-    /// println!("Low gas limit: {}", estimate.low.gas_limit);
-    /// println!("Medium gas limit: {}", estimate.medium.gas_limit);
-    /// println!("High gas limit: {}", estimate.high.gas_limit);
+    /// println!("Low gas limit: {:?}", estimate.low.gas_limit);
+    /// println!("Medium gas limit: {:?}", estimate.medium.gas_limit);
+    /// println!("High gas limit: {:?}", estimate.high.gas_limit);
     /// # Ok(())
     /// # }
     /// ```
     pub async fn estimate_contract_deployment_fee(
         &self,
         builder: EstimateContractDeploymentBodyBuilder,
-    ) -> CircleResult<FeeEstimation> {
+    ) -> CircleResult<FeeEstimate> {
         let body = builder.build();
         self.post("/v1/w3s/contracts/deploy/estimateFee", &body)
             .await
@@ -195,14 +204,14 @@ impl CircleView {
     /// .build();
     ///
     /// let estimate = view.estimate_template_deployment_fee(builder).await?;
-    /// println!("Estimated gas limit: {}", estimate.medium.gas_limit);
+    /// println!("Estimated gas limit: {:?}", estimate.medium.gas_limit);
     /// # Ok(())
     /// # }
     /// ```
     pub async fn estimate_template_deployment_fee(
         &self,
         builder: EstimateTemplateDeploymentFeeBodyBuilder,
-    ) -> CircleResult<FeeEstimation> {
+    ) -> CircleResult<FeeEstimate> {
         let builder = builder.build();
         let id = builder.template_id.clone();
 
@@ -464,6 +473,112 @@ impl CircleView {
         .await
     }
 
+    /// Converge webhook subscriptions to a desired set
+    ///
+    /// Lists the existing subscriptions and matches them to `desired` by
+    /// endpoint. Subscriptions with no spec, or whose notification types
+    /// differ from their spec, are deleted; specs without a matching
+    /// subscription are created; matches whose name or enabled flag differ
+    /// are updated. Deletions run first, since Circle rejects a second
+    /// subscription for an endpoint that is already registered.
+    ///
+    /// **Every subscription not described by `desired` is deleted**, including
+    /// ones created outside this SDK.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Config` if two specs share an endpoint. API
+    /// errors stop the sync part-way; running it again picks up from the
+    /// current state.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_view::circle_view::CircleView;
+    /// use inf_circle_sdk::contract::dto::{NotificationType, SubscriptionSpec};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let view = CircleView::new()?;
+    ///
+    /// let report = view
+    ///     .sync_notification_subscriptions(vec![
+    ///         SubscriptionSpec::new("https://example.com/webhooks/circle".to_string())
+    ///             .notification_types(vec![
+    ///                 NotificationType::TransactionsInbound,
+    ///                 NotificationType::TransactionsOutbound,
+    ///             ])
+    ///             .name("Transfers".to_string()),
+    ///     ])
+    ///     .await?;
+    /// println!("created {:?}, deleted {:?}", report.created, report.deleted);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn sync_notification_subscriptions(
+        &self,
+        desired: Vec<SubscriptionSpec>,
+    ) -> CircleResult<SubscriptionSyncReport> {
+        for (i, spec) in desired.iter().enumerate() {
+            if desired[..i].iter().any(|s| s.endpoint == spec.endpoint) {
+                return Err(CircleError::Config(format!(
+                    "Duplicate subscription spec for endpoint {}",
+                    spec.endpoint
+                )));
+            }
+        }
+
+        let mut existing = self.list_notification_subscriptions().await?;
+        let matched: Vec<Option<NotificationSubscription>> = desired
+            .iter()
+            .map(|spec| {
+                existing
+                    .iter()
+                    .position(|sub| sub.endpoint == spec.endpoint && spec.types_match(sub))
+                    .map(|i| existing.remove(i))
+            })
+            .collect();
+
+        let mut report = SubscriptionSyncReport::default();
+        for stale in existing {
+            self.delete_notification_subscription(&stale.id).await?;
+            report.deleted.push(stale.id);
+        }
+
+        for (spec, current) in desired.into_iter().zip(matched) {
+            let current = match current {
+                Some(current) => current,
+                None => {
+                    let mut builder =
+                        CreateNotificationSubscriptionBodyBuilder::new(spec.endpoint.clone());
+                    if !spec.notification_types.is_empty() {
+                        builder = builder.notification_types(spec.notification_types.clone());
+                    }
+                    let created = self.create_notification_subscription(builder).await?;
+                    report.created.push(created.id.clone());
+                    created
+                }
+            };
+
+            let name = spec.name.unwrap_or_else(|| current.name.clone());
+            if name == current.name && spec.enabled == current.enabled {
+                if !report.created.contains(&current.id) {
+                    report.unchanged.push(current.id);
+                }
+                continue;
+            }
+
+            let builder = UpdateNotificationSubscriptionBodyBuilder::new(current.id.clone())
+                .name(name)
+                .enabled(spec.enabled);
+            self.update_notification_subscription(builder).await?;
+            if !report.created.contains(&current.id) {
+                report.updated.push(current.id);
+            }
+        }
+
+        Ok(report)
+    }
+
     /// Get health of Circle API
     ///
     /// Retrieves the health status of the Circle API. This is a simple endpoint
@@ -500,10 +615,20 @@ impl CircleView {
     ///
     /// Note: Ensure no spaces are included in the event signature.
     ///
+    /// The `eventSignatureHash` returned by Circle is checked against the hash
+    /// computed locally with [`event_signature_hash`], so a malformed signature
+    /// is reported immediately instead of producing a monitor that never fires.
+    ///
     /// # Arguments
     ///
     /// * `builder` - The event monitor builder with contract address, blockchain, and event signature
     ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Config` if Circle's event signature hash differs from the
+    /// locally computed one. The monitor has already been created at that point; its ID
+    /// is included in the error message so it can be deleted.
+    ///
     /// # Example
     ///
     /// ```rust,no_run
@@ -532,7 +657,20 @@ impl CircleView {
         builder: CreateEventMonitorBodyBuilder,
     ) -> CircleResult<EventMonitorResponse> {
         let body = builder.build();
-        self.post("/v1/w3s/contracts/monitors", &body).await
+        let expected_hash = event_signature_hash(&body.event_signature);
+
+        let response: EventMonitorResponse = self.post("/v1/w3s/contracts/monitors", &body).await?;
+
+        let monitor = &response.event_monitor;
+        let actual_hash = monitor.event_signature_hash.trim_start_matches("0x");
+        if !actual_hash.eq_ignore_ascii_case(expected_hash.trim_start_matches("0x")) {
+            return Err(CircleError::Config(format!(
+                "Event monitor {} has signature hash {} but {} hashes to {}; check the event signature format",
+                monitor.id, monitor.event_signature_hash, body.event_signature, expected_hash
+            )));
+        }
+
+        Ok(response)
     }
 
     /// Update an event monitor
@@ -601,6 +739,9 @@ impl CircleView {
     /// Fetches a list of event monitors, optionally filtered by blockchain, contract address,
     /// and event signature.
     ///
+    /// Returns a [`Page`] with the cursors of the neighbouring pages; see
+    /// [`page`](crate::helper::page) for how they are derived.
+    ///
     /// # Arguments
     ///
     /// * `params` - Optional filter parameters for listing event monitors
@@ -615,7 +756,7 @@ impl CircleView {
     ///
     /// // List all event monitors
     /// let response = view.list_event_monitors(None).await?;
-    /// for monitor in response.event_monitors {
+    /// for monitor in response {
     ///     println!("Monitor: {} - Contract: {} - Enabled: {}",
     ///         monitor.id, monitor.contract_address, monitor.is_enabled);
     /// }
@@ -625,14 +766,19 @@ impl CircleView {
     pub async fn list_event_monitors(
         &self,
         params: Option<ListEventMonitorsParams>,
-    ) -> CircleResult<EventMonitorsResponse> {
-        match params {
+    ) -> CircleResult<Page<EventMonitor>> {
+        let pagination = params
+            .as_ref()
+            .map(|params| params.pagination.clone())
+            .unwrap_or_default();
+        let response: EventMonitorsResponse = match &params {
             Some(params) => {
-                self.get_with_params("/v1/w3s/contracts/monitors", &params)
-                    .await
+                self.get_with_params("/v1/w3s/contracts/monitors", params)
+                    .await?
             }
-            None => self.get("/v1/w3s/contracts/monitors").await,
-        }
+            None => self.get("/v1/w3s/contracts/monitors").await?,
+        };
+        Ok(Page::new(response.event_monitors, &pagination))
     }
 
     /// List event logs
@@ -641,6 +787,9 @@ impl CircleView {
     /// by blockchain and contract address. These are the actual events that were emitted
     /// by contracts and captured by your event monitors.
     ///
+    /// Returns a [`Page`] with the cursors of the neighbouring pages; see
+    /// [`page`](crate::helper::page) for how they are derived.
+    ///
     /// # Arguments
     ///
     /// * `params` - Optional filter parameters for listing event logs
@@ -655,7 +804,7 @@ impl CircleView {
     ///
     /// // List all event logs
     /// let response = view.list_event_logs(None).await?;
-    /// for log in response.event_logs {
+    /// for log in response {
     ///     println!("Event: {} - Block: {} - Tx: {}",
     ///         log.event_signature, log.block_height, log.tx_hash);
     /// }
@@ -665,13 +814,124 @@ impl CircleView {
     pub async fn list_event_logs(
         &self,
         params: Option<ListEventLogsParams>,
-    ) -> CircleResult<EventLogsResponse> {
-        match params {
+    ) -> CircleResult<Page<EventLog>> {
+        let pagination = params
+            .as_ref()
+            .map(|params| params.pagination.clone())
+            .unwrap_or_default();
+        let response: EventLogsResponse = match &params {
             Some(params) => {
-                self.get_with_params("/v1/w3s/contracts/events", &params)
-                    .await
+                self.get_with_params("/v1/w3s/contracts/events", params)
+                    .await?
             }
-            None => self.get("/v1/w3s/contracts/events").await,
+            None => self.get("/v1/w3s/contracts/events").await?,
+        };
+        Ok(Page::new(response.event_logs, &pagination))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        contract::dto::{NotificationType, SubscriptionSpec},
+        helper::CircleError,
+        testing::{fixtures, MockCircle},
+    };
+    use mockito::Matcher;
+    use serde_json::json;
+
+    fn subscription(id: &str, endpoint: &str, types: &[&str]) -> serde_json::Value {
+        let mut sub = serde_json::to_value(fixtures::notification_subscription()).unwrap();
+        sub["id"] = json!(id);
+        sub["endpoint"] = json!(endpoint);
+        sub["notificationTypes"] = json!(types);
+        sub["restricted"] = json!(!types.is_empty());
+        sub
+    }
+
+    #[tokio::test]
+    async fn test_sync_notification_subscriptions() {
+        let mut circle = MockCircle::start().await;
+        circle
+            .respond(
+                "GET",
+                "/v2/notifications/subscriptions",
+                &json!([
+                    subscription("keep", "https://a.example", &["transactions.inbound"]),
+                    subscription("retyped", "https://b.example", &["transactions.inbound"]),
+                    subscription("stale", "https://c.example", &[]),
+                ]),
+            )
+            .await;
+        let deleted_retyped = circle
+            .respond(
+                "DELETE",
+                "/v2/notifications/subscriptions/retyped",
+                &json!({}),
+            )
+            .await;
+        let deleted_stale = circle
+            .respond(
+                "DELETE",
+                "/v2/notifications/subscriptions/stale",
+                &json!({}),
+            )
+            .await;
+        let created = circle
+            .server()
+            .mock("POST", "/v2/notifications/subscriptions")
+            .match_body(Matcher::Json(json!({
+                "endpoint": "https://b.example",
+                "notificationTypes": ["transactions.outbound"],
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "data": subscription("recreated", "https://b.example", &["transactions.outbound"])
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let renamed = circle
+            .server()
+            .mock("PATCH", "/v2/notifications/subscriptions/keep")
+            .match_body(Matcher::Json(json!({ "name": "Inbound", "enabled": true })))
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({ "data": subscription("keep", "https://a.example", &[]) }).to_string(),
+            )
+            .create_async()
+            .await;
+
+        let report = circle
+            .view()
+            .sync_notification_subscriptions(vec![
+                SubscriptionSpec::new("https://a.example".to_string())
+                    .notification_types(vec![NotificationType::TransactionsInbound])
+                    .name("Inbound".to_string()),
+                SubscriptionSpec::new("https://b.example".to_string())
+                    .notification_types(vec![NotificationType::TransactionsOutbound]),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(report.deleted, ["retyped", "stale"]);
+        assert_eq!(report.created, ["recreated"]);
+        assert_eq!(report.updated, ["keep"]);
+        assert!(report.unchanged.is_empty());
+        for mock in [deleted_retyped, deleted_stale, created, renamed] {
+            mock.assert_async().await;
         }
+
+        let duplicate = SubscriptionSpec::new("https://a.example".to_string());
+        assert!(matches!(
+            circle
+                .view()
+                .sync_notification_subscriptions(vec![duplicate.clone(), duplicate])
+                .await,
+            Err(CircleError::Config(_))
+        ));
     }
 }
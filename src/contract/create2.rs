@@ -0,0 +1,318 @@
+//! CREATE2 Deployments
+//!
+//! This module predicts the address of contracts deployed with `CREATE2` and
+//! builds the contract execution that deploys them through a factory. A
+//! `CREATE2` address depends only on the deployer, a 32-byte salt and the init
+//! code, so the same deployment lands at the same address on every EVM chain.
+//! That lets integrators write cross-chain configuration before anything is
+//! deployed.
+//!
+//! By default deployments go through the deterministic deployment proxy at
+//! [`DETERMINISTIC_DEPLOYMENT_PROXY`], which is available at the same address
+//! on most EVM networks.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::{
+//!     circle_ops::circler_ops::CircleOps,
+//!     contract::create2::Create2DeploymentBuilder,
+//! };
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let ops = CircleOps::new(None)?;
+//! let salt = "0x0000000000000000000000000000000000000000000000000000000000000001";
+//! let bytecode = "0x608060405234801561001057600080fd5b50...";
+//!
+//! let deployment = Create2DeploymentBuilder::new(
+//!     "wallet-id".to_string(),
+//!     salt.to_string(),
+//!     bytecode.to_string(),
+//! );
+//! println!("Contract will be deployed at {}", deployment.predicted_address()?);
+//!
+//! let tx = ops
+//!     .create_dev_contract_execution_transaction(deployment.into_execution()?)
+//!     .await?;
+//! println!("Deployment transaction: {}", tx.id);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    abi::{keccak256, AbiValue, Address},
+    dev_wallet::{
+        dto::{FeeConfig, FeeLevel},
+        ops::create_contract_transaction::CreateContractExecutionTransactionRequestBuilder,
+    },
+    helper::{CircleError, CircleResult},
+};
+
+/// Address of the deterministic deployment proxy (Arachnid's CREATE2 factory)
+///
+/// The proxy takes `salt ++ init_code` as raw call data and deploys the init
+/// code with `CREATE2`.
+pub const DETERMINISTIC_DEPLOYMENT_PROXY: &str = "0x4e59b44847b379578588920cA78FbF26c0B4956C";
+
+/// Predict the address of a contract deployed with `CREATE2`
+///
+/// Computes `keccak256(0xff ++ deployer ++ salt ++ keccak256(bytecode))[12..]`
+/// as specified by EIP-1014.
+///
+/// # Arguments
+/// * `deployer` - Address of the contract executing `CREATE2` (the factory)
+/// * `salt` - 32-byte salt as hex
+/// * `bytecode` - Init code as hex, including any ABI-encoded constructor arguments
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::contract::predict_create2_address;
+///
+/// let address = predict_create2_address(
+///     "0xdeadbeef00000000000000000000000000000000",
+///     "0x0000000000000000000000000000000000000000000000000000000000000000",
+///     "0x00",
+/// )
+/// .unwrap();
+/// assert_eq!(address.to_string(), "0xb928f69bb1d91cd65274e3c79d8986362984fda3");
+/// ```
+pub fn predict_create2_address(
+    deployer: &str,
+    salt: &str,
+    bytecode: &str,
+) -> CircleResult<Address> {
+    let deployer: Address = deployer.parse()?;
+    let salt = parse_salt(salt)?;
+    let bytecode = parse_hex("bytecode", bytecode)?;
+    Ok(create2_address(&deployer, &salt, &bytecode))
+}
+
+fn create2_address(deployer: &Address, salt: &[u8; 32], bytecode: &[u8]) -> Address {
+    let mut preimage = Vec::with_capacity(85);
+    preimage.push(0xff);
+    preimage.extend_from_slice(deployer.as_bytes());
+    preimage.extend_from_slice(salt);
+    preimage.extend_from_slice(&keccak256(bytecode));
+
+    let hash = keccak256(&preimage);
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[12..]);
+    Address::from_bytes(address)
+}
+
+fn parse_salt(salt: &str) -> CircleResult<[u8; 32]> {
+    let bytes = parse_hex("salt", salt)?;
+    bytes.try_into().map_err(|bytes: Vec<u8>| {
+        CircleError::Abi(format!("Salt must be 32 bytes, got {}", bytes.len()))
+    })
+}
+
+fn parse_hex(field: &str, value: &str) -> CircleResult<Vec<u8>> {
+    let digits = value.strip_prefix("0x").unwrap_or(value);
+    hex::decode(digits).map_err(|e| CircleError::Abi(format!("Invalid {} {}: {}", field, value, e)))
+}
+
+/// Builder for a deployment through a CREATE2 factory
+///
+/// Produces a contract execution transaction from a developer wallet to the
+/// factory. The deployed contract's address is known up front via
+/// [`predicted_address`](Self::predicted_address).
+#[derive(Clone, Debug)]
+pub struct Create2DeploymentBuilder {
+    pub wallet_id: String,
+    pub salt: String,
+    pub bytecode: String,
+    pub factory_address: String,
+    pub factory_function: Option<String>,
+    pub fee: Option<FeeConfig>,
+    pub ref_id: Option<String>,
+    pub idempotency_key: Option<String>,
+}
+
+impl Create2DeploymentBuilder {
+    /// Create a new builder with required fields
+    ///
+    /// # Arguments
+    /// * `wallet_id` - ID of the wallet sending the deployment transaction
+    /// * `salt` - 32-byte salt as hex
+    /// * `bytecode` - Init code as hex, including any ABI-encoded constructor arguments
+    pub fn new(wallet_id: String, salt: String, bytecode: String) -> Self {
+        Self {
+            wallet_id,
+            salt,
+            bytecode,
+            factory_address: DETERMINISTIC_DEPLOYMENT_PROXY.to_string(),
+            factory_function: None,
+            fee: None,
+            ref_id: None,
+            idempotency_key: None,
+        }
+    }
+
+    /// Deploy through a different factory
+    ///
+    /// Defaults to [`DETERMINISTIC_DEPLOYMENT_PROXY`].
+    pub fn factory_address(mut self, factory_address: String) -> Self {
+        self.factory_address = factory_address;
+        self
+    }
+
+    /// Call a factory function instead of sending raw `salt ++ init_code`
+    ///
+    /// The function must take `(bytes32 salt, bytes initCode)` and deploy with
+    /// `CREATE2` from the factory itself, e.g. `"deploy(bytes32,bytes)"`.
+    pub fn factory_function(mut self, signature: String) -> Self {
+        self.factory_function = Some(signature);
+        self
+    }
+
+    /// Set fee settings
+    pub fn fee(mut self, fee: FeeConfig) -> Self {
+        self.fee = Some(fee);
+        self
+    }
+
+    /// Set fee level (LOW, MEDIUM, HIGH)
+    pub fn fee_level(mut self, fee_level: FeeLevel) -> Self {
+        self.fee = Some(FeeConfig::Level(fee_level));
+        self
+    }
+
+    /// Set reference ID
+    pub fn ref_id(mut self, ref_id: String) -> Self {
+        self.ref_id = Some(ref_id);
+        self
+    }
+
+    /// Set a custom idempotency key
+    pub fn idempotency_key(mut self, key: String) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
+
+    /// Address the contract will be deployed at
+    pub fn predicted_address(&self) -> CircleResult<Address> {
+        predict_create2_address(&self.factory_address, &self.salt, &self.bytecode)
+    }
+
+    /// Convert into the contract execution that performs the deployment
+    ///
+    /// Fails if the salt, bytecode or factory address is malformed.
+    pub fn into_execution(self) -> CircleResult<CreateContractExecutionTransactionRequestBuilder> {
+        let factory: Address = self.factory_address.parse()?;
+        let salt = parse_salt(&self.salt)?;
+        let bytecode = parse_hex("bytecode", &self.bytecode)?;
+
+        let mut builder = CreateContractExecutionTransactionRequestBuilder::new(
+            self.wallet_id,
+            factory.to_string(),
+        );
+        builder = match &self.factory_function {
+            Some(signature) => builder.encoded_call(
+                signature,
+                &[
+                    AbiValue::fixed_bytes(salt.to_vec())?,
+                    AbiValue::bytes(bytecode),
+                ],
+            ),
+            None => builder.call_data(format!("0x{}{}", hex::encode(salt), hex::encode(bytecode))),
+        };
+        if let Some(fee) = self.fee {
+            builder = builder.fee(fee);
+        }
+        if let Some(ref_id) = self.ref_id {
+            builder = builder.ref_id(ref_id);
+        }
+        if let Some(key) = self.idempotency_key {
+            builder = builder.idempotency_key(key);
+        }
+        Ok(builder.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::encode_function_call;
+
+    const ZERO_SALT: &str = "0x0000000000000000000000000000000000000000000000000000000000000000";
+
+    #[test]
+    fn test_predict_create2_address_eip1014_vectors() {
+        let cases = [
+            (
+                "0x0000000000000000000000000000000000000000",
+                ZERO_SALT,
+                "0x00",
+                "0x4d1a2e2bb4f88f0250f26ffff098b0b30b26bf38",
+            ),
+            (
+                "0x00000000000000000000000000000000deadbeef",
+                "0x00000000000000000000000000000000000000000000000000000000cafebabe",
+                "0xdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef",
+                "0x1d8bfdc5d46dc4f61d6b6115972536ebe6a8854c",
+            ),
+            (
+                "0x0000000000000000000000000000000000000000",
+                ZERO_SALT,
+                "0x",
+                "0xe33c0c7f7df4809055c3eba6c09cfe4baf1bd9e0",
+            ),
+        ];
+
+        for (deployer, salt, bytecode, expected) in cases {
+            let address = predict_create2_address(deployer, salt, bytecode).unwrap();
+            assert_eq!(address.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_predict_create2_address_rejects_bad_salt() {
+        let result = predict_create2_address(DETERMINISTIC_DEPLOYMENT_PROXY, "0x01", "0x00");
+        assert!(matches!(result, Err(CircleError::Abi(message)) if message.contains("32 bytes")));
+    }
+
+    #[test]
+    fn test_into_execution_targets_factory() {
+        let deployment = Create2DeploymentBuilder::new(
+            "wallet-id".to_string(),
+            ZERO_SALT.to_string(),
+            "0x6001".to_string(),
+        )
+        .fee_level(FeeLevel::High);
+
+        let predicted = deployment.predicted_address().unwrap();
+        let expected =
+            predict_create2_address(DETERMINISTIC_DEPLOYMENT_PROXY, ZERO_SALT, "0x6001").unwrap();
+        assert_eq!(predicted, expected);
+
+        let execution = deployment.into_execution().unwrap();
+        assert_eq!(
+            execution.contract_address,
+            DETERMINISTIC_DEPLOYMENT_PROXY.to_lowercase()
+        );
+        assert_eq!(
+            execution.call_data.as_deref(),
+            Some(format!("{}6001", ZERO_SALT).as_str())
+        );
+        assert_eq!(execution.fee, Some(FeeConfig::Level(FeeLevel::High)));
+
+        let execution = Create2DeploymentBuilder::new(
+            "wallet-id".to_string(),
+            ZERO_SALT.to_string(),
+            "0x6001".to_string(),
+        )
+        .factory_function("deploy(bytes32,bytes)".to_string())
+        .into_execution()
+        .unwrap();
+        let expected = encode_function_call(
+            "deploy(bytes32,bytes)",
+            &[
+                AbiValue::fixed_bytes(vec![0u8; 32]).unwrap(),
+                AbiValue::bytes(vec![0x60, 0x01]),
+            ],
+        );
+        assert_eq!(execution.call_data.as_deref(), Some(expected.as_str()));
+    }
+}
@@ -1,9 +1,50 @@
-use crate::{helper::PaginationParams, types::Blockchain};
+use crate::{
+    abi::{decode_hex, AbiDecode},
+    fees::{EstimatedFee, FeeEstimate},
+    helper::{time_window, CircleResult, PaginationParams, ResourceId, SecretString},
+    types::Blockchain,
+};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// `since`/`today`/`between` creation-date filters for list parameters
+/// with `from`/`to` fields
+macro_rules! time_window_filters {
+    ($($params:ty),* $(,)?) => {$(
+        impl $params {
+            /// Only items created within the last `duration`
+            pub fn since(mut self, duration: Duration) -> Self {
+                self.from = Some(time_window::since(duration));
+                self.to = None;
+                self
+            }
+
+            /// Only items created since midnight UTC
+            pub fn today(mut self) -> Self {
+                self.from = Some(time_window::start_of_today());
+                self.to = None;
+                self
+            }
+
+            /// Only items created between `from` and `to`
+            pub fn between(mut self, from: DateTime<Utc>, to: DateTime<Utc>) -> Self {
+                self.from = Some(from);
+                self.to = Some(to);
+                self
+            }
+        }
+    )*};
+}
+
+time_window_filters!(
+    ListContractsParams,
+    ListEventMonitorsParams,
+    ListEventLogsParams
+);
 
 /// Request structure for estimating contract template deployment fee
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct EstimateTemplateDeploymentFeeBody {
     /// Blockchain network
@@ -18,11 +59,11 @@ pub struct EstimateTemplateDeploymentFeeBody {
 }
 
 /// Request structure for deploying a contract from template
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DeployContractFromTemplateRequest {
     /// Entity secret ciphertext
-    pub entity_secret_ciphertext: String,
+    pub entity_secret_ciphertext: SecretString,
 
     /// Contract name
     pub name: String,
@@ -70,7 +111,7 @@ pub struct DeployContractFromTemplateRequest {
 }
 
 /// Request structure for importing an existing contract
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ImportContractRequest {
     /// Blockchain network
@@ -91,7 +132,7 @@ pub struct ImportContractRequest {
 }
 
 /// Request structure for updating a contract
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateContractRequest {
     /// Contract name
@@ -103,55 +144,35 @@ pub struct UpdateContractRequest {
     pub ref_id: Option<String>,
 }
 
-/// Fee level details
-#[derive(Debug, Deserialize)]
+/// Request structure for replacing a contract's stored ABI
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct FeeLevelEstimate {
-    /// Gas limit
-    pub gas_limit: String,
-
-    /// Base fee
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub base_fee: Option<String>,
-
-    /// Priority fee
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub priority_fee: Option<String>,
-
-    /// Max fee
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub max_fee: Option<String>,
-
-    /// Gas price (for non-EIP-1559)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub gas_price: Option<String>,
-
-    /// Network fee - The estimated network fee (maximum amount in native token like ETH, SOL)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub network_fee: Option<String>,
-
-    /// Network fee raw - Similar to network_fee but with lower buffer, closer to actual on-chain expense
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub network_fee_raw: Option<String>,
+pub struct UpdateContractAbiRequest {
+    /// ABI as a JSON array string
+    pub abi_json: String,
 }
 
-/// Fee estimation response
-#[derive(Debug, Deserialize)]
+/// Request structure for archiving or unarchiving a contract
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct FeeEstimation {
-    /// Low fee estimate
-    pub low: FeeLevelEstimate,
+pub struct ArchiveContractRequest {
+    /// Whether the contract should be archived
+    pub archived: bool,
+}
 
-    /// Medium fee estimate
-    pub medium: FeeLevelEstimate,
+/// Fee level details (the shared [`EstimatedFee`])
+pub type FeeLevelEstimate = EstimatedFee;
 
-    /// High fee estimate
-    pub high: FeeLevelEstimate,
-}
+/// Fee estimation response (the shared [`FeeEstimate`])
+pub type FeeEstimation = FeeEstimate;
 
 /// Contract response structure
+///
+/// Non-exhaustive, since Circle keeps adding fields: deserialize one, or
+/// start from a [`fixtures`](crate::testing::fixtures) value in tests.
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct Contract {
     /// Unique contract identifier
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -167,7 +188,7 @@ pub struct Contract {
 
     /// Blockchain network
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub blockchain: Option<String>,
+    pub blockchain: Option<Blockchain>,
 
     /// Creation timestamp
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -248,6 +269,16 @@ pub struct Contract {
     /// Implementation contract (for proxy contracts)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub implementation_contract: Option<Box<Contract>>,
+
+    /// Fields not modelled above
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ResourceId for Contract {
+    fn resource_id(&self) -> &str {
+        self.id.as_deref().unwrap_or_default()
+    }
 }
 
 /// Template contract deployment response
@@ -285,12 +316,79 @@ pub struct QueryContractResponse {
     pub output_data: String,
 }
 
+impl QueryContractResponse {
+    /// Decode `output_data` into a typed value
+    ///
+    /// Use a single type for functions with one return value and a tuple for
+    /// functions with several, e.g. `(U256, U256, u32)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use inf_circle_sdk::abi::U256;
+    /// use inf_circle_sdk::contract::dto::QueryContractResponse;
+    ///
+    /// let response = QueryContractResponse {
+    ///     output_values: None,
+    ///     output_data: format!("0x{:064x}", 1_000_000u64),
+    /// };
+    /// let balance: U256 = response.decode_output().unwrap();
+    /// assert_eq!(balance, U256::from(1_000_000u64));
+    /// ```
+    pub fn decode_output<T: AbiDecode>(&self) -> CircleResult<T> {
+        decode_hex(&self.output_data)
+    }
+}
+
+/// ERC-20 token metadata returned by `Erc20Client::metadata`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Erc20Metadata {
+    /// Token name (e.g., "USD Coin")
+    pub name: String,
+
+    /// Token symbol (e.g., "USDC")
+    pub symbol: String,
+
+    /// Number of decimals used by token amounts
+    pub decimals: u8,
+}
+
+/// NFT metadata document referenced by an ERC-721 `tokenURI` or ERC-1155 `uri`
+///
+/// Field names follow the ERC-721 / OpenSea metadata standard (snake_case).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct NftMetadata {
+    /// Name of the asset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Description of the asset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// URI of the asset image
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+
+    /// URI of an animation or multimedia attachment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub animation_url: Option<String>,
+
+    /// External URL describing the asset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_url: Option<String>,
+
+    /// Asset attributes (typically `{"trait_type": ..., "value": ...}` objects)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<Vec<serde_json::Value>>,
+}
+
 /// Request structure for deploying a contract from bytecode
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DeployContractRequest {
     /// Entity secret ciphertext
-    pub entity_secret_ciphertext: String,
+    pub entity_secret_ciphertext: SecretString,
 
     /// Bytecode of the contract being deployed
     pub bytecode: String,
@@ -358,7 +456,7 @@ pub struct ContractsResponse {
 }
 
 /// Query parameters for listing contracts
-#[derive(Debug, Serialize, Default)]
+#[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct ListContractsParams {
     /// Filter by contract address
@@ -525,11 +623,16 @@ pub enum NotificationType {
     /// Ramp session KYC submitted
     #[serde(rename = "rampSession.kycSubmitted")]
     RampSessionKycSubmitted,
+
+    /// A type this SDK version does not know, such as a test ping or a
+    /// type Circle added later, kept as sent
+    #[serde(untagged)]
+    Other(String),
 }
 
 impl NotificationType {
     /// Convert the enum to its string representation
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             Self::All => "*",
             Self::TransactionsAll => "transactions.*",
@@ -564,6 +667,7 @@ impl NotificationType {
             Self::RampSessionKycApproved => "rampSession.kycApproved",
             Self::RampSessionKycRejected => "rampSession.kycRejected",
             Self::RampSessionKycSubmitted => "rampSession.kycSubmitted",
+            Self::Other(notification_type) => notification_type,
         }
     }
 }
@@ -597,8 +701,14 @@ pub struct NotificationSubscription {
     pub restricted: bool,
 }
 
+impl ResourceId for NotificationSubscription {
+    fn resource_id(&self) -> &str {
+        &self.id
+    }
+}
+
 /// Request structure for creating a notification subscription
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateNotificationSubscriptionBody {
     /// URL of the endpoint to subscribe to notifications
@@ -614,7 +724,7 @@ pub struct CreateNotificationSubscriptionBody {
 pub type CreateNotificationSubscriptionResponse = NotificationSubscription;
 
 /// Request structure for updating a notification subscription
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateNotificationSubscriptionBody {
     /// Whether the subscription is enabled. true indicates the subscription is active.
@@ -628,6 +738,95 @@ pub struct UpdateNotificationSubscriptionBody {
 /// Note: The API returns the subscription directly, not wrapped in a collection
 pub type UpdateNotificationSubscriptionResponse = NotificationSubscription;
 
+/// Desired state of one webhook subscription, used by
+/// [`CircleView::sync_notification_subscriptions`](crate::circle_view::circle_view::CircleView::sync_notification_subscriptions)
+///
+/// Subscriptions are matched to specs by endpoint. An empty
+/// `notification_types` list means an unrestricted subscription (all types).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubscriptionSpec {
+    /// URL of the endpoint subscribing to notifications
+    pub endpoint: String,
+
+    /// Notification types to subscribe to; empty for all types
+    pub notification_types: Vec<NotificationType>,
+
+    /// Subscription name; the name Circle assigned is kept when `None`
+    pub name: Option<String>,
+
+    /// Whether the subscription should be enabled
+    pub enabled: bool,
+}
+
+impl SubscriptionSpec {
+    /// Create an enabled, unrestricted spec for `endpoint`
+    pub fn new(endpoint: String) -> Self {
+        Self {
+            endpoint,
+            notification_types: Vec::new(),
+            name: None,
+            enabled: true,
+        }
+    }
+
+    /// Restrict the subscription to these notification types
+    pub fn notification_types(mut self, types: Vec<NotificationType>) -> Self {
+        self.notification_types = types;
+        self
+    }
+
+    /// Set the subscription name
+    pub fn name(mut self, name: String) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Set whether the subscription should be enabled
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Whether `subscription` delivers exactly the notification types in this spec
+    pub(crate) fn types_match(&self, subscription: &NotificationSubscription) -> bool {
+        let subscribed: &[NotificationType] = if subscription.restricted {
+            &subscription.notification_types
+        } else {
+            &[]
+        };
+        let desired = &self.notification_types;
+        desired.iter().all(|t| subscribed.contains(t))
+            && subscribed.iter().all(|t| desired.contains(t))
+    }
+}
+
+/// Changes made by
+/// [`CircleView::sync_notification_subscriptions`](crate::circle_view::circle_view::CircleView::sync_notification_subscriptions)
+///
+/// Each list holds subscription IDs. A subscription whose notification types
+/// changed is deleted and recreated, since types cannot be updated in place.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SubscriptionSyncReport {
+    /// Subscriptions created for specs with no matching subscription
+    pub created: Vec<String>,
+
+    /// Subscriptions whose name or enabled flag was updated
+    pub updated: Vec<String>,
+
+    /// Subscriptions removed because no spec wanted them
+    pub deleted: Vec<String>,
+
+    /// Subscriptions that already matched their spec
+    pub unchanged: Vec<String>,
+}
+
+impl SubscriptionSyncReport {
+    /// Whether the sync made no changes
+    pub fn is_noop(&self) -> bool {
+        self.created.is_empty() && self.updated.is_empty() && self.deleted.is_empty()
+    }
+}
+
 /// Response structure for getting health of Circle API
 #[derive(Debug, Deserialize, Serialize)]
 pub struct PingResponse {
@@ -666,8 +865,14 @@ pub struct EventMonitor {
     pub update_date: Option<DateTime<Utc>>,
 }
 
+impl ResourceId for EventMonitor {
+    fn resource_id(&self) -> &str {
+        &self.id
+    }
+}
+
 /// Request structure for creating an event monitor
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateEventMonitorRequest {
     /// UUID v4 for idempotency
@@ -692,7 +897,7 @@ pub struct EventMonitorResponse {
 }
 
 /// Request structure for updating an event monitor
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateEventMonitorRequest {
     /// Indicates whether the event monitor should be active (true) or inactive (false)
@@ -708,7 +913,7 @@ pub struct EventMonitorsResponse {
 }
 
 /// Query parameters for listing event monitors
-#[derive(Debug, Serialize, Default)]
+#[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct ListEventMonitorsParams {
     /// Filter contracts by address
@@ -780,6 +985,12 @@ pub struct EventLog {
     pub first_confirm_date: String,
 }
 
+impl ResourceId for EventLog {
+    fn resource_id(&self) -> &str {
+        &self.id
+    }
+}
+
 /// Response structure for listing event logs
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -789,7 +1000,7 @@ pub struct EventLogsResponse {
 }
 
 /// Query parameters for listing event logs
-#[derive(Debug, Serialize, Default, Clone)]
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ListEventLogsParams {
     /// Filter contracts by address
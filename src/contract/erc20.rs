@@ -0,0 +1,227 @@
+//! ERC-20 Token Helper
+//!
+//! This module provides [`Erc20Client`], a thin wrapper around a token contract
+//! that exposes the standard ERC-20 functions. Reads are performed with
+//! `CircleView::query_contract` and writes with
+//! `CircleOps::create_dev_contract_execution_transaction`, with call data
+//! encoded locally so amounts keep full `uint256` precision.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::{
+//!     abi::U256,
+//!     circle_ops::circler_ops::CircleOps,
+//!     circle_view::circle_view::CircleView,
+//!     contract::erc20::Erc20Client,
+//!     types::Blockchain,
+//! };
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let view = CircleView::new()?;
+//! let ops = CircleOps::new(None)?;
+//!
+//! let usdc = Erc20Client::new(
+//!     Blockchain::EthSepolia,
+//!     "0x1c7D4B196Cb0C7B01d743Fbc6116a902379C7238".to_string(),
+//! );
+//!
+//! let metadata = usdc.metadata(&view).await?;
+//! let balance = usdc.balance_of(&view, "0xOwnerAddress").await?;
+//! println!("{} balance: {} (decimals: {})", metadata.symbol, balance, metadata.decimals);
+//!
+//! let tx = usdc
+//!     .transfer(&ops, "wallet-id", "0xRecipientAddress", U256::from(1_000_000u64))
+//!     .await?;
+//! println!("Transfer transaction: {}", tx.id);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    abi::{AbiDecode, AbiValue, Address, U256},
+    circle_ops::circler_ops::CircleOps,
+    circle_view::circle_view::CircleView,
+    contract::{
+        calls::{execute_encoded, query_decoded},
+        dto::Erc20Metadata,
+    },
+    dev_wallet::dto::{CreateContractExecutionTransactionResponse, FeeConfig, FeeLevel},
+    helper::CircleResult,
+    types::Blockchain,
+};
+
+/// Client for a single ERC-20 token contract
+///
+/// Write operations are submitted from a developer-controlled wallet using the
+/// configured fee level (`MEDIUM` by default).
+#[derive(Clone, Debug)]
+pub struct Erc20Client {
+    /// Blockchain the token is deployed on
+    pub blockchain: Blockchain,
+
+    /// Address of the token contract
+    pub token_address: String,
+
+    /// Fee settings used for write operations
+    pub fee: FeeConfig,
+}
+
+impl Erc20Client {
+    /// Create a client for the token at `token_address`
+    pub fn new(blockchain: Blockchain, token_address: String) -> Self {
+        Self {
+            blockchain,
+            token_address,
+            fee: FeeConfig::Level(FeeLevel::Medium),
+        }
+    }
+
+    /// Set the fee level used for write operations
+    pub fn fee_level(mut self, fee_level: FeeLevel) -> Self {
+        self.fee = FeeConfig::Level(fee_level);
+        self
+    }
+
+    /// Set the fee settings used for write operations
+    pub fn fee(mut self, fee: FeeConfig) -> Self {
+        self.fee = fee;
+        self
+    }
+
+    /// Get the token balance of `owner` in base units
+    pub async fn balance_of(&self, view: &CircleView, owner: &str) -> CircleResult<U256> {
+        let owner: Address = owner.parse()?;
+        self.call(view, "balanceOf(address)", &[AbiValue::from(owner)])
+            .await
+    }
+
+    /// Get the amount `spender` is allowed to transfer on behalf of `owner`, in base units
+    pub async fn allowance(
+        &self,
+        view: &CircleView,
+        owner: &str,
+        spender: &str,
+    ) -> CircleResult<U256> {
+        let owner: Address = owner.parse()?;
+        let spender: Address = spender.parse()?;
+        self.call(
+            view,
+            "allowance(address,address)",
+            &[AbiValue::from(owner), AbiValue::from(spender)],
+        )
+        .await
+    }
+
+    /// Get the total token supply in base units
+    pub async fn total_supply(&self, view: &CircleView) -> CircleResult<U256> {
+        self.call(view, "totalSupply()", &[]).await
+    }
+
+    /// Get the token name, symbol and decimals
+    pub async fn metadata(&self, view: &CircleView) -> CircleResult<Erc20Metadata> {
+        let name: String = self.call(view, "name()", &[]).await?;
+        let symbol: String = self.call(view, "symbol()", &[]).await?;
+        let decimals: u8 = self.call(view, "decimals()", &[]).await?;
+
+        Ok(Erc20Metadata {
+            name,
+            symbol,
+            decimals,
+        })
+    }
+
+    /// Transfer `amount` base units from the wallet to `to`
+    pub async fn transfer(
+        &self,
+        ops: &CircleOps,
+        wallet_id: &str,
+        to: &str,
+        amount: U256,
+    ) -> CircleResult<CreateContractExecutionTransactionResponse> {
+        let to: Address = to.parse()?;
+        self.execute(
+            ops,
+            wallet_id,
+            "transfer(address,uint256)",
+            &[AbiValue::from(to), AbiValue::from(amount)],
+        )
+        .await
+    }
+
+    /// Allow `spender` to transfer up to `amount` base units from the wallet
+    pub async fn approve(
+        &self,
+        ops: &CircleOps,
+        wallet_id: &str,
+        spender: &str,
+        amount: U256,
+    ) -> CircleResult<CreateContractExecutionTransactionResponse> {
+        let spender: Address = spender.parse()?;
+        self.execute(
+            ops,
+            wallet_id,
+            "approve(address,uint256)",
+            &[AbiValue::from(spender), AbiValue::from(amount)],
+        )
+        .await
+    }
+
+    /// Transfer `amount` base units from `from` to `to` using the wallet's allowance
+    pub async fn transfer_from(
+        &self,
+        ops: &CircleOps,
+        wallet_id: &str,
+        from: &str,
+        to: &str,
+        amount: U256,
+    ) -> CircleResult<CreateContractExecutionTransactionResponse> {
+        let from: Address = from.parse()?;
+        let to: Address = to.parse()?;
+        self.execute(
+            ops,
+            wallet_id,
+            "transferFrom(address,address,uint256)",
+            &[
+                AbiValue::from(from),
+                AbiValue::from(to),
+                AbiValue::from(amount),
+            ],
+        )
+        .await
+    }
+
+    async fn call<T: AbiDecode>(
+        &self,
+        view: &CircleView,
+        signature: &str,
+        values: &[AbiValue],
+    ) -> CircleResult<T> {
+        query_decoded(
+            view,
+            &self.blockchain,
+            &self.token_address,
+            signature,
+            values,
+        )
+        .await
+    }
+
+    async fn execute(
+        &self,
+        ops: &CircleOps,
+        wallet_id: &str,
+        signature: &str,
+        values: &[AbiValue],
+    ) -> CircleResult<CreateContractExecutionTransactionResponse> {
+        execute_encoded(
+            ops,
+            wallet_id,
+            &self.token_address,
+            &self.fee,
+            signature,
+            values,
+        )
+        .await
+    }
+}
@@ -0,0 +1,408 @@
+//! Declarative Event Monitor Configuration
+//!
+//! This module provides [`EventMonitorSet`], a list of contracts and the events
+//! to monitor on each. [`EventMonitorSet::apply`] lists the existing monitors
+//! for every configured contract and creates, enables, disables or deletes
+//! monitors until they match. When a contract lists no event signatures, every
+//! event in the contract's stored ABI is monitored.
+//!
+//! Monitors are matched by event signature hash, so `"Transfer(address
+//! indexed from, address indexed to, uint256 value)"` and
+//! `"Transfer(address,address,uint256)"` refer to the same monitor. Monitors
+//! on contracts that are not part of the set are left untouched.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::{
+//!     circle_view::circle_view::CircleView,
+//!     contract::event_monitor_set::{EventMonitorSet, MonitoredContract},
+//!     types::Blockchain,
+//! };
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let view = CircleView::new()?;
+//!
+//! let set = EventMonitorSet::new()
+//!     // Every event in the stored ABI
+//!     .contract(MonitoredContract::new(
+//!         "0x1234567890abcdef1234567890abcdef12345678".to_string(),
+//!         Blockchain::EthSepolia,
+//!     ))
+//!     // Only `Transfer`, with `Approval` kept but paused
+//!     .contract(
+//!         MonitoredContract::new(
+//!             "0x1c7D4B196Cb0C7B01d743Fbc6116a902379C7238".to_string(),
+//!             Blockchain::EthSepolia,
+//!         )
+//!         .event_signature("Transfer(address,address,uint256)".to_string())
+//!         .disabled_event_signature("Approval(address,address,uint256)".to_string()),
+//!     );
+//!
+//! let report = set.apply(&view).await?;
+//! println!("created {}, deleted {}", report.created.len(), report.deleted.len());
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    circle_view::circle_view::CircleView,
+    contract::{
+        abi::extract_event_signatures,
+        dto::{Contract, EventMonitor, ListContractsParams, ListEventMonitorsParams},
+        views::{
+            create_event_monitor::CreateEventMonitorBodyBuilder,
+            update_event_monitor::UpdateEventMonitorBodyBuilder,
+        },
+    },
+    helper::{event_signature_hash, generate_uuid, CircleError, CircleResult},
+    types::Blockchain,
+};
+
+/// Events to monitor on one contract
+#[derive(Clone, Debug)]
+pub struct MonitoredContract {
+    /// On-chain address of the contract
+    pub contract_address: String,
+
+    /// Blockchain the contract is deployed on
+    pub blockchain: Blockchain,
+
+    /// Event signatures with an enabled monitor
+    ///
+    /// When empty, every event in the contract's stored ABI is monitored
+    /// (except those listed in `disabled_event_signatures`).
+    pub event_signatures: Vec<String>,
+
+    /// Event signatures whose monitor should exist but be disabled
+    pub disabled_event_signatures: Vec<String>,
+}
+
+impl MonitoredContract {
+    /// Monitor every event in the stored ABI of the contract at `contract_address`
+    pub fn new(contract_address: String, blockchain: Blockchain) -> Self {
+        Self {
+            contract_address,
+            blockchain,
+            event_signatures: Vec::new(),
+            disabled_event_signatures: Vec::new(),
+        }
+    }
+
+    /// Add an event signature to monitor
+    pub fn event_signature(mut self, signature: String) -> Self {
+        self.event_signatures.push(signature);
+        self
+    }
+
+    /// Add an event signature whose monitor should be kept disabled
+    pub fn disabled_event_signature(mut self, signature: String) -> Self {
+        self.disabled_event_signatures.push(signature);
+        self
+    }
+}
+
+/// Desired event monitors across a set of contracts
+#[derive(Clone, Debug, Default)]
+pub struct EventMonitorSet {
+    /// Contracts and the events to monitor on each
+    pub contracts: Vec<MonitoredContract>,
+}
+
+/// Monitors changed by [`EventMonitorSet::apply`], as monitor IDs
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EventMonitorSetReport {
+    /// Monitors created for events with no existing monitor
+    pub created: Vec<String>,
+
+    /// Disabled monitors that were re-enabled
+    pub enabled: Vec<String>,
+
+    /// Monitors that were disabled (including ones created disabled)
+    pub disabled: Vec<String>,
+
+    /// Monitors for events that are no longer configured
+    pub deleted: Vec<String>,
+
+    /// Monitors that already matched the configuration
+    pub unchanged: Vec<String>,
+}
+
+/// An event signature with the enabled state its monitor should have
+struct DesiredMonitor {
+    signature: String,
+    hash: String,
+    enabled: bool,
+}
+
+impl EventMonitorSet {
+    /// Create an empty set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a contract to the set
+    pub fn contract(mut self, contract: MonitoredContract) -> Self {
+        self.contracts.push(contract);
+        self
+    }
+
+    /// Create, enable, disable and delete monitors so they match the set
+    ///
+    /// Contracts are processed in order. For each one, the stored ABI is
+    /// fetched when no enabled signatures are configured, existing monitors
+    /// whose event is not configured are deleted, and missing monitors are
+    /// created.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Config` if a contract relies on its stored ABI
+    /// but Circle has no contract at that address or the ABI declares no
+    /// events. API errors stop the apply part-way; applying again picks up
+    /// from the current state.
+    pub async fn apply(&self, view: &CircleView) -> CircleResult<EventMonitorSetReport> {
+        let mut report = EventMonitorSetReport::default();
+        for contract in &self.contracts {
+            let desired = desired_monitors(view, contract).await?;
+            let existing = view
+                .list_event_monitors(Some(ListEventMonitorsParams {
+                    contract_address: Some(contract.contract_address.clone()),
+                    blockchain: Some(contract.blockchain.clone()),
+                    ..Default::default()
+                }))
+                .await?
+                .items;
+
+            let mut matched = vec![false; desired.len()];
+            for monitor in existing {
+                let wanted = desired.iter().position(|d| hash_matches(&monitor, &d.hash));
+                let Some(i) = wanted.filter(|&i| !matched[i]) else {
+                    view.delete_event_monitor(&monitor.id).await?;
+                    report.deleted.push(monitor.id);
+                    continue;
+                };
+                matched[i] = true;
+                set_enabled(view, monitor, desired[i].enabled, &mut report).await?;
+            }
+
+            for (wanted, _) in desired.iter().zip(matched).filter(|(_, m)| !m) {
+                let monitor = view
+                    .create_event_monitor(CreateEventMonitorBodyBuilder::new(
+                        generate_uuid(),
+                        wanted.signature.clone(),
+                        contract.contract_address.clone(),
+                        contract.blockchain.clone(),
+                    ))
+                    .await?
+                    .event_monitor;
+                report.created.push(monitor.id.clone());
+                if !wanted.enabled {
+                    set_enabled(view, monitor, false, &mut report).await?;
+                }
+            }
+        }
+        Ok(report)
+    }
+}
+
+/// Resolve the configured signatures of `contract`, fetching its ABI if needed
+async fn desired_monitors(
+    view: &CircleView,
+    contract: &MonitoredContract,
+) -> CircleResult<Vec<DesiredMonitor>> {
+    let enabled = if contract.event_signatures.is_empty() {
+        let stored = find_contract(view, contract).await?;
+        let signatures: Vec<String> = extract_event_signatures(&stored)
+            .into_iter()
+            .map(|event| event.canonical)
+            .collect();
+        if signatures.is_empty() {
+            return Err(CircleError::Config(format!(
+                "Contract {} has no events in its stored ABI",
+                contract.contract_address
+            )));
+        }
+        signatures
+    } else {
+        contract.event_signatures.clone()
+    };
+
+    let mut desired: Vec<DesiredMonitor> = Vec::new();
+    for (signature, is_enabled) in contract
+        .disabled_event_signatures
+        .iter()
+        .map(|s| (s, false))
+        .chain(enabled.iter().map(|s| (s, true)))
+    {
+        let hash = event_signature_hash(signature);
+        // Disabled entries come first so they win over ABI-derived ones
+        if desired.iter().all(|d| d.hash != hash) {
+            desired.push(DesiredMonitor {
+                signature: signature.clone(),
+                hash,
+                enabled: is_enabled,
+            });
+        }
+    }
+    Ok(desired)
+}
+
+async fn find_contract(view: &CircleView, contract: &MonitoredContract) -> CircleResult<Contract> {
+    let contracts = view
+        .list_contracts(Some(ListContractsParams {
+            address: Some(contract.contract_address.clone()),
+            blockchain: Some(contract.blockchain.clone()),
+            ..Default::default()
+        }))
+        .await?
+        .items;
+
+    contracts
+        .into_iter()
+        .find(|c| {
+            c.contract_address
+                .as_deref()
+                .or(c.address.as_deref())
+                .is_some_and(|a| a.eq_ignore_ascii_case(&contract.contract_address))
+        })
+        .ok_or_else(|| {
+            CircleError::Config(format!(
+                "No contract at {} on {} to read event signatures from",
+                contract.contract_address,
+                contract.blockchain.as_str()
+            ))
+        })
+}
+
+fn hash_matches(monitor: &EventMonitor, hash: &str) -> bool {
+    monitor
+        .event_signature_hash
+        .trim_start_matches("0x")
+        .eq_ignore_ascii_case(hash.trim_start_matches("0x"))
+}
+
+async fn set_enabled(
+    view: &CircleView,
+    monitor: EventMonitor,
+    enabled: bool,
+    report: &mut EventMonitorSetReport,
+) -> CircleResult<()> {
+    if monitor.is_enabled == enabled {
+        report.unchanged.push(monitor.id);
+        return Ok(());
+    }
+    view.update_event_monitor(UpdateEventMonitorBodyBuilder::new(
+        monitor.id.clone(),
+        enabled,
+    ))
+    .await?;
+    if enabled {
+        report.enabled.push(monitor.id);
+    } else {
+        report.disabled.push(monitor.id);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{fixtures, MockCircle};
+    use mockito::Matcher;
+    use serde_json::json;
+
+    const APPROVAL_HASH: &str =
+        "0x8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925";
+
+    fn monitor(id: &str, signature: &str, hash: &str, enabled: bool) -> serde_json::Value {
+        let mut monitor = serde_json::to_value(fixtures::event_monitor()).unwrap();
+        monitor["id"] = json!(id);
+        monitor["eventSignature"] = json!(signature);
+        monitor["eventSignatureHash"] = json!(hash);
+        monitor["isEnabled"] = json!(enabled);
+        monitor
+    }
+
+    #[tokio::test]
+    async fn test_apply_converges_monitors() {
+        let mut circle = MockCircle::start().await;
+        let transfer = fixtures::event_monitor();
+        circle
+            .respond(
+                "GET",
+                "/v1/w3s/contracts/monitors",
+                &json!({ "eventMonitors": [
+                    monitor("transfer", &transfer.event_signature, &transfer.event_signature_hash, false),
+                    monitor("stale", "Paused(address)", "0x62e78cea01bee320cd4e420270b5ea74000d11b0c9f74754ebdbfc544b05a258", true),
+                ]}),
+            )
+            .await;
+        let enabled = circle
+            .server()
+            .mock("PUT", "/v1/w3s/contracts/monitors/transfer")
+            .match_body(Matcher::Json(json!({ "isEnabled": true })))
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "data": { "eventMonitor": transfer } }).to_string())
+            .create_async()
+            .await;
+        let deleted = circle
+            .respond("DELETE", "/v1/w3s/contracts/monitors/stale", &json!({}))
+            .await;
+        let created = circle
+            .server()
+            .mock("POST", "/v1/w3s/contracts/monitors")
+            .match_body(Matcher::PartialJson(json!({
+                "eventSignature": "Approval(address,address,uint256)",
+                "contractAddress": fixtures::CONTRACT_ADDRESS,
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({ "data": { "eventMonitor": monitor(
+                    "approval",
+                    "Approval(address,address,uint256)",
+                    APPROVAL_HASH,
+                    true,
+                )}})
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let disabled = circle
+            .server()
+            .mock("PUT", "/v1/w3s/contracts/monitors/approval")
+            .match_body(Matcher::Json(json!({ "isEnabled": false })))
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({ "data": { "eventMonitor": monitor(
+                    "approval",
+                    "Approval(address,address,uint256)",
+                    APPROVAL_HASH,
+                    false,
+                )}})
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let set = EventMonitorSet::new().contract(
+            MonitoredContract::new(
+                fixtures::CONTRACT_ADDRESS.to_string(),
+                Blockchain::EthSepolia,
+            )
+            .event_signature(
+                "Transfer(address indexed from, address indexed to, uint256 value)".to_string(),
+            )
+            .disabled_event_signature("Approval(address,address,uint256)".to_string()),
+        );
+        let report = set.apply(&circle.view()).await.unwrap();
+
+        assert_eq!(report.enabled, ["transfer"]);
+        assert_eq!(report.deleted, ["stale"]);
+        assert_eq!(report.created, ["approval"]);
+        assert_eq!(report.disabled, ["approval"]);
+        for mock in [enabled, deleted, created, disabled] {
+            mock.assert_async().await;
+        }
+    }
+}
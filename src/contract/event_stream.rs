@@ -0,0 +1,239 @@
+//! Polling tail over contract event logs for CircleView
+
+use std::collections::{HashSet, VecDeque};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use futures_util::{stream, Stream};
+
+use crate::circle_view::circle_view::CircleView;
+use crate::contract::dto::{EventLog, ListEventLogsParams};
+use crate::helper::{sleep, CircleResult};
+
+/// Page size used when the caller's params don't set one
+const DEFAULT_PAGE_SIZE: u32 = 50;
+
+/// State carried between polls of [`CircleView::stream_event_logs`]
+struct EventLogTail<'a> {
+    view: &'a CircleView,
+    params: ListEventLogsParams,
+    poll_interval: Duration,
+    /// `from` filter for the next poll: the newest `firstConfirmDate` seen so far
+    watermark: Option<DateTime<Utc>>,
+    /// IDs already yielded at the watermark timestamp. `from` is inclusive,
+    /// so these come back on the next poll and must be skipped.
+    seen: HashSet<String>,
+    pending: VecDeque<EventLog>,
+    polled: bool,
+}
+
+impl EventLogTail<'_> {
+    /// Fetch every page newer than the watermark and queue the unseen logs
+    async fn poll(&mut self) -> CircleResult<()> {
+        let page_size = self
+            .params
+            .pagination
+            .page_size
+            .unwrap_or(DEFAULT_PAGE_SIZE);
+        let mut params = self.params.clone();
+        params.from = self.watermark;
+        params.to = None;
+        params.pagination.page_before = None;
+        params.pagination.page_size = Some(page_size);
+
+        let mut fresh = Vec::new();
+        loop {
+            let page = self.view.list_event_logs(Some(params.clone())).await?;
+            let count = page.items.len();
+            params.pagination.page_after = page.items.last().map(|log| log.id.clone());
+            fresh.extend(
+                page.items
+                    .into_iter()
+                    .filter(|log| !self.seen.contains(&log.id)),
+            );
+            if count < page_size as usize || params.pagination.page_after.is_none() {
+                break;
+            }
+        }
+
+        fresh.sort_by_cached_key(|log| {
+            (
+                confirm_date(log),
+                log.block_height,
+                log.log_index.parse::<u64>().unwrap_or(u64::MAX),
+            )
+        });
+        fresh.dedup_by(|a, b| a.id == b.id);
+
+        for log in fresh {
+            match confirm_date(&log) {
+                Some(date) if self.watermark.is_none_or(|mark| date > mark) => {
+                    self.watermark = Some(date);
+                    self.seen.clear();
+                }
+                _ => {}
+            }
+            self.seen.insert(log.id.clone());
+            self.pending.push_back(log);
+        }
+        Ok(())
+    }
+}
+
+fn confirm_date(log: &EventLog) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(&log.first_confirm_date)
+        .ok()
+        .map(|date| date.with_timezone(&Utc))
+}
+
+impl CircleView {
+    /// Tail contract event logs as a stream
+    ///
+    /// Repeatedly polls [`list_event_logs`](CircleView::list_event_logs),
+    /// using the newest `firstConfirmDate` seen so far as the `from` filter
+    /// of the next poll. Logs are yielded oldest first and each log ID is
+    /// yielded at most once, even though `from` is inclusive and the boundary
+    /// logs are returned again by the next poll.
+    ///
+    /// Polls fetch every page (following `pageAfter`) before sleeping for
+    /// `poll_interval`. `params.from` sets the starting point; `params.to` and
+    /// `pageBefore` are ignored since the tail never ends. The stream is
+    /// infinite: a failed poll yields an `Err` item and the next poll is tried
+    /// after `poll_interval`, so the caller decides when to stop.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures_util::{pin_mut, StreamExt};
+    /// use inf_circle_sdk::circle_view::circle_view::CircleView;
+    /// use inf_circle_sdk::contract::dto::ListEventLogsParams;
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let view = CircleView::new()?;
+    ///
+    /// let params = ListEventLogsParams {
+    ///     contract_address: Some("0x1234567890abcdef1234567890abcdef12345678".to_string()),
+    ///     from: Some(chrono::Utc::now()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let logs = view.stream_event_logs(params, Duration::from_secs(10));
+    /// pin_mut!(logs);
+    /// while let Some(log) = logs.next().await {
+    ///     let log = log?;
+    ///     println!("{} in tx {}", log.event_signature, log.tx_hash);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream_event_logs(
+        &self,
+        params: ListEventLogsParams,
+        poll_interval: Duration,
+    ) -> impl Stream<Item = CircleResult<EventLog>> + '_ {
+        let tail = EventLogTail {
+            view: self,
+            watermark: params.from,
+            params,
+            poll_interval,
+            seen: HashSet::new(),
+            pending: VecDeque::new(),
+            polled: false,
+        };
+
+        stream::unfold(tail, |mut tail| async move {
+            loop {
+                if let Some(log) = tail.pending.pop_front() {
+                    return Some((Ok(log), tail));
+                }
+                if tail.polled {
+                    sleep(tail.poll_interval).await;
+                }
+                tail.polled = true;
+                if let Err(e) = tail.poll().await {
+                    return Some((Err(e), tail));
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        contract::dto::ListEventLogsParams,
+        testing::{fixtures, MockCircle},
+    };
+    use futures_util::{pin_mut, StreamExt};
+    use mockito::Matcher;
+    use serde_json::json;
+    use std::time::Duration;
+
+    fn log_json(id: &str, confirmed: &str) -> serde_json::Value {
+        let mut log = serde_json::to_value(fixtures::event_log()).unwrap();
+        log["id"] = json!(id);
+        log["firstConfirmDate"] = json!(confirmed);
+        log
+    }
+
+    #[tokio::test]
+    async fn test_stream_event_logs_advances_watermark_and_dedupes() {
+        let mut circle = MockCircle::start().await;
+        let polls = [
+            (
+                "2025-01-01T00:00:00Z",
+                vec![
+                    log_json("b", "2025-01-01T00:00:02Z"),
+                    log_json("a", "2025-01-01T00:00:01Z"),
+                ],
+            ),
+            (
+                "2025-01-01T00:00:02Z",
+                vec![
+                    log_json("c", "2025-01-01T00:00:03Z"),
+                    log_json("b", "2025-01-01T00:00:02Z"),
+                ],
+            ),
+            (
+                "2025-01-01T00:00:03Z",
+                vec![log_json("c", "2025-01-01T00:00:03Z")],
+            ),
+        ];
+        let mut mocks = Vec::new();
+        for (from, logs) in polls {
+            mocks.push(
+                circle
+                    .server()
+                    .mock("GET", "/v1/w3s/contracts/events")
+                    .match_query(Matcher::UrlEncoded("from".into(), from.into()))
+                    .with_header("content-type", "application/json")
+                    .with_body(json!({ "data": { "eventLogs": logs } }).to_string())
+                    .expect_at_least(1)
+                    .create_async()
+                    .await,
+            );
+        }
+
+        let params = ListEventLogsParams {
+            from: Some("2025-01-01T00:00:00Z".parse().unwrap()),
+            ..Default::default()
+        };
+        let view = circle.view();
+        let logs = view.stream_event_logs(params, Duration::from_millis(10));
+        pin_mut!(logs);
+
+        let mut ids = Vec::new();
+        for _ in 0..3 {
+            ids.push(logs.next().await.unwrap().unwrap().id);
+        }
+        assert_eq!(ids, ["a", "b", "c"]);
+
+        // The next poll only returns "c" again, so nothing new is yielded
+        let next = tokio::time::timeout(Duration::from_millis(100), logs.next()).await;
+        assert!(next.is_err());
+        for mock in mocks {
+            mock.assert_async().await;
+        }
+    }
+}
@@ -9,15 +9,25 @@
 //! - **Import Contracts**: Import existing contracts for monitoring and interaction
 //! - **Query Contracts**: Read contract state without gas fees
 //! - **Execute Contracts**: Call contract functions that modify state
-//! - **Event Monitoring**: Create monitors for contract events and retrieve logs
+//! - **Event Monitoring**: Create monitors for contract events and retrieve or tail logs
 //! - **Fee Estimation**: Estimate gas fees before deployment or execution
+//! - **ERC-20 Tokens**: Typed helpers for the standard token functions
+//! - **NFTs**: ERC-721 / ERC-1155 helpers and token metadata fetching
+//! - **CREATE2**: Deterministic address prediction and factory deployments
 //!
 //! # Main Components
 //!
+//! - [`abi`]: Typed event and function signatures from stored contract ABIs
 //! - [`contract_ops`]: Write operations (deploy, import contracts)
+//! - [`create2`]: CREATE2 address prediction and factory deployment builder
 //! - [`contract_view`]: Read operations (query contracts, list contracts, event monitors)
 //! - [`dto`]: Data transfer objects (request/response structures)
+//! - [`erc20`]: High-level ERC-20 token client
+//! - [`event_monitor_set`]: Declarative event monitor configuration
+//! - [`event_stream`]: Polling stream over new event logs
+//! - [`nft`]: High-level ERC-721 and ERC-1155 clients
 //! - [`ops`]: Builder modules for deployment and import operations
+//! - [`templates`]: Parameter schemas for contract template deployments
 //! - [`views`]: Builder modules for query and view operations
 //!
 //! # Example - Deploy Contract
@@ -74,8 +84,18 @@
 //! # }
 //! ```
 
+pub mod abi;
+pub(crate) mod calls;
 pub mod contract_ops;
 pub mod contract_view;
+pub mod create2;
 pub mod dto;
+pub mod erc20;
+pub mod event_monitor_set;
+pub mod event_stream;
+pub mod nft;
 pub mod ops;
+pub mod templates;
 pub mod views;
+
+pub use create2::predict_create2_address;
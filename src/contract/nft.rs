@@ -0,0 +1,553 @@
+//! NFT Helpers (ERC-721 / ERC-1155)
+//!
+//! This module provides [`Erc721Client`] and [`Erc1155Client`] for the standard
+//! NFT read and write functions using developer-controlled wallets, plus
+//! [`fetch_nft_metadata`] to load the JSON metadata referenced by a token URI.
+//!
+//! Reads use `CircleView::query_contract` and writes use
+//! `CircleOps::create_dev_contract_execution_transaction` with call data encoded locally.
+//!
+//! # Example - Transfer an ERC-721 Token
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::{
+//!     abi::U256,
+//!     circle_ops::circler_ops::CircleOps,
+//!     circle_view::circle_view::CircleView,
+//!     contract::nft::Erc721Client,
+//!     types::Blockchain,
+//! };
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let view = CircleView::new()?;
+//! let ops = CircleOps::new(None)?;
+//!
+//! let collection = Erc721Client::new(Blockchain::EthSepolia, "0xCollectionAddress".to_string());
+//! let token_id = U256::from(42u64);
+//!
+//! let owner = collection.owner_of(&view, token_id).await?;
+//! let metadata = collection.metadata(&view, token_id).await?;
+//! println!("Owner: {}, name: {:?}", owner, metadata.name);
+//!
+//! let tx = collection
+//!     .safe_transfer_from(&ops, "wallet-id", &owner.to_string(), "0xRecipient", token_id)
+//!     .await?;
+//! println!("Transfer transaction: {}", tx.id);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Example - Batch Transfer ERC-1155 Tokens
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::{
+//!     abi::U256,
+//!     circle_ops::circler_ops::CircleOps,
+//!     contract::nft::Erc1155Client,
+//!     types::Blockchain,
+//! };
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let ops = CircleOps::new(None)?;
+//! let items = Erc1155Client::new(Blockchain::EthSepolia, "0xCollectionAddress".to_string());
+//!
+//! let tx = items
+//!     .safe_batch_transfer_from(
+//!         &ops,
+//!         "wallet-id",
+//!         "0xWalletAddress",
+//!         "0xRecipient",
+//!         vec![U256::from(1u64), U256::from(2u64)],
+//!         vec![U256::from(10u64), U256::from(5u64)],
+//!     )
+//!     .await?;
+//! println!("Batch transfer transaction: {}", tx.id);
+//! # Ok(())
+//! # }
+//! ```
+
+use base64::{engine::general_purpose, Engine};
+
+use crate::{
+    abi::{AbiDecode, AbiValue, Address, U256},
+    circle_ops::circler_ops::CircleOps,
+    circle_view::circle_view::CircleView,
+    contract::{
+        calls::{execute_encoded, query_decoded},
+        dto::NftMetadata,
+    },
+    dev_wallet::dto::{CreateContractExecutionTransactionResponse, FeeConfig, FeeLevel},
+    helper::{CircleError, CircleResult},
+    types::Blockchain,
+};
+
+/// Public gateway used to resolve `ipfs://` URIs
+pub const IPFS_GATEWAY: &str = "https://ipfs.io/ipfs/";
+
+/// Client for a single ERC-721 collection
+#[derive(Clone, Debug)]
+pub struct Erc721Client {
+    /// Blockchain the collection is deployed on
+    pub blockchain: Blockchain,
+
+    /// Address of the collection contract
+    pub contract_address: String,
+
+    /// Fee settings used for write operations
+    pub fee: FeeConfig,
+}
+
+impl Erc721Client {
+    /// Create a client for the collection at `contract_address`
+    pub fn new(blockchain: Blockchain, contract_address: String) -> Self {
+        Self {
+            blockchain,
+            contract_address,
+            fee: FeeConfig::Level(FeeLevel::Medium),
+        }
+    }
+
+    /// Set the fee level used for write operations
+    pub fn fee_level(mut self, fee_level: FeeLevel) -> Self {
+        self.fee = FeeConfig::Level(fee_level);
+        self
+    }
+
+    /// Set the fee settings used for write operations
+    pub fn fee(mut self, fee: FeeConfig) -> Self {
+        self.fee = fee;
+        self
+    }
+
+    /// Get the owner of `token_id`
+    pub async fn owner_of(&self, view: &CircleView, token_id: U256) -> CircleResult<Address> {
+        self.call(view, "ownerOf(uint256)", &[AbiValue::from(token_id)])
+            .await
+    }
+
+    /// Get the number of tokens owned by `owner`
+    pub async fn balance_of(&self, view: &CircleView, owner: &str) -> CircleResult<U256> {
+        let owner: Address = owner.parse()?;
+        self.call(view, "balanceOf(address)", &[AbiValue::from(owner)])
+            .await
+    }
+
+    /// Get the metadata URI of `token_id`
+    pub async fn token_uri(&self, view: &CircleView, token_id: U256) -> CircleResult<String> {
+        self.call(view, "tokenURI(uint256)", &[AbiValue::from(token_id)])
+            .await
+    }
+
+    /// Check whether `operator` may manage all tokens of `owner`
+    pub async fn is_approved_for_all(
+        &self,
+        view: &CircleView,
+        owner: &str,
+        operator: &str,
+    ) -> CircleResult<bool> {
+        let owner: Address = owner.parse()?;
+        let operator: Address = operator.parse()?;
+        self.call(
+            view,
+            "isApprovedForAll(address,address)",
+            &[AbiValue::from(owner), AbiValue::from(operator)],
+        )
+        .await
+    }
+
+    /// Fetch the JSON metadata of `token_id` from its token URI
+    pub async fn metadata(&self, view: &CircleView, token_id: U256) -> CircleResult<NftMetadata> {
+        let uri = self.token_uri(view, token_id).await?;
+        fetch_nft_metadata(&uri, Some(token_id)).await
+    }
+
+    /// Safely transfer `token_id` from `from` to `to`
+    pub async fn safe_transfer_from(
+        &self,
+        ops: &CircleOps,
+        wallet_id: &str,
+        from: &str,
+        to: &str,
+        token_id: U256,
+    ) -> CircleResult<CreateContractExecutionTransactionResponse> {
+        let from: Address = from.parse()?;
+        let to: Address = to.parse()?;
+        self.execute(
+            ops,
+            wallet_id,
+            "safeTransferFrom(address,address,uint256)",
+            &[
+                AbiValue::from(from),
+                AbiValue::from(to),
+                AbiValue::from(token_id),
+            ],
+        )
+        .await
+    }
+
+    /// Grant or revoke `operator` permission to manage all of the wallet's tokens
+    pub async fn set_approval_for_all(
+        &self,
+        ops: &CircleOps,
+        wallet_id: &str,
+        operator: &str,
+        approved: bool,
+    ) -> CircleResult<CreateContractExecutionTransactionResponse> {
+        let operator: Address = operator.parse()?;
+        self.execute(
+            ops,
+            wallet_id,
+            "setApprovalForAll(address,bool)",
+            &[AbiValue::from(operator), AbiValue::from(approved)],
+        )
+        .await
+    }
+
+    async fn call<T: AbiDecode>(
+        &self,
+        view: &CircleView,
+        signature: &str,
+        values: &[AbiValue],
+    ) -> CircleResult<T> {
+        query_decoded(
+            view,
+            &self.blockchain,
+            &self.contract_address,
+            signature,
+            values,
+        )
+        .await
+    }
+
+    async fn execute(
+        &self,
+        ops: &CircleOps,
+        wallet_id: &str,
+        signature: &str,
+        values: &[AbiValue],
+    ) -> CircleResult<CreateContractExecutionTransactionResponse> {
+        execute_encoded(
+            ops,
+            wallet_id,
+            &self.contract_address,
+            &self.fee,
+            signature,
+            values,
+        )
+        .await
+    }
+}
+
+/// Client for a single ERC-1155 collection
+#[derive(Clone, Debug)]
+pub struct Erc1155Client {
+    /// Blockchain the collection is deployed on
+    pub blockchain: Blockchain,
+
+    /// Address of the collection contract
+    pub contract_address: String,
+
+    /// Fee settings used for write operations
+    pub fee: FeeConfig,
+}
+
+impl Erc1155Client {
+    /// Create a client for the collection at `contract_address`
+    pub fn new(blockchain: Blockchain, contract_address: String) -> Self {
+        Self {
+            blockchain,
+            contract_address,
+            fee: FeeConfig::Level(FeeLevel::Medium),
+        }
+    }
+
+    /// Set the fee level used for write operations
+    pub fn fee_level(mut self, fee_level: FeeLevel) -> Self {
+        self.fee = FeeConfig::Level(fee_level);
+        self
+    }
+
+    /// Set the fee settings used for write operations
+    pub fn fee(mut self, fee: FeeConfig) -> Self {
+        self.fee = fee;
+        self
+    }
+
+    /// Get the balance of token `id` held by `owner`
+    pub async fn balance_of(&self, view: &CircleView, owner: &str, id: U256) -> CircleResult<U256> {
+        let owner: Address = owner.parse()?;
+        self.call(
+            view,
+            "balanceOf(address,uint256)",
+            &[AbiValue::from(owner), AbiValue::from(id)],
+        )
+        .await
+    }
+
+    /// Get the metadata URI template of token `id`
+    ///
+    /// The URI may contain the `{id}` placeholder defined by ERC-1155;
+    /// [`fetch_nft_metadata`] substitutes it automatically.
+    pub async fn uri(&self, view: &CircleView, id: U256) -> CircleResult<String> {
+        self.call(view, "uri(uint256)", &[AbiValue::from(id)]).await
+    }
+
+    /// Check whether `operator` may manage all tokens of `owner`
+    pub async fn is_approved_for_all(
+        &self,
+        view: &CircleView,
+        owner: &str,
+        operator: &str,
+    ) -> CircleResult<bool> {
+        let owner: Address = owner.parse()?;
+        let operator: Address = operator.parse()?;
+        self.call(
+            view,
+            "isApprovedForAll(address,address)",
+            &[AbiValue::from(owner), AbiValue::from(operator)],
+        )
+        .await
+    }
+
+    /// Fetch the JSON metadata of token `id` from its URI
+    pub async fn metadata(&self, view: &CircleView, id: U256) -> CircleResult<NftMetadata> {
+        let uri = self.uri(view, id).await?;
+        fetch_nft_metadata(&uri, Some(id)).await
+    }
+
+    /// Safely transfer `amount` of token `id` from `from` to `to`
+    pub async fn safe_transfer_from(
+        &self,
+        ops: &CircleOps,
+        wallet_id: &str,
+        from: &str,
+        to: &str,
+        id: U256,
+        amount: U256,
+    ) -> CircleResult<CreateContractExecutionTransactionResponse> {
+        let from: Address = from.parse()?;
+        let to: Address = to.parse()?;
+        self.execute(
+            ops,
+            wallet_id,
+            "safeTransferFrom(address,address,uint256,uint256,bytes)",
+            &[
+                AbiValue::from(from),
+                AbiValue::from(to),
+                AbiValue::from(id),
+                AbiValue::from(amount),
+                AbiValue::bytes(Vec::new()),
+            ],
+        )
+        .await
+    }
+
+    /// Safely transfer several token ids from `from` to `to` in one transaction
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Config` if `ids` and `amounts` differ in length or are empty.
+    pub async fn safe_batch_transfer_from(
+        &self,
+        ops: &CircleOps,
+        wallet_id: &str,
+        from: &str,
+        to: &str,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+    ) -> CircleResult<CreateContractExecutionTransactionResponse> {
+        if ids.is_empty() || ids.len() != amounts.len() {
+            return Err(CircleError::Config(format!(
+                "Batch transfer needs matching non-empty ids and amounts (got {} ids, {} amounts)",
+                ids.len(),
+                amounts.len()
+            )));
+        }
+
+        let from: Address = from.parse()?;
+        let to: Address = to.parse()?;
+        self.execute(
+            ops,
+            wallet_id,
+            "safeBatchTransferFrom(address,address,uint256[],uint256[],bytes)",
+            &[
+                AbiValue::from(from),
+                AbiValue::from(to),
+                AbiValue::array(ids.into_iter().map(AbiValue::from).collect()),
+                AbiValue::array(amounts.into_iter().map(AbiValue::from).collect()),
+                AbiValue::bytes(Vec::new()),
+            ],
+        )
+        .await
+    }
+
+    /// Grant or revoke `operator` permission to manage all of the wallet's tokens
+    pub async fn set_approval_for_all(
+        &self,
+        ops: &CircleOps,
+        wallet_id: &str,
+        operator: &str,
+        approved: bool,
+    ) -> CircleResult<CreateContractExecutionTransactionResponse> {
+        let operator: Address = operator.parse()?;
+        self.execute(
+            ops,
+            wallet_id,
+            "setApprovalForAll(address,bool)",
+            &[AbiValue::from(operator), AbiValue::from(approved)],
+        )
+        .await
+    }
+
+    async fn call<T: AbiDecode>(
+        &self,
+        view: &CircleView,
+        signature: &str,
+        values: &[AbiValue],
+    ) -> CircleResult<T> {
+        query_decoded(
+            view,
+            &self.blockchain,
+            &self.contract_address,
+            signature,
+            values,
+        )
+        .await
+    }
+
+    async fn execute(
+        &self,
+        ops: &CircleOps,
+        wallet_id: &str,
+        signature: &str,
+        values: &[AbiValue],
+    ) -> CircleResult<CreateContractExecutionTransactionResponse> {
+        execute_encoded(
+            ops,
+            wallet_id,
+            &self.contract_address,
+            &self.fee,
+            signature,
+            values,
+        )
+        .await
+    }
+}
+
+/// Resolve a token URI to a fetchable HTTP(S) URL
+///
+/// - `ipfs://` URIs are rewritten to [`IPFS_GATEWAY`]
+/// - The ERC-1155 `{id}` placeholder is replaced with the 64-character lowercase hex token id
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::abi::U256;
+/// use inf_circle_sdk::contract::nft::resolve_token_uri;
+///
+/// assert_eq!(
+///     resolve_token_uri("ipfs://QmHash/1.json", None),
+///     "https://ipfs.io/ipfs/QmHash/1.json"
+/// );
+/// assert_eq!(
+///     resolve_token_uri("https://example.com/{id}.json", Some(U256::from(255u64))),
+///     format!("https://example.com/{:0>64}.json", "ff")
+/// );
+/// ```
+pub fn resolve_token_uri(uri: &str, token_id: Option<U256>) -> String {
+    let mut resolved = match uri.strip_prefix("ipfs://") {
+        Some(path) => format!("{}{}", IPFS_GATEWAY, path.trim_start_matches("ipfs/")),
+        None => uri.to_string(),
+    };
+
+    if let Some(token_id) = token_id {
+        if resolved.contains("{id}") {
+            resolved = resolved.replace("{id}", &hex::encode(token_id.to_be_bytes()));
+        }
+    }
+
+    resolved
+}
+
+/// Fetch and parse NFT metadata from a token URI
+///
+/// Supports `https://`, `ipfs://` and inline `data:application/json` URIs
+/// (plain or base64 encoded).
+///
+/// # Arguments
+/// * `uri` - The token URI returned by `tokenURI` / `uri`
+/// * `token_id` - Token id used to fill the ERC-1155 `{id}` placeholder
+///
+/// # Returns
+/// * `CircleResult<NftMetadata>` - The parsed metadata
+pub async fn fetch_nft_metadata(uri: &str, token_id: Option<U256>) -> CircleResult<NftMetadata> {
+    if let Some(data) = uri.strip_prefix("data:") {
+        return parse_data_uri(data);
+    }
+
+    let url = resolve_token_uri(uri, token_id);
+    let response = reqwest::get(&url).await?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(CircleError::Api {
+            status: status.as_u16(),
+            request_id: None,
+            message: format!("Failed to fetch NFT metadata from {}", url),
+        });
+    }
+
+    let text = response.text().await?;
+    Ok(serde_json::from_str(&text)?)
+}
+
+fn parse_data_uri(data: &str) -> CircleResult<NftMetadata> {
+    let (header, payload) = data
+        .split_once(',')
+        .ok_or_else(|| CircleError::Config("Malformed data URI".to_string()))?;
+
+    let json = if header.ends_with(";base64") {
+        let bytes = general_purpose::STANDARD
+            .decode(payload)
+            .map_err(|e| CircleError::Config(format!("Invalid base64 data URI: {}", e)))?;
+        String::from_utf8(bytes)
+            .map_err(|e| CircleError::Config(format!("Invalid UTF-8 in data URI: {}", e)))?
+    } else {
+        urlencoding::decode(payload)
+            .map_err(|e| CircleError::Config(format!("Invalid data URI encoding: {}", e)))?
+            .into_owned()
+    };
+
+    Ok(serde_json::from_str(&json)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_fetch_inline_metadata() {
+        let json = r#"{"name":"Badge #1","image":"ipfs://QmImage","attributes":[{"trait_type":"Level","value":3}]}"#;
+        let uri = format!(
+            "data:application/json;base64,{}",
+            general_purpose::STANDARD.encode(json)
+        );
+
+        let metadata = fetch_nft_metadata(&uri, None).await.unwrap();
+        assert_eq!(metadata.name.as_deref(), Some("Badge #1"));
+        assert_eq!(metadata.image.as_deref(), Some("ipfs://QmImage"));
+        assert_eq!(metadata.attributes.unwrap().len(), 1);
+
+        let plain = fetch_nft_metadata("data:application/json,{\"name\":\"Plain\"}", None)
+            .await
+            .unwrap();
+        assert_eq!(plain.name.as_deref(), Some("Plain"));
+    }
+
+    #[test]
+    fn test_resolve_ipfs_path_prefix() {
+        assert_eq!(
+            resolve_token_uri("ipfs://ipfs/QmHash", None),
+            "https://ipfs.io/ipfs/QmHash"
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use crate::contract::dto::Contract;
+use crate::helper::{CircleError, CircleResult};
+
+/// Builder for archiving or unarchiving a contract
+///
+/// Archiving hides a contract from default listings without affecting it
+/// on-chain. Circle does not support deleting contracts, so archiving is the way
+/// to retire one.
+///
+/// To guard against acting on the wrong contract ID, set
+/// [`expected_address`](Self::expected_address): the contract is fetched first
+/// and the change is refused if its on-chain address differs.
+#[derive(Clone, Debug)]
+pub struct ArchiveContractRequestBuilder {
+    pub contract_id: String,
+    pub expected_address: Option<String>,
+}
+
+impl ArchiveContractRequestBuilder {
+    /// Create a new builder
+    ///
+    /// # Arguments
+    /// * `contract_id` - ID of the contract to archive or unarchive
+    pub fn new(contract_id: String) -> Self {
+        Self {
+            contract_id,
+            expected_address: None,
+        }
+    }
+
+    /// Require the contract to have this on-chain address (compared case-insensitively)
+    pub fn expected_address(mut self, address: String) -> Self {
+        self.expected_address = Some(address);
+        self
+    }
+
+    /// Check a fetched contract against the expected address
+    pub(crate) fn confirm(&self, contract: &Contract) -> CircleResult<()> {
+        let Some(expected) = &self.expected_address else {
+            return Ok(());
+        };
+        let actual = contract
+            .contract_address
+            .as_deref()
+            .or(contract.address.as_deref());
+        if actual.is_some_and(|a| a.eq_ignore_ascii_case(expected)) {
+            Ok(())
+        } else {
+            Err(CircleError::Config(format!(
+                "Contract {} has address {}, expected {}",
+                self.contract_id,
+                actual.unwrap_or("<none>"),
+                expected
+            )))
+        }
+    }
+
+    pub fn build(self) -> Self {
+        self
+    }
+}
@@ -1,11 +1,21 @@
+use std::path::Path;
+
 use serde_json::Value;
 
+use crate::abi::AbiValue;
+use crate::contract::{abi::validate_constructor_values, dto};
+use crate::dev_wallet::dto::{AbiParameter, FeeConfig, FeeLevel};
+use crate::helper::{
+    preview_request, CircleError, CircleResult, ENTITY_SECRET_PLACEHOLDER,
+    GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER,
+};
 use crate::types::Blockchain;
 
 /// Builder for deploying a contract from bytecode
 ///
 /// Deploy a smart contract on a specified blockchain using the contract's ABI and bytecode.
 /// The deployment will originate from one of your Circle Wallets.
+#[derive(Clone, Debug)]
 pub struct DeployContractRequestBuilder {
     // Required fields
     bytecode: String,
@@ -17,11 +27,7 @@ pub struct DeployContractRequestBuilder {
     // Optional fields
     description: Option<String>,
     constructor_parameters: Option<Vec<Value>>,
-    fee_level: Option<String>,
-    gas_limit: Option<String>,
-    gas_price: Option<String>,
-    max_fee: Option<String>,
-    priority_fee: Option<String>,
+    fee: Option<FeeConfig>,
     ref_id: Option<String>,
     idempotency_key: Option<String>,
 }
@@ -50,16 +56,100 @@ impl DeployContractRequestBuilder {
             blockchain,
             description: None,
             constructor_parameters: None,
-            fee_level: None,
-            gas_limit: None,
-            gas_price: None,
-            max_fee: None,
-            priority_fee: None,
+            fee: None,
             ref_id: None,
             idempotency_key: None,
         }
     }
 
+    /// Create a builder from a Foundry build artifact (`out/<File>.sol/<Contract>.json`)
+    ///
+    /// Reads the ABI from `abi` and the creation bytecode from `bytecode.object`.
+    /// Returns `CircleError::Config` if the file cannot be read, is missing either
+    /// field, or contains unlinked library placeholders.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::contract::ops::deploy_contract::DeployContractRequestBuilder;
+    /// use inf_circle_sdk::dev_wallet::dto::FeeLevel;
+    /// use inf_circle_sdk::types::Blockchain;
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let builder = DeployContractRequestBuilder::from_foundry_artifact(
+    ///     "out/MyToken.sol/MyToken.json",
+    ///     "wallet-id".to_string(),
+    ///     "MyToken".to_string(),
+    ///     Blockchain::EthSepolia,
+    /// )?
+    /// .fee_level(FeeLevel::Medium);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_foundry_artifact(
+        path: impl AsRef<Path>,
+        wallet_id: String,
+        name: String,
+        blockchain: Blockchain,
+    ) -> CircleResult<Self> {
+        let artifact = read_artifact(path.as_ref())?;
+        let bytecode = artifact.pointer("/bytecode/object");
+        Self::from_artifact(&artifact, bytecode, wallet_id, name, blockchain)
+    }
+
+    /// Create a builder from a Hardhat build artifact (`artifacts/contracts/<File>.sol/<Contract>.json`)
+    ///
+    /// Reads the ABI from `abi` and the creation bytecode from `bytecode`.
+    /// Returns `CircleError::Config` if the file cannot be read, is missing either
+    /// field, or contains unlinked library placeholders.
+    pub fn from_hardhat_artifact(
+        path: impl AsRef<Path>,
+        wallet_id: String,
+        name: String,
+        blockchain: Blockchain,
+    ) -> CircleResult<Self> {
+        let artifact = read_artifact(path.as_ref())?;
+        let bytecode = artifact.get("bytecode");
+        Self::from_artifact(&artifact, bytecode, wallet_id, name, blockchain)
+    }
+
+    fn from_artifact(
+        artifact: &Value,
+        bytecode: Option<&Value>,
+        wallet_id: String,
+        name: String,
+        blockchain: Blockchain,
+    ) -> CircleResult<Self> {
+        let abi = artifact
+            .get("abi")
+            .filter(|abi| abi.is_array())
+            .ok_or_else(|| CircleError::Config("Artifact has no `abi` array".to_string()))?;
+
+        let bytecode = bytecode
+            .and_then(Value::as_str)
+            .map(|code| code.strip_prefix("0x").unwrap_or(code))
+            .filter(|code| !code.is_empty())
+            .ok_or_else(|| {
+                CircleError::Config(
+                    "Artifact has no creation bytecode (is the contract abstract or an interface?)"
+                        .to_string(),
+                )
+            })?;
+        if bytecode.contains("__") {
+            return Err(CircleError::Config(
+                "Artifact bytecode has unlinked library placeholders".to_string(),
+            ));
+        }
+
+        Ok(Self::new(
+            format!("0x{}", bytecode),
+            abi.to_string(),
+            wallet_id,
+            name,
+            blockchain,
+        ))
+    }
+
     /// Set the description for the contract
     pub fn description(mut self, description: String) -> Self {
         self.description = Some(description);
@@ -75,48 +165,64 @@ impl DeployContractRequestBuilder {
         self
     }
 
-    /// Set the fee level (LOW, MEDIUM, or HIGH)
+    /// Set the constructor parameters from typed ABI values
     ///
-    /// A dynamic blockchain fee level setting that will be used to pay gas for the transaction.
-    /// Cannot be used with gasPrice, priorityFee, or maxFee.
-    pub fn fee_level(mut self, level: String) -> Self {
-        self.fee_level = Some(level);
-        self
-    }
-
-    /// Set the gas limit
+    /// The values are checked against the constructor declared in the builder's
+    /// ABI JSON (parameter count, types and integer widths) before anything is
+    /// sent. Integers are sent as decimal strings so `uint256` values keep full
+    /// precision, and addresses and byte strings as `0x`-prefixed hex.
     ///
-    /// The maximum units of gas to use for the transaction.
-    /// Required if feeLevel is not provided.
-    pub fn gas_limit(mut self, limit: String) -> Self {
-        self.gas_limit = Some(limit);
-        self
-    }
-
-    /// Set the gas price (for non-EIP-1559 blockchains)
+    /// # Example
     ///
-    /// The maximum price of gas, in gwei, to use per each unit of gas.
-    /// Requires gasLimit. Cannot be used with feeLevel, priorityFee, or maxFee.
-    pub fn gas_price(mut self, price: String) -> Self {
-        self.gas_price = Some(price);
-        self
+    /// ```rust
+    /// use inf_circle_sdk::abi::{AbiValue, Address, U256};
+    /// use inf_circle_sdk::contract::ops::deploy_contract::DeployContractRequestBuilder;
+    /// use inf_circle_sdk::types::Blockchain;
+    ///
+    /// let abi_json = r#"[{"type":"constructor","inputs":[
+    ///     {"name":"owner","type":"address"},
+    ///     {"name":"supply","type":"uint256"}
+    /// ]}]"#;
+    /// let owner: Address = "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb0".parse().unwrap();
+    ///
+    /// let builder = DeployContractRequestBuilder::new(
+    ///     "0x6080...".to_string(),
+    ///     abi_json.to_string(),
+    ///     "wallet-id".to_string(),
+    ///     "MyToken".to_string(),
+    ///     Blockchain::EthSepolia,
+    /// )
+    /// .constructor_values(vec![
+    ///     AbiValue::from(owner),
+    ///     AbiValue::from(U256::from(1_000_000u64)),
+    /// ])
+    /// .unwrap();
+    /// ```
+    pub fn constructor_values(mut self, values: Vec<AbiValue>) -> CircleResult<Self> {
+        let abi: Value = serde_json::from_str(&self.abi_json)
+            .map_err(|e| CircleError::Abi(format!("Invalid contract ABI JSON: {}", e)))?;
+        validate_constructor_values(&abi, &values)?;
+
+        let parameters = values
+            .into_iter()
+            .map(|value| serde_json::to_value(AbiParameter::from(value)))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.constructor_parameters = Some(parameters);
+        Ok(self)
     }
 
-    /// Set the max fee (for EIP-1559 blockchains)
-    ///
-    /// The maximum price per unit of gas, in gwei.
-    /// Requires priorityFee and gasLimit. Cannot be used with feeLevel or gasPrice.
-    pub fn max_fee(mut self, fee: String) -> Self {
-        self.max_fee = Some(fee);
+    /// Set the fee settings (fee level, legacy gas price or EIP-1559 fees)
+    pub fn fee(mut self, fee: FeeConfig) -> Self {
+        self.fee = Some(fee);
         self
     }
 
-    /// Set the priority fee (for EIP-1559 blockchains)
+    /// Set the fee level (LOW, MEDIUM, or HIGH)
     ///
-    /// The "tip", in gwei, to add to the base fee as an incentive for validators.
-    /// Requires maxFee and gasLimit. Cannot be used with feeLevel or gasPrice.
-    pub fn priority_fee(mut self, fee: String) -> Self {
-        self.priority_fee = Some(fee);
+    /// A dynamic blockchain fee level setting that will be used to pay gas for the transaction.
+    /// Shorthand for `fee(FeeConfig::Level(level))`.
+    pub fn fee_level(mut self, level: FeeLevel) -> Self {
+        self.fee = Some(FeeConfig::Level(level));
         self
     }
 
@@ -137,6 +243,87 @@ impl DeployContractRequestBuilder {
         self
     }
 
+    /// Validate the request before anything is sent
+    ///
+    /// The bytecode, ABI JSON, wallet ID and name must be non-empty. Fee
+    /// settings are checked with [`FeeConfig::validate`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Config` if a required field is empty or the fee
+    /// settings are invalid.
+    pub fn validate(&self) -> CircleResult<()> {
+        for (field, value) in [
+            ("bytecode", &self.bytecode),
+            ("abi_json", &self.abi_json),
+            ("wallet_id", &self.wallet_id),
+            ("name", &self.name),
+        ] {
+            if value.trim().is_empty() {
+                return Err(CircleError::Config(format!(
+                    "Contract deployment requires a non-empty {}",
+                    field
+                )));
+            }
+        }
+
+        match &self.fee {
+            Some(fee) => fee.validate(),
+            None => Ok(()),
+        }
+    }
+
+    /// Convert the builder into the API request body
+    ///
+    /// `generate_idempotency_key` is only called when no custom key was set.
+    pub(crate) fn into_request(
+        self,
+        entity_secret_ciphertext: String,
+        generate_idempotency_key: impl FnOnce() -> String,
+    ) -> dto::DeployContractRequest {
+        let fee = FeeConfig::into_fields(self.fee);
+        dto::DeployContractRequest {
+            entity_secret_ciphertext: entity_secret_ciphertext.into(),
+            bytecode: self.bytecode,
+            abi_json: self.abi_json,
+            wallet_id: self.wallet_id,
+            name: self.name,
+            blockchain: self.blockchain,
+            idempotency_key: self
+                .idempotency_key
+                .unwrap_or_else(generate_idempotency_key),
+            description: self.description,
+            constructor_parameters: self.constructor_parameters,
+            fee_level: fee.fee_level.map(|level| level.as_str().to_string()),
+            gas_limit: fee.gas_limit,
+            gas_price: fee.gas_price,
+            max_fee: fee.max_fee,
+            priority_fee: fee.priority_fee,
+            ref_id: self.ref_id,
+        }
+    }
+
+    /// Preview the request body as pretty JSON
+    ///
+    /// The entity secret ciphertext is replaced by [`ENTITY_SECRET_PLACEHOLDER`] and,
+    /// when no idempotency key was set, the key is shown as
+    /// [`GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER`].
+    pub fn preview(&self) -> CircleResult<String> {
+        preview_request(
+            &self
+                .clone()
+                .into_request(ENTITY_SECRET_PLACEHOLDER.to_string(), || {
+                    GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER.to_string()
+                }),
+        )
+    }
+
+    /// Build and return all fields, checking them with [`validate`](Self::validate)
+    pub fn try_build(self) -> CircleResult<DeployContractRequest> {
+        self.validate()?;
+        Ok(self.build())
+    }
+
     /// Build and return all fields for CircleOps to use
     pub fn build(self) -> DeployContractRequest {
         DeployContractRequest {
@@ -147,17 +334,22 @@ impl DeployContractRequestBuilder {
             blockchain: self.blockchain,
             description: self.description,
             constructor_parameters: self.constructor_parameters,
-            fee_level: self.fee_level,
-            gas_limit: self.gas_limit,
-            gas_price: self.gas_price,
-            max_fee: self.max_fee,
-            priority_fee: self.priority_fee,
+            fee: self.fee,
             ref_id: self.ref_id,
             idempotency_key: self.idempotency_key,
         }
     }
 }
 
+fn read_artifact(path: &Path) -> CircleResult<Value> {
+    let contents = std::fs::read_to_string(path).map_err(|e| {
+        CircleError::Config(format!("Failed to read artifact {}: {}", path.display(), e))
+    })?;
+    serde_json::from_str(&contents).map_err(|e| {
+        CircleError::Config(format!("Invalid artifact JSON {}: {}", path.display(), e))
+    })
+}
+
 /// Internal request structure for deploy contract
 pub struct DeployContractRequest {
     pub bytecode: String,
@@ -167,11 +359,185 @@ pub struct DeployContractRequest {
     pub blockchain: Blockchain,
     pub description: Option<String>,
     pub constructor_parameters: Option<Vec<Value>>,
-    pub fee_level: Option<String>,
-    pub gas_limit: Option<String>,
-    pub gas_price: Option<String>,
-    pub max_fee: Option<String>,
-    pub priority_fee: Option<String>,
+    pub fee: Option<FeeConfig>,
     pub ref_id: Option<String>,
     pub idempotency_key: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::{Address, U256};
+    use serde_json::json;
+
+    fn builder(abi: Value) -> DeployContractRequestBuilder {
+        DeployContractRequestBuilder::new(
+            "0x6080".to_string(),
+            abi.to_string(),
+            "wallet-id".to_string(),
+            "Vault".to_string(),
+            Blockchain::EthSepolia,
+        )
+    }
+
+    #[test]
+    fn test_constructor_values_are_validated_and_serialized() {
+        let abi = json!([{
+            "type": "constructor",
+            "inputs": [
+                {"name": "owner", "type": "address"},
+                {"name": "cap", "type": "uint256"},
+                {"name": "signers", "type": "address[]"},
+                {"name": "threshold", "type": "uint8"}
+            ]
+        }]);
+        let owner: Address = "0x742d35cc6634c0532925a3b844bc9e7595f0beb0"
+            .parse()
+            .unwrap();
+
+        let request = builder(abi.clone())
+            .constructor_values(vec![
+                AbiValue::from(owner),
+                AbiValue::Uint(U256::MAX),
+                AbiValue::array(vec![AbiValue::from(owner)]),
+                AbiValue::from(2u64),
+            ])
+            .unwrap()
+            .build();
+        assert_eq!(
+            request.constructor_parameters.unwrap(),
+            vec![
+                json!("0x742d35cc6634c0532925a3b844bc9e7595f0beb0"),
+                json!(U256::MAX.to_string()),
+                json!(["0x742d35cc6634c0532925a3b844bc9e7595f0beb0"]),
+                json!("2"),
+            ]
+        );
+
+        let wrong_count = builder(abi.clone()).constructor_values(vec![AbiValue::from(owner)]);
+        assert!(matches!(wrong_count, Err(CircleError::Abi(_))));
+
+        let too_wide = builder(abi).constructor_values(vec![
+            AbiValue::from(owner),
+            AbiValue::from(1u64),
+            AbiValue::array(vec![]),
+            AbiValue::from(256u64),
+        ]);
+        let Err(CircleError::Abi(message)) = too_wide else {
+            panic!("expected an ABI error");
+        };
+        assert!(message.contains("threshold"));
+    }
+
+    #[test]
+    fn test_try_build_rejects_missing_fields() {
+        assert!(builder(json!([])).try_build().is_ok());
+
+        let unnamed = DeployContractRequestBuilder::new(
+            "0x6080".to_string(),
+            "[]".to_string(),
+            "wallet-id".to_string(),
+            " ".to_string(),
+            Blockchain::EthSepolia,
+        );
+        let Err(CircleError::Config(message)) = unnamed.try_build() else {
+            panic!("expected a config error");
+        };
+        assert!(message.contains("name"));
+    }
+
+    fn write_artifact(artifact: &Value) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "inf-circle-artifact-{}.json",
+            crate::helper::generate_uuid()
+        ));
+        std::fs::write(&path, artifact.to_string()).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_from_foundry_and_hardhat_artifacts() {
+        let abi = json!([{"type": "constructor", "inputs": [{"name": "cap", "type": "uint256"}]}]);
+
+        let foundry = write_artifact(&json!({
+            "abi": abi,
+            "bytecode": {"object": "0x6080604052", "linkReferences": {}},
+            "deployedBytecode": {"object": "0x6080"}
+        }));
+        let hardhat = write_artifact(&json!({
+            "contractName": "Vault",
+            "abi": abi,
+            "bytecode": "0x6080604052",
+            "deployedBytecode": "0x6080"
+        }));
+        let from_foundry = DeployContractRequestBuilder::from_foundry_artifact(
+            &foundry,
+            "wallet-id".to_string(),
+            "Vault".to_string(),
+            Blockchain::EthSepolia,
+        );
+        let from_hardhat = DeployContractRequestBuilder::from_hardhat_artifact(
+            &hardhat,
+            "wallet-id".to_string(),
+            "Vault".to_string(),
+            Blockchain::EthSepolia,
+        );
+        std::fs::remove_file(&foundry).ok();
+        std::fs::remove_file(&hardhat).ok();
+
+        for builder in [from_foundry.unwrap(), from_hardhat.unwrap()] {
+            let request = builder
+                .constructor_values(vec![AbiValue::from(5u64)])
+                .unwrap()
+                .build();
+            assert_eq!(request.bytecode, "0x6080604052");
+            assert_eq!(
+                serde_json::from_str::<Value>(&request.abi_json).unwrap(),
+                abi
+            );
+        }
+    }
+
+    #[test]
+    fn test_artifact_errors() {
+        let open = |artifact: Value| {
+            let path = write_artifact(&artifact);
+            let result = DeployContractRequestBuilder::from_hardhat_artifact(
+                &path,
+                "wallet-id".to_string(),
+                "Vault".to_string(),
+                Blockchain::EthSepolia,
+            );
+            std::fs::remove_file(&path).ok();
+            result
+        };
+
+        let interface = open(json!({"abi": [], "bytecode": "0x"}));
+        assert!(matches!(interface, Err(CircleError::Config(_))));
+        let unlinked = open(json!({"abi": [], "bytecode": "0x6080__$1234$__"}));
+        assert!(matches!(unlinked, Err(CircleError::Config(_))));
+        let missing_abi = open(json!({"bytecode": "0x6080"}));
+        assert!(matches!(missing_abi, Err(CircleError::Config(_))));
+
+        let missing_file = DeployContractRequestBuilder::from_foundry_artifact(
+            "/nonexistent/Vault.json",
+            "wallet-id".to_string(),
+            "Vault".to_string(),
+            Blockchain::EthSepolia,
+        );
+        assert!(matches!(missing_file, Err(CircleError::Config(_))));
+    }
+
+    #[test]
+    fn test_constructor_values_without_constructor() {
+        let abi = json!([{"type": "function", "name": "ping", "inputs": []}]);
+        let request = builder(abi.clone())
+            .constructor_values(vec![])
+            .unwrap()
+            .build();
+        assert_eq!(request.constructor_parameters, Some(vec![]));
+        assert!(builder(abi)
+            .constructor_values(vec![AbiValue::from(true)])
+            .is_err());
+    }
+}
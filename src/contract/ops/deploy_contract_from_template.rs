@@ -1,7 +1,12 @@
-use crate::CircleResult;
+use crate::contract::dto::DeployContractFromTemplateRequest;
+use crate::contract::templates::TemplateSchema;
+use crate::dev_wallet::dto::{FeeConfig, FeeLevel};
+use crate::helper::{
+    preview_request, CircleResult, ENTITY_SECRET_PLACEHOLDER, GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER,
+};
 
 /// Builder for DeployContractFromTemplateRequest
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct DeployContractFromTemplateRequestBuilder {
     pub(crate) template_id: String,
     pub(crate) name: String,
@@ -9,13 +14,10 @@ pub struct DeployContractFromTemplateRequestBuilder {
     pub(crate) blockchain: String,
     pub(crate) description: Option<String>,
     pub(crate) template_parameters: Option<serde_json::Value>,
-    pub(crate) fee_level: Option<String>,
-    pub(crate) gas_limit: Option<String>,
-    pub(crate) gas_price: Option<String>,
-    pub(crate) max_fee: Option<String>,
-    pub(crate) priority_fee: Option<String>,
+    pub(crate) fee: Option<FeeConfig>,
     pub(crate) ref_id: Option<String>,
     pub(crate) idempotency_key: Option<String>,
+    pub(crate) schema: Option<TemplateSchema>,
 }
 
 impl DeployContractFromTemplateRequestBuilder {
@@ -35,13 +37,10 @@ impl DeployContractFromTemplateRequestBuilder {
             blockchain,
             description: None,
             template_parameters: None,
-            fee_level: None,
-            gas_limit: None,
-            gas_price: None,
-            max_fee: None,
-            priority_fee: None,
+            fee: None,
             ref_id: None,
             idempotency_key: None,
+            schema: None,
         })
     }
 
@@ -57,46 +56,107 @@ impl DeployContractFromTemplateRequestBuilder {
         self
     }
 
-    /// Set fee level (LOW, MEDIUM, or HIGH)
-    pub fn fee_level(mut self, level: String) -> Self {
-        self.fee_level = Some(level);
+    /// Set fee settings (fee level, legacy gas price or EIP-1559 fees)
+    pub fn fee(mut self, fee: FeeConfig) -> Self {
+        self.fee = Some(fee);
         self
     }
 
-    /// Set gas limit
-    pub fn gas_limit(mut self, limit: String) -> Self {
-        self.gas_limit = Some(limit);
+    /// Set fee level (LOW, MEDIUM, or HIGH)
+    pub fn fee_level(mut self, level: FeeLevel) -> Self {
+        self.fee = Some(FeeConfig::Level(level));
         self
     }
 
-    /// Set gas price (for non-EIP-1559 chains)
-    pub fn gas_price(mut self, price: String) -> Self {
-        self.gas_price = Some(price);
+    /// Set reference ID
+    pub fn ref_id(mut self, ref_id: String) -> Self {
+        self.ref_id = Some(ref_id);
         self
     }
 
-    /// Set max fee (for EIP-1559 chains)
-    pub fn max_fee(mut self, fee: String) -> Self {
-        self.max_fee = Some(fee);
+    /// Set custom idempotency key
+    pub fn idempotency_key(mut self, key: String) -> Self {
+        self.idempotency_key = Some(key);
         self
     }
 
-    /// Set priority fee (for EIP-1559 chains)
-    pub fn priority_fee(mut self, fee: String) -> Self {
-        self.priority_fee = Some(fee);
+    /// Set the parameter schema to validate template parameters against
+    ///
+    /// Only needed for custom templates; the schemas of Circle's standard
+    /// templates are looked up by template ID.
+    pub fn template_schema(mut self, schema: TemplateSchema) -> Self {
+        self.schema = Some(schema);
         self
     }
 
-    /// Set reference ID
-    pub fn ref_id(mut self, ref_id: String) -> Self {
-        self.ref_id = Some(ref_id);
-        self
+    /// Validate the template parameters against the template's parameter schema
+    ///
+    /// Uses the schema set with [`template_schema`](Self::template_schema), or the
+    /// built-in schema from [`TemplateSchema::known`]. Templates without a known
+    /// schema are not checked. Fee settings are checked with
+    /// [`FeeConfig::validate`]. Called automatically before deployment.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Config` if a required parameter is missing, a
+    /// parameter is not declared by the template, a value has the wrong type, or
+    /// the fee settings are invalid.
+    pub fn validate(&self) -> CircleResult<()> {
+        if let Some(fee) = &self.fee {
+            fee.validate()?;
+        }
+        let schema = match &self.schema {
+            Some(schema) => Some(schema.clone()),
+            None => TemplateSchema::known(&self.template_id),
+        };
+        match schema {
+            Some(schema) => schema.validate(self.template_parameters.as_ref()),
+            None => Ok(()),
+        }
     }
 
-    /// Set custom idempotency key
-    pub fn idempotency_key(mut self, key: String) -> Self {
-        self.idempotency_key = Some(key);
-        self
+    /// Convert the builder into the API request body
+    ///
+    /// `generate_idempotency_key` is only called when no custom key was set.
+    /// The template ID is part of the request path, not the body.
+    pub(crate) fn into_request(
+        self,
+        entity_secret_ciphertext: String,
+        generate_idempotency_key: impl FnOnce() -> String,
+    ) -> DeployContractFromTemplateRequest {
+        let fee = FeeConfig::into_fields(self.fee);
+        DeployContractFromTemplateRequest {
+            entity_secret_ciphertext: entity_secret_ciphertext.into(),
+            name: self.name,
+            wallet_id: self.wallet_id,
+            blockchain: self.blockchain,
+            idempotency_key: self
+                .idempotency_key
+                .unwrap_or_else(generate_idempotency_key),
+            description: self.description,
+            template_parameters: self.template_parameters,
+            fee_level: fee.fee_level.map(|level| level.as_str().to_string()),
+            gas_limit: fee.gas_limit,
+            gas_price: fee.gas_price,
+            max_fee: fee.max_fee,
+            priority_fee: fee.priority_fee,
+            ref_id: self.ref_id,
+        }
+    }
+
+    /// Preview the request body as pretty JSON
+    ///
+    /// The entity secret ciphertext is replaced by [`ENTITY_SECRET_PLACEHOLDER`] and,
+    /// when no idempotency key was set, the key is shown as
+    /// [`GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER`].
+    pub fn preview(&self) -> CircleResult<String> {
+        preview_request(
+            &self
+                .clone()
+                .into_request(ENTITY_SECRET_PLACEHOLDER.to_string(), || {
+                    GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER.to_string()
+                }),
+        )
     }
 
     /// Build the request parameters
@@ -106,3 +166,108 @@ impl DeployContractFromTemplateRequestBuilder {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contract::templates::{
+        TemplateParam, TemplateParamType, ERC20_TEMPLATE_ID, ERC721_TEMPLATE_ID,
+    };
+    use crate::helper::CircleError;
+    use serde_json::json;
+
+    const ADMIN: &str = "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb0";
+
+    fn builder(template_id: &str) -> DeployContractFromTemplateRequestBuilder {
+        DeployContractFromTemplateRequestBuilder::new(
+            template_id.to_string(),
+            "MyNFT".to_string(),
+            "wallet-id".to_string(),
+            "ETH-SEPOLIA".to_string(),
+        )
+        .unwrap()
+    }
+
+    fn config_error(result: CircleResult<()>) -> String {
+        match result {
+            Err(CircleError::Config(message)) => message,
+            other => panic!("expected a config error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_validate_known_template() {
+        let valid = json!({
+            "name": "My NFT",
+            "symbol": "MNFT",
+            "defaultAdmin": ADMIN,
+            "primarySaleRecipient": ADMIN,
+            "royaltyRecipient": ADMIN,
+            "royaltyPercent": 0.05,
+            "trustedForwarders": [ADMIN]
+        });
+        assert!(builder(ERC721_TEMPLATE_ID)
+            .template_parameters(valid.clone())
+            .validate()
+            .is_ok());
+
+        let mut missing = valid.clone();
+        missing.as_object_mut().unwrap().remove("royaltyRecipient");
+        let message = config_error(
+            builder(ERC721_TEMPLATE_ID)
+                .template_parameters(missing)
+                .validate(),
+        );
+        assert!(message.contains("royaltyRecipient"));
+
+        let mut bad_percent = valid.clone();
+        bad_percent["royaltyPercent"] = json!(5);
+        let message = config_error(
+            builder(ERC721_TEMPLATE_ID)
+                .template_parameters(bad_percent)
+                .validate(),
+        );
+        assert!(message.contains("royaltyPercent"));
+
+        let mut typo = valid;
+        typo["defaultAdmn"] = json!(ADMIN);
+        let message = config_error(
+            builder(ERC721_TEMPLATE_ID)
+                .template_parameters(typo)
+                .validate(),
+        );
+        assert!(message.contains("defaultAdmn"));
+
+        // ERC-20 has no royalty parameters and nothing passes without parameters
+        let message = config_error(builder(ERC20_TEMPLATE_ID).validate());
+        assert!(message.contains("name"));
+    }
+
+    #[test]
+    fn test_validate_custom_and_unknown_templates() {
+        assert!(builder("custom-template")
+            .template_parameters(json!({"anything": 1}))
+            .validate()
+            .is_ok());
+
+        let schema = TemplateSchema::new(
+            "custom-template",
+            vec![
+                TemplateParam::required("owner", TemplateParamType::Address),
+                TemplateParam::optional("paused", TemplateParamType::Bool),
+            ],
+        );
+        let with_schema = builder("custom-template").template_schema(schema);
+        assert!(with_schema
+            .clone()
+            .template_parameters(json!({"owner": ADMIN, "paused": false}))
+            .validate()
+            .is_ok());
+        let message = config_error(
+            with_schema
+                .template_parameters(json!({"owner": "not-an-address"}))
+                .validate(),
+        );
+        assert!(message.contains("owner"));
+    }
+}
@@ -1,5 +1,8 @@
+use crate::contract::dto::ImportContractRequest;
+use crate::helper::{preview_request, CircleResult, GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER};
 use crate::types::Blockchain;
 
+#[derive(Clone, Debug)]
 pub struct ImportContractRequestBuilder {
     pub blockchain: Blockchain,
     pub address: String,
@@ -28,6 +31,29 @@ impl ImportContractRequestBuilder {
         self
     }
 
+    /// Convert the builder into the API request body
+    pub(crate) fn into_request(self, idempotency_key: String) -> ImportContractRequest {
+        ImportContractRequest {
+            blockchain: self.blockchain,
+            address: self.address,
+            name: self.name,
+            idempotency_key,
+            description: self.description,
+        }
+    }
+
+    /// Preview the request body as pretty JSON
+    ///
+    /// The idempotency key is generated when the request is sent, so it is shown
+    /// as [`GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER`].
+    pub fn preview(&self) -> CircleResult<String> {
+        preview_request(
+            &self
+                .clone()
+                .into_request(GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER.to_string()),
+        )
+    }
+
     pub fn build(self) -> Self {
         self
     }
@@ -1,3 +1,4 @@
+pub mod archive_contract;
 pub mod deploy_contract;
 pub mod deploy_contract_from_template;
 pub mod import_contract;
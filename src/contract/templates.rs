@@ -0,0 +1,232 @@
+//! Contract Template Parameter Schemas
+//!
+//! Circle's contract templates take their initialization arguments as a JSON
+//! object of `templateParameters`. A [`TemplateSchema`] describes the parameters
+//! a template accepts, so a deployment can be checked for missing required
+//! fields, misspelled names and wrongly typed values before it is submitted.
+//!
+//! Schemas for Circle's ERC-20, ERC-721 and ERC-1155 templates are built in and
+//! looked up by template ID with [`TemplateSchema::known`]; other templates can
+//! be described with [`TemplateSchema::new`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use inf_circle_sdk::contract::templates::{TemplateSchema, ERC20_TEMPLATE_ID};
+//! use serde_json::json;
+//!
+//! let schema = TemplateSchema::known(ERC20_TEMPLATE_ID).unwrap();
+//! let result = schema.validate(Some(&json!({
+//!     "name": "My Token",
+//!     "defaultAdmin": "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb0",
+//! })));
+//!
+//! // primarySaleRecipient is required by the ERC-20 template
+//! assert!(result.is_err());
+//! ```
+
+use serde_json::Value;
+
+use crate::helper::{CircleError, CircleResult};
+
+/// Template ID of Circle's ERC-20 token template
+pub const ERC20_TEMPLATE_ID: &str = "a1b74add-23e0-4712-88d1-6b3009e85a86";
+
+/// Template ID of Circle's ERC-721 NFT template
+pub const ERC721_TEMPLATE_ID: &str = "76b83278-50e2-4006-8b63-5b1a2a814533";
+
+/// Template ID of Circle's ERC-1155 multi-token template
+pub const ERC1155_TEMPLATE_ID: &str = "aea21da6-0aa2-4971-9a1a-5098842b1248";
+
+/// Type of a template parameter value
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TemplateParamType {
+    /// Any string
+    String,
+    /// A `0x`-prefixed 20-byte EVM address
+    Address,
+    /// A list of EVM addresses
+    AddressList,
+    /// A number, given as a JSON number or a decimal string
+    Number,
+    /// A fraction between 0 and 1 (e.g., `0.05` for 5%)
+    Percent,
+    /// A boolean
+    Bool,
+}
+
+impl TemplateParamType {
+    /// Human-readable name used in validation errors
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TemplateParamType::String => "a string",
+            TemplateParamType::Address => "an address",
+            TemplateParamType::AddressList => "a list of addresses",
+            TemplateParamType::Number => "a number",
+            TemplateParamType::Percent => "a number between 0 and 1",
+            TemplateParamType::Bool => "a boolean",
+        }
+    }
+
+    fn accepts(&self, value: &Value) -> bool {
+        match self {
+            TemplateParamType::String => value.is_string(),
+            TemplateParamType::Address => value.as_str().is_some_and(is_evm_address),
+            TemplateParamType::AddressList => value
+                .as_array()
+                .is_some_and(|items| items.iter().all(|v| v.as_str().is_some_and(is_evm_address))),
+            TemplateParamType::Number => as_number(value).is_some(),
+            TemplateParamType::Percent => {
+                as_number(value).is_some_and(|n| (0.0..=1.0).contains(&n))
+            }
+            TemplateParamType::Bool => value.is_boolean(),
+        }
+    }
+}
+
+/// A parameter accepted by a contract template
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TemplateParam {
+    /// Parameter name as it appears in `templateParameters`
+    pub name: String,
+    /// Expected value type
+    pub ty: TemplateParamType,
+    /// Whether the deployment fails without this parameter
+    pub required: bool,
+}
+
+impl TemplateParam {
+    /// Create a required parameter
+    pub fn required(name: impl Into<String>, ty: TemplateParamType) -> Self {
+        Self {
+            name: name.into(),
+            ty,
+            required: true,
+        }
+    }
+
+    /// Create an optional parameter
+    pub fn optional(name: impl Into<String>, ty: TemplateParamType) -> Self {
+        Self {
+            name: name.into(),
+            ty,
+            required: false,
+        }
+    }
+}
+
+/// The parameters a contract template accepts
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TemplateSchema {
+    /// Template ID
+    pub template_id: String,
+    /// Declared parameters
+    pub params: Vec<TemplateParam>,
+}
+
+impl TemplateSchema {
+    /// Create a schema for a template
+    pub fn new(template_id: impl Into<String>, params: Vec<TemplateParam>) -> Self {
+        Self {
+            template_id: template_id.into(),
+            params,
+        }
+    }
+
+    /// Built-in schema for one of Circle's standard templates, if the ID is known
+    pub fn known(template_id: &str) -> Option<Self> {
+        use TemplateParamType::*;
+
+        let mut params = vec![
+            TemplateParam::required("name", String),
+            TemplateParam::optional("symbol", String),
+            TemplateParam::required("defaultAdmin", Address),
+            TemplateParam::required("primarySaleRecipient", Address),
+        ];
+        match template_id {
+            ERC20_TEMPLATE_ID => {}
+            ERC721_TEMPLATE_ID | ERC1155_TEMPLATE_ID => params.extend([
+                TemplateParam::required("royaltyRecipient", Address),
+                TemplateParam::required("royaltyPercent", Percent),
+            ]),
+            _ => return None,
+        }
+        params.extend([
+            TemplateParam::optional("platformFeeRecipient", Address),
+            TemplateParam::optional("platformFeePercent", Percent),
+            TemplateParam::optional("contractUri", String),
+            TemplateParam::optional("trustedForwarders", AddressList),
+        ]);
+        Some(Self::new(template_id, params))
+    }
+
+    /// Check template parameters against this schema
+    ///
+    /// `params` must be a JSON object (or absent, when no parameter is required).
+    /// Every required parameter must be present and non-null, every present
+    /// parameter must be declared, and each value must match its declared type.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Config` describing the first problem found.
+    pub fn validate(&self, params: Option<&Value>) -> CircleResult<()> {
+        let empty = serde_json::Map::new();
+        let object = match params {
+            None => &empty,
+            Some(Value::Object(object)) => object,
+            Some(_) => {
+                return Err(CircleError::Config(
+                    "Template parameters must be a JSON object".to_string(),
+                ))
+            }
+        };
+
+        for param in &self.params {
+            match object.get(&param.name).filter(|v| !v.is_null()) {
+                Some(value) if !param.ty.accepts(value) => {
+                    return Err(CircleError::Config(format!(
+                        "Template parameter {} must be {}, got {}",
+                        param.name,
+                        param.ty.as_str(),
+                        value
+                    )));
+                }
+                None if param.required => {
+                    return Err(CircleError::Config(format!(
+                        "Missing required template parameter {} for template {}",
+                        param.name, self.template_id
+                    )));
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(unknown) = object
+            .keys()
+            .find(|key| !self.params.iter().any(|p| &p.name == *key))
+        {
+            let declared: Vec<&str> = self.params.iter().map(|p| p.name.as_str()).collect();
+            return Err(CircleError::Config(format!(
+                "Unknown template parameter {} for template {} (expected one of: {})",
+                unknown,
+                self.template_id,
+                declared.join(", ")
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+fn is_evm_address(s: &str) -> bool {
+    s.strip_prefix("0x")
+        .is_some_and(|hex| hex.len() == 40 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+fn as_number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.parse().ok().filter(|n: &f64| n.is_finite()),
+        _ => None,
+    }
+}
@@ -0,0 +1,365 @@
+//! Token balance polling with change detection for CircleView
+
+use std::cmp::Ordering;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use futures_util::{stream, Stream};
+
+use crate::circle_view::circle_view::CircleView;
+use crate::dev_wallet::dto::{QueryParams, Token};
+use crate::helper::{compare_decimal_str, sleep, CircleResult, Instant};
+
+/// The token whose balance is watched
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WatchedToken {
+    /// Circle token ID
+    Id(String),
+    /// Token contract address (compared case-insensitively)
+    Address(String),
+    /// Token symbol (e.g., "USDC"); the first matching balance is used
+    Symbol(String),
+    /// The blockchain's native token
+    Native,
+}
+
+impl WatchedToken {
+    fn matches(&self, token: &Token) -> bool {
+        match self {
+            WatchedToken::Id(id) => &token.id == id,
+            WatchedToken::Address(address) => token
+                .token_address
+                .as_deref()
+                .is_some_and(|a| a.eq_ignore_ascii_case(address)),
+            WatchedToken::Symbol(symbol) => token.symbol.as_deref() == Some(symbol.as_str()),
+            WatchedToken::Native => token.is_native,
+        }
+    }
+}
+
+/// Polling and stop conditions for [`CircleView::watch_token_balance`]
+#[derive(Debug, Clone)]
+pub struct WatchBalanceOptions {
+    /// Delay between polls (default 5s)
+    pub poll_interval: Duration,
+    /// End the stream after this long
+    pub timeout: Option<Duration>,
+    /// End the stream once the balance is at least this decimal amount
+    pub until_at_least: Option<String>,
+    /// End the stream after this many changes
+    pub max_changes: Option<usize>,
+}
+
+impl Default for WatchBalanceOptions {
+    fn default() -> Self {
+        Self {
+            poll_interval: Duration::from_secs(5),
+            timeout: None,
+            until_at_least: None,
+            max_changes: None,
+        }
+    }
+}
+
+impl WatchBalanceOptions {
+    /// Create options with the default 5s poll interval and no stop condition
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the delay between polls
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// End the stream after `timeout`
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// End the stream once the balance reaches `amount` (decimal string)
+    pub fn until_at_least(mut self, amount: String) -> Self {
+        self.until_at_least = Some(amount);
+        self
+    }
+
+    /// End the stream after `count` changes
+    pub fn max_changes(mut self, count: usize) -> Self {
+        self.max_changes = Some(count);
+        self
+    }
+
+    fn reached_target(&self, amount: &str) -> bool {
+        self.until_at_least
+            .as_deref()
+            .is_some_and(|target| compare_decimal_str(amount, target) != Some(Ordering::Less))
+    }
+}
+
+/// A change in a watched token balance
+#[derive(Debug, Clone)]
+pub struct BalanceChange {
+    /// The token, or `None` if the wallet no longer holds it
+    pub token: Option<Token>,
+    /// Balance before the change ("0" when the token was not held)
+    pub previous: String,
+    /// Balance after the change ("0" when the token is no longer held)
+    pub current: String,
+    /// When the change was observed
+    pub observed_at: DateTime<Utc>,
+}
+
+impl BalanceChange {
+    /// Whether the balance went up
+    pub fn is_increase(&self) -> bool {
+        compare_decimal_str(&self.current, &self.previous) == Some(Ordering::Greater)
+    }
+}
+
+/// State carried between polls of [`CircleView::watch_token_balance`]
+struct BalanceWatch<'a> {
+    view: &'a CircleView,
+    wallet_id: String,
+    token: WatchedToken,
+    options: WatchBalanceOptions,
+    deadline: Option<Instant>,
+    /// Last observed balance; `None` until the baseline poll succeeds
+    last: Option<String>,
+    changes: usize,
+    polled: bool,
+    done: bool,
+}
+
+impl BalanceWatch<'_> {
+    async fn poll(&self) -> CircleResult<(Option<Token>, String)> {
+        let params = QueryParams {
+            include_all: Some(true),
+            ..Default::default()
+        };
+        let balances = self
+            .view
+            .get_token_balances(&self.wallet_id, params)
+            .await?;
+        Ok(balances
+            .token_balances
+            .into_iter()
+            .find(|balance| self.token.matches(&balance.token))
+            .map(|balance| (Some(balance.token), balance.amount))
+            .unwrap_or_else(|| (None, "0".to_string())))
+    }
+
+    fn timed_out(&self) -> bool {
+        self.deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// Sleep until the next poll, or return false if the deadline passes first
+    async fn wait(&self) -> bool {
+        let delay = match self.deadline {
+            Some(deadline) => {
+                let now = Instant::now();
+                if now >= deadline {
+                    return false;
+                }
+                self.options.poll_interval.min(deadline - now)
+            }
+            None => self.options.poll_interval,
+        };
+        sleep(delay).await;
+        !self.timed_out()
+    }
+}
+
+impl CircleView {
+    /// Watch a wallet's token balance and yield each change
+    ///
+    /// Polls [`get_token_balances`](CircleView::get_token_balances) every
+    /// `options.poll_interval`. The first successful poll sets the baseline
+    /// and yields nothing; after that, every poll whose amount differs from
+    /// the previous one yields a [`BalanceChange`]. A token the wallet does
+    /// not hold counts as a balance of "0".
+    ///
+    /// The stream ends when the timeout elapses, after `max_changes` changes,
+    /// or once the balance is at least `until_at_least` (which may already be
+    /// true at the baseline, ending the stream without any change). A failed
+    /// poll yields an `Err` item and polling continues.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// use futures_util::{pin_mut, StreamExt};
+    /// use inf_circle_sdk::circle_view::circle_view::CircleView;
+    /// use inf_circle_sdk::dev_wallet::balance_watch::{WatchBalanceOptions, WatchedToken};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let view = CircleView::new()?;
+    ///
+    /// // Wait for a deposit of at least 10 USDC
+    /// let options = WatchBalanceOptions::new()
+    ///     .poll_interval(Duration::from_secs(3))
+    ///     .timeout(Duration::from_secs(300))
+    ///     .until_at_least("10".to_string());
+    /// let changes =
+    ///     view.watch_token_balance("wallet-id", WatchedToken::Symbol("USDC".to_string()), options);
+    /// pin_mut!(changes);
+    /// while let Some(change) = changes.next().await {
+    ///     let change = change?;
+    ///     println!("{} -> {}", change.previous, change.current);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn watch_token_balance(
+        &self,
+        wallet_id: &str,
+        token: WatchedToken,
+        options: WatchBalanceOptions,
+    ) -> impl Stream<Item = CircleResult<BalanceChange>> + '_ {
+        let watch = BalanceWatch {
+            view: self,
+            wallet_id: wallet_id.to_string(),
+            token,
+            deadline: options.timeout.map(|timeout| Instant::now() + timeout),
+            options,
+            last: None,
+            changes: 0,
+            polled: false,
+            done: false,
+        };
+
+        stream::unfold(watch, |mut watch| async move {
+            if watch.done {
+                return None;
+            }
+            loop {
+                if watch.polled && !watch.wait().await {
+                    return None;
+                }
+                watch.polled = true;
+
+                let (token, current) = match watch.poll().await {
+                    Ok(balance) => balance,
+                    Err(e) => return Some((Err(e), watch)),
+                };
+
+                let Some(previous) = watch.last.replace(current.clone()) else {
+                    // Baseline poll
+                    if watch.options.reached_target(&current) {
+                        return None;
+                    }
+                    continue;
+                };
+                if previous == current
+                    || compare_decimal_str(&previous, &current) == Some(Ordering::Equal)
+                {
+                    continue;
+                }
+
+                watch.changes += 1;
+                watch.done = watch.options.reached_target(&current)
+                    || watch
+                        .options
+                        .max_changes
+                        .is_some_and(|max| watch.changes >= max);
+                let change = BalanceChange {
+                    token,
+                    previous,
+                    current,
+                    observed_at: Utc::now(),
+                };
+                return Some((Ok(change), watch));
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{fixtures, MockCircle};
+    use futures_util::{pin_mut, StreamExt};
+    use serde_json::json;
+
+    fn balances(amount: &str) -> serde_json::Value {
+        json!({ "tokenBalances": [{
+            "amount": amount,
+            "token": {
+                "id": "usdc-token",
+                "blockchain": "ETH-SEPOLIA",
+                "decimals": 6,
+                "isNative": false,
+                "symbol": "USDC",
+                "tokenAddress": "0x1c7D4B196Cb0C7B01d743Fbc6116a902379C7238",
+                "updateDate": "2024-01-01T00:00:00Z",
+                "createDate": "2024-01-01T00:00:00Z"
+            },
+            "updateDate": "2024-01-01T00:00:00Z"
+        }]})
+    }
+
+    #[tokio::test]
+    async fn test_watch_token_balance_until_target() {
+        let mut circle = MockCircle::start().await;
+        let path = format!("/v1/w3s/wallets/{}/balances", fixtures::WALLET_ID);
+
+        let view = circle.view();
+        let options = WatchBalanceOptions::new()
+            .poll_interval(Duration::from_millis(10))
+            .timeout(Duration::from_secs(5))
+            .until_at_least("10".to_string());
+        let changes = view.watch_token_balance(
+            fixtures::WALLET_ID,
+            WatchedToken::Address("0x1c7d4b196cb0c7b01d743fbc6116a902379c7238".to_string()),
+            options,
+        );
+        pin_mut!(changes);
+
+        // No balance yet, then a partial deposit, then the rest
+        let empty = circle
+            .respond("GET", &path, &json!({ "tokenBalances": [] }))
+            .await;
+        let mut next = Box::pin(changes.next());
+        tokio::select! {
+            _ = &mut next => panic!("no change expected while the balance is empty"),
+            _ = sleep(Duration::from_millis(50)) => {}
+        }
+        empty.remove_async().await;
+        let partial = circle.respond("GET", &path, &balances("4.5")).await;
+
+        let change = next.await.unwrap().unwrap();
+        assert_eq!(
+            (change.previous.as_str(), change.current.as_str()),
+            ("0", "4.5")
+        );
+        assert!(change.is_increase());
+        assert_eq!(change.token.unwrap().symbol.as_deref(), Some("USDC"));
+
+        partial.remove_async().await;
+        circle.respond("GET", &path, &balances("10.0")).await;
+        let change = changes.next().await.unwrap().unwrap();
+        assert_eq!(change.current, "10.0");
+        assert!(changes.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_watch_token_balance_times_out() {
+        let mut circle = MockCircle::start().await;
+        let path = format!("/v1/w3s/wallets/{}/balances", fixtures::WALLET_ID);
+        circle.respond("GET", &path, &balances("1")).await;
+
+        let view = circle.view();
+        let options = WatchBalanceOptions::new()
+            .poll_interval(Duration::from_millis(10))
+            .timeout(Duration::from_millis(60));
+        let changes = view.watch_token_balance(
+            fixtures::WALLET_ID,
+            WatchedToken::Symbol("USDC".to_string()),
+            options,
+        );
+        pin_mut!(changes);
+        assert!(changes.next().await.is_none());
+    }
+}
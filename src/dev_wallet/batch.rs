@@ -0,0 +1,387 @@
+//! Concurrent batch transfers for CircleOps
+//!
+//! [`TransferBatch`] collects many transfers and
+//! [`CircleOps::execute_transfer_batch`] submits them with bounded
+//! concurrency. Every transfer gets its idempotency key before the first
+//! attempt, so retries after rate limiting, maintenance or server errors can
+//! never create a transfer twice. To keep the batch under Circle's rate
+//! limits, attach a [`RateLimiter`](crate::helper::rate_limit::RateLimiter)
+//! to the client.
+
+use std::time::Duration;
+
+use futures_util::{stream, StreamExt};
+
+use crate::circle_ops::circler_ops::CircleOps;
+use crate::dev_wallet::dto::CreateTransferTransactionResponse;
+use crate::dev_wallet::ops::create_transfer_transaction::CreateTransferTransactionRequestBuilder;
+use crate::helper::{generate_uuid, sleep, CircleError};
+
+/// Transfers to submit together
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+/// use inf_circle_sdk::dev_wallet::{
+///     batch::TransferBatch, dto::FeeLevel,
+///     ops::create_transfer_transaction::CreateTransferTransactionRequestBuilder,
+/// };
+/// use inf_circle_sdk::helper::rate_limit::{RateLimit, RateLimiter};
+///
+/// # async fn example(payees: Vec<(String, String)>) -> Result<(), Box<dyn std::error::Error>> {
+/// let ops = CircleOps::new(None)?.with_rate_limiter(RateLimiter::new(RateLimit::per_second(5.0)));
+///
+/// let batch = TransferBatch::new()
+///     .transfers(payees.into_iter().map(|(address, amount)| {
+///         CreateTransferTransactionRequestBuilder::new()
+///             .wallet_id("treasury-wallet-id".to_string())
+///             .token_id("usdc-token-id".to_string())
+///             .destination_address(address.clone())
+///             .amounts([amount])
+///             .ref_id(format!("payout-{}", address))
+///             .fee_level(FeeLevel::Medium)
+///             .build()
+///     }))
+///     .concurrency(8)
+///     .max_retries(3);
+///
+/// let report = ops.execute_transfer_batch(batch).await;
+/// println!("{} sent, {} failed", report.successes.len(), report.failures.len());
+/// for failure in &report.failures {
+///     println!("#{} failed after {} attempts: {}", failure.index, failure.attempts, failure.error);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct TransferBatch {
+    transfers: Vec<CreateTransferTransactionRequestBuilder>,
+    concurrency: usize,
+    max_retries: u32,
+    retry_delay: Duration,
+}
+
+impl Default for TransferBatch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TransferBatch {
+    /// Create an empty batch (4 transfers in flight, 2 retries starting at 1s)
+    pub fn new() -> Self {
+        Self {
+            transfers: Vec::new(),
+            concurrency: 4,
+            max_retries: 2,
+            retry_delay: Duration::from_secs(1),
+        }
+    }
+
+    /// Add a transfer
+    pub fn transfer(mut self, transfer: CreateTransferTransactionRequestBuilder) -> Self {
+        self.transfers.push(transfer);
+        self
+    }
+
+    /// Add several transfers
+    pub fn transfers(
+        mut self,
+        transfers: impl IntoIterator<Item = CreateTransferTransactionRequestBuilder>,
+    ) -> Self {
+        self.transfers.extend(transfers);
+        self
+    }
+
+    /// Set the maximum number of transfers in flight (at least 1)
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Set how often a transfer is retried after a retryable error
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Set the delay before the first retry; it doubles for each further retry
+    pub fn retry_delay(mut self, delay: Duration) -> Self {
+        self.retry_delay = delay;
+        self
+    }
+
+    /// Number of transfers in the batch
+    pub fn len(&self) -> usize {
+        self.transfers.len()
+    }
+
+    /// Whether the batch contains no transfers
+    pub fn is_empty(&self) -> bool {
+        self.transfers.is_empty()
+    }
+
+    fn backoff(&self, retry: u32, error: &CircleError) -> Duration {
+        let delay = self
+            .retry_delay
+            .saturating_mul(2u32.saturating_pow(retry.saturating_sub(1)));
+        match error {
+            CircleError::Maintenance {
+                retry_after: Some(retry_after),
+            } => delay.max(*retry_after),
+            _ => delay,
+        }
+    }
+}
+
+/// Outcome of `CircleOps::execute_transfer_batch`
+#[derive(Debug, Default)]
+pub struct TransferBatchReport {
+    /// Transfers Circle accepted, in submission order
+    pub successes: Vec<TransferBatchSuccess>,
+
+    /// Transfers that failed, in submission order
+    pub failures: Vec<TransferBatchFailure>,
+}
+
+impl TransferBatchReport {
+    /// Whether every transfer in the batch was accepted
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Total number of retries across the batch
+    pub fn retries(&self) -> u32 {
+        let attempts = self.successes.iter().map(|s| s.attempts);
+        let attempts = attempts.chain(self.failures.iter().map(|f| f.attempts));
+        attempts.map(|attempts| attempts - 1).sum()
+    }
+}
+
+/// A transfer Circle accepted
+#[derive(Debug)]
+pub struct TransferBatchSuccess {
+    /// Position of the transfer in the batch
+    pub index: usize,
+
+    /// Idempotency key the transfer was submitted with
+    pub idempotency_key: String,
+
+    /// Number of submissions, including the successful one
+    pub attempts: u32,
+
+    /// Circle's response
+    pub response: CreateTransferTransactionResponse,
+}
+
+/// A transfer that failed
+#[derive(Debug)]
+pub struct TransferBatchFailure {
+    /// Position of the transfer in the batch
+    pub index: usize,
+
+    /// The transfer, with the idempotency key it was submitted with
+    ///
+    /// Resubmitting it cannot create a duplicate if an earlier attempt was
+    /// in fact accepted.
+    pub transfer: CreateTransferTransactionRequestBuilder,
+
+    /// Number of submissions made
+    pub attempts: u32,
+
+    /// Error returned by the last submission
+    pub error: CircleError,
+}
+
+/// Whether a failed submission may succeed if repeated
+///
+/// Rate limiting, maintenance, server errors, transport failures and
+/// timeouts are retried; validation and other client errors are not.
+fn is_retryable(error: &CircleError) -> bool {
+    match error {
+        CircleError::Api { status, .. } => *status == 429 || *status >= 500,
+        CircleError::Http(_) | CircleError::Maintenance { .. } | CircleError::Timeout(_) => true,
+        _ => false,
+    }
+}
+
+impl CircleOps {
+    /// Submit a batch of transfers with bounded concurrency and retries
+    ///
+    /// Transfers without an idempotency key are given one up front, and each
+    /// transfer is retried with exponential backoff (up to the batch's
+    /// `max_retries`) while it fails with a retryable error. A failed
+    /// transfer does not stop the others.
+    ///
+    /// # Returns
+    ///
+    /// A report listing every transfer as a success or a failure, with the
+    /// number of attempts it took.
+    pub async fn execute_transfer_batch(&self, batch: TransferBatch) -> TransferBatchReport {
+        let transfers: Vec<_> = batch
+            .transfers
+            .iter()
+            .cloned()
+            .map(|transfer| {
+                let key = transfer
+                    .idempotency_key
+                    .clone()
+                    .unwrap_or_else(generate_uuid);
+                transfer.idempotency_key(key)
+            })
+            .enumerate()
+            .collect();
+
+        let mut outcomes: Vec<_> = stream::iter(transfers)
+            .map(|(index, transfer)| self.submit_with_retries(&batch, index, transfer))
+            .buffer_unordered(batch.concurrency)
+            .collect()
+            .await;
+        outcomes.sort_by_key(|outcome| match outcome {
+            Ok(success) => success.index,
+            Err(failure) => failure.index,
+        });
+
+        let mut report = TransferBatchReport::default();
+        for outcome in outcomes {
+            match outcome {
+                Ok(success) => report.successes.push(success),
+                Err(failure) => report.failures.push(failure),
+            }
+        }
+        report
+    }
+
+    async fn submit_with_retries(
+        &self,
+        batch: &TransferBatch,
+        index: usize,
+        transfer: CreateTransferTransactionRequestBuilder,
+    ) -> Result<TransferBatchSuccess, TransferBatchFailure> {
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            let error = match self.create_dev_transfer_transaction(transfer.clone()).await {
+                Ok(response) => {
+                    return Ok(TransferBatchSuccess {
+                        index,
+                        idempotency_key: transfer.idempotency_key.clone().unwrap_or_default(),
+                        attempts,
+                        response,
+                    })
+                }
+                Err(error) => error,
+            };
+            if attempts > batch.max_retries || !is_retryable(&error) {
+                return Err(TransferBatchFailure {
+                    index,
+                    transfer,
+                    attempts,
+                    error,
+                });
+            }
+            sleep(batch.backoff(attempts, &error)).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dev_wallet::dto::FeeLevel;
+    use crate::testing::{fixtures, MockCircle};
+    use mockito::Matcher;
+    use serde_json::json;
+
+    const PATH: &str = "/v1/w3s/developer/transactions/transfer";
+
+    fn transfer(key: &str) -> CreateTransferTransactionRequestBuilder {
+        CreateTransferTransactionRequestBuilder::new()
+            .wallet_id(fixtures::WALLET_ID.to_string())
+            .destination_address(fixtures::WALLET_ADDRESS.to_string())
+            .amounts(["1"])
+            .fee_level(FeeLevel::Medium)
+            .idempotency_key(key.to_string())
+    }
+
+    async fn respond(circle: &mut MockCircle, key: &str, status: usize, hits: usize) {
+        circle
+            .server()
+            .mock("POST", PATH)
+            .match_body(Matcher::PartialJson(json!({ "idempotencyKey": key })))
+            .with_status(status)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "data": { "id": format!("tx-{}", key), "state": "INITIATED" }, "code": 1, "message": "error" }).to_string())
+            .expect(hits)
+            .create_async()
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_transfer_batch_report() {
+        let mut circle = MockCircle::start().await;
+        respond(&mut circle, "ok", 201, 1).await;
+        respond(&mut circle, "flaky", 429, 1).await;
+        respond(&mut circle, "flaky", 201, 1).await;
+        respond(&mut circle, "bad", 400, 1).await;
+        respond(&mut circle, "down", 500, 2).await;
+
+        let batch = TransferBatch::new()
+            .transfers(["ok", "flaky", "bad", "down"].map(transfer))
+            .concurrency(2)
+            .max_retries(1)
+            .retry_delay(Duration::from_millis(1));
+        let report = circle.ops().execute_transfer_batch(batch).await;
+
+        let succeeded: Vec<_> = report
+            .successes
+            .iter()
+            .map(|s| (s.index, s.response.id.as_str(), s.attempts))
+            .collect();
+        assert_eq!(succeeded, vec![(0, "tx-ok", 1), (1, "tx-flaky", 2)]);
+
+        let failed: Vec<_> = report
+            .failures
+            .iter()
+            .map(|f| (f.index, f.attempts, f.transfer.idempotency_key.as_deref()))
+            .collect();
+        assert_eq!(failed, vec![(2, 1, Some("bad")), (3, 2, Some("down"))]);
+        assert!(!report.is_complete());
+        assert_eq!(report.retries(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_transfer_batch_assigns_idempotency_keys() {
+        let mut circle = MockCircle::start().await;
+        let mock = circle
+            .server()
+            .mock("POST", PATH)
+            .match_body(Matcher::Regex(
+                r#""idempotencyKey":"[0-9a-f-]{36}""#.to_string(),
+            ))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "data": { "id": "tx-1", "state": "INITIATED" } }).to_string())
+            .expect(3)
+            .create_async()
+            .await;
+
+        let mut unkeyed = transfer("unused");
+        unkeyed.idempotency_key = None;
+        let batch = TransferBatch::new().transfers(vec![unkeyed; 3]);
+        let report = circle.ops().execute_transfer_batch(batch).await;
+
+        assert!(report.is_complete());
+        let mut keys: Vec<_> = report
+            .successes
+            .iter()
+            .map(|s| s.idempotency_key.clone())
+            .collect();
+        keys.sort();
+        keys.dedup();
+        assert_eq!(keys.len(), 3);
+        mock.assert_async().await;
+    }
+}
@@ -2,32 +2,44 @@
 
 use crate::{
     circle_ops::circler_ops::CircleOps,
+    circle_view::circle_view::CircleView,
+    contract::dto::QueryContractResponse,
     dev_wallet::{
         dto::{
-            AccelerateTransactionRequest, AccelerateTransactionResponse, CancelTransactionRequest,
-            CancelTransactionResponse, CreateContractExecutionTransactionRequest,
-            CreateContractExecutionTransactionResponse, CreateDevWalletRequest,
-            CreateTransferTransactionRequest, CreateTransferTransactionResponse,
-            CreateWalletUpgradeTransactionRequest, CreateWalletUpgradeTransactionResponse,
-            DevWalletResponse, DevWalletsResponse, QueryContractRequest, QueryContractResponse,
-            SignDataRequest, SignDelegateRequest, SignDelegateResponse, SignMessageRequest,
-            SignTransactionRequest, SignTransactionResponse, SignatureResponse,
-            UpdateDevWalletRequest,
+            AccelerateTransactionResponse, CancelTransactionResponse,
+            CreateContractExecutionTransactionResponse, CreateTransferTransactionResponse,
+            CreateWalletUpgradeTransactionResponse, DevWallet, DevWalletMetadata,
+            DevWalletResponse, DevWalletsResponse, FeeConfig, FeeEstimate, FeeLevel, FeeSelection,
+            QueryContractRequest, SignDelegateResponse, SignTransactionResponse, SignatureResponse,
+            Transaction, TransferWithEstimate, UpdateDevWalletRequest, ValidateAddressResponse,
         },
         ops::{
             accelerate_transaction::AccelerateTransactionRequestBuilder,
             cancel_transaction::CancelTransactionRequestBuilder,
             create_contract_transaction::CreateContractExecutionTransactionRequestBuilder,
             create_dev_wallet::CreateDevWalletRequestBuilder,
+            create_dev_wallet_batch::{BatchWalletFailure, BatchWalletPlan, BatchWalletResult},
             create_transfer_transaction::CreateTransferTransactionRequestBuilder,
             create_wallet_upgrade_transaction::CreateWalletUpgradeTransactionRequestBuilder,
-            sign_data::SignDataRequestBuilder, sign_delegate::SignDelegateRequestBuilder,
+            ensure_wallet::{ensure_wallet_idempotency_key, EnsureWalletOptions},
+            sign_data::SignDataRequestBuilder,
+            sign_delegate::SignDelegateRequestBuilder,
             sign_message::SignMessageRequestBuilder,
             sign_transaction::SignTransactionRequestBuilder,
         },
+        views::{
+            list_transactions::ListTransactionsParamsBuilder,
+            list_wallets::ListDevWalletsParamsBuilder,
+            validate_address::ValidateAddressBodyBuilder,
+        },
+    },
+    helper::{
+        address::validate_address, await_visible_where, compare_decimal_str, generate_uuid,
+        CircleError, CircleResult,
     },
-    helper::CircleResult,
+    types::{Blockchain, Stablecoin},
 };
+use std::time::Duration;
 use uuid::Uuid;
 
 impl CircleOps {
@@ -78,24 +90,178 @@ impl CircleOps {
         // Encrypt the entity secret (fresh encryption for each request)
         let entity_secret_ciphertext = self.entity_secret()?;
 
-        // Generate a new UUID for each request (or use custom one if provided)
-        let idempotency_key = Uuid::new_v4().to_string();
-
-        let request = CreateDevWalletRequest {
-            wallet_set_id: builder.wallet_set_id,
-            entity_secret_ciphertext,
-            blockchains: builder.blockchains,
-            idempotency_key,
-            account_type: builder.account_type,
-            count: builder.count,
-            metadata: builder.metadata,
-            name: builder.name,
-            ref_id: builder.ref_id,
-        };
+        // Use the custom idempotency key if provided, otherwise generate a new UUID
+        let request = builder.into_request(entity_secret_ciphertext, generate_uuid);
 
         self.post("/v1/w3s/developer/wallets", &request).await
     }
 
+    /// Create wallets from a batch plan
+    ///
+    /// Sends one create request per blockchain (and per chunk of
+    /// [`MAX_WALLETS_PER_REQUEST`](crate::dev_wallet::ops::create_dev_wallet_batch::MAX_WALLETS_PER_REQUEST)
+    /// wallets), so every wallet keeps its own name and ref ID. Requests run one
+    /// after another; a failed request does not stop the remaining ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `plan` - The wallets to create, grouped by blockchain
+    ///
+    /// # Returns
+    ///
+    /// The created wallets and, for each failed request, the metadata of the
+    /// wallets it would have created together with the error.
+    ///
+    /// # Errors
+    ///
+    /// Only returns an error if the plan cannot be turned into requests; API
+    /// failures are reported in [`BatchWalletResult::failures`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+    /// use inf_circle_sdk::dev_wallet::dto::{AccountType, DevWalletMetadata};
+    /// use inf_circle_sdk::dev_wallet::ops::create_dev_wallet_batch::BatchWalletPlan;
+    /// use inf_circle_sdk::types::Blockchain;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ops = CircleOps::new(None)?;
+    ///
+    /// let plan = BatchWalletPlan::new("wallet-set-id".to_string())
+    ///     .account_type(AccountType::Eoa)
+    ///     .wallet(
+    ///         Blockchain::EthSepolia,
+    ///         DevWalletMetadata { name: Some("Treasury".to_string()), ref_id: Some("treasury-eth".to_string()) },
+    ///     )
+    ///     .wallet(
+    ///         Blockchain::SolDevnet,
+    ///         DevWalletMetadata { name: Some("Treasury".to_string()), ref_id: Some("treasury-sol".to_string()) },
+    ///     );
+    ///
+    /// let result = ops.create_dev_wallet_batch(&plan).await?;
+    /// println!("Created {} wallets", result.wallets.len());
+    /// for failure in &result.failures {
+    ///     println!("{} failed: {}", failure.blockchain.as_str(), failure.error);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_dev_wallet_batch(
+        &self,
+        plan: &BatchWalletPlan,
+    ) -> CircleResult<BatchWalletResult> {
+        let mut result = BatchWalletResult::default();
+
+        for builder in plan.requests()? {
+            let blockchain = builder.blockchains[0].clone();
+            let metadata = builder.metadata.clone().unwrap_or_default();
+
+            match self.create_dev_wallet(builder).await {
+                Ok(response) => result.wallets.extend(response.wallets),
+                Err(error) => result.failures.push(BatchWalletFailure {
+                    blockchain,
+                    metadata,
+                    error,
+                }),
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Get the wallet with `ref_id` on `blockchain`, creating it only if there is none
+    ///
+    /// Lists the wallet set by ref ID first. A missing wallet is created
+    /// with an idempotency key derived from the wallet set, blockchain and
+    /// ref ID (see [`ensure_wallet_idempotency_key`]), so callers racing to
+    /// create the same wallet send the same request. The loser of the race
+    /// gets an idempotency conflict from Circle and lists again until the
+    /// winner's wallet shows up.
+    ///
+    /// # Arguments
+    ///
+    /// * `wallet_set_id` - Wallet set to look in and create the wallet in
+    /// * `blockchain` - Blockchain of the wallet
+    /// * `ref_id` - Reference ID identifying the wallet
+    /// * `options` - Account type and name for creation, and re-list policy
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Config` if several wallets on `blockchain` share
+    /// `ref_id`, or the conflicting wallet still cannot be listed after the
+    /// configured re-list attempts.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+    /// use inf_circle_sdk::dev_wallet::ops::ensure_wallet::EnsureWalletOptions;
+    /// use inf_circle_sdk::types::Blockchain;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ops = CircleOps::new(None)?;
+    ///
+    /// let wallet = ops
+    ///     .ensure_wallet(
+    ///         "wallet-set-id",
+    ///         &Blockchain::EthSepolia,
+    ///         "customer-42-eth",
+    ///         EnsureWalletOptions::new().name("Customer 42"),
+    ///     )
+    ///     .await?;
+    /// println!("Deposit address: {}", wallet.address);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn ensure_wallet(
+        &self,
+        wallet_set_id: &str,
+        blockchain: &Blockchain,
+        ref_id: &str,
+        options: EnsureWalletOptions,
+    ) -> CircleResult<DevWallet> {
+        let view = CircleView::from_core(&self.core());
+        if let Some(wallet) = find_wallet(&view, wallet_set_id, blockchain, ref_id).await? {
+            return Ok(wallet);
+        }
+
+        let request = CreateDevWalletRequestBuilder::new(
+            wallet_set_id.to_string(),
+            vec![blockchain.clone()],
+        )?
+        .account_type(options.account_type)
+        .metadata(vec![DevWalletMetadata {
+            name: options.name.clone(),
+            ref_id: Some(ref_id.to_string()),
+        }])
+        .idempotency_key(ensure_wallet_idempotency_key(
+            wallet_set_id,
+            blockchain,
+            ref_id,
+        ));
+
+        match self.create_dev_wallet(request).await {
+            Ok(response) => response.wallets.into_iter().next().ok_or_else(|| {
+                CircleError::Config(format!("Circle created no wallet for ref ID {}", ref_id))
+            }),
+            // A concurrent caller created it first; wait for it to be listed
+            Err(CircleError::Api { status: 409, .. }) => {
+                await_visible_where(
+                    &format!("wallet with ref ID {}", ref_id),
+                    |_: &DevWallet| true,
+                    || async {
+                        let wallet = find_wallet(&view, wallet_set_id, blockchain, ref_id).await?;
+                        Ok(wallet.into_iter().collect())
+                    },
+                    options.relist_timeout,
+                )
+                .await
+            }
+            Err(e) => Err(e),
+        }
+    }
+
     /// Update a wallet
     ///
     /// Updates wallet metadata such as name and reference ID
@@ -169,15 +335,9 @@ impl CircleOps {
     ) -> CircleResult<SignatureResponse> {
         let entity_secret_ciphertext = self.entity_secret()?;
 
-        let request = SignMessageRequest {
-            entity_secret_ciphertext,
-            message: builder.message,
-            wallet_id: builder.wallet_id,
-            encoded_by_hex: builder.encoded_by_hex,
-            memo: builder.memo,
-        };
+        let request = builder.into_request(entity_secret_ciphertext);
 
-        let path = format!("/v1/w3s/developer/sign/message");
+        let path = "/v1/w3s/developer/sign/message".to_string();
         self.post(&path, &request).await
     }
 
@@ -227,14 +387,9 @@ impl CircleOps {
     ) -> CircleResult<SignatureResponse> {
         let entity_secret_ciphertext = self.entity_secret()?;
 
-        let request = SignDataRequest {
-            entity_secret_ciphertext,
-            data: builder.data,
-            wallet_id: builder.wallet_id,
-            memo: builder.memo,
-        };
+        let request = builder.into_request(entity_secret_ciphertext);
 
-        let path = format!("/v1/w3s/developer/sign/typedData");
+        let path = "/v1/w3s/developer/sign/typedData".to_string();
         self.post(&path, &request).await
     }
 
@@ -280,15 +435,9 @@ impl CircleOps {
     ) -> CircleResult<SignTransactionResponse> {
         let entity_secret_ciphertext = self.entity_secret()?;
 
-        let request = SignTransactionRequest {
-            entity_secret_ciphertext,
-            raw_transaction: builder.raw_transaction,
-            transaction: builder.transaction,
-            wallet_id: builder.wallet_id,
-            memo: builder.memo,
-        };
+        let request = builder.into_request(entity_secret_ciphertext);
 
-        let path = format!("/v1/w3s/developer/sign/transaction");
+        let path = "/v1/w3s/developer/sign/transaction".to_string();
         self.post(&path, &request).await
     }
 
@@ -335,13 +484,9 @@ impl CircleOps {
     ) -> CircleResult<SignDelegateResponse> {
         let entity_secret_ciphertext = self.entity_secret()?;
 
-        let request = SignDelegateRequest {
-            entity_secret_ciphertext,
-            unsigned_delegate_action: builder.unsigned_delegate_action,
-            wallet_id: builder.wallet_id,
-        };
+        let request = builder.into_request(entity_secret_ciphertext);
 
-        let path = format!("/v1/w3s/developer/sign/delegateAction");
+        let path = "/v1/w3s/developer/sign/delegateAction".to_string();
         self.post(&path, &request).await
     }
 
@@ -358,6 +503,31 @@ impl CircleOps {
     ///
     /// Returns transaction details including the transaction ID and state.
     ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::AmountTooSmall` without calling the API if an amount is
+    /// below the minimums checked by
+    /// [`validate`](CreateTransferTransactionRequestBuilder::validate), and
+    /// `CircleError::Config` if the builder's chain extras do not match its blockchain.
+    /// With [`validate_destination`](CreateTransferTransactionRequestBuilder::validate_destination)
+    /// enabled, also returns `CircleError::Config` if the destination address is
+    /// malformed for the blockchain or Circle reports it as invalid.
+    ///
+    /// # Retries
+    ///
+    /// Resubmitting with the same idempotency key never creates a second
+    /// transfer. When Circle rejects the key with `409 Conflict`, the transfer
+    /// created by the first attempt is looked up by `ref_id` among the source
+    /// wallet's transactions of the last [`CONFLICT_LOOKBACK`] and returned
+    /// instead of the error. A transaction with that `ref_id` but a different
+    /// destination, amounts or token is not the original, and the `409` is
+    /// returned.
+    ///
+    /// Recovery requires a `ref_id`: Circle does not report the idempotency
+    /// key of a transaction, so without one the `409` is returned as is. To
+    /// retry a transfer until it succeeds, set both an idempotency key and a
+    /// `ref_id` unique to the transfer, and retry within the lookback.
+    ///
     /// # Example - Native Token Transfer
     ///
     /// ```rust,no_run
@@ -413,29 +583,294 @@ impl CircleOps {
         &self,
         builder: CreateTransferTransactionRequestBuilder,
     ) -> CircleResult<CreateTransferTransactionResponse> {
+        builder.validate()?;
+        if builder.validate_destination {
+            self.check_destination(&builder).await?;
+        }
+
         let entity_secret_ciphertext = self.entity_secret()?;
 
-        let request = CreateTransferTransactionRequest {
-            entity_secret_ciphertext,
-            wallet_id: builder.wallet_id,
-            wallet_address: builder.wallet_address,
-            destination_address: builder.destination_address,
-            amounts: builder.amounts,
-            nft_token_ids: builder.nft_token_ids,
-            token_id: builder.token_id,
-            token_address: builder.token_address,
-            idempotency_key: builder.idempotency_key,
-            ref_id: builder.ref_id,
-            blockchain: builder.blockchain,
-            gas_limit: builder.gas_limit,
-            gas_price: builder.gas_price,
-            max_fee: builder.max_fee,
-            priority_fee: builder.priority_fee,
-            fee_level: builder.fee_level,
+        let source = ConflictSource::transfer(&builder);
+        let request = builder.into_request(entity_secret_ciphertext, generate_uuid);
+
+        let path = "/v1/w3s/developer/transactions/transfer".to_string();
+        match self.post(&path, &request).await {
+            Err(conflict @ CircleError::Api { status: 409, .. }) => {
+                let transaction = self.find_conflicting_transaction(conflict, source).await?;
+                Ok(CreateTransferTransactionResponse {
+                    id: transaction.id,
+                    state: transaction.state,
+                })
+            }
+            result => result,
+        }
+    }
+
+    /// Find the transaction an earlier request with the same idempotency key created
+    ///
+    /// Circle rejects a reused idempotency key with `409 Conflict` rather than
+    /// returning the original transaction. The original is matched by ref ID
+    /// among the source wallet's transactions created in the last
+    /// [`CONFLICT_LOOKBACK`], following the pages up to
+    /// [`CONFLICT_MAX_PAGES`]. A transaction with the ref ID only matches if
+    /// its destination, amounts and token are also those of the request, so a
+    /// reused ref ID never turns an unrelated transaction into a success.
+    /// Without a ref ID, or if nothing matches, `conflict` is returned.
+    async fn find_conflicting_transaction(
+        &self,
+        conflict: CircleError,
+        source: ConflictSource,
+    ) -> CircleResult<Transaction> {
+        let Some(ref_id) = source.ref_id.as_deref() else {
+            return Err(conflict);
         };
 
-        let path = format!("/v1/w3s/developer/transactions/transfer");
-        self.post(&path, &request).await
+        let mut params = ListTransactionsParamsBuilder::new()
+            .since(CONFLICT_LOOKBACK)
+            .page_size(50);
+        if let Some(wallet_id) = &source.wallet_id {
+            params = params.wallet_ids(wallet_id.clone());
+        }
+        if let Some(blockchain) = &source.blockchain {
+            params = params.blockchain(blockchain.as_str().to_string());
+        }
+        let mut params = params.build();
+        let view = CircleView::from_core(&self.core());
+
+        for _ in 0..CONFLICT_MAX_PAGES {
+            let page = view.list_transactions(params.clone()).await?;
+            let next = page.next_page();
+            if let Some(transaction) = page
+                .into_iter()
+                .find(|transaction| source.matches(ref_id, transaction))
+            {
+                return Ok(transaction);
+            }
+            match next {
+                Some(next) => params.pagination = next,
+                None => break,
+            }
+        }
+        Err(conflict)
+    }
+
+    /// Check a transfer's destination locally, then ask Circle whether it is valid
+    async fn check_destination(
+        &self,
+        builder: &CreateTransferTransactionRequestBuilder,
+    ) -> CircleResult<()> {
+        let Some(blockchain) = builder.blockchain.clone() else {
+            return Err(CircleError::Config(
+                "Destination validation requires an explicit blockchain".to_string(),
+            ));
+        };
+        validate_address(&blockchain, &builder.destination_address)?;
+
+        let body = ValidateAddressBodyBuilder::new()
+            .address(builder.destination_address.clone())
+            .blockchain(blockchain.clone())
+            .build();
+        let response: ValidateAddressResponse = self
+            .post("/v1/w3s/transactions/validateAddress", &body)
+            .await?;
+        if response.is_valid {
+            Ok(())
+        } else {
+            Err(CircleError::Config(format!(
+                "Invalid destination address {} on {}",
+                builder.destination_address,
+                blockchain.as_str()
+            )))
+        }
+    }
+
+    /// Estimate a transfer's fee, then submit it with the estimated gas settings
+    ///
+    /// Calls the transfer fee estimate endpoint for `builder`, takes the
+    /// `selection` tier and submits the transfer with that tier's explicit gas
+    /// settings (see [`EstimatedFee::to_fee_config`]), replacing any fee set on
+    /// the builder. This pins the fee the transfer pays to the estimate that was
+    /// shown, instead of letting Circle re-estimate at submission time. On
+    /// blockchains whose estimates carry no gas settings, the transfer falls back
+    /// to the fee level of the same tier.
+    ///
+    /// # Errors
+    ///
+    /// Returns the builder's validation error before any API call, or the error
+    /// of the estimate or transfer request.
+    ///
+    /// [`EstimatedFee::to_fee_config`]: crate::dev_wallet::dto::EstimatedFee::to_fee_config
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+    /// use inf_circle_sdk::dev_wallet::dto::FeeSelection;
+    /// use inf_circle_sdk::dev_wallet::ops::create_transfer_transaction::CreateTransferTransactionRequestBuilder;
+    /// use inf_circle_sdk::types::Blockchain;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ops = CircleOps::new(None)?;
+    ///
+    /// let builder = CreateTransferTransactionRequestBuilder::new()
+    ///     .wallet_id("wallet-id".to_string())
+    ///     .destination_address("0x1234...".to_string())
+    ///     .amounts(vec!["0.1".to_string()])
+    ///     .blockchain(Blockchain::EthSepolia);
+    ///
+    /// let result = ops
+    ///     .create_transfer_with_estimate(builder, FeeSelection::Medium)
+    ///     .await?;
+    /// println!(
+    ///     "Transaction {} with network fee {:?}",
+    ///     result.transaction.id, result.estimate.network_fee
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_transfer_with_estimate(
+        &self,
+        builder: CreateTransferTransactionRequestBuilder,
+        selection: FeeSelection,
+    ) -> CircleResult<TransferWithEstimate> {
+        builder.validate()?;
+
+        let estimate: FeeEstimate = self
+            .post(
+                "/v1/w3s/transactions/transfer/estimateFee",
+                &builder.estimate_request(),
+            )
+            .await?;
+        let estimate = selection.select(estimate);
+        let fee = estimate
+            .to_fee_config()
+            .unwrap_or_else(|| FeeConfig::Level(selection.fee_level()));
+
+        let transaction = self
+            .create_dev_transfer_transaction(builder.fee(fee.clone()))
+            .await?;
+        Ok(TransferWithEstimate {
+            estimate,
+            fee,
+            transaction,
+        })
+    }
+
+    /// Transfer a Circle stablecoin (USDC or EURC)
+    ///
+    /// Looks up the stablecoin's token address on `blockchain` and submits a
+    /// transfer with a fresh idempotency key and `MEDIUM` fee level.
+    ///
+    /// # Arguments
+    ///
+    /// * `wallet_id` - Source wallet ID
+    /// * `blockchain` - Blockchain to transfer on
+    /// * `stablecoin` - The stablecoin to send
+    /// * `destination_address` - Recipient address
+    /// * `amount` - Amount in whole tokens (e.g., "10.5") or a [`TokenAmount`](crate::types::TokenAmount)
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Config` if the stablecoin is not issued on `blockchain`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+    /// use inf_circle_sdk::types::{Blockchain, Stablecoin};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ops = CircleOps::new(None)?;
+    ///
+    /// let response = ops
+    ///     .transfer_stablecoin("wallet-id", Blockchain::BaseSepolia, Stablecoin::Usdc, "0x1234...", "25")
+    ///     .await?;
+    /// println!("Transaction ID: {}", response.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn transfer_stablecoin(
+        &self,
+        wallet_id: &str,
+        blockchain: Blockchain,
+        stablecoin: Stablecoin,
+        destination_address: &str,
+        amount: impl Into<String>,
+    ) -> CircleResult<CreateTransferTransactionResponse> {
+        let token_address = stablecoin.address(&blockchain).ok_or_else(|| {
+            CircleError::Config(format!(
+                "{} is not available on {}",
+                stablecoin.symbol(),
+                blockchain.as_str()
+            ))
+        })?;
+
+        let builder = CreateTransferTransactionRequestBuilder::new()
+            .wallet_id(wallet_id.to_string())
+            .destination_address(destination_address.to_string())
+            .amounts([amount])
+            .token_address(token_address.to_string())
+            .blockchain(blockchain)
+            .fee_level(FeeLevel::Medium)
+            .idempotency_key(Uuid::new_v4().to_string())
+            .build();
+
+        self.create_dev_transfer_transaction(builder).await
+    }
+
+    /// Transfer USDC from a developer-controlled wallet
+    ///
+    /// Shorthand for [`transfer_stablecoin`](Self::transfer_stablecoin) with [`Stablecoin::Usdc`].
+    pub async fn transfer_usdc(
+        &self,
+        wallet_id: &str,
+        blockchain: Blockchain,
+        destination_address: &str,
+        amount: impl Into<String>,
+    ) -> CircleResult<CreateTransferTransactionResponse> {
+        self.transfer_stablecoin(
+            wallet_id,
+            blockchain,
+            Stablecoin::Usdc,
+            destination_address,
+            amount,
+        )
+        .await
+    }
+
+    /// Transfer EURC from a developer-controlled wallet
+    ///
+    /// Shorthand for [`transfer_stablecoin`](Self::transfer_stablecoin) with [`Stablecoin::Eurc`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+    /// use inf_circle_sdk::types::Blockchain;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ops = CircleOps::new(None)?;
+    /// let response = ops
+    ///     .transfer_eurc("wallet-id", Blockchain::EthSepolia, "0x1234...", "12.50")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn transfer_eurc(
+        &self,
+        wallet_id: &str,
+        blockchain: Blockchain,
+        destination_address: &str,
+        amount: impl Into<String>,
+    ) -> CircleResult<CreateTransferTransactionResponse> {
+        self.transfer_stablecoin(
+            wallet_id,
+            blockchain,
+            Stablecoin::Eurc,
+            destination_address,
+            amount,
+        )
+        .await
     }
 
     /// Query a contract
@@ -494,13 +929,17 @@ impl CircleOps {
     ///
     /// Returns transaction details including the transaction ID and state.
     ///
+    /// If Circle rejects a reused idempotency key and the builder has a
+    /// `ref_id`, the original transaction is returned as for
+    /// [`create_dev_transfer_transaction`](Self::create_dev_transfer_transaction#retries);
+    /// without a `ref_id` the `409` is returned.
+    ///
     /// # Example - Call ERC-20 Approve Function
     ///
     /// ```rust,no_run
     /// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
     /// use inf_circle_sdk::dev_wallet::ops::create_contract_transaction::CreateContractExecutionTransactionRequestBuilder;
     /// use inf_circle_sdk::dev_wallet::dto::FeeLevel;
-    /// use uuid::Uuid;
     ///
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let ops = CircleOps::new(None)?;
@@ -510,7 +949,6 @@ impl CircleOps {
     /// let builder = CreateContractExecutionTransactionRequestBuilder::new(
     ///     "wallet-id".to_string(),
     ///     "0x1c7D4B196Cb0C7B01d743Fbc6116a902379C7238".to_string(),
-    ///     Uuid::new_v4().to_string()
     /// )
     /// .abi_function_signature("approve(address,uint256)".to_string())
     /// .abi_parameters(vec![
@@ -529,27 +967,33 @@ impl CircleOps {
         &self,
         builder: CreateContractExecutionTransactionRequestBuilder,
     ) -> CircleResult<CreateContractExecutionTransactionResponse> {
+        builder.validate()?;
+
         let entity_secret_ciphertext = self.entity_secret()?;
 
-        let request = CreateContractExecutionTransactionRequest {
-            wallet_id: builder.wallet_id,
-            entity_secret_ciphertext,
-            contract_address: builder.contract_address,
-            idempotency_key: builder.idempotency_key,
-            abi_function_signature: builder.abi_function_signature,
-            abi_parameters: builder.abi_parameters,
-            call_data: builder.call_data,
-            amount: builder.amount,
-            fee_level: builder.fee_level,
-            gas_limit: builder.gas_limit,
-            gas_price: builder.gas_price,
-            max_fee: builder.max_fee,
-            priority_fee: builder.priority_fee,
-            ref_id: builder.ref_id,
-        };
+        let source = ConflictSource::wallet(
+            &builder.wallet_id,
+            builder.ref_id.clone(),
+            ConflictPayload::ContractExecution {
+                contract_address: builder.contract_address.clone(),
+                amount: builder.amount.clone(),
+            },
+        );
+        let request = builder.into_request(entity_secret_ciphertext, generate_uuid);
 
-        self.post("/v1/w3s/developer/transactions/contractExecution", &request)
+        match self
+            .post("/v1/w3s/developer/transactions/contractExecution", &request)
             .await
+        {
+            Err(conflict @ CircleError::Api { status: 409, .. }) => {
+                let transaction = self.find_conflicting_transaction(conflict, source).await?;
+                Ok(CreateContractExecutionTransactionResponse {
+                    id: transaction.id,
+                    state: transaction.state,
+                })
+            }
+            result => result,
+        }
     }
 
     /// Create a wallet upgrade transaction
@@ -566,13 +1010,17 @@ impl CircleOps {
     ///
     /// Returns transaction details including the transaction ID and state.
     ///
+    /// If Circle rejects a reused idempotency key and the builder has a
+    /// `ref_id`, the original transaction is returned as for
+    /// [`create_dev_transfer_transaction`](Self::create_dev_transfer_transaction#retries);
+    /// without a `ref_id` the `409` is returned.
+    ///
     /// # Example
     ///
     /// ```rust,no_run
     /// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
     /// use inf_circle_sdk::dev_wallet::ops::create_wallet_upgrade_transaction::CreateWalletUpgradeTransactionRequestBuilder;
     /// use inf_circle_sdk::dev_wallet::dto::{FeeLevel, ScaCore};
-    /// use uuid::Uuid;
     ///
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let ops = CircleOps::new(None)?;
@@ -580,7 +1028,6 @@ impl CircleOps {
     /// let builder = CreateWalletUpgradeTransactionRequestBuilder::new(
     ///     "wallet-id".to_string(),
     ///     ScaCore::Circle6900SingleownerV3,
-    ///     Uuid::new_v4().to_string()
     /// )
     /// .fee_level(FeeLevel::Medium)
     /// .ref_id("upgrade-to-v1".to_string())
@@ -595,23 +1042,30 @@ impl CircleOps {
         &self,
         builder: CreateWalletUpgradeTransactionRequestBuilder,
     ) -> CircleResult<CreateWalletUpgradeTransactionResponse> {
+        builder.validate()?;
+
         let entity_secret_ciphertext = self.entity_secret()?;
 
-        let request = CreateWalletUpgradeTransactionRequest {
-            wallet_id: builder.wallet_id,
-            entity_secret_ciphertext,
-            new_sca_core: builder.new_sca_core.as_str().to_string(),
-            idempotency_key: builder.idempotency_key,
-            fee_level: builder.fee_level,
-            gas_limit: builder.gas_limit,
-            gas_price: builder.gas_price,
-            max_fee: builder.max_fee,
-            priority_fee: builder.priority_fee,
-            ref_id: builder.ref_id,
-        };
+        let source = ConflictSource::wallet(
+            &builder.wallet_id,
+            builder.ref_id.clone(),
+            ConflictPayload::WalletUpgrade,
+        );
+        let request = builder.into_request(entity_secret_ciphertext, generate_uuid);
 
-        self.post("/v1/w3s/developer/transactions/walletUpgrade", &request)
+        match self
+            .post("/v1/w3s/developer/transactions/walletUpgrade", &request)
             .await
+        {
+            Err(conflict @ CircleError::Api { status: 409, .. }) => {
+                let transaction = self.find_conflicting_transaction(conflict, source).await?;
+                Ok(CreateWalletUpgradeTransactionResponse {
+                    id: transaction.id,
+                    state: transaction.state,
+                })
+            }
+            result => result,
+        }
     }
 
     /// Cancel a transaction
@@ -633,15 +1087,11 @@ impl CircleOps {
     /// ```rust,no_run
     /// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
     /// use inf_circle_sdk::dev_wallet::ops::cancel_transaction::CancelTransactionRequestBuilder;
-    /// use uuid::Uuid;
     ///
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let ops = CircleOps::new(None)?;
     ///
-    /// let builder = CancelTransactionRequestBuilder::new(
-    ///     "transaction-id-to-cancel".to_string(),
-    ///     Uuid::new_v4().to_string()
-    /// )
+    /// let builder = CancelTransactionRequestBuilder::new("transaction-id-to-cancel".to_string())
     /// .build();
     ///
     /// let response = ops.cancel_dev_transaction(builder).await?;
@@ -659,15 +1109,12 @@ impl CircleOps {
     ) -> CircleResult<CancelTransactionResponse> {
         let entity_secret_ciphertext = self.entity_secret()?;
 
-        let request = CancelTransactionRequest {
-            entity_secret_ciphertext,
-            idempotency_key: builder.idempotency_key,
-        };
-
         let path = format!(
             "/v1/w3s/developer/transactions/{}/cancel",
             builder.transaction_id
         );
+        let request = builder.into_request(entity_secret_ciphertext, generate_uuid);
+
         self.post(&path, &request).await
     }
 
@@ -690,15 +1137,11 @@ impl CircleOps {
     /// ```rust,no_run
     /// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
     /// use inf_circle_sdk::dev_wallet::ops::accelerate_transaction::AccelerateTransactionRequestBuilder;
-    /// use uuid::Uuid;
     ///
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let ops = CircleOps::new(None)?;
     ///
-    /// let builder = AccelerateTransactionRequestBuilder::new(
-    ///     "slow-transaction-id".to_string(),
-    ///     Uuid::new_v4().to_string()
-    /// )
+    /// let builder = AccelerateTransactionRequestBuilder::new("slow-transaction-id".to_string())
     /// .build();
     ///
     /// let response = ops.accelerate_dev_transaction(builder).await?;
@@ -716,25 +1159,210 @@ impl CircleOps {
     ) -> CircleResult<AccelerateTransactionResponse> {
         let entity_secret_ciphertext = self.entity_secret()?;
 
-        let request = AccelerateTransactionRequest {
-            entity_secret_ciphertext,
-            idempotency_key: builder.idempotency_key,
-        };
-
         let path = format!(
             "/v1/w3s/developer/transactions/{}/accelerate",
             builder.transaction_id
         );
+        let request = builder.into_request(entity_secret_ciphertext, generate_uuid);
+
         self.post(&path, &request).await
     }
 }
 
+/// How far back an idempotency conflict is looked up in the source wallet's transactions
+pub const CONFLICT_LOOKBACK: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Most pages of 50 transactions an idempotency conflict lookup fetches
+pub const CONFLICT_MAX_PAGES: usize = 20;
+
+/// Where to look for the transaction behind an idempotency conflict
+struct ConflictSource {
+    wallet_id: Option<String>,
+    wallet_address: Option<String>,
+    blockchain: Option<Blockchain>,
+    ref_id: Option<String>,
+    payload: ConflictPayload,
+}
+
+/// What the conflicting request asked for, checked against the candidate original
+enum ConflictPayload {
+    Transfer {
+        destination_address: String,
+        amounts: Vec<String>,
+        token_id: Option<String>,
+        token_address: Option<String>,
+    },
+    ContractExecution {
+        contract_address: String,
+        amount: Option<String>,
+    },
+    WalletUpgrade,
+}
+
+impl ConflictSource {
+    fn transfer(builder: &CreateTransferTransactionRequestBuilder) -> Self {
+        Self {
+            wallet_id: builder.wallet_id.clone(),
+            wallet_address: builder.wallet_address.clone(),
+            blockchain: builder.blockchain.clone(),
+            ref_id: builder.ref_id.clone(),
+            payload: ConflictPayload::Transfer {
+                destination_address: builder.destination_address.clone(),
+                amounts: builder.amounts.clone(),
+                token_id: builder.token_id.clone(),
+                token_address: builder.token_address.clone(),
+            },
+        }
+    }
+
+    fn wallet(wallet_id: &str, ref_id: Option<String>, payload: ConflictPayload) -> Self {
+        Self {
+            wallet_id: Some(wallet_id.to_string()),
+            wallet_address: None,
+            blockchain: None,
+            ref_id,
+            payload,
+        }
+    }
+
+    /// Whether `transaction` carries `ref_id`, was sent from this source and
+    /// does what the conflicting request asked for
+    fn matches(&self, ref_id: &str, transaction: &Transaction) -> bool {
+        transaction.ref_id.as_deref() == Some(ref_id)
+            && self
+                .wallet_id
+                .as_ref()
+                .is_none_or(|wallet_id| transaction.wallet_id.as_ref() == Some(wallet_id))
+            && self.wallet_address.as_ref().is_none_or(|address| {
+                transaction
+                    .source_address
+                    .as_ref()
+                    .is_some_and(|source| same_address(source, address))
+            })
+            && self.payload.matches(transaction)
+    }
+}
+
+impl ConflictPayload {
+    /// Whether `transaction` has the same destination, amounts and token
+    ///
+    /// A token address is only compared when Circle reports one for the
+    /// transaction, since transfers identify their token by ID.
+    fn matches(&self, transaction: &Transaction) -> bool {
+        match self {
+            ConflictPayload::Transfer {
+                destination_address,
+                amounts,
+                token_id,
+                token_address,
+            } => {
+                let reported_token_address =
+                    transaction.contract_address.as_deref().or_else(|| {
+                        transaction
+                            .extra
+                            .get("tokenAddress")
+                            .and_then(|address| address.as_str())
+                    });
+                transaction
+                    .destination_address
+                    .as_deref()
+                    .is_some_and(|address| same_address(address, destination_address))
+                    && transaction
+                        .amounts
+                        .as_ref()
+                        .is_some_and(|actual| same_amounts(actual, amounts))
+                    && token_id
+                        .as_ref()
+                        .is_none_or(|token_id| transaction.token_id.as_ref() == Some(token_id))
+                    && token_address
+                        .as_deref()
+                        .zip(reported_token_address)
+                        .is_none_or(|(expected, reported)| same_address(expected, reported))
+            }
+            ConflictPayload::ContractExecution {
+                contract_address,
+                amount,
+            } => {
+                let amount = amount.as_deref().unwrap_or("0");
+                transaction
+                    .contract_address
+                    .as_deref()
+                    .is_some_and(|address| same_address(address, contract_address))
+                    && transaction.amounts.as_ref().is_none_or(|actual| {
+                        (actual.is_empty() && amount == "0")
+                            || same_amounts(actual, &[amount.to_string()])
+                    })
+            }
+            ConflictPayload::WalletUpgrade => true,
+        }
+    }
+}
+
+/// Whether two addresses are equal, ignoring case only for hex (EVM) addresses
+fn same_address(a: &str, b: &str) -> bool {
+    if a.starts_with("0x") && b.starts_with("0x") {
+        a.eq_ignore_ascii_case(b)
+    } else {
+        a == b
+    }
+}
+
+/// Whether two lists of decimal amounts are numerically equal, pairwise
+fn same_amounts(actual: &[String], expected: &[String]) -> bool {
+    actual.len() == expected.len()
+        && actual
+            .iter()
+            .zip(expected)
+            .all(|(a, b)| compare_decimal_str(a, b) == Some(std::cmp::Ordering::Equal))
+}
+
+/// The single wallet in `wallet_set_id` on `blockchain` whose ref ID is exactly `ref_id`
+async fn find_wallet(
+    view: &CircleView,
+    wallet_set_id: &str,
+    blockchain: &Blockchain,
+    ref_id: &str,
+) -> CircleResult<Option<DevWallet>> {
+    let params = ListDevWalletsParamsBuilder::new()
+        .wallet_set_id(wallet_set_id.to_string())
+        .blockchain(blockchain.as_str().to_string())
+        .ref_id(ref_id.to_string())
+        .page_size(10)
+        .build();
+    let mut wallets = view.list_wallets(params).await?.items;
+    wallets.retain(|wallet| {
+        wallet.ref_id.as_deref() == Some(ref_id) && &wallet.blockchain == blockchain
+    });
+
+    match wallets.len() {
+        0 => Ok(None),
+        1 => Ok(wallets.pop()),
+        count => Err(CircleError::Config(format!(
+            "{} wallets on {} share ref ID {}",
+            count,
+            blockchain.as_str(),
+            ref_id
+        ))),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
-        dev_wallet::{dto::AccountType, ops::create_dev_wallet::CreateDevWalletRequestBuilder},
+        dev_wallet::{
+            dto::{AccountType, FeeConfig, FeeSelection},
+            ops::{
+                create_dev_wallet::CreateDevWalletRequestBuilder,
+                create_transfer_transaction::CreateTransferTransactionRequestBuilder,
+                ensure_wallet::{ensure_wallet_idempotency_key, EnsureWalletOptions},
+            },
+        },
+        helper::{CircleError, ENTITY_SECRET_PLACEHOLDER, GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER},
+        testing::{fixtures, MockCircle},
         types::Blockchain,
     };
+    use mockito::Matcher;
+    use serde_json::json;
 
     #[test]
     fn test_builder_pattern() {
@@ -770,4 +1398,393 @@ mod tests {
 
         assert_eq!(builder.idempotency_key, Some(custom_key.to_string()));
     }
+
+    #[test]
+    fn test_preview_shows_idempotency_key() {
+        let builder = CreateDevWalletRequestBuilder::new(
+            "test-wallet-set-id".to_string(),
+            vec![Blockchain::EthSepolia],
+        )
+        .unwrap();
+
+        let preview = builder.preview().unwrap();
+        assert!(preview.contains(GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER));
+        assert!(preview.contains(ENTITY_SECRET_PLACEHOLDER));
+
+        let preview = builder
+            .idempotency_key("custom-test-key-123".to_string())
+            .preview()
+            .unwrap();
+        assert!(preview.contains("\"idempotencyKey\": \"custom-test-key-123\""));
+    }
+
+    #[tokio::test]
+    async fn test_create_transfer_with_estimate() {
+        let mut circle = MockCircle::start().await;
+        let estimate = circle
+            .server()
+            .mock("POST", "/v1/w3s/transactions/transfer/estimateFee")
+            .match_body(Matcher::PartialJson(json!({
+                "walletId": fixtures::WALLET_ID,
+                "blockchain": "ETH-SEPOLIA",
+                "amounts": ["0.1"]
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "data": fixtures::fee_estimate() }).to_string())
+            .create_async()
+            .await;
+        let transfer = circle
+            .server()
+            .mock("POST", "/v1/w3s/developer/transactions/transfer")
+            .match_body(Matcher::PartialJson(json!({
+                "maxFee": "4.2",
+                "priorityFee": "1.2",
+                "gasLimit": "21000"
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "data": fixtures::create_transaction_response() }).to_string())
+            .create_async()
+            .await;
+
+        let builder = CreateTransferTransactionRequestBuilder::new()
+            .wallet_id(fixtures::WALLET_ID.to_string())
+            .destination_address("0x4444444444444444444444444444444444444444".to_string())
+            .amounts(vec!["0.1".to_string()])
+            .blockchain(Blockchain::EthSepolia);
+        let result = circle
+            .ops()
+            .create_transfer_with_estimate(builder, FeeSelection::High)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result.fee,
+            FeeConfig::eip1559("4.2".to_string(), "1.2".to_string(), "21000".to_string())
+        );
+        assert_eq!(result.estimate.network_fee.as_deref(), Some("0.0000882"));
+        assert_eq!(result.transaction.state, "INITIATED");
+        estimate.assert_async().await;
+        transfer.assert_async().await;
+    }
+
+    /// A transfer rejected with `409` because `idempotency_key` was used before
+    async fn mock_transfer_conflict(circle: &mut MockCircle, hits: usize) -> mockito::Mock {
+        circle
+            .server()
+            .mock("POST", "/v1/w3s/developer/transactions/transfer")
+            .match_body(Matcher::PartialJson(
+                json!({ "idempotencyKey": "transfer-1" }),
+            ))
+            .with_status(409)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "code": 409, "message": "Idempotency conflict" }).to_string())
+            .expect(hits)
+            .create_async()
+            .await
+    }
+
+    fn conflicting_transfer(ref_id: Option<&str>) -> CreateTransferTransactionRequestBuilder {
+        let builder = CreateTransferTransactionRequestBuilder::new()
+            .wallet_id(fixtures::WALLET_ID.to_string())
+            .destination_address("0x4444444444444444444444444444444444444444".to_string())
+            .amounts(vec!["1".to_string()])
+            .blockchain(Blockchain::EthSepolia)
+            .idempotency_key("transfer-1".to_string());
+        match ref_id {
+            Some(ref_id) => builder.ref_id(ref_id.to_string()),
+            None => builder,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_transfer_idempotency_conflict_returns_original() {
+        let mut circle = MockCircle::start().await;
+        let mut other = fixtures::transaction();
+        other.id = "other-transaction".to_string();
+        other.ref_id = Some("payout-7".to_string());
+        let listed = circle
+            .server()
+            .mock("GET", "/v1/w3s/transactions")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("walletIds".to_string(), fixtures::WALLET_ID.to_string()),
+                Matcher::Regex("from=".to_string()),
+            ]))
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({ "data": { "transactions": [other, fixtures::transaction()] } }).to_string(),
+            )
+            .create_async()
+            .await;
+        let transfer = mock_transfer_conflict(&mut circle, 1).await;
+
+        let original = circle
+            .ops()
+            .create_dev_transfer_transaction(conflicting_transfer(Some("test-transfer")))
+            .await
+            .unwrap();
+        assert_eq!(original.id, fixtures::TRANSACTION_ID);
+        assert_eq!(original.state, "COMPLETE");
+        listed.assert_async().await;
+        transfer.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_transfer_idempotency_conflict_follows_pages() {
+        let mut circle = MockCircle::start().await;
+        let newer: Vec<_> = (0..50)
+            .map(|i| {
+                let mut transaction = fixtures::transaction();
+                transaction.id = format!("newer-{}", i);
+                transaction.ref_id = Some(format!("payout-{}", i));
+                transaction
+            })
+            .collect();
+        let second = circle
+            .server()
+            .mock("GET", "/v1/w3s/transactions")
+            .match_query(Matcher::UrlEncoded(
+                "pageAfter".to_string(),
+                "newer-49".to_string(),
+            ))
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "data": { "transactions": [fixtures::transaction()] } }).to_string())
+            .create_async()
+            .await;
+        // Created after the cursor mock, so only the first request lands here
+        let first = circle
+            .server()
+            .mock("GET", "/v1/w3s/transactions")
+            .match_query(Matcher::Any)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "data": { "transactions": newer } }).to_string())
+            .create_async()
+            .await;
+        let transfer = mock_transfer_conflict(&mut circle, 1).await;
+
+        let original = circle
+            .ops()
+            .create_dev_transfer_transaction(conflicting_transfer(Some("test-transfer")))
+            .await
+            .unwrap();
+        assert_eq!(original.id, fixtures::TRANSACTION_ID);
+        first.assert_async().await;
+        second.assert_async().await;
+        transfer.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_transfer_idempotency_conflict_without_match() {
+        let mut circle = MockCircle::start().await;
+        let listed = circle
+            .server()
+            .mock("GET", "/v1/w3s/transactions")
+            .match_query(Matcher::Any)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "data": { "transactions": [fixtures::transaction()] } }).to_string())
+            .create_async()
+            .await;
+        let transfer = mock_transfer_conflict(&mut circle, 1).await;
+
+        let result = circle
+            .ops()
+            .create_dev_transfer_transaction(conflicting_transfer(Some("payout-8")))
+            .await;
+        assert!(matches!(result, Err(CircleError::Api { status: 409, .. })));
+        listed.assert_async().await;
+        transfer.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_transfer_idempotency_conflict_with_different_payload() {
+        let mut circle = MockCircle::start().await;
+        let mut other_amount = fixtures::transaction();
+        other_amount.amounts = Some(vec!["2".to_string()]);
+        let mut other_destination = fixtures::transaction();
+        other_destination.destination_address =
+            Some("0x5555555555555555555555555555555555555555".to_string());
+        let mut other_token = fixtures::transaction();
+        other_token.token_id = Some("other-token".to_string());
+        let listed = circle
+            .server()
+            .mock("GET", "/v1/w3s/transactions")
+            .match_query(Matcher::Any)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({ "data": { "transactions": [other_amount, other_destination, other_token] } })
+                    .to_string(),
+            )
+            .create_async()
+            .await;
+        let transfer = mock_transfer_conflict(&mut circle, 1).await;
+
+        let result = circle
+            .ops()
+            .create_dev_transfer_transaction(
+                conflicting_transfer(Some("test-transfer"))
+                    .token_id(fixtures::TOKEN_ID.to_string()),
+            )
+            .await;
+        assert!(matches!(result, Err(CircleError::Api { status: 409, .. })));
+        listed.assert_async().await;
+        transfer.assert_async().await;
+    }
+
+    #[test]
+    fn test_conflict_payload_matches_equal_amounts() {
+        let builder = conflicting_transfer(Some("test-transfer")).amounts(["1.0"]);
+        let source = super::ConflictSource::transfer(&builder);
+        assert!(source.matches("test-transfer", &fixtures::transaction()));
+        assert!(!source.matches("other-ref", &fixtures::transaction()));
+    }
+
+    #[tokio::test]
+    async fn test_transfer_idempotency_conflict_without_ref_id() {
+        let mut circle = MockCircle::start().await;
+        let listed = circle
+            .server()
+            .mock("GET", "/v1/w3s/transactions")
+            .match_query(Matcher::Any)
+            .expect(0)
+            .create_async()
+            .await;
+        let transfer = mock_transfer_conflict(&mut circle, 1).await;
+
+        let result = circle
+            .ops()
+            .create_dev_transfer_transaction(conflicting_transfer(None))
+            .await;
+        assert!(matches!(result, Err(CircleError::Api { status: 409, .. })));
+        listed.assert_async().await;
+        transfer.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_validate_destination() {
+        let mut circle = MockCircle::start().await;
+        let validate = circle
+            .server()
+            .mock("POST", "/v1/w3s/transactions/validateAddress")
+            .match_body(Matcher::Json(json!({
+                "address": "0x000000000000000000000000000000000000dead",
+                "blockchain": "ETH-SEPOLIA"
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "data": { "isValid": false } }).to_string())
+            .create_async()
+            .await;
+        let transfer = circle
+            .server()
+            .mock("POST", "/v1/w3s/developer/transactions/transfer")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let builder = CreateTransferTransactionRequestBuilder::new()
+            .wallet_id(fixtures::WALLET_ID.to_string())
+            .destination_address("0x000000000000000000000000000000000000dead".to_string())
+            .amounts(vec!["0.1".to_string()])
+            .blockchain(Blockchain::EthSepolia)
+            .validate_destination(true);
+        let err = circle
+            .ops()
+            .create_dev_transfer_transaction(builder.clone())
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid configuration: Invalid destination address 0x000000000000000000000000000000000000dead on ETH-SEPOLIA"
+        );
+
+        // Malformed addresses are rejected locally without asking Circle
+        let malformed = builder
+            .clone()
+            .destination_address("0xnot-an-address".to_string());
+        assert!(matches!(
+            circle
+                .ops()
+                .create_dev_transfer_transaction(malformed)
+                .await,
+            Err(CircleError::Config(_))
+        ));
+        validate.assert_async().await;
+        transfer.assert_async().await;
+
+        let without_chain = CreateTransferTransactionRequestBuilder {
+            blockchain: None,
+            ..builder
+        };
+        assert!(matches!(
+            circle
+                .ops()
+                .create_dev_transfer_transaction(without_chain)
+                .await,
+            Err(CircleError::Config(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_ensure_wallet_relists_after_conflict() {
+        let mut circle = MockCircle::start().await;
+        let mut wallet = fixtures::dev_wallet();
+        wallet.ref_id = Some("customer-42".to_string());
+
+        let by_ref_id = Matcher::AllOf(vec![
+            Matcher::UrlEncoded("refId".to_string(), "customer-42".to_string()),
+            Matcher::UrlEncoded("blockchain".to_string(), "ETH-SEPOLIA".to_string()),
+        ]);
+        let empty = circle
+            .server()
+            .mock("GET", "/v1/w3s/wallets")
+            .match_query(by_ref_id.clone())
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "data": { "wallets": [] } }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+        let listed = circle
+            .server()
+            .mock("GET", "/v1/w3s/wallets")
+            .match_query(by_ref_id)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "data": { "wallets": [wallet] } }).to_string())
+            .create_async()
+            .await;
+        // Another caller won the race with the same idempotency key
+        let create = circle
+            .server()
+            .mock("POST", "/v1/w3s/developer/wallets")
+            .match_body(Matcher::PartialJson(json!({
+                "idempotencyKey": ensure_wallet_idempotency_key(
+                    fixtures::WALLET_SET_ID,
+                    &Blockchain::EthSepolia,
+                    "customer-42",
+                ),
+                "metadata": [{ "refId": "customer-42" }]
+            })))
+            .with_status(409)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "code": 409, "message": "Idempotency conflict" }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let options = EnsureWalletOptions::new().relist_timeout(std::time::Duration::from_secs(1));
+        let ensured = circle
+            .ops()
+            .ensure_wallet(
+                fixtures::WALLET_SET_ID,
+                &Blockchain::EthSepolia,
+                "customer-42",
+                options,
+            )
+            .await
+            .unwrap();
+        assert_eq!(ensured.id, fixtures::WALLET_ID);
+        empty.assert_async().await;
+        listed.assert_async().await;
+        create.assert_async().await;
+    }
 }
@@ -4,19 +4,20 @@ use crate::{
     circle_view::circle_view::CircleView,
     dev_wallet::{
         dto::{
-            DevWalletResponse, EstimateContractExecutionFeeBody,
-            EstimateContractExecutionFeeResponse, EstimateTransferFeeRequest,
-            EstimateTransferFeeResponse, ListTransactionsParams, ListWalletsWithBalancesParams,
-            NftsResponse, QueryParams, RequestTestnetTokensRequest, TokenBalancesResponse,
+            AbiParameter, AccelerationFeeEstimate, DevWalletResponse, EntityPublicKeyResponse,
+            EstimateContractExecutionFeeBody, EstimateTransferFeeRequest, FeeEstimate,
+            ListTransactionsParams, ListWalletsWithBalancesParams, NftsResponse, QueryParams,
+            RequestTestnetTokensRequest, Token, TokenBalancesResponse, TokenResponse, Transaction,
             TransactionResponse, TransactionsResponse, ValidateAddressBody,
-            ValidateAddressResponse, WalletsWithBalancesResponse,
+            ValidateAddressResponse, WalletWithBalances, WalletsWithBalancesResponse,
         },
         views::{
             estimate_contract_execution_fee::EstimateContractExecutionFeeBodyBuilder,
+            estimate_transfer_fee::EstimateTransferFeeRequestBuilder,
             validate_address::ValidateAddressBodyBuilder,
         },
     },
-    helper::CircleResult,
+    helper::{page::Page, CircleError, CircleResult},
 };
 
 // Re-use the Wallet struct from CircleOps since it's the same
@@ -27,6 +28,11 @@ impl CircleView {
     ///
     /// Retrieves a list of all wallets that match the specified filter parameters.
     ///
+    /// Returns a [`Page`] with the cursors of the neighbouring pages. The
+    /// cursors come from the wallets Circle returned, before the client-side
+    /// state filter is applied, so a filtered page may be short or empty while
+    /// more pages exist.
+    ///
     /// # Arguments
     ///
     /// * `params` - Filter parameters including wallet set ID, blockchain, pagination, etc.
@@ -45,8 +51,8 @@ impl CircleView {
     ///     .page_size(10)
     ///     .build();
     ///
-    /// let response = view.list_wallets(params).await?;
-    /// for wallet in response.wallets {
+    /// let page = view.list_wallets(params).await?;
+    /// for wallet in &page {
     ///     println!("Wallet: {} - {}", wallet.id, wallet.address);
     /// }
     /// # Ok(())
@@ -55,8 +61,14 @@ impl CircleView {
     pub async fn list_wallets(
         &self,
         params: ListDevWalletsParams,
-    ) -> CircleResult<DevWalletsResponse> {
-        self.get_with_params("/v1/w3s/wallets", &params).await
+    ) -> CircleResult<Page<DevWallet>> {
+        let response: DevWalletsResponse = self.get_with_params("/v1/w3s/wallets", &params).await?;
+        let mut page = Page::new(response.wallets, &params.pagination);
+        if let Some(state) = params.state {
+            page.items
+                .retain(|wallet| wallet.wallet_state() == Some(state));
+        }
+        Ok(page)
     }
 
     /// List wallets with token balances
@@ -64,6 +76,9 @@ impl CircleView {
     /// Retrieves a list of all wallets with token balances that fit the specified parameters.
     /// This is useful for finding wallets that hold specific tokens or amounts.
     ///
+    /// Returns a [`Page`] with the cursors of the neighbouring pages; see
+    /// [`page`](crate::helper::page) for how they are derived.
+    ///
     /// # Arguments
     ///
     /// * `params` - Filter parameters including wallet set ID, blockchain, token address, etc.
@@ -85,7 +100,7 @@ impl CircleView {
     ///     .build();
     ///
     /// let response = view.list_wallets_with_token_balances(params).await?;
-    /// for wallet in response.wallets {
+    /// for wallet in response {
     ///     println!("Wallet {} on {}", wallet.address, wallet.blockchain);
     /// }
     /// # Ok(())
@@ -94,9 +109,11 @@ impl CircleView {
     pub async fn list_wallets_with_token_balances(
         &self,
         params: ListWalletsWithBalancesParams,
-    ) -> CircleResult<WalletsWithBalancesResponse> {
-        self.get_with_params("/v1/w3s/wallets/balances", &params)
-            .await
+    ) -> CircleResult<Page<WalletWithBalances>> {
+        let response: WalletsWithBalancesResponse = self
+            .get_with_params("/v1/w3s/wallets/balances", &params)
+            .await?;
+        Ok(Page::new(response.wallets, &params.pagination))
     }
 
     /// Get a specific wallet
@@ -210,6 +227,9 @@ impl CircleView {
     /// Retrieves a list of all transactions that fit the specified parameters.
     /// Supports filtering by wallet, blockchain, state, type, and date range.
     ///
+    /// Returns a [`Page`] with the cursors of the neighbouring pages; see
+    /// [`page`](crate::helper::page) for how they are derived.
+    ///
     /// # Arguments
     ///
     /// * `params` - Filter parameters including wallet IDs, blockchain, state, pagination, etc.
@@ -232,7 +252,7 @@ impl CircleView {
     ///     .build();
     ///
     /// let response = view.list_transactions(params).await?;
-    /// for tx in response.transactions {
+    /// for tx in response {
     ///     println!("Transaction {}: {} - Amounts: {:?}", tx.id, tx.state, tx.amounts);
     /// }
     /// # Ok(())
@@ -241,8 +261,11 @@ impl CircleView {
     pub async fn list_transactions(
         &self,
         params: ListTransactionsParams,
-    ) -> CircleResult<TransactionsResponse> {
-        self.get_with_params("/v1/w3s/transactions", &params).await
+    ) -> CircleResult<Page<Transaction>> {
+        let response: TransactionsResponse = self
+            .get_with_params("/v1/w3s/transactions", &params)
+            .await?;
+        Ok(Page::new(response.transactions, &params.pagination))
     }
 
     /// Get a specific transaction
@@ -277,6 +300,110 @@ impl CircleView {
         self.get(&path).await
     }
 
+    /// Get a token
+    ///
+    /// Resolves a Circle token ID, such as the `token_id` of a transaction or
+    /// balance, to its symbol, decimals, blockchain and contract address.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_id` - The Circle token ID
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_view::circle_view::CircleView;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let view = CircleView::new()?;
+    ///
+    /// let tx = view.get_transaction("transaction-id").await?;
+    /// if let Some(token_id) = tx.transaction.token_id {
+    ///     let token = view.get_token(&token_id).await?.token;
+    ///     println!("{:?} on {} ({:?} decimals)", token.symbol, token.blockchain, token.decimals);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_token(&self, token_id: &str) -> CircleResult<TokenResponse> {
+        let path = format!("/v1/w3s/tokens/{}", token_id);
+        self.get(&path).await
+    }
+
+    /// Get the entity public key
+    ///
+    /// Retrieves the RSA public key Circle uses to decrypt entity secret
+    /// ciphertexts: the key otherwise configured as `CIRCLE_PUBLIC_KEY`. See
+    /// [`CircleOps::bootstrap`](crate::circle_ops::circler_ops::CircleOps::bootstrap)
+    /// to build a write client without configuring it.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_view::circle_view::CircleView;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let view = CircleView::new()?;
+    ///
+    /// let public_key = view.get_public_key().await?.public_key;
+    /// println!("{}", public_key);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_public_key(&self) -> CircleResult<EntityPublicKeyResponse> {
+        self.get("/v1/w3s/config/entity/publicKey").await
+    }
+
+    /// Get several tokens
+    ///
+    /// Looks up each distinct token ID with [`get_token`](Self::get_token),
+    /// preserving the order in which the IDs first appear. Useful for
+    /// resolving every `token_id` of a page of transactions at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_ids` - The Circle token IDs to resolve; duplicates are looked up once
+    ///
+    /// # Errors
+    ///
+    /// Returns the first lookup error encountered.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_view::circle_view::CircleView;
+    /// use inf_circle_sdk::dev_wallet::views::list_transactions::ListTransactionsParamsBuilder;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let view = CircleView::new()?;
+    ///
+    /// let txs = view
+    ///     .list_transactions(ListTransactionsParamsBuilder::new().build())
+    ///     .await?
+    ///     .items;
+    /// let tokens = view
+    ///     .list_tokens(txs.iter().filter_map(|tx| tx.token_id.as_deref()))
+    ///     .await?;
+    /// for token in tokens {
+    ///     println!("{}: {:?}", token.id, token.symbol);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_tokens<'a>(
+        &self,
+        token_ids: impl IntoIterator<Item = &'a str>,
+    ) -> CircleResult<Vec<Token>> {
+        let mut seen = std::collections::HashSet::new();
+        let mut tokens = Vec::new();
+        for token_id in token_ids {
+            if seen.insert(token_id) {
+                tokens.push(self.get_token(token_id).await?.token);
+            }
+        }
+        Ok(tokens)
+    }
+
     /// Validate an address
     ///
     /// Validates whether an address is correctly formatted for a specific blockchain.
@@ -295,12 +422,14 @@ impl CircleView {
     /// ```rust,no_run
     /// use inf_circle_sdk::circle_view::circle_view::CircleView;
     /// use inf_circle_sdk::dev_wallet::dto::ValidateAddressBody;
+    /// use inf_circle_sdk::types::Blockchain;
     ///
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let view = CircleView::new()?;
     ///
     /// let body = ValidateAddressBody {
     ///     address: "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb".to_string(),
+    ///     blockchain: Some(Blockchain::EthSepolia),
     /// };
     ///
     /// let result = view.validate_address(body).await?;
@@ -316,9 +445,11 @@ impl CircleView {
         &self,
         body: ValidateAddressBody,
     ) -> CircleResult<ValidateAddressResponse> {
-        let body = ValidateAddressBodyBuilder::new()
-            .address(body.address)
-            .build();
+        let mut builder = ValidateAddressBodyBuilder::new().address(body.address);
+        if let Some(blockchain) = body.blockchain {
+            builder = builder.blockchain(blockchain);
+        }
+        let body = builder.build();
 
         self.post::<ValidateAddressBody, ValidateAddressResponse>(
             "/v1/w3s/transactions/validateAddress",
@@ -367,7 +498,7 @@ impl CircleView {
     pub async fn estimate_contract_execution_fee(
         &self,
         request: EstimateContractExecutionFeeBody,
-    ) -> CircleResult<EstimateContractExecutionFeeResponse> {
+    ) -> CircleResult<FeeEstimate> {
         let body = EstimateContractExecutionFeeBodyBuilder::new(request.contract_address)
             .abi_function_signature(request.abi_function_signature)
             .abi_parameters(request.abi_parameters)
@@ -378,7 +509,7 @@ impl CircleView {
             .wallet_id(request.wallet_id)
             .build();
 
-        self.post::<EstimateContractExecutionFeeBody, EstimateContractExecutionFeeResponse>(
+        self.post::<EstimateContractExecutionFeeBody, FeeEstimate>(
             "/v1/w3s/transactions/contractExecution/estimateFee",
             &body,
         )
@@ -422,14 +553,118 @@ impl CircleView {
     pub async fn estimate_transfer_fee(
         &self,
         request: EstimateTransferFeeRequest,
-    ) -> CircleResult<EstimateTransferFeeResponse> {
-        self.post::<EstimateTransferFeeRequest, EstimateTransferFeeResponse>(
+    ) -> CircleResult<FeeEstimate> {
+        self.post::<EstimateTransferFeeRequest, FeeEstimate>(
             "/v1/w3s/transactions/transfer/estimateFee",
             &request,
         )
         .await
     }
 
+    /// Estimate the replacement fee for accelerating a transaction
+    ///
+    /// Re-estimates the transaction at the current network conditions and
+    /// returns the HIGH fee level next to the fee the transaction was submitted
+    /// with, so callers can decide whether `accelerate_dev_transaction` is worth
+    /// it. Contract executions are re-estimated from their ABI signature and
+    /// parameters, transfers from their destination, amounts and token.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx_id` - The unique identifier of the transaction
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Config` if the transaction lacks the destination
+    /// or contract details needed to re-estimate it, or an API error from the
+    /// lookup or estimation requests.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+    /// use inf_circle_sdk::circle_view::circle_view::CircleView;
+    /// use inf_circle_sdk::dev_wallet::ops::accelerate_transaction::AccelerateTransactionRequestBuilder;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let view = CircleView::new()?;
+    /// let ops = CircleOps::new(None)?;
+    ///
+    /// let estimate = view.estimate_transaction_acceleration_fee("transaction-id").await?;
+    /// println!("Replacement network fee: {:?}", estimate.replacement_fee.network_fee);
+    ///
+    /// if estimate.fee_multiplier().is_some_and(|m| m > 1.2) {
+    ///     let builder = AccelerateTransactionRequestBuilder::new(
+    ///         "transaction-id".to_string(),
+    ///     )
+    ///     .build();
+    ///     ops.accelerate_dev_transaction(builder).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn estimate_transaction_acceleration_fee(
+        &self,
+        tx_id: &str,
+    ) -> CircleResult<AccelerationFeeEstimate> {
+        let tx = self.get_transaction(tx_id).await?.transaction;
+        let (wallet_id, source_address, blockchain) = match tx.wallet_id {
+            Some(wallet_id) => (Some(wallet_id), None, None),
+            None => (
+                None,
+                tx.source_address,
+                Some(tx.blockchain.as_str().to_string()),
+            ),
+        };
+
+        let estimate = match (tx.contract_address, tx.abi_function_signature) {
+            (Some(contract_address), Some(signature)) => {
+                let abi_parameters = tx
+                    .abi_parameters
+                    .map(|params| {
+                        serde_json::from_value::<Vec<AbiParameter>>(serde_json::Value::Array(
+                            params,
+                        ))
+                    })
+                    .transpose()?;
+                let body = EstimateContractExecutionFeeBodyBuilder::new(contract_address)
+                    .abi_function_signature(Some(signature))
+                    .abi_parameters(abi_parameters)
+                    .blockchain(blockchain)
+                    .source_address(source_address)
+                    .wallet_id(wallet_id)
+                    .build();
+                self.estimate_contract_execution_fee(body).await?
+            }
+            _ => {
+                let destination = tx.destination_address.ok_or_else(|| {
+                    CircleError::Config(format!(
+                        "Transaction {} has no destination address to estimate against",
+                        tx_id
+                    ))
+                })?;
+                let request = EstimateTransferFeeRequestBuilder::new(
+                    destination,
+                    tx.amounts.unwrap_or_default(),
+                )
+                .nft_token_ids(tx.nfts)
+                .token_id(tx.token_id)
+                .blockchain(blockchain)
+                .source_address(source_address)
+                .wallet_id(wallet_id)
+                .build();
+                self.estimate_transfer_fee(request).await?
+            }
+        };
+
+        Ok(AccelerationFeeEstimate {
+            transaction_id: tx.id,
+            state: tx.state,
+            current_fee: tx.estimated_fee,
+            replacement_fee: estimate.high,
+        })
+    }
+
     /// Request testnet tokens from faucet
     ///
     /// Requests testnet tokens (ETH, USDC, EURC) from Circle's faucet for testing purposes.
@@ -1,11 +1,20 @@
+pub use crate::fees::{EstimatedFee, FeeEstimate};
+
 use crate::{
-    helper::{serialize_bool_as_string, serialize_datetime_as_string, PaginationParams},
+    abi::dto::AbiValue,
+    helper::{
+        compare_decimal_str, deserialize_optional_from_string, serialize_bool_as_string,
+        serialize_datetime_as_string, CircleError, CircleResult, PaginationParams, ResourceId,
+        SecretString,
+    },
     types::Blockchain,
 };
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::{cmp::Ordering, fmt, str::FromStr};
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum FeeLevel {
     Low,
@@ -23,6 +32,173 @@ impl FeeLevel {
     }
 }
 
+/// Fee settings for a transaction
+///
+/// Circle accepts either a dynamic fee level or explicit gas parameters, and the
+/// explicit parameters must come in matching sets. `FeeConfig` represents exactly
+/// one valid combination, so it can be shared by every transaction builder and
+/// checked in one place with [`validate`](Self::validate).
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::dev_wallet::dto::{FeeConfig, FeeLevel};
+///
+/// let dynamic = FeeConfig::level(FeeLevel::Medium);
+/// assert!(dynamic.validate().is_ok());
+///
+/// // 50 gwei max fee, 2 gwei tip
+/// let eip1559 = FeeConfig::eip1559("50".to_string(), "2".to_string(), "21000".to_string());
+/// assert!(eip1559.validate().is_ok());
+///
+/// // The tip cannot exceed the max fee
+/// let invalid = FeeConfig::eip1559("1".to_string(), "2".to_string(), "21000".to_string());
+/// assert!(invalid.validate().is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum FeeConfig {
+    /// Dynamic fee level (LOW, MEDIUM, or HIGH) estimated by Circle
+    Level(FeeLevel),
+    /// Gas price for blockchains without EIP-1559 support
+    Legacy {
+        /// Maximum price per unit of gas, in gwei
+        gas_price: String,
+        /// Maximum units of gas to use
+        gas_limit: String,
+    },
+    /// Fee cap and tip for blockchains with EIP-1559 support
+    Eip1559 {
+        /// Maximum price per unit of gas, in gwei
+        max_fee: String,
+        /// Tip to add to the base fee, in gwei
+        priority_fee: String,
+        /// Maximum units of gas to use
+        gas_limit: String,
+    },
+}
+
+impl From<FeeLevel> for FeeConfig {
+    fn from(level: FeeLevel) -> Self {
+        FeeConfig::Level(level)
+    }
+}
+
+impl FeeConfig {
+    /// Use a dynamic fee level
+    pub fn level(level: FeeLevel) -> Self {
+        FeeConfig::Level(level)
+    }
+
+    /// Use an explicit gas price (non-EIP-1559 blockchains)
+    pub fn legacy(gas_price: String, gas_limit: String) -> Self {
+        FeeConfig::Legacy {
+            gas_price,
+            gas_limit,
+        }
+    }
+
+    /// Use an explicit EIP-1559 fee cap and tip
+    pub fn eip1559(max_fee: String, priority_fee: String, gas_limit: String) -> Self {
+        FeeConfig::Eip1559 {
+            max_fee,
+            priority_fee,
+            gas_limit,
+        }
+    }
+
+    /// Check that the explicit gas parameters are well formed
+    ///
+    /// Gas limits must be positive integers and gas prices positive decimal
+    /// strings; an EIP-1559 priority fee may be zero but cannot exceed the max fee.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Config` describing the first invalid value.
+    pub fn validate(&self) -> CircleResult<()> {
+        match self {
+            FeeConfig::Level(_) => Ok(()),
+            FeeConfig::Legacy {
+                gas_price,
+                gas_limit,
+            } => {
+                check_gas_limit(gas_limit)?;
+                check_gwei("gas price", gas_price, false)
+            }
+            FeeConfig::Eip1559 {
+                max_fee,
+                priority_fee,
+                gas_limit,
+            } => {
+                check_gas_limit(gas_limit)?;
+                check_gwei("max fee", max_fee, false)?;
+                check_gwei("priority fee", priority_fee, true)?;
+                if compare_decimal_str(priority_fee, max_fee) == Some(Ordering::Greater) {
+                    return Err(CircleError::Config(format!(
+                        "Priority fee {} exceeds max fee {}",
+                        priority_fee, max_fee
+                    )));
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Split into the request body's fee fields
+    pub(crate) fn into_fields(fee: Option<FeeConfig>) -> FeeFields {
+        let mut fields = FeeFields::default();
+        match fee {
+            None => {}
+            Some(FeeConfig::Level(level)) => fields.fee_level = Some(level),
+            Some(FeeConfig::Legacy {
+                gas_price,
+                gas_limit,
+            }) => {
+                fields.gas_price = Some(gas_price);
+                fields.gas_limit = Some(gas_limit);
+            }
+            Some(FeeConfig::Eip1559 {
+                max_fee,
+                priority_fee,
+                gas_limit,
+            }) => {
+                fields.max_fee = Some(max_fee);
+                fields.priority_fee = Some(priority_fee);
+                fields.gas_limit = Some(gas_limit);
+            }
+        }
+        fields
+    }
+}
+
+/// Fee fields as they appear in Circle request bodies
+#[derive(Default)]
+pub(crate) struct FeeFields {
+    pub fee_level: Option<FeeLevel>,
+    pub gas_limit: Option<String>,
+    pub gas_price: Option<String>,
+    pub max_fee: Option<String>,
+    pub priority_fee: Option<String>,
+}
+
+fn check_gas_limit(gas_limit: &str) -> CircleResult<()> {
+    match gas_limit.parse::<u64>() {
+        Ok(limit) if limit > 0 => Ok(()),
+        _ => Err(CircleError::Config(format!(
+            "Invalid gas limit: {}",
+            gas_limit
+        ))),
+    }
+}
+
+fn check_gwei(name: &str, value: &str, allow_zero: bool) -> CircleResult<()> {
+    match compare_decimal_str(value, "0") {
+        Some(Ordering::Greater) => Ok(()),
+        Some(Ordering::Equal) if allow_zero => Ok(()),
+        _ => Err(CircleError::Config(format!("Invalid {}: {}", name, value))),
+    }
+}
+
 /// Request structure for creating wallets
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -31,7 +207,7 @@ pub struct CreateDevWalletRequest {
     pub wallet_set_id: String,
 
     /// Base64 encrypted entity secret ciphertext
-    pub entity_secret_ciphertext: String,
+    pub entity_secret_ciphertext: SecretString,
 
     /// Target blockchains for wallet creation
     pub blockchains: Vec<Blockchain>,
@@ -93,9 +269,21 @@ pub struct DevWalletResponse {
     pub wallet: DevWallet,
 }
 
+/// Entity public key response structure
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityPublicKeyResponse {
+    /// Circle's RSA public key in PEM format, used to encrypt the entity secret
+    pub public_key: String,
+}
+
 /// Wallet response structure
+///
+/// Non-exhaustive, since Circle keeps adding fields: deserialize one, or
+/// start from a [`fixtures`](crate::testing::fixtures) value in tests.
 #[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct DevWallet {
     /// Unique wallet identifier
     pub id: String,
@@ -139,6 +327,66 @@ pub struct DevWallet {
 
     /// Account type (EOA or SCA)
     pub account_type: String,
+
+    /// Fields not modelled above
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl ResourceId for DevWallet {
+    fn resource_id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl DevWallet {
+    /// The wallet's state, or `None` for a state this SDK does not know
+    pub fn wallet_state(&self) -> Option<WalletState> {
+        self.state.parse().ok()
+    }
+
+    /// Whether the wallet can send transactions
+    pub fn is_live(&self) -> bool {
+        self.wallet_state() == Some(WalletState::Live)
+    }
+}
+
+/// State of a developer-controlled wallet
+///
+/// Circle sets the state; its API has no endpoint to freeze, deactivate or
+/// reactivate a developer-controlled wallet. Exclude wallets by state when
+/// listing with [`ListDevWalletsParamsBuilder::state`](crate::dev_wallet::views::list_wallets::ListDevWalletsParamsBuilder::state).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum WalletState {
+    Live,
+    Frozen,
+}
+
+impl WalletState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WalletState::Live => "LIVE",
+            WalletState::Frozen => "FROZEN",
+        }
+    }
+}
+
+impl fmt::Display for WalletState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for WalletState {
+    type Err = CircleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [WalletState::Live, WalletState::Frozen]
+            .into_iter()
+            .find(|state| state.as_str().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| CircleError::Config(format!("Unknown wallet state: {}", s)))
+    }
 }
 
 /// Request structure for signing a message
@@ -146,7 +394,7 @@ pub struct DevWallet {
 #[serde(rename_all = "camelCase")]
 pub struct SignMessageRequest {
     /// A base64 string expression of the entity secret ciphertext. The entity secret should be encrypted by the entity public key. Circle mandates that the entity secret ciphertext is unique for each API request.
-    pub entity_secret_ciphertext: String,
+    pub entity_secret_ciphertext: SecretString,
 
     /// The user friendly message that needs to be signed. If it is a hex string, encoded_by_hex needs to be TRUE. The hex string should start with "0x" and have even length.
     pub message: String,
@@ -168,7 +416,7 @@ pub struct SignMessageRequest {
 #[serde(rename_all = "camelCase")]
 pub struct SignDataRequest {
     /// A base64 string expression of the entity secret ciphertext. The entity secret should be encrypted by the entity public key. Circle mandates that the entity secret ciphertext is unique for each API request.
-    pub entity_secret_ciphertext: String,
+    pub entity_secret_ciphertext: SecretString,
 
     /// The data that needs to be signed.
     pub data: String,
@@ -186,7 +434,7 @@ pub struct SignDataRequest {
 #[serde(rename_all = "camelCase")]
 pub struct SignTransactionRequest {
     /// A base64 string expression of the entity secret ciphertext. The entity secret should be encrypted by the entity public key. Circle mandates that the entity secret ciphertext is unique for each API request.
-    pub entity_secret_ciphertext: String,
+    pub entity_secret_ciphertext: SecretString,
 
     /// The raw transaction that needs to be signed.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -224,7 +472,7 @@ pub struct SignTransactionResponse {
 #[serde(rename_all = "camelCase")]
 pub struct SignDelegateRequest {
     /// A base64 string expression of the entity secret ciphertext. The entity secret should be encrypted by the entity public key. Circle mandates that the entity secret ciphertext is unique for each API request.
-    pub entity_secret_ciphertext: String,
+    pub entity_secret_ciphertext: SecretString,
 
     /// Unsigned delegate action string that needs to be signed. Must be base64 encoded.
     pub unsigned_delegate_action: String,
@@ -258,6 +506,8 @@ pub struct SignatureResponse {
 }
 
 /// Account type enum
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
 pub enum AccountType {
     Eoa,
     Sca,
@@ -273,8 +523,12 @@ impl AccountType {
 }
 
 /// Query parameters for listing wallets
-#[derive(Debug, Serialize, Default)]
+///
+/// Non-exhaustive; build with
+/// [`ListDevWalletsParamsBuilder`](crate::dev_wallet::views::list_wallets::ListDevWalletsParamsBuilder).
+#[derive(Debug, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct ListDevWalletsParams {
     /// Filter by blockchain address
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -286,7 +540,7 @@ pub struct ListDevWalletsParams {
 
     /// Filter by SCA version
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sca_core: Option<String>,
+    pub sca_core: Option<ScaCore>,
 
     /// Filter by wallet set ID
     #[serde(rename = "walletSetId", skip_serializing_if = "Option::is_none")]
@@ -316,6 +570,15 @@ pub struct ListDevWalletsParams {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub order: Option<String>,
+
+    /// Keep only wallets in this state
+    ///
+    /// Circle does not filter by state, so `list_wallets` drops other
+    /// wallets from each page after fetching it: a page may hold fewer than
+    /// `page_size` wallets. Follow the page's cursors, which are taken
+    /// before filtering, rather than its last wallet.
+    #[serde(skip)]
+    pub state: Option<WalletState>,
 }
 
 /// Query parameters for listing wallets with token balances
@@ -331,7 +594,7 @@ pub struct ListWalletsWithBalancesParams {
 
     /// Filter by SCA version
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sca_core: Option<String>,
+    pub sca_core: Option<ScaCore>,
 
     /// Filter by wallet set ID
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -377,7 +640,9 @@ pub struct QueryParams {
     /// Return all resources with monitored and non-monitored tokens
     #[serde(
         skip_serializing_if = "Option::is_none",
-        serialize_with = "serialize_bool_as_string"
+        serialize_with = "serialize_bool_as_string",
+        deserialize_with = "deserialize_optional_from_string",
+        default
     )]
     pub include_all: Option<bool>,
 
@@ -401,7 +666,7 @@ pub struct QueryParams {
 }
 
 /// Parameters for listing transactions
-#[derive(Debug, Serialize, Default, Deserialize)]
+#[derive(Debug, Clone, Serialize, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ListTransactionsParams {
     /// Filter by blockchain
@@ -419,7 +684,9 @@ pub struct ListTransactionsParams {
     /// Return all resources with monitored and non-monitored tokens
     #[serde(
         skip_serializing_if = "Option::is_none",
-        serialize_with = "serialize_bool_as_string"
+        serialize_with = "serialize_bool_as_string",
+        deserialize_with = "deserialize_optional_from_string",
+        default
     )]
     pub include_all: Option<bool>,
 
@@ -497,8 +764,12 @@ pub struct TokenBalance {
 }
 
 /// Token information
-#[derive(Debug, Deserialize, Serialize)]
+///
+/// Non-exhaustive, since Circle keeps adding fields: deserialize one, or
+/// start from a [`fixtures`](crate::testing::fixtures) value in tests.
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct Token {
     /// Unique token identifier
     pub id: String,
@@ -510,7 +781,7 @@ pub struct Token {
     pub standard: Option<String>,
 
     /// Blockchain network
-    pub blockchain: String,
+    pub blockchain: Blockchain,
 
     /// Number of decimals
     pub decimals: Option<u32>,
@@ -529,6 +800,17 @@ pub struct Token {
 
     /// Creation timestamp
     pub create_date: DateTime<Utc>,
+
+    /// Fields not modelled above
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Token lookup response structure
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenResponse {
+    pub token: Token,
 }
 
 /// Wallets with balances response structure
@@ -549,7 +831,7 @@ pub struct WalletWithBalances {
     pub address: String,
 
     /// Blockchain network
-    pub blockchain: String,
+    pub blockchain: Blockchain,
 
     /// Creation timestamp
     pub create_date: DateTime<Utc>,
@@ -589,6 +871,12 @@ pub struct WalletWithBalances {
     pub token_balances: Vec<TokenBalance>,
 }
 
+impl ResourceId for WalletWithBalances {
+    fn resource_id(&self) -> &str {
+        &self.id
+    }
+}
+
 /// NFTs response structure
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -631,8 +919,12 @@ pub struct TransactionResponse {
 }
 
 /// Individual transaction
+///
+/// Non-exhaustive, since Circle keeps adding fields: deserialize one, or
+/// start from a [`fixtures`](crate::testing::fixtures) value in tests.
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct Transaction {
     /// System-generated unique identifier of the resource
     pub id: String,
@@ -662,7 +954,7 @@ pub struct Transaction {
     pub block_height: Option<i64>,
 
     /// The blockchain network
-    pub blockchain: String,
+    pub blockchain: Blockchain,
 
     /// The blockchain address of the contract
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -747,47 +1039,20 @@ pub struct Transaction {
     /// Transaction screening evaluation
     #[serde(skip_serializing_if = "Option::is_none")]
     pub transaction_screening_evaluation: Option<TransactionScreeningEvaluation>,
-}
-
-/// Estimated fee for the transaction
-#[derive(Debug, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct EstimatedFee {
-    /// The maximum units of gas to use for the transaction
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub gas_limit: Option<String>,
 
-    /// The maximum price of gas, in gwei, to use per each unit of gas
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub gas_price: Option<String>,
-
-    /// The maximum price per unit of gas for EIP-1559 support
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub max_fee: Option<String>,
-
-    /// The "tip" to add to the base fee for EIP-1559 support
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub priority_fee: Option<String>,
-
-    /// The estimated base fee for EIP-1559 support
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub base_fee: Option<String>,
-
-    /// The estimated network fee
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub network_fee: Option<String>,
-
-    /// The estimated network fee with lower buffer
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub network_fee_raw: Option<String>,
+    /// Fields not modelled above
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
 
-    /// Defines the blockchain fee level
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub fee_level: Option<String>,
+impl ResourceId for Transaction {
+    fn resource_id(&self) -> &str {
+        &self.id
+    }
 }
 
 /// Transaction screening evaluation
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionScreeningEvaluation {
     /// Name of the matched rule found in screening
@@ -808,7 +1073,7 @@ pub struct TransactionScreeningEvaluation {
 }
 
 /// Risk signal
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RiskSignal {
     /// Source of the risk signal
@@ -827,6 +1092,86 @@ pub struct RiskSignal {
     pub r#type: String,
 }
 
+/// Blockchain-specific optional fields for transfer transactions
+///
+/// Each variant only carries the fields its blockchain family understands, so
+/// a NEAR memo can never be attached to an EVM transfer by accident. The
+/// fields are flattened into the transfer request body.
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::dev_wallet::dto::ChainExtras;
+/// use inf_circle_sdk::types::Blockchain;
+///
+/// let extras = ChainExtras::near_memo("deposit-12345");
+/// assert!(extras.supports(&Blockchain::NearTestnet));
+/// assert!(!extras.supports(&Blockchain::EthSepolia));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ChainExtras {
+    /// Solana transfer extras
+    #[serde(rename_all = "camelCase")]
+    Solana {
+        /// Memo recorded with the transfer via the SPL Memo program
+        #[serde(skip_serializing_if = "Option::is_none")]
+        memo: Option<String>,
+
+        /// Base58 reference public keys attached to the transfer (Solana Pay)
+        reference_keys: Vec<String>,
+    },
+
+    /// NEAR transfer extras
+    Near {
+        /// Memo attached to the transfer (e.g., an exchange deposit identifier)
+        memo: String,
+    },
+}
+
+impl ChainExtras {
+    /// NEAR extras with a deposit memo
+    pub fn near_memo(memo: impl Into<String>) -> Self {
+        ChainExtras::Near { memo: memo.into() }
+    }
+
+    /// Solana extras with a memo and no reference keys
+    pub fn solana_memo(memo: impl Into<String>) -> Self {
+        ChainExtras::Solana {
+            memo: Some(memo.into()),
+            reference_keys: Vec::new(),
+        }
+    }
+
+    /// Solana extras with reference public keys
+    pub fn solana_reference_keys(reference_keys: Vec<String>) -> Self {
+        ChainExtras::Solana {
+            memo: None,
+            reference_keys,
+        }
+    }
+
+    /// Check whether these extras apply to `blockchain`
+    pub fn supports(&self, blockchain: &Blockchain) -> bool {
+        match self {
+            ChainExtras::Solana { .. } => {
+                matches!(blockchain, Blockchain::Sol | Blockchain::SolDevnet)
+            }
+            ChainExtras::Near { .. } => {
+                matches!(blockchain, Blockchain::Near | Blockchain::NearTestnet)
+            }
+        }
+    }
+
+    /// Name of the blockchain family these extras belong to
+    pub fn chain_name(&self) -> &'static str {
+        match self {
+            ChainExtras::Solana { .. } => "Solana",
+            ChainExtras::Near { .. } => "NEAR",
+        }
+    }
+}
+
 /// Request structure for creating a transfer transaction
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -838,7 +1183,7 @@ pub struct CreateTransferTransactionRequest {
     pub wallet_address: Option<String>,
 
     /// A base64 string expression of the entity secret ciphertext. The entity secret should be encrypted by the entity public key.
-    pub entity_secret_ciphertext: String,
+    pub entity_secret_ciphertext: SecretString,
 
     /// Blockchain generated unique identifier, associated with wallet (account), smart contract or other blockchain objects.
     pub destination_address: String,
@@ -894,6 +1239,10 @@ pub struct CreateTransferTransactionRequest {
     /// The blockchain and tokenId fields are mutually exclusive.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub blockchain: Option<Blockchain>,
+
+    /// Blockchain-specific extras (memos, reference keys), flattened into the request body
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub chain_extras: Option<ChainExtras>,
 }
 
 /// Response structure for creating a transfer transaction
@@ -912,6 +1261,10 @@ pub struct CreateTransferTransactionResponse {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ValidateAddressBody {
     pub address: String,
+
+    /// Blockchain the address is validated for
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blockchain: Option<Blockchain>,
 }
 
 /// Response structure for validating an address
@@ -931,6 +1284,30 @@ pub enum AbiParameter {
     Array(Vec<AbiParameter>),
 }
 
+impl From<AbiValue> for AbiParameter {
+    /// Convert a typed ABI value into the JSON form accepted by Circle
+    ///
+    /// Integers are sent as decimal strings so `uint256` values keep full precision,
+    /// while addresses and byte strings are sent as `0x`-prefixed hex.
+    /// Tuples and fixed arrays are sent as nested arrays.
+    fn from(value: AbiValue) -> Self {
+        match value {
+            AbiValue::Address(address) => AbiParameter::String(address.to_string()),
+            AbiValue::Uint(value) => AbiParameter::String(value.to_string()),
+            AbiValue::Int(value) => AbiParameter::String(value.to_signed_string()),
+            AbiValue::Bool(value) => AbiParameter::Boolean(value),
+            AbiValue::FixedBytes(bytes) => {
+                AbiParameter::String(format!("0x{}", hex::encode(bytes.as_bytes())))
+            }
+            AbiValue::Bytes(bytes) => AbiParameter::String(format!("0x{}", hex::encode(bytes))),
+            AbiValue::String(s) => AbiParameter::String(s),
+            AbiValue::Array(values) | AbiValue::FixedArray(values) | AbiValue::Tuple(values) => {
+                AbiParameter::Array(values.into_iter().map(AbiParameter::from).collect())
+            }
+        }
+    }
+}
+
 /// Request structure for estimating contract execution fee
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -973,31 +1350,8 @@ pub struct EstimateContractExecutionFeeBody {
     pub wallet_id: Option<String>,
 }
 
-/// Fee estimation data for contract execution
-#[derive(Debug, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct EstimateContractExecutionFeeResponse {
-    /// High fee level estimation
-    pub high: EstimatedFee,
-
-    /// Low fee level estimation
-    pub low: EstimatedFee,
-
-    /// Medium fee level estimation
-    pub medium: EstimatedFee,
-
-    /// ERC-4337 gas field: amount of gas for main execution call (SCA only)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub call_gas_limit: Option<String>,
-
-    /// ERC-4337 gas field: amount of gas for verification step (SCA only)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub verification_gas_limit: Option<String>,
-
-    /// ERC-4337 gas field: gas to compensate bundler for pre-verification (SCA only)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub pre_verification_gas: Option<String>,
-}
+/// Fee estimation data for contract execution (the shared [`FeeEstimate`])
+pub type EstimateContractExecutionFeeResponse = FeeEstimate;
 
 /// Request structure for estimating transfer transaction fee
 #[derive(Debug, Serialize, Deserialize)]
@@ -1042,8 +1396,50 @@ pub struct EstimateTransferFeeRequest {
 }
 
 /// Response structure for estimating transfer transaction fee
-/// Reuses the same structure as contract execution fee estimation
-pub type EstimateTransferFeeResponse = EstimateContractExecutionFeeResponse;
+pub type EstimateTransferFeeResponse = FeeEstimate;
+
+/// Which tier of a fee estimate to use
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum FeeSelection {
+    Low,
+    #[default]
+    Medium,
+    High,
+}
+
+impl FeeSelection {
+    /// The fee level of the same tier
+    pub fn fee_level(&self) -> FeeLevel {
+        match self {
+            FeeSelection::Low => FeeLevel::Low,
+            FeeSelection::Medium => FeeLevel::Medium,
+            FeeSelection::High => FeeLevel::High,
+        }
+    }
+
+    /// Take the selected tier out of a fee estimate
+    pub fn select(&self, estimate: EstimateTransferFeeResponse) -> EstimatedFee {
+        match self {
+            FeeSelection::Low => estimate.low,
+            FeeSelection::Medium => estimate.medium,
+            FeeSelection::High => estimate.high,
+        }
+    }
+}
+
+/// Result of [`CircleOps::create_transfer_with_estimate`](crate::circle_ops::circler_ops::CircleOps::create_transfer_with_estimate)
+#[derive(Debug)]
+pub struct TransferWithEstimate {
+    /// The estimate tier the fee was taken from
+    pub estimate: EstimatedFee,
+
+    /// The fee settings the transfer was submitted with
+    pub fee: FeeConfig,
+
+    /// The created transfer transaction
+    pub transaction: CreateTransferTransactionResponse,
+}
 
 /// ABI parameter types for contract queries
 #[derive(Debug, Serialize, Clone, Deserialize)]
@@ -1090,6 +1486,10 @@ pub struct QueryContractRequest {
 }
 
 /// Output value types for contract query results
+#[deprecated(
+    since = "0.2.7",
+    note = "contract queries return `contract::dto::QueryContractResponse`, whose output values are `serde_json::Value`"
+)]
 #[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(untagged)]
 pub enum ContractOutputValue {
@@ -1099,33 +1499,64 @@ pub enum ContractOutputValue {
 }
 
 /// Response data for contract query
-#[derive(Debug, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct QueryContractResponse {
-    /// Output for the ABI interaction
-    /// May be null if the contract call returns no values
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub output_values: Option<Vec<ContractOutputValue>>,
-
-    /// OutputData is output in hex format
-    pub output_data: String,
-}
-
-/// SCA Core version enum for wallet upgrades
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Kept as an alias so existing imports keep compiling; the contract module
+/// owns the type.
+#[deprecated(since = "0.2.7", note = "use `contract::dto::QueryContractResponse`")]
+pub type QueryContractResponse = crate::contract::dto::QueryContractResponse;
+
+/// SCA Core version
+///
+/// Used as the target of wallet upgrades and as the `scaCore` filter when
+/// listing wallets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ScaCore {
+    #[serde(rename = "circle_4337_v1")]
+    Circle4337V1,
+    #[serde(rename = "circle_6900_singleowner_v1")]
+    Circle6900SingleownerV1,
+    #[serde(rename = "circle_6900_singleowner_v2")]
+    Circle6900SingleownerV2,
     #[serde(rename = "circle_6900_singleowner_v3")]
     Circle6900SingleownerV3,
 }
 
 impl ScaCore {
+    /// All published SCA core versions, oldest first
+    pub const ALL: [ScaCore; 4] = [
+        ScaCore::Circle4337V1,
+        ScaCore::Circle6900SingleownerV1,
+        ScaCore::Circle6900SingleownerV2,
+        ScaCore::Circle6900SingleownerV3,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
+            ScaCore::Circle4337V1 => "circle_4337_v1",
+            ScaCore::Circle6900SingleownerV1 => "circle_6900_singleowner_v1",
+            ScaCore::Circle6900SingleownerV2 => "circle_6900_singleowner_v2",
             ScaCore::Circle6900SingleownerV3 => "circle_6900_singleowner_v3",
         }
     }
 }
 
+impl fmt::Display for ScaCore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ScaCore {
+    type Err = CircleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ScaCore::ALL
+            .into_iter()
+            .find(|core| core.as_str().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| CircleError::Config(format!("Unknown SCA core version: {}", s)))
+    }
+}
+
 /// Request structure for creating a wallet upgrade transaction
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -1134,7 +1565,7 @@ pub struct CreateWalletUpgradeTransactionRequest {
     pub wallet_id: String,
 
     /// A base64 string expression of the entity secret ciphertext
-    pub entity_secret_ciphertext: String,
+    pub entity_secret_ciphertext: SecretString,
 
     /// Version of the SCA available for upgrade
     pub new_sca_core: String,
@@ -1190,7 +1621,7 @@ pub struct CreateContractExecutionTransactionRequest {
     pub wallet_id: String,
 
     /// A base64 string expression of the entity secret ciphertext
-    pub entity_secret_ciphertext: String,
+    pub entity_secret_ciphertext: SecretString,
 
     /// The blockchain address of the contract to be executed
     pub contract_address: String,
@@ -1262,7 +1693,7 @@ pub struct CreateContractExecutionTransactionResponse {
 #[serde(rename_all = "camelCase")]
 pub struct CancelTransactionRequest {
     /// A base64 string expression of the entity secret ciphertext
-    pub entity_secret_ciphertext: String,
+    pub entity_secret_ciphertext: SecretString,
 
     /// UUID v4 for idempotency
     pub idempotency_key: String,
@@ -1284,7 +1715,7 @@ pub struct CancelTransactionResponse {
 #[serde(rename_all = "camelCase")]
 pub struct AccelerateTransactionRequest {
     /// A base64 string expression of the entity secret ciphertext
-    pub entity_secret_ciphertext: String,
+    pub entity_secret_ciphertext: SecretString,
 
     /// UUID v4 for idempotency
     pub idempotency_key: String,
@@ -1298,6 +1729,42 @@ pub struct AccelerateTransactionResponse {
     pub id: String,
 }
 
+/// Replacement fee estimate for accelerating a transaction
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccelerationFeeEstimate {
+    /// Identifier of the transaction to accelerate
+    pub transaction_id: String,
+
+    /// Current state of the transaction
+    pub state: String,
+
+    /// Fee the transaction was originally submitted with
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_fee: Option<EstimatedFee>,
+
+    /// Fee a replacement would use at today's HIGH fee level
+    pub replacement_fee: EstimatedFee,
+}
+
+impl AccelerationFeeEstimate {
+    /// Ratio of the replacement network fee to the current one
+    ///
+    /// Returns `None` if either network fee is unknown or the current fee is zero.
+    /// A value close to `1.0` means accelerating is unlikely to help much.
+    pub fn fee_multiplier(&self) -> Option<f64> {
+        let current: f64 = self
+            .current_fee
+            .as_ref()?
+            .network_fee
+            .as_ref()?
+            .parse()
+            .ok()?;
+        let replacement: f64 = self.replacement_fee.network_fee.as_ref()?.parse().ok()?;
+        (current > 0.0).then(|| replacement / current)
+    }
+}
+
 /// Request structure for requesting testnet tokens from faucet
 #[derive(Debug, Serialize, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -1320,3 +1787,87 @@ pub struct RequestTestnetTokensRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub eurc: Option<bool>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures;
+    use serde_json::json;
+
+    #[test]
+    fn test_query_params_round_trip() {
+        let params = ListTransactionsParams {
+            include_all: Some(true),
+            from: Some("2025-01-15T10:00:00Z".parse().unwrap()),
+            pagination: PaginationParams {
+                page_size: Some(20),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let json = serde_json::to_value(&params).unwrap();
+        assert_eq!(json["includeAll"], "true");
+        assert_eq!(json["pageSize"], "20");
+
+        let parsed: ListTransactionsParams = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.include_all, Some(true));
+        assert_eq!(parsed.from, params.from);
+        assert_eq!(parsed.pagination.page_size, Some(20));
+    }
+
+    #[test]
+    fn test_request_enums_round_trip() {
+        for fee in [
+            FeeConfig::level(FeeLevel::High),
+            FeeConfig::legacy("20".to_string(), "21000".to_string()),
+            FeeConfig::eip1559("50".to_string(), "2".to_string(), "21000".to_string()),
+        ] {
+            let json = serde_json::to_string(&fee).unwrap();
+            assert_eq!(serde_json::from_str::<FeeConfig>(&json).unwrap(), fee);
+        }
+        assert_eq!(
+            serde_json::to_value(FeeConfig::eip1559(
+                "50".to_string(),
+                "2".to_string(),
+                "21000".to_string()
+            ))
+            .unwrap(),
+            json!({"eip1559": {"maxFee": "50", "priorityFee": "2", "gasLimit": "21000"}})
+        );
+
+        assert_eq!(serde_json::to_value(AccountType::Sca).unwrap(), "SCA");
+        assert_eq!(
+            serde_json::from_value::<FeeSelection>(json!("LOW")).unwrap(),
+            FeeSelection::Low
+        );
+    }
+
+    #[test]
+    fn test_unknown_fields_are_kept() {
+        let mut json = serde_json::to_value(fixtures::transaction()).unwrap();
+        json["newCircleField"] = json!({"nested": 1});
+
+        let transaction: Transaction = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(transaction.extra["newCircleField"], json!({"nested": 1}));
+        assert!(!transaction.extra.contains_key("txHash"));
+        assert_eq!(serde_json::to_value(&transaction).unwrap(), json);
+    }
+
+    #[test]
+    fn test_ciphertext_is_redacted_from_debug() {
+        let request = SignMessageRequest {
+            entity_secret_ciphertext: "c2VjcmV0".into(),
+            message: "hello".to_string(),
+            wallet_id: "wallet-1".to_string(),
+            encoded_by_hex: None,
+            memo: None,
+        };
+        let debug = format!("{:?}", request);
+        assert!(!debug.contains("c2VjcmV0"));
+        assert!(debug.contains("[REDACTED]"));
+        assert_eq!(
+            serde_json::to_value(&request).unwrap()["entitySecretCiphertext"],
+            "c2VjcmV0"
+        );
+    }
+}
@@ -0,0 +1,499 @@
+//! Transaction export to CSV and JSON Lines for accounting
+//!
+//! Every transaction is flattened into a [`TransactionRow`]: one row per
+//! transaction with amounts and fees as normalized decimal strings, their USD
+//! values, the on-chain hash and the state. A single page of results can be
+//! written with [`Page::export_csv`] or [`Page::export_jsonl`];
+//! [`TransactionExporter`] pages
+//! through [`list_transactions`](CircleView::list_transactions) between two
+//! dates and writes rows as each page arrives, so large histories never sit
+//! in memory.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use chrono::{TimeZone, Utc};
+//! use inf_circle_sdk::circle_view::circle_view::CircleView;
+//! use inf_circle_sdk::dev_wallet::export::TransactionExporter;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let view = CircleView::new()?;
+//! let from = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+//! let to = Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap();
+//!
+//! let file = std::fs::File::create("transactions-2024-q1.csv")?;
+//! let rows = TransactionExporter::new(&view, from, to)
+//!     .export_csv(std::io::BufWriter::new(file))
+//!     .await?;
+//! println!("Exported {} transactions", rows);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::io::Write;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::circle_view::circle_view::CircleView;
+use crate::dev_wallet::dto::{ListTransactionsParams, Transaction};
+use crate::helper::{page::Page, CircleError, CircleResult};
+use crate::rates::dto::Decimal;
+
+/// Page size used when the caller's params don't set one
+const DEFAULT_PAGE_SIZE: u32 = 50;
+
+/// CSV column names, in the order [`TransactionRow`] fields are written
+pub const TRANSACTION_CSV_HEADER: [&str; 16] = [
+    "id",
+    "createDate",
+    "walletId",
+    "blockchain",
+    "transactionType",
+    "operation",
+    "state",
+    "tokenId",
+    "amount",
+    "amountInUsd",
+    "networkFee",
+    "networkFeeInUsd",
+    "sourceAddress",
+    "destinationAddress",
+    "txHash",
+    "refId",
+];
+
+/// Output format of an export
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Comma-separated values with a header line
+    Csv,
+    /// One JSON object per line
+    Jsonl,
+}
+
+/// One transaction flattened for accounting
+///
+/// Decimal fields have trailing zeros removed; values Circle sends that are
+/// not plain decimals are kept as sent. Missing values are empty in CSV and
+/// `null` in JSON Lines.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionRow {
+    /// Circle transaction ID
+    pub id: String,
+
+    /// Creation time, RFC 3339 in UTC
+    pub create_date: String,
+
+    /// Wallet the transaction belongs to
+    pub wallet_id: Option<String>,
+
+    /// Blockchain of the transaction
+    pub blockchain: String,
+
+    /// `INBOUND` or `OUTBOUND`
+    pub transaction_type: String,
+
+    /// Operation type (e.g., `TRANSFER`, `CONTRACT_EXECUTION`)
+    pub operation: Option<String>,
+
+    /// Current state (e.g., `COMPLETE`, `FAILED`)
+    pub state: String,
+
+    /// Circle token ID of the transferred token
+    pub token_id: Option<String>,
+
+    /// Transferred amount; several amounts are joined with `;`
+    pub amount: Option<String>,
+
+    /// Transferred amount in USD
+    pub amount_in_usd: Option<String>,
+
+    /// Network fee in the native token
+    pub network_fee: Option<String>,
+
+    /// Network fee in USD
+    pub network_fee_in_usd: Option<String>,
+
+    /// Sending address
+    pub source_address: Option<String>,
+
+    /// Receiving address
+    pub destination_address: Option<String>,
+
+    /// On-chain transaction hash
+    pub tx_hash: Option<String>,
+
+    /// Caller-supplied reference
+    pub ref_id: Option<String>,
+}
+
+impl From<&Transaction> for TransactionRow {
+    fn from(tx: &Transaction) -> Self {
+        let amount = tx
+            .amounts
+            .as_ref()
+            .filter(|amounts| !amounts.is_empty())
+            .map(|amounts| {
+                amounts
+                    .iter()
+                    .map(|amount| normalize_decimal(amount))
+                    .collect::<Vec<_>>()
+                    .join(";")
+            });
+
+        Self {
+            id: tx.id.clone(),
+            create_date: tx.create_date.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+            wallet_id: tx.wallet_id.clone(),
+            blockchain: tx.blockchain.as_str().to_string(),
+            transaction_type: tx.transaction_type.clone(),
+            operation: tx.operation.clone(),
+            state: tx.state.clone(),
+            token_id: tx.token_id.clone(),
+            amount,
+            amount_in_usd: tx.amount_in_usd.as_deref().map(normalize_decimal),
+            network_fee: tx.network_fee.as_deref().map(normalize_decimal),
+            network_fee_in_usd: tx.network_fee_in_usd.as_deref().map(normalize_decimal),
+            source_address: tx.source_address.clone(),
+            destination_address: tx.destination_address.clone(),
+            tx_hash: tx.tx_hash.clone(),
+            ref_id: tx.ref_id.clone(),
+        }
+    }
+}
+
+impl TransactionRow {
+    /// Field values in [`TRANSACTION_CSV_HEADER`] order
+    fn columns(&self) -> [Option<&str>; 16] {
+        [
+            Some(&self.id),
+            Some(&self.create_date),
+            self.wallet_id.as_deref(),
+            Some(&self.blockchain),
+            Some(&self.transaction_type),
+            self.operation.as_deref(),
+            Some(&self.state),
+            self.token_id.as_deref(),
+            self.amount.as_deref(),
+            self.amount_in_usd.as_deref(),
+            self.network_fee.as_deref(),
+            self.network_fee_in_usd.as_deref(),
+            self.source_address.as_deref(),
+            self.destination_address.as_deref(),
+            self.tx_hash.as_deref(),
+            self.ref_id.as_deref(),
+        ]
+    }
+}
+
+impl Page<Transaction> {
+    /// Write these transactions as CSV, header first
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_view::circle_view::CircleView;
+    /// use inf_circle_sdk::dev_wallet::views::list_transactions::ListTransactionsParamsBuilder;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let view = CircleView::new()?;
+    /// let params = ListTransactionsParamsBuilder::new().page_size(50).build();
+    ///
+    /// let mut csv = Vec::new();
+    /// view.list_transactions(params).await?.export_csv(&mut csv)?;
+    /// print!("{}", String::from_utf8(csv)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn export_csv<W: Write>(&self, writer: W) -> CircleResult<()> {
+        self.export(writer, ExportFormat::Csv)
+    }
+
+    /// Write these transactions as JSON Lines, one [`TransactionRow`] per line
+    pub fn export_jsonl<W: Write>(&self, writer: W) -> CircleResult<()> {
+        self.export(writer, ExportFormat::Jsonl)
+    }
+
+    /// Write these transactions in `format`
+    pub fn export<W: Write>(&self, writer: W, format: ExportFormat) -> CircleResult<()> {
+        let mut rows = RowWriter::new(writer, format);
+        for tx in &self.items {
+            rows.write(&TransactionRow::from(tx))?;
+        }
+        rows.finish()
+    }
+}
+
+/// Streaming export of every transaction created between two dates
+///
+/// Pages through `list_transactions` oldest first and writes each page before
+/// requesting the next one.
+pub struct TransactionExporter<'a> {
+    view: &'a CircleView,
+    params: ListTransactionsParams,
+}
+
+impl<'a> TransactionExporter<'a> {
+    /// Export transactions created from `from` to `to`
+    pub fn new(view: &'a CircleView, from: DateTime<Utc>, to: DateTime<Utc>) -> Self {
+        Self {
+            view,
+            params: ListTransactionsParams {
+                from: Some(from),
+                to: Some(to),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Restrict the export with extra filters (wallet IDs, blockchain, state, ...)
+    ///
+    /// The date range, `order` and the pagination cursors are managed by the
+    /// exporter; the page size is kept if set.
+    pub fn params(mut self, params: ListTransactionsParams) -> Self {
+        self.params = ListTransactionsParams {
+            from: self.params.from,
+            to: self.params.to,
+            ..params
+        };
+        self
+    }
+
+    /// Write every matching transaction as CSV and return how many were written
+    pub async fn export_csv<W: Write>(&self, writer: W) -> CircleResult<usize> {
+        self.export(writer, ExportFormat::Csv).await
+    }
+
+    /// Write every matching transaction as JSON Lines and return how many were written
+    pub async fn export_jsonl<W: Write>(&self, writer: W) -> CircleResult<usize> {
+        self.export(writer, ExportFormat::Jsonl).await
+    }
+
+    /// Write every matching transaction in `format` and return how many were written
+    ///
+    /// Rows already written stay in `writer` if a later page fails to load.
+    pub async fn export<W: Write>(&self, writer: W, format: ExportFormat) -> CircleResult<usize> {
+        let page_size = self
+            .params
+            .pagination
+            .page_size
+            .unwrap_or(DEFAULT_PAGE_SIZE);
+        let mut params = self.params.clone();
+        params.order = Some("ASC".to_string());
+        params.pagination.page_before = None;
+        params.pagination.page_after = None;
+        params.pagination.page_size = Some(page_size);
+
+        let mut rows = RowWriter::new(writer, format);
+        loop {
+            let page = self.view.list_transactions(params.clone()).await?;
+            for tx in &page.items {
+                rows.write(&TransactionRow::from(tx))?;
+            }
+            params.pagination.page_after = page.items.last().map(|tx| tx.id.clone());
+            if page.items.len() < page_size as usize || params.pagination.page_after.is_none() {
+                break;
+            }
+        }
+
+        let written = rows.written;
+        rows.finish()?;
+        Ok(written)
+    }
+}
+
+/// Writes rows in one format, adding the CSV header before the first row
+struct RowWriter<W: Write> {
+    writer: W,
+    format: ExportFormat,
+    header_written: bool,
+    written: usize,
+}
+
+impl<W: Write> RowWriter<W> {
+    fn new(writer: W, format: ExportFormat) -> Self {
+        Self {
+            writer,
+            format,
+            header_written: false,
+            written: 0,
+        }
+    }
+
+    fn write(&mut self, row: &TransactionRow) -> CircleResult<()> {
+        let mut line = match self.format {
+            ExportFormat::Csv => {
+                let mut line = String::new();
+                if !self.header_written {
+                    line.push_str(&TRANSACTION_CSV_HEADER.join(","));
+                    line.push('\n');
+                    self.header_written = true;
+                }
+                let fields: Vec<_> = row
+                    .columns()
+                    .iter()
+                    .map(|value| csv_field(value.unwrap_or_default()))
+                    .collect();
+                line.push_str(&fields.join(","));
+                line.into_bytes()
+            }
+            ExportFormat::Jsonl => serde_json::to_vec(row)?,
+        };
+        line.push(b'\n');
+
+        self.writer.write_all(&line).map_err(write_error)?;
+        self.written += 1;
+        Ok(())
+    }
+
+    /// Write the CSV header if no row was written, then flush
+    fn finish(mut self) -> CircleResult<()> {
+        if self.format == ExportFormat::Csv && !self.header_written {
+            let header = format!("{}\n", TRANSACTION_CSV_HEADER.join(","));
+            self.writer
+                .write_all(header.as_bytes())
+                .map_err(write_error)?;
+        }
+        self.writer.flush().map_err(write_error)
+    }
+}
+
+fn write_error(e: std::io::Error) -> CircleError {
+    CircleError::Config(format!("Failed to write transaction export: {}", e))
+}
+
+/// Quote a CSV field if it contains a separator, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// `value` without trailing zeros, or unchanged if it is not a plain decimal
+fn normalize_decimal(value: &str) -> String {
+    Decimal::parse(value)
+        .map(|decimal| decimal.normalize().to_string())
+        .unwrap_or_else(|_| value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{fixtures, MockCircle};
+    use mockito::Matcher;
+    use serde_json::json;
+
+    fn transaction(id: &str) -> Transaction {
+        let mut tx = fixtures::transaction();
+        tx.id = id.to_string();
+        tx
+    }
+
+    #[test]
+    fn test_export_csv_and_jsonl() {
+        let mut tx = transaction("tx-1");
+        tx.amounts = Some(vec!["1.500000".to_string()]);
+        tx.amount_in_usd = Some("1.50".to_string());
+        tx.ref_id = Some("invoice \"42\", March".to_string());
+        let response = Page::new(vec![tx], &Default::default());
+
+        let mut csv = Vec::new();
+        response.export_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let lines: Vec<_> = csv.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("id,createDate,walletId,"));
+        assert!(lines[1].starts_with("tx-1,"));
+        assert!(lines[1].contains(",COMPLETE,"));
+        assert!(lines[1].contains(",1.5,1.5,0.0000882,,"));
+        assert!(lines[1].ends_with(",\"invoice \"\"42\"\", March\""));
+
+        let mut jsonl = Vec::new();
+        response.export_jsonl(&mut jsonl).unwrap();
+        let row: TransactionRow = serde_json::from_slice(&jsonl).unwrap();
+        assert_eq!(row.amount.as_deref(), Some("1.5"));
+        assert_eq!(row.network_fee_in_usd, None);
+        assert_eq!(
+            row.tx_hash,
+            fixtures::transaction().tx_hash,
+            "the hash is copied as is"
+        );
+
+        // An empty page still gets a CSV header
+        let mut csv = Vec::new();
+        Page::<Transaction>::new(vec![], &Default::default())
+            .export_csv(&mut csv)
+            .unwrap();
+        assert_eq!(csv.iter().filter(|&&b| b == b'\n').count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_exporter_pages_between_dates() {
+        let mut circle = MockCircle::start().await;
+        let from: DateTime<Utc> = "2024-01-01T00:00:00Z".parse().unwrap();
+        let to: DateTime<Utc> = "2024-02-01T00:00:00Z".parse().unwrap();
+
+        let range = |page: Matcher| {
+            Matcher::AllOf(vec![
+                Matcher::UrlEncoded("from".to_string(), "2024-01-01T00:00:00+00:00".to_string()),
+                Matcher::UrlEncoded("to".to_string(), "2024-02-01T00:00:00+00:00".to_string()),
+                Matcher::UrlEncoded("order".to_string(), "ASC".to_string()),
+                Matcher::UrlEncoded("pageSize".to_string(), "2".to_string()),
+                page,
+            ])
+        };
+        let first = circle
+            .server()
+            .mock("GET", "/v1/w3s/transactions")
+            .match_query(range(Matcher::Any))
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({ "data": { "transactions": [transaction("tx-1"), transaction("tx-2")] } })
+                    .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+        let second = circle
+            .server()
+            .mock("GET", "/v1/w3s/transactions")
+            .match_query(range(Matcher::UrlEncoded(
+                "pageAfter".to_string(),
+                "tx-2".to_string(),
+            )))
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "data": { "transactions": [transaction("tx-3")] } }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let view = circle.view();
+        let params = ListTransactionsParams {
+            pagination: crate::helper::PaginationParams {
+                page_size: Some(2),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut jsonl = Vec::new();
+        let written = TransactionExporter::new(&view, from, to)
+            .params(params)
+            .export_jsonl(&mut jsonl)
+            .await
+            .unwrap();
+        assert_eq!(written, 3);
+
+        let ids: Vec<String> = String::from_utf8(jsonl)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str::<TransactionRow>(line).unwrap().id)
+            .collect();
+        assert_eq!(ids, ["tx-1", "tx-2", "tx-3"]);
+        second.assert_async().await;
+        first.assert_async().await;
+    }
+}
@@ -0,0 +1,438 @@
+//! Testnet faucet funding with wait-until-funded semantics
+//!
+//! [`fund_and_wait`] tops a testnet wallet up to the balances described by a
+//! [`FundingTarget`]: it checks the current balances, requests only the
+//! missing tokens from Circle's faucet, then polls until every minimum is met
+//! or the timeout expires.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::circle_view::circle_view::CircleView;
+//! use inf_circle_sdk::dev_wallet::faucet::{fund_and_wait, FundingTarget};
+//!
+//! # async fn example(wallet: inf_circle_sdk::dev_wallet::dto::DevWallet) -> Result<(), Box<dyn std::error::Error>> {
+//! let view = CircleView::new()?;
+//!
+//! let target = FundingTarget::new()
+//!     .native("0.01".to_string())
+//!     .usdc("1".to_string());
+//! let report = fund_and_wait(&view, &wallet, target).await?;
+//! for token in &report.tokens {
+//!     println!("{}: {} -> {}", token.asset.symbol(), token.before, token.after);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::cmp::Ordering;
+use std::time::Duration;
+
+use crate::circle_view::circle_view::CircleView;
+use crate::dev_wallet::dto::{DevWallet, QueryParams, RequestTestnetTokensRequest, TokenBalance};
+use crate::helper::{compare_decimal_str, sleep, CircleError, CircleResult, Instant};
+use crate::types::{Blockchain, Stablecoin};
+
+/// An asset the faucet can send
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FaucetAsset {
+    /// The blockchain's native token
+    Native,
+    /// A Circle stablecoin (USDC or EURC)
+    Stablecoin(Stablecoin),
+}
+
+impl FaucetAsset {
+    /// Display symbol: the stablecoin ticker, or "native"
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            FaucetAsset::Native => "native",
+            FaucetAsset::Stablecoin(coin) => coin.symbol(),
+        }
+    }
+
+    fn matches(&self, balance: &TokenBalance, blockchain: &Blockchain) -> bool {
+        match self {
+            FaucetAsset::Native => balance.token.is_native,
+            FaucetAsset::Stablecoin(coin) => match coin.address(blockchain) {
+                Some(address) => balance
+                    .token
+                    .token_address
+                    .as_deref()
+                    .is_some_and(|a| a.eq_ignore_ascii_case(address)),
+                None => balance.token.symbol.as_deref() == Some(coin.symbol()),
+            },
+        }
+    }
+}
+
+/// Minimum balances to reach with [`fund_and_wait`]
+#[derive(Debug, Clone)]
+pub struct FundingTarget {
+    /// Minimum balance per asset, as decimal strings in whole units
+    pub minimums: Vec<(FaucetAsset, String)>,
+    /// How long to wait for the tokens to arrive (default 120s)
+    pub timeout: Duration,
+    /// Delay between balance checks (default 5s)
+    pub poll_interval: Duration,
+}
+
+impl Default for FundingTarget {
+    fn default() -> Self {
+        Self {
+            minimums: Vec::new(),
+            timeout: Duration::from_secs(120),
+            poll_interval: Duration::from_secs(5),
+        }
+    }
+}
+
+impl FundingTarget {
+    /// Create an empty target with the default timeout and poll interval
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Require at least `amount` of the native token
+    pub fn native(self, amount: String) -> Self {
+        self.minimum(FaucetAsset::Native, amount)
+    }
+
+    /// Require at least `amount` USDC
+    pub fn usdc(self, amount: String) -> Self {
+        self.minimum(FaucetAsset::Stablecoin(Stablecoin::Usdc), amount)
+    }
+
+    /// Require at least `amount` EURC
+    pub fn eurc(self, amount: String) -> Self {
+        self.minimum(FaucetAsset::Stablecoin(Stablecoin::Eurc), amount)
+    }
+
+    /// Require at least `amount` of `asset`, replacing any earlier minimum for it
+    pub fn minimum(mut self, asset: FaucetAsset, amount: String) -> Self {
+        self.minimums.retain(|(a, _)| *a != asset);
+        self.minimums.push((asset, amount));
+        self
+    }
+
+    /// Set how long to wait for the tokens to arrive
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set the delay between balance checks
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+}
+
+/// Balance of one asset before and after funding
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FundedToken {
+    /// The asset
+    pub asset: FaucetAsset,
+    /// Required minimum
+    pub minimum: String,
+    /// Balance before funding
+    pub before: String,
+    /// Balance when funding finished
+    pub after: String,
+}
+
+impl FundedToken {
+    /// Whether the minimum was reached
+    pub fn is_funded(&self) -> bool {
+        meets(&self.after, &self.minimum)
+    }
+
+    /// Whether the balance went up while waiting
+    pub fn arrived(&self) -> bool {
+        compare_decimal_str(&self.after, &self.before) == Some(Ordering::Greater)
+    }
+}
+
+/// Outcome of [`fund_and_wait`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FundingReport {
+    /// Whether the faucet was called (false when the wallet was already funded)
+    pub requested: bool,
+    /// One entry per asset in the target
+    pub tokens: Vec<FundedToken>,
+}
+
+impl FundingReport {
+    /// Whether every minimum was reached
+    pub fn is_funded(&self) -> bool {
+        self.tokens.iter().all(FundedToken::is_funded)
+    }
+}
+
+/// Fund a testnet wallet from Circle's faucet and wait for the tokens to arrive
+///
+/// Assets already at their minimum are not requested. If nothing is missing
+/// the faucet is not called and the report has `requested == false`.
+///
+/// # Errors
+///
+/// Returns `CircleError::Config` if the wallet is not on a testnet, the target
+/// is empty, or the minimums are still not met after the timeout (the message
+/// lists which tokens arrived and which are missing). Faucet errors such as
+/// rate limiting are returned as-is.
+pub async fn fund_and_wait(
+    view: &CircleView,
+    wallet: &DevWallet,
+    target: FundingTarget,
+) -> CircleResult<FundingReport> {
+    if !wallet.blockchain.is_testnet() {
+        return Err(CircleError::Config(format!(
+            "The faucet only funds testnet wallets, {} is on {}",
+            wallet.id,
+            wallet.blockchain.as_str()
+        )));
+    }
+    if target.minimums.is_empty() {
+        return Err(CircleError::Config(
+            "Funding target has no minimum balances".to_string(),
+        ));
+    }
+
+    let before = balances(view, wallet, &target).await?;
+    let report = |after: Vec<String>, requested: bool| FundingReport {
+        requested,
+        tokens: target
+            .minimums
+            .iter()
+            .zip(before.iter().zip(after))
+            .map(|((asset, minimum), (before, after))| FundedToken {
+                asset: *asset,
+                minimum: minimum.clone(),
+                before: before.clone(),
+                after,
+            })
+            .collect(),
+    };
+
+    let requested_assets: Vec<FaucetAsset> = target
+        .minimums
+        .iter()
+        .zip(&before)
+        .filter(|((_, minimum), amount)| !meets(amount, minimum))
+        .map(|((asset, _), _)| *asset)
+        .collect();
+    if requested_assets.is_empty() {
+        return Ok(report(before.clone(), false));
+    }
+
+    let wants = |asset: FaucetAsset| requested_assets.contains(&asset).then_some(true);
+    view.request_testnet_tokens(RequestTestnetTokensRequest {
+        blockchain: wallet.blockchain.clone(),
+        address: wallet.address.clone(),
+        native: wants(FaucetAsset::Native),
+        usdc: wants(FaucetAsset::Stablecoin(Stablecoin::Usdc)),
+        eurc: wants(FaucetAsset::Stablecoin(Stablecoin::Eurc)),
+    })
+    .await?;
+
+    let deadline = Instant::now() + target.timeout;
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        sleep(target.poll_interval.min(remaining)).await;
+
+        let report = report(balances(view, wallet, &target).await?, true);
+        if report.is_funded() {
+            return Ok(report);
+        }
+        if Instant::now() >= deadline {
+            return Err(timeout_error(wallet, &report));
+        }
+    }
+}
+
+/// Current balance of each asset in the target, "0" when not held
+async fn balances(
+    view: &CircleView,
+    wallet: &DevWallet,
+    target: &FundingTarget,
+) -> CircleResult<Vec<String>> {
+    let params = QueryParams {
+        include_all: Some(true),
+        ..Default::default()
+    };
+    let balances = view.get_token_balances(&wallet.id, params).await?;
+    Ok(target
+        .minimums
+        .iter()
+        .map(|(asset, _)| {
+            balances
+                .token_balances
+                .iter()
+                .find(|balance| asset.matches(balance, &wallet.blockchain))
+                .map(|balance| balance.amount.clone())
+                .unwrap_or_else(|| "0".to_string())
+        })
+        .collect())
+}
+
+fn meets(amount: &str, minimum: &str) -> bool {
+    matches!(
+        compare_decimal_str(amount, minimum),
+        Some(Ordering::Greater | Ordering::Equal)
+    )
+}
+
+fn timeout_error(wallet: &DevWallet, report: &FundingReport) -> CircleError {
+    let describe = |funded: bool| {
+        let names: Vec<String> = report
+            .tokens
+            .iter()
+            .filter(|token| token.is_funded() == funded)
+            .map(|token| {
+                format!(
+                    "{} {} (min {})",
+                    token.asset.symbol(),
+                    token.after,
+                    token.minimum
+                )
+            })
+            .collect();
+        if names.is_empty() {
+            "none".to_string()
+        } else {
+            names.join(", ")
+        }
+    };
+    CircleError::Config(format!(
+        "Timed out funding wallet {}; funded: {}; missing: {}",
+        wallet.id,
+        describe(true),
+        describe(false)
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{fixtures, MockCircle};
+    use mockito::Matcher;
+    use serde_json::json;
+
+    const USDC_SEPOLIA: &str = "0x1c7D4B196Cb0C7B01d743Fbc6116a902379C7238";
+
+    fn balance(native: bool, amount: &str) -> serde_json::Value {
+        json!({
+            "amount": amount,
+            "token": {
+                "id": if native { "eth" } else { "usdc" },
+                "blockchain": "ETH-SEPOLIA",
+                "decimals": if native { 18 } else { 6 },
+                "isNative": native,
+                "symbol": if native { "ETH-SEPOLIA" } else { "USDC" },
+                "tokenAddress": if native { None } else { Some(USDC_SEPOLIA) },
+                "updateDate": "2024-01-01T00:00:00Z",
+                "createDate": "2024-01-01T00:00:00Z"
+            },
+            "updateDate": "2024-01-01T00:00:00Z"
+        })
+    }
+
+    fn target() -> FundingTarget {
+        FundingTarget::new()
+            .native("0.01".to_string())
+            .usdc("1".to_string())
+            .poll_interval(Duration::from_millis(10))
+    }
+
+    #[tokio::test]
+    async fn test_fund_and_wait_requests_only_missing_tokens() {
+        let mut circle = MockCircle::start().await;
+        let wallet = fixtures::dev_wallet();
+        let path = format!("/v1/w3s/wallets/{}/balances", wallet.id);
+
+        let before = circle
+            .respond(
+                "GET",
+                &path,
+                &json!({ "tokenBalances": [balance(true, "0.5")] }),
+            )
+            .await;
+        let drip = circle
+            .server()
+            .mock("POST", "/v1/faucet/drips")
+            .match_body(Matcher::Json(json!({
+                "blockchain": "ETH-SEPOLIA",
+                "address": wallet.address,
+                "usdc": true
+            })))
+            .with_status(204)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let view = circle.view();
+        let funding = tokio::spawn({
+            let view = view.clone();
+            let wallet = wallet.clone();
+            async move { fund_and_wait(&view, &wallet, target()).await }
+        });
+        sleep(Duration::from_millis(50)).await;
+        before.remove_async().await;
+        circle
+            .respond(
+                "GET",
+                &path,
+                &json!({ "tokenBalances": [balance(true, "0.5"), balance(false, "10")] }),
+            )
+            .await;
+
+        let report = funding.await.unwrap().unwrap();
+        drip.assert_async().await;
+        assert!(report.requested);
+        assert!(report.is_funded());
+        assert!(!report.tokens[0].arrived());
+        assert!(report.tokens[1].arrived());
+        assert_eq!(report.tokens[1].after, "10");
+    }
+
+    #[tokio::test]
+    async fn test_fund_and_wait_skips_funded_wallet_and_times_out() {
+        let mut circle = MockCircle::start().await;
+        let wallet = fixtures::dev_wallet();
+        let path = format!("/v1/w3s/wallets/{}/balances", wallet.id);
+        circle
+            .respond(
+                "GET",
+                &path,
+                &json!({ "tokenBalances": [balance(true, "0.5"), balance(false, "0.2")] }),
+            )
+            .await;
+        circle
+            .server()
+            .mock("POST", "/v1/faucet/drips")
+            .with_status(204)
+            .create_async()
+            .await;
+        let view = circle.view();
+
+        let funded = fund_and_wait(
+            &view,
+            &wallet,
+            FundingTarget::new().native("0.01".to_string()),
+        )
+        .await
+        .unwrap();
+        assert!(!funded.requested);
+
+        let result =
+            fund_and_wait(&view, &wallet, target().timeout(Duration::from_millis(40))).await;
+        assert!(matches!(
+            result,
+            Err(CircleError::Config(message)) if message.contains("missing: USDC 0.2")
+        ));
+
+        let mut mainnet = wallet;
+        mainnet.blockchain = Blockchain::Eth;
+        assert!(fund_and_wait(&view, &mainnet, target()).await.is_err());
+    }
+}
@@ -8,12 +8,28 @@
 //! - **EOA (Externally Owned Account)**: Traditional wallets with a single private key
 //! - **SCA (Smart Contract Account)**: Smart contract-based wallets with advanced features
 //!
+//! # Existing EOAs
+//!
+//! Circle generates the keys of developer-controlled wallets itself and its
+//! API has no endpoint for importing an existing private key, so this SDK
+//! never takes private key material. To bring an existing EOA under Circle
+//! custody, create a wallet (see [`registry`] for get-or-create by name) and
+//! transfer the EOA's funds to its address with your current signer.
+//!
 //! # Main Components
 //!
+//! - [`balance_watch`]: Token balance polling with change detection
+//! - [`batch`]: Concurrent batch transfers with retries and a result report
 //! - [`dev_wallet_ops`]: Write operations (create wallets, transfers, signing, etc.)
 //! - [`dev_wallet_view`]: Read operations (list wallets, query balances, transactions, etc.)
 //! - [`dto`]: Data transfer objects (request/response structures)
+//! - [`export`]: Transaction export to CSV and JSON Lines for accounting
+//! - [`faucet`]: Testnet faucet funding that waits for the tokens to arrive
 //! - [`ops`]: Builder modules for write operations
+//! - [`portfolio`]: Consolidated balances and USD value across wallets
+//! - [`registry`]: Logical wallet names mapped to Circle ref IDs, with get-or-create
+//! - [`transaction_feed`]: Resumable per-wallet transaction history feed
+//! - [`tx_queue`]: Sequential per-wallet transfer queue with priorities and cancellation
 //! - [`views`]: Builder modules for read operations
 //!
 //! # Example
@@ -43,8 +59,16 @@
 //! # }
 //! ```
 
+pub mod balance_watch;
+pub mod batch;
 pub mod dev_wallet_ops;
 pub mod dev_wallet_view;
 pub mod dto;
+pub mod export;
+pub mod faucet;
 pub mod ops;
+pub mod portfolio;
+pub mod registry;
+pub mod transaction_feed;
+pub mod tx_queue;
 pub mod views;
@@ -1,3 +1,8 @@
+use crate::dev_wallet::dto::CancelTransactionRequest;
+use crate::helper::{
+    preview_request, CircleResult, ENTITY_SECRET_PLACEHOLDER, GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER,
+};
+
 /// Builder for creating cancel transaction requests
 ///
 /// This builder helps construct requests to cancel pending transactions.
@@ -6,17 +11,13 @@
 ///
 /// ```rust,no_run
 /// use inf_circle_sdk::dev_wallet::ops::cancel_transaction::CancelTransactionRequestBuilder;
-/// use uuid::Uuid;
 ///
-/// let builder = CancelTransactionRequestBuilder::new(
-///     "transaction-id".to_string(),
-///     Uuid::new_v4().to_string()
-/// ).build();
+/// let builder = CancelTransactionRequestBuilder::new("transaction-id".to_string()).build();
 /// ```
 #[derive(Clone, Debug)]
 pub struct CancelTransactionRequestBuilder {
     pub transaction_id: String,
-    pub idempotency_key: String,
+    pub idempotency_key: Option<String>,
 }
 
 impl CancelTransactionRequestBuilder {
@@ -24,14 +25,52 @@ impl CancelTransactionRequestBuilder {
     ///
     /// # Arguments
     /// * `transaction_id` - The ID of the transaction to cancel
-    /// * `idempotency_key` - Unique identifier for the request
-    pub fn new(transaction_id: String, idempotency_key: String) -> Self {
+    pub fn new(transaction_id: String) -> Self {
         Self {
             transaction_id,
-            idempotency_key,
+            idempotency_key: None,
+        }
+    }
+
+    /// Set a custom idempotency key
+    ///
+    /// If not set, a UUID is generated when the request is sent.
+    pub fn idempotency_key(mut self, key: String) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
+
+    /// Convert the builder into the API request body
+    ///
+    /// `generate_idempotency_key` is only called when no custom key was set.
+    pub(crate) fn into_request(
+        self,
+        entity_secret_ciphertext: String,
+        generate_idempotency_key: impl FnOnce() -> String,
+    ) -> CancelTransactionRequest {
+        CancelTransactionRequest {
+            entity_secret_ciphertext: entity_secret_ciphertext.into(),
+            idempotency_key: self
+                .idempotency_key
+                .unwrap_or_else(generate_idempotency_key),
         }
     }
 
+    /// Preview the request body as pretty JSON
+    ///
+    /// The entity secret ciphertext is replaced by [`ENTITY_SECRET_PLACEHOLDER`] and,
+    /// when no idempotency key was set, the key is shown as
+    /// [`GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER`].
+    pub fn preview(&self) -> CircleResult<String> {
+        preview_request(
+            &self
+                .clone()
+                .into_request(ENTITY_SECRET_PLACEHOLDER.to_string(), || {
+                    GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER.to_string()
+                }),
+        )
+    }
+
     /// Build the CancelTransactionRequestBuilder
     pub fn build(self) -> CancelTransactionRequestBuilder {
         self
@@ -1,4 +1,11 @@
-use crate::dev_wallet::dto::{AbiParameter, FeeLevel};
+use crate::abi::{dto::AbiValue, handler::encode_function_call};
+use crate::dev_wallet::dto::{
+    AbiParameter, CreateContractExecutionTransactionRequest, FeeConfig, FeeLevel,
+};
+use crate::helper::{
+    preview_request, CircleError, CircleResult, ENTITY_SECRET_PLACEHOLDER,
+    GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER,
+};
 
 /// Builder for creating contract execution transaction requests
 ///
@@ -10,12 +17,10 @@ use crate::dev_wallet::dto::{AbiParameter, FeeLevel};
 /// ```rust,no_run
 /// use inf_circle_sdk::dev_wallet::ops::create_contract_transaction::CreateContractExecutionTransactionRequestBuilder;
 /// use inf_circle_sdk::dev_wallet::dto::{AbiParameter, FeeLevel};
-/// use uuid::Uuid;
 ///
 /// let builder = CreateContractExecutionTransactionRequestBuilder::new(
 ///     "wallet-id".to_string(),
 ///     "0xContractAddress".to_string(),
-///     Uuid::new_v4().to_string()
 /// )
 /// .abi_function_signature("transfer(address,uint256)".to_string())
 /// .abi_parameters(vec![
@@ -29,39 +34,39 @@ use crate::dev_wallet::dto::{AbiParameter, FeeLevel};
 pub struct CreateContractExecutionTransactionRequestBuilder {
     pub wallet_id: String,
     pub contract_address: String,
-    pub idempotency_key: String,
+    pub idempotency_key: Option<String>,
     pub abi_function_signature: Option<String>,
     pub abi_parameters: Option<Vec<AbiParameter>>,
     pub call_data: Option<String>,
     pub amount: Option<String>,
-    pub fee_level: Option<FeeLevel>,
-    pub gas_limit: Option<String>,
-    pub gas_price: Option<String>,
-    pub max_fee: Option<String>,
-    pub priority_fee: Option<String>,
+    pub fee: Option<FeeConfig>,
     pub ref_id: Option<String>,
 }
 
 impl CreateContractExecutionTransactionRequestBuilder {
     /// Create a new builder with required fields
-    pub fn new(wallet_id: String, contract_address: String, idempotency_key: String) -> Self {
+    pub fn new(wallet_id: String, contract_address: String) -> Self {
         Self {
             wallet_id,
             contract_address,
-            idempotency_key,
+            idempotency_key: None,
             abi_function_signature: None,
             abi_parameters: None,
             call_data: None,
             amount: None,
-            fee_level: None,
-            gas_limit: None,
-            gas_price: None,
-            max_fee: None,
-            priority_fee: None,
+            fee: None,
             ref_id: None,
         }
     }
 
+    /// Set a custom idempotency key
+    ///
+    /// If not set, a UUID is generated when the request is sent.
+    pub fn idempotency_key(mut self, key: String) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
+
     /// Set the contract ABI function signature (e.g., "burn(uint256)")
     /// Cannot be used simultaneously with callData
     pub fn abi_function_signature(mut self, signature: String) -> Self {
@@ -76,6 +81,27 @@ impl CreateContractExecutionTransactionRequestBuilder {
         self
     }
 
+    /// Set the contract ABI function signature parameters from typed ABI values
+    ///
+    /// Unlike `AbiParameter::Integer`, integers are sent as decimal strings so
+    /// `uint256` amounts keep full precision.
+    /// Should be used exclusively with abiFunctionSignature
+    pub fn abi_values(mut self, values: Vec<AbiValue>) -> Self {
+        self.abi_parameters = Some(values.into_iter().map(AbiParameter::from).collect());
+        self
+    }
+
+    /// ABI-encode a function call locally and set it as the raw call data
+    ///
+    /// Clears any previously set abiFunctionSignature and abiParameters,
+    /// since they are mutually exclusive with callData.
+    pub fn encoded_call(mut self, signature: &str, values: &[AbiValue]) -> Self {
+        self.call_data = Some(encode_function_call(signature, values));
+        self.abi_function_signature = None;
+        self.abi_parameters = None;
+        self
+    }
+
     /// Set the raw transaction data (hexadecimal string with 0x prefix)
     /// Mutually exclusive with abiFunctionSignature and abiParameters
     pub fn call_data(mut self, call_data: String) -> Self {
@@ -89,48 +115,140 @@ impl CreateContractExecutionTransactionRequestBuilder {
         self
     }
 
-    /// Set the fee level (LOW, MEDIUM, or HIGH)
-    /// Cannot be used with gasPrice, priorityFee, or maxFee
-    pub fn fee_level(mut self, fee_level: FeeLevel) -> Self {
-        self.fee_level = Some(fee_level);
+    /// Set the fee settings (fee level, legacy gas price or EIP-1559 fees)
+    pub fn fee(mut self, fee: FeeConfig) -> Self {
+        self.fee = Some(fee);
         self
     }
 
-    /// Set the maximum units of gas to use for the transaction
-    pub fn gas_limit(mut self, gas_limit: String) -> Self {
-        self.gas_limit = Some(gas_limit);
+    /// Set the fee level (LOW, MEDIUM, or HIGH)
+    ///
+    /// Shorthand for `fee(FeeConfig::Level(fee_level))`.
+    pub fn fee_level(mut self, fee_level: FeeLevel) -> Self {
+        self.fee = Some(FeeConfig::Level(fee_level));
         self
     }
 
-    /// Set the maximum price of gas, in gwei
-    /// Requires gasLimit. Cannot be used with feeLevel, priorityFee, or maxFee
-    pub fn gas_price(mut self, gas_price: String) -> Self {
-        self.gas_price = Some(gas_price);
+    /// Set the optional reference or description
+    pub fn ref_id(mut self, ref_id: String) -> Self {
+        self.ref_id = Some(ref_id);
         self
     }
 
-    /// Set the maximum price per unit of gas, in gwei (EIP-1559)
-    /// Requires priorityFee and gasLimit. Cannot be used with feeLevel or gasPrice
-    pub fn max_fee(mut self, max_fee: String) -> Self {
-        self.max_fee = Some(max_fee);
-        self
+    /// Validate the request before anything is sent
+    ///
+    /// The wallet ID and contract address must be non-empty, and exactly one of
+    /// `abi_function_signature` or `call_data` must be set (`abi_parameters`
+    /// only apply with a signature). Fee settings are checked with
+    /// [`FeeConfig::validate`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Config` if a required field is missing, the call is
+    /// specified both or neither way, or the fee settings are invalid.
+    pub fn validate(&self) -> CircleResult<()> {
+        if self.wallet_id.trim().is_empty() {
+            return Err(CircleError::Config(
+                "Contract execution requires a wallet_id".to_string(),
+            ));
+        }
+        if self.contract_address.trim().is_empty() {
+            return Err(CircleError::Config(
+                "Contract execution requires a contract_address".to_string(),
+            ));
+        }
+        match (&self.abi_function_signature, &self.call_data) {
+            (Some(_), Some(_)) => {
+                return Err(CircleError::Config(
+                    "abi_function_signature and call_data are mutually exclusive".to_string(),
+                ));
+            }
+            (None, None) => {
+                return Err(CircleError::Config(
+                    "Contract execution requires an abi_function_signature or call_data"
+                        .to_string(),
+                ));
+            }
+            (None, Some(_)) if self.abi_parameters.is_some() => {
+                return Err(CircleError::Config(
+                    "abi_parameters require an abi_function_signature".to_string(),
+                ));
+            }
+            _ => {}
+        }
+
+        match &self.fee {
+            Some(fee) => fee.validate(),
+            None => Ok(()),
+        }
     }
 
-    /// Set the priority fee, in gwei (EIP-1559)
-    /// Requires maxFee and gasLimit. Cannot be used with feeLevel or gasPrice
-    pub fn priority_fee(mut self, priority_fee: String) -> Self {
-        self.priority_fee = Some(priority_fee);
-        self
+    /// Convert the builder into the API request body
+    ///
+    /// `generate_idempotency_key` is only called when no custom key was set.
+    pub(crate) fn into_request(
+        self,
+        entity_secret_ciphertext: String,
+        generate_idempotency_key: impl FnOnce() -> String,
+    ) -> CreateContractExecutionTransactionRequest {
+        let fee = FeeConfig::into_fields(self.fee);
+        CreateContractExecutionTransactionRequest {
+            wallet_id: self.wallet_id,
+            entity_secret_ciphertext: entity_secret_ciphertext.into(),
+            contract_address: self.contract_address,
+            idempotency_key: self
+                .idempotency_key
+                .unwrap_or_else(generate_idempotency_key),
+            abi_function_signature: self.abi_function_signature,
+            abi_parameters: self.abi_parameters,
+            call_data: self.call_data,
+            amount: self.amount,
+            fee_level: fee.fee_level,
+            gas_limit: fee.gas_limit,
+            gas_price: fee.gas_price,
+            max_fee: fee.max_fee,
+            priority_fee: fee.priority_fee,
+            ref_id: self.ref_id,
+        }
     }
 
-    /// Set the optional reference or description
-    pub fn ref_id(mut self, ref_id: String) -> Self {
-        self.ref_id = Some(ref_id);
-        self
+    /// Preview the request body as pretty JSON
+    ///
+    /// The entity secret ciphertext is replaced by [`ENTITY_SECRET_PLACEHOLDER`] and,
+    /// when no idempotency key was set, the key is shown as
+    /// [`GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER`].
+    pub fn preview(&self) -> CircleResult<String> {
+        preview_request(
+            &self
+                .clone()
+                .into_request(ENTITY_SECRET_PLACEHOLDER.to_string(), || {
+                    GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER.to_string()
+                }),
+        )
     }
 
     /// Build the CreateContractExecutionTransactionRequestBuilder
     pub fn build(self) -> CreateContractExecutionTransactionRequestBuilder {
         self
     }
+
+    /// Build the request, checking it with [`validate`](Self::validate)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use inf_circle_sdk::dev_wallet::ops::create_contract_transaction::CreateContractExecutionTransactionRequestBuilder;
+    ///
+    /// // Neither an ABI function signature nor call data was set
+    /// let result = CreateContractExecutionTransactionRequestBuilder::new(
+    ///     "wallet-id".to_string(),
+    ///     "0xContractAddress".to_string(),
+    /// )
+    /// .try_build();
+    /// assert!(result.is_err());
+    /// ```
+    pub fn try_build(self) -> CircleResult<CreateContractExecutionTransactionRequestBuilder> {
+        self.validate()?;
+        Ok(self)
+    }
 }
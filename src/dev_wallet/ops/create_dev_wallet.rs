@@ -1,5 +1,7 @@
-use crate::dev_wallet::dto::{AccountType, DevWalletMetadata};
-use crate::helper::CircleResult;
+use crate::dev_wallet::dto::{AccountType, CreateDevWalletRequest, DevWalletMetadata};
+use crate::helper::{
+    preview_request, CircleResult, ENTITY_SECRET_PLACEHOLDER, GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER,
+};
 use crate::types::Blockchain;
 
 /// Builder for creating developer wallet requests
@@ -138,6 +140,44 @@ impl CreateDevWalletRequestBuilder {
         self
     }
 
+    /// Convert the builder into the API request body
+    ///
+    /// `generate_idempotency_key` is only called when no custom key was set.
+    pub(crate) fn into_request(
+        self,
+        entity_secret_ciphertext: String,
+        generate_idempotency_key: impl FnOnce() -> String,
+    ) -> CreateDevWalletRequest {
+        CreateDevWalletRequest {
+            wallet_set_id: self.wallet_set_id,
+            entity_secret_ciphertext: entity_secret_ciphertext.into(),
+            blockchains: self.blockchains,
+            idempotency_key: self
+                .idempotency_key
+                .unwrap_or_else(generate_idempotency_key),
+            account_type: self.account_type,
+            count: self.count,
+            metadata: self.metadata,
+            name: self.name,
+            ref_id: self.ref_id,
+        }
+    }
+
+    /// Preview the request body as pretty JSON
+    ///
+    /// The entity secret ciphertext is replaced by [`ENTITY_SECRET_PLACEHOLDER`] and,
+    /// when no idempotency key was set, the key is shown as
+    /// [`GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER`].
+    pub fn preview(&self) -> CircleResult<String> {
+        preview_request(
+            &self
+                .clone()
+                .into_request(ENTITY_SECRET_PLACEHOLDER.to_string(), || {
+                    GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER.to_string()
+                }),
+        )
+    }
+
     /// Build the request parameters
     ///
     /// Returns the builder data for use by the create_dev_wallet method
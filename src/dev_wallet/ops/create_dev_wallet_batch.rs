@@ -0,0 +1,202 @@
+use crate::dev_wallet::dto::{AccountType, DevWallet, DevWalletMetadata};
+use crate::dev_wallet::ops::create_dev_wallet::CreateDevWalletRequestBuilder;
+use crate::helper::{CircleError, CircleResult};
+use crate::types::Blockchain;
+
+/// Maximum number of wallets Circle creates in a single request
+pub const MAX_WALLETS_PER_REQUEST: u32 = 200;
+
+/// Plan for creating many wallets across several blockchains
+///
+/// `CreateDevWalletRequestBuilder` applies one metadata list to every
+/// blockchain in the request. A plan instead lets each wallet on each chain
+/// carry its own name and ref ID; `CircleOps::create_dev_wallet_batch` splits
+/// it into one request per blockchain (chunked to
+/// [`MAX_WALLETS_PER_REQUEST`]) and aggregates the results.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use inf_circle_sdk::dev_wallet::dto::{AccountType, DevWalletMetadata};
+/// use inf_circle_sdk::dev_wallet::ops::create_dev_wallet_batch::BatchWalletPlan;
+/// use inf_circle_sdk::types::Blockchain;
+///
+/// let mut plan = BatchWalletPlan::new("wallet-set-id".to_string()).account_type(AccountType::Sca);
+/// for user in ["alice", "bob"] {
+///     for chain in [Blockchain::EthSepolia, Blockchain::MaticAmoy] {
+///         plan = plan.wallet(
+///             chain.clone(),
+///             DevWalletMetadata {
+///                 name: Some(format!("{} on {}", user, chain.as_str())),
+///                 ref_id: Some(format!("{}-{}", user, chain.as_str())),
+///             },
+///         );
+///     }
+/// }
+/// assert_eq!(plan.len(), 4);
+/// ```
+#[derive(Clone, Debug)]
+pub struct BatchWalletPlan {
+    wallet_set_id: String,
+    account_type: Option<AccountType>,
+    chains: Vec<(Blockchain, Vec<DevWalletMetadata>)>,
+    max_per_request: u32,
+}
+
+impl BatchWalletPlan {
+    /// Create an empty plan for the given wallet set
+    pub fn new(wallet_set_id: String) -> Self {
+        Self {
+            wallet_set_id,
+            account_type: None,
+            chains: Vec::new(),
+            max_per_request: MAX_WALLETS_PER_REQUEST,
+        }
+    }
+
+    /// Set the account type (EOA or SCA) of every wallet in the plan
+    pub fn account_type(mut self, account_type: AccountType) -> Self {
+        self.account_type = Some(account_type);
+        self
+    }
+
+    /// Add one wallet on `blockchain` with its own metadata
+    pub fn wallet(mut self, blockchain: Blockchain, metadata: DevWalletMetadata) -> Self {
+        self.chain_entry(blockchain).push(metadata);
+        self
+    }
+
+    /// Add several wallets on `blockchain`, one per metadata entry
+    pub fn wallets(
+        mut self,
+        blockchain: Blockchain,
+        metadata: impl IntoIterator<Item = DevWalletMetadata>,
+    ) -> Self {
+        self.chain_entry(blockchain).extend(metadata);
+        self
+    }
+
+    /// Limit the number of wallets per API request (capped at [`MAX_WALLETS_PER_REQUEST`])
+    pub fn max_per_request(mut self, max: u32) -> Self {
+        self.max_per_request = max.clamp(1, MAX_WALLETS_PER_REQUEST);
+        self
+    }
+
+    /// Total number of wallets in the plan
+    pub fn len(&self) -> usize {
+        self.chains.iter().map(|(_, wallets)| wallets.len()).sum()
+    }
+
+    /// Whether the plan contains no wallets
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Split the plan into one create request per blockchain and chunk
+    pub(crate) fn requests(&self) -> CircleResult<Vec<CreateDevWalletRequestBuilder>> {
+        let mut requests = Vec::new();
+        for (blockchain, wallets) in &self.chains {
+            for chunk in wallets.chunks(self.max_per_request as usize) {
+                let mut builder = CreateDevWalletRequestBuilder::new(
+                    self.wallet_set_id.clone(),
+                    vec![blockchain.clone()],
+                )?
+                .count(chunk.len() as u32)
+                .metadata(chunk.to_vec());
+                if let Some(account_type) = self.account_type {
+                    builder = builder.account_type(account_type);
+                }
+                requests.push(builder);
+            }
+        }
+        Ok(requests)
+    }
+
+    fn chain_entry(&mut self, blockchain: Blockchain) -> &mut Vec<DevWalletMetadata> {
+        let index = match self
+            .chains
+            .iter()
+            .position(|(chain, _)| *chain == blockchain)
+        {
+            Some(index) => index,
+            None => {
+                self.chains.push((blockchain, Vec::new()));
+                self.chains.len() - 1
+            }
+        };
+        &mut self.chains[index].1
+    }
+}
+
+/// Outcome of `CircleOps::create_dev_wallet_batch`
+#[derive(Debug, Default)]
+pub struct BatchWalletResult {
+    /// Wallets created by the requests that succeeded
+    pub wallets: Vec<DevWallet>,
+
+    /// Requests that failed, with the wallets they would have created
+    pub failures: Vec<BatchWalletFailure>,
+}
+
+impl BatchWalletResult {
+    /// Whether every request in the plan succeeded
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// A failed request within a batch
+#[derive(Debug)]
+pub struct BatchWalletFailure {
+    /// Blockchain of the failed request
+    pub blockchain: Blockchain,
+
+    /// Metadata of the wallets that were not created
+    pub metadata: Vec<DevWalletMetadata>,
+
+    /// Error returned for the request
+    pub error: CircleError,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meta(name: &str) -> DevWalletMetadata {
+        DevWalletMetadata {
+            name: Some(name.to_string()),
+            ref_id: Some(format!("ref-{}", name)),
+        }
+    }
+
+    #[test]
+    fn test_plan_splits_per_chain_and_chunk() {
+        let plan = BatchWalletPlan::new("set".to_string())
+            .account_type(AccountType::Sca)
+            .wallets(
+                Blockchain::EthSepolia,
+                ["a", "b", "c"].into_iter().map(meta),
+            )
+            .wallet(Blockchain::SolDevnet, meta("d"))
+            .wallet(Blockchain::EthSepolia, meta("e"))
+            .max_per_request(2);
+
+        assert_eq!(plan.len(), 5);
+        let requests = plan.requests().unwrap();
+        assert_eq!(requests.len(), 3);
+
+        assert_eq!(requests[0].blockchains, vec![Blockchain::EthSepolia]);
+        assert_eq!(requests[0].count, Some(2));
+        assert_eq!(requests[0].account_type.as_deref(), Some("SCA"));
+        let names: Vec<_> = requests[1]
+            .metadata
+            .iter()
+            .flatten()
+            .filter_map(|m| m.name.as_deref())
+            .collect();
+        assert_eq!(names, vec!["c", "e"]);
+
+        assert_eq!(requests[2].blockchains, vec![Blockchain::SolDevnet]);
+        assert_eq!(requests[2].count, Some(1));
+    }
+}
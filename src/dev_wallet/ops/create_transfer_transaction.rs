@@ -1,5 +1,12 @@
-use crate::dev_wallet::dto::FeeLevel;
+use crate::dev_wallet::dto::{
+    ChainExtras, CreateTransferTransactionRequest, EstimateTransferFeeRequest, FeeConfig, FeeLevel,
+};
+use crate::helper::{
+    compare_decimal_str, preview_request, CircleError, CircleResult, ENTITY_SECRET_PLACEHOLDER,
+    GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER,
+};
 use crate::types::Blockchain;
+use std::cmp::Ordering;
 
 /// Builder for creating transfer transaction requests
 ///
@@ -15,7 +22,7 @@ use crate::types::Blockchain;
 /// let builder = CreateTransferTransactionRequestBuilder::new()
 ///     .wallet_id("wallet-id".to_string())
 ///     .destination_address("0x1234...".to_string())
-///     .amounts(vec!["1000000000000000000".to_string()]) // 1 ETH in wei
+///     .amounts(vec!["1".to_string()]) // 1 ETH
 ///     .blockchain(Blockchain::EthSepolia)
 ///     .build();
 /// ```
@@ -28,14 +35,21 @@ pub struct CreateTransferTransactionRequestBuilder {
     pub nft_token_ids: Option<Vec<String>>,
     pub token_id: Option<String>,
     pub token_address: Option<String>,
-    pub idempotency_key: String,
+    pub token_decimals: Option<u8>,
+    pub idempotency_key: Option<String>,
     pub ref_id: Option<String>,
     pub blockchain: Option<Blockchain>,
-    pub priority_fee: Option<String>,
-    pub fee_level: Option<FeeLevel>,
-    pub gas_limit: Option<String>,
-    pub gas_price: Option<String>,
-    pub max_fee: Option<String>,
+    pub fee: Option<FeeConfig>,
+    pub chain_extras: Option<ChainExtras>,
+    pub validate_destination: bool,
+    pub new_destination_account: bool,
+    pub check_amounts: bool,
+}
+
+impl Default for CreateTransferTransactionRequestBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl CreateTransferTransactionRequestBuilder {
@@ -49,14 +63,15 @@ impl CreateTransferTransactionRequestBuilder {
             nft_token_ids: None,
             token_id: None,
             token_address: None,
-            idempotency_key: String::new(),
+            token_decimals: None,
+            idempotency_key: None,
             ref_id: None,
             blockchain: None,
-            priority_fee: None,
-            fee_level: None,
-            gas_limit: None,
-            gas_price: None,
-            max_fee: None,
+            fee: None,
+            chain_extras: None,
+            validate_destination: false,
+            new_destination_account: false,
+            check_amounts: true,
         }
     }
 
@@ -78,9 +93,12 @@ impl CreateTransferTransactionRequestBuilder {
         self
     }
 
-    /// Set the amounts to transfer (in the token's smallest unit, e.g., wei for ETH)
-    pub fn amounts(mut self, amounts: Vec<String>) -> Self {
-        self.amounts = amounts;
+    /// Set the amounts to transfer, as decimal strings in whole token units (e.g., "0.1" ETH)
+    ///
+    /// Accepts plain strings or [`TokenAmount`](crate::types::TokenAmount) values, which convert from base
+    /// units without decimal mistakes.
+    pub fn amounts<A: Into<String>>(mut self, amounts: impl IntoIterator<Item = A>) -> Self {
+        self.amounts = amounts.into_iter().map(Into::into).collect();
         self
     }
 
@@ -102,9 +120,20 @@ impl CreateTransferTransactionRequestBuilder {
         self
     }
 
+    /// Set the number of decimals of the transferred token
+    ///
+    /// Token amounts are only checked against the dust threshold
+    /// ([`Blockchain::token_dust_threshold`]) when the decimals are known.
+    pub fn token_decimals(mut self, token_decimals: u8) -> Self {
+        self.token_decimals = Some(token_decimals);
+        self
+    }
+
     /// Set a custom idempotency key for the request
+    ///
+    /// If not set, a UUID is generated when the request is sent.
     pub fn idempotency_key(mut self, idempotency_key: String) -> Self {
-        self.idempotency_key = idempotency_key;
+        self.idempotency_key = Some(idempotency_key);
         self
     }
 
@@ -120,38 +149,477 @@ impl CreateTransferTransactionRequestBuilder {
         self
     }
 
-    /// Set the priority fee (EIP-1559 maxPriorityFeePerGas)
-    pub fn priority_fee(mut self, priority_fee: String) -> Self {
-        self.priority_fee = Some(priority_fee);
+    /// Set the fee settings (fee level, legacy gas price or EIP-1559 fees)
+    pub fn fee(mut self, fee: FeeConfig) -> Self {
+        self.fee = Some(fee);
         self
     }
 
     /// Set the fee level (low, medium, high)
+    ///
+    /// Shorthand for `fee(FeeConfig::Level(fee_level))`.
     pub fn fee_level(mut self, fee_level: FeeLevel) -> Self {
-        self.fee_level = Some(fee_level);
+        self.fee = Some(FeeConfig::Level(fee_level));
         self
     }
 
-    /// Set the gas limit for the transaction
-    pub fn gas_limit(mut self, gas_limit: String) -> Self {
-        self.gas_limit = Some(gas_limit);
+    /// Set blockchain-specific extras (NEAR memo, Solana memo or reference keys)
+    ///
+    /// The extras must match the transfer's blockchain; this is checked by
+    /// [`validate`](Self::validate).
+    pub fn chain_extras(mut self, chain_extras: ChainExtras) -> Self {
+        self.chain_extras = Some(chain_extras);
         self
     }
 
-    /// Set the gas price for the transaction
-    pub fn gas_price(mut self, gas_price: String) -> Self {
-        self.gas_price = Some(gas_price);
+    /// Check the destination address with Circle before creating the transfer
+    ///
+    /// When enabled, [`create_dev_transfer_transaction`](crate::circle_ops::circler_ops::CircleOps::create_dev_transfer_transaction)
+    /// checks the destination's format locally (see [`helper::address`](crate::helper::address)),
+    /// then validates it with Circle for the transfer's blockchain, and refuses to
+    /// submit if either check fails. Requires an explicit [`blockchain`](Self::blockchain).
+    pub fn validate_destination(mut self, validate: bool) -> Self {
+        self.validate_destination = validate;
         self
     }
 
-    /// Set the maximum fee (EIP-1559 maxFeePerGas)
-    pub fn max_fee(mut self, max_fee: String) -> Self {
-        self.max_fee = Some(max_fee);
+    /// Mark the destination as an account that does not exist yet
+    ///
+    /// Native transfers are then also checked against
+    /// [`Blockchain::new_account_minimum`], the amount needed to create the
+    /// account on Solana and NEAR. Off by default, since transfers to funded
+    /// accounts may be smaller.
+    pub fn new_destination_account(mut self, new_account: bool) -> Self {
+        self.new_destination_account = new_account;
         self
     }
 
+    /// Check transfer amounts against the blockchain's minimums (on by default)
+    ///
+    /// Disable to submit amounts that [`validate`](Self::validate) would reject
+    /// and leave the decision to Circle.
+    pub fn check_amounts(mut self, check_amounts: bool) -> Self {
+        self.check_amounts = check_amounts;
+        self
+    }
+
+    /// Validate the request before anything is sent
+    ///
+    /// Required fields are checked first: a source wallet (`wallet_id`, or
+    /// `wallet_address` together with `blockchain`), a destination address, and
+    /// at least one amount or NFT token ID.
+    ///
+    /// Transfer amounts are then validated against the blockchain's minimums,
+    /// unless disabled with [`check_amounts`](Self::check_amounts). Native
+    /// transfers are checked against [`Blockchain::min_native_transfer_amount`],
+    /// or [`Blockchain::new_account_minimum`] for a
+    /// [`new_destination_account`](Self::new_destination_account). Token
+    /// transfers are checked against [`Blockchain::token_dust_threshold`] when
+    /// [`token_decimals`](Self::token_decimals) is set. NFT transfers and
+    /// requests without an explicit blockchain are not checked.
+    ///
+    /// Chain extras are checked against the blockchain, and Solana reference keys
+    /// must be base58 encoded 32-byte public keys. Fee settings are checked with
+    /// [`FeeConfig::validate`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::AmountTooSmall` if an amount is below the minimum, or
+    /// `CircleError::Config` if a required field is missing, an amount is not a
+    /// valid decimal string, the chain extras do not apply to the blockchain or
+    /// the fee settings are invalid.
+    pub fn validate(&self) -> CircleResult<()> {
+        self.validate_required_fields()?;
+        self.validate_chain_extras()?;
+        if let Some(fee) = &self.fee {
+            fee.validate()?;
+        }
+
+        let Some(blockchain) = &self.blockchain else {
+            return Ok(());
+        };
+        if !self.check_amounts || self.nft_token_ids.is_some() {
+            return Ok(());
+        }
+
+        let minimum = if self.token_id.is_some() || self.token_address.is_some() {
+            match self.token_decimals {
+                Some(decimals) => blockchain.token_dust_threshold(decimals),
+                None => return Ok(()),
+            }
+        } else {
+            blockchain
+                .new_account_minimum()
+                .filter(|_| self.new_destination_account)
+                .unwrap_or(blockchain.min_native_transfer_amount())
+                .to_string()
+        };
+
+        for amount in &self.amounts {
+            match compare_decimal_str(amount, &minimum) {
+                Some(Ordering::Less) => {
+                    return Err(CircleError::AmountTooSmall {
+                        amount: amount.clone(),
+                        minimum: minimum.clone(),
+                        blockchain: blockchain.as_str().to_string(),
+                    });
+                }
+                Some(_) => {}
+                None => {
+                    return Err(CircleError::Config(format!(
+                        "Invalid transfer amount: {}",
+                        amount
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_required_fields(&self) -> CircleResult<()> {
+        if self.wallet_id.is_none() && self.wallet_address.is_none() {
+            return Err(CircleError::Config(
+                "Transfer requires a wallet_id or wallet_address".to_string(),
+            ));
+        }
+        if self.wallet_id.is_none() && self.blockchain.is_none() {
+            return Err(CircleError::Config(
+                "Transfer from a wallet_address requires a blockchain".to_string(),
+            ));
+        }
+        if self.destination_address.trim().is_empty() {
+            return Err(CircleError::Config(
+                "Transfer requires a destination_address".to_string(),
+            ));
+        }
+        let has_nfts = self
+            .nft_token_ids
+            .as_ref()
+            .is_some_and(|ids| !ids.is_empty());
+        if self.amounts.is_empty() && !has_nfts {
+            return Err(CircleError::Config(
+                "Transfer requires amounts or nft_token_ids".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn validate_chain_extras(&self) -> CircleResult<()> {
+        let Some(extras) = &self.chain_extras else {
+            return Ok(());
+        };
+
+        let Some(blockchain) = &self.blockchain else {
+            return Err(CircleError::Config(format!(
+                "{} chain extras require an explicit blockchain",
+                extras.chain_name()
+            )));
+        };
+        if !extras.supports(blockchain) {
+            return Err(CircleError::Config(format!(
+                "{} chain extras cannot be used on {}",
+                extras.chain_name(),
+                blockchain.as_str()
+            )));
+        }
+
+        if let ChainExtras::Solana { reference_keys, .. } = extras {
+            for key in reference_keys {
+                let valid = bs58::decode(key)
+                    .into_vec()
+                    .map(|bytes| bytes.len() == 32)
+                    .unwrap_or(false);
+                if !valid {
+                    return Err(CircleError::Config(format!(
+                        "Invalid Solana reference key: {}",
+                        key
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The fee estimate request for this transfer
+    pub(crate) fn estimate_request(&self) -> EstimateTransferFeeRequest {
+        EstimateTransferFeeRequest {
+            destination_address: self.destination_address.clone(),
+            amounts: self.amounts.clone(),
+            nft_token_ids: self.nft_token_ids.clone(),
+            source_address: self.wallet_address.clone(),
+            token_id: self.token_id.clone(),
+            token_address: self.token_address.clone(),
+            blockchain: self.blockchain.as_ref().map(|b| b.as_str().to_string()),
+            wallet_id: self.wallet_id.clone(),
+        }
+    }
+
+    /// Convert the builder into the API request body
+    ///
+    /// `generate_idempotency_key` is only called when no custom key was set.
+    pub(crate) fn into_request(
+        self,
+        entity_secret_ciphertext: String,
+        generate_idempotency_key: impl FnOnce() -> String,
+    ) -> CreateTransferTransactionRequest {
+        let fee = FeeConfig::into_fields(self.fee);
+        CreateTransferTransactionRequest {
+            entity_secret_ciphertext: entity_secret_ciphertext.into(),
+            wallet_id: self.wallet_id,
+            wallet_address: self.wallet_address,
+            destination_address: self.destination_address,
+            amounts: self.amounts,
+            nft_token_ids: self.nft_token_ids,
+            token_id: self.token_id,
+            token_address: self.token_address,
+            idempotency_key: self
+                .idempotency_key
+                .unwrap_or_else(generate_idempotency_key),
+            ref_id: self.ref_id,
+            blockchain: self.blockchain,
+            gas_limit: fee.gas_limit,
+            gas_price: fee.gas_price,
+            max_fee: fee.max_fee,
+            priority_fee: fee.priority_fee,
+            fee_level: fee.fee_level,
+            chain_extras: self.chain_extras,
+        }
+    }
+
+    /// Preview the request body as pretty JSON
+    ///
+    /// The entity secret ciphertext is replaced by [`ENTITY_SECRET_PLACEHOLDER`] and,
+    /// when no idempotency key was set, the key is shown as
+    /// [`GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER`].
+    pub fn preview(&self) -> CircleResult<String> {
+        preview_request(
+            &self
+                .clone()
+                .into_request(ENTITY_SECRET_PLACEHOLDER.to_string(), || {
+                    GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER.to_string()
+                }),
+        )
+    }
+
     /// Build the transfer transaction request
     pub fn build(self) -> CreateTransferTransactionRequestBuilder {
         self
     }
+
+    /// Build the transfer transaction request, checking it with [`validate`](Self::validate)
+    ///
+    /// Catches missing required fields and invalid amounts at construction
+    /// time instead of when the request is submitted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use inf_circle_sdk::dev_wallet::ops::create_transfer_transaction::CreateTransferTransactionRequestBuilder;
+    ///
+    /// let missing_destination = CreateTransferTransactionRequestBuilder::new()
+    ///     .wallet_id("wallet-id".to_string())
+    ///     .amounts(["1"])
+    ///     .try_build();
+    /// assert!(missing_destination.is_err());
+    /// ```
+    pub fn try_build(self) -> CircleResult<CreateTransferTransactionRequestBuilder> {
+        self.validate()?;
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn native_transfer(
+        blockchain: Blockchain,
+        amount: &str,
+    ) -> CreateTransferTransactionRequestBuilder {
+        CreateTransferTransactionRequestBuilder::new()
+            .wallet_id("wallet-id".to_string())
+            .destination_address("destination".to_string())
+            .amounts(vec![amount.to_string()])
+            .blockchain(blockchain)
+            .build()
+    }
+
+    #[test]
+    fn test_validate_native_minimum() {
+        assert!(native_transfer(Blockchain::SolDevnet, "0.0001")
+            .validate()
+            .is_ok());
+        assert!(native_transfer(Blockchain::SolDevnet, "0.001")
+            .new_destination_account(true)
+            .validate()
+            .is_ok());
+
+        let err = native_transfer(Blockchain::SolDevnet, "0.0001")
+            .new_destination_account(true)
+            .validate()
+            .unwrap_err();
+        assert!(matches!(err, CircleError::AmountTooSmall { .. }));
+        assert_eq!(
+            err.to_string(),
+            "Amount 0.0001 is below the minimum of 0.00089088 on SOL-DEVNET"
+        );
+    }
+
+    #[test]
+    fn test_validate_required_fields() {
+        let missing = |builder: CreateTransferTransactionRequestBuilder| {
+            matches!(builder.try_build(), Err(CircleError::Config(_)))
+        };
+        let transfer = native_transfer(Blockchain::EthSepolia, "0.01");
+        assert!(transfer.clone().try_build().is_ok());
+
+        let mut no_wallet = transfer.clone();
+        no_wallet.wallet_id = None;
+        assert!(missing(no_wallet.clone()));
+        assert!(no_wallet
+            .wallet_address("0x1111".to_string())
+            .try_build()
+            .is_ok());
+
+        let mut no_blockchain = transfer.clone();
+        no_blockchain.wallet_id = None;
+        no_blockchain.blockchain = None;
+        assert!(missing(no_blockchain.wallet_address("0x1111".to_string())));
+
+        assert!(missing(transfer.clone().destination_address(String::new())));
+        assert!(missing(transfer.clone().amounts(Vec::<String>::new())));
+        assert!(transfer
+            .amounts(Vec::<String>::new())
+            .nft_token_ids(vec!["1".to_string()])
+            .try_build()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_validate_token_dust() {
+        let token = |amount: &str| {
+            native_transfer(Blockchain::EthSepolia, amount)
+                .token_address("0x1c7D4B196Cb0C7B01d743Fbc6116a902379C7238".to_string())
+        };
+        assert!(matches!(
+            token("0.0000001").token_decimals(6).validate(),
+            Err(CircleError::AmountTooSmall { .. })
+        ));
+        assert!(token("0.000001").token_decimals(6).validate().is_ok());
+        assert!(token("0.0000001").token_decimals(18).validate().is_ok());
+        assert!(token("0.0000001").validate().is_ok());
+    }
+
+    #[test]
+    fn test_check_amounts_can_be_disabled() {
+        let builder = native_transfer(Blockchain::EthSepolia, "0");
+        assert!(builder.validate().is_err());
+        assert!(builder.check_amounts(false).validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_zero_and_garbage() {
+        assert!(matches!(
+            native_transfer(Blockchain::EthSepolia, "0").validate(),
+            Err(CircleError::AmountTooSmall { .. })
+        ));
+        assert!(matches!(
+            native_transfer(Blockchain::EthSepolia, "one").validate(),
+            Err(CircleError::Config(_))
+        ));
+    }
+
+    #[test]
+    fn test_chain_extras_must_match_blockchain() {
+        let near = native_transfer(Blockchain::NearTestnet, "0.01")
+            .chain_extras(ChainExtras::near_memo("deposit-1"));
+        assert!(near.validate().is_ok());
+
+        let evm = native_transfer(Blockchain::EthSepolia, "0.01")
+            .chain_extras(ChainExtras::near_memo("deposit-1"));
+        assert!(matches!(evm.validate(), Err(CircleError::Config(_))));
+
+        let bad_key = native_transfer(Blockchain::SolDevnet, "0.01").chain_extras(
+            ChainExtras::solana_reference_keys(vec!["not-base58!".to_string()]),
+        );
+        assert!(matches!(bad_key.validate(), Err(CircleError::Config(_))));
+
+        let reference = bs58::encode([7u8; 32]).into_string();
+        let sol = native_transfer(Blockchain::SolDevnet, "0.01")
+            .chain_extras(ChainExtras::solana_reference_keys(vec![reference]));
+        assert!(sol.validate().is_ok());
+    }
+
+    #[test]
+    fn test_chain_extras_serialization() {
+        let extras = ChainExtras::Solana {
+            memo: Some("order-7".to_string()),
+            reference_keys: vec!["Ref111".to_string()],
+        };
+        let json = serde_json::to_value(&extras).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "memo": "order-7", "referenceKeys": ["Ref111"] })
+        );
+    }
+
+    #[test]
+    fn test_amounts_accept_token_amount() {
+        use crate::{abi::U256, types::TokenAmount};
+
+        let builder = CreateTransferTransactionRequestBuilder::new()
+            .amounts([TokenAmount::new(U256::from(2_500_000u64), 6)]);
+        assert_eq!(builder.amounts, vec!["2.5".to_string()]);
+    }
+
+    #[test]
+    fn test_fee_config() {
+        let preview = native_transfer(Blockchain::EthSepolia, "0.01")
+            .fee(FeeConfig::eip1559(
+                "50".to_string(),
+                "2".to_string(),
+                "21000".to_string(),
+            ))
+            .preview()
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_str(&preview).unwrap();
+        assert_eq!(body["maxFee"], "50");
+        assert_eq!(body["priorityFee"], "2");
+        assert_eq!(body["gasLimit"], "21000");
+        assert!(body.get("feeLevel").is_none());
+        assert!(body.get("gasPrice").is_none());
+
+        let level = native_transfer(Blockchain::EthSepolia, "0.01").fee_level(FeeLevel::High);
+        assert_eq!(level.fee, Some(FeeConfig::Level(FeeLevel::High)));
+
+        for fee in [
+            FeeConfig::legacy("0".to_string(), "21000".to_string()),
+            FeeConfig::legacy("20".to_string(), "21000.5".to_string()),
+            FeeConfig::eip1559("1".to_string(), "2".to_string(), "21000".to_string()),
+        ] {
+            let builder = native_transfer(Blockchain::EthSepolia, "0.01").fee(fee);
+            assert!(matches!(builder.validate(), Err(CircleError::Config(_))));
+        }
+        let zero_tip = FeeConfig::eip1559("1".to_string(), "0".to_string(), "21000".to_string());
+        assert!(zero_tip.validate().is_ok());
+    }
+
+    #[test]
+    fn test_preview_masks_entity_secret() {
+        let preview = native_transfer(Blockchain::NearTestnet, "0.5")
+            .idempotency_key("key-1".to_string())
+            .chain_extras(ChainExtras::near_memo("deposit-1"))
+            .preview()
+            .unwrap();
+
+        let body: serde_json::Value = serde_json::from_str(&preview).unwrap();
+        assert_eq!(body["entitySecretCiphertext"], ENTITY_SECRET_PLACEHOLDER);
+        assert_eq!(body["idempotencyKey"], "key-1");
+        assert_eq!(body["amounts"], serde_json::json!(["0.5"]));
+        assert_eq!(body["blockchain"], "NEAR-TESTNET");
+        assert_eq!(body["memo"], "deposit-1");
+        assert!(preview.contains('\n'));
+    }
 }
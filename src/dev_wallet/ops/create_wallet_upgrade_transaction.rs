@@ -1,4 +1,7 @@
-use crate::dev_wallet::dto::{FeeLevel, ScaCore};
+use crate::dev_wallet::dto::{CreateWalletUpgradeTransactionRequest, FeeConfig, FeeLevel, ScaCore};
+use crate::helper::{
+    preview_request, CircleResult, ENTITY_SECRET_PLACEHOLDER, GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER,
+};
 
 /// Builder for creating wallet upgrade transaction requests
 ///
@@ -9,12 +12,10 @@ use crate::dev_wallet::dto::{FeeLevel, ScaCore};
 /// ```rust,no_run
 /// use inf_circle_sdk::dev_wallet::ops::create_wallet_upgrade_transaction::CreateWalletUpgradeTransactionRequestBuilder;
 /// use inf_circle_sdk::dev_wallet::dto::{FeeLevel, ScaCore};
-/// use uuid::Uuid;
 ///
 /// let builder = CreateWalletUpgradeTransactionRequestBuilder::new(
 ///     "wallet-id".to_string(),
 ///     ScaCore::Circle6900SingleownerV3,
-///     Uuid::new_v4().to_string()
 /// )
 /// .fee_level(FeeLevel::Medium)
 /// .build();
@@ -23,69 +24,101 @@ use crate::dev_wallet::dto::{FeeLevel, ScaCore};
 pub struct CreateWalletUpgradeTransactionRequestBuilder {
     pub wallet_id: String,
     pub new_sca_core: ScaCore,
-    pub idempotency_key: String,
-    pub fee_level: Option<FeeLevel>,
-    pub gas_limit: Option<String>,
-    pub gas_price: Option<String>,
-    pub max_fee: Option<String>,
-    pub priority_fee: Option<String>,
+    pub idempotency_key: Option<String>,
+    pub fee: Option<FeeConfig>,
     pub ref_id: Option<String>,
 }
 
 impl CreateWalletUpgradeTransactionRequestBuilder {
     /// Create a new builder with required fields
-    pub fn new(wallet_id: String, new_sca_core: ScaCore, idempotency_key: String) -> Self {
+    pub fn new(wallet_id: String, new_sca_core: ScaCore) -> Self {
         Self {
             wallet_id,
             new_sca_core,
-            idempotency_key,
-            fee_level: None,
-            gas_limit: None,
-            gas_price: None,
-            max_fee: None,
-            priority_fee: None,
+            idempotency_key: None,
+            fee: None,
             ref_id: None,
         }
     }
 
-    /// Set the fee level (LOW, MEDIUM, or HIGH)
-    /// Cannot be used with gasPrice, priorityFee, or maxFee
-    pub fn fee_level(mut self, fee_level: FeeLevel) -> Self {
-        self.fee_level = Some(fee_level);
+    /// Set a custom idempotency key
+    ///
+    /// If not set, a UUID is generated when the request is sent.
+    pub fn idempotency_key(mut self, key: String) -> Self {
+        self.idempotency_key = Some(key);
         self
     }
 
-    /// Set the maximum units of gas to use for the transaction
-    pub fn gas_limit(mut self, gas_limit: String) -> Self {
-        self.gas_limit = Some(gas_limit);
+    /// Set the fee settings (fee level, legacy gas price or EIP-1559 fees)
+    pub fn fee(mut self, fee: FeeConfig) -> Self {
+        self.fee = Some(fee);
         self
     }
 
-    /// Set the maximum price of gas, in gwei
-    /// Requires gasLimit. Cannot be used with feeLevel, priorityFee, or maxFee
-    pub fn gas_price(mut self, gas_price: String) -> Self {
-        self.gas_price = Some(gas_price);
+    /// Set the fee level (LOW, MEDIUM, or HIGH)
+    ///
+    /// Shorthand for `fee(FeeConfig::Level(fee_level))`.
+    pub fn fee_level(mut self, fee_level: FeeLevel) -> Self {
+        self.fee = Some(FeeConfig::Level(fee_level));
         self
     }
 
-    /// Set the maximum price per unit of gas, in gwei (EIP-1559)
-    /// Requires priorityFee and gasLimit. Cannot be used with feeLevel or gasPrice
-    pub fn max_fee(mut self, max_fee: String) -> Self {
-        self.max_fee = Some(max_fee);
+    /// Set the optional reference or description
+    pub fn ref_id(mut self, ref_id: String) -> Self {
+        self.ref_id = Some(ref_id);
         self
     }
 
-    /// Set the priority fee, in gwei (EIP-1559)
-    /// Requires maxFee and gasLimit. Cannot be used with feeLevel or gasPrice
-    pub fn priority_fee(mut self, priority_fee: String) -> Self {
-        self.priority_fee = Some(priority_fee);
-        self
+    /// Validate the fee settings with [`FeeConfig::validate`]
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Config` if the fee settings are invalid.
+    pub fn validate(&self) -> CircleResult<()> {
+        match &self.fee {
+            Some(fee) => fee.validate(),
+            None => Ok(()),
+        }
     }
 
-    /// Set the optional reference or description
-    pub fn ref_id(mut self, ref_id: String) -> Self {
-        self.ref_id = Some(ref_id);
-        self
+    /// Convert the builder into the API request body
+    ///
+    /// `generate_idempotency_key` is only called when no custom key was set.
+    pub(crate) fn into_request(
+        self,
+        entity_secret_ciphertext: String,
+        generate_idempotency_key: impl FnOnce() -> String,
+    ) -> CreateWalletUpgradeTransactionRequest {
+        let fee = FeeConfig::into_fields(self.fee);
+        CreateWalletUpgradeTransactionRequest {
+            wallet_id: self.wallet_id,
+            entity_secret_ciphertext: entity_secret_ciphertext.into(),
+            new_sca_core: self.new_sca_core.as_str().to_string(),
+            idempotency_key: self
+                .idempotency_key
+                .unwrap_or_else(generate_idempotency_key),
+            fee_level: fee.fee_level,
+            gas_limit: fee.gas_limit,
+            gas_price: fee.gas_price,
+            max_fee: fee.max_fee,
+            priority_fee: fee.priority_fee,
+            ref_id: self.ref_id,
+        }
+    }
+
+    /// Preview the request body as pretty JSON
+    ///
+    /// The entity secret ciphertext is replaced by [`ENTITY_SECRET_PLACEHOLDER`] and,
+    /// when no idempotency key was set, the key is shown as
+    /// [`GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER`].
+    pub fn preview(&self) -> CircleResult<String> {
+        preview_request(
+            &self
+                .clone()
+                .into_request(ENTITY_SECRET_PLACEHOLDER.to_string(), || {
+                    GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER.to_string()
+                }),
+        )
     }
 
     /// Build the CreateWalletUpgradeTransactionRequestBuilder
@@ -0,0 +1,86 @@
+use std::time::Duration;
+
+use crate::dev_wallet::dto::AccountType;
+use crate::helper::deterministic_uuid;
+use crate::types::Blockchain;
+
+/// Options for `CircleOps::ensure_wallet`
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use inf_circle_sdk::dev_wallet::dto::AccountType;
+/// use inf_circle_sdk::dev_wallet::ops::ensure_wallet::EnsureWalletOptions;
+///
+/// let options = EnsureWalletOptions::new()
+///     .account_type(AccountType::Sca)
+///     .name("Treasury")
+///     .relist_timeout(Duration::from_secs(10));
+/// ```
+#[derive(Clone, Debug)]
+pub struct EnsureWalletOptions {
+    pub(crate) account_type: AccountType,
+    pub(crate) name: Option<String>,
+    pub(crate) relist_timeout: Duration,
+}
+
+impl Default for EnsureWalletOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EnsureWalletOptions {
+    /// EOA wallets without a name; wait up to 3s for the wallet to be listed after a create conflict
+    pub fn new() -> Self {
+        Self {
+            account_type: AccountType::Eoa,
+            name: None,
+            relist_timeout: Duration::from_secs(3),
+        }
+    }
+
+    /// Account type of the wallet if it has to be created
+    pub fn account_type(mut self, account_type: AccountType) -> Self {
+        self.account_type = account_type;
+        self
+    }
+
+    /// Name of the wallet if it has to be created
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// How long to wait for the wallet to be listed when a concurrent create wins the race
+    ///
+    /// Listing is polled with [`await_visible_where`](crate::helper::await_visible_where).
+    pub fn relist_timeout(mut self, timeout: Duration) -> Self {
+        self.relist_timeout = timeout;
+        self
+    }
+
+    /// Wait up to `attempts` times `delay` for the wallet to be listed
+    #[deprecated(since = "0.2.7", note = "use `relist_timeout`")]
+    pub fn relist(self, attempts: u32, delay: Duration) -> Self {
+        self.relist_timeout(delay * attempts)
+    }
+}
+
+/// Idempotency key `ensure_wallet` creates the wallet with
+///
+/// Derived from the wallet set, blockchain and ref ID, so every caller
+/// racing to create the same wallet sends the same key.
+pub fn ensure_wallet_idempotency_key(
+    wallet_set_id: &str,
+    blockchain: &Blockchain,
+    ref_id: &str,
+) -> String {
+    deterministic_uuid(&format!(
+        "{}:{}:{}",
+        wallet_set_id,
+        blockchain.as_str(),
+        ref_id
+    ))
+}
@@ -2,9 +2,12 @@ pub mod accelerate_transaction;
 pub mod cancel_transaction;
 pub mod create_contract_transaction;
 pub mod create_dev_wallet;
+pub mod create_dev_wallet_batch;
 pub mod create_transfer_transaction;
 pub mod create_wallet_upgrade_transaction;
+pub mod ensure_wallet;
 pub mod sign_data;
 pub mod sign_delegate;
 pub mod sign_message;
 pub mod sign_transaction;
+pub mod transfer_template;
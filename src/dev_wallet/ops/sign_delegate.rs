@@ -1,4 +1,5 @@
-use crate::helper::CircleResult;
+use crate::dev_wallet::dto::SignDelegateRequest;
+use crate::helper::{preview_request, CircleResult, ENTITY_SECRET_PLACEHOLDER};
 
 /// Builder for creating delegate action signing requests
 ///
@@ -50,6 +51,27 @@ impl SignDelegateRequestBuilder {
         self
     }
 
+    /// Convert the builder into the API request body
+    pub(crate) fn into_request(self, entity_secret_ciphertext: String) -> SignDelegateRequest {
+        SignDelegateRequest {
+            entity_secret_ciphertext: entity_secret_ciphertext.into(),
+            unsigned_delegate_action: self.unsigned_delegate_action,
+            wallet_id: self.wallet_id,
+        }
+    }
+
+    /// Preview the request body as pretty JSON
+    ///
+    /// The entity secret ciphertext is replaced by [`ENTITY_SECRET_PLACEHOLDER`];
+    /// everything else is exactly what will be sent.
+    pub fn preview(&self) -> CircleResult<String> {
+        preview_request(
+            &self
+                .clone()
+                .into_request(ENTITY_SECRET_PLACEHOLDER.to_string()),
+        )
+    }
+
     /// Build the sign delegate request
     pub fn build(self) -> SignDelegateRequestBuilder {
         self
@@ -1,4 +1,5 @@
-use crate::helper::CircleResult;
+use crate::dev_wallet::dto::SignMessageRequest;
+use crate::helper::{preview_request, CircleResult, ENTITY_SECRET_PLACEHOLDER};
 
 /// Builder for creating message signing requests
 ///
@@ -62,6 +63,29 @@ impl SignMessageRequestBuilder {
         self
     }
 
+    /// Convert the builder into the API request body
+    pub(crate) fn into_request(self, entity_secret_ciphertext: String) -> SignMessageRequest {
+        SignMessageRequest {
+            entity_secret_ciphertext: entity_secret_ciphertext.into(),
+            message: self.message,
+            wallet_id: self.wallet_id,
+            encoded_by_hex: self.encoded_by_hex,
+            memo: self.memo,
+        }
+    }
+
+    /// Preview the request body as pretty JSON
+    ///
+    /// The entity secret ciphertext is replaced by [`ENTITY_SECRET_PLACEHOLDER`];
+    /// everything else is exactly what will be sent.
+    pub fn preview(&self) -> CircleResult<String> {
+        preview_request(
+            &self
+                .clone()
+                .into_request(ENTITY_SECRET_PLACEHOLDER.to_string()),
+        )
+    }
+
     /// Build the sign message request
     pub fn build(self) -> SignMessageRequestBuilder {
         self
@@ -1,4 +1,5 @@
-use crate::helper::CircleResult;
+use crate::dev_wallet::dto::SignTransactionRequest;
+use crate::helper::{preview_request, CircleResult, ENTITY_SECRET_PLACEHOLDER};
 
 /// Builder for creating transaction signing requests
 ///
@@ -73,6 +74,29 @@ impl SignTransactionRequestBuilder {
         self
     }
 
+    /// Convert the builder into the API request body
+    pub(crate) fn into_request(self, entity_secret_ciphertext: String) -> SignTransactionRequest {
+        SignTransactionRequest {
+            entity_secret_ciphertext: entity_secret_ciphertext.into(),
+            raw_transaction: self.raw_transaction,
+            transaction: self.transaction,
+            wallet_id: self.wallet_id,
+            memo: self.memo,
+        }
+    }
+
+    /// Preview the request body as pretty JSON
+    ///
+    /// The entity secret ciphertext is replaced by [`ENTITY_SECRET_PLACEHOLDER`];
+    /// everything else is exactly what will be sent.
+    pub fn preview(&self) -> CircleResult<String> {
+        preview_request(
+            &self
+                .clone()
+                .into_request(ENTITY_SECRET_PLACEHOLDER.to_string()),
+        )
+    }
+
     /// Build the sign transaction request
     pub fn build(self) -> SignTransactionRequestBuilder {
         self
@@ -0,0 +1,211 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::dev_wallet::dto::{FeeConfig, FeeLevel};
+use crate::dev_wallet::ops::create_transfer_transaction::CreateTransferTransactionRequestBuilder;
+use crate::types::Blockchain;
+
+/// Reusable transfer settings for payout loops
+///
+/// A template captures everything about a transfer except its destination and
+/// amount: source wallet, token, blockchain, fee policy and a ref ID prefix.
+/// Every transfer created from the template gets exactly the same settings,
+/// which keeps the fee policy consistent across large payout runs.
+///
+/// Each instantiated request gets its own idempotency key when it is sent,
+/// unless the caller sets one on the returned builder.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+/// use inf_circle_sdk::dev_wallet::dto::FeeLevel;
+/// use inf_circle_sdk::dev_wallet::ops::transfer_template::TransferTemplate;
+/// use inf_circle_sdk::types::Blockchain;
+///
+/// # async fn example(payouts: Vec<(String, String)>) -> Result<(), Box<dyn std::error::Error>> {
+/// let ops = CircleOps::new(None)?;
+/// let template = TransferTemplate::new()
+///     .wallet_id("treasury-wallet-id".to_string())
+///     .token_id("usdc-token-id".to_string())
+///     .blockchain(Blockchain::MaticAmoy)
+///     .fee_level(FeeLevel::Medium)
+///     .ref_id_prefix("payout-2025-01".to_string());
+///
+/// for (destination, amount) in payouts {
+///     let transfer = template.instantiate(destination, amount);
+///     ops.create_dev_transfer_transaction(transfer).await?; // ref IDs payout-2025-01-1, -2, ...
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct TransferTemplate {
+    base: CreateTransferTransactionRequestBuilder,
+    ref_id_prefix: Option<String>,
+    sequence: AtomicU64,
+}
+
+impl Default for TransferTemplate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TransferTemplate {
+    /// Create an empty template
+    pub fn new() -> Self {
+        Self {
+            base: CreateTransferTransactionRequestBuilder::new(),
+            ref_id_prefix: None,
+            sequence: AtomicU64::new(0),
+        }
+    }
+
+    /// Set the source wallet ID
+    pub fn wallet_id(mut self, wallet_id: String) -> Self {
+        self.base = self.base.wallet_id(wallet_id);
+        self
+    }
+
+    /// Set the source wallet address (requires [`blockchain`](Self::blockchain))
+    pub fn wallet_address(mut self, wallet_address: String) -> Self {
+        self.base = self.base.wallet_address(wallet_address);
+        self
+    }
+
+    /// Set the token ID to transfer
+    pub fn token_id(mut self, token_id: String) -> Self {
+        self.base = self.base.token_id(token_id);
+        self
+    }
+
+    /// Set the token contract address to transfer
+    pub fn token_address(mut self, token_address: String) -> Self {
+        self.base = self.base.token_address(token_address);
+        self
+    }
+
+    /// Set the number of decimals of the token, enabling the dust check
+    pub fn token_decimals(mut self, token_decimals: u8) -> Self {
+        self.base = self.base.token_decimals(token_decimals);
+        self
+    }
+
+    /// Set the blockchain of every transfer
+    pub fn blockchain(mut self, blockchain: Blockchain) -> Self {
+        self.base = self.base.blockchain(blockchain);
+        self
+    }
+
+    /// Use a fee level (low, medium, high) for every transfer
+    pub fn fee_level(mut self, fee_level: FeeLevel) -> Self {
+        self.base = self.base.fee_level(fee_level);
+        self
+    }
+
+    /// Use explicit fee settings for every transfer
+    pub fn fee(mut self, fee: FeeConfig) -> Self {
+        self.base = self.base.fee(fee);
+        self
+    }
+
+    /// Check every transfer's destination address with Circle before submitting
+    pub fn validate_destination(mut self, validate: bool) -> Self {
+        self.base = self.base.validate_destination(validate);
+        self
+    }
+
+    /// Check every transfer's amount against the blockchain's minimums (on by default)
+    pub fn check_amounts(mut self, check_amounts: bool) -> Self {
+        self.base = self.base.check_amounts(check_amounts);
+        self
+    }
+
+    /// Prefix the ref ID of every transfer with `prefix`
+    ///
+    /// [`instantiate`](Self::instantiate) appends a per-template sequence
+    /// number (`prefix-1`, `prefix-2`, ...), and
+    /// [`instantiate_with_ref`](Self::instantiate_with_ref) appends the given
+    /// suffix.
+    pub fn ref_id_prefix(mut self, prefix: String) -> Self {
+        self.ref_id_prefix = Some(prefix);
+        self
+    }
+
+    /// Create a transfer of `amount` to `destination_address`
+    ///
+    /// `amount` is a decimal string in whole token units, or a
+    /// [`TokenAmount`](crate::types::TokenAmount).
+    pub fn instantiate(
+        &self,
+        destination_address: impl Into<String>,
+        amount: impl Into<String>,
+    ) -> CreateTransferTransactionRequestBuilder {
+        let sequence = self.sequence.fetch_add(1, Ordering::Relaxed) + 1;
+        self.instantiate_with_ref(destination_address, amount, &sequence.to_string())
+    }
+
+    /// Create a transfer whose ref ID ends with `ref_suffix`
+    ///
+    /// Use this to tie transfers to your own records (e.g., a payout ID). Without a
+    /// ref ID prefix, `ref_suffix` becomes the whole ref ID.
+    pub fn instantiate_with_ref(
+        &self,
+        destination_address: impl Into<String>,
+        amount: impl Into<String>,
+        ref_suffix: &str,
+    ) -> CreateTransferTransactionRequestBuilder {
+        let ref_id = match &self.ref_id_prefix {
+            Some(prefix) => format!("{}-{}", prefix, ref_suffix),
+            None => ref_suffix.to_string(),
+        };
+
+        self.base
+            .clone()
+            .destination_address(destination_address.into())
+            .amounts([amount.into()])
+            .ref_id(ref_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn template() -> TransferTemplate {
+        TransferTemplate::new()
+            .wallet_id("wallet-id".to_string())
+            .token_id("token-id".to_string())
+            .blockchain(Blockchain::MaticAmoy)
+            .fee_level(FeeLevel::High)
+            .ref_id_prefix("payout".to_string())
+    }
+
+    #[test]
+    fn test_instantiate_applies_template() {
+        let template = template();
+        let first = template.instantiate("0xaaa", "1.5");
+        let second = template.instantiate("0xbbb", "2");
+
+        assert_eq!(first.wallet_id.as_deref(), Some("wallet-id"));
+        assert_eq!(first.token_id.as_deref(), Some("token-id"));
+        assert_eq!(first.blockchain, Some(Blockchain::MaticAmoy));
+        assert_eq!(first.fee, Some(FeeConfig::Level(FeeLevel::High)));
+        assert_eq!(first.destination_address, "0xaaa");
+        assert_eq!(first.amounts, vec!["1.5"]);
+        assert_eq!(first.ref_id.as_deref(), Some("payout-1"));
+        assert!(first.idempotency_key.is_none());
+
+        assert_eq!(second.destination_address, "0xbbb");
+        assert_eq!(second.ref_id.as_deref(), Some("payout-2"));
+    }
+
+    #[test]
+    fn test_instantiate_with_ref() {
+        let transfer = template().instantiate_with_ref("0xaaa", "1", "invoice-42");
+        assert_eq!(transfer.ref_id.as_deref(), Some("payout-invoice-42"));
+
+        let transfer = TransferTemplate::new().instantiate_with_ref("0xaaa", "1", "invoice-42");
+        assert_eq!(transfer.ref_id.as_deref(), Some("invoice-42"));
+    }
+}
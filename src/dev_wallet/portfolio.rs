@@ -0,0 +1,405 @@
+//! Consolidated balances across wallets for CircleView
+//!
+//! [`CircleView::get_portfolio`] fetches every wallet's token balances
+//! concurrently and flattens them into one [`Portfolio`] with normalized
+//! decimal amounts and per-symbol totals. Native SOL and NEAR balances are
+//! read from chain RPC, so they are current even when Circle's indexer lags;
+//! if the RPC call fails the balance Circle reports is kept.
+//! [`CircleView::get_portfolio_in_usd`] also values stablecoin holdings in
+//! USD with rates from the [`rates`](crate::rates) module.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::circle_view::circle_view::CircleView;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let view = CircleView::new()?;
+//!
+//! let portfolio = view
+//!     .get_portfolio_in_usd(["treasury-wallet-id", "payouts-wallet-id"])
+//!     .await?;
+//! for total in &portfolio.totals {
+//!     println!("{} {} (${})", total.amount, total.symbol, total.usd_value.as_deref().unwrap_or("?"));
+//! }
+//! println!("Priced holdings: ${}", portfolio.usd_total.unwrap_or_default());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::BTreeMap;
+
+use futures_util::{future, stream, StreamExt, TryStreamExt};
+
+use crate::circle_view::circle_view::CircleView;
+use crate::dev_wallet::dto::{DevWallet, QueryParams};
+use crate::helper::CircleResult;
+use crate::rates::dto::{Currency, CurrencyPair, Decimal, ExchangeRate};
+use crate::solana::{dto::SolanaNetwork, get_solana_balance};
+use crate::types::Blockchain;
+
+/// Wallets fetched at the same time
+const PORTFOLIO_CONCURRENCY: usize = 8;
+
+/// One token balance held by one wallet
+#[derive(Debug, Clone)]
+pub struct PortfolioHolding {
+    /// Wallet holding the token
+    pub wallet_id: String,
+
+    /// Blockchain of the wallet
+    pub blockchain: Blockchain,
+
+    /// Address of the wallet
+    pub address: String,
+
+    /// Circle token ID; `None` for a native balance Circle did not report
+    pub token_id: Option<String>,
+
+    /// Token symbol (e.g., "USDC")
+    pub symbol: Option<String>,
+
+    /// Token contract address; `None` for native tokens
+    pub token_address: Option<String>,
+
+    /// Whether this is the blockchain's native token
+    pub is_native: bool,
+
+    /// Number of decimals of the token, if known
+    pub decimals: Option<u32>,
+
+    /// Decimal amount without trailing zeros
+    pub amount: String,
+
+    /// Value in USD, set by [`Portfolio::apply_usd_rates`]
+    pub usd_value: Option<String>,
+}
+
+impl PortfolioHolding {
+    /// Key holdings are totalled under: the symbol, else the token ID
+    fn total_key(&self) -> String {
+        self.symbol
+            .clone()
+            .or_else(|| self.token_id.clone())
+            .unwrap_or_else(|| "UNKNOWN".to_string())
+    }
+}
+
+/// Sum of one symbol's holdings across wallets and blockchains
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PortfolioTotal {
+    /// Token symbol, or the Circle token ID for tokens without one
+    pub symbol: String,
+
+    /// Decimal amount without trailing zeros
+    pub amount: String,
+
+    /// Value in USD, if every holding of the symbol was priced
+    pub usd_value: Option<String>,
+}
+
+/// Balances of a set of wallets
+#[derive(Debug, Clone)]
+pub struct Portfolio {
+    /// Every non-zero balance, in the order the wallets were given
+    pub holdings: Vec<PortfolioHolding>,
+
+    /// Totals per symbol, sorted by symbol
+    ///
+    /// Testnet and mainnet tokens with the same symbol are added together.
+    pub totals: Vec<PortfolioTotal>,
+
+    /// Value in USD of the holdings that were priced
+    pub usd_total: Option<String>,
+}
+
+impl Portfolio {
+    /// Build a portfolio from holdings, computing the totals
+    pub fn from_holdings(holdings: Vec<PortfolioHolding>) -> CircleResult<Self> {
+        let mut amounts: BTreeMap<String, Decimal> = BTreeMap::new();
+        for holding in &holdings {
+            let amount = Decimal::parse(&holding.amount)?;
+            let total = match amounts.remove(&holding.total_key()) {
+                Some(total) => total.checked_add(amount)?,
+                None => amount,
+            };
+            amounts.insert(holding.total_key(), total);
+        }
+
+        let totals = amounts
+            .into_iter()
+            .map(|(symbol, amount)| PortfolioTotal {
+                symbol,
+                amount: amount.normalize().to_string(),
+                usd_value: None,
+            })
+            .collect();
+        Ok(Self {
+            holdings,
+            totals,
+            usd_total: None,
+        })
+    }
+
+    /// Value holdings in USD with `rates`
+    ///
+    /// A holding is priced when one of the rates converts its symbol into
+    /// USD; USD itself is priced at 1. Totals get a USD value only when all
+    /// their holdings were priced.
+    pub fn apply_usd_rates(&mut self, rates: &[ExchangeRate]) -> CircleResult<()> {
+        let mut usd_total = None;
+        let mut per_symbol: BTreeMap<String, Option<Decimal>> = BTreeMap::new();
+
+        for holding in &mut self.holdings {
+            let currency = Currency::from(holding.symbol.as_deref().unwrap_or_default());
+            let rate = if currency == Currency::Usd {
+                Some(ExchangeRate::new(
+                    CurrencyPair::new(Currency::Usd, Currency::Usd),
+                    "1",
+                ))
+            } else {
+                rates
+                    .iter()
+                    .find(|rate| rate.pair.from == currency && rate.pair.to == Currency::Usd)
+                    .cloned()
+            };
+
+            holding.usd_value = rate.map(|rate| rate.convert(&holding.amount)).transpose()?;
+            let value = holding
+                .usd_value
+                .as_deref()
+                .map(Decimal::parse)
+                .transpose()?;
+
+            if let Some(value) = value {
+                usd_total = Some(match usd_total {
+                    Some(total) => value.checked_add(total)?,
+                    None => value,
+                });
+            }
+            let key = holding.total_key();
+            let total = match (per_symbol.remove(&key), value) {
+                (None, value) => value,
+                (Some(Some(total)), Some(value)) => Some(total.checked_add(value)?),
+                _ => None,
+            };
+            per_symbol.insert(key, total);
+        }
+
+        for total in &mut self.totals {
+            total.usd_value = per_symbol
+                .get(&total.symbol)
+                .copied()
+                .flatten()
+                .map(|value| value.to_string());
+        }
+        self.usd_total = usd_total.map(|total| total.to_string());
+        Ok(())
+    }
+}
+
+impl CircleView {
+    /// Get the balances of several wallets as one portfolio
+    ///
+    /// Wallets are fetched concurrently. Native SOL and NEAR balances come
+    /// from chain RPC (NEAR requires the `near` feature) and fall back to
+    /// Circle's figure if the RPC call fails.
+    ///
+    /// # Arguments
+    ///
+    /// * `wallet_ids` - IDs of the wallets to include
+    pub async fn get_portfolio<I, S>(&self, wallet_ids: I) -> CircleResult<Portfolio>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let wallet_ids: Vec<String> = wallet_ids
+            .into_iter()
+            .map(|id| id.as_ref().to_string())
+            .collect();
+
+        let holdings: Vec<Vec<PortfolioHolding>> = stream::iter(&wallet_ids)
+            .map(|wallet_id| self.wallet_holdings(wallet_id))
+            .buffered(PORTFOLIO_CONCURRENCY)
+            .try_collect()
+            .await?;
+        Portfolio::from_holdings(holdings.into_iter().flatten().collect())
+    }
+
+    /// [`get_portfolio`](Self::get_portfolio) with stablecoin holdings valued in USD
+    ///
+    /// Fetches one reference rate into USD for every known currency held
+    /// (USDC, EURC, EUR); other tokens are left unpriced.
+    pub async fn get_portfolio_in_usd<I, S>(&self, wallet_ids: I) -> CircleResult<Portfolio>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut portfolio = self.get_portfolio(wallet_ids).await?;
+
+        let mut currencies: Vec<Currency> = portfolio
+            .holdings
+            .iter()
+            .filter_map(|holding| holding.symbol.as_deref().map(Currency::from))
+            .filter(|currency| !matches!(currency, Currency::Other(_) | Currency::Usd))
+            .collect();
+        currencies.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        currencies.dedup();
+
+        let rates = future::try_join_all(currencies.into_iter().map(|currency| async move {
+            self.get_exchange_rate(&CurrencyPair::new(currency, Currency::Usd))
+                .await
+        }))
+        .await?;
+        portfolio.apply_usd_rates(&rates)?;
+        Ok(portfolio)
+    }
+
+    /// A wallet's non-zero balances, with the native balance read from chain where supported
+    async fn wallet_holdings(&self, wallet_id: &str) -> CircleResult<Vec<PortfolioHolding>> {
+        let (wallet, balances) = future::try_join(
+            self.get_wallet(wallet_id),
+            self.get_token_balances(wallet_id, QueryParams::default()),
+        )
+        .await?;
+        let wallet = wallet.wallet;
+
+        let mut holdings = Vec::with_capacity(balances.token_balances.len() + 1);
+        for balance in balances.token_balances {
+            holdings.push(PortfolioHolding {
+                wallet_id: wallet.id.clone(),
+                blockchain: wallet.blockchain.clone(),
+                address: wallet.address.clone(),
+                token_id: Some(balance.token.id),
+                symbol: balance.token.symbol,
+                token_address: balance.token.token_address,
+                is_native: balance.token.is_native,
+                decimals: balance.token.decimals,
+                amount: Decimal::parse(&balance.amount)?.normalize().to_string(),
+                usd_value: None,
+            });
+        }
+
+        if let Some((symbol, decimals, amount)) = native_chain_balance(&wallet).await {
+            let amount = Decimal::parse(&amount)?.normalize().to_string();
+            match holdings.iter_mut().find(|holding| holding.is_native) {
+                Some(native) => native.amount = amount,
+                None => holdings.push(PortfolioHolding {
+                    wallet_id: wallet.id.clone(),
+                    blockchain: wallet.blockchain.clone(),
+                    address: wallet.address.clone(),
+                    token_id: None,
+                    symbol: Some(symbol.to_string()),
+                    token_address: None,
+                    is_native: true,
+                    decimals: Some(decimals),
+                    amount,
+                    usd_value: None,
+                }),
+            }
+        }
+
+        holdings.retain(|holding| holding.amount != "0");
+        Ok(holdings)
+    }
+}
+
+/// Native balance of a Solana or NEAR wallet read from chain: (symbol, decimals, amount)
+async fn native_chain_balance(wallet: &DevWallet) -> Option<(&'static str, u32, String)> {
+    match wallet.blockchain {
+        Blockchain::Sol | Blockchain::SolDevnet => {
+            let network = if wallet.blockchain == Blockchain::Sol {
+                SolanaNetwork::Mainnet
+            } else {
+                SolanaNetwork::Devnet
+            };
+            let balance = get_solana_balance(&wallet.address, &network).await.ok()?;
+            Some(("SOL", 9, balance.sol))
+        }
+        #[cfg(feature = "near")]
+        Blockchain::Near | Blockchain::NearTestnet => {
+            use crate::near::{get_near_account_balance, NearNetwork};
+
+            let network = if wallet.blockchain == Blockchain::Near {
+                NearNetwork::Mainnet
+            } else {
+                NearNetwork::Testnet
+            };
+            let balance = get_near_account_balance(&wallet.address, network)
+                .await
+                .ok()?;
+            Some(("NEAR", 24, balance.total))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{fixtures, MockCircle};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_get_portfolio_in_usd() {
+        let mut circle = MockCircle::start().await;
+        let first = fixtures::dev_wallet();
+        let mut second = fixtures::dev_wallet();
+        second.id = "second-wallet".to_string();
+
+        let mut other_balance = fixtures::token_balance();
+        other_balance.amount = "50.500000".to_string();
+        let mut native = fixtures::token_balance();
+        native.amount = "0.000".to_string();
+        native.token.is_native = true;
+        native.token.symbol = Some("ETH".to_string());
+
+        let _wallets = (
+            circle.mock_wallet(&first).await,
+            circle.mock_wallet(&second).await,
+        );
+        let _balances = (
+            circle
+                .mock_balances(&first.id, &[fixtures::token_balance()])
+                .await,
+            circle
+                .mock_balances(&second.id, &[other_balance, native])
+                .await,
+        );
+        let quote = circle
+            .respond(
+                "POST",
+                "/v1/exchange/quotes",
+                &json!({
+                    "id": "quote-1",
+                    "rate": 0.9998,
+                    "from": { "currency": "USDC", "amount": 1 },
+                    "to": { "currency": "USD", "amount": 0.9998 },
+                    "type": "reference"
+                }),
+            )
+            .await;
+
+        let portfolio = circle
+            .view()
+            .get_portfolio_in_usd([&first.id, &second.id])
+            .await
+            .unwrap();
+
+        // The zero ETH balance is dropped and USDC is summed across wallets
+        assert_eq!(portfolio.holdings.len(), 2);
+        assert_eq!(portfolio.holdings[1].wallet_id, "second-wallet");
+        assert_eq!(portfolio.holdings[1].amount, "50.5");
+        assert_eq!(
+            portfolio.totals,
+            vec![PortfolioTotal {
+                symbol: "USDC".to_string(),
+                amount: "150.5".to_string(),
+                usd_value: Some("150.47".to_string()),
+            }]
+        );
+        assert_eq!(portfolio.holdings[0].usd_value.as_deref(), Some("99.98"));
+        assert_eq!(portfolio.usd_total.as_deref(), Some("150.47"));
+        quote.assert_async().await;
+    }
+}
@@ -0,0 +1,329 @@
+//! Named wallets backed by Circle ref IDs
+//!
+//! A [`WalletRegistry`] maps logical names such as `"treasury-eth"` or
+//! `"payouts-sol"` to developer-controlled wallets in one wallet set. Each
+//! name gets the deterministic ref ID `{namespace}:{name}`, so the mapping
+//! lives in Circle itself and every process using the same namespace sees
+//! the same wallets.
+//!
+//! [`get_or_create`](WalletRegistry::get_or_create) looks the wallet up by
+//! ref ID and creates it only when it is missing. Creation is safe to race:
+//! calls for the same name in one process are serialized, and creation goes
+//! through [`CircleOps::ensure_wallet`], whose idempotency key lets Circle
+//! deduplicate concurrent creates from other processes. A name already used
+//! on another blockchain, or shared by several wallets, is reported as a
+//! conflict instead of being silently reused.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+//! use inf_circle_sdk::circle_view::circle_view::CircleView;
+//! use inf_circle_sdk::dev_wallet::registry::WalletRegistry;
+//! use inf_circle_sdk::types::Blockchain;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let registry = WalletRegistry::new(
+//!     CircleOps::new(None)?,
+//!     CircleView::new()?,
+//!     std::env::var("CIRCLE_WALLET_SET_ID")?,
+//! )
+//! .namespace("acme-prod");
+//!
+//! let treasury = registry.get_or_create("treasury-eth", &Blockchain::Eth).await?;
+//! let payouts = registry.get_or_create("payouts-sol", &Blockchain::Sol).await?;
+//! println!("treasury {} / payouts {}", treasury.address, payouts.address);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::circle_ops::circler_ops::CircleOps;
+use crate::circle_view::circle_view::CircleView;
+use crate::dev_wallet::dto::{AccountType, DevWallet};
+use crate::dev_wallet::ops::ensure_wallet::EnsureWalletOptions;
+use crate::dev_wallet::views::list_wallets::ListDevWalletsParamsBuilder;
+use crate::helper::{CircleError, CircleResult};
+use crate::types::Blockchain;
+
+/// Maps logical names to wallets in one wallet set
+///
+/// Cheap to clone; clones share the cache of resolved wallets.
+#[derive(Clone)]
+pub struct WalletRegistry {
+    ops: CircleOps,
+    view: CircleView,
+    wallet_set_id: String,
+    namespace: String,
+    account_type: AccountType,
+    wallets: Arc<Mutex<HashMap<String, DevWallet>>>,
+    creating: Arc<Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>>,
+}
+
+impl WalletRegistry {
+    /// Create a registry for `wallet_set_id` in the `registry` namespace, creating EOA wallets
+    pub fn new(ops: CircleOps, view: CircleView, wallet_set_id: impl Into<String>) -> Self {
+        Self {
+            ops,
+            view,
+            wallet_set_id: wallet_set_id.into(),
+            namespace: "registry".to_string(),
+            account_type: AccountType::Eoa,
+            wallets: Arc::new(Mutex::new(HashMap::new())),
+            creating: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Prefix of every ref ID, to keep registries for different environments apart
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = namespace.into();
+        self
+    }
+
+    /// Account type of wallets the registry creates
+    pub fn account_type(mut self, account_type: AccountType) -> Self {
+        self.account_type = account_type;
+        self
+    }
+
+    /// The ref ID a name maps to
+    ///
+    /// Names may contain lowercase letters, digits, `-`, `_` and `.`.
+    pub fn ref_id(&self, name: &str) -> CircleResult<String> {
+        let valid = !name.is_empty()
+            && name.chars().all(|c| {
+                c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '-' | '_' | '.')
+            });
+        if !valid {
+            return Err(CircleError::Config(format!(
+                "Invalid wallet name {:?}: use lowercase letters, digits, '-', '_' and '.'",
+                name
+            )));
+        }
+        Ok(format!("{}:{}", self.namespace, name))
+    }
+
+    /// Look up the wallet registered under `name`
+    ///
+    /// Returns `CircleError::Config` if several wallets share the name's ref
+    /// ID, or the wallet is on a different blockchain than `blockchain`.
+    pub async fn get(
+        &self,
+        name: &str,
+        blockchain: &Blockchain,
+    ) -> CircleResult<Option<DevWallet>> {
+        let ref_id = self.ref_id(name)?;
+        if let Some(wallet) = self.cached(&ref_id) {
+            return check_blockchain(name, wallet, blockchain).map(Some);
+        }
+
+        let wallet = self.lookup(name, &ref_id).await?;
+        let wallet = wallet
+            .map(|wallet| check_blockchain(name, wallet, blockchain))
+            .transpose()?;
+        if let Some(wallet) = &wallet {
+            self.remember(&ref_id, wallet);
+        }
+        Ok(wallet)
+    }
+
+    /// Get the wallet registered under `name`, creating it on `blockchain` if there is none
+    ///
+    /// Returns `CircleError::Config` if the name is already used on another
+    /// blockchain or by several wallets.
+    pub async fn get_or_create(
+        &self,
+        name: &str,
+        blockchain: &Blockchain,
+    ) -> CircleResult<DevWallet> {
+        if let Some(wallet) = self.get(name, blockchain).await? {
+            return Ok(wallet);
+        }
+
+        // Only one creation per name at a time in this process
+        let ref_id = self.ref_id(name)?;
+        let turn = self.creation_lock(&ref_id);
+        let _turn = turn.lock().await;
+        if let Some(wallet) = self.cached(&ref_id) {
+            return check_blockchain(name, wallet, blockchain);
+        }
+
+        let options = EnsureWalletOptions::new()
+            .account_type(self.account_type)
+            .name(name);
+        let wallet = self
+            .ops
+            .ensure_wallet(&self.wallet_set_id, blockchain, &ref_id, options)
+            .await?;
+        self.remember(&ref_id, &wallet);
+        Ok(wallet)
+    }
+
+    /// Names resolved so far with their wallet IDs
+    pub fn resolved(&self) -> Vec<(String, String)> {
+        let prefix = format!("{}:", self.namespace);
+        let mut resolved: Vec<_> = self
+            .wallets
+            .lock()
+            .map(|wallets| {
+                wallets
+                    .iter()
+                    .filter_map(|(ref_id, wallet)| {
+                        let name = ref_id.strip_prefix(&prefix)?;
+                        Some((name.to_string(), wallet.id.clone()))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        resolved.sort();
+        resolved
+    }
+
+    async fn lookup(&self, name: &str, ref_id: &str) -> CircleResult<Option<DevWallet>> {
+        let params = ListDevWalletsParamsBuilder::new()
+            .wallet_set_id(self.wallet_set_id.clone())
+            .ref_id(ref_id.to_string())
+            .page_size(10)
+            .build();
+        let mut wallets = self.view.list_wallets(params).await?.items;
+        // Circle may match ref IDs loosely; only exact matches count
+        wallets.retain(|wallet| wallet.ref_id.as_deref() == Some(ref_id));
+
+        match wallets.len() {
+            0 => Ok(None),
+            1 => Ok(wallets.pop()),
+            count => Err(CircleError::Config(format!(
+                "Wallet name {} is ambiguous: {} wallets have ref ID {}",
+                name, count, ref_id
+            ))),
+        }
+    }
+
+    fn cached(&self, ref_id: &str) -> Option<DevWallet> {
+        self.wallets
+            .lock()
+            .ok()
+            .and_then(|wallets| wallets.get(ref_id).cloned())
+    }
+
+    fn remember(&self, ref_id: &str, wallet: &DevWallet) {
+        if let Ok(mut wallets) = self.wallets.lock() {
+            wallets.insert(ref_id.to_string(), wallet.clone());
+        }
+    }
+
+    fn creation_lock(&self, ref_id: &str) -> Arc<tokio::sync::Mutex<()>> {
+        match self.creating.lock() {
+            Ok(mut creating) => creating.entry(ref_id.to_string()).or_default().clone(),
+            Err(_) => Arc::default(),
+        }
+    }
+}
+
+/// Fail if the wallet registered under `name` is not on `blockchain`
+fn check_blockchain(
+    name: &str,
+    wallet: DevWallet,
+    blockchain: &Blockchain,
+) -> CircleResult<DevWallet> {
+    if &wallet.blockchain != blockchain {
+        return Err(CircleError::Config(format!(
+            "Wallet name {} is already used by {} on {}, not {}",
+            name,
+            wallet.id,
+            wallet.blockchain.as_str(),
+            blockchain.as_str()
+        )));
+    }
+    Ok(wallet)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helper::deterministic_uuid;
+    use crate::testing::{fixtures, MockCircle};
+    use mockito::Matcher;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_get_or_create_creates_once() {
+        let mut circle = MockCircle::start().await;
+        let registry = WalletRegistry::new(circle.ops(), circle.view(), fixtures::WALLET_SET_ID)
+            .namespace("acme");
+        let mut wallet = fixtures::dev_wallet();
+        wallet.ref_id = Some("acme:treasury-eth".to_string());
+
+        let list = circle
+            .server()
+            .mock("GET", "/v1/w3s/wallets")
+            .match_query(Matcher::UrlEncoded(
+                "refId".to_string(),
+                "acme:treasury-eth".to_string(),
+            ))
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "data": { "wallets": [] } }).to_string())
+            .expect(2)
+            .create_async()
+            .await;
+        let key = deterministic_uuid(&format!(
+            "{}:ETH-SEPOLIA:acme:treasury-eth",
+            fixtures::WALLET_SET_ID
+        ));
+        let create = circle
+            .server()
+            .mock("POST", "/v1/w3s/developer/wallets")
+            .match_body(Matcher::PartialJson(json!({ "idempotencyKey": key })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "data": { "wallets": [wallet] } }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let created = registry
+            .get_or_create("treasury-eth", &Blockchain::EthSepolia)
+            .await
+            .unwrap();
+        let again = registry
+            .clone()
+            .get_or_create("treasury-eth", &Blockchain::EthSepolia)
+            .await
+            .unwrap();
+        assert_eq!(created.id, again.id);
+        assert_eq!(
+            registry.resolved(),
+            vec![("treasury-eth".to_string(), created.id.clone())]
+        );
+        list.assert_async().await;
+        create.assert_async().await;
+
+        // The name is taken on ETH-SEPOLIA
+        assert!(matches!(
+            registry.get("treasury-eth", &Blockchain::SolDevnet).await,
+            Err(CircleError::Config(_))
+        ));
+        assert!(matches!(
+            registry.ref_id("Treasury ETH"),
+            Err(CircleError::Config(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_ref_ids_are_a_conflict() {
+        let mut circle = MockCircle::start().await;
+        let mut first = fixtures::dev_wallet();
+        first.ref_id = Some("registry:payouts".to_string());
+        let mut second = first.clone();
+        second.id = "second-wallet".to_string();
+        let _list = circle.mock_wallets(&[first, second]).await;
+
+        let registry = WalletRegistry::new(circle.ops(), circle.view(), fixtures::WALLET_SET_ID);
+        let result = registry
+            .get_or_create("payouts", &Blockchain::EthSepolia)
+            .await;
+        assert!(matches!(result, Err(CircleError::Config(_))));
+    }
+}
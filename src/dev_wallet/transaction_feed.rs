@@ -0,0 +1,472 @@
+//! Resumable per-wallet transaction history feed
+//!
+//! A [`TransactionFeed`] walks a wallet's transactions in creation order using
+//! [`list_transactions`](CircleView::list_transactions) and remembers where it
+//! stopped in a [`FeedCursor`]: the newest `createDate` delivered so far and the
+//! IDs already delivered at that timestamp. With a [`CursorStore`] attached,
+//! the cursor survives restarts, so a reconciliation job sees each transaction
+//! once even though Circle's `from` filter is inclusive.
+//!
+//! The cursor is saved when the caller acknowledges what it has received:
+//! explicitly with [`TransactionFeed::commit`], implicitly when asking for the
+//! next batch, or, for [`TransactionFeed::stream`], when polling for the next
+//! item. A crash while processing therefore redelivers the unacknowledged
+//! transactions instead of losing them.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use std::sync::Arc;
+//! use inf_circle_sdk::circle_view::circle_view::CircleView;
+//! use inf_circle_sdk::dev_wallet::transaction_feed::{FileCursorStore, TransactionFeed};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let view = CircleView::new()?;
+//! let store = Arc::new(FileCursorStore::open("transaction-cursors.json")?);
+//!
+//! let mut feed = TransactionFeed::new(&view, "wallet-id".to_string()).with_store(store)?;
+//! loop {
+//!     let batch = feed.next_batch().await?;
+//!     if batch.is_empty() {
+//!         break;
+//!     }
+//!     for tx in &batch {
+//!         println!("{} {} {:?}", tx.create_date, tx.state, tx.amounts);
+//!     }
+//! }
+//! // Acknowledge the last batch before exiting
+//! feed.commit()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use futures_util::{stream, Stream};
+use serde::{Deserialize, Serialize};
+
+use crate::circle_view::circle_view::CircleView;
+use crate::dev_wallet::dto::{ListTransactionsParams, Transaction};
+use crate::helper::{sleep, CircleError, CircleResult};
+
+/// Page size used when the caller's params don't set one
+const DEFAULT_PAGE_SIZE: u32 = 50;
+
+/// Position of a [`TransactionFeed`] in a wallet's history
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedCursor {
+    /// `createDate` of the newest transaction delivered so far
+    pub create_date: Option<DateTime<Utc>>,
+
+    /// IDs already delivered with exactly that `createDate`
+    pub seen_ids: Vec<String>,
+}
+
+impl FeedCursor {
+    /// Whether a transaction is at or before this cursor
+    fn covers(&self, tx: &Transaction) -> bool {
+        match self.create_date {
+            Some(date) if tx.create_date < date => true,
+            Some(date) if tx.create_date == date => self.seen_ids.contains(&tx.id),
+            _ => false,
+        }
+    }
+
+    /// Move the cursor past a delivered transaction
+    fn advance(&mut self, tx: &Transaction) {
+        if self.create_date.is_none_or(|date| tx.create_date > date) {
+            self.create_date = Some(tx.create_date);
+            self.seen_ids.clear();
+        }
+        if !self.seen_ids.contains(&tx.id) {
+            self.seen_ids.push(tx.id.clone());
+        }
+    }
+}
+
+/// Persistence hook for [`TransactionFeed`] cursors, keyed by wallet ID
+pub trait CursorStore: Send + Sync {
+    /// The saved cursor for a wallet, if any
+    fn load(&self, wallet_id: &str) -> CircleResult<Option<FeedCursor>>;
+
+    /// Save the cursor for a wallet, replacing any previous one
+    fn save(&self, wallet_id: &str, cursor: &FeedCursor) -> CircleResult<()>;
+}
+
+/// In-process [`CursorStore`], mainly useful for tests
+#[derive(Debug, Default)]
+pub struct InMemoryCursorStore {
+    cursors: Mutex<HashMap<String, FeedCursor>>,
+}
+
+impl InMemoryCursorStore {
+    /// Create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn cursors(&self) -> CircleResult<std::sync::MutexGuard<'_, HashMap<String, FeedCursor>>> {
+        self.cursors
+            .lock()
+            .map_err(|_| CircleError::Config("Cursor store lock poisoned".to_string()))
+    }
+}
+
+impl CursorStore for InMemoryCursorStore {
+    fn load(&self, wallet_id: &str) -> CircleResult<Option<FeedCursor>> {
+        Ok(self.cursors()?.get(wallet_id).cloned())
+    }
+
+    fn save(&self, wallet_id: &str, cursor: &FeedCursor) -> CircleResult<()> {
+        self.cursors()?
+            .insert(wallet_id.to_string(), cursor.clone());
+        Ok(())
+    }
+}
+
+/// [`CursorStore`] backed by a JSON file mapping wallet IDs to cursors
+///
+/// Each save rewrites the file through a temporary file and a rename, so a
+/// crash leaves either the old or the new cursors on disk.
+#[derive(Debug)]
+pub struct FileCursorStore {
+    path: PathBuf,
+    cursors: Mutex<HashMap<String, FeedCursor>>,
+}
+
+impl FileCursorStore {
+    /// Open the cursor file at `path`, starting empty if it does not exist
+    pub fn open(path: impl AsRef<Path>) -> CircleResult<Self> {
+        let path = path.as_ref().to_path_buf();
+        let cursors = match std::fs::read_to_string(&path) {
+            Ok(contents) if !contents.trim().is_empty() => serde_json::from_str(&contents)?,
+            Ok(_) => HashMap::new(),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => HashMap::new(),
+            Err(e) => {
+                return Err(CircleError::Config(format!(
+                    "Failed to read cursor file {}: {}",
+                    path.display(),
+                    e
+                )))
+            }
+        };
+
+        Ok(Self {
+            path,
+            cursors: Mutex::new(cursors),
+        })
+    }
+}
+
+impl CursorStore for FileCursorStore {
+    fn load(&self, wallet_id: &str) -> CircleResult<Option<FeedCursor>> {
+        let cursors = self
+            .cursors
+            .lock()
+            .map_err(|_| CircleError::Config("Cursor file lock poisoned".to_string()))?;
+        Ok(cursors.get(wallet_id).cloned())
+    }
+
+    fn save(&self, wallet_id: &str, cursor: &FeedCursor) -> CircleResult<()> {
+        let mut cursors = self
+            .cursors
+            .lock()
+            .map_err(|_| CircleError::Config("Cursor file lock poisoned".to_string()))?;
+        cursors.insert(wallet_id.to_string(), cursor.clone());
+
+        let contents = serde_json::to_vec_pretty(&*cursors)?;
+        let temp = self.path.with_extension("tmp");
+        std::fs::write(&temp, contents)
+            .and_then(|_| std::fs::rename(&temp, &self.path))
+            .map_err(|e| {
+                CircleError::Config(format!(
+                    "Failed to write cursor file {}: {}",
+                    self.path.display(),
+                    e
+                ))
+            })
+    }
+}
+
+/// Resumable feed over one wallet's transactions, oldest first
+pub struct TransactionFeed<'a> {
+    view: &'a CircleView,
+    wallet_id: String,
+    params: ListTransactionsParams,
+    cursor: FeedCursor,
+    store: Option<Arc<dyn CursorStore>>,
+    /// Whether the cursor moved since it was last saved
+    dirty: bool,
+}
+
+impl<'a> TransactionFeed<'a> {
+    /// Create a feed over a wallet's whole history
+    pub fn new(view: &'a CircleView, wallet_id: String) -> Self {
+        Self {
+            view,
+            wallet_id,
+            params: ListTransactionsParams::default(),
+            cursor: FeedCursor::default(),
+            store: None,
+            dirty: false,
+        }
+    }
+
+    /// Restrict the feed with extra filters (blockchain, state, operation, ...)
+    ///
+    /// `walletIds`, `order`, `to` and the pagination cursors are managed by the
+    /// feed. `from` only applies while the cursor is empty, as a starting point.
+    pub fn params(mut self, params: ListTransactionsParams) -> Self {
+        if self.cursor.create_date.is_none() {
+            self.cursor.create_date = params.from;
+        }
+        self.params = params;
+        self
+    }
+
+    /// Persist the cursor in `store`, resuming from the saved cursor if there is one
+    pub fn with_store(mut self, store: Arc<dyn CursorStore>) -> CircleResult<Self> {
+        if let Some(cursor) = store.load(&self.wallet_id)? {
+            self.cursor = cursor;
+        }
+        self.store = Some(store);
+        Ok(self)
+    }
+
+    /// Resume from a cursor saved elsewhere
+    pub fn resume_from(mut self, cursor: FeedCursor) -> Self {
+        self.cursor = cursor;
+        self
+    }
+
+    /// The current position of the feed, including delivered but uncommitted transactions
+    pub fn cursor(&self) -> &FeedCursor {
+        &self.cursor
+    }
+
+    /// Wallet this feed reads
+    pub fn wallet_id(&self) -> &str {
+        &self.wallet_id
+    }
+
+    /// Acknowledge everything delivered so far by saving the cursor to the store
+    ///
+    /// Does nothing without a store or when nothing was delivered since the last commit.
+    pub fn commit(&mut self) -> CircleResult<()> {
+        if let (true, Some(store)) = (self.dirty, &self.store) {
+            store.save(&self.wallet_id, &self.cursor)?;
+        }
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Fetch every transaction newer than the cursor
+    ///
+    /// Commits the previous batch first, then follows `pageAfter` until all
+    /// newer transactions have been read. Returns an empty batch when the
+    /// feed has caught up.
+    pub async fn next_batch(&mut self) -> CircleResult<Vec<Transaction>> {
+        self.commit()?;
+        let batch = self.fetch().await?;
+        for tx in &batch {
+            self.cursor.advance(tx);
+        }
+        self.dirty |= !batch.is_empty();
+        Ok(batch)
+    }
+
+    /// Tail the wallet's transactions as an infinite stream
+    ///
+    /// Polls for new transactions every `poll_interval` once caught up. The
+    /// cursor is committed each time the next item is requested, so an item
+    /// counts as processed once the consumer moves past it. A failed poll or
+    /// commit yields an `Err` item and the stream carries on.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// use futures_util::{pin_mut, StreamExt};
+    /// use inf_circle_sdk::circle_view::circle_view::CircleView;
+    /// use inf_circle_sdk::dev_wallet::transaction_feed::TransactionFeed;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let view = CircleView::new()?;
+    /// let transactions =
+    ///     TransactionFeed::new(&view, "wallet-id".to_string()).stream(Duration::from_secs(15));
+    /// pin_mut!(transactions);
+    /// while let Some(tx) = transactions.next().await {
+    ///     println!("{}", tx?.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream(
+        self,
+        poll_interval: Duration,
+    ) -> impl Stream<Item = CircleResult<Transaction>> + 'a {
+        let state = (self, VecDeque::new(), false);
+        stream::unfold(
+            state,
+            move |(mut feed, mut pending, mut polled)| async move {
+                if let Err(e) = feed.commit() {
+                    return Some((Err(e), (feed, pending, polled)));
+                }
+
+                loop {
+                    if let Some(tx) = pending.pop_front() {
+                        feed.cursor.advance(&tx);
+                        feed.dirty = true;
+                        return Some((Ok(tx), (feed, pending, polled)));
+                    }
+
+                    if polled {
+                        sleep(poll_interval).await;
+                    }
+                    polled = true;
+                    match feed.fetch().await {
+                        Ok(batch) => pending.extend(batch),
+                        Err(e) => return Some((Err(e), (feed, pending, polled))),
+                    }
+                }
+            },
+        )
+    }
+
+    /// Read every page newer than the cursor, oldest first, without moving it
+    async fn fetch(&self) -> CircleResult<Vec<Transaction>> {
+        let page_size = self
+            .params
+            .pagination
+            .page_size
+            .unwrap_or(DEFAULT_PAGE_SIZE);
+        let mut params = self.params.clone();
+        params.wallet_ids = Some(self.wallet_id.clone());
+        params.order = Some("ASC".to_string());
+        params.from = self.cursor.create_date;
+        params.to = None;
+        params.pagination.page_before = None;
+        params.pagination.page_after = None;
+        params.pagination.page_size = Some(page_size);
+
+        let mut fresh = Vec::new();
+        let mut ids = HashSet::new();
+        loop {
+            let page = self.view.list_transactions(params.clone()).await?;
+            let count = page.items.len();
+            params.pagination.page_after = page.items.last().map(|tx| tx.id.clone());
+            fresh.extend(
+                page.items
+                    .into_iter()
+                    .filter(|tx| !self.cursor.covers(tx) && ids.insert(tx.id.clone())),
+            );
+            if count < page_size as usize || params.pagination.page_after.is_none() {
+                break;
+            }
+        }
+
+        fresh.sort_by_key(|tx| tx.create_date);
+        Ok(fresh)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{fixtures, MockCircle};
+    use serde_json::json;
+
+    fn transaction(id: &str, created: &str) -> serde_json::Value {
+        let mut tx = serde_json::to_value(fixtures::transaction()).unwrap();
+        tx["id"] = json!(id);
+        tx["createDate"] = json!(created);
+        tx
+    }
+
+    fn ids(batch: &[Transaction]) -> Vec<&str> {
+        batch.iter().map(|tx| tx.id.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_next_batch_resumes_from_stored_cursor() {
+        let mut circle = MockCircle::start().await;
+        let store = Arc::new(InMemoryCursorStore::new());
+
+        // Circle's `from` is inclusive, so already delivered transactions come back
+        let first = circle
+            .respond(
+                "GET",
+                "/v1/w3s/transactions",
+                &json!({ "transactions": [
+                    transaction("tx-2", "2024-01-01T00:00:05Z"),
+                    transaction("tx-1", "2024-01-01T00:00:00Z"),
+                    transaction("tx-3", "2024-01-01T00:00:05Z"),
+                ]}),
+            )
+            .await;
+
+        let view = circle.view();
+        let mut feed = TransactionFeed::new(&view, fixtures::WALLET_ID.to_string())
+            .with_store(store.clone())
+            .unwrap();
+        assert_eq!(
+            ids(&feed.next_batch().await.unwrap()),
+            ["tx-1", "tx-2", "tx-3"]
+        );
+        // Not acknowledged yet
+        assert!(store.load(fixtures::WALLET_ID).unwrap().is_none());
+        assert!(feed.next_batch().await.unwrap().is_empty());
+
+        let saved = store.load(fixtures::WALLET_ID).unwrap().unwrap();
+        assert_eq!(saved.seen_ids, ["tx-2", "tx-3"]);
+
+        first.remove_async().await;
+        circle
+            .respond(
+                "GET",
+                "/v1/w3s/transactions",
+                &json!({ "transactions": [
+                    transaction("tx-3", "2024-01-01T00:00:05Z"),
+                    transaction("tx-4", "2024-01-01T00:00:09Z"),
+                ]}),
+            )
+            .await;
+
+        // A new feed (e.g., after a restart) picks up where the store left off
+        let mut resumed = TransactionFeed::new(&view, fixtures::WALLET_ID.to_string())
+            .with_store(store.clone())
+            .unwrap();
+        assert_eq!(ids(&resumed.next_batch().await.unwrap()), ["tx-4"]);
+        resumed.commit().unwrap();
+        assert_eq!(
+            store.load(fixtures::WALLET_ID).unwrap().unwrap().seen_ids,
+            ["tx-4"]
+        );
+    }
+
+    #[test]
+    fn test_file_cursor_store_round_trip() {
+        let path = std::env::temp_dir().join(format!(
+            "inf-circle-cursors-{}.json",
+            crate::helper::generate_uuid()
+        ));
+        let cursor = FeedCursor {
+            create_date: Some("2024-01-01T00:00:05Z".parse().unwrap()),
+            seen_ids: vec!["tx-2".to_string()],
+        };
+        FileCursorStore::open(&path)
+            .unwrap()
+            .save("wallet", &cursor)
+            .unwrap();
+
+        let reopened = FileCursorStore::open(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(reopened.load("wallet").unwrap(), Some(cursor));
+        assert_eq!(reopened.load("other").unwrap(), None);
+    }
+}
@@ -0,0 +1,503 @@
+//! Sequential transfer queue per wallet
+//!
+//! Transfers submitted concurrently from the same wallet race for nonces and
+//! pile up in Circle's own queue in an order the caller does not control. A
+//! [`WalletTxQueue`] submits one transfer per wallet at a time: the next
+//! transfer is only submitted once the previous one has been broadcast (or
+//! has failed), and waiting transfers are picked by priority, then in the
+//! order they were queued. Each queued transfer returns a [`QueuedTransfer`]
+//! handle that resolves once the transaction reaches a terminal state.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+//! use inf_circle_sdk::circle_view::circle_view::CircleView;
+//! use inf_circle_sdk::dev_wallet::dto::FeeLevel;
+//! use inf_circle_sdk::dev_wallet::ops::create_transfer_transaction::CreateTransferTransactionRequestBuilder;
+//! use inf_circle_sdk::dev_wallet::tx_queue::{TxPriority, WalletTxQueue};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let queue = WalletTxQueue::new(CircleOps::new(None)?, CircleView::new()?);
+//!
+//! let transfer = |amount: &str| {
+//!     CreateTransferTransactionRequestBuilder::new()
+//!         .wallet_id("wallet-id".to_string())
+//!         .token_id("usdc-token-id".to_string())
+//!         .destination_address("0xRecipient".to_string())
+//!         .amounts([amount])
+//!         .fee_level(FeeLevel::Medium)
+//! };
+//!
+//! let payout = queue.submit(transfer("10"));
+//! let refund = queue.submit_with_priority(transfer("1"), TxPriority::High);
+//! let dust = queue.submit_with_priority(transfer("0.01"), TxPriority::Low);
+//! dust.canceller().cancel();
+//!
+//! let (payout, refund) = tokio::join!(payout.wait(), refund.wait());
+//! println!("payout {}, refund {}", payout?.state, refund?.state);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::sync::oneshot;
+
+use crate::circle_ops::circler_ops::CircleOps;
+use crate::circle_view::circle_view::CircleView;
+use crate::dev_wallet::dto::Transaction;
+use crate::dev_wallet::ops::create_transfer_transaction::CreateTransferTransactionRequestBuilder;
+use crate::helper::{generate_uuid, sleep, CircleError, CircleResult, Instant};
+
+/// Transaction states at which Circle has assigned a nonce (or given up)
+const SENT_OR_LATER: &[&str] = &[
+    "SENT",
+    "CONFIRMED",
+    "COMPLETE",
+    "FAILED",
+    "CANCELLED",
+    "DENIED",
+];
+
+/// Transaction states that will not change any more
+const TERMINAL: &[&str] = &["COMPLETE", "FAILED", "CANCELLED", "DENIED"];
+
+/// Order in which waiting transfers of the same wallet are submitted
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TxPriority {
+    /// Submitted after every other waiting transfer
+    Low,
+    /// The default priority
+    #[default]
+    Normal,
+    /// Submitted before every other waiting transfer
+    High,
+}
+
+struct Pending {
+    priority: TxPriority,
+    seq: u64,
+    grant: oneshot::Sender<()>,
+}
+
+impl PartialEq for Pending {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Pending {}
+
+impl PartialOrd for Pending {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Pending {
+    /// Highest priority first, then the earliest queued
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority
+            .cmp(&other.priority)
+            .then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+#[derive(Default)]
+struct Lane {
+    busy: bool,
+    pending: BinaryHeap<Pending>,
+}
+
+#[derive(Default)]
+struct Lanes {
+    next_seq: u64,
+    lanes: HashMap<String, Lane>,
+}
+
+impl Lanes {
+    /// Hand the wallet to the next waiting transfer, or mark it idle
+    fn release(&mut self, wallet: &str) {
+        let Some(lane) = self.lanes.get_mut(wallet) else {
+            return;
+        };
+        while let Some(next) = lane.pending.pop() {
+            if next.grant.send(()).is_ok() {
+                return;
+            }
+        }
+        self.lanes.remove(wallet);
+    }
+}
+
+/// Serializes transfers per wallet
+///
+/// The queue is cheap to clone; clones share the same per-wallet lanes.
+#[derive(Clone)]
+pub struct WalletTxQueue {
+    ops: CircleOps,
+    view: CircleView,
+    poll_interval: Duration,
+    send_timeout: Duration,
+    settle_timeout: Duration,
+    lanes: Arc<Mutex<Lanes>>,
+}
+
+impl WalletTxQueue {
+    /// Create a queue that submits through `ops` and tracks transactions through `view`
+    ///
+    /// Defaults: poll every 2s, release a wallet after at most 120s without
+    /// the transaction being sent, give up waiting for a terminal state after 600s.
+    pub fn new(ops: CircleOps, view: CircleView) -> Self {
+        Self {
+            ops,
+            view,
+            poll_interval: Duration::from_secs(2),
+            send_timeout: Duration::from_secs(120),
+            settle_timeout: Duration::from_secs(600),
+            lanes: Arc::new(Mutex::new(Lanes::default())),
+        }
+    }
+
+    /// Set the delay between transaction state checks
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Set how long a wallet is held waiting for its transaction to be sent
+    ///
+    /// Once this passes the next transfer is submitted anyway; the handle
+    /// keeps waiting for the terminal state.
+    pub fn send_timeout(mut self, timeout: Duration) -> Self {
+        self.send_timeout = timeout;
+        self
+    }
+
+    /// Set how long a handle waits for a terminal state after submission
+    pub fn settle_timeout(mut self, timeout: Duration) -> Self {
+        self.settle_timeout = timeout;
+        self
+    }
+
+    /// Queue a transfer with [`TxPriority::Normal`]
+    pub fn submit(&self, transfer: CreateTransferTransactionRequestBuilder) -> QueuedTransfer {
+        self.submit_with_priority(transfer, TxPriority::Normal)
+    }
+
+    /// Queue a transfer
+    ///
+    /// The transfer takes its place in its wallet's queue immediately, but it
+    /// is only submitted while [`QueuedTransfer::wait`] is being awaited.
+    /// Transfers without an idempotency key are given one here.
+    pub fn submit_with_priority(
+        &self,
+        transfer: CreateTransferTransactionRequestBuilder,
+        priority: TxPriority,
+    ) -> QueuedTransfer {
+        let wallet = transfer
+            .wallet_id
+            .clone()
+            .or_else(|| transfer.wallet_address.as_ref().map(|a| a.to_lowercase()))
+            .unwrap_or_default();
+        let key = transfer
+            .idempotency_key
+            .clone()
+            .unwrap_or_else(generate_uuid);
+        let transfer = transfer.idempotency_key(key);
+
+        let (grant, receiver) = oneshot::channel();
+        let mut lanes = self.lanes.lock().unwrap_or_else(|e| e.into_inner());
+        let seq = lanes.next_seq;
+        lanes.next_seq += 1;
+        let lane = lanes.lanes.entry(wallet.clone()).or_default();
+        if lane.busy {
+            lane.pending.push(Pending {
+                priority,
+                seq,
+                grant,
+            });
+        } else {
+            lane.busy = true;
+            let _ = grant.send(());
+        }
+        drop(lanes);
+
+        QueuedTransfer {
+            queue: self.clone(),
+            wallet,
+            seq,
+            transfer: Some(transfer),
+            receiver: Some(receiver),
+        }
+    }
+
+    /// Transfers waiting for their turn on `wallet_id`
+    pub fn queued(&self, wallet_id: &str) -> usize {
+        let lanes = self.lanes.lock().unwrap_or_else(|e| e.into_inner());
+        lanes
+            .lanes
+            .get(wallet_id)
+            .map_or(0, |lane| lane.pending.len())
+    }
+
+    /// Remove a waiting transfer; `false` if it already had its turn
+    fn cancel(&self, wallet: &str, seq: u64) -> bool {
+        let mut lanes = self.lanes.lock().unwrap_or_else(|e| e.into_inner());
+        let Some(lane) = lanes.lanes.get_mut(wallet) else {
+            return false;
+        };
+        let before = lane.pending.len();
+        lane.pending.retain(|pending| pending.seq != seq);
+        lane.pending.len() < before
+    }
+
+    /// Poll until the transaction is sent; `false` if the send timeout passed first
+    async fn wait_until_sent(&self, transaction_id: &str) -> bool {
+        let deadline = Instant::now() + self.send_timeout;
+        loop {
+            if let Ok(response) = self.view.get_transaction(transaction_id).await {
+                if SENT_OR_LATER.contains(&response.transaction.state.as_str()) {
+                    return true;
+                }
+            }
+            if Instant::now() + self.poll_interval > deadline {
+                return false;
+            }
+            sleep(self.poll_interval).await;
+        }
+    }
+
+    /// Poll until the transaction reaches a terminal state
+    async fn wait_until_terminal(
+        &self,
+        transaction_id: &str,
+        deadline: Instant,
+    ) -> CircleResult<Transaction> {
+        loop {
+            let transaction = self.view.get_transaction(transaction_id).await?.transaction;
+            if TERMINAL.contains(&transaction.state.as_str()) {
+                return Ok(transaction);
+            }
+            if Instant::now() + self.poll_interval > deadline {
+                return Err(CircleError::Config(format!(
+                    "Transaction {} did not reach a terminal state in time; last state {}",
+                    transaction_id, transaction.state
+                )));
+            }
+            sleep(self.poll_interval).await;
+        }
+    }
+}
+
+/// Releases a wallet when the transfer holding it is done or dropped
+struct Turn<'a> {
+    queue: &'a WalletTxQueue,
+    wallet: &'a str,
+}
+
+impl Drop for Turn<'_> {
+    fn drop(&mut self) {
+        let mut lanes = self.queue.lanes.lock().unwrap_or_else(|e| e.into_inner());
+        lanes.release(self.wallet);
+    }
+}
+
+/// Cancels a queued transfer from anywhere
+#[derive(Clone)]
+pub struct TxCanceller {
+    queue: WalletTxQueue,
+    wallet: String,
+    seq: u64,
+}
+
+impl TxCanceller {
+    /// Remove the transfer from its wallet's queue
+    ///
+    /// Returns `false` if the transfer already had its turn; a submitted
+    /// transaction can still be cancelled with `CircleOps::cancel_transaction`.
+    pub fn cancel(&self) -> bool {
+        self.queue.cancel(&self.wallet, self.seq)
+    }
+}
+
+/// A transfer waiting in a [`WalletTxQueue`]
+///
+/// Dropping the handle before the transfer was submitted removes it from
+/// the queue.
+pub struct QueuedTransfer {
+    queue: WalletTxQueue,
+    wallet: String,
+    seq: u64,
+    transfer: Option<CreateTransferTransactionRequestBuilder>,
+    receiver: Option<oneshot::Receiver<()>>,
+}
+
+impl QueuedTransfer {
+    /// Wallet lane the transfer is queued on
+    pub fn wallet(&self) -> &str {
+        &self.wallet
+    }
+
+    /// Idempotency key the transfer will be submitted with
+    pub fn idempotency_key(&self) -> Option<&str> {
+        self.transfer.as_ref()?.idempotency_key.as_deref()
+    }
+
+    /// A handle that can cancel the transfer while it is still waiting
+    pub fn canceller(&self) -> TxCanceller {
+        TxCanceller {
+            queue: self.queue.clone(),
+            wallet: self.wallet.clone(),
+            seq: self.seq,
+        }
+    }
+
+    /// Wait for the transfer's turn, submit it, and wait for a terminal state
+    ///
+    /// # Returns
+    ///
+    /// The transaction in its terminal state (`COMPLETE`, `FAILED`,
+    /// `CANCELLED` or `DENIED`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Config` if the transfer was cancelled or did not
+    /// settle within the settle timeout, and the error from
+    /// `CircleOps::create_dev_transfer_transaction` if submission failed.
+    pub async fn wait(mut self) -> CircleResult<Transaction> {
+        let receiver = self.receiver.as_mut().expect("receiver taken");
+        if receiver.await.is_err() {
+            return Err(CircleError::Config(format!(
+                "Transfer {} on wallet {} was cancelled before it was submitted",
+                self.idempotency_key().unwrap_or_default(),
+                self.wallet
+            )));
+        }
+        self.receiver = None;
+        let transfer = self.transfer.take().expect("transfer taken");
+        let queue = &self.queue;
+
+        let turn = Turn {
+            queue,
+            wallet: &self.wallet,
+        };
+        let response = queue.ops.create_dev_transfer_transaction(transfer).await?;
+        let deadline = Instant::now() + queue.settle_timeout;
+        queue.wait_until_sent(&response.id).await;
+        drop(turn);
+
+        queue.wait_until_terminal(&response.id, deadline).await
+    }
+}
+
+impl Drop for QueuedTransfer {
+    fn drop(&mut self) {
+        let Some(mut receiver) = self.receiver.take() else {
+            return;
+        };
+        let mut lanes = self.queue.lanes.lock().unwrap_or_else(|e| e.into_inner());
+        // A turn granted but never taken still has to be handed on
+        if receiver.try_recv().is_ok() {
+            lanes.release(&self.wallet);
+        } else if let Some(lane) = lanes.lanes.get_mut(&self.wallet) {
+            lane.pending.retain(|pending| pending.seq != self.seq);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dev_wallet::dto::FeeLevel;
+    use crate::testing::{fixtures, MockCircle};
+    use serde_json::{json, Value};
+
+    const PATH: &str = "/v1/w3s/developer/transactions/transfer";
+
+    fn transfer(key: &str) -> CreateTransferTransactionRequestBuilder {
+        CreateTransferTransactionRequestBuilder::new()
+            .wallet_id(fixtures::WALLET_ID.to_string())
+            .destination_address(fixtures::WALLET_ADDRESS.to_string())
+            .amounts(["1"])
+            .fee_level(FeeLevel::Medium)
+            .idempotency_key(key.to_string())
+    }
+
+    #[tokio::test]
+    async fn test_wallet_queue_orders_by_priority_and_cancels() {
+        let mut circle = MockCircle::start().await;
+        let arrivals = Arc::new(Mutex::new(Vec::new()));
+        let recorded = arrivals.clone();
+        circle
+            .server()
+            .mock("POST", PATH)
+            .with_status(201)
+            .with_body_from_request(move |request| {
+                let body: Value = serde_json::from_slice(request.body().unwrap()).unwrap();
+                recorded
+                    .lock()
+                    .unwrap()
+                    .push(body["idempotencyKey"].as_str().unwrap().to_string());
+                json!({"data": {"id": fixtures::TRANSACTION_ID, "state": "INITIATED"}})
+                    .to_string()
+                    .into()
+            })
+            .expect(3)
+            .create_async()
+            .await;
+        let transaction = fixtures::transaction();
+        circle
+            .respond(
+                "GET",
+                &format!("/v1/w3s/transactions/{}", fixtures::TRANSACTION_ID),
+                &json!({ "transaction": transaction }),
+            )
+            .await;
+
+        let queue = WalletTxQueue::new(circle.ops(), circle.view())
+            .poll_interval(Duration::from_millis(10));
+        let first = queue.submit(transfer("first"));
+        let low = queue.submit_with_priority(transfer("low"), TxPriority::Low);
+        let cancelled = queue.submit(transfer("cancelled"));
+        let high = queue.submit_with_priority(transfer("high"), TxPriority::High);
+        assert_eq!(queue.queued(fixtures::WALLET_ID), 3);
+
+        assert!(cancelled.canceller().cancel());
+        let (first, low, cancelled, high) =
+            tokio::join!(first.wait(), low.wait(), cancelled.wait(), high.wait());
+        assert_eq!(first.unwrap().state, "COMPLETE");
+        assert!(low.is_ok() && high.is_ok());
+        assert!(cancelled.is_err());
+
+        assert_eq!(*arrivals.lock().unwrap(), vec!["first", "high", "low"]);
+        assert_eq!(queue.queued(fixtures::WALLET_ID), 0);
+    }
+
+    #[tokio::test]
+    async fn test_dropped_transfer_releases_wallet() {
+        let mut circle = MockCircle::start().await;
+        circle
+            .respond("POST", PATH, &fixtures::create_transaction_response())
+            .await;
+        circle
+            .respond(
+                "GET",
+                &format!("/v1/w3s/transactions/{}", fixtures::TRANSACTION_ID),
+                &json!({ "transaction": fixtures::transaction() }),
+            )
+            .await;
+
+        let queue = WalletTxQueue::new(circle.ops(), circle.view());
+        let abandoned = queue.submit(transfer("abandoned"));
+        let next = queue.submit(transfer("next"));
+        drop(abandoned);
+
+        let transaction = next.wait().await.unwrap();
+        assert_eq!(transaction.id, fixtures::TRANSACTION_ID);
+    }
+}
@@ -13,7 +13,7 @@ use crate::dev_wallet::dto::EstimateTransferFeeRequest;
 /// // Estimate fee for native token transfer
 /// let request = EstimateTransferFeeRequestBuilder::new(
 ///     "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb".to_string(),
-///     vec!["1000000000000000000".to_string()] // 1 ETH in wei
+///     vec!["1".to_string()] // 1 ETH
 /// )
 /// .blockchain(Some("ETH-SEPOLIA".to_string()))
 /// .wallet_id(Some("wallet-id".to_string()))
@@ -36,7 +36,7 @@ impl EstimateTransferFeeRequestBuilder {
     /// # Arguments
     ///
     /// * `destination_address` - The destination address for the transfer
-    /// * `amounts` - Vector of amounts to transfer (in the token's smallest unit)
+    /// * `amounts` - Amounts to transfer as decimal strings in whole token units, or [`TokenAmount`](crate::types::TokenAmount) values
     ///
     /// # Example
     ///
@@ -45,13 +45,16 @@ impl EstimateTransferFeeRequestBuilder {
     ///
     /// let builder = EstimateTransferFeeRequestBuilder::new(
     ///     "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb".to_string(),
-    ///     vec!["1000000000000000000".to_string()] // 1 ETH
+    ///     vec!["1".to_string()] // 1 ETH
     /// );
     /// ```
-    pub fn new(destination_address: impl Into<String>, amounts: Vec<String>) -> Self {
+    pub fn new<A: Into<String>>(
+        destination_address: impl Into<String>,
+        amounts: impl IntoIterator<Item = A>,
+    ) -> Self {
         Self {
             destination_address: destination_address.into(),
-            amounts,
+            amounts: amounts.into_iter().map(Into::into).collect(),
             nft_token_ids: None,
             source_address: None,
             token_id: None,
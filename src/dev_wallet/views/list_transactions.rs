@@ -1,6 +1,10 @@
 use chrono::{DateTime, Utc};
+use std::time::Duration;
 
-use crate::{dev_wallet::dto::ListTransactionsParams, helper::PaginationParams};
+use crate::{
+    dev_wallet::dto::ListTransactionsParams,
+    helper::{time_window, PaginationParams},
+};
 
 /// Builder for creating list transactions query parameters
 ///
@@ -22,6 +26,12 @@ pub struct ListTransactionsParamsBuilder {
     params: ListTransactionsParams,
 }
 
+impl Default for ListTransactionsParamsBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ListTransactionsParamsBuilder {
     /// Create a new builder instance
     pub fn new() -> Self {
@@ -85,6 +95,25 @@ impl ListTransactionsParamsBuilder {
         self
     }
 
+    /// Filter by date range; same as [`date_range`](Self::date_range)
+    pub fn between(self, from: DateTime<Utc>, to: DateTime<Utc>) -> Self {
+        self.date_range(from, to)
+    }
+
+    /// Only transactions created within the last `duration`
+    pub fn since(mut self, duration: Duration) -> Self {
+        self.params.from = Some(time_window::since(duration));
+        self.params.to = None;
+        self
+    }
+
+    /// Only transactions created since midnight UTC
+    pub fn today(mut self) -> Self {
+        self.params.from = Some(time_window::start_of_today());
+        self.params.to = None;
+        self
+    }
+
     /// Set pagination parameters
     pub fn pagination(mut self, pagination: PaginationParams) -> Self {
         self.params.pagination = pagination;
@@ -1,4 +1,4 @@
-use crate::dev_wallet::dto::ListDevWalletsParams;
+use crate::dev_wallet::dto::{ListDevWalletsParams, ScaCore, WalletState};
 use crate::helper::PaginationParams;
 use chrono::{DateTime, Utc};
 
@@ -23,6 +23,12 @@ pub struct ListDevWalletsParamsBuilder {
     params: ListDevWalletsParams,
 }
 
+impl Default for ListDevWalletsParamsBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ListDevWalletsParamsBuilder {
     /// Create a new builder instance
     ///
@@ -63,8 +69,8 @@ impl ListDevWalletsParamsBuilder {
     ///
     /// # Arguments
     ///
-    /// * `sca_core` - SCA core version (e.g., "CORE_V1")
-    pub fn sca_core(mut self, sca_core: String) -> Self {
+    /// * `sca_core` - SCA core version (e.g., `ScaCore::Circle6900SingleownerV3`)
+    pub fn sca_core(mut self, sca_core: ScaCore) -> Self {
         self.params.sca_core = Some(sca_core);
         self
     }
@@ -89,6 +95,14 @@ impl ListDevWalletsParamsBuilder {
         self
     }
 
+    /// Keep only wallets in `state`, e.g. to leave frozen wallets out of a payout run
+    ///
+    /// Applied after each page is fetched; see [`ListDevWalletsParams::state`].
+    pub fn state(mut self, state: WalletState) -> Self {
+        self.params.state = Some(state);
+        self
+    }
+
     /// Filter by creation date range
     ///
     /// # Arguments
@@ -140,3 +154,50 @@ impl ListDevWalletsParamsBuilder {
         self.params
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helper::build_query_params;
+
+    #[test]
+    fn test_sca_core_filter_serializes_to_version_string() {
+        let params = ListDevWalletsParamsBuilder::new()
+            .sca_core(ScaCore::Circle6900SingleownerV3)
+            .build();
+        assert_eq!(
+            build_query_params(&params).unwrap(),
+            "scaCore=circle_6900_singleowner_v3"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_state_filter_is_applied_client_side() {
+        use crate::testing::{fixtures, MockCircle};
+
+        let live = fixtures::dev_wallet();
+        let mut frozen = fixtures::dev_wallet();
+        frozen.id = "frozen-wallet".to_string();
+        frozen.state = "FROZEN".to_string();
+
+        let mut circle = MockCircle::start().await;
+        circle.mock_wallets(&[live.clone(), frozen]).await;
+
+        let params = ListDevWalletsParamsBuilder::new()
+            .state(WalletState::Live)
+            .build();
+        assert_eq!(build_query_params(&params).unwrap(), "");
+        let wallets = circle.view().list_wallets(params).await.unwrap().items;
+        assert_eq!(wallets.len(), 1);
+        assert_eq!(wallets[0].id, live.id);
+        assert!(wallets[0].is_live());
+    }
+
+    #[test]
+    fn test_sca_core_round_trips_through_strings() {
+        for core in ScaCore::ALL {
+            assert_eq!(core.to_string().parse::<ScaCore>().unwrap(), core);
+        }
+        assert!("CORE_V1".parse::<ScaCore>().is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::dev_wallet::dto::ListWalletsWithBalancesParams;
+use crate::dev_wallet::dto::{ListWalletsWithBalancesParams, ScaCore};
 use crate::helper::PaginationParams;
 use chrono::{DateTime, Utc};
 
@@ -23,6 +23,12 @@ pub struct ListWalletsWithBalancesParamsBuilder {
     params: ListWalletsWithBalancesParams,
 }
 
+impl Default for ListWalletsWithBalancesParamsBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ListWalletsWithBalancesParamsBuilder {
     /// Create a new builder instance
     ///
@@ -86,7 +92,7 @@ impl ListWalletsWithBalancesParamsBuilder {
     }
 
     /// Filter by SCA version
-    pub fn sca_core(mut self, sca_core: String) -> Self {
+    pub fn sca_core(mut self, sca_core: ScaCore) -> Self {
         self.params.sca_core = Some(sca_core);
         self
     }
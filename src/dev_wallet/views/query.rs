@@ -22,6 +22,12 @@ pub struct QueryParamsBuilder {
     params: QueryParams,
 }
 
+impl Default for QueryParamsBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl QueryParamsBuilder {
     /// Create a new builder instance
     ///
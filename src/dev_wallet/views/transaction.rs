@@ -17,6 +17,12 @@ pub struct TransactionParamsBuilder {
     params: TransactionParams,
 }
 
+impl Default for TransactionParamsBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl TransactionParamsBuilder {
     /// Create a new builder instance
     pub fn new() -> Self {
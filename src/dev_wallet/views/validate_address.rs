@@ -1,4 +1,5 @@
 use crate::dev_wallet::dto::ValidateAddressBody;
+use crate::types::Blockchain;
 
 /// Builder for creating address validation requests
 ///
@@ -8,13 +9,22 @@ use crate::dev_wallet::dto::ValidateAddressBody;
 ///
 /// ```rust,no_run
 /// use inf_circle_sdk::dev_wallet::views::validate_address::ValidateAddressBodyBuilder;
+/// use inf_circle_sdk::types::Blockchain;
 ///
 /// let builder = ValidateAddressBodyBuilder::new()
 ///     .address("0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb".to_string())
+///     .blockchain(Blockchain::EthSepolia)
 ///     .build();
 /// ```
 pub struct ValidateAddressBodyBuilder {
     address: String,
+    blockchain: Option<Blockchain>,
+}
+
+impl Default for ValidateAddressBodyBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl ValidateAddressBodyBuilder {
@@ -22,6 +32,7 @@ impl ValidateAddressBodyBuilder {
     pub fn new() -> Self {
         Self {
             address: String::new(),
+            blockchain: None,
         }
     }
 
@@ -31,10 +42,17 @@ impl ValidateAddressBodyBuilder {
         self
     }
 
+    /// Set the blockchain to validate the address for
+    pub fn blockchain(mut self, blockchain: Blockchain) -> Self {
+        self.blockchain = Some(blockchain);
+        self
+    }
+
     /// Build the address validation request
     pub fn build(self) -> ValidateAddressBody {
         ValidateAddressBody {
             address: self.address,
+            blockchain: self.blockchain,
         }
     }
 }
@@ -0,0 +1,476 @@
+//! Digest Aggregator
+//!
+//! This module provides the [`DigestAggregator`], which folds transaction
+//! events into a per-wallet-set [`DailyDigest`].
+
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+
+use super::dto::{DailyDigest, DigestFailure, TokenTotal, WalletSetDigest};
+use crate::{
+    circle_view::circle_view::CircleView,
+    dev_wallet::{
+        dto::{DevWallet, Transaction},
+        views::{
+            list_transactions::ListTransactionsParamsBuilder,
+            list_wallets::ListDevWalletsParamsBuilder,
+        },
+    },
+    helper::CircleResult,
+    types::Blockchain,
+};
+
+/// Page size used when loading wallets and transactions
+const PAGE_SIZE: u32 = 50;
+
+/// Maximum number of wallet IDs sent in one `walletIds` filter
+const WALLET_ID_CHUNK: usize = 20;
+
+/// Folds webhook or polled transactions into a [`DailyDigest`]
+///
+/// Transactions are keyed by ID, so the same transaction may be recorded
+/// several times (for example once per webhook state change); only the most
+/// recently updated version counts towards the digest. Transactions created
+/// outside the reporting window are ignored.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use chrono::NaiveDate;
+/// use inf_circle_sdk::circle_view::circle_view::CircleView;
+/// use inf_circle_sdk::digest::{DigestAggregator, DigestSink};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let view = CircleView::new()?;
+/// let day = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
+///
+/// let mut aggregator = DigestAggregator::for_day(day);
+/// aggregator.load_wallet_set(&view, "wallet-set-id").await?;
+///
+/// let digest = aggregator.finish();
+/// DigestSink::webhook("https://ops.example.com/hooks/circle-digest")
+///     .send(&digest)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct DigestAggregator {
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    wallet_sets: HashMap<String, String>,
+    transactions: HashMap<String, DigestEntry>,
+}
+
+/// The parts of a transaction the digest needs
+#[derive(Debug, Clone)]
+struct DigestEntry {
+    wallet_id: Option<String>,
+    blockchain: Blockchain,
+    token_id: Option<String>,
+    transaction_type: String,
+    state: String,
+    amounts: Vec<String>,
+    amount_in_usd: Option<String>,
+    error_reason: Option<String>,
+    update_date: DateTime<Utc>,
+}
+
+impl DigestAggregator {
+    /// Create an aggregator for transactions created in `[from, to)`
+    pub fn new(from: DateTime<Utc>, to: DateTime<Utc>) -> Self {
+        Self {
+            from,
+            to,
+            wallet_sets: HashMap::new(),
+            transactions: HashMap::new(),
+        }
+    }
+
+    /// Create an aggregator covering one UTC calendar day
+    pub fn for_day(date: NaiveDate) -> Self {
+        let from = date.and_time(chrono::NaiveTime::MIN).and_utc();
+        Self::new(from, from + Duration::days(1))
+    }
+
+    /// Map a wallet to its wallet set
+    pub fn map_wallet(mut self, wallet_id: String, wallet_set_id: String) -> Self {
+        self.wallet_sets.insert(wallet_id, wallet_set_id);
+        self
+    }
+
+    /// Map every wallet in `wallets` to its wallet set
+    pub fn wallets(mut self, wallets: &[DevWallet]) -> Self {
+        for wallet in wallets {
+            self.wallet_sets
+                .insert(wallet.id.clone(), wallet.wallet_set_id.clone());
+        }
+        self
+    }
+
+    /// Record a transaction event
+    ///
+    /// Accepts both polled transactions and the `notification` object of a
+    /// `transactions.inbound` / `transactions.outbound` webhook.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the transaction falls inside the reporting window and
+    /// replaced any older version of itself, `false` if it was ignored.
+    pub fn record(&mut self, transaction: &Transaction) -> bool {
+        if transaction.create_date < self.from || transaction.create_date >= self.to {
+            return false;
+        }
+
+        if let Some(existing) = self.transactions.get(&transaction.id) {
+            if existing.update_date > transaction.update_date {
+                return false;
+            }
+        }
+
+        self.transactions.insert(
+            transaction.id.clone(),
+            DigestEntry {
+                wallet_id: transaction.wallet_id.clone(),
+                blockchain: transaction.blockchain.clone(),
+                token_id: transaction.token_id.clone(),
+                transaction_type: transaction.transaction_type.clone(),
+                state: transaction.state.clone(),
+                amounts: transaction.amounts.clone().unwrap_or_default(),
+                amount_in_usd: transaction.amount_in_usd.clone(),
+                error_reason: transaction.error_reason.clone(),
+                update_date: transaction.update_date,
+            },
+        );
+        true
+    }
+
+    /// Load every wallet of a wallet set and its transactions for the window
+    ///
+    /// # Arguments
+    ///
+    /// * `view` - Client used for the read requests
+    /// * `wallet_set_id` - Wallet set to load
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if listing wallets or transactions fails.
+    pub async fn load_wallet_set(
+        &mut self,
+        view: &CircleView,
+        wallet_set_id: &str,
+    ) -> CircleResult<()> {
+        let mut wallet_ids = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let mut params = ListDevWalletsParamsBuilder::new()
+                .wallet_set_id(wallet_set_id.to_string())
+                .page_size(PAGE_SIZE);
+            if let Some(cursor) = cursor.take() {
+                params = params.page_after(cursor);
+            }
+
+            let page = view.list_wallets(params.build()).await?.items;
+            for wallet in &page {
+                self.wallet_sets
+                    .insert(wallet.id.clone(), wallet.wallet_set_id.clone());
+                wallet_ids.push(wallet.id.clone());
+            }
+
+            match page.last() {
+                Some(last) if page.len() as u32 == PAGE_SIZE => cursor = Some(last.id.clone()),
+                _ => break,
+            }
+        }
+
+        for chunk in wallet_ids.chunks(WALLET_ID_CHUNK) {
+            let mut cursor: Option<String> = None;
+            loop {
+                let mut params = ListTransactionsParamsBuilder::new()
+                    .wallet_ids(chunk.join(","))
+                    .date_range(self.from, self.to)
+                    .page_size(PAGE_SIZE);
+                if let Some(cursor) = cursor.take() {
+                    params = params.page_after(cursor);
+                }
+
+                let page = view.list_transactions(params.build()).await?.items;
+                for transaction in &page {
+                    self.record(transaction);
+                }
+
+                match page.last() {
+                    Some(last) if page.len() as u32 == PAGE_SIZE => cursor = Some(last.id.clone()),
+                    _ => break,
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Build the digest from the transactions recorded so far
+    pub fn finish(&self) -> DailyDigest {
+        let mut sets: BTreeMap<String, WalletSetDigest> = BTreeMap::new();
+        let mut unassigned = WalletSetDigest::default();
+
+        let mut ids: Vec<&String> = self.transactions.keys().collect();
+        ids.sort();
+
+        for id in ids {
+            let entry = &self.transactions[id];
+            let wallet_set_id = entry
+                .wallet_id
+                .as_ref()
+                .and_then(|wallet_id| self.wallet_sets.get(wallet_id));
+
+            let summary = match wallet_set_id {
+                Some(set_id) => sets
+                    .entry(set_id.clone())
+                    .or_insert_with(|| WalletSetDigest {
+                        wallet_set_id: set_id.clone(),
+                        ..Default::default()
+                    }),
+                None => &mut unassigned,
+            };
+            summary.add(id, entry);
+        }
+
+        DailyDigest {
+            from: self.from,
+            to: self.to,
+            wallet_sets: sets.into_values().collect(),
+            unassigned: (unassigned.transaction_count > 0).then_some(unassigned),
+        }
+    }
+}
+
+impl WalletSetDigest {
+    fn add(&mut self, transaction_id: &str, entry: &DigestEntry) {
+        self.transaction_count += 1;
+        *self.counts_by_state.entry(entry.state.clone()).or_default() += 1;
+
+        match entry.state.as_str() {
+            "COMPLETE" => {
+                let total = match self.totals.iter_mut().find(|total| {
+                    total.blockchain == entry.blockchain
+                        && total.token_id == entry.token_id
+                        && total.transaction_type == entry.transaction_type
+                }) {
+                    Some(total) => total,
+                    None => {
+                        self.totals.push(TokenTotal {
+                            blockchain: entry.blockchain.clone(),
+                            token_id: entry.token_id.clone(),
+                            transaction_type: entry.transaction_type.clone(),
+                            amount: "0".to_string(),
+                            count: 0,
+                        });
+                        self.totals.last_mut().expect("total was just pushed")
+                    }
+                };
+                total.count += 1;
+                for amount in &entry.amounts {
+                    if let Some(sum) = add_decimal(&total.amount, amount) {
+                        total.amount = sum;
+                    }
+                }
+
+                if let Some(usd) = &entry.amount_in_usd {
+                    let current = self.total_usd.as_deref().unwrap_or("0");
+                    if let Some(sum) = add_decimal(current, usd) {
+                        self.total_usd = Some(sum);
+                    }
+                }
+            }
+            "FAILED" | "DENIED" => self.failures.push(DigestFailure {
+                transaction_id: transaction_id.to_string(),
+                wallet_id: entry.wallet_id.clone(),
+                blockchain: entry.blockchain.clone(),
+                state: entry.state.clone(),
+                error_reason: entry.error_reason.clone(),
+            }),
+            _ => {}
+        }
+    }
+}
+
+/// Add two non-negative decimal strings without losing precision
+///
+/// Returns `None` if either value is not a plain decimal number.
+fn add_decimal(a: &str, b: &str) -> Option<String> {
+    fn split(value: &str) -> Option<(&str, &str)> {
+        let value = value.trim();
+        let (int, frac) = value.split_once('.').unwrap_or((value, ""));
+        let digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+        (!(int.is_empty() && frac.is_empty()) && digits(int) && digits(frac)).then_some((int, frac))
+    }
+
+    let (a_int, a_frac) = split(a)?;
+    let (b_int, b_frac) = split(b)?;
+    let frac_len = a_frac.len().max(b_frac.len());
+    let int_len = a_int.len().max(b_int.len()) + 1;
+
+    let pad = |int: &str, frac: &str| -> Vec<u8> {
+        format!("{:0>int_len$}{:0<frac_len$}", int, frac)
+            .bytes()
+            .map(|b| b - b'0')
+            .collect()
+    };
+    let a_digits = pad(a_int, a_frac);
+    let b_digits = pad(b_int, b_frac);
+
+    let mut sum = vec![0u8; a_digits.len()];
+    let mut carry = 0;
+    for i in (0..sum.len()).rev() {
+        let digit = a_digits[i] + b_digits[i] + carry;
+        sum[i] = digit % 10;
+        carry = digit / 10;
+    }
+
+    let digits: String = sum.iter().map(|d| (d + b'0') as char).collect();
+    let (int, frac) = digits.split_at(int_len);
+    let int = int.trim_start_matches('0');
+    let int = if int.is_empty() { "0" } else { int };
+    let frac = frac.trim_end_matches('0');
+
+    Some(if frac.is_empty() {
+        int.to_string()
+    } else {
+        format!("{}.{}", int, frac)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn transaction(
+        id: &str,
+        wallet_id: &str,
+        state: &str,
+        amount: &str,
+        updated: &str,
+    ) -> Transaction {
+        serde_json::from_value(json!({
+            "id": id,
+            "walletId": wallet_id,
+            "blockchain": "ETH-SEPOLIA",
+            "tokenId": "usdc-token",
+            "transactionType": "OUTBOUND",
+            "state": state,
+            "amounts": [amount],
+            "amountInUsd": amount,
+            "errorReason": if state == "FAILED" { json!("INSUFFICIENT_NATIVE_TOKEN") } else { json!(null) },
+            "createDate": "2025-01-15T10:00:00Z",
+            "updateDate": updated
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_add_decimal() {
+        assert_eq!(add_decimal("0", "1.5").as_deref(), Some("1.5"));
+        assert_eq!(add_decimal("999.99", "0.01").as_deref(), Some("1000"));
+        assert_eq!(
+            add_decimal("0.000000000000000001", "1").as_deref(),
+            Some("1.000000000000000001")
+        );
+        assert_eq!(add_decimal(".5", "5.").as_deref(), Some("5.5"));
+        assert!(add_decimal("1e3", "1").is_none());
+        assert!(add_decimal("-1", "1").is_none());
+    }
+
+    #[test]
+    fn test_digest_groups_by_wallet_set() {
+        let day = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
+        let mut aggregator = DigestAggregator::for_day(day)
+            .map_wallet("wallet-a".to_string(), "set-1".to_string())
+            .map_wallet("wallet-b".to_string(), "set-2".to_string());
+
+        assert!(aggregator.record(&transaction(
+            "tx-1",
+            "wallet-a",
+            "SENT",
+            "1.25",
+            "2025-01-15T10:00:01Z"
+        )));
+        // Later webhook for the same transaction replaces the earlier state
+        assert!(aggregator.record(&transaction(
+            "tx-1",
+            "wallet-a",
+            "COMPLETE",
+            "1.25",
+            "2025-01-15T10:05:00Z"
+        )));
+        // Stale redelivery is ignored
+        assert!(!aggregator.record(&transaction(
+            "tx-1",
+            "wallet-a",
+            "SENT",
+            "1.25",
+            "2025-01-15T10:00:01Z"
+        )));
+        aggregator.record(&transaction(
+            "tx-2",
+            "wallet-a",
+            "COMPLETE",
+            "0.75",
+            "2025-01-15T11:00:00Z",
+        ));
+        aggregator.record(&transaction(
+            "tx-3",
+            "wallet-b",
+            "FAILED",
+            "5",
+            "2025-01-15T12:00:00Z",
+        ));
+        aggregator.record(&transaction(
+            "tx-4",
+            "wallet-unknown",
+            "COMPLETE",
+            "3",
+            "2025-01-15T12:00:00Z",
+        ));
+
+        let digest = aggregator.finish();
+        assert_eq!(digest.transaction_count(), 4);
+        assert_eq!(digest.failure_count(), 1);
+        assert_eq!(digest.wallet_sets.len(), 2);
+
+        let set_1 = &digest.wallet_sets[0];
+        assert_eq!(set_1.wallet_set_id, "set-1");
+        assert_eq!(set_1.transaction_count, 2);
+        assert_eq!(set_1.counts_by_state.get("COMPLETE"), Some(&2));
+        assert_eq!(set_1.totals.len(), 1);
+        assert_eq!(set_1.totals[0].amount, "2");
+        assert_eq!(set_1.totals[0].count, 2);
+        assert_eq!(set_1.total_usd.as_deref(), Some("2"));
+
+        let set_2 = &digest.wallet_sets[1];
+        assert!(set_2.totals.is_empty());
+        assert_eq!(set_2.failures[0].transaction_id, "tx-3");
+        assert_eq!(
+            set_2.failures[0].error_reason.as_deref(),
+            Some("INSUFFICIENT_NATIVE_TOKEN")
+        );
+
+        assert_eq!(digest.unassigned.unwrap().transaction_count, 1);
+    }
+
+    #[test]
+    fn test_digest_ignores_transactions_outside_window() {
+        let day = NaiveDate::from_ymd_opt(2025, 1, 16).unwrap();
+        let mut aggregator = DigestAggregator::for_day(day);
+        assert!(!aggregator.record(&transaction(
+            "tx-1",
+            "wallet-a",
+            "COMPLETE",
+            "1",
+            "2025-01-16T00:00:01Z"
+        )));
+        assert_eq!(aggregator.finish().transaction_count(), 0);
+    }
+}
@@ -0,0 +1,116 @@
+//! Digest Data Transfer Objects
+//!
+//! This module contains the summary structures produced by the
+//! [`DigestAggregator`](super::DigestAggregator).
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::types::Blockchain;
+
+/// Spending summary for a reporting window, grouped by wallet set
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyDigest {
+    /// Start of the reporting window (inclusive)
+    pub from: DateTime<Utc>,
+
+    /// End of the reporting window (exclusive)
+    pub to: DateTime<Utc>,
+
+    /// One summary per wallet set, ordered by wallet set ID
+    pub wallet_sets: Vec<WalletSetDigest>,
+
+    /// Transactions whose wallet is not mapped to a wallet set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unassigned: Option<WalletSetDigest>,
+}
+
+impl DailyDigest {
+    /// Total number of transactions across all wallet sets
+    pub fn transaction_count(&self) -> u64 {
+        self.wallet_sets
+            .iter()
+            .chain(self.unassigned.iter())
+            .map(|set| set.transaction_count)
+            .sum()
+    }
+
+    /// Total number of failed transactions across all wallet sets
+    pub fn failure_count(&self) -> usize {
+        self.wallet_sets
+            .iter()
+            .chain(self.unassigned.iter())
+            .map(|set| set.failures.len())
+            .sum()
+    }
+}
+
+/// Summary of the transactions of one wallet set
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletSetDigest {
+    /// Wallet set identifier (empty for unassigned transactions)
+    pub wallet_set_id: String,
+
+    /// Number of distinct transactions seen in the window
+    pub transaction_count: u64,
+
+    /// Number of transactions per state (COMPLETE, FAILED, ...)
+    pub counts_by_state: BTreeMap<String, u64>,
+
+    /// Completed transfer totals per blockchain, token and direction
+    pub totals: Vec<TokenTotal>,
+
+    /// Sum of `amountInUsd` over completed transactions, in decimal format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_usd: Option<String>,
+
+    /// Transactions that ended in the FAILED or DENIED state
+    pub failures: Vec<DigestFailure>,
+}
+
+/// Total amount moved for one token in one direction
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenTotal {
+    /// Blockchain the token lives on
+    pub blockchain: Blockchain,
+
+    /// Circle token ID, if reported
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_id: Option<String>,
+
+    /// Transaction direction (INBOUND or OUTBOUND)
+    pub transaction_type: String,
+
+    /// Sum of the transfer amounts, in decimal format
+    pub amount: String,
+
+    /// Number of completed transactions contributing to `amount`
+    pub count: u64,
+}
+
+/// A failed transaction listed in the digest
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DigestFailure {
+    /// Transaction identifier
+    pub transaction_id: String,
+
+    /// Wallet that submitted or received the transaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wallet_id: Option<String>,
+
+    /// Blockchain of the transaction
+    pub blockchain: Blockchain,
+
+    /// Final state (FAILED or DENIED)
+    pub state: String,
+
+    /// Error reason reported by Circle
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_reason: Option<String>,
+}
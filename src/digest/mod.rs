@@ -0,0 +1,50 @@
+//! Wallet Set Spending Digest
+//!
+//! This module turns raw transaction events, whether polled from the API or
+//! received through `transactions.*` webhooks, into a per-wallet-set summary
+//! (counts per state, completed totals per token, failures) that can be
+//! delivered to an ops webhook or an in-process channel once a day.
+//!
+//! # Main Components
+//!
+//! - [`aggregator`]: [`DigestAggregator`] that folds transactions into a digest
+//! - [`dto`]: The [`DailyDigest`] and its per-wallet-set summaries
+//! - [`sink`]: [`DigestSink`] destinations (webhook URL or channel)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use chrono::{Duration, Utc};
+//! use inf_circle_sdk::circle_view::circle_view::CircleView;
+//! use inf_circle_sdk::digest::{DigestAggregator, DigestSink};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let view = CircleView::new()?;
+//! let yesterday = (Utc::now() - Duration::days(1)).date_naive();
+//!
+//! let mut aggregator = DigestAggregator::for_day(yesterday);
+//! aggregator.load_wallet_set(&view, "treasury-wallet-set-id").await?;
+//! aggregator.load_wallet_set(&view, "payouts-wallet-set-id").await?;
+//!
+//! let digest = aggregator.finish();
+//! println!(
+//!     "{} transactions, {} failures",
+//!     digest.transaction_count(),
+//!     digest.failure_count()
+//! );
+//!
+//! DigestSink::webhook("https://ops.example.com/hooks/circle-digest")
+//!     .send(&digest)
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod aggregator;
+pub mod dto;
+pub mod sink;
+
+// Re-export commonly used items
+pub use aggregator::DigestAggregator;
+pub use dto::{DailyDigest, DigestFailure, TokenTotal, WalletSetDigest};
+pub use sink::DigestSink;
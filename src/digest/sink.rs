@@ -0,0 +1,97 @@
+//! Digest Sinks
+//!
+//! This module provides [`DigestSink`], the destination a finished
+//! [`DailyDigest`] is delivered to.
+
+use reqwest::Client;
+use tokio::sync::mpsc::UnboundedSender;
+
+use super::dto::DailyDigest;
+use crate::helper::{CircleError, CircleResult};
+
+/// Destination for a finished [`DailyDigest`]
+#[derive(Debug, Clone)]
+pub enum DigestSink {
+    /// POST the digest as JSON to a URL (Slack/Teams relays, ops dashboards, ...)
+    Webhook {
+        /// HTTP client used for delivery
+        client: Client,
+
+        /// Target URL
+        url: String,
+    },
+
+    /// Send the digest to an in-process channel
+    Channel(UnboundedSender<DailyDigest>),
+}
+
+impl DigestSink {
+    /// Create a sink that POSTs the digest to `url`
+    pub fn webhook(url: impl Into<String>) -> Self {
+        Self::Webhook {
+            client: Client::new(),
+            url: url.into(),
+        }
+    }
+
+    /// Create a sink that forwards the digest to `sender`
+    pub fn channel(sender: UnboundedSender<DailyDigest>) -> Self {
+        Self::Channel(sender)
+    }
+
+    /// Deliver the digest
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the webhook request fails or answers with a
+    /// non-success status, or if the channel receiver has been dropped.
+    pub async fn send(&self, digest: &DailyDigest) -> CircleResult<()> {
+        match self {
+            Self::Webhook { client, url } => {
+                let response = client.post(url).json(digest).send().await?;
+                let status = response.status();
+                if !status.is_success() {
+                    return Err(CircleError::Api {
+                        status: status.as_u16(),
+                        request_id: None,
+                        message: response.text().await.unwrap_or_default(),
+                    });
+                }
+                Ok(())
+            }
+            Self::Channel(sender) => sender.send(digest.clone()).map_err(|_| {
+                CircleError::Config("Digest channel receiver was dropped".to_string())
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, TimeZone, Utc};
+    use tokio::sync::mpsc;
+
+    #[tokio::test]
+    async fn test_channel_sink_delivers_digest() {
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let sink = DigestSink::channel(sender);
+        let digest =
+            crate::digest::DigestAggregator::for_day(NaiveDate::from_ymd_opt(2025, 1, 15).unwrap())
+                .finish();
+
+        sink.send(&digest).await.unwrap();
+        let received = receiver.recv().await.unwrap();
+        assert_eq!(
+            received.from,
+            Utc.with_ymd_and_hms(2025, 1, 15, 0, 0, 0).unwrap()
+        );
+        assert_eq!(received, digest);
+
+        drop(receiver);
+        assert!(matches!(
+            sink.send(&digest).await,
+            Err(CircleError::Config(_))
+        ));
+    }
+}
@@ -0,0 +1,75 @@
+//! EVM Data Transfer Objects
+//!
+//! This module contains the data structures used for EVM transaction signing,
+//! including the transaction object Circle's sign transaction endpoint expects
+//! and the decoded form of the signed transaction it returns.
+
+use serde::{Deserialize, Serialize};
+
+use crate::abi::dto::{Address, U256};
+
+/// EVM transaction envelope type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EvmTransactionType {
+    /// Pre-EIP-2718 transaction priced with `gasPrice`
+    Legacy,
+    /// EIP-2930 transaction with an access list (type 1)
+    AccessList,
+    /// EIP-1559 transaction priced with `maxFeePerGas` (type 2)
+    Eip1559,
+}
+
+/// Unsigned EVM transaction in the JSON form Circle signs
+///
+/// Wei amounts are decimal strings because they routinely exceed the range of
+/// JSON numbers. Produced by
+/// [`EvmTransactionBuilder`](crate::evm::transaction::EvmTransactionBuilder).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmTransaction {
+    pub nonce: u64,
+    /// Recipient address; absent for contract creation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+    /// Amount of native currency in wei
+    pub value: String,
+    /// Call data (0x-prefixed hex)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
+    /// Gas limit
+    pub gas: u64,
+    /// Legacy gas price in wei
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_price: Option<String>,
+    /// EIP-1559 fee cap in wei
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_fee_per_gas: Option<String>,
+    /// EIP-1559 priority fee in wei
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_priority_fee_per_gas: Option<String>,
+    pub chain_id: u64,
+}
+
+/// Signed EVM transaction decoded from Circle's `signedTransaction`
+///
+/// `raw_transaction` can be submitted as-is with `eth_sendRawTransaction`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedEvmTransaction {
+    pub transaction_type: EvmTransactionType,
+    /// Chain ID; `None` only for pre-EIP-155 legacy transactions
+    pub chain_id: Option<u64>,
+    pub nonce: u64,
+    /// Recipient; `None` for contract creation
+    pub to: Option<Address>,
+    pub value: U256,
+    pub data: Vec<u8>,
+    pub gas_limit: u64,
+    /// Signature recovery value: `v` for legacy, the y-parity for typed transactions
+    pub v: u64,
+    pub r: U256,
+    pub s: U256,
+    /// Transaction hash (0x-prefixed Keccak-256 of the raw transaction)
+    pub hash: String,
+    /// Raw signed transaction (0x-prefixed hex)
+    pub raw_transaction: String,
+}
@@ -0,0 +1,46 @@
+//! EVM Transaction Support
+//!
+//! This module provides a typed builder for the EVM transaction object that
+//! Circle's sign transaction endpoint expects, and decoding of the signed
+//! transaction it returns so it can be broadcast through your own RPC
+//! provider.
+//!
+//! # Main Components
+//!
+//! - [`dto`]: Data transfer objects (the transaction JSON and decoded signed transactions)
+//! - [`transaction`]: Transaction building and signed transaction parsing
+//!
+//! # Example - Sign a Native Transfer
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::abi::{Address, U256};
+//! use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+//! use inf_circle_sdk::evm::{parse_signed_evm_transaction, EvmTransactionBuilder};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let ops = CircleOps::new(None)?;
+//! let recipient: Address = "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb0".parse()?;
+//!
+//! let request = EvmTransactionBuilder::new(11155111, 0)
+//!     .to(recipient)
+//!     .value("1000000000000000".parse()?)
+//!     .gas_limit(21_000)
+//!     .max_fee_per_gas(U256::from(30_000_000_000u64))
+//!     .max_priority_fee_per_gas(U256::from(1_000_000_000u64))
+//!     .into_sign_request("wallet-id".to_string())?;
+//!
+//! let response = ops.dev_sign_transaction(request).await?;
+//! let signed = parse_signed_evm_transaction(&response.signed_transaction)?;
+//! // Submit `signed.raw_transaction` with eth_sendRawTransaction
+//! println!("Transaction hash: {}", signed.hash);
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod dto;
+mod rlp;
+pub mod transaction;
+
+// Re-export commonly used items
+pub use dto::{EvmTransaction, EvmTransactionType, SignedEvmTransaction};
+pub use transaction::{parse_signed_evm_transaction, EvmTransactionBuilder};
@@ -0,0 +1,161 @@
+//! Minimal RLP Decoder
+//!
+//! Decodes the Recursive Length Prefix encoding used by signed EVM
+//! transactions. Only decoding is needed: Circle builds and signs the
+//! transaction from its JSON form.
+
+use crate::helper::{CircleError, CircleResult};
+
+/// A decoded RLP item borrowing from the input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum RlpItem<'a> {
+    String(&'a [u8]),
+    List(Vec<RlpItem<'a>>),
+}
+
+impl<'a> RlpItem<'a> {
+    /// The byte string, or an error naming `field` if this is a list
+    pub(crate) fn as_bytes(&self, field: &str) -> CircleResult<&'a [u8]> {
+        match self {
+            RlpItem::String(bytes) => Ok(bytes),
+            RlpItem::List(_) => Err(invalid(&format!("{} must be a byte string", field))),
+        }
+    }
+
+    /// The byte string as a big-endian integer of at most `u64` width
+    pub(crate) fn as_u64(&self, field: &str) -> CircleResult<u64> {
+        let bytes = self.as_bytes(field)?;
+        if bytes.len() > 8 || bytes.first() == Some(&0) {
+            return Err(invalid(&format!("{} is not a canonical u64", field)));
+        }
+        Ok(bytes
+            .iter()
+            .fold(0u64, |acc, byte| (acc << 8) | *byte as u64))
+    }
+
+    /// The list items, or an error naming `field` if this is a byte string
+    pub(crate) fn as_list(&self, field: &str) -> CircleResult<&[RlpItem<'a>]> {
+        match self {
+            RlpItem::List(items) => Ok(items),
+            RlpItem::String(_) => Err(invalid(&format!("{} must be a list", field))),
+        }
+    }
+}
+
+fn invalid(reason: &str) -> CircleError {
+    CircleError::Config(format!("Invalid RLP: {}", reason))
+}
+
+/// Decode a single RLP item that spans all of `data`
+pub(crate) fn decode(data: &[u8]) -> CircleResult<RlpItem<'_>> {
+    let (item, consumed) = decode_item(data)?;
+    if consumed != data.len() {
+        return Err(invalid("trailing bytes"));
+    }
+    Ok(item)
+}
+
+/// Decode the item at the start of `data`, returning it and its encoded length
+fn decode_item(data: &[u8]) -> CircleResult<(RlpItem<'_>, usize)> {
+    let prefix = *data.first().ok_or_else(|| invalid("truncated"))?;
+    match prefix {
+        0x00..=0x7f => Ok((RlpItem::String(&data[..1]), 1)),
+        0x80..=0xb7 => {
+            let len = (prefix - 0x80) as usize;
+            let payload = slice(data, 1, len)?;
+            if len == 1 && payload[0] < 0x80 {
+                return Err(invalid("single byte must be encoded as itself"));
+            }
+            Ok((RlpItem::String(payload), 1 + len))
+        }
+        0xb8..=0xbf => {
+            let (len, header) = long_length(data, (prefix - 0xb7) as usize)?;
+            Ok((RlpItem::String(slice(data, header, len)?), header + len))
+        }
+        0xc0..=0xf7 => {
+            let len = (prefix - 0xc0) as usize;
+            Ok((RlpItem::List(decode_list(slice(data, 1, len)?)?), 1 + len))
+        }
+        0xf8..=0xff => {
+            let (len, header) = long_length(data, (prefix - 0xf7) as usize)?;
+            let payload = slice(data, header, len)?;
+            Ok((RlpItem::List(decode_list(payload)?), header + len))
+        }
+    }
+}
+
+fn decode_list(mut payload: &[u8]) -> CircleResult<Vec<RlpItem<'_>>> {
+    let mut items = Vec::new();
+    while !payload.is_empty() {
+        let (item, consumed) = decode_item(payload)?;
+        items.push(item);
+        payload = &payload[consumed..];
+    }
+    Ok(items)
+}
+
+/// Read a big-endian payload length of `len_of_len` bytes following the prefix
+fn long_length(data: &[u8], len_of_len: usize) -> CircleResult<(usize, usize)> {
+    let bytes = slice(data, 1, len_of_len)?;
+    if bytes[0] == 0 || len_of_len > std::mem::size_of::<usize>() {
+        return Err(invalid("non-canonical length"));
+    }
+    let len = bytes
+        .iter()
+        .fold(0usize, |acc, byte| (acc << 8) | *byte as usize);
+    if len < 56 {
+        return Err(invalid("non-canonical length"));
+    }
+    Ok((len, 1 + len_of_len))
+}
+
+fn slice(data: &[u8], start: usize, len: usize) -> CircleResult<&[u8]> {
+    start
+        .checked_add(len)
+        .and_then(|end| data.get(start..end))
+        .ok_or_else(|| invalid("truncated"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_rlp() {
+        assert_eq!(decode(&[0x05]).unwrap(), RlpItem::String(&[0x05]));
+        assert_eq!(decode(&[0x80]).unwrap(), RlpItem::String(&[]));
+        assert_eq!(
+            decode(&[0x83, b'd', b'o', b'g']).unwrap(),
+            RlpItem::String(b"dog")
+        );
+        assert_eq!(
+            decode(&[0xc8, 0x83, b'c', b'a', b't', 0x83, b'd', b'o', b'g']).unwrap(),
+            RlpItem::List(vec![RlpItem::String(b"cat"), RlpItem::String(b"dog")])
+        );
+
+        let mut long = vec![0xb8, 56];
+        long.extend([0xaa; 56]);
+        assert_eq!(decode(&long).unwrap(), RlpItem::String(&[0xaa; 56]));
+
+        assert_eq!(
+            decode(&[0x82, 0x04, 0x00])
+                .unwrap()
+                .as_u64("value")
+                .unwrap(),
+            1024
+        );
+    }
+
+    #[test]
+    fn test_decode_rlp_rejects_malformed_input() {
+        assert!(decode(&[]).is_err());
+        assert!(decode(&[0x83, b'd', b'o']).is_err());
+        assert!(decode(&[0x05, 0x05]).is_err());
+        assert!(decode(&[0x81, 0x05]).is_err());
+        assert!(decode(&[0xb8, 0x01, 0xaa]).is_err());
+        assert!(decode(&[0x82, 0x00, 0x01])
+            .unwrap()
+            .as_u64("nonce")
+            .is_err());
+    }
+}
@@ -0,0 +1,510 @@
+//! EVM Transaction Building
+//!
+//! This module builds the typed transaction object for Circle's sign
+//! transaction endpoint and decodes the signed transaction it returns.
+
+use crate::abi::dto::{AbiValue, Address, U256};
+use crate::abi::handler::{encode, function_selector, keccak256};
+use crate::dev_wallet::ops::sign_transaction::SignTransactionRequestBuilder;
+use crate::helper::{CircleError, CircleResult};
+
+use super::dto::{EvmTransaction, EvmTransactionType, SignedEvmTransaction};
+use super::rlp::{self, RlpItem};
+
+/// Builder for an unsigned EVM transaction
+///
+/// Set either [`gas_price`](Self::gas_price) for a legacy transaction or both
+/// EIP-1559 fee fields. Values are checked when the transaction is built.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use inf_circle_sdk::abi::{AbiValue, Address, U256};
+/// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+/// use inf_circle_sdk::evm::{parse_signed_evm_transaction, EvmTransactionBuilder};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let ops = CircleOps::new(None)?;
+/// let usdc: Address = "0x1c7D4B196Cb0C7B01d743Fbc6116a902379C7238".parse()?;
+/// let recipient: Address = "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb0".parse()?;
+///
+/// let request = EvmTransactionBuilder::new(11155111, 7)
+///     .to(usdc)
+///     .call(
+///         "transfer(address,uint256)",
+///         &[AbiValue::from(recipient), AbiValue::from(U256::from(1_000_000u64))],
+///     )
+///     .gas_limit(65_000)
+///     .max_fee_per_gas(U256::from(30_000_000_000u64))
+///     .max_priority_fee_per_gas(U256::from(1_500_000_000u64))
+///     .into_sign_request("wallet-id".to_string())?;
+///
+/// let response = ops.dev_sign_transaction(request).await?;
+/// let signed = parse_signed_evm_transaction(&response.signed_transaction)?;
+/// println!("Broadcast {} (hash {})", signed.raw_transaction, signed.hash);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct EvmTransactionBuilder {
+    pub chain_id: u64,
+    pub nonce: u64,
+    pub to: Option<Address>,
+    pub value: U256,
+    pub data: Vec<u8>,
+    pub gas_limit: Option<u64>,
+    pub gas_price: Option<U256>,
+    pub max_fee_per_gas: Option<U256>,
+    pub max_priority_fee_per_gas: Option<U256>,
+}
+
+impl EvmTransactionBuilder {
+    /// Create a new builder for `chain_id` using the sender's `nonce`
+    pub fn new(chain_id: u64, nonce: u64) -> Self {
+        Self {
+            chain_id,
+            nonce,
+            to: None,
+            value: U256::ZERO,
+            data: Vec::new(),
+            gas_limit: None,
+            gas_price: None,
+            max_fee_per_gas: None,
+            max_priority_fee_per_gas: None,
+        }
+    }
+
+    /// Set the recipient; leave unset to deploy a contract
+    pub fn to(mut self, to: Address) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Set the amount of native currency to send, in wei
+    pub fn value(mut self, value: U256) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Set the raw call data (or init code for contract creation)
+    pub fn data(mut self, data: impl Into<Vec<u8>>) -> Self {
+        self.data = data.into();
+        self
+    }
+
+    /// Set the call data to an ABI-encoded function call
+    ///
+    /// # Arguments
+    /// * `signature` - Canonical function signature (e.g., "transfer(address,uint256)")
+    /// * `values` - The function arguments
+    pub fn call(mut self, signature: &str, values: &[AbiValue]) -> Self {
+        let mut data = function_selector(signature).to_vec();
+        data.extend(encode(values));
+        self.data = data;
+        self
+    }
+
+    /// Set the gas limit
+    pub fn gas_limit(mut self, gas_limit: u64) -> Self {
+        self.gas_limit = Some(gas_limit);
+        self
+    }
+
+    /// Set the legacy gas price in wei
+    pub fn gas_price(mut self, gas_price: U256) -> Self {
+        self.gas_price = Some(gas_price);
+        self
+    }
+
+    /// Set the EIP-1559 fee cap in wei
+    pub fn max_fee_per_gas(mut self, max_fee_per_gas: U256) -> Self {
+        self.max_fee_per_gas = Some(max_fee_per_gas);
+        self
+    }
+
+    /// Set the EIP-1559 priority fee in wei
+    pub fn max_priority_fee_per_gas(mut self, max_priority_fee_per_gas: U256) -> Self {
+        self.max_priority_fee_per_gas = Some(max_priority_fee_per_gas);
+        self
+    }
+
+    /// Validate the fields and build the transaction object
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CircleError::Config`] if the chain ID or gas limit is zero or
+    /// missing, if the fee fields are missing or mix legacy and EIP-1559
+    /// pricing, if the priority fee exceeds the fee cap, or if a contract
+    /// creation has no init code.
+    pub fn to_transaction(&self) -> CircleResult<EvmTransaction> {
+        let invalid =
+            |reason: &str| CircleError::Config(format!("Invalid EVM transaction: {}", reason));
+
+        if self.chain_id == 0 {
+            return Err(invalid("chain ID must be non-zero"));
+        }
+        let gas = match self.gas_limit {
+            Some(0) | None => return Err(invalid("a non-zero gas limit is required")),
+            Some(gas) => gas,
+        };
+        if self.to.is_none() && self.data.is_empty() {
+            return Err(invalid("contract creation requires init code"));
+        }
+
+        let fees = (
+            self.gas_price,
+            self.max_fee_per_gas,
+            self.max_priority_fee_per_gas,
+        );
+        let (gas_price, max_fee_per_gas, max_priority_fee_per_gas) = match fees {
+            (Some(gas_price), None, None) => (Some(gas_price.to_string()), None, None),
+            (None, Some(max_fee), Some(priority_fee)) => {
+                if priority_fee > max_fee {
+                    return Err(invalid("max priority fee per gas exceeds max fee per gas"));
+                }
+                (
+                    None,
+                    Some(max_fee.to_string()),
+                    Some(priority_fee.to_string()),
+                )
+            }
+            (Some(_), _, _) => {
+                return Err(invalid(
+                    "gas price cannot be combined with EIP-1559 fee fields",
+                ))
+            }
+            (None, None, None) => {
+                return Err(invalid(
+                    "either gas price or EIP-1559 fee fields are required",
+                ))
+            }
+            (None, _, _) => {
+                return Err(invalid(
+                    "EIP-1559 transactions require both max fee and max priority fee per gas",
+                ))
+            }
+        };
+
+        Ok(EvmTransaction {
+            nonce: self.nonce,
+            to: self.to.map(|to| to.to_string()),
+            value: self.value.to_string(),
+            data: (!self.data.is_empty()).then(|| format!("0x{}", hex::encode(&self.data))),
+            gas,
+            gas_price,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            chain_id: self.chain_id,
+        })
+    }
+
+    /// Serialize the transaction to the JSON string Circle expects
+    pub fn to_json(&self) -> CircleResult<String> {
+        Ok(serde_json::to_string(&self.to_transaction()?)?)
+    }
+
+    /// Create a sign transaction request for the Circle wallet `wallet_id`
+    pub fn into_sign_request(
+        self,
+        wallet_id: String,
+    ) -> CircleResult<SignTransactionRequestBuilder> {
+        SignTransactionRequestBuilder::new(wallet_id, None, Some(self.to_json()?))
+    }
+}
+
+/// Parse the `signedTransaction` returned by Circle into its fields
+///
+/// Accepts legacy, EIP-2930 and EIP-1559 transactions, and computes the
+/// transaction hash so the broadcast can be tracked.
+///
+/// # Arguments
+/// * `signed_transaction` - Hex signed transaction from `SignTransactionResponse`
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::evm::parse_signed_evm_transaction;
+///
+/// // EIP-155 example transaction
+/// let signed = parse_signed_evm_transaction(concat!(
+///     "0xf86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a7640000",
+///     "8025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aec",
+///     "b703304b3800ccf555c9f3dc64214b297fb1966a3b6d83",
+/// )).unwrap();
+/// assert_eq!(signed.chain_id, Some(1));
+/// assert_eq!(signed.nonce, 9);
+/// ```
+pub fn parse_signed_evm_transaction(
+    signed_transaction: &str,
+) -> CircleResult<SignedEvmTransaction> {
+    let trimmed = signed_transaction.trim();
+    let bytes = hex::decode(trimmed.strip_prefix("0x").unwrap_or(trimmed))
+        .map_err(|e| CircleError::Config(format!("Invalid hex EVM transaction: {}", e)))?;
+    let invalid =
+        |reason: &str| CircleError::Config(format!("Invalid EVM transaction: {}", reason));
+
+    let (transaction_type, payload) = match bytes.first() {
+        None => return Err(invalid("empty")),
+        Some(0x01) => (EvmTransactionType::AccessList, &bytes[1..]),
+        Some(0x02) => (EvmTransactionType::Eip1559, &bytes[1..]),
+        Some(prefix) if *prefix >= 0xc0 => (EvmTransactionType::Legacy, &bytes[..]),
+        Some(prefix) => {
+            return Err(invalid(&format!(
+                "unsupported transaction type 0x{:02x}",
+                prefix
+            )))
+        }
+    };
+
+    let decoded = rlp::decode(payload)?;
+    let fields = decoded.as_list("transaction")?;
+
+    // Index of the nonce and of the gas limit within the field list
+    let (expected, nonce_at, gas_at) = match transaction_type {
+        EvmTransactionType::Legacy => (9, 0, 2),
+        EvmTransactionType::AccessList => (11, 1, 3),
+        EvmTransactionType::Eip1559 => (12, 1, 4),
+    };
+    if fields.len() != expected {
+        return Err(invalid(&format!(
+            "expected {} fields, found {}",
+            expected,
+            fields.len()
+        )));
+    }
+
+    let to = match fields[gas_at + 1].as_bytes("to")? {
+        [] => None,
+        bytes if bytes.len() == 20 => {
+            let mut address = [0u8; 20];
+            address.copy_from_slice(bytes);
+            Some(Address::from_bytes(address))
+        }
+        _ => return Err(invalid("recipient must be 20 bytes")),
+    };
+
+    let signature = &fields[expected - 3..];
+    let v = signature[0].as_u64("v")?;
+    let chain_id = match transaction_type {
+        EvmTransactionType::Legacy if v >= 35 => Some((v - 35) / 2),
+        EvmTransactionType::Legacy if v == 27 || v == 28 => None,
+        EvmTransactionType::Legacy => return Err(invalid("invalid v value")),
+        _ => Some(fields[0].as_u64("chain ID")?),
+    };
+
+    Ok(SignedEvmTransaction {
+        transaction_type,
+        chain_id,
+        nonce: fields[nonce_at].as_u64("nonce")?,
+        to,
+        value: rlp_u256(&fields[gas_at + 2], "value")?,
+        data: fields[gas_at + 3].as_bytes("data")?.to_vec(),
+        gas_limit: fields[gas_at].as_u64("gas limit")?,
+        v,
+        r: rlp_u256(&signature[1], "r")?,
+        s: rlp_u256(&signature[2], "s")?,
+        hash: format!("0x{}", hex::encode(keccak256(&bytes))),
+        raw_transaction: format!("0x{}", hex::encode(&bytes)),
+    })
+}
+
+fn rlp_u256(item: &RlpItem<'_>, field: &str) -> CircleResult<U256> {
+    let bytes = item.as_bytes(field)?;
+    if bytes.len() > 32 {
+        return Err(CircleError::Config(format!(
+            "Invalid EVM transaction: {} exceeds 256 bits",
+            field
+        )));
+    }
+    let mut word = [0u8; 32];
+    word[32 - bytes.len()..].copy_from_slice(bytes);
+    Ok(U256::from_be_bytes(word))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::handler::encode_function_call;
+    use serde_json::json;
+
+    /// EIP-155 example: nonce 9, 20 gwei, 21000 gas, 1 ether to 0x3535...35 on mainnet
+    const EIP155_SIGNED: &str = concat!(
+        "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a7640000",
+        "8025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aec",
+        "b703304b3800ccf555c9f3dc64214b297fb1966a3b6d83",
+    );
+
+    fn rlp_string(bytes: &[u8]) -> Vec<u8> {
+        match bytes {
+            [byte] if *byte < 0x80 => vec![*byte],
+            _ => {
+                assert!(bytes.len() < 56);
+                let mut out = vec![0x80 + bytes.len() as u8];
+                out.extend_from_slice(bytes);
+                out
+            }
+        }
+    }
+
+    fn rlp_list(items: &[Vec<u8>]) -> Vec<u8> {
+        let payload: Vec<u8> = items.concat();
+        let mut out = if payload.len() < 56 {
+            vec![0xc0 + payload.len() as u8]
+        } else {
+            vec![0xf8, payload.len() as u8]
+        };
+        out.extend(payload);
+        out
+    }
+
+    #[test]
+    fn test_builder_serializes_circle_json() {
+        let to: Address = "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb0"
+            .parse()
+            .unwrap();
+        let builder = EvmTransactionBuilder::new(11155111, 3)
+            .to(to)
+            .value(U256::from(1_000_000_000_000_000_000u128))
+            .gas_limit(21_000)
+            .max_fee_per_gas(U256::from(30_000_000_000u64))
+            .max_priority_fee_per_gas(U256::from(1_000_000_000u64));
+
+        let value: serde_json::Value = serde_json::from_str(&builder.to_json().unwrap()).unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "nonce": 3,
+                "to": "0x742d35cc6634c0532925a3b844bc9e7595f0beb0",
+                "value": "1000000000000000000",
+                "gas": 21000,
+                "maxFeePerGas": "30000000000",
+                "maxPriorityFeePerGas": "1000000000",
+                "chainId": 11155111
+            })
+        );
+
+        let request = builder
+            .call(
+                "transfer(address,uint256)",
+                &[AbiValue::from(to), AbiValue::from(U256::from(5u64))],
+            )
+            .into_sign_request("wallet-id".to_string())
+            .unwrap();
+        let transaction: EvmTransaction =
+            serde_json::from_str(request.transaction.as_deref().unwrap()).unwrap();
+        assert_eq!(
+            transaction.data.unwrap(),
+            encode_function_call(
+                "transfer(address,uint256)",
+                &[AbiValue::from(to), AbiValue::from(U256::from(5u64))]
+            )
+        );
+        assert!(request.raw_transaction.is_none());
+    }
+
+    #[test]
+    fn test_builder_validation() {
+        let to = Address::from_bytes([0x11; 20]);
+        let base = EvmTransactionBuilder::new(1, 0).to(to).gas_limit(21_000);
+
+        let legacy = base
+            .clone()
+            .gas_price(U256::from(1u64))
+            .to_transaction()
+            .unwrap();
+        assert_eq!(legacy.gas_price.as_deref(), Some("1"));
+        assert!(legacy.max_fee_per_gas.is_none());
+
+        assert!(base.clone().to_transaction().is_err());
+        assert!(base
+            .clone()
+            .gas_price(U256::from(1u64))
+            .max_fee_per_gas(U256::from(1u64))
+            .to_transaction()
+            .is_err());
+        assert!(base
+            .clone()
+            .max_fee_per_gas(U256::from(1u64))
+            .to_transaction()
+            .is_err());
+        assert!(base
+            .clone()
+            .max_fee_per_gas(U256::from(1u64))
+            .max_priority_fee_per_gas(U256::from(2u64))
+            .to_transaction()
+            .is_err());
+        assert!(EvmTransactionBuilder::new(0, 0)
+            .to(to)
+            .gas_limit(21_000)
+            .gas_price(U256::from(1u64))
+            .to_transaction()
+            .is_err());
+        assert!(EvmTransactionBuilder::new(1, 0)
+            .to(to)
+            .gas_price(U256::from(1u64))
+            .to_transaction()
+            .is_err());
+
+        let deployment = EvmTransactionBuilder::new(1, 0)
+            .gas_limit(100_000)
+            .gas_price(U256::from(1u64));
+        assert!(deployment.clone().to_transaction().is_err());
+        let deployment = deployment.data(vec![0x60, 0x00]).to_transaction().unwrap();
+        assert!(deployment.to.is_none());
+        assert_eq!(deployment.data.as_deref(), Some("0x6000"));
+    }
+
+    #[test]
+    fn test_parse_legacy_eip155_transaction() {
+        let signed = parse_signed_evm_transaction(EIP155_SIGNED).unwrap();
+        assert_eq!(signed.transaction_type, EvmTransactionType::Legacy);
+        assert_eq!(signed.chain_id, Some(1));
+        assert_eq!(signed.nonce, 9);
+        assert_eq!(signed.gas_limit, 21_000);
+        assert_eq!(signed.to, Some(Address::from_bytes([0x35; 20])));
+        assert_eq!(signed.value, U256::from(1_000_000_000_000_000_000u128));
+        assert!(signed.data.is_empty());
+        assert_eq!(signed.v, 37);
+        assert_eq!(
+            signed.hash,
+            "0x33469b22e9f636356c4160a87eb19df52b7412e8eac32a4a55ffe88ea8350788"
+        );
+        assert_eq!(signed.raw_transaction, format!("0x{}", EIP155_SIGNED));
+    }
+
+    #[test]
+    fn test_parse_eip1559_transaction() {
+        let fields = vec![
+            rlp_string(&[0xaa, 0x36, 0xa7]),             // chain ID 11155111
+            rlp_string(&[0x07]),                         // nonce
+            rlp_string(&[0x3b, 0x9a, 0xca, 0x00]),       // priority fee
+            rlp_string(&[0x06, 0xfc, 0x23, 0xac, 0x00]), // max fee
+            rlp_string(&[0xfd, 0xe8]),                   // gas limit 65000
+            rlp_string(&[0x22; 20]),                     // to
+            rlp_string(&[]),                             // value
+            rlp_string(&[0xa9, 0x05, 0x9c, 0xbb]),       // data
+            rlp_list(&[]),                               // access list
+            rlp_string(&[0x01]),                         // y parity
+            rlp_string(&[0x11; 32]),                     // r
+            rlp_string(&[0x22; 32]),                     // s
+        ];
+        let mut raw = vec![0x02];
+        raw.extend(rlp_list(&fields));
+
+        let signed = parse_signed_evm_transaction(&hex::encode(&raw)).unwrap();
+        assert_eq!(signed.transaction_type, EvmTransactionType::Eip1559);
+        assert_eq!(signed.chain_id, Some(11155111));
+        assert_eq!(signed.nonce, 7);
+        assert_eq!(signed.gas_limit, 65_000);
+        assert_eq!(signed.to, Some(Address::from_bytes([0x22; 20])));
+        assert_eq!(signed.value, U256::ZERO);
+        assert_eq!(signed.data, vec![0xa9, 0x05, 0x9c, 0xbb]);
+        assert_eq!(signed.v, 1);
+        assert_eq!(signed.r, U256::from_be_bytes([0x11; 32]));
+
+        assert!(parse_signed_evm_transaction(&hex::encode(&raw[..raw.len() - 1])).is_err());
+        raw[0] = 0x04;
+        assert!(parse_signed_evm_transaction(&hex::encode(&raw)).is_err());
+        assert!(parse_signed_evm_transaction("0xzz").is_err());
+    }
+}
@@ -0,0 +1,406 @@
+//! Fee estimate types shared by all estimate endpoints, and budget recommendations
+
+use std::cmp::Ordering;
+
+use serde::{Deserialize, Serialize};
+
+use crate::abi::U256;
+use crate::dev_wallet::dto::{FeeConfig, FeeLevel, FeeSelection};
+use crate::helper::{compare_decimal_str, CircleError, CircleResult};
+use crate::rates::dto::Decimal;
+use crate::types::TokenAmount;
+
+use super::{gas_cost_native, normalize_native, GWEI_DECIMALS};
+
+/// Decimal places of the native token of EVM blockchains
+const NATIVE_DECIMALS: u32 = 18;
+
+/// One tier of a fee estimate
+///
+/// All fields are optional: EVM estimates carry gas pricing, while
+/// blockchains without gas pricing (e.g., Solana) only report a network fee.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EstimatedFee {
+    /// The maximum units of gas to use for the transaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_limit: Option<String>,
+
+    /// The maximum price of gas, in gwei, to use per each unit of gas
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_price: Option<String>,
+
+    /// The maximum price per unit of gas for EIP-1559 support
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_fee: Option<String>,
+
+    /// The "tip" to add to the base fee for EIP-1559 support
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority_fee: Option<String>,
+
+    /// The estimated base fee for EIP-1559 support
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_fee: Option<String>,
+
+    /// The estimated network fee (maximum amount in the native token)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_fee: Option<String>,
+
+    /// The estimated network fee with lower buffer, closer to the actual on-chain expense
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_fee_raw: Option<String>,
+
+    /// Defines the blockchain fee level
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fee_level: Option<String>,
+}
+
+impl EstimatedFee {
+    /// Explicit gas settings matching this estimate
+    ///
+    /// Returns EIP-1559 settings when the estimate has a max fee, priority fee
+    /// and gas limit, legacy settings when it has a gas price and gas limit, and
+    /// `None` otherwise (e.g., on blockchains without gas pricing).
+    pub fn to_fee_config(&self) -> Option<FeeConfig> {
+        let gas_limit = self.gas_limit.clone()?;
+        match (&self.max_fee, &self.priority_fee, &self.gas_price) {
+            (Some(max_fee), Some(priority_fee), _) => Some(FeeConfig::eip1559(
+                max_fee.clone(),
+                priority_fee.clone(),
+                gas_limit,
+            )),
+            (_, _, Some(gas_price)) => Some(FeeConfig::legacy(gas_price.clone(), gas_limit)),
+            _ => None,
+        }
+    }
+
+    /// Maximum cost in the blockchain's native token (e.g., ETH), as a decimal string
+    ///
+    /// With gas pricing this is `gas_limit × max_fee`, or `gas_limit ×
+    /// gas_price` for legacy estimates, converting the price from gwei.
+    /// Otherwise it is Circle's `network_fee`, which is already in the native
+    /// token. Returns `None` when the estimate has neither. See
+    /// [`fees`](crate::fees) for display formatting.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Config` if a field is not a plain non-negative
+    /// decimal or the product is too large.
+    pub fn total_cost_native(&self) -> CircleResult<Option<String>> {
+        let price = self.max_fee.as_ref().or(self.gas_price.as_ref());
+        if let (Some(gas_limit), Some(price)) = (&self.gas_limit, price) {
+            return gas_cost_native(gas_limit, price).map(Some);
+        }
+        self.network_fee
+            .as_deref()
+            .map(normalize_native)
+            .transpose()
+    }
+}
+
+/// Low, medium and high fee tiers, returned by every fee estimate endpoint
+///
+/// Transfer, contract execution, contract deployment and template deployment
+/// estimates all share this type.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeEstimate {
+    pub low: EstimatedFee,
+    pub medium: EstimatedFee,
+    pub high: EstimatedFee,
+
+    /// ERC-4337 gas field: amount of gas for main execution call (SCA only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_gas_limit: Option<String>,
+
+    /// ERC-4337 gas field: amount of gas for verification step (SCA only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification_gas_limit: Option<String>,
+
+    /// ERC-4337 gas field: gas to compensate bundler for pre-verification (SCA only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pre_verification_gas: Option<String>,
+}
+
+impl FeeEstimate {
+    /// The tier matching `selection`
+    pub fn tier(&self, selection: FeeSelection) -> &EstimatedFee {
+        match selection {
+            FeeSelection::Low => &self.low,
+            FeeSelection::Medium => &self.medium,
+            FeeSelection::High => &self.high,
+        }
+    }
+
+    /// Pick the fee for a transaction that must cost at most `max_budget_native`
+    ///
+    /// Tries the tier matching `urgency` first, then each cheaper tier, and
+    /// returns the first whose [maximum cost](EstimatedFee::total_cost_native)
+    /// fits the budget. When even the low tier is too expensive, returns
+    /// custom gas settings: the low tier's gas limit with the highest gas
+    /// price the budget allows. Such a transaction may confirm slowly or not
+    /// at all. Returns `None` if the budget cannot pay for the low tier's gas
+    /// limit at any price, or the estimate has no gas pricing to adjust.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Config` if the budget or an estimate field is not
+    /// a plain non-negative decimal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use inf_circle_sdk::fees::{FeeEstimate, FeeRecommendation, Urgency};
+    /// use inf_circle_sdk::dev_wallet::dto::FeeSelection;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let tier = |max_fee: &str| serde_json::json!({
+    ///     "gasLimit": "21000", "maxFee": max_fee, "priorityFee": "1"
+    /// });
+    /// let estimate: FeeEstimate = serde_json::from_value(serde_json::json!({
+    ///     "low": tier("20"), "medium": tier("30"), "high": tier("50")
+    /// }))?;
+    ///
+    /// // High costs 0.00105 ETH, medium 0.00063 ETH
+    /// let pick = estimate.recommend("0.001", Urgency::High)?.unwrap();
+    /// assert!(matches!(pick, FeeRecommendation::Tier { selection: FeeSelection::Medium, .. }));
+    ///
+    /// // Below the low tier's 0.00042 ETH, the gas price is capped instead
+    /// let pick = estimate.recommend("0.00021", Urgency::Normal)?.unwrap();
+    /// assert_eq!(pick.max_cost_native(), "0.00021");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn recommend(
+        &self,
+        max_budget_native: &str,
+        urgency: Urgency,
+    ) -> CircleResult<Option<FeeRecommendation>> {
+        let tiers = [FeeSelection::High, FeeSelection::Medium, FeeSelection::Low];
+        let start = tiers
+            .iter()
+            .position(|tier| *tier == urgency.selection())
+            .unwrap_or_default();
+
+        for selection in &tiers[start..] {
+            let fee = self.tier(*selection);
+            let Some(cost) = fee.total_cost_native()? else {
+                continue;
+            };
+            if fits(&cost, max_budget_native)? {
+                return Ok(Some(FeeRecommendation::Tier {
+                    selection: *selection,
+                    fee: fee.clone(),
+                    max_cost_native: cost,
+                }));
+            }
+        }
+        custom_fee(&self.low, max_budget_native)
+    }
+}
+
+/// How quickly a transaction should confirm
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Urgency {
+    /// Cheapest tier only
+    Low,
+    /// Medium tier, or low if medium is over budget
+    #[default]
+    Normal,
+    /// High tier, or the fastest cheaper tier within budget
+    High,
+}
+
+impl Urgency {
+    /// The tier tried first
+    pub fn selection(&self) -> FeeSelection {
+        match self {
+            Urgency::Low => FeeSelection::Low,
+            Urgency::Normal => FeeSelection::Medium,
+            Urgency::High => FeeSelection::High,
+        }
+    }
+}
+
+/// Fee picked by [`FeeEstimate::recommend`]
+#[derive(Debug, Clone)]
+pub enum FeeRecommendation {
+    /// One of Circle's fee tiers
+    Tier {
+        selection: FeeSelection,
+        fee: EstimatedFee,
+        /// Maximum cost in the native token
+        max_cost_native: String,
+    },
+    /// Explicit gas settings capped at the budget
+    Custom {
+        fee: FeeConfig,
+        /// Maximum cost in the native token
+        max_cost_native: String,
+    },
+}
+
+impl FeeRecommendation {
+    /// Fee settings to submit the transaction with
+    pub fn fee_config(&self) -> FeeConfig {
+        match self {
+            FeeRecommendation::Tier { selection, .. } => FeeConfig::level(selection.fee_level()),
+            FeeRecommendation::Custom { fee, .. } => fee.clone(),
+        }
+    }
+
+    /// Maximum cost in the native token, as a decimal string
+    pub fn max_cost_native(&self) -> &str {
+        match self {
+            FeeRecommendation::Tier {
+                max_cost_native, ..
+            }
+            | FeeRecommendation::Custom {
+                max_cost_native, ..
+            } => max_cost_native,
+        }
+    }
+
+    /// The fee level of a tier recommendation
+    pub fn fee_level(&self) -> Option<FeeLevel> {
+        match self {
+            FeeRecommendation::Tier { selection, .. } => Some(selection.fee_level()),
+            FeeRecommendation::Custom { .. } => None,
+        }
+    }
+}
+
+fn fits(cost: &str, budget: &str) -> CircleResult<bool> {
+    compare_decimal_str(cost, budget)
+        .map(|ordering| ordering != Ordering::Greater)
+        .ok_or_else(|| {
+            CircleError::Config(format!("Invalid fee budget or cost: {} / {}", budget, cost))
+        })
+}
+
+/// The low tier's gas limit at the highest gas price `budget` pays for
+fn custom_fee(low: &EstimatedFee, budget: &str) -> CircleResult<Option<FeeRecommendation>> {
+    let Some(gas_limit) = &low.gas_limit else {
+        return Ok(None);
+    };
+    if low.max_fee.is_none() && low.gas_price.is_none() {
+        return Ok(None);
+    }
+
+    let invalid = || CircleError::Config(format!("Invalid gas limit: {}", gas_limit));
+    let limit: u128 = gas_limit.trim().parse().map_err(|_| invalid())?;
+    if limit == 0 {
+        return Err(invalid());
+    }
+    let budget_wei = Decimal::parse(budget.trim())?
+        .round(NATIVE_DECIMALS)
+        .rescale(NATIVE_DECIMALS)?;
+    let price_wei = budget_wei / limit;
+    if price_wei == 0 {
+        return Ok(None);
+    }
+    let price = TokenAmount::new(U256::from(price_wei), GWEI_DECIMALS as u8).to_string();
+
+    let fee = match &low.priority_fee {
+        Some(priority_fee) if low.max_fee.is_some() => {
+            let priority_fee = match compare_decimal_str(priority_fee, &price) {
+                Some(Ordering::Greater) => price.clone(),
+                _ => priority_fee.clone(),
+            };
+            FeeConfig::eip1559(price.clone(), priority_fee, gas_limit.clone())
+        }
+        _ => FeeConfig::legacy(price.clone(), gas_limit.clone()),
+    };
+    Ok(Some(FeeRecommendation::Custom {
+        fee,
+        max_cost_native: gas_cost_native(gas_limit, &price)?,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn estimate() -> FeeEstimate {
+        let tier =
+            |max_fee: &str| json!({"gasLimit": "100000", "maxFee": max_fee, "priorityFee": "2"});
+        serde_json::from_value(json!({
+            "low": tier("10"), "medium": tier("20"), "high": tier("40")
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_recommend_steps_down_to_fit_budget() {
+        // Tier costs: low 0.001, medium 0.002, high 0.004
+        let estimate = estimate();
+
+        let pick = estimate.recommend("1", Urgency::High).unwrap().unwrap();
+        assert_eq!(pick.fee_level(), Some(FeeLevel::High));
+        let pick = estimate.recommend("1", Urgency::Low).unwrap().unwrap();
+        assert_eq!(pick.fee_level(), Some(FeeLevel::Low));
+        let pick = estimate.recommend("0.002", Urgency::High).unwrap().unwrap();
+        assert_eq!(pick.fee_level(), Some(FeeLevel::Medium));
+        assert_eq!(pick.max_cost_native(), "0.002");
+
+        // 0.0001 ETH over 100,000 gas is 1 gwei; the tip is capped at the max fee
+        let pick = estimate
+            .recommend("0.0001", Urgency::Normal)
+            .unwrap()
+            .unwrap();
+        assert!(matches!(
+            pick.fee_config(),
+            FeeConfig::Eip1559 { ref max_fee, ref priority_fee, .. }
+                if max_fee == "1" && priority_fee == "1"
+        ));
+        assert!(pick.fee_config().validate().is_ok());
+
+        // Less than one wei per gas
+        assert!(estimate
+            .recommend("0.00000000000001", Urgency::Normal)
+            .unwrap()
+            .is_none());
+        assert!(estimate.recommend("abc", Urgency::Normal).is_err());
+    }
+
+    #[test]
+    fn test_estimate_endpoints_share_one_shape() {
+        // Deployment estimates always carry a gas limit
+        let deployment: FeeEstimate = serde_json::from_value(json!({
+            "low": {"gasLimit": "21000", "gasPrice": "5"},
+            "medium": {"gasLimit": "21000", "gasPrice": "6"},
+            "high": {"gasLimit": "21000", "gasPrice": "7"}
+        }))
+        .unwrap();
+        assert_eq!(
+            deployment.tier(FeeSelection::High).gas_price.as_deref(),
+            Some("7")
+        );
+
+        // A legacy estimate gets a legacy custom fee
+        let pick = deployment
+            .recommend("0.000021", Urgency::Normal)
+            .unwrap()
+            .unwrap();
+        assert!(matches!(
+            pick.fee_config(),
+            FeeConfig::Legacy { ref gas_price, .. } if gas_price == "1"
+        ));
+
+        // Transfer estimates on blockchains without gas pricing only have network fees
+        let transfer: FeeEstimate = serde_json::from_value(json!({
+            "low": {"networkFee": "0.01"},
+            "medium": {"networkFee": "0.02"},
+            "high": {"networkFee": "0.03"},
+            "callGasLimit": "50000"
+        }))
+        .unwrap();
+        assert_eq!(transfer.call_gas_limit.as_deref(), Some("50000"));
+        // Without gas pricing, only Circle's tiers can be recommended
+        assert!(transfer
+            .recommend("0.005", Urgency::Normal)
+            .unwrap()
+            .is_none());
+    }
+}
@@ -0,0 +1,178 @@
+//! Fee Estimates and Display
+//!
+//! Circle fee estimates give gas limits in gas units and gas prices in gwei,
+//! while users want to see what a transaction costs in the native token and
+//! in fiat. [`EstimatedFee::total_cost_native`] does the gas × price math in
+//! exact decimals, and the helpers here format the result.
+//!
+//! # Main Components
+//!
+//! - [`FeeEstimate`]: Low, medium and high [`EstimatedFee`] tiers, returned by every estimate endpoint
+//! - [`FeeEstimate::recommend`]: The fastest tier, or custom gas, within a budget
+//! - [`format_fee`]: A fee estimate as `~0.0021 ETH ($4.12)`
+//! - [`format_native`]: A native token amount rounded for display
+//! - [`format_gwei`]: An on-chain wei value in gwei
+//!
+//! # Example
+//!
+//! ```rust
+//! use inf_circle_sdk::fees::{format_fee, EstimatedFee};
+//! use inf_circle_sdk::rates::dto::{Currency, CurrencyPair, ExchangeRate};
+//! use inf_circle_sdk::types::Blockchain;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! // 21,000 gas at up to 100 gwei
+//! let fee: EstimatedFee = serde_json::from_value(serde_json::json!({
+//!     "gasLimit": "21000",
+//!     "maxFee": "100",
+//!     "priorityFee": "2"
+//! }))?;
+//! assert_eq!(fee.total_cost_native()?.as_deref(), Some("0.0021"));
+//!
+//! let eth_usd = ExchangeRate::new(CurrencyPair::new("ETH", Currency::Usd), "1962.45");
+//! let label = format_fee(&fee, &Blockchain::Eth, Some(&eth_usd))?;
+//! assert_eq!(label.as_deref(), Some("~0.0021 ETH ($4.12)"));
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod estimate;
+
+use crate::abi::U256;
+use crate::helper::CircleResult;
+use crate::rates::dto::{Currency, Decimal, ExchangeRate};
+use crate::types::{Blockchain, TokenAmount};
+
+pub use estimate::{EstimatedFee, FeeEstimate, FeeRecommendation, Urgency};
+
+/// Decimal places of a gwei amount expressed in wei, and of a native amount expressed in gwei
+const GWEI_DECIMALS: u32 = 9;
+
+/// Significant digits [`format_fee`] keeps for the native amount
+const FEE_SIGNIFICANT_DIGITS: u32 = 3;
+
+/// Format a wei value in gwei, e.g. `1500000000` as `1.5 gwei`
+pub fn format_gwei(wei: U256) -> String {
+    format!("{} gwei", TokenAmount::new(wei, GWEI_DECIMALS as u8))
+}
+
+/// Format a native token amount rounded to `significant_digits`, e.g. `0.0021 ETH`
+///
+/// Digits before the decimal point are never rounded away.
+///
+/// # Errors
+///
+/// Returns `CircleError::Config` if `amount` is not a plain non-negative decimal.
+pub fn format_native(amount: &str, symbol: &str, significant_digits: u32) -> CircleResult<String> {
+    let rounded = Decimal::parse(amount.trim())?
+        .round_significant(significant_digits)
+        .normalize();
+    Ok(format!("{} {}", rounded, symbol))
+}
+
+/// Format a fee estimate as `~0.0021 ETH`, or `~0.0021 ETH ($4.12)` with a rate
+///
+/// `rate` converts the blockchain's native token into the display currency.
+/// The fiat value is computed from the unrounded native cost. Returns `None`
+/// when the estimate carries no cost (see [`EstimatedFee::total_cost_native`]).
+///
+/// # Errors
+///
+/// Returns `CircleError::Config` if a fee field or the rate is not a plain
+/// non-negative decimal.
+pub fn format_fee(
+    fee: &EstimatedFee,
+    blockchain: &Blockchain,
+    rate: Option<&ExchangeRate>,
+) -> CircleResult<Option<String>> {
+    let Some(cost) = fee.total_cost_native()? else {
+        return Ok(None);
+    };
+    let symbol = blockchain.native_symbol().unwrap_or(blockchain.as_str());
+    let mut label = format!("~{}", format_native(&cost, symbol, FEE_SIGNIFICANT_DIGITS)?);
+    if let Some(rate) = rate {
+        let converted = rate.convert(&cost)?;
+        match rate.pair.to {
+            Currency::Usd => label.push_str(&format!(" (${})", converted)),
+            ref other => label.push_str(&format!(" ({} {})", converted, other.as_str())),
+        }
+    }
+    Ok(Some(label))
+}
+
+/// `gas_limit × price_gwei` in the native token, as a normalized decimal string
+pub(crate) fn gas_cost_native(gas_limit: &str, price_gwei: &str) -> CircleResult<String> {
+    let cost = Decimal::parse(gas_limit.trim())?
+        .checked_mul(Decimal::parse(price_gwei.trim())?)?
+        .shift_right(GWEI_DECIMALS)
+        .normalize();
+    Ok(cost.to_string())
+}
+
+/// A native token amount as a normalized decimal string
+pub(crate) fn normalize_native(amount: &str) -> CircleResult<String> {
+    Ok(Decimal::parse(amount.trim())?.normalize().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rates::dto::CurrencyPair;
+    use serde_json::json;
+
+    fn fee(value: serde_json::Value) -> EstimatedFee {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_total_cost_native_units() {
+        // EIP-1559 uses the max fee
+        let eip1559 = fee(json!({"gasLimit": "21000", "maxFee": "30.5", "priorityFee": "1.5"}));
+        assert_eq!(
+            eip1559.total_cost_native().unwrap().as_deref(),
+            Some("0.0006405")
+        );
+        // Legacy uses the gas price
+        let legacy = fee(json!({"gasLimit": "50000", "gasPrice": "0.000000001"}));
+        assert_eq!(
+            legacy.total_cost_native().unwrap().as_deref(),
+            Some("0.00000000000005")
+        );
+        // Without gas pricing, Circle's network fee is already in the native token
+        let solana = fee(json!({"networkFee": "0.000005000"}));
+        assert_eq!(
+            solana.total_cost_native().unwrap().as_deref(),
+            Some("0.000005")
+        );
+        assert_eq!(fee(json!({})).total_cost_native().unwrap(), None);
+        assert!(fee(json!({"gasLimit": "1e5", "gasPrice": "1"}))
+            .total_cost_native()
+            .is_err());
+    }
+
+    #[test]
+    fn test_formatting() {
+        assert_eq!(format_gwei(U256::from(1_500_000_000u64)), "1.5 gwei");
+        assert_eq!(format_gwei(U256::from(7u64)), "0.000000007 gwei");
+
+        assert_eq!(format_native("0.00209876", "ETH", 3).unwrap(), "0.0021 ETH");
+        assert_eq!(format_native("0.009996", "ETH", 3).unwrap(), "0.01 ETH");
+        assert_eq!(format_native("1234.5678", "AVAX", 3).unwrap(), "1235 AVAX");
+        assert_eq!(format_native("0", "SOL", 3).unwrap(), "0 SOL");
+
+        let estimate = fee(json!({"gasLimit": "21000", "gasPrice": "100"}));
+        let eur = ExchangeRate::new(CurrencyPair::new("POL", Currency::Eur), "0.5");
+        assert_eq!(
+            format_fee(&estimate, &Blockchain::Matic, Some(&eur))
+                .unwrap()
+                .as_deref(),
+            Some("~0.0021 POL (0.00 EUR)")
+        );
+        assert_eq!(
+            format_fee(&estimate, &Blockchain::Evm, None)
+                .unwrap()
+                .as_deref(),
+            Some("~0.0021 EVM")
+        );
+    }
+}
@@ -9,6 +9,18 @@
 //! - [`CircleResult`]: Type alias for `Result<T, CircleError>`
 //! - [`HttpClient`]: Configured HTTP client for Circle API requests
 //! - [`encrypt_entity_secret`]: RSA-OAEP encryption for entity secrets
+//! - [`event_signature_hash`]: Local Keccak-256 event topic computation
+//! - [`verify_evm_personal_sign`], [`verify_near_signature`], [`verify_solana_signature`]:
+//!   Local verification of `dev_sign_message` signatures
+//! - [`await_visible`], [`await_visible_where`]: Read-your-writes polling for freshly created resources
+//! - [`with_timeout`]: Per-call timeouts and cancellation
+//! - [`address`]: Offline address format validation per blockchain
+//! - [`environment`]: Sandbox/production presets and the sandbox mainnet guard
+//! - [`http_core`]: Connection pool shared by `CircleView` and `CircleOps`
+//! - [`metrics`]: Per-request metrics hooks (counters and latencies)
+//! - [`page`]: [`Page`](page::Page) of a list endpoint with next/previous cursors
+//! - [`rate_limit`]: Client-side token bucket rate limiting per endpoint class
+//! - [`time_window`]: `since`/`today` bounds for date-range list filters
 //! - Serialization helpers for API compatibility
 //!
 //! # Error Handling
@@ -29,17 +41,37 @@
 //! ```
 
 use chrono::{DateTime, Utc};
-use reqwest::{Client, Method, RequestBuilder, Response};
-use serde::{Deserialize, Serialize, Serializer};
+use futures_util::future::{select, Either};
+use reqwest::{Client, Method, Request, RequestBuilder, Response};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::future::Future;
+use std::pin::pin;
+use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
 use url::Url;
 
 // Cryptography imports
+use crate::abi::keccak256;
 use anyhow::{anyhow, Result as AnyhowResult};
 use base64::{engine::general_purpose, Engine};
 use rsa::{pkcs1::DecodeRsaPublicKey, pkcs8::DecodePublicKey, Oaep, RsaPublicKey};
 use sha2::Sha256;
+use zeroize::Zeroizing;
+
+pub mod address;
+pub mod environment;
+pub mod http_core;
+pub mod metrics;
+pub mod page;
+pub mod rate_limit;
+pub mod time_window;
+
+use environment::Environment;
+use metrics::{endpoint_template, MetricsRecorder, RequestMetrics};
+use rate_limit::{EndpointClass, RateLimiter};
 
 /// Result type alias for Circle SDK operations
 pub type CircleResult<T> = Result<T, CircleError>;
@@ -56,9 +88,14 @@ pub type CircleResult<T> = Result<T, CircleError>;
 /// - `Http`: HTTP request failures (network errors, timeouts, etc.)
 /// - `Json`: JSON serialization/deserialization errors
 /// - `Url`: URL parsing errors
-/// - `Api`: Circle API errors with HTTP status code and message
+/// - `Api`: Circle API errors with HTTP status code, message and Circle's request ID
 /// - `Config`: Invalid SDK configuration
 /// - `Uuid`: UUID parsing or generation errors
+/// - `Abi`: Invalid ABI values or encoding failures
+/// - `AmountTooSmall`: Transfer amount below the blockchain's minimum or dust threshold
+/// - `Maintenance`: Circle is in a maintenance window (HTTP 503), with the advertised `Retry-After`
+/// - `Schema`: A response does not match Circle's published schema (`schema-validation` feature)
+/// - `Timeout`: A call wrapped in [`with_timeout`] did not finish in time
 #[derive(Error, Debug)]
 pub enum CircleError {
     #[error("Environment variable error: {0}")]
@@ -73,14 +110,111 @@ pub enum CircleError {
     #[error("URL parsing error: {0}")]
     Url(#[from] url::ParseError),
 
-    #[error("API error: {status} - {message}")]
-    Api { status: u16, message: String },
+    #[error("API error: {status} - {message}{}", request_id_hint(.request_id))]
+    Api {
+        status: u16,
+        message: String,
+        /// Circle's `X-Request-Id` for the failed call, to quote in support tickets
+        request_id: Option<String>,
+    },
 
     #[error("Invalid configuration: {0}")]
     Config(String),
 
     #[error("UUID error: {0}")]
     Uuid(#[from] uuid::Error),
+
+    #[error("ABI error: {0}")]
+    Abi(String),
+
+    #[error("Amount {amount} is below the minimum of {minimum} on {blockchain}")]
+    AmountTooSmall {
+        amount: String,
+        minimum: String,
+        blockchain: String,
+    },
+
+    #[error("Circle API is unavailable for maintenance{}", retry_after_hint(.retry_after))]
+    Maintenance { retry_after: Option<Duration> },
+
+    #[cfg(feature = "schema-validation")]
+    #[error("Response from {endpoint} does not match the Circle schema: {}", .violations.join("; "))]
+    Schema {
+        endpoint: String,
+        violations: Vec<String>,
+    },
+
+    #[error("Call did not finish within {0:?}")]
+    Timeout(Duration),
+}
+
+/// Format the optional retry delay of a maintenance error
+fn retry_after_hint(retry_after: &Option<Duration>) -> String {
+    retry_after
+        .map(|delay| format!(" (retry after {}s)", delay.as_secs()))
+        .unwrap_or_default()
+}
+
+/// Format the optional request ID of an API error
+fn request_id_hint(request_id: &Option<String>) -> String {
+    request_id
+        .as_ref()
+        .map(|id| format!(" (request ID {})", id))
+        .unwrap_or_default()
+}
+
+impl CircleError {
+    /// Whether the call was abandoned because it exceeded its timeout
+    ///
+    /// True both for a request that exceeded the client's
+    /// `with_request_timeout` and for a call cut short by [`with_timeout`].
+    pub fn is_timeout(&self) -> bool {
+        match self {
+            CircleError::Http(error) => error.is_timeout(),
+            CircleError::Timeout(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Circle's request ID, if this error came from a Circle API response that carried one
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            CircleError::Api { request_id, .. } => request_id.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Header Circle uses to identify a request in its logs and support tickets
+pub(crate) const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Read Circle's request ID from a response
+pub(crate) fn response_request_id(response: &Response) -> Option<String> {
+    response
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+}
+
+/// Response details Circle sends alongside the data
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResponseMeta {
+    /// HTTP status code
+    pub status: u16,
+
+    /// Circle's `X-Request-Id`, to quote in support tickets
+    pub request_id: Option<String>,
+}
+
+/// A decoded response together with its [`ResponseMeta`]
+#[derive(Debug, Clone)]
+pub struct WithMeta<T> {
+    /// The decoded `data` field
+    pub data: T,
+
+    /// Status and request ID of the response
+    pub meta: ResponseMeta,
 }
 
 /// Standard Circle API response wrapper
@@ -131,6 +265,30 @@ where
     }
 }
 
+/// Helper function to deserialize an optional value written either as itself or as a string
+///
+/// Counterpart of the `serialize_*_as_string` helpers, so query parameters
+/// survive a serialize/deserialize round trip.
+pub fn deserialize_optional_from_string<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: std::str::FromStr + Deserialize<'de>,
+    T::Err: std::fmt::Display,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrValue<T> {
+        String(String),
+        Value(T),
+    }
+
+    match Option::<StringOrValue<T>>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(StringOrValue::Value(value)) => Ok(Some(value)),
+        Some(StringOrValue::String(value)) => value.parse().map(Some).map_err(de::Error::custom),
+    }
+}
+
 /// Common query parameters for pagination
 #[derive(Debug, Serialize, Default, Clone, Deserialize)]
 pub struct PaginationParams {
@@ -143,7 +301,9 @@ pub struct PaginationParams {
     #[serde(
         rename = "pageSize",
         skip_serializing_if = "Option::is_none",
-        serialize_with = "serialize_u32_as_string"
+        serialize_with = "serialize_u32_as_string",
+        deserialize_with = "deserialize_optional_from_string",
+        default
     )]
     pub page_size: Option<u32>,
 }
@@ -157,19 +317,38 @@ pub struct HttpClient {
     client: Client,
     base_url: Url,
     api_key: Option<String>,
+    environment: Environment,
+    mainnet_guard: bool,
+    rate_limiter: Option<RateLimiter>,
+    metrics: Option<Arc<dyn MetricsRecorder>>,
+    request_timeout: Option<Duration>,
+    #[cfg(feature = "schema-validation")]
+    validate_schemas: bool,
 }
 
 impl HttpClient {
     /// Create a new HTTP client with base URL
+    ///
+    /// Circle's sandbox and production URLs are recognized as their
+    /// [`Environment`] presets, so the sandbox mainnet guard applies.
     pub fn new(base_url: &str) -> CircleResult<Self> {
-        let client = Client::new();
-        let base_url = Url::parse(base_url)?;
+        Ok(Self::for_environment(Environment::from_base_url(base_url)?))
+    }
 
-        Ok(Self {
-            client,
-            base_url,
+    /// Create a new HTTP client for an environment
+    pub fn for_environment(environment: Environment) -> Self {
+        Self {
+            client: Client::new(),
+            base_url: environment.base_url(),
             api_key: None,
-        })
+            mainnet_guard: environment.is_sandbox(),
+            environment,
+            rate_limiter: None,
+            metrics: None,
+            request_timeout: None,
+            #[cfg(feature = "schema-validation")]
+            validate_schemas: false,
+        }
     }
 
     /// Create a new HTTP client with base URL and API key
@@ -179,6 +358,91 @@ impl HttpClient {
         Ok(client)
     }
 
+    /// Set the API key sent as a bearer token
+    pub fn api_key(mut self, api_key: String) -> Self {
+        self.api_key = Some(api_key);
+        self
+    }
+
+    /// Send requests through a preconfigured `reqwest::Client`
+    pub fn reqwest_client(mut self, client: Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Throttle every request through `limiter` before sending it
+    pub fn rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Report every request to `recorder` once it completes
+    pub fn metrics_recorder(mut self, recorder: Arc<dyn MetricsRecorder>) -> Self {
+        self.metrics = Some(recorder);
+        self
+    }
+
+    /// Abandon each request that takes longer than `timeout`
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    /// Check successful responses against the vendored Circle schemas (off by default)
+    #[cfg(feature = "schema-validation")]
+    pub fn validate_schemas(mut self, enabled: bool) -> Self {
+        self.validate_schemas = enabled;
+        self
+    }
+
+    /// The environment requests are sent to
+    pub fn environment(&self) -> &Environment {
+        &self.environment
+    }
+
+    /// Wait for the rate limiter, if any, to admit `request`
+    async fn throttle(&self, request: &Request) {
+        if let Some(limiter) = &self.rate_limiter {
+            limiter
+                .acquire(EndpointClass::of(request.method(), request.url().path()))
+                .await;
+        }
+    }
+
+    /// Send a request, reporting it to the metrics recorder if any
+    async fn dispatch(&self, client: &Client, request: Request) -> CircleResult<Response> {
+        let Some(recorder) = &self.metrics else {
+            return Ok(client.execute(request).await?);
+        };
+        let method = request.method().clone();
+        let endpoint = endpoint_template(request.url().path());
+        let started = Instant::now();
+        let result = client.execute(request).await;
+        recorder.record(&RequestMetrics {
+            method,
+            endpoint,
+            status: result
+                .as_ref()
+                .ok()
+                .map(|response| response.status().as_u16()),
+            duration: started.elapsed(),
+        });
+        Ok(result?)
+    }
+
+    /// Stop refusing mainnet blockchains in sandbox request bodies
+    pub(crate) fn allow_mainnet_on_sandbox(&mut self) {
+        self.mainnet_guard = false;
+    }
+
+    /// Refuse a request body naming a mainnet blockchain while the sandbox guard is on
+    pub(crate) fn check_payload(&self, payload: &serde_json::Value) -> CircleResult<()> {
+        if self.mainnet_guard {
+            environment::check_sandbox_payload(payload)?;
+        }
+        Ok(())
+    }
+
     /// Build a request with common headers
     pub fn request(&self, method: Method, path: &str) -> CircleResult<RequestBuilder> {
         let url = self.base_url.join(path)?;
@@ -192,29 +456,145 @@ impl HttpClient {
             request = request.header("Authorization", format!("Bearer {}", api_key));
         }
 
+        if let Some(timeout) = self.request_timeout {
+            request = request.timeout(timeout);
+        }
+
         Ok(request)
     }
 
     /// Execute a request and handle the response
+    ///
+    /// With the `tracing` feature, the call runs inside a `circle.request` span.
     pub async fn execute<T>(&self, request: RequestBuilder) -> CircleResult<T>
     where
         T: for<'de> Deserialize<'de>,
     {
-        let response = request.send().await?;
-        self.handle_response(response).await
+        Ok(self.execute_with_meta(request).await?.data)
+    }
+
+    /// Execute a request and return the decoded data with the response's status and request ID
+    pub async fn execute_with_meta<T>(&self, request: RequestBuilder) -> CircleResult<WithMeta<T>>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        let (client, request) = request.build_split();
+        self.send(&client, request?).await
+    }
+
+    /// Execute a request and return the raw response
+    ///
+    /// Used for endpoints whose body is not wrapped in `data` (or is empty).
+    /// The caller is responsible for checking the status.
+    #[cfg(not(feature = "tracing"))]
+    pub(crate) async fn execute_raw(&self, request: RequestBuilder) -> CircleResult<Response> {
+        let (client, request) = request.build_split();
+        let request = request?;
+        self.throttle(&request).await;
+        self.dispatch(&client, request).await
+    }
+
+    /// Execute a request inside a tracing span and return the raw response
+    #[cfg(feature = "tracing")]
+    pub(crate) async fn execute_raw(&self, request: RequestBuilder) -> CircleResult<Response> {
+        use tracing::Instrument;
+
+        let (client, request) = request.build_split();
+        let request = request?;
+        self.throttle(&request).await;
+        let span = crate::telemetry::request_span(&request);
+        async {
+            let started = Instant::now();
+            match self.dispatch(&client, request).await {
+                Ok(response) => {
+                    crate::telemetry::record_response(&response, started);
+                    Ok(response)
+                }
+                Err(error) => {
+                    crate::telemetry::record_transport_failure(started, &error);
+                    Err(error)
+                }
+            }
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// Send a built request and decode the response
+    #[cfg(not(feature = "tracing"))]
+    async fn send<T>(&self, client: &Client, request: Request) -> CircleResult<WithMeta<T>>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        self.throttle(&request).await;
+        let method = request.method().clone();
+        let response = self.dispatch(client, request).await?;
+        self.handle_response(&method, response).await
+    }
+
+    /// Send a built request and decode the response inside a tracing span
+    #[cfg(feature = "tracing")]
+    async fn send<T>(&self, client: &Client, request: Request) -> CircleResult<WithMeta<T>>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        use tracing::Instrument;
+
+        self.throttle(&request).await;
+        let span = crate::telemetry::request_span(&request);
+        async {
+            let method = request.method().clone();
+            let started = Instant::now();
+            let result = match self.dispatch(client, request).await {
+                Ok(response) => {
+                    crate::telemetry::record_response(&response, started);
+                    self.handle_response(&method, response).await
+                }
+                Err(error) => {
+                    crate::telemetry::record_transport_failure(started, &error);
+                    return Err(error);
+                }
+            };
+            if let Err(error) = &result {
+                crate::telemetry::record_failure(error);
+            }
+            result
+        }
+        .instrument(span)
+        .await
     }
 
     /// Handle HTTP response and convert to typed result
-    async fn handle_response<T>(&self, response: Response) -> CircleResult<T>
+    async fn handle_response<T>(
+        &self,
+        method: &Method,
+        response: Response,
+    ) -> CircleResult<WithMeta<T>>
     where
         T: for<'de> Deserialize<'de>,
     {
         let status = response.status();
+        let path = response.url().path().to_string();
+        let request_id = response_request_id(&response);
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| parse_retry_after(value, Utc::now()));
         let response_text = response.text().await?;
 
+        if status == reqwest::StatusCode::SERVICE_UNAVAILABLE {
+            return Err(CircleError::Maintenance { retry_after });
+        }
+
         if status.is_success() {
-            let circle_response: CircleResponse<T> = serde_json::from_str(&response_text)?;
-            Ok(circle_response.data)
+            Ok(WithMeta {
+                data: self.decode_data(method, &path, &response_text)?,
+                meta: ResponseMeta {
+                    status: status.as_u16(),
+                    request_id,
+                },
+            })
         } else {
             // Try to parse error response
             let error_message = match serde_json::from_str::<CircleErrorResponse>(&response_text) {
@@ -224,10 +604,240 @@ impl HttpClient {
 
             Err(CircleError::Api {
                 status: status.as_u16(),
+                request_id,
                 message: error_message,
             })
         }
     }
+
+    /// Deserialize the `data` field of a successful response
+    ///
+    /// With schema validation enabled, the data is first checked against the
+    /// vendored schema for the route.
+    fn decode_data<T>(&self, method: &Method, path: &str, response_text: &str) -> CircleResult<T>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        #[cfg(feature = "schema-validation")]
+        if self.validate_schemas {
+            let circle_response: CircleResponse<serde_json::Value> =
+                serde_json::from_str(response_text)?;
+            crate::schema::ResponseSchemas::vendored().validate(
+                method,
+                path,
+                &circle_response.data,
+            )?;
+            return Ok(serde_json::from_value(circle_response.data)?);
+        }
+        #[cfg(not(feature = "schema-validation"))]
+        let _ = (method, path);
+
+        let circle_response: CircleResponse<T> = serde_json::from_str(response_text)?;
+        Ok(circle_response.data)
+    }
+}
+
+/// Parse a `Retry-After` header value
+///
+/// Accepts both forms allowed by RFC 9110: a number of seconds or an HTTP
+/// date. Dates in the past yield a zero delay.
+pub(crate) fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (date.with_timezone(&Utc) - now)
+            .to_std()
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+/// Monotonic clock used for deadlines and latencies
+///
+/// Tokio's clock natively (so paused-time tests can drive it) and the
+/// browser's `performance.now()` on `wasm32`, where `std::time::Instant`
+/// panics.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) type Instant = tokio::time::Instant;
+#[cfg(target_arch = "wasm32")]
+pub(crate) type Instant = web_time::Instant;
+
+/// Wait for `duration` without blocking the executor
+///
+/// Uses Tokio's timer natively and a browser timer on `wasm32`, where there
+/// is no Tokio runtime to drive one.
+pub(crate) async fn sleep(duration: Duration) {
+    #[cfg(not(target_arch = "wasm32"))]
+    tokio::time::sleep(duration).await;
+    #[cfg(target_arch = "wasm32")]
+    gloo_timers::future::sleep(duration).await;
+}
+
+/// Resources that can be located by ID in a list response
+pub trait ResourceId {
+    /// The Circle identifier of the resource
+    fn resource_id(&self) -> &str;
+}
+
+/// Wait until a freshly created resource shows up in a list endpoint
+///
+/// Circle's list endpoints are backed by an index that can lag a few seconds
+/// behind writes, so listing straight after a create may miss the new
+/// resource. This polls `lister` with a short backoff (250ms doubling up to
+/// 2s) until an item with `resource_id` is returned or `timeout` elapses.
+///
+/// # Arguments
+///
+/// * `resource_id` - ID returned by the create call
+/// * `lister` - Closure performing the list request, called once per attempt
+/// * `timeout` - Total time to wait
+///
+/// # Returns
+///
+/// The matching resource as returned by the list endpoint.
+///
+/// # Errors
+///
+/// Returns the lister's error if a list request fails, or
+/// `CircleError::Config` if the resource is still missing after `timeout`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use inf_circle_sdk::circle_view::circle_view::CircleView;
+/// use inf_circle_sdk::dev_wallet::views::list_wallets::ListDevWalletsParamsBuilder;
+/// use inf_circle_sdk::helper::await_visible;
+///
+/// # async fn example(view: CircleView, new_wallet_id: String) -> Result<(), Box<dyn std::error::Error>> {
+/// let wallet = await_visible(
+///     &new_wallet_id,
+///     || async {
+///         let params = ListDevWalletsParamsBuilder::new()
+///             .wallet_set_id("wallet-set-id".to_string())
+///             .build();
+///         Ok(view.list_wallets(params).await?.items)
+///     },
+///     Duration::from_secs(30),
+/// )
+/// .await?;
+/// println!("Wallet {} is now listed", wallet.address);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn await_visible<T, F, Fut>(
+    resource_id: &str,
+    lister: F,
+    timeout: Duration,
+) -> CircleResult<T>
+where
+    T: ResourceId,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = CircleResult<Vec<T>>>,
+{
+    await_visible_where(
+        resource_id,
+        |resource: &T| resource.resource_id() == resource_id,
+        lister,
+        timeout,
+    )
+    .await
+}
+
+/// Wait until a resource matching `matches` shows up in a list endpoint
+///
+/// Like [`await_visible`], for resources whose ID is not known yet, such as
+/// one created by a concurrent caller and identified by its ref ID.
+/// `description` names the resource in the timeout error.
+///
+/// # Errors
+///
+/// Returns the lister's error if a list request fails, or
+/// `CircleError::Config` if no matching resource is listed after `timeout`.
+pub async fn await_visible_where<T, P, F, Fut>(
+    description: &str,
+    mut matches: P,
+    mut lister: F,
+    timeout: Duration,
+) -> CircleResult<T>
+where
+    P: FnMut(&T) -> bool,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = CircleResult<Vec<T>>>,
+{
+    let deadline = Instant::now() + timeout;
+    let mut delay = Duration::from_millis(250);
+
+    loop {
+        if let Some(resource) = lister().await?.into_iter().find(&mut matches) {
+            return Ok(resource);
+        }
+
+        let now = Instant::now();
+        if now >= deadline {
+            return Err(CircleError::Config(format!(
+                "Resource {} was not visible after {:?}",
+                description, timeout
+            )));
+        }
+        sleep(delay.min(deadline - now)).await;
+        delay = (delay * 2).min(Duration::from_secs(2));
+    }
+}
+
+/// Bound a single call to `timeout`
+///
+/// Unlike a client's `with_request_timeout`, which limits each HTTP
+/// exchange, the timeout covers the whole call: rate limiter waits,
+/// maintenance retries and polling included. If `future` has not finished
+/// when the timeout expires it is dropped and `CircleError::Timeout` is
+/// returned.
+///
+/// # Cancellation
+///
+/// Every SDK call can be dropped at any await point (by a timeout here, a
+/// `select!`, or an aborted task) without corrupting client state. Dropping
+/// a write does not recall it, though: the request may already have reached
+/// Circle and may still execute. Resubmit it with the same idempotency key,
+/// which Circle executes at most once. With an
+/// [idempotency store](crate::circle_ops::circler_ops::CircleOps::with_idempotency_store)
+/// attached, a dropped write stays pending in the store and
+/// [`resume_pending`](crate::circle_ops::circler_ops::CircleOps::resume_pending)
+/// resubmits it.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+/// use inf_circle_sdk::dev_wallet::ops::create_transfer_transaction::CreateTransferTransactionRequestBuilder;
+/// use inf_circle_sdk::helper::with_timeout;
+///
+/// # async fn example(transfer: CreateTransferTransactionRequestBuilder) -> Result<(), Box<dyn std::error::Error>> {
+/// let ops = CircleOps::new(None)?;
+///
+/// let response = with_timeout(
+///     Duration::from_secs(10),
+///     ops.create_dev_transfer_transaction(transfer),
+/// )
+/// .await?;
+/// println!("Transaction ID: {}", response.id);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn with_timeout<T, F>(timeout: Duration, future: F) -> CircleResult<T>
+where
+    F: Future<Output = CircleResult<T>>,
+{
+    let future = pin!(future);
+    let expired = pin!(sleep(timeout));
+    match select(future, expired).await {
+        Either::Left((result, _)) => result,
+        Either::Right(((), _)) => Err(CircleError::Timeout(timeout)),
+    }
 }
 
 /// Helper function to read environment variable
@@ -331,6 +941,411 @@ pub fn generate_uuid() -> String {
     uuid::Uuid::new_v4().to_string()
 }
 
+/// Derive a UUID v4-formatted idempotency key from `seed`
+///
+/// The same seed always gives the same key, so repeated attempts at one
+/// logical operation (even from different processes) are deduplicated by
+/// Circle's idempotency handling.
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::helper::deterministic_uuid;
+///
+/// let key = deterministic_uuid("wallet-set-1:ETH:treasury");
+/// assert_eq!(key, deterministic_uuid("wallet-set-1:ETH:treasury"));
+/// assert_ne!(key, deterministic_uuid("wallet-set-1:SOL:treasury"));
+/// assert_eq!(&key[14..15], "4");
+/// ```
+pub fn deterministic_uuid(seed: &str) -> String {
+    use sha2::Digest;
+
+    let digest = Sha256::digest(seed.as_bytes());
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&digest[..16]);
+    uuid::Builder::from_random_bytes(bytes)
+        .into_uuid()
+        .to_string()
+}
+
+/// Normalize an event signature to its canonical form
+///
+/// Strips whitespace, parameter names and `indexed` keywords so that
+/// `"Transfer(address indexed from, address indexed to, uint256 value)"`
+/// becomes `"Transfer(address,address,uint256)"`. Tuple parameters are kept
+/// intact (e.g., `"(uint256,address)[]"`).
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::helper::canonical_event_signature;
+///
+/// assert_eq!(
+///     canonical_event_signature("Approval(address indexed owner, address indexed spender, uint256 value)"),
+///     "Approval(address,address,uint256)"
+/// );
+/// ```
+pub fn canonical_event_signature(signature: &str) -> String {
+    let signature = signature.trim();
+    let Some((name, rest)) = signature.split_once('(') else {
+        return signature.to_string();
+    };
+    let params = rest.trim_end().strip_suffix(')').unwrap_or(rest);
+
+    format!("{}({})", name.trim(), canonical_param_list(params))
+}
+
+/// Canonicalize a comma-separated parameter list, splitting only at the top level
+fn canonical_param_list(params: &str) -> String {
+    let mut types = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in params.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                types.push(canonical_param_type(&params[start..i]));
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    types.push(canonical_param_type(&params[start..]));
+
+    types.retain(|ty| !ty.is_empty());
+    types.join(",")
+}
+
+/// Extract the type of a single parameter, dropping `indexed` and the parameter name
+fn canonical_param_type(param: &str) -> String {
+    let param = param.trim();
+    let mut depth = 0usize;
+    let mut end = param.len();
+    let mut tuple_close = None;
+    for (i, c) in param.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 && tuple_close.is_none() {
+                    tuple_close = Some(i);
+                }
+            }
+            c if c.is_whitespace() && depth == 0 => {
+                end = i;
+                break;
+            }
+            _ => {}
+        }
+    }
+
+    let ty = &param[..end];
+    match (ty.starts_with('('), tuple_close) {
+        (true, Some(close)) => format!(
+            "({}){}",
+            canonical_param_list(&ty[1..close]),
+            &ty[close + 1..]
+        ),
+        _ => ty.to_string(),
+    }
+}
+
+/// Compute the event topic (`topic0`) for an event signature
+///
+/// The signature is normalized with [`canonical_event_signature`] first, so
+/// parameter names, `indexed` keywords and spaces are ignored.
+///
+/// # Returns
+///
+/// Returns the `0x`-prefixed lowercase hex Keccak-256 hash.
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::helper::event_signature_hash;
+///
+/// assert_eq!(
+///     event_signature_hash("Transfer(address,address,uint256)"),
+///     "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+/// );
+/// ```
+pub fn event_signature_hash(signature: &str) -> String {
+    let canonical = canonical_event_signature(signature);
+    format!("0x{}", hex::encode(keccak256(canonical.as_bytes())))
+}
+
+/// Verify an EIP-191 `personal_sign` signature against an EVM address
+///
+/// This is the scheme `dev_sign_message` uses for EVM wallets: the message is
+/// prefixed with `"\x19Ethereum Signed Message:\n" + len`, hashed with
+/// Keccak-256, and the signer address is recovered from the signature.
+///
+/// # Arguments
+/// * `message` - The signed message bytes (hex-decoded if it was sent with `encoded_by_hex`)
+/// * `signature` - 65-byte `r || s || v` signature, hex encoded (`v` may be 0/1 or 27/28)
+/// * `address` - Expected signer address (any letter case)
+///
+/// # Returns
+/// * `Ok(true)` if the signature was produced by `address`, `Ok(false)` otherwise
+///
+/// # Errors
+///
+/// Returns [`CircleError::Config`] if the signature or address is malformed.
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::helper::verify_evm_personal_sign;
+///
+/// let valid = verify_evm_personal_sign(
+///     "Some data",
+///     concat!(
+///         "0xb91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd",
+///         "6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a0291c",
+///     ),
+///     "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23",
+/// ).unwrap();
+/// assert!(valid);
+/// ```
+pub fn verify_evm_personal_sign(
+    message: impl AsRef<[u8]>,
+    signature: &str,
+    address: &str,
+) -> CircleResult<bool> {
+    use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+
+    let expected: crate::abi::Address = address.trim().parse()?;
+    let bytes = decode_signature_hex(signature, 65)
+        .ok_or_else(|| CircleError::Config("EVM signature must be 65 hex bytes".to_string()))?;
+
+    let recovery_id = match bytes[64] {
+        v @ (0 | 1) => v,
+        v @ (27 | 28) => v - 27,
+        v => {
+            return Err(CircleError::Config(format!(
+                "Invalid EVM signature recovery value: {}",
+                v
+            )))
+        }
+    };
+    let recovery_id = RecoveryId::from_byte(recovery_id).expect("recovery id is 0 or 1");
+    let signature = Signature::from_slice(&bytes[..64])
+        .map_err(|e| CircleError::Config(format!("Invalid EVM signature: {}", e)))?;
+    // k256 only recovers from low-S signatures, while ecrecover also accepts
+    // the high-S twin, which recovers the same key with the other parity
+    let (signature, recovery_id) = match signature.normalize_s() {
+        Some(low) => (
+            low,
+            RecoveryId::new(!recovery_id.is_y_odd(), recovery_id.is_x_reduced()),
+        ),
+        None => (signature, recovery_id),
+    };
+
+    let message = message.as_ref();
+    let mut prefixed = format!("\x19Ethereum Signed Message:\n{}", message.len()).into_bytes();
+    prefixed.extend_from_slice(message);
+    let digest = keccak256(&prefixed);
+
+    let Ok(public_key) = VerifyingKey::recover_from_prehash(&digest, &signature, recovery_id)
+    else {
+        return Ok(false);
+    };
+    let hash = keccak256(&public_key.to_encoded_point(false).as_bytes()[1..]);
+    Ok(hash[12..] == expected.as_bytes()[..])
+}
+
+/// Verify a NEAR ed25519 message signature
+///
+/// # Arguments
+/// * `message` - The signed message bytes
+/// * `signature` - 64-byte signature as hex or base58 (an `ed25519:` prefix is accepted)
+/// * `public_key` - Signer public key as base58, with or without the `ed25519:` prefix
+///
+/// # Returns
+/// * `Ok(true)` if the signature is valid for `public_key`, `Ok(false)` otherwise
+///
+/// # Errors
+///
+/// Returns [`CircleError::Config`] if the signature or public key is malformed.
+pub fn verify_near_signature(
+    message: impl AsRef<[u8]>,
+    signature: &str,
+    public_key: &str,
+) -> CircleResult<bool> {
+    verify_ed25519("NEAR", message.as_ref(), signature, public_key)
+}
+
+/// Verify a Solana ed25519 message signature
+///
+/// # Arguments
+/// * `message` - The signed message bytes
+/// * `signature` - 64-byte signature as base58 or hex
+/// * `public_key` - Signer address (base58 public key)
+///
+/// # Returns
+/// * `Ok(true)` if the signature is valid for `public_key`, `Ok(false)` otherwise
+///
+/// # Errors
+///
+/// Returns [`CircleError::Config`] if the signature or public key is malformed.
+pub fn verify_solana_signature(
+    message: impl AsRef<[u8]>,
+    signature: &str,
+    public_key: &str,
+) -> CircleResult<bool> {
+    verify_ed25519("Solana", message.as_ref(), signature, public_key)
+}
+
+/// Decode a hex signature of exactly `len` bytes, with or without `0x`
+fn decode_signature_hex(signature: &str, len: usize) -> Option<Vec<u8>> {
+    let signature = signature.trim();
+    let digits = signature.strip_prefix("0x").unwrap_or(signature);
+    hex::decode(digits).ok().filter(|bytes| bytes.len() == len)
+}
+
+/// Decode a 32- or 64-byte ed25519 value given as base58 or hex
+fn decode_ed25519_value<const N: usize>(value: &str) -> Option<[u8; N]> {
+    let value = value.trim();
+    let value = value.strip_prefix("ed25519:").unwrap_or(value);
+    let bytes = decode_signature_hex(value, N).or_else(|| bs58::decode(value).into_vec().ok())?;
+    bytes.try_into().ok()
+}
+
+fn verify_ed25519(
+    chain: &str,
+    message: &[u8],
+    signature: &str,
+    public_key: &str,
+) -> CircleResult<bool> {
+    use ed25519_dalek::{Signature, VerifyingKey};
+
+    let key_bytes = decode_ed25519_value::<32>(public_key).ok_or_else(|| {
+        CircleError::Config(format!("Invalid {} public key: {}", chain, public_key))
+    })?;
+    let signature_bytes = decode_ed25519_value::<64>(signature).ok_or_else(|| {
+        CircleError::Config(format!(
+            "{} signature must be 64 bytes of hex or base58",
+            chain
+        ))
+    })?;
+
+    let key = VerifyingKey::from_bytes(&key_bytes)
+        .map_err(|e| CircleError::Config(format!("Invalid {} public key: {}", chain, e)))?;
+    Ok(key
+        .verify_strict(message, &Signature::from_bytes(&signature_bytes))
+        .is_ok())
+}
+
+/// A string wiped from memory on drop and hidden from `Debug` output
+///
+/// Request DTOs hold their entity secret ciphertext in this type, so logging
+/// a request with `{:?}` does not leak it. Serializes as the plain string.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct SecretString(Zeroizing<String>);
+
+impl SecretString {
+    /// Wrap a secret value
+    pub fn new(secret: String) -> Self {
+        Self(Zeroizing::new(secret))
+    }
+
+    /// The secret value
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(secret: String) -> Self {
+        Self::new(secret)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(secret: &str) -> Self {
+        Self::new(secret.to_string())
+    }
+}
+
+impl std::fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+impl Serialize for SecretString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.expose_secret())
+    }
+}
+
+impl<'de> Deserialize<'de> for SecretString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self::new)
+    }
+}
+
+/// Placeholder shown in request previews instead of the entity secret ciphertext
+pub const ENTITY_SECRET_PLACEHOLDER: &str = "<entity-secret-ciphertext>";
+
+/// Placeholder shown in request previews for idempotency keys generated when the request is sent
+pub const GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER: &str = "<generated-at-request-time>";
+
+/// Serialize a request body as pretty JSON for builder `preview()` methods
+pub(crate) fn preview_request<T: Serialize>(request: &T) -> CircleResult<String> {
+    Ok(serde_json::to_string_pretty(request)?)
+}
+
+/// Compare two non-negative decimal strings (e.g., "0.001" and "0.0001")
+///
+/// The comparison is exact and works for any number of decimal places, so it
+/// is safe for 18-decimal token amounts that do not fit in an `f64`.
+///
+/// # Returns
+/// * `Option<Ordering>` - `None` if either input is not a valid decimal string
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::helper::compare_decimal_str;
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(compare_decimal_str("1.50", "1.5"), Some(Ordering::Equal));
+/// assert_eq!(compare_decimal_str("0.0001", "0.001"), Some(Ordering::Less));
+/// assert_eq!(compare_decimal_str("abc", "1"), None);
+/// ```
+pub fn compare_decimal_str(a: &str, b: &str) -> Option<Ordering> {
+    fn split(s: &str) -> Option<(&str, &str)> {
+        let (whole, fraction) = s.split_once('.').unwrap_or((s, ""));
+        if whole.is_empty() && fraction.is_empty() {
+            return None;
+        }
+        if !whole
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+        {
+            return None;
+        }
+        Some((
+            whole.trim_start_matches('0'),
+            fraction.trim_end_matches('0'),
+        ))
+    }
+
+    let (a_whole, a_fraction) = split(a.trim())?;
+    let (b_whole, b_fraction) = split(b.trim())?;
+
+    Some(
+        a_whole
+            .len()
+            .cmp(&b_whole.len())
+            .then_with(|| a_whole.cmp(b_whole))
+            .then_with(|| a_fraction.cmp(b_fraction)),
+    )
+}
+
 /// Encrypts entity secret using RSA-OAEP with SHA-256
 ///
 /// This function takes a hex-encoded entity secret and encrypts it using the provided
@@ -395,6 +1410,166 @@ pub fn encrypt_entity_secret(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::TimeZone;
+
+    struct Item(&'static str);
+
+    impl ResourceId for Item {
+        fn resource_id(&self) -> &str {
+            self.0
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_await_visible_polls_until_listed() {
+        let mut calls = 0;
+        let found = await_visible(
+            "new",
+            || {
+                calls += 1;
+                let items = if calls < 3 {
+                    vec![Item("old")]
+                } else {
+                    vec![Item("old"), Item("new")]
+                };
+                async move { Ok(items) }
+            },
+            Duration::from_secs(10),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(found.0, "new");
+        assert_eq!(calls, 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_await_visible_times_out() {
+        let result = await_visible(
+            "missing",
+            || async { Ok(vec![Item("old")]) },
+            Duration::from_secs(3),
+        )
+        .await;
+
+        assert!(matches!(result, Err(CircleError::Config(_))));
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        let now = Utc.with_ymd_and_hms(2025, 1, 15, 12, 0, 0).unwrap();
+        assert_eq!(
+            parse_retry_after("120", now),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 15 Jan 2025 12:05:00 GMT", now),
+            Some(Duration::from_secs(300))
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 15 Jan 2025 11:00:00 GMT", now),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon", now), None);
+    }
+
+    #[tokio::test]
+    async fn test_request_id_is_captured() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/v1/w3s/ping")
+            .with_status(200)
+            .with_header("x-request-id", "req-ok")
+            .with_body(r#"{"data":{"message":"pong"}}"#)
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/v1/w3s/wallets/missing")
+            .with_status(404)
+            .with_header("x-request-id", "req-failed")
+            .with_body(r#"{"code":156001,"message":"Cannot find wallet"}"#)
+            .create_async()
+            .await;
+
+        let client = HttpClient::new(&server.url()).unwrap();
+        let ping: WithMeta<serde_json::Value> = client
+            .execute_with_meta(client.request(Method::GET, "/v1/w3s/ping").unwrap())
+            .await
+            .unwrap();
+        assert_eq!(ping.meta.status, 200);
+        assert_eq!(ping.meta.request_id.as_deref(), Some("req-ok"));
+
+        let error = client
+            .execute::<serde_json::Value>(
+                client
+                    .request(Method::GET, "/v1/w3s/wallets/missing")
+                    .unwrap(),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(error.request_id(), Some("req-failed"));
+        assert_eq!(
+            error.to_string(),
+            "API error: 404 - Cannot find wallet (request ID req-failed)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_request_timeout() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/v1/w3s/slow")
+            .with_status(200)
+            .with_body_from_request(|_| {
+                std::thread::sleep(Duration::from_millis(500));
+                br#"{"data":{}}"#.to_vec()
+            })
+            .create_async()
+            .await;
+
+        let client = HttpClient::new(&server.url())
+            .unwrap()
+            .request_timeout(Duration::from_millis(50));
+        let error = client
+            .execute::<serde_json::Value>(client.request(Method::GET, "/v1/w3s/slow").unwrap())
+            .await
+            .unwrap_err();
+        assert!(error.is_timeout(), "{}", error);
+        assert!(!CircleError::Config("x".to_string()).is_timeout());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_with_timeout() {
+        let fast = with_timeout(Duration::from_secs(1), async { Ok(7) }).await;
+        assert_eq!(fast.unwrap(), 7);
+
+        let slow = with_timeout(Duration::from_millis(50), async {
+            sleep(Duration::from_secs(1)).await;
+            Ok(())
+        })
+        .await
+        .unwrap_err();
+        assert!(slow.is_timeout());
+        assert!(
+            matches!(slow, CircleError::Timeout(timeout) if timeout == Duration::from_millis(50))
+        );
+    }
+
+    #[test]
+    fn test_maintenance_error_message() {
+        let error = CircleError::Maintenance {
+            retry_after: Some(Duration::from_secs(30)),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Circle API is unavailable for maintenance (retry after 30s)"
+        );
+        let error = CircleError::Maintenance { retry_after: None };
+        assert_eq!(
+            error.to_string(),
+            "Circle API is unavailable for maintenance"
+        );
+    }
 
     #[test]
     fn test_generate_uuid() {
@@ -403,6 +1578,41 @@ mod tests {
         assert!(uuid.contains('-'));
     }
 
+    #[test]
+    fn test_event_signature_hash_ignores_formatting() {
+        let expected = "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+        assert_eq!(
+            event_signature_hash("Transfer(address indexed,address indexed,uint256)"),
+            expected
+        );
+        assert_eq!(
+            event_signature_hash(
+                "Transfer(address indexed from, address indexed to, uint256 value)"
+            ),
+            expected
+        );
+        assert_eq!(
+            canonical_event_signature(
+                "Swap((uint256 amount, address to)[] legs, bytes32 indexed id)"
+            ),
+            "Swap((uint256,address)[],bytes32)"
+        );
+        assert_eq!(canonical_event_signature("Paused()"), "Paused()");
+    }
+
+    #[test]
+    fn test_compare_decimal_str() {
+        assert_eq!(compare_decimal_str("10", "9.999"), Some(Ordering::Greater));
+        assert_eq!(compare_decimal_str("007.10", "7.1"), Some(Ordering::Equal));
+        assert_eq!(compare_decimal_str(".5", "0.50"), Some(Ordering::Equal));
+        assert_eq!(
+            compare_decimal_str("0.000000000000000001", "0.00000000000000001"),
+            Some(Ordering::Less)
+        );
+        assert_eq!(compare_decimal_str("-1", "0"), None);
+        assert_eq!(compare_decimal_str("1e5", "0"), None);
+    }
+
     #[test]
     fn test_pagination_params_serialization() {
         let params = PaginationParams {
@@ -434,4 +1644,104 @@ mod tests {
         // The important thing is that the function exists and has the right signature
         // In real usage with valid keys, multiple calls would produce different encrypted values
     }
+
+    #[test]
+    fn test_verify_evm_personal_sign() {
+        let signature = concat!(
+            "0xb91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd",
+            "6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a0291c",
+        );
+        let signer = "0x2c7536e3605d9c16a7a3d7b1898e529396a65c23";
+
+        assert!(verify_evm_personal_sign("Some data", signature, signer).unwrap());
+        assert!(!verify_evm_personal_sign("Other data", signature, signer).unwrap());
+        assert!(!verify_evm_personal_sign(
+            "Some data",
+            signature,
+            "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb0"
+        )
+        .unwrap());
+
+        // Recovery value 0/1 is accepted as well as 27/28
+        let normalized = format!("{}01", &signature[..signature.len() - 2]);
+        assert!(verify_evm_personal_sign("Some data", &normalized, signer).unwrap());
+
+        assert!(verify_evm_personal_sign("Some data", "0x1234", signer).is_err());
+        assert!(verify_evm_personal_sign("Some data", signature, "0x1234").is_err());
+    }
+
+    #[test]
+    fn test_verify_evm_personal_sign_high_s() {
+        use k256::ecdsa::Signature;
+
+        let signature = hex::decode(concat!(
+            "b91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd",
+            "6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a029",
+        ))
+        .unwrap();
+        let (r, s) = Signature::from_slice(&signature).unwrap().split_scalars();
+        let high = Signature::from_scalars(r.to_bytes(), (-*s).to_bytes()).unwrap();
+        assert!(high.normalize_s().is_some());
+
+        // ecrecover accepts the high-S twin with the other recovery value
+        let twin = format!("0x{}1b", hex::encode(high.to_bytes()));
+        let signer = "0x2c7536e3605d9c16a7a3d7b1898e529396a65c23";
+        assert!(verify_evm_personal_sign("Some data", &twin, signer).unwrap());
+    }
+
+    #[test]
+    fn test_verify_evm_personal_sign_round_trip() {
+        use k256::ecdsa::SigningKey;
+
+        for (seed, message) in [(1u8, &b""[..]), (2, b"hello"), (3, &[0xff; 300][..])] {
+            let key = SigningKey::from_slice(&[seed; 32]).unwrap();
+            let public_key = key.verifying_key().to_encoded_point(false);
+            let address = format!(
+                "0x{}",
+                hex::encode(&keccak256(&public_key.as_bytes()[1..])[12..])
+            );
+
+            let mut prefixed =
+                format!("\x19Ethereum Signed Message:\n{}", message.len()).into_bytes();
+            prefixed.extend_from_slice(message);
+            let (signature, recovery_id) =
+                key.sign_prehash_recoverable(&keccak256(&prefixed)).unwrap();
+            let signature = format!(
+                "0x{}{:02x}",
+                hex::encode(signature.to_bytes()),
+                27 + recovery_id.to_byte()
+            );
+
+            assert!(verify_evm_personal_sign(message, &signature, &address).unwrap());
+            assert!(!verify_evm_personal_sign(b"tampered", &signature, &address).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_verify_ed25519_signatures() {
+        use ed25519_dalek::{Signer, SigningKey};
+
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        let public_key = bs58::encode(key.verifying_key().as_bytes()).into_string();
+        let signature = key.sign(b"hello circle").to_bytes();
+
+        let hex_signature = format!("0x{}", hex::encode(signature));
+        let base58_signature = bs58::encode(signature).into_string();
+
+        assert!(verify_solana_signature("hello circle", &base58_signature, &public_key).unwrap());
+        assert!(verify_solana_signature("hello circle", &hex_signature, &public_key).unwrap());
+        assert!(!verify_solana_signature("goodbye", &base58_signature, &public_key).unwrap());
+
+        let near_key = format!("ed25519:{}", public_key);
+        assert!(verify_near_signature(b"hello circle", &hex_signature, &near_key).unwrap());
+        assert!(verify_near_signature(
+            b"hello circle",
+            &format!("ed25519:{}", base58_signature),
+            &public_key
+        )
+        .unwrap());
+
+        assert!(verify_near_signature("hello circle", "abcd", &near_key).is_err());
+        assert!(verify_near_signature("hello circle", &hex_signature, "ed25519:abc").is_err());
+    }
 }
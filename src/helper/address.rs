@@ -0,0 +1,272 @@
+//! Offline address validation
+//!
+//! Pure-Rust format checks for the address families Circle supports, so a
+//! malformed destination can be caught without an API round trip:
+//!
+//! - EVM: `0x` + 40 hex digits, with the EIP-55 checksum enforced for mixed-case input
+//! - Solana: base58 encoded 32-byte public key
+//! - NEAR: named, implicit and ETH-implicit account IDs
+//! - Aptos: `0x` + up to 64 hex digits
+//!
+//! These checks only cover the format. An address can be well formed and still
+//! not exist, or belong to a different network of the same family; use
+//! [`CircleView::validate_address`](crate::circle_view::circle_view::CircleView::validate_address)
+//! when that matters.
+//!
+//! # Example
+//!
+//! ```rust
+//! use inf_circle_sdk::helper::address::{to_checksum_address, validate_address};
+//! use inf_circle_sdk::types::Blockchain;
+//!
+//! let address = to_checksum_address("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").unwrap();
+//! assert_eq!(address, "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+//!
+//! assert!(validate_address(&Blockchain::BaseSepolia, &address).is_ok());
+//! assert!(validate_address(&Blockchain::NearTestnet, "alice.testnet").is_ok());
+//! assert!(validate_address(&Blockchain::Sol, &address).is_err());
+//! ```
+
+use crate::abi::keccak256;
+use crate::helper::{CircleError, CircleResult};
+use crate::types::Blockchain;
+
+/// Address format used by a family of blockchains
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AddressFormat {
+    /// 20-byte hex address with optional EIP-55 checksum
+    Evm,
+    /// Base58 encoded ed25519 public key
+    Solana,
+    /// NEAR account ID
+    Near,
+    /// Hex account address of up to 32 bytes
+    Aptos,
+}
+
+impl AddressFormat {
+    /// The address format of `blockchain`, or `None` for unknown blockchains
+    pub fn for_blockchain(blockchain: &Blockchain) -> Option<Self> {
+        match blockchain {
+            Blockchain::Sol | Blockchain::SolDevnet => Some(AddressFormat::Solana),
+            Blockchain::Near | Blockchain::NearTestnet => Some(AddressFormat::Near),
+            Blockchain::Aptos | Blockchain::AptosTestnet => Some(AddressFormat::Aptos),
+            Blockchain::Other(_) => None,
+            _ => Some(AddressFormat::Evm),
+        }
+    }
+
+    /// Check that `address` is well formed for this format
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Config` describing why the address is malformed.
+    pub fn validate(&self, address: &str) -> CircleResult<()> {
+        match self {
+            AddressFormat::Evm => validate_evm_address(address),
+            AddressFormat::Solana => validate_solana_address(address),
+            AddressFormat::Near => validate_near_account_id(address),
+            AddressFormat::Aptos => validate_aptos_address(address),
+        }
+    }
+}
+
+/// Check that `address` is well formed for `blockchain`
+///
+/// Addresses on blockchains this SDK version does not know are accepted.
+///
+/// # Errors
+///
+/// Returns `CircleError::Config` describing why the address is malformed.
+pub fn validate_address(blockchain: &Blockchain, address: &str) -> CircleResult<()> {
+    match AddressFormat::for_blockchain(blockchain) {
+        Some(format) => format.validate(address),
+        None => Ok(()),
+    }
+}
+
+/// Check an EVM address
+///
+/// All-lowercase and all-uppercase addresses are accepted as unchecksummed;
+/// mixed-case addresses must match their EIP-55 checksum.
+pub fn validate_evm_address(address: &str) -> CircleResult<()> {
+    let digits = evm_digits(address)?;
+    let has_lower = digits.chars().any(|c| c.is_ascii_lowercase());
+    let has_upper = digits.chars().any(|c| c.is_ascii_uppercase());
+    if has_lower && has_upper && checksum_digits(digits) != digits {
+        return Err(invalid(address, "EVM", "checksum mismatch"));
+    }
+    Ok(())
+}
+
+/// Convert an EVM address to its EIP-55 checksummed form
+///
+/// The input's case is ignored, so a wrong checksum is corrected rather than
+/// rejected; use [`validate_evm_address`] to enforce it.
+pub fn to_checksum_address(address: &str) -> CircleResult<String> {
+    let digits = evm_digits(address)?;
+    Ok(format!("0x{}", checksum_digits(digits)))
+}
+
+/// Check a Solana address (base58 encoded 32-byte public key)
+pub fn validate_solana_address(address: &str) -> CircleResult<()> {
+    match bs58::decode(address).into_vec() {
+        Ok(bytes) if bytes.len() == 32 => Ok(()),
+        Ok(bytes) => Err(invalid(
+            address,
+            "Solana",
+            &format!("expected 32 bytes, got {}", bytes.len()),
+        )),
+        Err(_) => Err(invalid(address, "Solana", "not base58")),
+    }
+}
+
+/// Check a NEAR account ID
+///
+/// Account IDs are 2 to 64 characters of lowercase letters, digits and the
+/// separators `-`, `_` and `.`; separators cannot start or end the ID or
+/// follow each other. Implicit (64 hex digit) and ETH-implicit (`0x` + 40 hex
+/// digit) account IDs satisfy the same rules.
+pub fn validate_near_account_id(account_id: &str) -> CircleResult<()> {
+    if !(2..=64).contains(&account_id.len()) {
+        return Err(invalid(
+            account_id,
+            "NEAR",
+            "must be 2 to 64 characters long",
+        ));
+    }
+
+    let mut previous_separator = true;
+    for c in account_id.chars() {
+        let separator = matches!(c, '-' | '_' | '.');
+        if !separator && !c.is_ascii_lowercase() && !c.is_ascii_digit() {
+            return Err(invalid(
+                account_id,
+                "NEAR",
+                &format!("invalid character '{}'", c),
+            ));
+        }
+        if separator && previous_separator {
+            return Err(invalid(account_id, "NEAR", "misplaced separator"));
+        }
+        previous_separator = separator;
+    }
+    if previous_separator {
+        return Err(invalid(account_id, "NEAR", "misplaced separator"));
+    }
+    Ok(())
+}
+
+/// Check an Aptos address (`0x` + 1 to 64 hex digits)
+pub fn validate_aptos_address(address: &str) -> CircleResult<()> {
+    let digits = address
+        .strip_prefix("0x")
+        .ok_or_else(|| invalid(address, "Aptos", "missing 0x prefix"))?;
+    if digits.is_empty() || digits.len() > 64 {
+        return Err(invalid(address, "Aptos", "expected 1 to 64 hex digits"));
+    }
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(invalid(address, "Aptos", "not hex"));
+    }
+    Ok(())
+}
+
+fn evm_digits(address: &str) -> CircleResult<&str> {
+    let digits = address
+        .strip_prefix("0x")
+        .ok_or_else(|| invalid(address, "EVM", "missing 0x prefix"))?;
+    if digits.len() != 40 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(invalid(address, "EVM", "expected 40 hex digits"));
+    }
+    Ok(digits)
+}
+
+/// Apply the EIP-55 checksum to 40 hex digits
+fn checksum_digits(digits: &str) -> String {
+    let lower = digits.to_ascii_lowercase();
+    let hash = keccak256(lower.as_bytes());
+    lower
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = (hash[i / 2] >> (if i % 2 == 0 { 4 } else { 0 })) & 0x0f;
+            if c.is_ascii_alphabetic() && nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+fn invalid(address: &str, family: &str, reason: &str) -> CircleError {
+    CircleError::Config(format!(
+        "Invalid {} address {}: {}",
+        family, address, reason
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eip55_checksum() {
+        for address in [
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+            "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+        ] {
+            assert_eq!(
+                to_checksum_address(&address.to_lowercase()).unwrap(),
+                address
+            );
+            assert!(validate_evm_address(address).is_ok());
+        }
+
+        assert!(validate_evm_address("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").is_ok());
+        assert!(validate_evm_address("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD").is_err());
+        assert!(validate_evm_address("5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").is_err());
+        assert!(validate_evm_address("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAe").is_err());
+    }
+
+    #[test]
+    fn test_solana_address() {
+        assert!(validate_solana_address("11111111111111111111111111111111").is_ok());
+        assert!(validate_solana_address(&bs58::encode([7u8; 32]).into_string()).is_ok());
+        assert!(validate_solana_address(&bs58::encode([7u8; 20]).into_string()).is_err());
+        assert!(validate_solana_address("0OIl").is_err());
+    }
+
+    #[test]
+    fn test_near_account_id() {
+        for account in [
+            "alice.near",
+            "app-1.alice.testnet",
+            "a_b",
+            "98793cd91a3f870fb126f66285808c7e094afcfc4eda8a970f6648cdf0dbd6de",
+            "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed",
+        ] {
+            assert!(validate_near_account_id(account).is_ok(), "{}", account);
+        }
+        for account in [
+            "a",
+            "Alice.near",
+            "alice..near",
+            ".alice",
+            "alice-",
+            "al ice",
+        ] {
+            assert!(validate_near_account_id(account).is_err(), "{}", account);
+        }
+    }
+
+    #[test]
+    fn test_validate_address_by_blockchain() {
+        assert!(validate_address(&Blockchain::AptosTestnet, "0x1").is_ok());
+        assert!(validate_address(&Blockchain::AptosTestnet, "0xzz").is_err());
+        assert!(validate_address(&Blockchain::Near, "0xAB").is_err());
+        assert!(validate_address(&Blockchain::Other("HYPE".to_string()), "anything").is_ok());
+    }
+}
@@ -0,0 +1,214 @@
+//! Circle API environments
+//!
+//! [`Environment`] selects the API host a client talks to. The sandbox and
+//! production presets carry Circle's base URLs; [`Environment::Custom`] covers
+//! proxies and mock servers.
+//!
+//! Clients pointed at the sandbox refuse request bodies that name a mainnet
+//! blockchain, so a test configuration cannot quietly create mainnet wallets
+//! or transfers. Call `allow_mainnet_on_sandbox` on the client to opt out.
+//!
+//! # Example
+//!
+//! ```rust
+//! use inf_circle_sdk::circle_view::circle_view::CircleView;
+//! use inf_circle_sdk::helper::environment::Environment;
+//!
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let environment: Environment = "sandbox".parse()?;
+//! assert_eq!(environment.base_url().as_str(), "https://api-sandbox.circle.com/");
+//!
+//! let view = CircleView::with_environment(environment, "TEST_API_KEY:abc:def".to_string())?;
+//! assert!(view.environment().is_sandbox());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde_json::Value;
+use url::Url;
+
+use crate::helper::{get_env_var, CircleError, CircleResult};
+use crate::types::Blockchain;
+
+/// Base URL of Circle's sandbox API
+pub const SANDBOX_BASE_URL: &str = "https://api-sandbox.circle.com";
+
+/// Base URL of Circle's production API
+pub const PRODUCTION_BASE_URL: &str = "https://api.circle.com";
+
+/// The Circle API environment a client talks to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Environment {
+    /// Circle's sandbox; mainnet blockchains are refused by default
+    Sandbox,
+    /// Circle's production API
+    Production,
+    /// Any other base URL (proxies, mock servers)
+    Custom(Url),
+}
+
+impl Environment {
+    /// Read the environment from `CIRCLE_ENVIRONMENT` or `CIRCLE_BASE_URL`
+    ///
+    /// `CIRCLE_ENVIRONMENT` takes precedence and accepts `sandbox`,
+    /// `production` or a URL. Otherwise `CIRCLE_BASE_URL` is used, mapping the
+    /// preset URLs back to their presets so the sandbox guard still applies.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::EnvVar` if neither variable is set, or the
+    /// parse error of the value that is.
+    pub fn from_env() -> CircleResult<Self> {
+        match std::env::var("CIRCLE_ENVIRONMENT") {
+            Ok(value) => value.parse(),
+            Err(_) => Self::from_base_url(&get_env_var("CIRCLE_BASE_URL")?),
+        }
+    }
+
+    /// Map a base URL to its environment
+    ///
+    /// Circle's sandbox and production URLs (with or without a trailing
+    /// slash) map to their presets; anything else becomes [`Environment::Custom`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Url` if `base_url` is not a valid URL.
+    pub fn from_base_url(base_url: &str) -> CircleResult<Self> {
+        let url = Url::parse(base_url)?;
+        Ok(match url.as_str().trim_end_matches('/') {
+            SANDBOX_BASE_URL => Environment::Sandbox,
+            PRODUCTION_BASE_URL => Environment::Production,
+            _ => Environment::Custom(url),
+        })
+    }
+
+    /// Base URL requests are sent to
+    pub fn base_url(&self) -> Url {
+        match self {
+            Environment::Sandbox => Url::parse(SANDBOX_BASE_URL).expect("valid sandbox URL"),
+            Environment::Production => {
+                Url::parse(PRODUCTION_BASE_URL).expect("valid production URL")
+            }
+            Environment::Custom(url) => url.clone(),
+        }
+    }
+
+    /// Whether this is the sandbox preset
+    pub fn is_sandbox(&self) -> bool {
+        matches!(self, Environment::Sandbox)
+    }
+}
+
+impl FromStr for Environment {
+    type Err = CircleError;
+
+    /// Parse `sandbox`, `production` (case-insensitive) or a base URL
+    fn from_str(value: &str) -> CircleResult<Self> {
+        if value.eq_ignore_ascii_case("sandbox") {
+            Ok(Environment::Sandbox)
+        } else if value.eq_ignore_ascii_case("production") {
+            Ok(Environment::Production)
+        } else {
+            Self::from_base_url(value).map_err(|_| {
+                CircleError::Config(format!(
+                    "Invalid environment {:?}: expected sandbox, production or a URL",
+                    value
+                ))
+            })
+        }
+    }
+}
+
+impl fmt::Display for Environment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Environment::Sandbox => f.write_str("sandbox"),
+            Environment::Production => f.write_str("production"),
+            Environment::Custom(url) => write!(f, "{}", url),
+        }
+    }
+}
+
+/// Refuse a sandbox request body that names a mainnet blockchain
+///
+/// Looks for `blockchain` strings and `blockchains` arrays anywhere in the
+/// body. Blockchains this SDK version does not know are let through.
+pub(crate) fn check_sandbox_payload(payload: &Value) -> CircleResult<()> {
+    match payload {
+        Value::Object(fields) => {
+            for (key, value) in fields {
+                match (key.as_str(), value) {
+                    ("blockchain", Value::String(identifier)) => check_blockchain(identifier)?,
+                    ("blockchains", Value::Array(identifiers)) => {
+                        for identifier in identifiers.iter().filter_map(Value::as_str) {
+                            check_blockchain(identifier)?;
+                        }
+                    }
+                    _ => check_sandbox_payload(value)?,
+                }
+            }
+            Ok(())
+        }
+        Value::Array(values) => values.iter().try_for_each(check_sandbox_payload),
+        _ => Ok(()),
+    }
+}
+
+fn check_blockchain(identifier: &str) -> CircleResult<()> {
+    let blockchain = Blockchain::from(identifier);
+    if matches!(blockchain, Blockchain::Other(_)) || blockchain.is_testnet() {
+        return Ok(());
+    }
+    Err(CircleError::Config(format!(
+        "Refusing to send mainnet blockchain {} to the sandbox environment; \
+         call allow_mainnet_on_sandbox() on the client to override",
+        blockchain
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_environment_presets() {
+        assert_eq!(
+            Environment::from_base_url("https://api-sandbox.circle.com/").unwrap(),
+            Environment::Sandbox
+        );
+        assert_eq!(
+            "PRODUCTION"
+                .parse::<Environment>()
+                .unwrap()
+                .base_url()
+                .as_str(),
+            "https://api.circle.com/"
+        );
+        let custom: Environment = "http://127.0.0.1:8080".parse().unwrap();
+        assert!(matches!(custom, Environment::Custom(_)));
+        assert!("staging".parse::<Environment>().is_err());
+    }
+
+    #[test]
+    fn test_sandbox_payload_guard() {
+        let testnet = json!({
+            "blockchain": "BASE-SEPOLIA",
+            "blockchains": ["ETH-SEPOLIA", "SOL-DEVNET"],
+            "metadata": [{ "name": "wallet" }]
+        });
+        assert!(check_sandbox_payload(&testnet).is_ok());
+        assert!(check_sandbox_payload(&json!({ "blockchain": "NEW-CHAIN" })).is_ok());
+
+        let mainnet = json!({ "walletAddress": { "address": "0x1", "blockchain": "BASE" } });
+        assert!(matches!(
+            check_sandbox_payload(&mainnet),
+            Err(CircleError::Config(_))
+        ));
+        let mainnet = json!({ "blockchains": ["ETH-SEPOLIA", "MATIC"] });
+        assert!(check_sandbox_payload(&mainnet).is_err());
+    }
+}
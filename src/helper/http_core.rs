@@ -0,0 +1,143 @@
+//! Shared HTTP core for CircleView and CircleOps
+//!
+//! Each client built with `CircleView::new` or `CircleOps::new` owns its own
+//! `reqwest::Client`, and with it its own connection pool. Services that use
+//! both clients can build one [`CircleHttpCore`] and derive the clients from
+//! it, so they share a single pool and any per-connection state the core
+//! carries. Cloning a core (or a client built from it) is cheap and keeps the
+//! sharing.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+//! use inf_circle_sdk::circle_view::circle_view::CircleView;
+//! use inf_circle_sdk::helper::http_core::CircleHttpCore;
+//!
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let core = CircleHttpCore::from_env()?;
+//!
+//! let view = CircleView::from_core(&core);
+//! let ops = CircleOps::from_core(
+//!     &core,
+//!     std::env::var("CIRCLE_ENTITY_SECRET")?,
+//!     std::env::var("CIRCLE_PUBLIC_KEY")?,
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::Client;
+
+use crate::helper::{
+    environment::Environment, get_env_var, metrics::MetricsRecorder, rate_limit::RateLimiter,
+    CircleResult, HttpClient,
+};
+
+/// Connection pool and configuration shared by CircleView and CircleOps
+#[derive(Clone)]
+pub struct CircleHttpCore {
+    client: HttpClient,
+}
+
+impl CircleHttpCore {
+    /// Create a core for an environment
+    pub fn new(environment: Environment, api_key: String) -> Self {
+        Self {
+            client: HttpClient::for_environment(environment).api_key(api_key),
+        }
+    }
+
+    /// Create a core from `CIRCLE_API_KEY` and the environment variables read by [`Environment::from_env`]
+    ///
+    /// Loads `.env` if present, like the clients' `new` constructors.
+    pub fn from_env() -> CircleResult<Self> {
+        dotenv::dotenv().ok(); // Load .env file if present
+
+        let api_key = get_env_var("CIRCLE_API_KEY")?;
+        Ok(Self::new(Environment::from_env()?, api_key))
+    }
+
+    /// Use a preconfigured `reqwest::Client` (pool size, timeouts, proxies)
+    pub fn with_reqwest_client(mut self, client: Client) -> Self {
+        self.client = self.client.reqwest_client(client);
+        self
+    }
+
+    /// Throttle every client built from this core through one shared limiter
+    pub fn with_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.client = self.client.rate_limiter(limiter);
+        self
+    }
+
+    /// Report the requests of every client built from this core to one recorder
+    pub fn with_metrics_recorder(mut self, recorder: Arc<dyn MetricsRecorder>) -> Self {
+        self.client = self.client.metrics_recorder(recorder);
+        self
+    }
+
+    /// Abandon each request of every client built from this core that takes longer than `timeout`
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.client = self.client.request_timeout(timeout);
+        self
+    }
+
+    /// Check the responses of every client built from this core against the vendored schemas
+    #[cfg(feature = "schema-validation")]
+    pub fn with_schema_validation(mut self) -> Self {
+        self.client = self.client.validate_schemas(true);
+        self
+    }
+
+    /// The environment requests are sent to
+    pub fn environment(&self) -> &Environment {
+        self.client.environment()
+    }
+
+    /// The HTTP client handed to each CircleView or CircleOps
+    pub(crate) fn http_client(&self) -> HttpClient {
+        self.client.clone()
+    }
+
+    pub(crate) fn from_http_client(client: HttpClient) -> Self {
+        Self { client }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::circle_ops::circler_ops::CircleOps;
+    use crate::circle_view::circle_view::CircleView;
+    use serde_json::Value;
+    use url::Url;
+
+    #[tokio::test]
+    async fn test_clients_share_core() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v1/w3s/ping")
+            .match_header("authorization", "Bearer shared-key")
+            .with_status(200)
+            .with_body(r#"{"data":{"message":"pong"}}"#)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let environment = Environment::Custom(Url::parse(&server.url()).unwrap());
+        let core = CircleHttpCore::new(environment, "shared-key".to_string());
+        let view = CircleView::from_core(&core);
+        let ops = CircleOps::from_core(&core, String::new(), String::new());
+
+        let _: Value = view.get("/v1/w3s/ping").await.unwrap();
+        let _: Value = ops
+            .request(reqwest::Method::GET, "/v1/w3s/ping", None::<&Value>)
+            .await
+            .unwrap();
+        assert_eq!(ops.core().environment(), view.environment());
+        mock.assert_async().await;
+    }
+}
@@ -0,0 +1,209 @@
+//! Request metrics hooks
+//!
+//! A [`MetricsRecorder`] attached to a client is called once per Circle API
+//! call with the method, endpoint, status code and latency, so operators can
+//! feed their own monitoring. Endpoints are reported as path templates (IDs
+//! and addresses replaced by `{id}`) to keep label cardinality bounded.
+//!
+//! With the `metrics` feature, [`MetricsCrateRecorder`] forwards every call to
+//! the [`metrics`](https://docs.rs/metrics) facade, which exporters such as
+//! `metrics-exporter-prometheus` can scrape.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use std::sync::Arc;
+//! use inf_circle_sdk::circle_view::circle_view::CircleView;
+//! use inf_circle_sdk::helper::metrics::{MetricsRecorder, RequestMetrics};
+//!
+//! struct LogRecorder;
+//!
+//! impl MetricsRecorder for LogRecorder {
+//!     fn record(&self, metrics: &RequestMetrics) {
+//!         println!(
+//!             "{} {} -> {:?} in {:?}",
+//!             metrics.method, metrics.endpoint, metrics.status, metrics.duration
+//!         );
+//!     }
+//! }
+//!
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let view = CircleView::new()?.with_metrics_recorder(Arc::new(LogRecorder));
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::Duration;
+
+use reqwest::Method;
+
+/// Placeholder for ID-like path segments
+const ID_PLACEHOLDER: &str = "{id}";
+
+/// One completed Circle API call
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestMetrics {
+    /// HTTP method
+    pub method: Method,
+
+    /// Path template, e.g. `/v1/w3s/wallets/{id}/balances`
+    pub endpoint: String,
+
+    /// HTTP status code; `None` if no response was received
+    pub status: Option<u16>,
+
+    /// Time from sending the request to receiving the response headers
+    pub duration: Duration,
+}
+
+impl RequestMetrics {
+    /// Whether Circle answered with a 2xx status
+    pub fn is_success(&self) -> bool {
+        self.status
+            .is_some_and(|status| (200..300).contains(&status))
+    }
+}
+
+/// Receives metrics for every API call made by a client
+///
+/// `record` runs on the request path, so implementations should only update
+/// counters or hand the sample off, never block.
+pub trait MetricsRecorder: Send + Sync {
+    /// Record one API call
+    fn record(&self, metrics: &RequestMetrics);
+}
+
+/// Replace ID-like path segments (UUIDs, hex addresses, numbers, long tokens) with `{id}`
+pub fn endpoint_template(path: &str) -> String {
+    path.split('/')
+        .map(|segment| {
+            if is_id_segment(segment) {
+                ID_PLACEHOLDER
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn is_id_segment(segment: &str) -> bool {
+    let is_uuid = segment.len() == 36 && segment.chars().filter(|c| *c == '-').count() == 4;
+    let is_number = !segment.is_empty() && segment.chars().all(|c| c.is_ascii_digit());
+    is_uuid || is_number || segment.starts_with("0x") || segment.len() >= 24
+}
+
+/// [`MetricsRecorder`] that reports to the `metrics` crate facade
+///
+/// Records the counter `circle_api_requests_total` (labels `method`,
+/// `endpoint`, `status`) and the histogram
+/// `circle_api_request_duration_seconds` (labels `method`, `endpoint`).
+/// Calls without a response are counted with `status="error"`.
+#[cfg(feature = "metrics")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MetricsCrateRecorder;
+
+#[cfg(feature = "metrics")]
+impl MetricsRecorder for MetricsCrateRecorder {
+    fn record(&self, metrics: &RequestMetrics) {
+        let method = metrics.method.to_string();
+        let status = metrics
+            .status
+            .map_or_else(|| "error".to_string(), |status| status.to_string());
+        metrics::counter!(
+            "circle_api_requests_total",
+            "method" => method.clone(),
+            "endpoint" => metrics.endpoint.clone(),
+            "status" => status,
+        )
+        .increment(1);
+        metrics::histogram!(
+            "circle_api_request_duration_seconds",
+            "method" => method,
+            "endpoint" => metrics.endpoint.clone(),
+        )
+        .record(metrics.duration.as_secs_f64());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::circle_view::circle_view::CircleView;
+    use crate::helper::{CircleError, HttpClient};
+    use crate::testing::fixtures;
+    use serde_json::{json, Value};
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Default)]
+    struct Collect(Mutex<Vec<RequestMetrics>>);
+
+    impl MetricsRecorder for Collect {
+        fn record(&self, metrics: &RequestMetrics) {
+            self.0.lock().unwrap().push(metrics.clone());
+        }
+    }
+
+    #[test]
+    fn test_endpoint_template() {
+        assert_eq!(
+            endpoint_template("/v1/w3s/wallets/01234567-89ab-cdef-0123-456789abcdef/balances"),
+            "/v1/w3s/wallets/{id}/balances"
+        );
+        assert_eq!(
+            endpoint_template("/v1/w3s/contracts/0x2222222222222222222222222222222222222222"),
+            "/v1/w3s/contracts/{id}"
+        );
+        assert_eq!(
+            endpoint_template("/v1/w3s/developer/transactions/transfer"),
+            "/v1/w3s/developer/transactions/transfer"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_recorder_sees_every_call() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock(
+                "GET",
+                "/v1/w3s/wallets/01234567-89ab-cdef-0123-456789abcdef",
+            )
+            .with_status(200)
+            .with_body(json!({"data": {"wallet": fixtures::dev_wallet()}}).to_string())
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/v1/w3s/ping")
+            .with_status(404)
+            .with_body(r#"{"code":404,"message":"not found"}"#)
+            .create_async()
+            .await;
+
+        let recorder = Arc::new(Collect::default());
+        let client = HttpClient::new(&server.url()).unwrap();
+        // The fixture must stay valid for clients that check schemas
+        #[cfg(feature = "schema-validation")]
+        let client = client.validate_schemas(true);
+        let view = CircleView::from_client(client).with_metrics_recorder(recorder.clone());
+        let _: Value = view
+            .get("/v1/w3s/wallets/01234567-89ab-cdef-0123-456789abcdef")
+            .await
+            .unwrap();
+        let missing: Result<Value, _> = view.get("/v1/w3s/ping").await;
+        assert!(matches!(missing, Err(CircleError::Api { status: 404, .. })));
+
+        let recorded = recorder.0.lock().unwrap();
+        let summary: Vec<_> = recorded
+            .iter()
+            .map(|m| (m.endpoint.as_str(), m.status, m.is_success()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("/v1/w3s/wallets/{id}", Some(200), true),
+                ("/v1/w3s/ping", Some(404), false),
+            ]
+        );
+        assert!(recorded.iter().all(|m| m.method == Method::GET));
+    }
+}
@@ -0,0 +1,210 @@
+//! One page of a cursor-paginated list endpoint
+//!
+//! Circle lists are paginated with item IDs: `pageAfter` returns the items
+//! after (older than) the given item and `pageBefore` the items before
+//! (newer than) it. Responses carry neither cursors nor a total count, so a
+//! [`Page`] derives them from the items it holds and the request that
+//! fetched it. A full page means more items probably exist; when the last
+//! page happens to be exactly full, following its cursor returns an empty
+//! page.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::circle_view::circle_view::CircleView;
+//! use inf_circle_sdk::dev_wallet::views::list_transactions::ListTransactionsParamsBuilder;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let view = CircleView::new()?;
+//!
+//! let mut params = ListTransactionsParamsBuilder::new()
+//!     .wallet_ids("wallet-id".to_string())
+//!     .page_size(50)
+//!     .build();
+//! loop {
+//!     let page = view.list_transactions(params.clone()).await?;
+//!     for transaction in &page {
+//!         println!("{} {}", transaction.id, transaction.state);
+//!     }
+//!     match page.next_page() {
+//!         Some(next) => params.pagination = next,
+//!         None => break,
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::Serialize;
+
+use super::{PaginationParams, ResourceId};
+
+/// Page size Circle uses when a request does not set one
+pub const DEFAULT_PAGE_SIZE: u32 = 10;
+
+/// Items of one list response with the cursors of the neighbouring pages
+///
+/// Serializes with camelCase keys, so a page can be handed to a UI as is.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Page<T> {
+    /// The items, in the order Circle returned them
+    pub items: Vec<T>,
+
+    /// `pageAfter` cursor of the following page, if one may exist
+    pub next_cursor: Option<String>,
+
+    /// `pageBefore` cursor of the preceding page, if one exists
+    pub prev_cursor: Option<String>,
+
+    /// Whether a following page may exist (the next cursor is set)
+    pub has_more: bool,
+
+    /// Page size the request asked for
+    pub page_size: u32,
+}
+
+impl<T: ResourceId> Page<T> {
+    /// Build a page from the items `request` returned
+    ///
+    /// A request without cursors fetched the first page, which has no
+    /// preceding page. A `pageBefore` request walked backwards, so a
+    /// following page exists and a preceding one may.
+    pub fn new(items: Vec<T>, request: &PaginationParams) -> Self {
+        let page_size = request.page_size.unwrap_or(DEFAULT_PAGE_SIZE);
+        let full = items.len() >= page_size as usize;
+        let first = items.first().map(|item| item.resource_id().to_string());
+        let last = items.last().map(|item| item.resource_id().to_string());
+
+        let backwards = request.page_before.is_some() && request.page_after.is_none();
+        let (next_cursor, prev_cursor) = if backwards {
+            (last, first.filter(|_| full))
+        } else {
+            (
+                last.filter(|_| full),
+                first.filter(|_| request.page_after.is_some()),
+            )
+        };
+
+        Self {
+            items,
+            has_more: next_cursor.is_some(),
+            next_cursor,
+            prev_cursor,
+            page_size,
+        }
+    }
+}
+
+impl<T> Page<T> {
+    /// Pagination parameters for the following page, if one may exist
+    pub fn next_page(&self) -> Option<PaginationParams> {
+        self.next_cursor.as_ref().map(|cursor| PaginationParams {
+            page_after: Some(cursor.clone()),
+            page_before: None,
+            page_size: Some(self.page_size),
+        })
+    }
+
+    /// Pagination parameters for the preceding page, if one exists
+    pub fn prev_page(&self) -> Option<PaginationParams> {
+        self.prev_cursor.as_ref().map(|cursor| PaginationParams {
+            page_after: None,
+            page_before: Some(cursor.clone()),
+            page_size: Some(self.page_size),
+        })
+    }
+
+    /// Number of items on the page
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Whether the page holds no items
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Iterate over the items
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.items.iter()
+    }
+
+    /// Transform the items, keeping the cursors
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            next_cursor: self.next_cursor,
+            prev_cursor: self.prev_cursor,
+            has_more: self.has_more,
+            page_size: self.page_size,
+        }
+    }
+}
+
+impl<T> IntoIterator for Page<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Page<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Item(String);
+
+    impl ResourceId for Item {
+        fn resource_id(&self) -> &str {
+            &self.0
+        }
+    }
+
+    fn items(ids: &[&str]) -> Vec<Item> {
+        ids.iter().map(|id| Item(id.to_string())).collect()
+    }
+
+    fn params(after: Option<&str>, before: Option<&str>) -> PaginationParams {
+        PaginationParams {
+            page_after: after.map(str::to_string),
+            page_before: before.map(str::to_string),
+            page_size: Some(2),
+        }
+    }
+
+    #[test]
+    fn test_cursors_follow_request_direction() {
+        // First page, full
+        let page = Page::new(items(&["a", "b"]), &params(None, None));
+        assert!(page.has_more);
+        assert_eq!(page.next_page().unwrap().page_after.as_deref(), Some("b"));
+        assert!(page.prev_page().is_none());
+
+        // Last page, walking forward
+        let page = Page::new(items(&["c"]), &params(Some("b"), None));
+        assert!(!page.has_more);
+        assert_eq!(page.prev_page().unwrap().page_before.as_deref(), Some("c"));
+
+        // Walking backward to the first page
+        let page = Page::new(items(&["a"]), &params(None, Some("b")));
+        assert_eq!(page.next_cursor.as_deref(), Some("a"));
+        assert!(page.prev_cursor.is_none());
+
+        // Default page size, empty page
+        let page = Page::new(items(&[]), &PaginationParams::default());
+        assert_eq!(page.page_size, DEFAULT_PAGE_SIZE);
+        assert!(!page.has_more && page.is_empty());
+    }
+}
@@ -0,0 +1,198 @@
+//! Client-side rate limiting
+//!
+//! A [`RateLimiter`] holds one token bucket per [`EndpointClass`]. Every
+//! request takes a token from its class's bucket before it is sent and waits
+//! when the bucket is empty, so a batch job spreads its calls out instead of
+//! bursting into Circle's limits and retrying on 429s.
+//!
+//! Limiters are cheap to clone and clones share their buckets. Attach one to
+//! a [`CircleHttpCore`](crate::helper::http_core::CircleHttpCore) (or to each
+//! client) to throttle all clients built from it together.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::circle_view::circle_view::CircleView;
+//! use inf_circle_sdk::helper::rate_limit::{EndpointClass, RateLimit, RateLimiter};
+//!
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! // 10 requests/sec overall, with writes held to 2/sec in bursts of up to 5
+//! let limiter = RateLimiter::new(RateLimit::per_second(10.0))
+//!     .class(EndpointClass::Write, RateLimit::per_second(2.0).burst(5));
+//!
+//! let view = CircleView::new()?.with_rate_limiter(limiter);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use reqwest::Method;
+
+use crate::helper::{sleep, Instant};
+
+/// Group of endpoints sharing a token bucket
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EndpointClass {
+    /// GET requests
+    Read,
+    /// POST, PUT, PATCH and DELETE requests
+    Write,
+    /// Testnet faucet requests, which Circle limits separately
+    Faucet,
+}
+
+impl EndpointClass {
+    /// Classify a request by method and path
+    pub fn of(method: &Method, path: &str) -> Self {
+        if path.starts_with("/v1/faucet") {
+            EndpointClass::Faucet
+        } else if method == Method::GET {
+            EndpointClass::Read
+        } else {
+            EndpointClass::Write
+        }
+    }
+}
+
+/// Sustained rate and burst size of a token bucket
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    /// Tokens added per second
+    pub per_second: f64,
+    /// Bucket capacity; requests up to this count go out without waiting
+    pub burst: u32,
+}
+
+impl RateLimit {
+    /// Allow `per_second` requests per second with a burst of one second's worth
+    pub fn per_second(per_second: f64) -> Self {
+        Self {
+            per_second,
+            burst: per_second.ceil().max(1.0) as u32,
+        }
+    }
+
+    /// Set the bucket capacity
+    pub fn burst(mut self, burst: u32) -> Self {
+        self.burst = burst.max(1);
+        self
+    }
+}
+
+/// Token bucket rate limiter shared by every client it is attached to
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    default: RateLimit,
+    limits: HashMap<EndpointClass, RateLimit>,
+    buckets: Arc<Mutex<HashMap<EndpointClass, Bucket>>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    /// Available tokens; negative while requests are queued for future tokens
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl RateLimiter {
+    /// Create a limiter applying `default` to every endpoint class
+    ///
+    /// Each class gets its own bucket, so reads and writes do not consume
+    /// each other's tokens.
+    pub fn new(default: RateLimit) -> Self {
+        Self {
+            default,
+            limits: HashMap::new(),
+            buckets: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Override the limit of one endpoint class
+    pub fn class(mut self, class: EndpointClass, limit: RateLimit) -> Self {
+        self.limits.insert(class, limit);
+        self
+    }
+
+    /// The limit applied to `class`
+    pub fn limit(&self, class: EndpointClass) -> RateLimit {
+        self.limits.get(&class).copied().unwrap_or(self.default)
+    }
+
+    /// Wait until a request of `class` may be sent
+    ///
+    /// Callers are served in the order they arrive: each one reserves the next
+    /// token, even if it has not been added yet, and sleeps until it is.
+    pub async fn acquire(&self, class: EndpointClass) {
+        let delay = self.reserve(class);
+        if !delay.is_zero() {
+            sleep(delay).await;
+        }
+    }
+
+    /// Take a token and return how long to wait until it exists
+    fn reserve(&self, class: EndpointClass) -> Duration {
+        let limit = self.limit(class);
+        if limit.per_second <= 0.0 || !limit.per_second.is_finite() {
+            return Duration::ZERO;
+        }
+        let capacity = f64::from(limit.burst);
+        let now = Instant::now();
+
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        let bucket = buckets.entry(class).or_insert(Bucket {
+            tokens: capacity,
+            refilled_at: now,
+        });
+        let elapsed = now.saturating_duration_since(bucket.refilled_at);
+        bucket.tokens = (bucket.tokens + elapsed.as_secs_f64() * limit.per_second).min(capacity);
+        bucket.refilled_at = now;
+
+        bucket.tokens -= 1.0;
+        if bucket.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-bucket.tokens / limit.per_second)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_burst_then_sustained_rate() {
+        let limiter = RateLimiter::new(RateLimit::per_second(2.0).burst(3));
+        let started = Instant::now();
+
+        for _ in 0..3 {
+            limiter.acquire(EndpointClass::Read).await;
+        }
+        assert_eq!(started.elapsed(), Duration::ZERO);
+
+        // Two more at 2/sec take a second, and another class is unaffected
+        limiter.acquire(EndpointClass::Read).await;
+        limiter.acquire(EndpointClass::Read).await;
+        assert_eq!(started.elapsed(), Duration::from_secs(1));
+        limiter.acquire(EndpointClass::Write).await;
+        assert_eq!(started.elapsed(), Duration::from_secs(1));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_concurrent_callers_are_spaced() {
+        let limiter = RateLimiter::new(RateLimit::per_second(100.0))
+            .class(EndpointClass::Write, RateLimit::per_second(10.0).burst(1));
+        let started = Instant::now();
+
+        let write = || limiter.acquire(EndpointClass::Write);
+        tokio::join!(write(), write(), write(), write(), write());
+        assert_eq!(started.elapsed(), Duration::from_millis(400));
+        assert_eq!(
+            EndpointClass::of(&Method::POST, "/v1/faucet/drips"),
+            EndpointClass::Faucet
+        );
+    }
+}
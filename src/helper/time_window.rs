@@ -0,0 +1,59 @@
+//! Date-range bounds for list filters
+//!
+//! List endpoints filter on creation time with `from`/`to` timestamps. These
+//! helpers compute the common windows so call sites don't have to do the
+//! `DateTime<Utc>` arithmetic themselves; the list builders expose them as
+//! `since`, `today` and `between`.
+
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+
+/// Start of a window reaching back `duration` from now
+///
+/// Durations too long to represent clamp to the earliest supported time.
+pub fn since(duration: Duration) -> DateTime<Utc> {
+    since_at(Utc::now(), duration)
+}
+
+/// Midnight (UTC) at the start of the current day
+pub fn start_of_today() -> DateTime<Utc> {
+    start_of_day(Utc::now())
+}
+
+fn since_at(now: DateTime<Utc>, duration: Duration) -> DateTime<Utc> {
+    chrono::Duration::from_std(duration)
+        .ok()
+        .and_then(|duration| now.checked_sub_signed(duration))
+        .unwrap_or(DateTime::<Utc>::MIN_UTC)
+}
+
+fn start_of_day(now: DateTime<Utc>) -> DateTime<Utc> {
+    now.date_naive()
+        .and_hms_opt(0, 0, 0)
+        .map(|midnight| midnight.and_utc())
+        .unwrap_or(now)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_window_bounds() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 5, 14, 30, 15).unwrap();
+
+        assert_eq!(
+            since_at(now, Duration::from_secs(90 * 60)),
+            Utc.with_ymd_and_hms(2024, 3, 5, 13, 0, 15).unwrap()
+        );
+        assert_eq!(
+            since_at(now, Duration::from_secs(u64::MAX)),
+            DateTime::<Utc>::MIN_UTC
+        );
+        assert_eq!(
+            start_of_day(now),
+            Utc.with_ymd_and_hms(2024, 3, 5, 0, 0, 0).unwrap()
+        );
+    }
+}
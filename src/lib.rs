@@ -145,8 +145,29 @@
 //! - [`circle_ops`]: Write operations requiring entity secret authentication
 //! - [`circle_view`]: Read operations requiring only API key
 //! - [`dev_wallet`]: Developer-controlled wallet operations and views
+//! - [`compliance`]: Address screening against the entity's compliance rules
 //! - [`contract`]: Smart contract deployment, import, and interaction
+//! - [`admin`]: Signed account state snapshots for disaster recovery
+//! - [`aptos`]: BCS transaction payloads, public key parsing and REST queries for Aptos
+//! - [`abi`]: Typed Solidity ABI values and call data encoding
+//! - [`audit`]: NDJSON audit log of write operations
+//! - [`evm`]: Typed EVM transactions for `sign_transaction` and signed transaction decoding
+//! - [`broadcast`]: Submit signed EVM, Solana and NEAR transactions to your own RPC endpoint
+//! - [`digest`]: Daily per-wallet-set transaction summaries and delivery sinks
+//! - [`fees`]: Fee estimate types shared by all estimate endpoints, budget recommendations and display
+//! - [`modular_wallet`]: Passkey-secured smart accounts and user operations
+//! - [`solana`]: SOL/SPL token balances and transaction building for `sign_transaction`
+//! - [`payments`]: Circle Mint bank accounts, deposits, payouts and business account transfers
+//! - [`ramp`]: On/off-ramp sessions and `rampSession.*` webhook payloads
+//! - [`rates`]: Exchange rate quotes and exact currency conversion for display and `amountInUSD` checks
+//! - `blocking`: Synchronous wrappers around `CircleView`/`CircleOps` (`blocking` feature)
+//! - `wasm`: Browser timers, clock and randomness so `CircleView` runs on `wasm32-unknown-unknown`
+//! - `testing`: Local mock of the Circle API and canned DTO fixtures (`testing` feature)
+//! - `schema`: Response validation against vendored Circle schemas (`schema-validation` feature)
+//! - [`traits`]: Client traits for injecting mocks and decorators in place of `CircleOps`/`CircleView`
+//! - [`travel_rule`]: Travel rule identity submission, status lookups and webhook payloads
 //! - [`types`]: Common types used across the SDK (blockchains, etc.)
+//! - [`webhook`]: Webhook signature verification, replay protection and an `axum` extractor (`webhook-verify`/`axum` features)
 //! - [`helper`]: Utility functions and error handling
 //!
 //! ## Error Handling
@@ -174,13 +195,44 @@
 //!
 //! See [TESTING.md](https://github.com/Inferenco/inf-circle-sdk/TESTING.md) for comprehensive testing guide.
 
+#[cfg(all(target_arch = "wasm32", not(feature = "wasm")))]
+compile_error!("building for wasm32 requires the `wasm` feature (and `default-features = false`)");
+
+pub mod abi;
+pub mod admin;
+pub mod aptos;
+pub mod audit;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod broadcast;
 pub mod circle_ops;
 pub mod circle_view;
+pub mod compliance;
 pub mod contract;
 pub mod dev_wallet;
+pub mod digest;
+pub mod evm;
+pub mod fees;
 pub mod helper;
+pub mod modular_wallet;
+#[cfg(feature = "near")]
 pub mod near;
+pub mod payments;
+pub mod ramp;
+pub mod rates;
+#[cfg(feature = "schema-validation")]
+pub mod schema;
+pub mod solana;
+#[cfg(feature = "tracing")]
+mod telemetry;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+// Trait methods promise `Send` futures, which browser fetches cannot provide
+#[cfg(not(target_arch = "wasm32"))]
+pub mod traits;
+pub mod travel_rule;
 pub mod types;
+pub mod webhook;
 
 // Re-export main types for convenience
 pub use helper::{encrypt_entity_secret, CircleError, CircleResult};
@@ -0,0 +1,460 @@
+//! JSON-RPC client for Circle Modular Wallets
+//!
+//! Modular wallets are ERC-4337 smart accounts. Circle exposes them through a
+//! JSON-RPC endpoint authenticated with a client key rather than the API key
+//! used by `CircleView` and `CircleOps`. The same endpoint serves passkey
+//! (relying party) methods, Circle-specific address and gas price methods, and
+//! the standard bundler methods, with the target chain selected by URL path.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use reqwest::Client;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+use url::Url;
+
+use crate::{
+    contract::dto::NotificationType,
+    helper::{get_env_var, CircleError, CircleResult},
+    modular_wallet::dto::{
+        GetModularWalletAddressRequest, JsonRpcRequest, JsonRpcResponse, ModularWallet,
+        ModularWalletEvent, ModularWalletNotification, UserOperation, UserOperationGasEstimate,
+        UserOperationGasPrice, UserOperationReceipt,
+    },
+    types::Blockchain,
+};
+
+/// ERC-4337 v0.7 EntryPoint address, identical on every supported chain
+pub const ENTRY_POINT_V07: &str = "0x0000000071727De22E5E9d8BAf0edAc6f37da032";
+
+/// Client for the Circle Modular Wallets JSON-RPC endpoint
+#[derive(Clone, Debug)]
+pub struct ModularWalletClient {
+    client: Client,
+    client_url: Url,
+    client_key: String,
+    next_id: Arc<AtomicU64>,
+}
+
+impl ModularWalletClient {
+    /// Create a client from the client URL and client key shown in the Circle Console
+    ///
+    /// # Arguments
+    ///
+    /// * `client_url` - Modular wallets client URL (e.g., "https://modular-sdk.circle.com/v1/rpc/w3s/buidl")
+    /// * `client_key` - Client key used as the bearer token
+    pub fn new(client_url: &str, client_key: String) -> CircleResult<Self> {
+        let client_url = Url::parse(client_url.trim_end_matches('/'))?;
+
+        Ok(Self {
+            client: Client::new(),
+            client_url,
+            client_key,
+            next_id: Arc::new(AtomicU64::new(1)),
+        })
+    }
+
+    /// Create a client from the `CIRCLE_MODULAR_CLIENT_URL` and `CIRCLE_CLIENT_KEY` environment variables
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::modular_wallet::ModularWalletClient;
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ModularWalletClient::from_env()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_env() -> CircleResult<Self> {
+        dotenv::dotenv().ok();
+
+        let client_url = get_env_var("CIRCLE_MODULAR_CLIENT_URL")?;
+        let client_key = get_env_var("CIRCLE_CLIENT_KEY")?;
+        Self::new(&client_url, client_key)
+    }
+
+    /// Send a raw JSON-RPC request
+    ///
+    /// # Arguments
+    ///
+    /// * `chain` - Chain path segment (see [`modular_chain_path`]), or `None` for chain-agnostic methods
+    /// * `method` - JSON-RPC method name
+    /// * `params` - JSON-RPC params (usually an array)
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Api` with the HTTP status for transport failures, or with
+    /// the JSON-RPC error code and message when the endpoint returns an error object.
+    pub async fn rpc<P, R>(&self, chain: Option<&str>, method: &str, params: P) -> CircleResult<R>
+    where
+        P: Serialize,
+        R: DeserializeOwned,
+    {
+        let url = match chain {
+            Some(chain) => Url::parse(&format!("{}/{}", self.client_url, chain))?,
+            None => self.client_url.clone(),
+        };
+
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0",
+            id: self.next_id.fetch_add(1, Ordering::Relaxed),
+            method,
+            params,
+        };
+
+        let response = self
+            .client
+            .post(url)
+            .header("Content-Type", "application/json")
+            .header("Authorization", format!("Bearer {}", self.client_key))
+            .json(&request)
+            .send()
+            .await?;
+
+        let status = response.status();
+        let text = response.text().await?;
+        if !status.is_success() {
+            return Err(CircleError::Api {
+                status: status.as_u16(),
+                request_id: None,
+                message: text,
+            });
+        }
+
+        let response: JsonRpcResponse<R> = serde_json::from_str(&text)?;
+        if let Some(error) = response.error {
+            return Err(CircleError::Api {
+                status: status.as_u16(),
+                request_id: None,
+                message: format!("{} failed ({}): {}", method, error.code, error.message),
+            });
+        }
+
+        response.result.ok_or_else(|| CircleError::Api {
+            status: status.as_u16(),
+            request_id: None,
+            message: format!("{} returned no result", method),
+        })
+    }
+
+    /// Get WebAuthn registration options for a new passkey (`rp_getRegistrationOptions`)
+    ///
+    /// The returned `PublicKeyCredentialCreationOptions` JSON is passed to the
+    /// browser's `navigator.credentials.create()`.
+    pub async fn get_registration_options(&self, username: &str) -> CircleResult<Value> {
+        self.rpc(None, "rp_getRegistrationOptions", json!([username]))
+            .await
+    }
+
+    /// Verify a passkey registration response (`rp_getRegistrationVerification`)
+    ///
+    /// # Arguments
+    ///
+    /// * `registration_credential` - The `PublicKeyCredential` JSON returned by the browser
+    pub async fn verify_registration(&self, registration_credential: Value) -> CircleResult<Value> {
+        self.rpc(
+            None,
+            "rp_getRegistrationVerification",
+            json!([registration_credential]),
+        )
+        .await
+    }
+
+    /// Get WebAuthn login options for an existing passkey (`rp_getLoginOptions`)
+    pub async fn get_login_options(&self, user_id: &str) -> CircleResult<Value> {
+        self.rpc(None, "rp_getLoginOptions", json!([user_id])).await
+    }
+
+    /// Verify a passkey login response (`rp_getLoginVerification`)
+    pub async fn verify_login(&self, authentication_credential: Value) -> CircleResult<Value> {
+        self.rpc(
+            None,
+            "rp_getLoginVerification",
+            json!([authentication_credential]),
+        )
+        .await
+    }
+
+    /// Compute (and register) the counterfactual address of a modular wallet (`circle_getAddress`)
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::modular_wallet::{
+    ///     dto::{
+    ///         GetModularWalletAddressRequest, InitialOwnershipConfiguration,
+    ///         ModularScaConfiguration, WebAuthnOwner, WeightedMultisig,
+    ///     },
+    ///     ModularWalletClient,
+    /// };
+    /// use inf_circle_sdk::types::Blockchain;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ModularWalletClient::from_env()?;
+    ///
+    /// let request = GetModularWalletAddressRequest {
+    ///     sca_configuration: ModularScaConfiguration {
+    ///         initial_ownership_configuration: InitialOwnershipConfiguration {
+    ///             ownership_contract_address: None,
+    ///             weighted_multisig: WeightedMultisig {
+    ///                 webauthn_owners: Some(vec![WebAuthnOwner {
+    ///                     public_key_x: "1234...".to_string(),
+    ///                     public_key_y: "5678...".to_string(),
+    ///                     weight: 1,
+    ///                 }]),
+    ///                 threshold_weight: 1,
+    ///                 ..Default::default()
+    ///             },
+    ///         },
+    ///         sca_core: "circle_6900_singleowner_v2".to_string(),
+    ///         salt: None,
+    ///     },
+    ///     metadata: None,
+    /// };
+    ///
+    /// let wallet = client.get_address(&Blockchain::BaseSepolia, &request).await?;
+    /// println!("Modular wallet address: {}", wallet.address);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_address(
+        &self,
+        blockchain: &Blockchain,
+        request: &GetModularWalletAddressRequest,
+    ) -> CircleResult<ModularWallet> {
+        let chain = chain_path(blockchain)?;
+        self.rpc(Some(chain), "circle_getAddress", json!([request]))
+            .await
+    }
+
+    /// Get current user operation gas prices per fee level (`circle_getUserOperationGasPrice`)
+    pub async fn get_user_operation_gas_price(
+        &self,
+        blockchain: &Blockchain,
+    ) -> CircleResult<UserOperationGasPrice> {
+        let chain = chain_path(blockchain)?;
+        self.rpc(Some(chain), "circle_getUserOperationGasPrice", json!([]))
+            .await
+    }
+
+    /// Estimate gas limits for a user operation (`eth_estimateUserOperationGas`)
+    pub async fn estimate_user_operation_gas(
+        &self,
+        blockchain: &Blockchain,
+        user_operation: &UserOperation,
+    ) -> CircleResult<UserOperationGasEstimate> {
+        let chain = chain_path(blockchain)?;
+        self.rpc(
+            Some(chain),
+            "eth_estimateUserOperationGas",
+            json!([user_operation, ENTRY_POINT_V07]),
+        )
+        .await
+    }
+
+    /// Submit a signed user operation to the bundler (`eth_sendUserOperation`)
+    ///
+    /// # Returns
+    ///
+    /// Returns the user operation hash.
+    pub async fn send_user_operation(
+        &self,
+        blockchain: &Blockchain,
+        user_operation: &UserOperation,
+    ) -> CircleResult<String> {
+        let chain = chain_path(blockchain)?;
+        self.rpc(
+            Some(chain),
+            "eth_sendUserOperation",
+            json!([user_operation, ENTRY_POINT_V07]),
+        )
+        .await
+    }
+
+    /// Get a user operation by hash (`eth_getUserOperationByHash`)
+    ///
+    /// Returns `None` while the operation is unknown to the bundler.
+    pub async fn get_user_operation(
+        &self,
+        blockchain: &Blockchain,
+        user_op_hash: &str,
+    ) -> CircleResult<Option<Value>> {
+        let chain = chain_path(blockchain)?;
+        self.rpc(
+            Some(chain),
+            "eth_getUserOperationByHash",
+            json!([user_op_hash]),
+        )
+        .await
+        .or_else(none_if_missing)
+    }
+
+    /// Get the receipt of an included user operation (`eth_getUserOperationReceipt`)
+    ///
+    /// Returns `None` while the operation has not been included yet.
+    pub async fn get_user_operation_receipt(
+        &self,
+        blockchain: &Blockchain,
+        user_op_hash: &str,
+    ) -> CircleResult<Option<UserOperationReceipt>> {
+        let chain = chain_path(blockchain)?;
+        self.rpc(
+            Some(chain),
+            "eth_getUserOperationReceipt",
+            json!([user_op_hash]),
+        )
+        .await
+        .or_else(none_if_missing)
+    }
+}
+
+/// Bundler methods return `null` for unknown operations, which surfaces as "returned no result"
+fn none_if_missing<T>(error: CircleError) -> CircleResult<Option<T>> {
+    match error {
+        CircleError::Api { ref message, .. } if message.ends_with("returned no result") => Ok(None),
+        other => Err(other),
+    }
+}
+
+/// URL path segment selecting `blockchain` on the modular wallets endpoint
+///
+/// Returns `None` for blockchains without modular wallet support.
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::modular_wallet::modular_chain_path;
+/// use inf_circle_sdk::types::Blockchain;
+///
+/// assert_eq!(modular_chain_path(&Blockchain::BaseSepolia), Some("baseSepolia"));
+/// assert_eq!(modular_chain_path(&Blockchain::Sol), None);
+/// ```
+pub fn modular_chain_path(blockchain: &Blockchain) -> Option<&'static str> {
+    match blockchain {
+        Blockchain::Arb => Some("arbitrum"),
+        Blockchain::ArbSepolia => Some("arbitrumSepolia"),
+        Blockchain::Avax => Some("avalanche"),
+        Blockchain::AvaxFuji => Some("avalancheFuji"),
+        Blockchain::Base => Some("base"),
+        Blockchain::BaseSepolia => Some("baseSepolia"),
+        Blockchain::Matic => Some("polygon"),
+        Blockchain::MaticAmoy => Some("polygonAmoy"),
+        Blockchain::Op => Some("optimism"),
+        Blockchain::OpSepolia => Some("optimismSepolia"),
+        Blockchain::Uni => Some("unichain"),
+        Blockchain::UniSepolia => Some("unichainSepolia"),
+        Blockchain::Monad => Some("monad"),
+        Blockchain::MonadTestnet => Some("monadTestnet"),
+        Blockchain::ArcTestnet => Some("arcTestnet"),
+        _ => None,
+    }
+}
+
+fn chain_path(blockchain: &Blockchain) -> CircleResult<&'static str> {
+    modular_chain_path(blockchain).ok_or_else(|| {
+        CircleError::Config(format!(
+            "Modular wallets are not available on {}",
+            blockchain.as_str()
+        ))
+    })
+}
+
+/// Parse a `modularWallet.*` webhook body into its envelope and typed payload
+///
+/// # Errors
+///
+/// Returns `CircleError::Config` if the notification is not a modular wallet
+/// notification, or `CircleError::Json` if the payload does not match its type.
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::modular_wallet::{parse_modular_wallet_notification, dto::ModularWalletEvent};
+///
+/// let body = r#"{
+///     "subscriptionId": "sub-1",
+///     "notificationId": "n-1",
+///     "notificationType": "modularWallet.inboundTransfer",
+///     "notification": {"id": "t-1", "blockchain": "BASE-SEPOLIA", "amount": "5", "state": "COMPLETE"},
+///     "timestamp": "2025-01-01T00:00:00Z",
+///     "version": 2
+/// }"#;
+///
+/// let (_, event) = parse_modular_wallet_notification(body)?;
+/// assert!(matches!(event, ModularWalletEvent::InboundTransfer(ref t) if t.amount.as_deref() == Some("5")));
+/// # Ok::<(), inf_circle_sdk::helper::CircleError>(())
+/// ```
+pub fn parse_modular_wallet_notification(
+    body: &str,
+) -> CircleResult<(ModularWalletNotification, ModularWalletEvent)> {
+    let notification: ModularWalletNotification = serde_json::from_str(body)?;
+    let payload = notification.notification.clone();
+
+    let event = match notification.notification_type {
+        NotificationType::ModularWalletUserOperation => {
+            ModularWalletEvent::UserOperation(serde_json::from_value(payload)?)
+        }
+        NotificationType::ModularWalletInboundTransfer => {
+            ModularWalletEvent::InboundTransfer(serde_json::from_value(payload)?)
+        }
+        NotificationType::ModularWalletOutboundTransfer => {
+            ModularWalletEvent::OutboundTransfer(serde_json::from_value(payload)?)
+        }
+        ref other => {
+            return Err(CircleError::Config(format!(
+                "Not a modular wallet notification: {}",
+                other.as_str()
+            )))
+        }
+    };
+
+    Ok((notification, event))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_user_operation_notification() {
+        let body = r#"{
+            "subscriptionId": "sub-1",
+            "notificationId": "n-1",
+            "notificationType": "modularWallet.userOperation",
+            "notification": {
+                "id": "op-1",
+                "blockchain": "MATIC-AMOY",
+                "userOpHash": "0xabc",
+                "txHash": "0xdef",
+                "state": "CONFIRMED",
+                "networkFee": "0.01"
+            },
+            "timestamp": "2025-01-01T00:00:00Z"
+        }"#;
+
+        let (envelope, event) = parse_modular_wallet_notification(body).unwrap();
+        assert_eq!(envelope.notification_id, "n-1");
+        let ModularWalletEvent::UserOperation(op) = event else {
+            panic!("expected user operation event");
+        };
+        assert_eq!(op.user_op_hash.as_deref(), Some("0xabc"));
+        assert_eq!(op.extra["networkFee"], "0.01");
+    }
+
+    #[test]
+    fn test_rejects_other_notification_types() {
+        let body = r#"{
+            "subscriptionId": "sub-1",
+            "notificationId": "n-1",
+            "notificationType": "transactions.inbound",
+            "notification": {},
+            "timestamp": "2025-01-01T00:00:00Z"
+        }"#;
+        assert!(matches!(
+            parse_modular_wallet_notification(body),
+            Err(CircleError::Config(_))
+        ));
+    }
+}
@@ -0,0 +1,421 @@
+//! Data transfer objects for Circle Modular Wallets
+//!
+//! Hex quantities in user operations (`nonce`, gas limits, fees) are kept as
+//! `0x`-prefixed strings exactly as the bundler JSON-RPC API returns them.
+
+use crate::contract::dto::NotificationType;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// JSON-RPC 2.0 request envelope
+#[derive(Debug, Serialize)]
+pub(crate) struct JsonRpcRequest<'a, P: Serialize> {
+    pub jsonrpc: &'static str,
+    pub id: u64,
+    pub method: &'a str,
+    pub params: P,
+}
+
+/// JSON-RPC 2.0 response envelope
+#[derive(Debug, Deserialize)]
+pub(crate) struct JsonRpcResponse<R> {
+    pub result: Option<R>,
+    pub error: Option<JsonRpcError>,
+}
+
+/// JSON-RPC 2.0 error object
+#[derive(Debug, Deserialize)]
+pub(crate) struct JsonRpcError {
+    pub code: i64,
+    pub message: String,
+}
+
+/// Owner of a passkey (WebAuthn) controlled modular wallet
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebAuthnOwner {
+    /// X coordinate of the passkey's P-256 public key (decimal string)
+    pub public_key_x: String,
+
+    /// Y coordinate of the passkey's P-256 public key (decimal string)
+    pub public_key_y: String,
+
+    /// Weight of this owner in the multisig
+    pub weight: u32,
+}
+
+/// EOA owner of a modular wallet
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressOwner {
+    /// Owner address
+    pub address: String,
+
+    /// Weight of this owner in the multisig
+    pub weight: u32,
+}
+
+/// Weighted multisig ownership configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WeightedMultisig {
+    /// EOA owners
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owners: Option<Vec<AddressOwner>>,
+
+    /// Passkey owners
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webauthn_owners: Option<Vec<WebAuthnOwner>>,
+
+    /// Total weight required to authorize an operation
+    pub threshold_weight: u32,
+}
+
+/// Initial ownership of a modular wallet
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitialOwnershipConfiguration {
+    /// Address of the ownership module contract, if not the default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ownership_contract_address: Option<String>,
+
+    /// Weighted multisig owners
+    pub weighted_multisig: WeightedMultisig,
+}
+
+/// Smart contract account configuration used to derive a modular wallet address
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModularScaConfiguration {
+    /// Initial owners of the account
+    pub initial_ownership_configuration: InitialOwnershipConfiguration,
+
+    /// Smart contract account implementation (e.g., "circle_6900_singleowner_v2")
+    pub sca_core: String,
+
+    /// Salt used for address derivation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub salt: Option<String>,
+}
+
+/// Optional metadata attached to a modular wallet
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModularWalletMetadata {
+    /// Display name of the wallet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Reference identifier
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ref_id: Option<String>,
+}
+
+/// Parameters for `circle_getAddress`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetModularWalletAddressRequest {
+    /// Account configuration the address is derived from
+    pub sca_configuration: ModularScaConfiguration,
+
+    /// Optional wallet metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ModularWalletMetadata>,
+}
+
+/// Modular wallet returned by address computation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModularWallet {
+    /// System-generated unique identifier of the wallet
+    pub id: String,
+
+    /// Counterfactual smart account address
+    pub address: String,
+
+    /// Blockchain identifier
+    pub blockchain: String,
+
+    /// Wallet state (e.g., "LIVE")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+
+    /// Display name of the wallet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Account configuration the address was derived from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sca_configuration: Option<ModularScaConfiguration>,
+
+    /// Creation timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_date: Option<DateTime<Utc>>,
+
+    /// Last update timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update_date: Option<DateTime<Utc>>,
+}
+
+/// ERC-4337 v0.7 user operation
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserOperation {
+    /// Smart account sending the operation
+    pub sender: String,
+
+    /// Anti-replay nonce
+    pub nonce: String,
+
+    /// Account factory, for the first operation of an undeployed account
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub factory: Option<String>,
+
+    /// Factory call data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub factory_data: Option<String>,
+
+    /// Call data executed by the account
+    pub call_data: String,
+
+    /// Gas limit for the execution phase
+    pub call_gas_limit: String,
+
+    /// Gas limit for the verification phase
+    pub verification_gas_limit: String,
+
+    /// Gas paid to the bundler for pre-verification overhead
+    pub pre_verification_gas: String,
+
+    /// Maximum fee per gas (EIP-1559)
+    pub max_fee_per_gas: String,
+
+    /// Maximum priority fee per gas (EIP-1559)
+    pub max_priority_fee_per_gas: String,
+
+    /// Paymaster sponsoring the operation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paymaster: Option<String>,
+
+    /// Paymaster verification gas limit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paymaster_verification_gas_limit: Option<String>,
+
+    /// Paymaster post-operation gas limit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paymaster_post_op_gas_limit: Option<String>,
+
+    /// Paymaster data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paymaster_data: Option<String>,
+
+    /// Signature over the user operation hash
+    pub signature: String,
+}
+
+/// Gas estimate returned by `eth_estimateUserOperationGas`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserOperationGasEstimate {
+    /// Gas paid to the bundler for pre-verification overhead
+    pub pre_verification_gas: String,
+
+    /// Gas limit for the verification phase
+    pub verification_gas_limit: String,
+
+    /// Gas limit for the execution phase
+    pub call_gas_limit: String,
+
+    /// Paymaster verification gas limit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paymaster_verification_gas_limit: Option<String>,
+
+    /// Paymaster post-operation gas limit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paymaster_post_op_gas_limit: Option<String>,
+}
+
+/// Fee option returned by `circle_getUserOperationGasPrice`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserOperationGasPriceLevel {
+    /// Maximum fee per gas
+    pub max_fee_per_gas: String,
+
+    /// Maximum priority fee per gas
+    pub max_priority_fee_per_gas: String,
+}
+
+/// Gas prices per fee level returned by `circle_getUserOperationGasPrice`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserOperationGasPrice {
+    /// Low fee level
+    pub low: UserOperationGasPriceLevel,
+
+    /// Medium fee level
+    pub medium: UserOperationGasPriceLevel,
+
+    /// High fee level
+    pub high: UserOperationGasPriceLevel,
+
+    /// Whether the prices are for a deployed account
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deployed: Option<Value>,
+
+    /// Whether the prices are for an account that is not yet deployed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not_deployed: Option<Value>,
+}
+
+/// Receipt returned by `eth_getUserOperationReceipt`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserOperationReceipt {
+    /// Hash of the user operation
+    pub user_op_hash: String,
+
+    /// EntryPoint contract that executed the operation
+    pub entry_point: String,
+
+    /// Smart account that sent the operation
+    pub sender: String,
+
+    /// Nonce of the operation
+    pub nonce: String,
+
+    /// Paymaster that sponsored the operation, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paymaster: Option<String>,
+
+    /// Actual gas cost paid, in wei
+    pub actual_gas_cost: String,
+
+    /// Actual gas used
+    pub actual_gas_used: String,
+
+    /// Whether execution succeeded
+    pub success: bool,
+
+    /// Revert reason, if execution failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+
+    /// Logs emitted by the operation
+    #[serde(default)]
+    pub logs: Vec<Value>,
+
+    /// Receipt of the bundle transaction that included the operation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receipt: Option<Value>,
+}
+
+/// Webhook notification envelope for `modularWallet.*` events
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModularWalletNotification {
+    /// Notification subscription that produced this notification
+    pub subscription_id: String,
+
+    /// Unique identifier of the notification
+    pub notification_id: String,
+
+    /// Type of the notification
+    pub notification_type: NotificationType,
+
+    /// Notification payload
+    pub notification: Value,
+
+    /// Timestamp of the notification
+    pub timestamp: DateTime<Utc>,
+
+    /// Payload version
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<u32>,
+}
+
+/// Payload of a `modularWallet.userOperation` notification
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModularWalletUserOperationEvent {
+    /// System-generated unique identifier of the user operation
+    pub id: String,
+
+    /// Blockchain identifier
+    pub blockchain: String,
+
+    /// Smart account address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wallet_address: Option<String>,
+
+    /// Hash of the user operation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_op_hash: Option<String>,
+
+    /// Hash of the bundle transaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tx_hash: Option<String>,
+
+    /// State of the user operation (e.g., "SENT", "CONFIRMED", "FAILED")
+    pub state: String,
+
+    /// Fields not covered above
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, Value>,
+}
+
+/// Payload of a `modularWallet.inboundTransfer` or `modularWallet.outboundTransfer` notification
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModularWalletTransferEvent {
+    /// System-generated unique identifier of the transfer
+    pub id: String,
+
+    /// Blockchain identifier
+    pub blockchain: String,
+
+    /// Smart account address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wallet_address: Option<String>,
+
+    /// Transferred token address (empty for native tokens)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_address: Option<String>,
+
+    /// Transferred amount
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+
+    /// Sender address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+
+    /// Recipient address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+
+    /// Transaction hash
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tx_hash: Option<String>,
+
+    /// State of the transfer
+    pub state: String,
+
+    /// Fields not covered above
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, Value>,
+}
+
+/// Typed payload of a modular wallet notification
+#[derive(Debug, Clone)]
+pub enum ModularWalletEvent {
+    /// `modularWallet.userOperation`
+    UserOperation(ModularWalletUserOperationEvent),
+
+    /// `modularWallet.inboundTransfer`
+    InboundTransfer(ModularWalletTransferEvent),
+
+    /// `modularWallet.outboundTransfer`
+    OutboundTransfer(ModularWalletTransferEvent),
+}
@@ -0,0 +1,58 @@
+//! Circle Modular Wallets
+//!
+//! This module provides access to Circle's Modular Wallets: passkey-secured
+//! ERC-4337 smart accounts that are driven through a JSON-RPC endpoint using a
+//! client key, plus typed payloads for the `modularWallet.*` webhook
+//! notifications.
+//!
+//! # Main Components
+//!
+//! - [`client`]: [`ModularWalletClient`] for passkey registration, address computation and user operations
+//! - [`dto`]: Data transfer objects (ownership configuration, user operations, receipts, notifications)
+//!
+//! # Example - Send a User Operation
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::modular_wallet::{dto::UserOperation, ModularWalletClient};
+//! use inf_circle_sdk::types::Blockchain;
+//!
+//! # async fn example(signed_op: UserOperation) -> Result<(), Box<dyn std::error::Error>> {
+//! // Requires CIRCLE_MODULAR_CLIENT_URL and CIRCLE_CLIENT_KEY in env
+//! let client = ModularWalletClient::from_env()?;
+//!
+//! let user_op_hash = client
+//!     .send_user_operation(&Blockchain::BaseSepolia, &signed_op)
+//!     .await?;
+//!
+//! if let Some(receipt) = client
+//!     .get_user_operation_receipt(&Blockchain::BaseSepolia, &user_op_hash)
+//!     .await?
+//! {
+//!     println!("Included: success = {}", receipt.success);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Example - Handle a Webhook
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::modular_wallet::{dto::ModularWalletEvent, parse_modular_wallet_notification};
+//!
+//! # fn example(body: &str) -> Result<(), Box<dyn std::error::Error>> {
+//! match parse_modular_wallet_notification(body)?.1 {
+//!     ModularWalletEvent::UserOperation(op) => println!("User operation {} is {}", op.id, op.state),
+//!     ModularWalletEvent::InboundTransfer(t) => println!("Received {:?}", t.amount),
+//!     ModularWalletEvent::OutboundTransfer(t) => println!("Sent {:?}", t.amount),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod client;
+pub mod dto;
+
+// Re-export commonly used items
+pub use client::{
+    modular_chain_path, parse_modular_wallet_notification, ModularWalletClient, ENTRY_POINT_V07,
+};
@@ -0,0 +1,423 @@
+//! NEAR RPC client with configurable endpoints and failover
+//!
+//! The public `rpc.*.near.org` endpoints rate-limit aggressively. A
+//! [`NearClient`] takes a prioritized list of RPC URLs (your own node, a
+//! provider, then the public endpoint) and moves to the next one when a call
+//! fails with a transport error, a rate limit or a server error. The endpoint
+//! that last answered stays active for later calls; errors reported by the
+//! RPC handler itself (unknown account, missing access key) are returned
+//! without failover since every endpoint would give the same answer.
+//!
+//! The free functions in [`handler`](super::handler) use
+//! [`NearClient::new`] with the public endpoint.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::near::{dto::NearNetwork, NearClient};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let near = NearClient::with_endpoints(
+//!     NearNetwork::Mainnet,
+//!     [
+//!         "https://near-mainnet.example-provider.com/v1/KEY",
+//!         NearNetwork::Mainnet.rpc_url(),
+//!     ],
+//! )?;
+//!
+//! let balance = near.account_balance("example.near").await?;
+//! println!("{} NEAR via {}", balance.total, near.active_endpoint());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use near_jsonrpc_client::{errors::JsonRpcError, methods, JsonRpcClient};
+use near_jsonrpc_primitives::types::query::QueryResponseKind;
+use near_primitives::types::{AccountId, BlockReference, Finality};
+use serde_json::json;
+
+use super::dto::{
+    NearAccessKey, NearAccountBalance, NearNetwork, NearTokenBalance, NearTokenMetadata,
+};
+use super::handler::{format_yocto_to_near, parse_near_public_key};
+use crate::dev_wallet::dto::DevWallet;
+use crate::helper::{CircleError, CircleResult};
+use crate::types::Blockchain;
+
+/// NEAR RPC client over a prioritized list of endpoints
+///
+/// Cheap to clone; clones share the active endpoint.
+#[derive(Clone)]
+pub struct NearClient {
+    network: NearNetwork,
+    endpoints: Arc<[JsonRpcClient]>,
+    active: Arc<AtomicUsize>,
+}
+
+impl NearClient {
+    /// Create a client for the network's public RPC endpoint
+    pub fn new(network: NearNetwork) -> Self {
+        Self {
+            network,
+            endpoints: Arc::from([JsonRpcClient::connect(network.rpc_url())]),
+            active: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Create a client that tries `endpoints` in order, failing over on errors
+    ///
+    /// Returns `CircleError::Config` if the list is empty.
+    pub fn with_endpoints<I, S>(network: NearNetwork, endpoints: I) -> CircleResult<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let endpoints: Arc<[JsonRpcClient]> = endpoints
+            .into_iter()
+            .map(|url| JsonRpcClient::connect(url.as_ref()))
+            .collect();
+        if endpoints.is_empty() {
+            return Err(CircleError::Config(
+                "NEAR client needs at least one RPC endpoint".to_string(),
+            ));
+        }
+        Ok(Self {
+            network,
+            endpoints,
+            active: Arc::new(AtomicUsize::new(0)),
+        })
+    }
+
+    /// The network this client talks to
+    pub fn network(&self) -> NearNetwork {
+        self.network
+    }
+
+    /// Configured RPC URLs, in priority order
+    pub fn endpoints(&self) -> Vec<&str> {
+        self.endpoints
+            .iter()
+            .map(JsonRpcClient::server_addr)
+            .collect()
+    }
+
+    /// URL of the endpoint the next call goes to first
+    pub fn active_endpoint(&self) -> &str {
+        self.endpoints[self.active.load(Ordering::Relaxed) % self.endpoints.len()].server_addr()
+    }
+
+    /// Fail unless `wallet` lives on this client's network
+    pub(super) fn check_wallet_network(&self, wallet: &DevWallet) -> CircleResult<()> {
+        if wallet_network(wallet)? != self.network {
+            return Err(CircleError::Config(format!(
+                "Wallet {} is on {}, but the NEAR client is for {:?}",
+                wallet.id,
+                wallet.blockchain.as_str(),
+                self.network
+            )));
+        }
+        Ok(())
+    }
+
+    /// Call an RPC method, starting at the active endpoint and rotating on failure
+    pub(super) async fn call<M>(&self, method: &M) -> CircleResult<M::Response>
+    where
+        M: methods::RpcMethod,
+        JsonRpcError<M::Error>: std::fmt::Display,
+    {
+        let first = self.active.load(Ordering::Relaxed);
+        let mut last_error = None;
+
+        for attempt in 0..self.endpoints.len() {
+            let index = (first + attempt) % self.endpoints.len();
+            match self.endpoints[index].call(method).await {
+                Ok(response) => {
+                    self.active.store(index, Ordering::Relaxed);
+                    return Ok(response);
+                }
+                Err(e) if e.handler_error().is_some() => return Err(rpc_error(e)),
+                Err(e) => last_error = Some(e),
+            }
+        }
+
+        Err(last_error
+            .map(rpc_error)
+            .unwrap_or_else(|| CircleError::Config("NEAR client has no RPC endpoint".to_string())))
+    }
+
+    /// Get an account's total, available and staked balance
+    ///
+    /// See [`get_near_account_balance`](super::get_near_account_balance).
+    pub async fn account_balance(&self, account_id: &str) -> CircleResult<NearAccountBalance> {
+        let account_id = AccountId::from_str(account_id)
+            .map_err(|e| CircleError::Config(format!("Invalid NEAR account ID: {}", e)))?;
+
+        let request = methods::query::RpcQueryRequest {
+            block_reference: BlockReference::Finality(Finality::Final),
+            request: near_primitives::views::QueryRequest::ViewAccount { account_id },
+        };
+
+        let methods::query::RpcQueryResponse {
+            block_height,
+            block_hash,
+            kind,
+        } = self.call(&request).await?;
+        let account_view = match kind {
+            QueryResponseKind::ViewAccount(account_view) => account_view,
+            _ => return Err(unexpected_response()),
+        };
+
+        // In near-primitives 0.34+, amount and locked are NearToken types
+        let amount = account_view.amount.as_yoctonear();
+        let locked = account_view.locked.as_yoctonear();
+
+        Ok(NearAccountBalance {
+            total: format_yocto_to_near(amount),
+            available: format_yocto_to_near(amount.saturating_sub(locked)),
+            staked: format_yocto_to_near(locked),
+            state_hash: Some(account_view.code_hash.to_string()),
+            block_hash: Some(block_hash),
+            block_height: Some(block_height),
+        })
+    }
+
+    /// Get an account's access key, including its current nonce and permission
+    ///
+    /// See [`get_access_key`](super::get_access_key).
+    pub async fn access_key(
+        &self,
+        account_id: &str,
+        public_key: &str,
+    ) -> CircleResult<NearAccessKey> {
+        let account_id = AccountId::from_str(account_id)
+            .map_err(|e| CircleError::Config(format!("Invalid NEAR account ID: {}", e)))?;
+        let public_key = parse_near_public_key(public_key).map_err(CircleError::Config)?;
+
+        let request = methods::query::RpcQueryRequest {
+            block_reference: BlockReference::Finality(Finality::Final),
+            request: near_primitives::views::QueryRequest::ViewAccessKey {
+                account_id,
+                public_key,
+            },
+        };
+
+        let response = self.call(&request).await?;
+        match response.kind {
+            QueryResponseKind::AccessKey(access_key) => Ok(NearAccessKey {
+                nonce: access_key.nonce,
+                permission: access_key.permission,
+                block_height: response.block_height,
+                block_hash: response.block_hash,
+            }),
+            _ => Err(unexpected_response()),
+        }
+    }
+
+    /// Get the height of the latest final block
+    pub async fn latest_block_height(&self) -> CircleResult<u64> {
+        let request = methods::block::RpcBlockRequest {
+            block_reference: BlockReference::Finality(Finality::Final),
+        };
+        Ok(self.call(&request).await?.header.height)
+    }
+
+    /// Call a view method on a contract and parse its JSON result
+    pub(super) async fn view_function<T>(
+        &self,
+        contract_id: &AccountId,
+        method_name: &str,
+        args: Vec<u8>,
+    ) -> CircleResult<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let request = methods::query::RpcQueryRequest {
+            block_reference: BlockReference::Finality(Finality::Final),
+            request: near_primitives::views::QueryRequest::CallFunction {
+                account_id: contract_id.clone(),
+                method_name: method_name.to_string(),
+                args: args.into(),
+            },
+        };
+
+        match self.call(&request).await?.kind {
+            QueryResponseKind::CallResult(result) => {
+                Ok(serde_json::from_slice(&result.result).map_err(CircleError::Json)?)
+            }
+            _ => Err(unexpected_response()),
+        }
+    }
+
+    /// Get an account's raw balance of a NEP-141 token
+    ///
+    /// See [`get_near_token_balance`](super::get_near_token_balance).
+    pub async fn token_balance(
+        &self,
+        account_id: &str,
+        token_contract_id: &str,
+    ) -> CircleResult<String> {
+        let account_id = AccountId::from_str(account_id)
+            .map_err(|e| CircleError::Config(format!("Invalid NEAR account ID: {}", e)))?;
+        let contract_id = AccountId::from_str(token_contract_id)
+            .map_err(|e| CircleError::Config(format!("Invalid token contract ID: {}", e)))?;
+
+        let args = serde_json::to_vec(&json!({ "account_id": account_id.as_str() }))?;
+        self.view_function(&contract_id, "ft_balance_of", args)
+            .await
+    }
+
+    /// Get a NEP-141 token's metadata
+    ///
+    /// See [`get_near_token_metadata`](super::get_near_token_metadata).
+    pub async fn token_metadata(&self, token_contract_id: &str) -> CircleResult<NearTokenMetadata> {
+        let contract_id = AccountId::from_str(token_contract_id)
+            .map_err(|e| CircleError::Config(format!("Invalid token contract ID: {}", e)))?;
+
+        let metadata: serde_json::Value = self
+            .view_function(&contract_id, "ft_metadata", vec![])
+            .await?;
+        Ok(NearTokenMetadata {
+            symbol: metadata["symbol"].as_str().unwrap_or("").to_string(),
+            name: metadata["name"].as_str().unwrap_or("").to_string(),
+            decimals: metadata["decimals"].as_u64().unwrap_or(0) as u8,
+            icon: metadata["icon"].as_str().map(|s| s.to_string()),
+            reference: metadata["reference"].as_str().map(|s| s.to_string()),
+        })
+    }
+
+    /// Get an account's non-zero balances of several NEP-141 tokens
+    ///
+    /// See [`get_near_token_balances`](super::get_near_token_balances).
+    pub async fn token_balances(
+        &self,
+        account_id: &str,
+        token_contracts: &[String],
+        include_metadata: bool,
+    ) -> CircleResult<Vec<NearTokenBalance>> {
+        let mut balances = Vec::new();
+
+        for contract_id in token_contracts {
+            match self.token_balance(account_id, contract_id).await {
+                Ok(balance) => {
+                    // Only include non-zero balances
+                    if balance.parse::<u128>().unwrap_or(0) > 0 {
+                        let metadata = if include_metadata {
+                            // Continue even if metadata fetch fails
+                            self.token_metadata(contract_id).await.ok()
+                        } else {
+                            None
+                        };
+
+                        balances.push(NearTokenBalance {
+                            contract_id: contract_id.clone(),
+                            balance,
+                            metadata,
+                        });
+                    }
+                }
+                Err(e) => {
+                    // Log error but continue with other tokens
+                    eprintln!(
+                        "Warning: Failed to query balance for {}: {}",
+                        contract_id, e
+                    );
+                }
+            }
+        }
+
+        Ok(balances)
+    }
+}
+
+/// The NEAR network a wallet lives on
+pub(super) fn wallet_network(wallet: &DevWallet) -> CircleResult<NearNetwork> {
+    match wallet.blockchain {
+        Blockchain::Near => Ok(NearNetwork::Mainnet),
+        Blockchain::NearTestnet => Ok(NearNetwork::Testnet),
+        ref other => Err(CircleError::Config(format!(
+            "Expected a NEAR wallet, not {}",
+            other.as_str()
+        ))),
+    }
+}
+
+fn rpc_error<E>(error: JsonRpcError<E>) -> CircleError
+where
+    JsonRpcError<E>: std::fmt::Display,
+{
+    CircleError::Api {
+        status: 500,
+        request_id: None,
+        message: format!("NEAR RPC error: {}", error),
+    }
+}
+
+fn unexpected_response() -> CircleError {
+    CircleError::Api {
+        status: 500,
+        request_id: None,
+        message: "Unexpected response type from NEAR RPC".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use near_crypto::{KeyType, SecretKey};
+
+    #[tokio::test]
+    async fn test_fails_over_to_next_endpoint() {
+        let mut limited = mockito::Server::new_async().await;
+        let limited_mock = limited
+            .mock("POST", "/")
+            .with_status(429)
+            .expect(1)
+            .create_async()
+            .await;
+        let mut healthy = mockito::Server::new_async().await;
+        let healthy_mock = healthy
+            .mock("POST", "/")
+            .with_status(200)
+            .with_body(
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": "dontcare",
+                    "result": {
+                        "nonce": 41,
+                        "permission": "FullAccess",
+                        "block_height": 1000,
+                        "block_hash": "11111111111111111111111111111111"
+                    }
+                })
+                .to_string(),
+            )
+            .expect(2)
+            .create_async()
+            .await;
+
+        let near = NearClient::with_endpoints(NearNetwork::Testnet, [limited.url(), healthy.url()])
+            .unwrap();
+        let public_key = SecretKey::from_random(KeyType::ED25519)
+            .public_key()
+            .to_string();
+
+        let key = near.access_key("alice.testnet", &public_key).await.unwrap();
+        assert_eq!(key.nonce, 41);
+        assert_eq!(key.block_height, 1000);
+        assert_eq!(near.active_endpoint(), healthy.url());
+
+        // The healthy endpoint stays active; the rate-limited one is not retried
+        near.access_key("alice.testnet", &public_key).await.unwrap();
+        limited_mock.assert_async().await;
+        healthy_mock.assert_async().await;
+
+        assert!(matches!(
+            NearClient::with_endpoints(NearNetwork::Testnet, Vec::<String>::new()),
+            Err(CircleError::Config(_))
+        ));
+    }
+}
@@ -3,7 +3,7 @@
 //! This module contains all data structures used for NEAR protocol operations,
 //! including network identifiers, account balance information, and RPC response types.
 
-use near_primitives::hash::CryptoHash;
+use near_primitives::{hash::CryptoHash, views::AccessKeyPermissionView};
 use serde::{Deserialize, Serialize};
 
 /// NEAR network identifier
@@ -89,3 +89,16 @@ pub struct NearTokenMetadata {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reference: Option<String>,
 }
+
+/// NEAR access key of an account, as of the block it was read at
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NearAccessKey {
+    /// Nonce of the last transaction signed with this key; the next one must be higher
+    pub nonce: u64,
+    /// Full access, or function calls limited to a receiver, methods and allowance
+    pub permission: AccessKeyPermissionView,
+    /// Block height the key was read at
+    pub block_height: u64,
+    /// Block hash the key was read at
+    pub block_hash: CryptoHash,
+}
@@ -0,0 +1,262 @@
+//! NEP-141 fungible token transfers from a Circle wallet
+//!
+//! [`ft_transfer`] covers the whole flow: it registers the receiver with the
+//! token contract when it has no storage deposit yet, builds the `ft_transfer`
+//! call with the required 1 yoctoNEAR deposit, has Circle sign the
+//! transaction and submits it.
+
+use std::str::FromStr;
+
+use near_primitives::{
+    action::{Action, FunctionCallAction},
+    gas::Gas,
+    types::{AccountId, Balance},
+    views::FinalExecutionOutcomeView,
+};
+use serde::Deserialize;
+use serde_json::json;
+
+use super::client::{wallet_network, NearClient};
+use super::transaction::sign_near_transaction;
+use crate::circle_ops::circler_ops::CircleOps;
+use crate::dev_wallet::dto::DevWallet;
+use crate::helper::{CircleError, CircleResult};
+
+/// Gas attached to `ft_transfer`
+pub const FT_TRANSFER_GAS: Gas = Gas::from_teragas(30);
+
+/// Gas attached to `storage_deposit` when the receiver has to be registered
+pub const STORAGE_DEPOSIT_GAS: Gas = Gas::from_teragas(30);
+
+/// `storage_balance_bounds` response of a NEP-145 contract
+#[derive(Deserialize)]
+struct StorageBalanceBounds {
+    min: String,
+}
+
+/// Transfer `amount` base units of a NEP-141 token from `wallet` to `receiver_id`
+///
+/// If `receiver_id` is not registered with the token contract, a
+/// `storage_deposit` for the contract's minimum storage balance is paid by
+/// the wallet in the same transaction. The wallet also needs enough NEAR for
+/// gas and the 1 yoctoNEAR deposit `ft_transfer` requires. RPC calls go to the
+/// public endpoint; use [`NearClient::ft_transfer`] to pick your own.
+///
+/// A transfer the contract rejects is returned as an outcome with a
+/// `Failure` status, not as an error.
+///
+/// # Arguments
+/// * `ops` - Client used to call `dev_sign_transaction`
+/// * `wallet` - A NEAR or NEAR-TESTNET developer-controlled wallet
+/// * `token_contract_id` - Token contract (e.g., "usdc.fakes.testnet")
+/// * `receiver_id` - Account receiving the tokens
+/// * `amount` - Raw amount in the token's base units
+/// * `memo` - Optional memo recorded with the transfer
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+/// use inf_circle_sdk::circle_view::circle_view::CircleView;
+/// use inf_circle_sdk::near::ft_transfer;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let ops = CircleOps::new(None)?;
+/// let view = CircleView::new()?;
+/// let wallet = view.get_wallet("wallet-id").await?.wallet;
+///
+/// // 1.5 USDC (6 decimals)
+/// let outcome = ft_transfer(
+///     &ops,
+///     &wallet,
+///     "usdc.fakes.testnet",
+///     "bob.testnet",
+///     1_500_000,
+///     Some("invoice 42"),
+/// )
+/// .await?;
+/// println!("Submitted: {}", outcome.transaction_outcome.id);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn ft_transfer(
+    ops: &CircleOps,
+    wallet: &DevWallet,
+    token_contract_id: &str,
+    receiver_id: &str,
+    amount: u128,
+    memo: Option<&str>,
+) -> CircleResult<FinalExecutionOutcomeView> {
+    NearClient::new(wallet_network(wallet)?)
+        .ft_transfer(ops, wallet, token_contract_id, receiver_id, amount, memo)
+        .await
+}
+
+impl NearClient {
+    /// [`ft_transfer`] with RPC calls made through this client
+    pub async fn ft_transfer(
+        &self,
+        ops: &CircleOps,
+        wallet: &DevWallet,
+        token_contract_id: &str,
+        receiver_id: &str,
+        amount: u128,
+        memo: Option<&str>,
+    ) -> CircleResult<FinalExecutionOutcomeView> {
+        if amount == 0 {
+            return Err(CircleError::Config(
+                "Token transfer amount must be greater than zero".to_string(),
+            ));
+        }
+        let receiver_id = AccountId::from_str(receiver_id)
+            .map_err(|e| CircleError::Config(format!("Invalid NEAR account ID: {}", e)))?;
+
+        let storage_deposit = self
+            .storage_deposit_required(token_contract_id, &receiver_id)
+            .await?;
+        let actions = ft_transfer_actions(&receiver_id, amount, memo, storage_deposit)?;
+
+        let transaction = self
+            .build_transaction(wallet, token_contract_id, actions)
+            .await?;
+        let signed = sign_near_transaction(ops, &wallet.id, transaction).await?;
+        self.send_transaction(signed).await
+    }
+
+    /// Storage deposit `account_id` needs before it can hold the token, if any
+    ///
+    /// Returns `None` when the account is already registered, otherwise the
+    /// contract's minimum storage balance in yoctoNEAR.
+    pub async fn storage_deposit_required(
+        &self,
+        token_contract_id: &str,
+        account_id: &AccountId,
+    ) -> CircleResult<Option<u128>> {
+        let contract_id = AccountId::from_str(token_contract_id)
+            .map_err(|e| CircleError::Config(format!("Invalid token contract ID: {}", e)))?;
+
+        let args = serde_json::to_vec(&json!({ "account_id": account_id.as_str() }))?;
+        let balance: Option<serde_json::Value> = self
+            .view_function(&contract_id, "storage_balance_of", args)
+            .await?;
+        if balance.is_some() {
+            return Ok(None);
+        }
+
+        let bounds: StorageBalanceBounds = self
+            .view_function(&contract_id, "storage_balance_bounds", vec![])
+            .await?;
+        let min = bounds.min.parse::<u128>().map_err(|e| {
+            CircleError::Config(format!(
+                "Invalid storage_balance_bounds from {}: {}",
+                token_contract_id, e
+            ))
+        })?;
+        Ok(Some(min))
+    }
+}
+
+/// Actions for a token transfer, registering the receiver first if `storage_deposit` is set
+fn ft_transfer_actions(
+    receiver_id: &AccountId,
+    amount: u128,
+    memo: Option<&str>,
+    storage_deposit: Option<u128>,
+) -> CircleResult<Vec<Action>> {
+    let mut actions = Vec::with_capacity(2);
+
+    if let Some(deposit) = storage_deposit {
+        actions.push(Action::FunctionCall(Box::new(FunctionCallAction {
+            method_name: "storage_deposit".to_string(),
+            args: serde_json::to_vec(&json!({
+                "account_id": receiver_id.as_str(),
+                "registration_only": true,
+            }))?,
+            gas: STORAGE_DEPOSIT_GAS,
+            deposit: Balance::from_yoctonear(deposit),
+        })));
+    }
+
+    // NEP-141 requires exactly 1 yoctoNEAR so the call needs a full access key
+    actions.push(Action::FunctionCall(Box::new(FunctionCallAction {
+        method_name: "ft_transfer".to_string(),
+        args: serde_json::to_vec(&json!({
+            "receiver_id": receiver_id.as_str(),
+            "amount": amount.to_string(),
+            "memo": memo,
+        }))?,
+        gas: FT_TRANSFER_GAS,
+        deposit: Balance::from_yoctonear(1),
+    })));
+
+    Ok(actions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::near::dto::NearNetwork;
+
+    fn call_result(value: serde_json::Value) -> String {
+        json!({
+            "jsonrpc": "2.0",
+            "id": "dontcare",
+            "result": {
+                "result": serde_json::to_vec(&value).unwrap(),
+                "logs": [],
+                "block_height": 1000,
+                "block_hash": "11111111111111111111111111111111"
+            }
+        })
+        .to_string()
+    }
+
+    fn view_call(method_name: &str) -> mockito::Matcher {
+        mockito::Matcher::PartialJson(json!({ "params": { "method_name": method_name } }))
+    }
+
+    #[tokio::test]
+    async fn test_storage_deposit_required_for_unregistered_receiver() {
+        let mut server = mockito::Server::new_async().await;
+        let balance_mock = server
+            .mock("POST", "/")
+            .match_body(view_call("storage_balance_of"))
+            .with_body(call_result(serde_json::Value::Null))
+            .create_async()
+            .await;
+        let bounds_mock = server
+            .mock("POST", "/")
+            .match_body(view_call("storage_balance_bounds"))
+            .with_body(call_result(json!({
+                "min": "1250000000000000000000",
+                "max": "1250000000000000000000"
+            })))
+            .create_async()
+            .await;
+
+        let near = NearClient::with_endpoints(NearNetwork::Testnet, [server.url()]).unwrap();
+        let receiver: AccountId = "bob.testnet".parse().unwrap();
+        let deposit = near
+            .storage_deposit_required("usdc.fakes.testnet", &receiver)
+            .await
+            .unwrap();
+        assert_eq!(deposit, Some(1_250_000_000_000_000_000_000));
+        balance_mock.assert_async().await;
+        bounds_mock.assert_async().await;
+
+        let actions = ft_transfer_actions(&receiver, 1_500_000, Some("memo"), deposit).unwrap();
+        assert_eq!(actions.len(), 2);
+        let Action::FunctionCall(transfer) = &actions[1] else {
+            panic!("expected a function call");
+        };
+        assert_eq!(transfer.method_name, "ft_transfer");
+        assert_eq!(transfer.deposit, Balance::from_yoctonear(1));
+        let args: serde_json::Value = serde_json::from_slice(&transfer.args).unwrap();
+        assert_eq!(args["amount"], "1500000");
+        assert_eq!(args["memo"], "memo");
+
+        // A registered receiver only gets the transfer
+        let actions = ft_transfer_actions(&receiver, 1, None, None).unwrap();
+        assert_eq!(actions.len(), 1);
+    }
+}
@@ -2,25 +2,24 @@
 //!
 //! This module provides utility functions for working with NEAR protocol,
 //! including account balance queries, delegate action serialization, and public key parsing.
+//! The RPC queries go to the network's public endpoint; use
+//! [`NearClient`] to configure your own endpoints and failover.
 
-use crate::helper::{CircleError, CircleResult};
+use crate::helper::CircleResult;
 use near_crypto::PublicKey;
-use near_jsonrpc_client::{methods, JsonRpcClient};
-use near_jsonrpc_primitives::types::query::QueryResponseKind;
-use near_primitives::{
-    action::{base64, delegate::DelegateAction},
-    types::{AccountId, BlockReference, Finality},
-};
-use serde_json::json;
+use near_primitives::action::{base64, delegate::DelegateAction};
 use std::str::FromStr;
 
-use super::dto::{NearAccountBalance, NearNetwork, NearTokenBalance, NearTokenMetadata};
+use super::client::NearClient;
+use super::dto::{
+    NearAccessKey, NearAccountBalance, NearNetwork, NearTokenBalance, NearTokenMetadata,
+};
 
 /// Convert yoctoNEAR (1e24) to NEAR string with proper precision
 ///
 /// This function preserves precision by using integer arithmetic and formatting
 /// the result as a decimal string. It handles the full 24 decimal places of yoctoNEAR.
-fn format_yocto_to_near(yocto: u128) -> String {
+pub(crate) fn format_yocto_to_near(yocto: u128) -> String {
     const YOCTO_NEAR: u128 = 1_000_000_000_000_000_000_000_000;
 
     let whole = yocto / YOCTO_NEAR;
@@ -71,66 +70,62 @@ pub async fn get_near_account_balance(
     account_id: &str,
     network: NearNetwork,
 ) -> CircleResult<NearAccountBalance> {
-    let rpc_url = network.rpc_url();
-
-    // Create JSON-RPC client
-    let client = JsonRpcClient::connect(rpc_url);
-
-    // Parse account ID
-    let account_id = AccountId::from_str(account_id)
-        .map_err(|e| CircleError::Config(format!("Invalid NEAR account ID: {}", e)))?;
-
-    // Query account view
-    let request = methods::query::RpcQueryRequest {
-        block_reference: BlockReference::Finality(Finality::Final),
-        request: near_primitives::views::QueryRequest::ViewAccount { account_id },
-    };
-
-    let response = client.call(request).await.map_err(|e| CircleError::Api {
-        status: 500,
-        message: format!("NEAR RPC error: {}", e),
-    })?;
-
-    // Extract account view from response - RpcQueryResponse is a wrapper
-    let (account_view, block_height, block_hash) = match response {
-        methods::query::RpcQueryResponse {
-            block_height,
-            block_hash,
-            kind,
-        } => match kind {
-            QueryResponseKind::ViewAccount(account_view) => {
-                (account_view, Some(block_height), Some(block_hash))
-            }
-            _ => {
-                return Err(CircleError::Api {
-                    status: 500,
-                    message: "Unexpected response type from NEAR RPC".to_string(),
-                });
-            }
-        },
-    };
-
-    // Extract amounts (in yoctoNEAR)
-    // In near-primitives 0.34+, amount and locked are NearToken types, need to convert to u128
-    let amount = account_view.amount.as_yoctonear();
-    let locked = account_view.locked.as_yoctonear();
-
-    // Calculate available balance using integer arithmetic to preserve precision
-    let available_yocto = amount.saturating_sub(locked);
+    NearClient::new(network).account_balance(account_id).await
+}
 
-    // Convert to NEAR strings with full precision
-    let total = format_yocto_to_near(amount);
-    let available = format_yocto_to_near(available_yocto);
-    let staked = format_yocto_to_near(locked);
+/// Get an account's access key, including its current nonce and permission
+///
+/// Transactions and delegate actions signed with the key must use a nonce
+/// above the returned one.
+///
+/// # Arguments
+/// * `account_id` - The NEAR account ID owning the key
+/// * `public_key` - The key, with or without the `ed25519:` prefix
+/// * `network` - The NEAR network to query (Mainnet or Testnet)
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use inf_circle_sdk::near::{get_access_key, dto::NearNetwork};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let key = get_access_key(
+///     "guest-book.testnet",
+///     "ed25519:5tzF9KaC4uEJ9rZx2vXJ5J5J5J5J5J5J5J5J5J5J5J5J",
+///     NearNetwork::Testnet,
+/// )
+/// .await?;
+/// println!("next nonce: {}", key.nonce + 1);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_access_key(
+    account_id: &str,
+    public_key: &str,
+    network: NearNetwork,
+) -> CircleResult<NearAccessKey> {
+    NearClient::new(network)
+        .access_key(account_id, public_key)
+        .await
+}
 
-    Ok(NearAccountBalance {
-        total,
-        available,
-        staked,
-        state_hash: Some(account_view.code_hash.to_string()),
-        block_hash,
-        block_height,
-    })
+/// Get the height of the latest final block
+///
+/// Use it to set `max_block_height` on delegate actions.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use inf_circle_sdk::near::{get_latest_block_height, dto::NearNetwork};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let height = get_latest_block_height(NearNetwork::Testnet).await?;
+/// println!("valid until block {}", height + 120);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_latest_block_height(network: NearNetwork) -> CircleResult<u64> {
+    NearClient::new(network).latest_block_height().await
 }
 
 /// Serialize a NEAR DelegateAction to base64 for Circle API
@@ -195,60 +190,9 @@ pub async fn get_near_token_balance(
     token_contract_id: &str,
     network: NearNetwork,
 ) -> CircleResult<String> {
-    let rpc_url = network.rpc_url();
-    let client = JsonRpcClient::connect(rpc_url);
-
-    let account_id = AccountId::from_str(account_id)
-        .map_err(|e| CircleError::Config(format!("Invalid NEAR account ID: {}", e)))?;
-    let contract_id = AccountId::from_str(token_contract_id)
-        .map_err(|e| CircleError::Config(format!("Invalid token contract ID: {}", e)))?;
-
-    // Prepare arguments for ft_balance_of
-    let args = json!({
-        "account_id": account_id.as_str()
-    });
-    let args_bytes = serde_json::to_vec(&args).map_err(|e| CircleError::Json(e))?;
-
-    // Query the token contract
-    let request = methods::query::RpcQueryRequest {
-        block_reference: BlockReference::Finality(Finality::Final),
-        request: near_primitives::views::QueryRequest::CallFunction {
-            account_id: contract_id.clone(),
-            method_name: "ft_balance_of".to_string(),
-            args: args_bytes.into(),
-        },
-    };
-
-    let response = client.call(request).await.map_err(|e| CircleError::Api {
-        status: 500,
-        message: format!("NEAR RPC error querying token balance: {}", e),
-    })?;
-
-    // Parse the response
-    let result = match response {
-        methods::query::RpcQueryResponse { kind, .. } => match kind {
-            QueryResponseKind::CallResult(result) => {
-                // The result is a JSON string containing the balance
-                let result_str = String::from_utf8(result.result).map_err(|e| {
-                    CircleError::Config(format!("Invalid UTF-8 in token balance response: {}", e))
-                })?;
-
-                // Parse the JSON to extract the balance
-                let balance: String =
-                    serde_json::from_str(&result_str).map_err(|e| CircleError::Json(e))?;
-
-                balance
-            }
-            _ => {
-                return Err(CircleError::Api {
-                    status: 500,
-                    message: "Unexpected response type from NEAR RPC".to_string(),
-                });
-            }
-        },
-    };
-
-    Ok(result)
+    NearClient::new(network)
+        .token_balance(account_id, token_contract_id)
+        .await
 }
 
 /// Get metadata for a NEP-141 fungible token
@@ -279,57 +223,9 @@ pub async fn get_near_token_metadata(
     token_contract_id: &str,
     network: NearNetwork,
 ) -> CircleResult<NearTokenMetadata> {
-    let rpc_url = network.rpc_url();
-    let client = JsonRpcClient::connect(rpc_url);
-
-    let contract_id = AccountId::from_str(token_contract_id)
-        .map_err(|e| CircleError::Config(format!("Invalid token contract ID: {}", e)))?;
-
-    // Query ft_metadata (no arguments needed)
-    let request = methods::query::RpcQueryRequest {
-        block_reference: BlockReference::Finality(Finality::Final),
-        request: near_primitives::views::QueryRequest::CallFunction {
-            account_id: contract_id.clone(),
-            method_name: "ft_metadata".to_string(),
-            args: vec![].into(),
-        },
-    };
-
-    let response = client.call(request).await.map_err(|e| CircleError::Api {
-        status: 500,
-        message: format!("NEAR RPC error querying token metadata: {}", e),
-    })?;
-
-    // Parse the response
-    let metadata = match response {
-        methods::query::RpcQueryResponse { kind, .. } => match kind {
-            QueryResponseKind::CallResult(result) => {
-                let result_str = String::from_utf8(result.result).map_err(|e| {
-                    CircleError::Config(format!("Invalid UTF-8 in token metadata response: {}", e))
-                })?;
-
-                // Parse the JSON metadata
-                let metadata_json: serde_json::Value =
-                    serde_json::from_str(&result_str).map_err(|e| CircleError::Json(e))?;
-
-                NearTokenMetadata {
-                    symbol: metadata_json["symbol"].as_str().unwrap_or("").to_string(),
-                    name: metadata_json["name"].as_str().unwrap_or("").to_string(),
-                    decimals: metadata_json["decimals"].as_u64().unwrap_or(0) as u8,
-                    icon: metadata_json["icon"].as_str().map(|s| s.to_string()),
-                    reference: metadata_json["reference"].as_str().map(|s| s.to_string()),
-                }
-            }
-            _ => {
-                return Err(CircleError::Api {
-                    status: 500,
-                    message: "Unexpected response type from NEAR RPC".to_string(),
-                });
-            }
-        },
-    };
-
-    Ok(metadata)
+    NearClient::new(network)
+        .token_metadata(token_contract_id)
+        .await
 }
 
 /// Get balances for multiple NEP-141 fungible tokens
@@ -375,44 +271,9 @@ pub async fn get_near_token_balances(
     network: NearNetwork,
     include_metadata: bool,
 ) -> CircleResult<Vec<NearTokenBalance>> {
-    let mut balances = Vec::new();
-
-    for contract_id in token_contracts {
-        // Query balance
-        match get_near_token_balance(account_id, contract_id, network).await {
-            Ok(balance_str) => {
-                // Parse balance as u128 to check if it's zero
-                let balance_u128: u128 = balance_str.parse().unwrap_or(0);
-
-                // Only include non-zero balances
-                if balance_u128 > 0 {
-                    let metadata = if include_metadata {
-                        match get_near_token_metadata(contract_id, network).await {
-                            Ok(meta) => Some(meta),
-                            Err(_) => None, // Continue even if metadata fetch fails
-                        }
-                    } else {
-                        None
-                    };
-
-                    balances.push(NearTokenBalance {
-                        contract_id: contract_id.clone(),
-                        balance: balance_str,
-                        metadata,
-                    });
-                }
-            }
-            Err(e) => {
-                // Log error but continue with other tokens
-                eprintln!(
-                    "Warning: Failed to query balance for {}: {}",
-                    contract_id, e
-                );
-            }
-        }
-    }
-
-    Ok(balances)
+    NearClient::new(network)
+        .token_balances(account_id, token_contracts, include_metadata)
+        .await
 }
 
 /// Parse a NEAR public key from various formats
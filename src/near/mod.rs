@@ -5,8 +5,12 @@
 //!
 //! # Main Components
 //!
-//! - [`dto`]: Data transfer objects (network identifiers, account balances, RPC types)
+//! - [`client`]: RPC client with custom endpoints and failover
+//! - [`dto`]: Data transfer objects (network identifiers, account balances, access keys, RPC types)
+//! - [`ft`]: NEP-141 token transfers signed by a Circle wallet
 //! - [`handler`]: Helper functions for NEAR operations
+//! - [`relayer`]: Meta-transactions signed by a Circle wallet, ready for a relayer
+//! - [`transaction`]: Full transactions signed by a Circle wallet, ready to submit
 //!
 //! # Example - Get Account Balance
 //!
@@ -79,12 +83,26 @@
 //! # }
 //! ```
 
+pub mod client;
 pub mod dto;
+pub mod ft;
 pub mod handler;
+pub mod relayer;
+pub mod transaction;
 
 // Re-export commonly used items
-pub use dto::{NearAccountBalance, NearNetwork, NearTokenBalance, NearTokenMetadata};
+pub use client::NearClient;
+pub use dto::{
+    NearAccessKey, NearAccountBalance, NearNetwork, NearTokenBalance, NearTokenMetadata,
+};
+pub use ft::ft_transfer;
 pub use handler::{
-    get_near_account_balance, get_near_token_balance, get_near_token_balances,
-    get_near_token_metadata, parse_near_public_key, serialize_near_delegate_action_to_base64,
+    get_access_key, get_latest_block_height, get_near_account_balance, get_near_token_balance,
+    get_near_token_balances, get_near_token_metadata, parse_near_public_key,
+    serialize_near_delegate_action_to_base64,
+};
+pub use relayer::{build_signed_delegate_action, decode_signed_delegate_action};
+pub use transaction::{
+    decode_signed_near_transaction, near_sign_request, serialize_near_transaction_to_base64,
+    sign_near_transaction,
 };
@@ -0,0 +1,214 @@
+//! NEAR meta-transactions (NEP-366) signed by a Circle wallet
+//!
+//! A relayer pays gas for a `SignedDelegateAction` built and signed by the
+//! user's wallet. [`build_signed_delegate_action`] does the whole round trip:
+//! it reads the wallet's access key nonce and the current block height from
+//! RPC, has Circle sign the delegate action with `dev_sign_delegate`, and
+//! decodes Circle's response into a `SignedDelegateAction` ready to relay.
+
+use std::str::FromStr;
+
+use base64::{engine::general_purpose, Engine};
+use borsh::BorshDeserialize;
+use near_primitives::{
+    action::{
+        delegate::{DelegateAction, NonDelegateAction, SignedDelegateAction},
+        Action,
+    },
+    types::AccountId,
+};
+
+use super::client::{wallet_network, NearClient};
+use super::dto::NearNetwork;
+use super::handler::{parse_near_public_key, serialize_near_delegate_action_to_base64};
+use crate::circle_ops::circler_ops::CircleOps;
+use crate::dev_wallet::dto::DevWallet;
+use crate::dev_wallet::ops::sign_delegate::SignDelegateRequestBuilder;
+use crate::helper::{CircleError, CircleResult};
+
+/// Blocks a delegate action stays valid for after the block it was built at
+///
+/// About two minutes on mainnet; a relayer has to submit it before then.
+pub const DELEGATE_ACTION_VALIDITY_BLOCKS: u64 = 120;
+
+/// NEP-461 prefix some encoders put in front of a signed delegate action
+const NEP_461_PREFIX: u32 = 0x40000000 + 461;
+
+/// Build a delegate action for `wallet`, have Circle sign it and return it ready to relay
+///
+/// The nonce is the wallet's current access key nonce plus one (see
+/// [`get_access_key`](super::get_access_key)), and the action expires
+/// [`DELEGATE_ACTION_VALIDITY_BLOCKS`] blocks after the latest final block.
+/// The wallet's account must already exist on chain. RPC calls go to the
+/// public endpoint; use [`NearClient::build_signed_delegate_action`] to pick
+/// your own.
+///
+/// # Arguments
+/// * `ops` - Client used to call `dev_sign_delegate`
+/// * `wallet` - A NEAR or NEAR-TESTNET developer-controlled wallet
+/// * `receiver_id` - Account the actions are executed on
+/// * `actions` - Actions to delegate; nested delegate actions are rejected
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+/// use inf_circle_sdk::circle_view::circle_view::CircleView;
+/// use inf_circle_sdk::near::build_signed_delegate_action;
+/// use near_primitives::action::{Action, FunctionCallAction};
+/// use near_primitives::gas::Gas;
+/// use near_primitives::types::Balance;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let ops = CircleOps::new(None)?;
+/// let view = CircleView::new()?;
+/// let wallet = view.get_wallet("wallet-id").await?.wallet;
+///
+/// let call = Action::FunctionCall(Box::new(FunctionCallAction {
+///     method_name: "addMessage".to_string(),
+///     args: br#"{"text":"hello"}"#.to_vec(),
+///     gas: Gas::from_gas(30_000_000_000_000),
+///     deposit: Balance::from_yoctonear(0),
+/// }));
+/// let signed = build_signed_delegate_action(&ops, &wallet, "guest-book.testnet", vec![call]).await?;
+/// println!("relay nonce {}", signed.delegate_action.nonce);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn build_signed_delegate_action(
+    ops: &CircleOps,
+    wallet: &DevWallet,
+    receiver_id: &str,
+    actions: Vec<Action>,
+) -> CircleResult<SignedDelegateAction> {
+    NearClient::new(wallet_network(wallet)?)
+        .build_signed_delegate_action(ops, wallet, receiver_id, actions)
+        .await
+}
+
+impl NearClient {
+    /// [`build_signed_delegate_action`] with the nonce and block height read through this client
+    pub async fn build_signed_delegate_action(
+        &self,
+        ops: &CircleOps,
+        wallet: &DevWallet,
+        receiver_id: &str,
+        actions: Vec<Action>,
+    ) -> CircleResult<SignedDelegateAction> {
+        self.check_wallet_network(wallet)?;
+
+        let sender_id = AccountId::from_str(&wallet.address)
+            .map_err(|e| CircleError::Config(format!("Invalid NEAR account ID: {}", e)))?;
+        let receiver_id = AccountId::from_str(receiver_id)
+            .map_err(|e| CircleError::Config(format!("Invalid NEAR account ID: {}", e)))?;
+        let public_key_str = wallet.initial_public_key.as_deref().ok_or_else(|| {
+            CircleError::Config(format!("Wallet {} has no public key", wallet.id))
+        })?;
+        let public_key = parse_near_public_key(public_key_str).map_err(CircleError::Config)?;
+        let actions = actions
+            .into_iter()
+            .map(NonDelegateAction::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| CircleError::Config("Delegate actions cannot be nested".to_string()))?;
+
+        // The access key query also reports the final block it was answered at
+        let access_key = self.access_key(&wallet.address, public_key_str).await?;
+
+        let delegate_action = DelegateAction {
+            sender_id,
+            receiver_id,
+            actions,
+            nonce: access_key.nonce + 1,
+            max_block_height: access_key.block_height + DELEGATE_ACTION_VALIDITY_BLOCKS,
+            public_key,
+        };
+        let unsigned = serialize_near_delegate_action_to_base64(&delegate_action).map_err(|e| {
+            CircleError::Config(format!("Failed to serialize delegate action: {}", e))
+        })?;
+
+        let signed = ops
+            .dev_sign_delegate(
+                SignDelegateRequestBuilder::new(wallet.id.clone(), unsigned)?.build(),
+            )
+            .await?;
+        let signed = decode_signed_delegate_action(&signed.signed_delegate_action)?;
+        if signed.delegate_action != delegate_action {
+            return Err(CircleError::Config(
+                "Circle signed a different delegate action than the one requested".to_string(),
+            ));
+        }
+        Ok(signed)
+    }
+}
+
+/// Decode the base64 `signedDelegateAction` returned by `dev_sign_delegate`
+///
+/// Accepts the action with or without the NEP-461 prefix and checks its
+/// signature against the public key it names.
+pub fn decode_signed_delegate_action(encoded: &str) -> CircleResult<SignedDelegateAction> {
+    let bytes = general_purpose::STANDARD
+        .decode(encoded.trim())
+        .map_err(|e| CircleError::Config(format!("Signed delegate action is not base64: {}", e)))?;
+
+    let signed = SignedDelegateAction::try_from_slice(&bytes).or_else(|e| {
+        match bytes.strip_prefix(NEP_461_PREFIX.to_le_bytes().as_slice()) {
+            Some(unprefixed) => SignedDelegateAction::try_from_slice(unprefixed),
+            None => Err(e),
+        }
+        .map_err(|e| CircleError::Config(format!("Invalid signed delegate action: {}", e)))
+    })?;
+
+    if !signed.verify() {
+        return Err(CircleError::Config(
+            "Signed delegate action has an invalid signature".to_string(),
+        ));
+    }
+    Ok(signed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use near_crypto::{KeyType, SecretKey, Signature};
+
+    fn signed_action(secret: &SecretKey) -> SignedDelegateAction {
+        let delegate_action = DelegateAction {
+            sender_id: "alice.testnet".parse().unwrap(),
+            receiver_id: "guest-book.testnet".parse().unwrap(),
+            actions: vec![],
+            nonce: 7,
+            max_block_height: 1_000,
+            public_key: secret.public_key(),
+        };
+        let signature = secret.sign(delegate_action.get_nep461_hash().as_bytes());
+        SignedDelegateAction {
+            delegate_action,
+            signature,
+        }
+    }
+
+    #[test]
+    fn test_decode_signed_delegate_action() {
+        let secret = SecretKey::from_random(KeyType::ED25519);
+        let signed = signed_action(&secret);
+        let bytes = borsh::to_vec(&signed).unwrap();
+
+        let plain = general_purpose::STANDARD.encode(&bytes);
+        assert_eq!(decode_signed_delegate_action(&plain).unwrap(), signed);
+
+        let mut prefixed = NEP_461_PREFIX.to_le_bytes().to_vec();
+        prefixed.extend_from_slice(&bytes);
+        let prefixed = general_purpose::STANDARD.encode(&prefixed);
+        assert_eq!(decode_signed_delegate_action(&prefixed).unwrap(), signed);
+
+        let forged = SignedDelegateAction {
+            signature: Signature::empty(KeyType::ED25519),
+            ..signed
+        };
+        let forged = general_purpose::STANDARD.encode(borsh::to_vec(&forged).unwrap());
+        assert!(matches!(
+            decode_signed_delegate_action(&forged),
+            Err(CircleError::Config(_))
+        ));
+    }
+}
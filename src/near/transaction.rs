@@ -0,0 +1,214 @@
+//! NEAR transactions signed by a Circle wallet
+//!
+//! Circle's sign transaction endpoint takes a NEAR transaction as the base64
+//! Borsh encoding of an unsigned `Transaction` in `rawTransaction`, and returns
+//! the base64 Borsh encoding of the `SignedTransaction` in `signedTransaction`.
+//! [`NearClient::build_transaction`] fills in the nonce and block hash from
+//! RPC, [`sign_near_transaction`] has Circle sign it, and
+//! [`NearClient::send_transaction`] submits the result.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+//! use inf_circle_sdk::circle_view::circle_view::CircleView;
+//! use inf_circle_sdk::near::{dto::NearNetwork, sign_near_transaction, NearClient};
+//! use near_primitives::action::{Action, TransferAction};
+//! use near_primitives::types::Balance;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let ops = CircleOps::new(None)?;
+//! let view = CircleView::new()?;
+//! let wallet = view.get_wallet("wallet-id").await?.wallet;
+//!
+//! let near = NearClient::new(NearNetwork::Testnet);
+//! let transfer = Action::Transfer(TransferAction {
+//!     deposit: Balance::from_yoctonear(10u128.pow(22)),
+//! });
+//! let transaction = near
+//!     .build_transaction(&wallet, "bob.testnet", vec![transfer])
+//!     .await?;
+//! let signed = sign_near_transaction(&ops, &wallet.id, transaction).await?;
+//! let outcome = near.send_transaction(signed).await?;
+//! println!("Submitted: {}", outcome.transaction_outcome.id);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::str::FromStr;
+
+use base64::{engine::general_purpose, Engine};
+use near_jsonrpc_client::methods;
+use near_primitives::{
+    action::Action,
+    transaction::{SignedTransaction, Transaction, TransactionV0},
+    types::AccountId,
+    views::FinalExecutionOutcomeView,
+};
+
+use super::client::NearClient;
+use super::handler::parse_near_public_key;
+use crate::circle_ops::circler_ops::CircleOps;
+use crate::dev_wallet::dto::DevWallet;
+use crate::dev_wallet::ops::sign_transaction::SignTransactionRequestBuilder;
+use crate::helper::{CircleError, CircleResult};
+
+impl NearClient {
+    /// Build an unsigned transaction from `wallet` to `receiver_id`
+    ///
+    /// The nonce is the wallet's current access key nonce plus one and the
+    /// block hash is the latest final block, both read through this client.
+    /// The wallet's account must already exist on chain.
+    ///
+    /// # Arguments
+    /// * `wallet` - A developer-controlled wallet on this client's network
+    /// * `receiver_id` - Account the actions are executed on
+    /// * `actions` - Actions to execute, in order
+    pub async fn build_transaction(
+        &self,
+        wallet: &DevWallet,
+        receiver_id: &str,
+        actions: Vec<Action>,
+    ) -> CircleResult<Transaction> {
+        self.check_wallet_network(wallet)?;
+
+        let signer_id = AccountId::from_str(&wallet.address)
+            .map_err(|e| CircleError::Config(format!("Invalid NEAR account ID: {}", e)))?;
+        let receiver_id = AccountId::from_str(receiver_id)
+            .map_err(|e| CircleError::Config(format!("Invalid NEAR account ID: {}", e)))?;
+        let public_key_str = wallet.initial_public_key.as_deref().ok_or_else(|| {
+            CircleError::Config(format!("Wallet {} has no public key", wallet.id))
+        })?;
+        let public_key = parse_near_public_key(public_key_str).map_err(CircleError::Config)?;
+
+        // The access key query also reports the final block it was answered at
+        let access_key = self.access_key(&wallet.address, public_key_str).await?;
+
+        Ok(Transaction::V0(TransactionV0 {
+            signer_id,
+            public_key,
+            nonce: access_key.nonce + 1,
+            receiver_id,
+            block_hash: access_key.block_hash,
+            actions,
+        }))
+    }
+
+    /// Submit a signed transaction and wait for its final execution outcome
+    ///
+    /// A transaction that was included but failed is returned as an outcome
+    /// with a `Failure` status, not as an error.
+    pub async fn send_transaction(
+        &self,
+        signed_transaction: SignedTransaction,
+    ) -> CircleResult<FinalExecutionOutcomeView> {
+        let request =
+            methods::broadcast_tx_commit::RpcBroadcastTxCommitRequest { signed_transaction };
+        self.call(&request).await
+    }
+}
+
+/// Serialize an unsigned NEAR transaction to base64, the `rawTransaction` format Circle expects
+pub fn serialize_near_transaction_to_base64(transaction: &Transaction) -> CircleResult<String> {
+    let bytes = borsh::to_vec(transaction)
+        .map_err(|e| CircleError::Config(format!("Failed to serialize transaction: {}", e)))?;
+    Ok(general_purpose::STANDARD.encode(bytes))
+}
+
+/// Create a sign transaction request for `transaction` and the Circle wallet `wallet_id`
+pub fn near_sign_request(
+    wallet_id: String,
+    transaction: &Transaction,
+) -> CircleResult<SignTransactionRequestBuilder> {
+    SignTransactionRequestBuilder::new(
+        wallet_id,
+        Some(serialize_near_transaction_to_base64(transaction)?),
+        None,
+    )
+}
+
+/// Have Circle sign `transaction` with the wallet `wallet_id`
+///
+/// Fails if Circle's signed transaction differs from the one requested or
+/// its signature does not verify.
+pub async fn sign_near_transaction(
+    ops: &CircleOps,
+    wallet_id: &str,
+    transaction: Transaction,
+) -> CircleResult<SignedTransaction> {
+    let request = near_sign_request(wallet_id.to_string(), &transaction)?.build();
+    let response = ops.dev_sign_transaction(request).await?;
+    let signed = decode_signed_near_transaction(&response.signed_transaction)?;
+    if signed.transaction != transaction {
+        return Err(CircleError::Config(
+            "Circle signed a different transaction than the one requested".to_string(),
+        ));
+    }
+    Ok(signed)
+}
+
+/// Decode the base64 `signedTransaction` returned by `dev_sign_transaction`
+///
+/// Checks the signature against the transaction's public key.
+pub fn decode_signed_near_transaction(encoded: &str) -> CircleResult<SignedTransaction> {
+    let bytes = general_purpose::STANDARD
+        .decode(encoded.trim())
+        .map_err(|e| CircleError::Config(format!("Signed transaction is not base64: {}", e)))?;
+    let signed: SignedTransaction = borsh::from_slice(&bytes)
+        .map_err(|e| CircleError::Config(format!("Invalid signed NEAR transaction: {}", e)))?;
+
+    if !signed.signature.verify(
+        signed.get_hash().as_bytes(),
+        signed.transaction.public_key(),
+    ) {
+        return Err(CircleError::Config(
+            "Signed NEAR transaction has an invalid signature".to_string(),
+        ));
+    }
+    Ok(signed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use near_crypto::{KeyType, SecretKey, Signature};
+    use near_primitives::{action::TransferAction, hash::CryptoHash, types::Balance};
+
+    fn transaction(secret: &SecretKey) -> Transaction {
+        Transaction::V0(TransactionV0 {
+            signer_id: "alice.testnet".parse().unwrap(),
+            public_key: secret.public_key(),
+            nonce: 42,
+            receiver_id: "bob.testnet".parse().unwrap(),
+            block_hash: CryptoHash::hash_bytes(b"block"),
+            actions: vec![Action::Transfer(TransferAction {
+                deposit: Balance::from_yoctonear(1),
+            })],
+        })
+    }
+
+    #[test]
+    fn test_decode_signed_near_transaction() {
+        let secret = SecretKey::from_random(KeyType::ED25519);
+        let transaction = transaction(&secret);
+
+        let unsigned = serialize_near_transaction_to_base64(&transaction).unwrap();
+        let decoded: Transaction =
+            borsh::from_slice(&general_purpose::STANDARD.decode(unsigned).unwrap()).unwrap();
+        assert_eq!(decoded, transaction);
+
+        let (hash, _) = transaction.get_hash_and_size();
+        let signed = SignedTransaction::new(secret.sign(hash.as_bytes()), transaction.clone());
+        let encoded = general_purpose::STANDARD.encode(borsh::to_vec(&signed).unwrap());
+        let decoded = decode_signed_near_transaction(&encoded).unwrap();
+        assert_eq!(decoded.transaction, transaction);
+        assert_eq!(decoded.get_hash(), hash);
+
+        let forged = SignedTransaction::new(Signature::empty(KeyType::ED25519), transaction);
+        let forged = general_purpose::STANDARD.encode(borsh::to_vec(&forged).unwrap());
+        assert!(matches!(
+            decode_signed_near_transaction(&forged),
+            Err(CircleError::Config(_))
+        ));
+    }
+}
@@ -0,0 +1,417 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::helper::{PaginationParams, ResourceId};
+use crate::rates::dto::Currency;
+
+/// An amount of fiat or stablecoin
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Money {
+    /// Decimal amount (e.g., "100.00")
+    pub amount: String,
+    pub currency: Currency,
+}
+
+impl Money {
+    /// Create an amount
+    pub fn new(amount: impl Into<String>, currency: Currency) -> Self {
+        Self {
+            amount: amount.into(),
+            currency,
+        }
+    }
+
+    /// An amount in US dollars
+    pub fn usd(amount: impl Into<String>) -> Self {
+        Self::new(amount, Currency::Usd)
+    }
+}
+
+/// Status shared by Circle Mint bank accounts, deposits, payouts and transfers
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PaymentStatus {
+    Pending,
+    Complete,
+    Failed,
+}
+
+/// Bank address as required for wire bank accounts
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BankAddress {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+
+    /// ISO 3166-1 alpha-2 country code
+    pub country: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line1: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line2: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub district: Option<String>,
+}
+
+/// Billing details of the bank account holder
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BillingDetails {
+    pub name: String,
+
+    pub city: String,
+
+    /// ISO 3166-1 alpha-2 country code
+    pub country: String,
+
+    pub line1: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line2: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub district: Option<String>,
+
+    pub postal_code: String,
+}
+
+/// Request structure for linking a wire bank account
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateWireBankAccountRequest {
+    /// UUID v4 for idempotency
+    pub idempotency_key: String,
+
+    /// Account number; for IBAN accounts use `iban` instead
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_number: Option<String>,
+
+    /// ABA routing number or SWIFT/BIC code
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub routing_number: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iban: Option<String>,
+
+    pub billing_details: BillingDetails,
+
+    pub bank_address: BankAddress,
+}
+
+/// A linked wire bank account
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WireBankAccount {
+    pub id: String,
+
+    pub status: PaymentStatus,
+
+    /// Bank name and masked account number (e.g., "WELLS FARGO BANK, NA ****0010")
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// Reference to include when wiring funds to Circle
+    #[serde(default)]
+    pub tracking_ref: Option<String>,
+
+    #[serde(default)]
+    pub fingerprint: Option<String>,
+
+    #[serde(default)]
+    pub billing_details: Option<BillingDetails>,
+
+    #[serde(default)]
+    pub bank_address: Option<BankAddress>,
+
+    pub create_date: DateTime<Utc>,
+
+    pub update_date: DateTime<Utc>,
+}
+
+/// Circle's receiving bank for wire deposits
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BeneficiaryBank {
+    pub name: String,
+
+    #[serde(default)]
+    pub swift_code: Option<String>,
+
+    #[serde(default)]
+    pub routing_number: Option<String>,
+
+    pub account_number: String,
+
+    #[serde(default)]
+    pub currency: Option<Currency>,
+
+    #[serde(default)]
+    pub address: Option<String>,
+
+    #[serde(default)]
+    pub city: Option<String>,
+
+    #[serde(default)]
+    pub postal_code: Option<String>,
+
+    #[serde(default)]
+    pub country: Option<String>,
+}
+
+/// Instructions for wiring fiat to Circle to mint USDC
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WireInstructions {
+    /// Reference that must be included in the wire memo
+    pub tracking_ref: String,
+
+    pub beneficiary: WireBeneficiary,
+
+    pub beneficiary_bank: BeneficiaryBank,
+}
+
+/// Account holder the wire is sent to
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WireBeneficiary {
+    pub name: String,
+
+    #[serde(default)]
+    pub address1: Option<String>,
+
+    #[serde(default)]
+    pub address2: Option<String>,
+}
+
+/// Balances of the Circle Mint business account
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BusinessBalances {
+    /// Funds available for payouts and transfers
+    pub available: Vec<Money>,
+
+    /// Funds received but not yet settled
+    pub unsettled: Vec<Money>,
+}
+
+/// A fiat deposit into the business account
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Deposit {
+    pub id: String,
+
+    /// The linked bank account the deposit came from
+    #[serde(default)]
+    pub source_wallet_id: Option<String>,
+
+    pub destination: PaymentEndpoint,
+
+    pub amount: Money,
+
+    #[serde(default)]
+    pub fee: Option<Money>,
+
+    pub status: PaymentStatus,
+
+    pub create_date: DateTime<Utc>,
+
+    #[serde(default)]
+    pub update_date: Option<DateTime<Utc>>,
+}
+
+impl ResourceId for Deposit {
+    fn resource_id(&self) -> &str {
+        &self.id
+    }
+}
+
+/// Source or destination of a payout or transfer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentEndpoint {
+    /// Endpoint type (e.g., "wire", "wallet", "verified_blockchain", "blockchain")
+    #[serde(rename = "type")]
+    pub endpoint_type: String,
+
+    /// Bank account or wallet ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// ID of an address on the business account's allowlist
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address_id: Option<String>,
+
+    /// Blockchain address
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+
+    /// Blockchain code used by Circle Mint (e.g., "ETH", "SOL")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chain: Option<String>,
+}
+
+impl PaymentEndpoint {
+    /// A linked wire bank account
+    pub fn wire(bank_account_id: impl Into<String>) -> Self {
+        Self {
+            endpoint_type: "wire".to_string(),
+            id: Some(bank_account_id.into()),
+            address_id: None,
+            address: None,
+            chain: None,
+        }
+    }
+
+    /// An address on the business account's allowlist
+    pub fn verified_blockchain(address_id: impl Into<String>) -> Self {
+        Self {
+            endpoint_type: "verified_blockchain".to_string(),
+            id: None,
+            address_id: Some(address_id.into()),
+            address: None,
+            chain: None,
+        }
+    }
+}
+
+/// Request structure for creating a payout (USDC to bank)
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePayoutRequest {
+    /// UUID v4 for idempotency
+    pub idempotency_key: String,
+
+    pub destination: PaymentEndpoint,
+
+    pub amount: Money,
+
+    /// Currency to pay out in, if different from `amount.currency`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to_amount: Option<PayoutCurrency>,
+}
+
+/// Currency a payout is converted into
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayoutCurrency {
+    pub currency: Currency,
+}
+
+/// A payout to a linked bank account
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Payout {
+    pub id: String,
+
+    #[serde(default)]
+    pub source_wallet_id: Option<String>,
+
+    pub destination: PaymentEndpoint,
+
+    pub amount: Money,
+
+    #[serde(default)]
+    pub to_amount: Option<Money>,
+
+    #[serde(default)]
+    pub fees: Option<Money>,
+
+    pub status: PaymentStatus,
+
+    /// Bank tracking reference once the wire is sent
+    #[serde(default)]
+    pub tracking_ref: Option<String>,
+
+    /// Reason the payout failed
+    #[serde(default)]
+    pub error_code: Option<String>,
+
+    pub create_date: DateTime<Utc>,
+
+    #[serde(default)]
+    pub update_date: Option<DateTime<Utc>>,
+}
+
+impl ResourceId for Payout {
+    fn resource_id(&self) -> &str {
+        &self.id
+    }
+}
+
+/// Request structure for transferring USDC from the business account on-chain
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateBusinessTransferRequest {
+    /// UUID v4 for idempotency
+    pub idempotency_key: String,
+
+    pub destination: PaymentEndpoint,
+
+    pub amount: Money,
+}
+
+/// A transfer between the business account and a blockchain address
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BusinessTransfer {
+    pub id: String,
+
+    #[serde(default)]
+    pub source: Option<PaymentEndpoint>,
+
+    pub destination: PaymentEndpoint,
+
+    pub amount: Money,
+
+    /// On-chain transaction hash once broadcast
+    #[serde(default)]
+    pub transaction_hash: Option<String>,
+
+    pub status: PaymentStatus,
+
+    /// Reason the transfer failed
+    #[serde(default)]
+    pub error_code: Option<String>,
+
+    pub create_date: DateTime<Utc>,
+}
+
+impl ResourceId for BusinessTransfer {
+    fn resource_id(&self) -> &str {
+        &self.id
+    }
+}
+
+/// Parameters for listing deposits, payouts and transfers
+#[derive(Debug, Clone, Serialize, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListPaymentsParams {
+    /// Filter by status (e.g., "pending")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
+    /// Filter deposits by type (e.g., "wire")
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub payment_type: Option<String>,
+
+    /// Filter payouts by destination bank account
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination: Option<String>,
+
+    /// Filter by creation date (from)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<DateTime<Utc>>,
+
+    /// Filter by creation date (to)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<DateTime<Utc>>,
+
+    /// Pagination parameters
+    #[serde(flatten)]
+    pub pagination: PaginationParams,
+}
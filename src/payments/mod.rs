@@ -0,0 +1,53 @@
+//! Circle Mint Payments
+//!
+//! Wallet backends often need fiat on and off ramps next to their on-chain
+//! operations. This module covers the Circle Mint business account: linking
+//! wire bank accounts, fiat deposits that mint USDC (bank to USDC), payouts
+//! back to a bank (USDC to bank), and on-chain transfers out of the business
+//! account. Writes live on `CircleOps` and reads on `CircleView`, as for the
+//! Web3 Services endpoints; both authenticate with the same API key, and none
+//! of these requests need the entity secret.
+//!
+//! Circle Mint responses return the resource directly under `data`, so list
+//! operations return plain vectors.
+//!
+//! # Main Components
+//!
+//! - [`payments_ops`]: Write operations (link bank accounts, create payouts and transfers)
+//! - [`payments_view`]: Read operations (balances, wire instructions, deposits, payouts, transfers)
+//! - [`dto`]: Data transfer objects (money amounts, bank accounts, deposits, payouts, transfers)
+//! - [`ops`]: Builder modules for write operations
+//!
+//! # Example - Cash Out to a Bank
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::{
+//!     circle_ops::circler_ops::CircleOps,
+//!     circle_view::circle_view::CircleView,
+//!     payments::{dto::Money, ops::create_payout::CreatePayoutRequestBuilder},
+//!     rates::dto::Currency,
+//! };
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let view = CircleView::new()?;
+//! let ops = CircleOps::new(None)?;
+//!
+//! let balances = view.get_business_balances().await?;
+//! let usd = balances.available.iter().find(|m| m.currency == Currency::Usd);
+//! println!("Available: {:?}", usd);
+//!
+//! let payout = ops
+//!     .create_payout(
+//!         CreatePayoutRequestBuilder::new("bank-account-id".to_string(), Money::usd("250.00"))
+//!             .build(),
+//!     )
+//!     .await?;
+//! println!("Payout {} is {:?}", payout.id, payout.status);
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod dto;
+pub mod ops;
+pub mod payments_ops;
+pub mod payments_view;
@@ -0,0 +1,84 @@
+use crate::helper::{preview_request, CircleResult, GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER};
+use crate::payments::dto::{CreateBusinessTransferRequest, Money, PaymentEndpoint};
+
+/// Builder for sending USDC from the business account to a blockchain address
+///
+/// The destination must be on the business account's allowlist of verified
+/// addresses.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use inf_circle_sdk::payments::{
+///     dto::Money, ops::create_business_transfer::CreateBusinessTransferRequestBuilder,
+/// };
+/// use inf_circle_sdk::rates::dto::Currency;
+///
+/// let builder = CreateBusinessTransferRequestBuilder::new(
+///     "address-id".to_string(),
+///     Money::new("1000.00", Currency::Usd),
+/// )
+/// .build();
+/// ```
+#[derive(Clone, Debug)]
+pub struct CreateBusinessTransferRequestBuilder {
+    pub address_id: String,
+    pub amount: Money,
+    pub idempotency_key: Option<String>,
+}
+
+impl CreateBusinessTransferRequestBuilder {
+    /// Create a new builder with required fields
+    ///
+    /// # Arguments
+    /// * `address_id` - ID of a verified recipient address
+    /// * `amount` - Amount to send, debited from the business account balance
+    pub fn new(address_id: String, amount: Money) -> Self {
+        Self {
+            address_id,
+            amount,
+            idempotency_key: None,
+        }
+    }
+
+    /// Set a custom idempotency key
+    ///
+    /// If not set, a UUID is generated when the request is sent.
+    pub fn idempotency_key(mut self, key: String) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
+
+    /// Convert the builder into the API request body
+    ///
+    /// `generate_idempotency_key` is only called when no custom key was set.
+    pub(crate) fn into_request(
+        self,
+        generate_idempotency_key: impl FnOnce() -> String,
+    ) -> CreateBusinessTransferRequest {
+        CreateBusinessTransferRequest {
+            idempotency_key: self
+                .idempotency_key
+                .unwrap_or_else(generate_idempotency_key),
+            destination: PaymentEndpoint::verified_blockchain(self.address_id),
+            amount: self.amount,
+        }
+    }
+
+    /// Preview the request body as pretty JSON
+    ///
+    /// When no idempotency key was set, the key is shown as
+    /// [`GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER`].
+    pub fn preview(&self) -> CircleResult<String> {
+        preview_request(
+            &self
+                .clone()
+                .into_request(|| GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER.to_string()),
+        )
+    }
+
+    /// Build the CreateBusinessTransferRequestBuilder
+    pub fn build(self) -> CreateBusinessTransferRequestBuilder {
+        self
+    }
+}
@@ -0,0 +1,87 @@
+use crate::helper::{preview_request, CircleResult, GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER};
+use crate::payments::dto::{CreatePayoutRequest, Money, PaymentEndpoint, PayoutCurrency};
+use crate::rates::dto::Currency;
+
+/// Builder for paying out USDC from the business account to a linked bank account
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use inf_circle_sdk::payments::{
+///     dto::Money, ops::create_payout::CreatePayoutRequestBuilder,
+/// };
+///
+/// let builder = CreatePayoutRequestBuilder::new("bank-account-id".to_string(), Money::usd("250.00"))
+///     .build();
+/// ```
+#[derive(Clone, Debug)]
+pub struct CreatePayoutRequestBuilder {
+    pub bank_account_id: String,
+    pub amount: Money,
+    pub to_currency: Option<Currency>,
+    pub idempotency_key: Option<String>,
+}
+
+impl CreatePayoutRequestBuilder {
+    /// Create a new builder with required fields
+    ///
+    /// # Arguments
+    /// * `bank_account_id` - A wire bank account linked to the business account
+    /// * `amount` - Amount to pay out, debited from the business account balance
+    pub fn new(bank_account_id: String, amount: Money) -> Self {
+        Self {
+            bank_account_id,
+            amount,
+            to_currency: None,
+            idempotency_key: None,
+        }
+    }
+
+    /// Pay out in a different currency than `amount` (e.g., EUR for a USD amount)
+    pub fn to_currency(mut self, currency: Currency) -> Self {
+        self.to_currency = Some(currency);
+        self
+    }
+
+    /// Set a custom idempotency key
+    ///
+    /// If not set, a UUID is generated when the request is sent.
+    pub fn idempotency_key(mut self, key: String) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
+
+    /// Convert the builder into the API request body
+    ///
+    /// `generate_idempotency_key` is only called when no custom key was set.
+    pub(crate) fn into_request(
+        self,
+        generate_idempotency_key: impl FnOnce() -> String,
+    ) -> CreatePayoutRequest {
+        CreatePayoutRequest {
+            idempotency_key: self
+                .idempotency_key
+                .unwrap_or_else(generate_idempotency_key),
+            destination: PaymentEndpoint::wire(self.bank_account_id),
+            amount: self.amount,
+            to_amount: self.to_currency.map(|currency| PayoutCurrency { currency }),
+        }
+    }
+
+    /// Preview the request body as pretty JSON
+    ///
+    /// When no idempotency key was set, the key is shown as
+    /// [`GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER`].
+    pub fn preview(&self) -> CircleResult<String> {
+        preview_request(
+            &self
+                .clone()
+                .into_request(|| GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER.to_string()),
+        )
+    }
+
+    /// Build the CreatePayoutRequestBuilder
+    pub fn build(self) -> CreatePayoutRequestBuilder {
+        self
+    }
+}
@@ -0,0 +1,123 @@
+use crate::helper::{preview_request, CircleResult, GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER};
+use crate::payments::dto::{BankAddress, BillingDetails, CreateWireBankAccountRequest};
+
+/// Builder for linking a wire bank account to the Circle Mint business account
+///
+/// US accounts use an account and routing number; most other accounts use an
+/// IBAN.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use inf_circle_sdk::payments::{
+///     dto::{BankAddress, BillingDetails},
+///     ops::create_wire_bank_account::CreateWireBankAccountRequestBuilder,
+/// };
+///
+/// let builder = CreateWireBankAccountRequestBuilder::new(
+///     BillingDetails {
+///         name: "Acme Inc".to_string(),
+///         city: "Boston".to_string(),
+///         country: "US".to_string(),
+///         line1: "100 Money Street".to_string(),
+///         postal_code: "01234".to_string(),
+///         ..Default::default()
+///     },
+///     BankAddress {
+///         country: "US".to_string(),
+///         ..Default::default()
+///     },
+/// )
+/// .account_number("12340010".to_string())
+/// .routing_number("121000248".to_string())
+/// .build();
+/// ```
+#[derive(Clone, Debug)]
+pub struct CreateWireBankAccountRequestBuilder {
+    pub billing_details: BillingDetails,
+    pub bank_address: BankAddress,
+    pub account_number: Option<String>,
+    pub routing_number: Option<String>,
+    pub iban: Option<String>,
+    pub idempotency_key: Option<String>,
+}
+
+impl CreateWireBankAccountRequestBuilder {
+    /// Create a new builder with required fields
+    ///
+    /// # Arguments
+    /// * `billing_details` - Account holder name and address
+    /// * `bank_address` - Address of the bank holding the account
+    pub fn new(billing_details: BillingDetails, bank_address: BankAddress) -> Self {
+        Self {
+            billing_details,
+            bank_address,
+            account_number: None,
+            routing_number: None,
+            iban: None,
+            idempotency_key: None,
+        }
+    }
+
+    /// Set the account number
+    pub fn account_number(mut self, account_number: String) -> Self {
+        self.account_number = Some(account_number);
+        self
+    }
+
+    /// Set the ABA routing number or SWIFT/BIC code
+    pub fn routing_number(mut self, routing_number: String) -> Self {
+        self.routing_number = Some(routing_number);
+        self
+    }
+
+    /// Set the IBAN (instead of an account and routing number)
+    pub fn iban(mut self, iban: String) -> Self {
+        self.iban = Some(iban);
+        self
+    }
+
+    /// Set a custom idempotency key
+    ///
+    /// If not set, a UUID is generated when the request is sent.
+    pub fn idempotency_key(mut self, key: String) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
+
+    /// Convert the builder into the API request body
+    ///
+    /// `generate_idempotency_key` is only called when no custom key was set.
+    pub(crate) fn into_request(
+        self,
+        generate_idempotency_key: impl FnOnce() -> String,
+    ) -> CreateWireBankAccountRequest {
+        CreateWireBankAccountRequest {
+            idempotency_key: self
+                .idempotency_key
+                .unwrap_or_else(generate_idempotency_key),
+            account_number: self.account_number,
+            routing_number: self.routing_number,
+            iban: self.iban,
+            billing_details: self.billing_details,
+            bank_address: self.bank_address,
+        }
+    }
+
+    /// Preview the request body as pretty JSON
+    ///
+    /// When no idempotency key was set, the key is shown as
+    /// [`GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER`].
+    pub fn preview(&self) -> CircleResult<String> {
+        preview_request(
+            &self
+                .clone()
+                .into_request(|| GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER.to_string()),
+        )
+    }
+
+    /// Build the CreateWireBankAccountRequestBuilder
+    pub fn build(self) -> CreateWireBankAccountRequestBuilder {
+        self
+    }
+}
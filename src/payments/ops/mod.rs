@@ -0,0 +1,3 @@
+pub mod create_business_transfer;
+pub mod create_payout;
+pub mod create_wire_bank_account;
@@ -0,0 +1,190 @@
+//! Circle Mint payment write operations for CircleOps
+
+use crate::{
+    circle_ops::circler_ops::CircleOps,
+    helper::{generate_uuid, CircleResult},
+    payments::{
+        dto::{BusinessTransfer, Payout, WireBankAccount},
+        ops::{
+            create_business_transfer::CreateBusinessTransferRequestBuilder,
+            create_payout::CreatePayoutRequestBuilder,
+            create_wire_bank_account::CreateWireBankAccountRequestBuilder,
+        },
+    },
+};
+
+impl CircleOps {
+    /// Link a wire bank account to the business account
+    ///
+    /// Wires from a linked account are credited to the business account
+    /// balance as USDC, and payouts can be sent back to it. Use
+    /// [`CircleView::get_wire_instructions`](crate::circle_view::circle_view::CircleView::get_wire_instructions)
+    /// for where to send deposits.
+    ///
+    /// # Arguments
+    ///
+    /// * `builder` - Account details, holder and bank address
+    pub async fn create_wire_bank_account(
+        &self,
+        builder: CreateWireBankAccountRequestBuilder,
+    ) -> CircleResult<WireBankAccount> {
+        let request = builder.into_request(generate_uuid);
+        self.post("/v1/businessAccount/banks/wires", &request).await
+    }
+
+    /// Pay out from the business account to a linked bank account (USDC to bank)
+    ///
+    /// # Arguments
+    ///
+    /// * `builder` - Bank account, amount and optional payout currency
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+    /// use inf_circle_sdk::payments::{
+    ///     dto::Money, ops::create_payout::CreatePayoutRequestBuilder,
+    /// };
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ops = CircleOps::new(None)?;
+    ///
+    /// let builder =
+    ///     CreatePayoutRequestBuilder::new("bank-account-id".to_string(), Money::usd("250.00"))
+    ///         .build();
+    /// let payout = ops.create_payout(builder).await?;
+    /// println!("Payout {} is {:?}", payout.id, payout.status);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_payout(&self, builder: CreatePayoutRequestBuilder) -> CircleResult<Payout> {
+        let request = builder.into_request(generate_uuid);
+        self.post("/v1/businessAccount/payouts", &request).await
+    }
+
+    /// Send USDC from the business account to a verified blockchain address
+    ///
+    /// # Arguments
+    ///
+    /// * `builder` - Recipient address ID and amount
+    pub async fn create_business_transfer(
+        &self,
+        builder: CreateBusinessTransferRequestBuilder,
+    ) -> CircleResult<BusinessTransfer> {
+        let request = builder.into_request(generate_uuid);
+        self.post("/v1/businessAccount/transfers", &request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        helper::GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER,
+        payments::{
+            dto::{Money, PaymentStatus},
+            ops::{
+                create_business_transfer::CreateBusinessTransferRequestBuilder,
+                create_payout::CreatePayoutRequestBuilder,
+            },
+        },
+        rates::dto::Currency,
+        testing::MockCircle,
+    };
+    use mockito::Matcher;
+    use serde_json::json;
+
+    #[test]
+    fn test_preview_create_payout() {
+        let preview = CreatePayoutRequestBuilder::new("bank-1".to_string(), Money::usd("10.00"))
+            .to_currency(Currency::Eur)
+            .preview()
+            .unwrap();
+        assert!(preview.contains(GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER));
+        assert!(preview.contains("\"type\": \"wire\""));
+        assert!(preview.contains("\"currency\": \"EUR\""));
+    }
+
+    #[tokio::test]
+    async fn test_create_payout() {
+        let mut circle = MockCircle::start().await;
+        let mock = circle
+            .server()
+            .mock("POST", "/v1/businessAccount/payouts")
+            .match_body(Matcher::PartialJson(json!({
+                "idempotencyKey": "payout-key",
+                "destination": { "type": "wire", "id": "bank-1" },
+                "amount": { "amount": "250.00", "currency": "USD" }
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({ "data": {
+                    "id": "payout-1",
+                    "sourceWalletId": "1000216185",
+                    "destination": { "type": "wire", "id": "bank-1" },
+                    "amount": { "amount": "250.00", "currency": "USD" },
+                    "status": "pending",
+                    "createDate": "2024-01-01T00:00:00Z"
+                }})
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let payout = circle
+            .ops()
+            .create_payout(
+                CreatePayoutRequestBuilder::new("bank-1".to_string(), Money::usd("250.00"))
+                    .idempotency_key("payout-key".to_string()),
+            )
+            .await
+            .unwrap();
+        assert_eq!(payout.id, "payout-1");
+        assert_eq!(payout.status, PaymentStatus::Pending);
+        assert_eq!(payout.amount, Money::usd("250.00"));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_create_business_transfer() {
+        let mut circle = MockCircle::start().await;
+        let mock = circle
+            .server()
+            .mock("POST", "/v1/businessAccount/transfers")
+            .match_body(Matcher::PartialJson(json!({
+                "destination": { "type": "verified_blockchain", "addressId": "address-1" },
+                "amount": { "amount": "5.00", "currency": "USD" }
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({ "data": {
+                    "id": "transfer-1",
+                    "source": { "type": "wallet", "id": "1000216185" },
+                    "destination": {
+                        "type": "blockchain",
+                        "address": "0x8381470ED67C3802402dbbFa0058E8871F017A6F",
+                        "chain": "ETH"
+                    },
+                    "amount": { "amount": "5.00", "currency": "USD" },
+                    "status": "pending",
+                    "createDate": "2024-01-01T00:00:00Z"
+                }})
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let transfer = circle
+            .ops()
+            .create_business_transfer(CreateBusinessTransferRequestBuilder::new(
+                "address-1".to_string(),
+                Money::usd("5.00"),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(transfer.destination.chain.as_deref(), Some("ETH"));
+        assert!(transfer.transaction_hash.is_none());
+        mock.assert_async().await;
+    }
+}
@@ -0,0 +1,184 @@
+//! Circle Mint payment read operations for CircleView
+
+use crate::{
+    circle_view::circle_view::CircleView,
+    helper::{page::Page, CircleResult},
+    payments::dto::{
+        BusinessBalances, BusinessTransfer, Deposit, ListPaymentsParams, Payout, WireBankAccount,
+        WireInstructions,
+    },
+    rates::dto::Currency,
+};
+
+impl CircleView {
+    /// Get the business account's available and unsettled balances
+    pub async fn get_business_balances(&self) -> CircleResult<BusinessBalances> {
+        self.get("/v1/businessAccount/balances").await
+    }
+
+    /// Get a linked wire bank account
+    ///
+    /// # Arguments
+    ///
+    /// * `bank_account_id` - The bank account ID returned when it was linked
+    pub async fn get_wire_bank_account(
+        &self,
+        bank_account_id: &str,
+    ) -> CircleResult<WireBankAccount> {
+        let path = format!("/v1/businessAccount/banks/wires/{}", bank_account_id);
+        self.get(&path).await
+    }
+
+    /// Get the instructions for wiring funds from a linked bank account
+    ///
+    /// Wires must include the returned tracking reference to be credited.
+    ///
+    /// # Arguments
+    ///
+    /// * `bank_account_id` - The linked bank account the wire will come from
+    /// * `currency` - Currency of the wire (e.g., USD or EUR)
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_view::circle_view::CircleView;
+    /// use inf_circle_sdk::rates::dto::Currency;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let view = CircleView::new()?;
+    ///
+    /// let instructions = view.get_wire_instructions("bank-account-id", Currency::Usd).await?;
+    /// println!(
+    ///     "Wire to {} account {} with reference {}",
+    ///     instructions.beneficiary_bank.name,
+    ///     instructions.beneficiary_bank.account_number,
+    ///     instructions.tracking_ref
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_wire_instructions(
+        &self,
+        bank_account_id: &str,
+        currency: Currency,
+    ) -> CircleResult<WireInstructions> {
+        let path = format!(
+            "/v1/businessAccount/banks/wires/{}/instructions",
+            bank_account_id
+        );
+        self.get_with_params(&path, &[("currency", currency.as_str())])
+            .await
+    }
+
+    /// List fiat deposits into the business account (bank to USDC)
+    ///
+    /// Returns a [`Page`] with the cursors of the neighbouring pages.
+    pub async fn list_deposits(&self, params: ListPaymentsParams) -> CircleResult<Page<Deposit>> {
+        let deposits = self
+            .get_with_params("/v1/businessAccount/deposits", &params)
+            .await?;
+        Ok(Page::new(deposits, &params.pagination))
+    }
+
+    /// List payouts from the business account (USDC to bank)
+    ///
+    /// Returns a [`Page`] with the cursors of the neighbouring pages.
+    pub async fn list_payouts(&self, params: ListPaymentsParams) -> CircleResult<Page<Payout>> {
+        let payouts = self
+            .get_with_params("/v1/businessAccount/payouts", &params)
+            .await?;
+        Ok(Page::new(payouts, &params.pagination))
+    }
+
+    /// Get a payout
+    pub async fn get_payout(&self, payout_id: &str) -> CircleResult<Payout> {
+        let path = format!("/v1/businessAccount/payouts/{}", payout_id);
+        self.get(&path).await
+    }
+
+    /// List on-chain transfers from the business account
+    ///
+    /// Returns a [`Page`] with the cursors of the neighbouring pages.
+    pub async fn list_business_transfers(
+        &self,
+        params: ListPaymentsParams,
+    ) -> CircleResult<Page<BusinessTransfer>> {
+        let transfers = self
+            .get_with_params("/v1/businessAccount/transfers", &params)
+            .await?;
+        Ok(Page::new(transfers, &params.pagination))
+    }
+
+    /// Get an on-chain transfer from the business account
+    pub async fn get_business_transfer(&self, transfer_id: &str) -> CircleResult<BusinessTransfer> {
+        let path = format!("/v1/businessAccount/transfers/{}", transfer_id);
+        self.get(&path).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        payments::dto::{ListPaymentsParams, Money, PaymentStatus},
+        testing::MockCircle,
+    };
+    use mockito::Matcher;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_get_business_balances() {
+        let mut circle = MockCircle::start().await;
+        circle
+            .respond(
+                "GET",
+                "/v1/businessAccount/balances",
+                &json!({
+                    "available": [{ "amount": "1200.50", "currency": "USD" }],
+                    "unsettled": []
+                }),
+            )
+            .await;
+
+        let balances = circle.view().get_business_balances().await.unwrap();
+        assert_eq!(balances.available, vec![Money::usd("1200.50")]);
+        assert!(balances.unsettled.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_list_deposits() {
+        let mut circle = MockCircle::start().await;
+        let mock = circle
+            .server()
+            .mock("GET", "/v1/businessAccount/deposits")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("type".into(), "wire".into()),
+                Matcher::UrlEncoded("pageSize".into(), "10".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({ "data": [{
+                    "id": "deposit-1",
+                    "sourceWalletId": "bank-1",
+                    "destination": { "type": "wallet", "id": "1000216185" },
+                    "amount": { "amount": "3000.00", "currency": "USD" },
+                    "fee": { "amount": "0.00", "currency": "USD" },
+                    "status": "complete",
+                    "createDate": "2024-01-01T00:00:00Z"
+                }]})
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let mut params = ListPaymentsParams {
+            payment_type: Some("wire".to_string()),
+            ..Default::default()
+        };
+        params.pagination.page_size = Some(10);
+        let deposits = circle.view().list_deposits(params).await.unwrap();
+        assert_eq!(deposits.len(), 1);
+        assert_eq!(deposits.items[0].status, PaymentStatus::Complete);
+        mock.assert_async().await;
+    }
+}
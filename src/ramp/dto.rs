@@ -0,0 +1,251 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::contract::dto::NotificationType;
+use crate::helper::{PaginationParams, ResourceId};
+use crate::payments::dto::Money;
+use crate::types::Blockchain;
+
+/// Direction of a ramp session
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RampType {
+    /// Fiat in, crypto delivered to the wallet (on-ramp)
+    Buy,
+    /// Crypto out of the wallet, fiat paid to the user (off-ramp)
+    Sell,
+}
+
+/// Which screen the hosted ramp flow opens on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RampMode {
+    /// The user picks the amount and sees a quote first
+    QuoteScreen,
+    /// The amount is fixed by the request and the user goes straight to payment
+    DepositScreen,
+}
+
+/// Lifecycle status of a ramp session
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RampSessionStatus {
+    Created,
+    KycSubmitted,
+    KycApproved,
+    KycRejected,
+    DepositReceived,
+    Completed,
+    Failed,
+    Expired,
+    /// A status this SDK version does not know yet
+    #[serde(other)]
+    Unknown,
+}
+
+impl RampSessionStatus {
+    /// Whether the session can no longer change
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            Self::KycRejected | Self::Completed | Self::Failed | Self::Expired
+        )
+    }
+}
+
+/// Wallet that receives (buy) or sends (sell) the crypto
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RampWalletAddress {
+    pub address: String,
+    pub blockchain: Blockchain,
+}
+
+/// Request structure for creating a ramp session
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateRampSessionRequest {
+    /// UUID v4 for idempotency
+    pub idempotency_key: String,
+
+    pub mode: RampMode,
+
+    pub ramp_type: RampType,
+
+    pub wallet_address: RampWalletAddress,
+
+    /// ISO 3166-1 alpha-2 country code of the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+
+    /// Fiat side of the trade
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fiat_amount: Option<Money>,
+
+    /// Crypto side of the trade
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crypto_amount: Option<Money>,
+
+    /// Where the hosted flow sends the user when it finishes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_url: Option<String>,
+
+    /// Your own reference for the session, echoed back in webhooks
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ref_id: Option<String>,
+}
+
+/// A ramp session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RampSession {
+    pub id: String,
+
+    pub status: RampSessionStatus,
+
+    pub ramp_type: RampType,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<RampMode>,
+
+    /// Hosted flow to send the user to; only returned while the session is open
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wallet_address: Option<RampWalletAddress>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fiat_amount: Option<Money>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crypto_amount: Option<Money>,
+
+    /// Fees charged for the trade
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fees: Option<Money>,
+
+    /// On-chain transaction delivering or collecting the crypto
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_hash: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ref_id: Option<String>,
+
+    /// Reason the session failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expire_date: Option<DateTime<Utc>>,
+
+    pub create_date: DateTime<Utc>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update_date: Option<DateTime<Utc>>,
+
+    /// Fields not modelled above
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl ResourceId for RampSession {
+    fn resource_id(&self) -> &str {
+        &self.id
+    }
+}
+
+/// Response structure for ramp session creation and lookups
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RampSessionResponse {
+    pub ramp_session: RampSession,
+}
+
+/// Response structure for listing ramp sessions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RampSessionsResponse {
+    pub ramp_sessions: Vec<RampSession>,
+}
+
+/// Parameters for listing ramp sessions
+#[derive(Debug, Clone, Serialize, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListRampSessionsParams {
+    /// Filter by status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<RampSessionStatus>,
+
+    /// Filter by direction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ramp_type: Option<RampType>,
+
+    /// Filter by your own reference
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ref_id: Option<String>,
+
+    /// Filter by creation date (from)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<DateTime<Utc>>,
+
+    /// Filter by creation date (to)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<DateTime<Utc>>,
+
+    /// Pagination parameters
+    #[serde(flatten)]
+    pub pagination: PaginationParams,
+}
+
+/// Envelope of a `rampSession.*` webhook notification
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RampSessionNotification {
+    /// Notification subscription that produced this notification
+    pub subscription_id: String,
+
+    /// Unique identifier of the notification
+    pub notification_id: String,
+
+    /// Type of the notification
+    pub notification_type: NotificationType,
+
+    /// Notification payload
+    pub notification: Value,
+
+    /// Timestamp of the notification
+    pub timestamp: DateTime<Utc>,
+
+    /// Payload version
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<u32>,
+}
+
+/// Typed payload of a `rampSession.*` notification
+#[derive(Debug, Clone)]
+pub enum RampSessionEvent {
+    KycSubmitted(RampSession),
+    KycApproved(RampSession),
+    KycRejected(RampSession),
+    DepositReceived(RampSession),
+    Completed(RampSession),
+    Failed(RampSession),
+    Expired(RampSession),
+}
+
+impl RampSessionEvent {
+    /// The session, whichever event it came from
+    pub fn session(&self) -> &RampSession {
+        match self {
+            Self::KycSubmitted(session)
+            | Self::KycApproved(session)
+            | Self::KycRejected(session)
+            | Self::DepositReceived(session)
+            | Self::Completed(session)
+            | Self::Failed(session)
+            | Self::Expired(session) => session,
+        }
+    }
+}
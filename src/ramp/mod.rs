@@ -0,0 +1,39 @@
+//! Ramp Sessions
+//!
+//! Ramp sessions hand a user off to a hosted flow that buys crypto into a
+//! wallet with fiat (on-ramp) or sells crypto from a wallet for fiat
+//! (off-ramp), including the KYC checks. This module creates and queries
+//! sessions, and provides typed payloads for the `rampSession.*` webhook
+//! notifications that report their progress.
+//!
+//! # Main Components
+//!
+//! - [`ramp_ops`]: Write operations (create a session)
+//! - [`ramp_view`]: Read operations (get and list sessions)
+//! - [`notification`]: [`parse_ramp_session_notification`] for webhook bodies
+//! - [`dto`]: Data transfer objects (sessions, statuses, notifications)
+//! - [`ops`]: Builder modules for write operations
+//!
+//! # Example - Handle a Webhook
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::ramp::{dto::RampSessionEvent, parse_ramp_session_notification};
+//!
+//! # fn example(body: &str) -> Result<(), Box<dyn std::error::Error>> {
+//! match parse_ramp_session_notification(body)?.1 {
+//!     RampSessionEvent::Completed(s) => println!("{} done: {:?}", s.id, s.transaction_hash),
+//!     RampSessionEvent::Failed(s) => println!("{} failed: {:?}", s.id, s.error_code),
+//!     other => println!("{} is now {:?}", other.session().id, other.session().status),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod dto;
+pub mod notification;
+pub mod ops;
+pub mod ramp_ops;
+pub mod ramp_view;
+
+// Re-export commonly used items
+pub use notification::parse_ramp_session_notification;
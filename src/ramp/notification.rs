@@ -0,0 +1,125 @@
+//! Ramp Session Webhook Parsing
+
+use crate::contract::dto::NotificationType;
+use crate::helper::{CircleError, CircleResult};
+use crate::ramp::dto::{RampSessionEvent, RampSessionNotification};
+
+/// Parse a `rampSession.*` webhook body into its envelope and typed payload
+///
+/// # Errors
+///
+/// Returns `CircleError::Config` if the notification is not a ramp session
+/// notification, or `CircleError::Json` if the payload does not match its type.
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::ramp::{parse_ramp_session_notification, dto::RampSessionEvent};
+///
+/// let body = r#"{
+///     "subscriptionId": "sub-1",
+///     "notificationId": "n-1",
+///     "notificationType": "rampSession.completed",
+///     "notification": {
+///         "id": "ramp-1",
+///         "status": "COMPLETED",
+///         "rampType": "BUY",
+///         "cryptoAmount": {"amount": "99.10", "currency": "USDC"},
+///         "transactionHash": "0xabc",
+///         "createDate": "2025-01-01T00:00:00Z"
+///     },
+///     "timestamp": "2025-01-01T00:05:00Z",
+///     "version": 2
+/// }"#;
+///
+/// let (_, event) = parse_ramp_session_notification(body)?;
+/// assert!(matches!(event, RampSessionEvent::Completed(ref s) if s.transaction_hash.as_deref() == Some("0xabc")));
+/// # Ok::<(), inf_circle_sdk::helper::CircleError>(())
+/// ```
+pub fn parse_ramp_session_notification(
+    body: &str,
+) -> CircleResult<(RampSessionNotification, RampSessionEvent)> {
+    let notification: RampSessionNotification = serde_json::from_str(body)?;
+    let payload = notification.notification.clone();
+
+    let event = match notification.notification_type {
+        NotificationType::RampSessionKycSubmitted => {
+            RampSessionEvent::KycSubmitted(serde_json::from_value(payload)?)
+        }
+        NotificationType::RampSessionKycApproved => {
+            RampSessionEvent::KycApproved(serde_json::from_value(payload)?)
+        }
+        NotificationType::RampSessionKycRejected => {
+            RampSessionEvent::KycRejected(serde_json::from_value(payload)?)
+        }
+        NotificationType::RampSessionDepositReceived => {
+            RampSessionEvent::DepositReceived(serde_json::from_value(payload)?)
+        }
+        NotificationType::RampSessionCompleted => {
+            RampSessionEvent::Completed(serde_json::from_value(payload)?)
+        }
+        NotificationType::RampSessionFailed => {
+            RampSessionEvent::Failed(serde_json::from_value(payload)?)
+        }
+        NotificationType::RampSessionExpired => {
+            RampSessionEvent::Expired(serde_json::from_value(payload)?)
+        }
+        ref other => {
+            return Err(CircleError::Config(format!(
+                "Not a ramp session notification: {}",
+                other.as_str()
+            )))
+        }
+    };
+
+    Ok((notification, event))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ramp::dto::RampSessionStatus;
+
+    #[test]
+    fn test_parse_kyc_rejected_notification() {
+        let body = r#"{
+            "subscriptionId": "sub-1",
+            "notificationId": "n-1",
+            "notificationType": "rampSession.kycRejected",
+            "notification": {
+                "id": "ramp-1",
+                "status": "KYC_REJECTED",
+                "rampType": "BUY",
+                "refId": "order-42",
+                "provider": "example",
+                "createDate": "2025-01-01T00:00:00Z"
+            },
+            "timestamp": "2025-01-01T00:01:00Z"
+        }"#;
+
+        let (envelope, event) = parse_ramp_session_notification(body).unwrap();
+        assert_eq!(envelope.notification_id, "n-1");
+        let RampSessionEvent::KycRejected(session) = event else {
+            panic!("expected KYC rejected event");
+        };
+        assert_eq!(session.status, RampSessionStatus::KycRejected);
+        assert!(session.status.is_terminal());
+        assert_eq!(session.ref_id.as_deref(), Some("order-42"));
+        assert_eq!(session.extra["provider"], "example");
+    }
+
+    #[test]
+    fn test_rejects_other_notification_types() {
+        let body = r#"{
+            "subscriptionId": "sub-1",
+            "notificationId": "n-1",
+            "notificationType": "rampSession.*",
+            "notification": {},
+            "timestamp": "2025-01-01T00:00:00Z"
+        }"#;
+        assert!(matches!(
+            parse_ramp_session_notification(body),
+            Err(CircleError::Config(_))
+        ));
+    }
+}
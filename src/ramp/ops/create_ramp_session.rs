@@ -0,0 +1,166 @@
+use crate::helper::{
+    preview_request, CircleError, CircleResult, GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER,
+};
+use crate::payments::dto::Money;
+use crate::ramp::dto::{CreateRampSessionRequest, RampMode, RampType, RampWalletAddress};
+use crate::types::Blockchain;
+
+/// Builder for creating a ramp session
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use inf_circle_sdk::payments::dto::Money;
+/// use inf_circle_sdk::ramp::{dto::RampType, ops::create_ramp_session::CreateRampSessionRequestBuilder};
+/// use inf_circle_sdk::types::Blockchain;
+///
+/// let builder = CreateRampSessionRequestBuilder::new(
+///     RampType::Buy,
+///     "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb0".to_string(),
+///     Blockchain::Base,
+/// )
+/// .fiat_amount(Money::usd("100.00"))
+/// .country("US".to_string())
+/// .ref_id("order-42".to_string())
+/// .build();
+/// ```
+#[derive(Clone, Debug)]
+pub struct CreateRampSessionRequestBuilder {
+    pub ramp_type: RampType,
+    pub mode: RampMode,
+    pub address: String,
+    pub blockchain: Blockchain,
+    pub country: Option<String>,
+    pub fiat_amount: Option<Money>,
+    pub crypto_amount: Option<Money>,
+    pub redirect_url: Option<String>,
+    pub ref_id: Option<String>,
+    pub idempotency_key: Option<String>,
+}
+
+impl CreateRampSessionRequestBuilder {
+    /// Create a new builder with required fields
+    ///
+    /// The session opens on the quote screen unless [`Self::mode`] says otherwise.
+    ///
+    /// # Arguments
+    /// * `ramp_type` - Buy (on-ramp) or sell (off-ramp)
+    /// * `address` - Wallet that receives or sends the crypto
+    /// * `blockchain` - Blockchain of `address`
+    pub fn new(ramp_type: RampType, address: String, blockchain: Blockchain) -> Self {
+        Self {
+            ramp_type,
+            mode: RampMode::QuoteScreen,
+            address,
+            blockchain,
+            country: None,
+            fiat_amount: None,
+            crypto_amount: None,
+            redirect_url: None,
+            ref_id: None,
+            idempotency_key: None,
+        }
+    }
+
+    /// Set the screen the hosted flow opens on
+    pub fn mode(mut self, mode: RampMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set the user's country (ISO 3166-1 alpha-2)
+    pub fn country(mut self, country: String) -> Self {
+        self.country = Some(country);
+        self
+    }
+
+    /// Set the fiat amount to pay (buy) or receive (sell)
+    pub fn fiat_amount(mut self, amount: Money) -> Self {
+        self.fiat_amount = Some(amount);
+        self
+    }
+
+    /// Set the crypto amount to receive (buy) or send (sell)
+    pub fn crypto_amount(mut self, amount: Money) -> Self {
+        self.crypto_amount = Some(amount);
+        self
+    }
+
+    /// Set where the hosted flow sends the user when it finishes
+    pub fn redirect_url(mut self, url: String) -> Self {
+        self.redirect_url = Some(url);
+        self
+    }
+
+    /// Set your own reference for the session
+    pub fn ref_id(mut self, ref_id: String) -> Self {
+        self.ref_id = Some(ref_id);
+        self
+    }
+
+    /// Set a custom idempotency key
+    ///
+    /// If not set, a UUID is generated when the request is sent.
+    pub fn idempotency_key(mut self, key: String) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
+
+    /// Check the builder before sending
+    ///
+    /// Deposit-screen sessions skip the quote, so they need exactly one of
+    /// the fiat or crypto amount.
+    pub fn validate(&self) -> CircleResult<()> {
+        if self.mode == RampMode::DepositScreen
+            && self.fiat_amount.is_some() == self.crypto_amount.is_some()
+        {
+            return Err(CircleError::Config(
+                "Deposit screen ramp sessions need exactly one of fiat_amount or crypto_amount"
+                    .to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Convert the builder into the API request body
+    ///
+    /// `generate_idempotency_key` is only called when no custom key was set.
+    pub(crate) fn into_request(
+        self,
+        generate_idempotency_key: impl FnOnce() -> String,
+    ) -> CreateRampSessionRequest {
+        CreateRampSessionRequest {
+            idempotency_key: self
+                .idempotency_key
+                .unwrap_or_else(generate_idempotency_key),
+            mode: self.mode,
+            ramp_type: self.ramp_type,
+            wallet_address: RampWalletAddress {
+                address: self.address,
+                blockchain: self.blockchain,
+            },
+            country: self.country,
+            fiat_amount: self.fiat_amount,
+            crypto_amount: self.crypto_amount,
+            redirect_url: self.redirect_url,
+            ref_id: self.ref_id,
+        }
+    }
+
+    /// Preview the request body as pretty JSON
+    ///
+    /// When no idempotency key was set, the key is shown as
+    /// [`GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER`].
+    pub fn preview(&self) -> CircleResult<String> {
+        preview_request(
+            &self
+                .clone()
+                .into_request(|| GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER.to_string()),
+        )
+    }
+
+    /// Build the CreateRampSessionRequestBuilder
+    pub fn build(self) -> CreateRampSessionRequestBuilder {
+        self
+    }
+}
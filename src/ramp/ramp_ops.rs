@@ -0,0 +1,140 @@
+//! Ramp session write operations for CircleOps
+
+use crate::{
+    circle_ops::circler_ops::CircleOps,
+    helper::{generate_uuid, CircleResult},
+    ramp::{dto::RampSessionResponse, ops::create_ramp_session::CreateRampSessionRequestBuilder},
+};
+
+impl CircleOps {
+    /// Create a ramp session
+    ///
+    /// Returns the session with the URL of the hosted buy or sell flow to send
+    /// the user to. Progress arrives through the `rampSession.*` webhooks or
+    /// [`CircleView::get_ramp_session`](crate::circle_view::circle_view::CircleView::get_ramp_session).
+    ///
+    /// # Arguments
+    ///
+    /// * `builder` - Direction, wallet, amounts and optional references
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+    /// use inf_circle_sdk::payments::dto::Money;
+    /// use inf_circle_sdk::ramp::{dto::RampType, ops::create_ramp_session::CreateRampSessionRequestBuilder};
+    /// use inf_circle_sdk::types::Blockchain;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ops = CircleOps::new(None)?;
+    ///
+    /// let builder = CreateRampSessionRequestBuilder::new(
+    ///     RampType::Buy,
+    ///     "0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb0".to_string(),
+    ///     Blockchain::Base,
+    /// )
+    /// .fiat_amount(Money::usd("100.00"))
+    /// .build();
+    ///
+    /// let session = ops.create_ramp_session(builder).await?.ramp_session;
+    /// println!("Send the user to {:?}", session.url);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_ramp_session(
+        &self,
+        builder: CreateRampSessionRequestBuilder,
+    ) -> CircleResult<RampSessionResponse> {
+        builder.validate()?;
+        let request = builder.into_request(generate_uuid);
+        self.post("/v1/w3s/ramp/sessions", &request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        helper::CircleError,
+        payments::dto::Money,
+        ramp::{
+            dto::{RampMode, RampSessionStatus, RampType},
+            ops::create_ramp_session::CreateRampSessionRequestBuilder,
+        },
+        rates::dto::Currency,
+        testing::{fixtures, MockCircle},
+        types::Blockchain,
+    };
+    use mockito::Matcher;
+    use serde_json::json;
+
+    fn builder() -> CreateRampSessionRequestBuilder {
+        CreateRampSessionRequestBuilder::new(
+            RampType::Buy,
+            fixtures::WALLET_ADDRESS.to_string(),
+            Blockchain::BaseSepolia,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_create_ramp_session() {
+        let mut circle = MockCircle::start().await;
+        let mock = circle
+            .server()
+            .mock("POST", "/v1/w3s/ramp/sessions")
+            .match_body(Matcher::PartialJson(json!({
+                "mode": "DEPOSIT_SCREEN",
+                "rampType": "BUY",
+                "walletAddress": {
+                    "address": fixtures::WALLET_ADDRESS,
+                    "blockchain": "BASE-SEPOLIA"
+                },
+                "fiatAmount": { "amount": "100.00", "currency": "USD" }
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({ "data": { "rampSession": {
+                    "id": "ramp-1",
+                    "status": "CREATED",
+                    "rampType": "BUY",
+                    "url": "https://ramp.example/session/ramp-1",
+                    "createDate": "2025-01-01T00:00:00Z"
+                }}})
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let session = circle
+            .ops()
+            .create_ramp_session(
+                builder()
+                    .mode(RampMode::DepositScreen)
+                    .fiat_amount(Money::usd("100.00"))
+                    .build(),
+            )
+            .await
+            .unwrap()
+            .ramp_session;
+        assert_eq!(session.status, RampSessionStatus::Created);
+        assert!(!session.status.is_terminal());
+        assert!(session.url.is_some());
+        mock.assert_async().await;
+    }
+
+    #[test]
+    fn test_deposit_screen_needs_one_amount() {
+        let deposit = builder().mode(RampMode::DepositScreen);
+        assert!(matches!(deposit.validate(), Err(CircleError::Config(_))));
+        let both = deposit
+            .clone()
+            .fiat_amount(Money::usd("100"))
+            .crypto_amount(Money::new("100", Currency::Usdc));
+        assert!(both.validate().is_err());
+        assert!(deposit
+            .crypto_amount(Money::new("100", Currency::Usdc))
+            .validate()
+            .is_ok());
+        assert!(builder().validate().is_ok());
+    }
+}
@@ -0,0 +1,96 @@
+//! Ramp session read operations for CircleView
+
+use crate::{
+    circle_view::circle_view::CircleView,
+    helper::{page::Page, CircleResult},
+    ramp::dto::{ListRampSessionsParams, RampSession, RampSessionResponse, RampSessionsResponse},
+};
+
+impl CircleView {
+    /// Get a ramp session
+    ///
+    /// # Arguments
+    ///
+    /// * `session_id` - The session ID returned on creation
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_view::circle_view::CircleView;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let view = CircleView::new()?;
+    ///
+    /// let session = view.get_ramp_session("ramp-session-id").await?.ramp_session;
+    /// if session.status.is_terminal() {
+    ///     println!("Finished as {:?}", session.status);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_ramp_session(&self, session_id: &str) -> CircleResult<RampSessionResponse> {
+        let path = format!("/v1/w3s/ramp/sessions/{}", session_id);
+        self.get(&path).await
+    }
+
+    /// List ramp sessions as a [`Page`] with the cursors of the neighbouring pages
+    pub async fn list_ramp_sessions(
+        &self,
+        params: ListRampSessionsParams,
+    ) -> CircleResult<Page<RampSession>> {
+        let response: RampSessionsResponse = self
+            .get_with_params("/v1/w3s/ramp/sessions", &params)
+            .await?;
+        Ok(Page::new(response.ramp_sessions, &params.pagination))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ramp::dto::{ListRampSessionsParams, RampSessionStatus, RampType},
+        testing::MockCircle,
+    };
+    use mockito::Matcher;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_list_ramp_sessions() {
+        let mut circle = MockCircle::start().await;
+        let mock = circle
+            .server()
+            .mock("GET", "/v1/w3s/ramp/sessions")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("status".into(), "DEPOSIT_RECEIVED".into()),
+                Matcher::UrlEncoded("rampType".into(), "SELL".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({ "data": { "rampSessions": [{
+                    "id": "ramp-1",
+                    "status": "DEPOSIT_RECEIVED",
+                    "rampType": "SELL",
+                    "cryptoAmount": { "amount": "50", "currency": "USDC" },
+                    "createDate": "2025-01-01T00:00:00Z"
+                }]}})
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let sessions = circle
+            .view()
+            .list_ramp_sessions(ListRampSessionsParams {
+                status: Some(RampSessionStatus::DepositReceived),
+                ramp_type: Some(RampType::Sell),
+                ..Default::default()
+            })
+            .await
+            .unwrap()
+            .items;
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions[0].crypto_amount.as_ref().unwrap().amount, "50");
+        mock.assert_async().await;
+    }
+}
@@ -0,0 +1,514 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::dev_wallet::dto::Transaction;
+use crate::helper::{generate_uuid, CircleError, CircleResult};
+use crate::types::Stablecoin;
+
+/// A fiat currency or Circle stablecoin
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(from = "String")]
+pub enum Currency {
+    Usd,
+    Eur,
+    Usdc,
+    Eurc,
+    /// A currency code this SDK version does not know yet
+    Other(String),
+}
+
+impl Currency {
+    /// ISO 4217 code or token symbol (e.g., "USD", "USDC")
+    pub fn as_str(&self) -> &str {
+        match self {
+            Currency::Usd => "USD",
+            Currency::Eur => "EUR",
+            Currency::Usdc => "USDC",
+            Currency::Eurc => "EURC",
+            Currency::Other(code) => code,
+        }
+    }
+
+    /// Decimal places used when displaying converted amounts
+    ///
+    /// Fiat currencies use cents; stablecoins and unknown currencies use the
+    /// 6 decimals of Circle's stablecoins.
+    pub fn display_decimals(&self) -> u32 {
+        match self {
+            Currency::Usd | Currency::Eur => 2,
+            _ => 6,
+        }
+    }
+}
+
+impl From<&str> for Currency {
+    fn from(code: &str) -> Self {
+        match code {
+            "USD" => Currency::Usd,
+            "EUR" => Currency::Eur,
+            "USDC" => Currency::Usdc,
+            "EURC" => Currency::Eurc,
+            other => Currency::Other(other.to_string()),
+        }
+    }
+}
+
+impl From<String> for Currency {
+    fn from(code: String) -> Self {
+        Currency::from(code.as_str())
+    }
+}
+
+impl From<Stablecoin> for Currency {
+    fn from(stablecoin: Stablecoin) -> Self {
+        match stablecoin {
+            Stablecoin::Usdc => Currency::Usdc,
+            Stablecoin::Eurc => Currency::Eurc,
+        }
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for Currency {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// A currency pair; rates give the amount of `to` per unit of `from`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CurrencyPair {
+    pub from: Currency,
+    pub to: Currency,
+}
+
+impl CurrencyPair {
+    /// Create a pair
+    pub fn new(from: impl Into<Currency>, to: impl Into<Currency>) -> Self {
+        Self {
+            from: from.into(),
+            to: to.into(),
+        }
+    }
+
+    /// The pair with `from` and `to` swapped
+    pub fn inverse(&self) -> Self {
+        Self {
+            from: self.to.clone(),
+            to: self.from.clone(),
+        }
+    }
+}
+
+impl fmt::Display for CurrencyPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.from, self.to)
+    }
+}
+
+/// Quote type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QuoteType {
+    /// Indicative rate that cannot be traded on
+    Reference,
+    /// Rate that can be locked for a trade until the quote expires
+    Tradable,
+}
+
+/// One side of an exchange quote
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuoteLeg {
+    pub currency: Currency,
+
+    /// Decimal amount; omitted on the side Circle calculates
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "optional_decimal"
+    )]
+    pub amount: Option<String>,
+}
+
+/// Request structure for creating an exchange quote
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeQuoteRequest {
+    #[serde(rename = "type")]
+    pub quote_type: QuoteType,
+
+    /// UUID v4 for idempotency
+    pub idempotency_key: String,
+
+    pub from: QuoteLeg,
+
+    pub to: QuoteLeg,
+}
+
+impl ExchangeQuoteRequest {
+    /// Request a reference quote for converting `amount` of `pair.from`
+    pub fn reference(pair: &CurrencyPair, amount: impl Into<String>) -> Self {
+        Self {
+            quote_type: QuoteType::Reference,
+            idempotency_key: generate_uuid(),
+            from: QuoteLeg {
+                currency: pair.from.clone(),
+                amount: Some(amount.into()),
+            },
+            to: QuoteLeg {
+                currency: pair.to.clone(),
+                amount: None,
+            },
+        }
+    }
+}
+
+/// Exchange quote returned by Circle
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeQuote {
+    #[serde(default)]
+    pub id: Option<String>,
+
+    /// Amount of `to` per unit of `from`
+    #[serde(deserialize_with = "decimal")]
+    pub rate: String,
+
+    pub from: QuoteLeg,
+
+    pub to: QuoteLeg,
+
+    /// When a tradable quote stops being honored
+    #[serde(default)]
+    pub expiry: Option<DateTime<Utc>>,
+
+    #[serde(default, rename = "type")]
+    pub quote_type: Option<QuoteType>,
+}
+
+/// An exchange rate for a currency pair
+///
+/// Conversions use exact decimal arithmetic, so converted amounts do not pick
+/// up floating point noise.
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::rates::dto::{Currency, CurrencyPair, ExchangeRate};
+///
+/// let rate = ExchangeRate::new(CurrencyPair::new(Currency::Usdc, Currency::Eur), "0.9215");
+/// assert_eq!(rate.convert("25.5").unwrap(), "23.50");
+///
+/// // 100 USDC reported as 100.40 USD is within 0.5% of the 1:1 rate
+/// let usd = ExchangeRate::new(CurrencyPair::new(Currency::Usdc, Currency::Usd), "1");
+/// assert!(usd.agrees_with("100", "100.40", 50).unwrap());
+/// assert!(!usd.agrees_with("100", "101", 50).unwrap());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExchangeRate {
+    pub pair: CurrencyPair,
+
+    /// Amount of `pair.to` per unit of `pair.from`
+    pub rate: String,
+
+    /// When the rate was observed
+    pub observed_at: DateTime<Utc>,
+}
+
+impl ExchangeRate {
+    /// Create a rate observed now
+    pub fn new(pair: CurrencyPair, rate: impl Into<String>) -> Self {
+        Self {
+            pair,
+            rate: rate.into(),
+            observed_at: Utc::now(),
+        }
+    }
+
+    /// Convert `amount` of `pair.from` into `pair.to`
+    ///
+    /// The result is rounded half-up to the target currency's
+    /// [`display_decimals`](Currency::display_decimals).
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Config` if the amount or rate is not a plain
+    /// non-negative decimal, or the product is too large.
+    pub fn convert(&self, amount: &str) -> CircleResult<String> {
+        let converted = self.product(amount)?;
+        Ok(converted.round(self.pair.to.display_decimals()).to_string())
+    }
+
+    /// Whether `converted` is within `tolerance_bps` basis points of `amount` at this rate
+    ///
+    /// Use this to cross-check a reported conversion, such as a transaction's
+    /// `amountInUSD`, against an independently fetched rate.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Config` if an amount or the rate is not a plain
+    /// non-negative decimal, or the numbers are too large.
+    pub fn agrees_with(
+        &self,
+        amount: &str,
+        converted: &str,
+        tolerance_bps: u32,
+    ) -> CircleResult<bool> {
+        let expected = self.product(amount)?;
+        let actual = Decimal::parse(converted)?;
+        let scale = expected.scale.max(actual.scale);
+        let (expected, actual) = (expected.rescale(scale)?, actual.rescale(scale)?);
+
+        let difference = expected.abs_diff(actual);
+        let allowed = expected
+            .checked_mul(u128::from(tolerance_bps))
+            .ok_or_else(overflow)?;
+        Ok(difference.checked_mul(10_000).ok_or_else(overflow)? <= allowed)
+    }
+
+    /// Cross-check a transaction's `amountInUSD` against this rate
+    ///
+    /// Sums the transaction's amounts (in `pair.from`) and compares their value
+    /// at this rate with `amountInUSD`. Returns `None` if the pair does not
+    /// convert into USD or the transaction has no amounts or USD value.
+    pub fn check_amount_in_usd(
+        &self,
+        transaction: &Transaction,
+        tolerance_bps: u32,
+    ) -> CircleResult<Option<bool>> {
+        if self.pair.to != Currency::Usd {
+            return Ok(None);
+        }
+        let (Some(amounts), Some(amount_in_usd)) =
+            (&transaction.amounts, &transaction.amount_in_usd)
+        else {
+            return Ok(None);
+        };
+        if amounts.is_empty() {
+            return Ok(None);
+        }
+
+        let mut total = Decimal::parse("0")?;
+        for amount in amounts {
+            total = total.checked_add(Decimal::parse(amount)?)?;
+        }
+        self.agrees_with(&total.to_string(), amount_in_usd, tolerance_bps)
+            .map(Some)
+    }
+
+    fn product(&self, amount: &str) -> CircleResult<Decimal> {
+        Decimal::parse(amount)?.checked_mul(Decimal::parse(&self.rate)?)
+    }
+}
+
+/// Non-negative decimal as an integer mantissa and a power-of-ten scale
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Decimal {
+    mantissa: u128,
+    scale: u32,
+}
+
+impl Decimal {
+    pub(crate) fn parse(value: &str) -> CircleResult<Self> {
+        let invalid = || CircleError::Config(format!("Invalid decimal amount: {}", value));
+        let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+        if (whole.is_empty() && fraction.is_empty())
+            || !whole
+                .chars()
+                .chain(fraction.chars())
+                .all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+        let digits = format!("{}{}", whole, fraction);
+        let digits = digits.trim_start_matches('0');
+        let mantissa = if digits.is_empty() {
+            0
+        } else {
+            digits.parse().map_err(|_| overflow())?
+        };
+        Ok(Self {
+            mantissa,
+            scale: fraction.len() as u32,
+        })
+    }
+
+    pub(crate) fn rescale(self, scale: u32) -> CircleResult<u128> {
+        10u128
+            .checked_pow(scale - self.scale)
+            .and_then(|factor| self.mantissa.checked_mul(factor))
+            .ok_or_else(overflow)
+    }
+
+    pub(crate) fn checked_add(self, other: Decimal) -> CircleResult<Self> {
+        let scale = self.scale.max(other.scale);
+        let mantissa = self
+            .rescale(scale)?
+            .checked_add(other.rescale(scale)?)
+            .ok_or_else(overflow)?;
+        Ok(Self { mantissa, scale })
+    }
+
+    pub(crate) fn checked_mul(self, other: Decimal) -> CircleResult<Self> {
+        Ok(Self {
+            mantissa: self
+                .mantissa
+                .checked_mul(other.mantissa)
+                .ok_or_else(overflow)?,
+            scale: self.scale + other.scale,
+        })
+    }
+
+    /// Divide by `10^places`
+    pub(crate) fn shift_right(self, places: u32) -> Self {
+        Self {
+            mantissa: self.mantissa,
+            scale: self.scale + places,
+        }
+    }
+
+    /// Drop trailing zeros after the decimal point
+    pub(crate) fn normalize(mut self) -> Self {
+        while self.scale > 0 && self.mantissa.is_multiple_of(10) {
+            self.mantissa /= 10;
+            self.scale -= 1;
+        }
+        self
+    }
+
+    /// Round half-up to `digits` significant digits, keeping every digit before the point
+    pub(crate) fn round_significant(self, digits: u32) -> Self {
+        let len = self.mantissa.checked_ilog10().map_or(1, |log| log + 1);
+        let excess = len.saturating_sub(digits.max(1));
+        self.round(self.scale.saturating_sub(excess))
+    }
+
+    /// Round half-up to `scale` decimal places (never adds precision)
+    pub(crate) fn round(self, scale: u32) -> Self {
+        if self.scale <= scale {
+            return self;
+        }
+        let factor = 10u128.pow(self.scale - scale);
+        let mut mantissa = self.mantissa / factor;
+        if (self.mantissa % factor).cmp(&(factor / 2)) != Ordering::Less {
+            mantissa += 1;
+        }
+        Self { mantissa, scale }
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let scale = self.scale as usize;
+        let digits = format!("{:0>width$}", self.mantissa, width = scale + 1);
+        let (whole, fraction) = digits.split_at(digits.len() - scale);
+        if fraction.is_empty() {
+            f.write_str(whole)
+        } else {
+            write!(f, "{}.{}", whole, fraction)
+        }
+    }
+}
+
+fn overflow() -> CircleError {
+    CircleError::Config("Decimal amount is too large to convert".to_string())
+}
+
+/// Accept a decimal given as a JSON number or string
+fn decimal<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::String(s) => Ok(s),
+        serde_json::Value::Number(n) => Ok(n.to_string()),
+        other => Err(serde::de::Error::custom(format!(
+            "expected a decimal, got {}",
+            other
+        ))),
+    }
+}
+
+fn optional_decimal<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::Null => Ok(None),
+        serde_json::Value::String(s) => Ok(Some(s)),
+        serde_json::Value::Number(n) => Ok(Some(n.to_string())),
+        other => Err(serde::de::Error::custom(format!(
+            "expected a decimal, got {}",
+            other
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures;
+
+    #[test]
+    fn test_convert_rounds_to_target_decimals() {
+        let pair = CurrencyPair::new(Currency::Eur, Currency::Usdc);
+        let rate = ExchangeRate::new(pair.clone(), "1.085125");
+        assert_eq!(rate.convert("10").unwrap(), "10.851250");
+        assert_eq!(rate.convert("0.000001").unwrap(), "0.000001");
+        assert_eq!(rate.convert("0").unwrap(), "0.000000");
+
+        let rate = ExchangeRate::new(pair.inverse(), "0.92155");
+        assert_eq!(rate.pair.to, Currency::Eur);
+        assert_eq!(rate.convert("1").unwrap(), "0.92");
+        assert_eq!(rate.convert("10").unwrap(), "9.22");
+        assert_eq!(rate.convert(".5").unwrap(), "0.46");
+
+        for amount in ["", ".", "-1", "1e6", "1,000"] {
+            assert!(rate.convert(amount).is_err(), "{}", amount);
+        }
+        assert_eq!(pair.to_string(), "EUR/USDC");
+    }
+
+    #[test]
+    fn test_check_amount_in_usd() {
+        let mut transaction = fixtures::transaction();
+        transaction.amounts = Some(vec!["60".to_string(), "40.5".to_string()]);
+        transaction.amount_in_usd = Some("100.45".to_string());
+
+        let rate = ExchangeRate::new(CurrencyPair::new(Currency::Usdc, Currency::Usd), "0.9998");
+        assert_eq!(
+            rate.check_amount_in_usd(&transaction, 10).unwrap(),
+            Some(true)
+        );
+        assert_eq!(
+            rate.check_amount_in_usd(&transaction, 1).unwrap(),
+            Some(false)
+        );
+
+        let eur = ExchangeRate::new(CurrencyPair::new(Currency::Usdc, Currency::Eur), "0.92");
+        assert_eq!(eur.check_amount_in_usd(&transaction, 10).unwrap(), None);
+
+        transaction.amount_in_usd = None;
+        assert_eq!(rate.check_amount_in_usd(&transaction, 10).unwrap(), None);
+    }
+
+    #[test]
+    fn test_currency_serde() {
+        let currency: Currency = serde_json::from_str("\"EURC\"").unwrap();
+        assert_eq!(currency, Currency::from(Stablecoin::Eurc));
+        let other: Currency = serde_json::from_str("\"GBP\"").unwrap();
+        assert_eq!(other, Currency::Other("GBP".to_string()));
+        assert_eq!(serde_json::to_string(&other).unwrap(), "\"GBP\"");
+    }
+}
@@ -0,0 +1,32 @@
+//! Exchange Rates
+//!
+//! This module wraps Circle's exchange quote endpoint so amounts can be shown
+//! in another currency, and so the `amountInUSD` Circle reports on
+//! transactions can be cross-checked against an independently fetched rate.
+//! Pairs are typed ([`CurrencyPair`](dto::CurrencyPair)) and conversions use
+//! exact decimal arithmetic.
+//!
+//! # Main Components
+//!
+//! - [`dto`]: Data transfer objects (currencies, pairs, quotes and rates)
+//! - [`rates_view`]: Read operations (reference quotes and exchange rates)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::circle_view::circle_view::CircleView;
+//! use inf_circle_sdk::rates::dto::{Currency, CurrencyPair};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let view = CircleView::new()?;
+//!
+//! let rate = view
+//!     .get_exchange_rate(&CurrencyPair::new(Currency::Usdc, Currency::Eur))
+//!     .await?;
+//! println!("25 USDC = {} EUR", rate.convert("25")?);
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod dto;
+pub mod rates_view;
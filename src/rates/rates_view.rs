@@ -0,0 +1,102 @@
+//! Exchange rate read operations for CircleView
+
+use chrono::Utc;
+
+use crate::{
+    circle_view::circle_view::CircleView,
+    helper::CircleResult,
+    rates::dto::{CurrencyPair, ExchangeQuote, ExchangeQuoteRequest, ExchangeRate},
+};
+
+impl CircleView {
+    /// Request an exchange quote
+    ///
+    /// Reference quotes are indicative only; use
+    /// [`ExchangeQuoteRequest::reference`] to build one.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The currencies and amount to quote
+    pub async fn get_exchange_quote(
+        &self,
+        request: ExchangeQuoteRequest,
+    ) -> CircleResult<ExchangeQuote> {
+        self.post("/v1/exchange/quotes", &request).await
+    }
+
+    /// Get the current reference rate for a currency pair
+    ///
+    /// Requests a reference quote for one unit of `pair.from`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_view::circle_view::CircleView;
+    /// use inf_circle_sdk::rates::dto::{Currency, CurrencyPair};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let view = CircleView::new()?;
+    ///
+    /// let transaction = view.get_transaction("transaction-id").await?.transaction;
+    /// let rate = view
+    ///     .get_exchange_rate(&CurrencyPair::new(Currency::Usdc, Currency::Usd))
+    ///     .await?;
+    /// if rate.check_amount_in_usd(&transaction, 50)? == Some(false) {
+    ///     println!("amountInUSD is more than 0.5% off the current rate");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_exchange_rate(&self, pair: &CurrencyPair) -> CircleResult<ExchangeRate> {
+        let quote = self
+            .get_exchange_quote(ExchangeQuoteRequest::reference(pair, "1"))
+            .await?;
+        Ok(ExchangeRate {
+            pair: pair.clone(),
+            rate: quote.rate,
+            observed_at: Utc::now(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rates::dto::{Currency, CurrencyPair};
+    use crate::testing::MockCircle;
+    use mockito::Matcher;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_get_exchange_rate() {
+        let mut circle = MockCircle::start().await;
+        let mock = circle
+            .server()
+            .mock("POST", "/v1/exchange/quotes")
+            .match_body(Matcher::PartialJson(json!({
+                "type": "reference",
+                "from": { "currency": "USDC", "amount": "1" },
+                "to": { "currency": "EUR" }
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({ "data": {
+                    "id": "quote-1",
+                    "rate": 0.9215,
+                    "from": { "currency": "USDC", "amount": 1 },
+                    "to": { "currency": "EUR", "amount": 0.9215 },
+                    "type": "reference"
+                }})
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let pair = CurrencyPair::new(Currency::Usdc, Currency::Eur);
+        let rate = circle.view().get_exchange_rate(&pair).await.unwrap();
+        assert_eq!(rate.pair, pair);
+        assert_eq!(rate.rate, "0.9215");
+        assert_eq!(rate.convert("1000").unwrap(), "921.50");
+        mock.assert_async().await;
+    }
+}
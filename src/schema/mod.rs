@@ -0,0 +1,278 @@
+//! Response Schema Validation
+//!
+//! Available with the `schema-validation` feature. Successful responses are
+//! checked against a vendored subset of Circle's OpenAPI response schemas
+//! before they are deserialized. When Circle changes the type of a field, the
+//! call fails with [`CircleError::Schema`] listing every offending JSON
+//! pointer, instead of a serde error that only names the first mismatch.
+//!
+//! Validation is opt-in per client, so enabling the feature (possibly through
+//! another crate in the build) changes nothing on its own. Clients built with
+//! `with_schema_validation` check every response whose route is covered by
+//! the vendored schemas; routes that are not covered are deserialized as
+//! usual.
+//!
+//! # Example
+//!
+//! ```rust
+//! use inf_circle_sdk::schema::ResponseSchemas;
+//! use reqwest::Method;
+//! use serde_json::json;
+//!
+//! let data = json!({"transaction": {"id": "tx-1", "state": 3}});
+//! let violations = ResponseSchemas::vendored()
+//!     .violations(&Method::GET, "/v1/w3s/transactions/tx-1", &data)
+//!     .unwrap();
+//! assert!(violations.iter().any(|v| v.starts_with("/transaction/state")));
+//! ```
+
+use std::sync::OnceLock;
+
+use jsonschema::Validator;
+use reqwest::Method;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::helper::{CircleError, CircleResult};
+
+/// Vendored Circle response schemas, keyed by route
+const VENDORED_SCHEMAS: &str = include_str!("circle_responses.json");
+
+#[derive(Deserialize)]
+struct SchemaFile {
+    routes: Vec<RouteSpec>,
+    components: Value,
+}
+
+#[derive(Deserialize)]
+struct RouteSpec {
+    method: String,
+    path: String,
+    schema: String,
+}
+
+struct Route {
+    method: Method,
+    segments: Vec<String>,
+    validator: Validator,
+}
+
+impl Route {
+    fn matches(&self, method: &Method, path: &str) -> bool {
+        let mut segments = path.trim_matches('/').split('/');
+        *method == self.method
+            && self.segments.iter().all(|expected| match segments.next() {
+                Some(actual) if expected.starts_with('{') => !actual.is_empty(),
+                Some(actual) => actual == expected,
+                None => false,
+            })
+            && segments.next().is_none()
+    }
+}
+
+/// Compiled response schemas for the Circle endpoints used by the SDK
+pub struct ResponseSchemas {
+    routes: Vec<Route>,
+}
+
+impl ResponseSchemas {
+    /// The schemas vendored with this crate, compiled on first use
+    pub fn vendored() -> &'static ResponseSchemas {
+        static SCHEMAS: OnceLock<ResponseSchemas> = OnceLock::new();
+        SCHEMAS.get_or_init(|| {
+            Self::from_json(VENDORED_SCHEMAS).expect("vendored Circle schemas are valid")
+        })
+    }
+
+    /// Compile schemas from a document in the vendored format
+    ///
+    /// The document has a `routes` list (`method`, `path` with `{param}`
+    /// placeholders, `schema` name) and a `components.schemas` map that the
+    /// route schemas and their `$ref`s resolve against.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Config` if a route has an invalid method or its
+    /// schema does not compile.
+    pub fn from_json(document: &str) -> CircleResult<Self> {
+        let file: SchemaFile = serde_json::from_str(document)?;
+
+        let routes = file
+            .routes
+            .into_iter()
+            .map(|spec| {
+                let method = Method::from_bytes(spec.method.as_bytes()).map_err(|_| {
+                    CircleError::Config(format!("Invalid schema route method: {}", spec.method))
+                })?;
+                let schema = json!({
+                    "$ref": format!("#/components/schemas/{}", spec.schema),
+                    "components": file.components,
+                });
+                let validator = jsonschema::validator_for(&schema).map_err(|e| {
+                    CircleError::Config(format!(
+                        "Invalid response schema {} for {}: {}",
+                        spec.schema, spec.path, e
+                    ))
+                })?;
+
+                Ok(Route {
+                    method,
+                    segments: spec
+                        .path
+                        .trim_matches('/')
+                        .split('/')
+                        .map(str::to_string)
+                        .collect(),
+                    validator,
+                })
+            })
+            .collect::<CircleResult<_>>()?;
+
+        Ok(Self { routes })
+    }
+
+    /// Check the `data` field of a response to `method path`
+    ///
+    /// Returns `None` if no schema covers the route, otherwise every
+    /// violation as `"<json pointer>: <reason>"` (empty if the data is valid).
+    pub fn violations(&self, method: &Method, path: &str, data: &Value) -> Option<Vec<String>> {
+        let route = self.routes.iter().find(|r| r.matches(method, path))?;
+        Some(
+            route
+                .validator
+                .iter_errors(data)
+                .map(|error| {
+                    let pointer = error.instance_path.to_string();
+                    let pointer = if pointer.is_empty() { "/" } else { &pointer };
+                    format!("{}: {}", pointer, error)
+                })
+                .collect(),
+        )
+    }
+
+    /// Validate the `data` field of a response to `method path`
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Schema` if the data violates the route's schema.
+    pub fn validate(&self, method: &Method, path: &str, data: &Value) -> CircleResult<()> {
+        match self.violations(method, path, data) {
+            Some(violations) if !violations.is_empty() => Err(CircleError::Schema {
+                endpoint: format!("{} {}", method, path),
+                violations,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helper::HttpClient;
+
+    fn transaction() -> Value {
+        json!({
+            "id": "tx-1",
+            "blockchain": "ETH-SEPOLIA",
+            "createDate": "2025-01-15T10:00:00Z",
+            "updateDate": "2025-01-15T10:00:00Z",
+            "state": "COMPLETE",
+            "transactionType": "OUTBOUND",
+            "amounts": ["1.5"]
+        })
+    }
+
+    #[test]
+    fn test_routes_match_path_parameters() {
+        let schemas = ResponseSchemas::vendored();
+        let data = json!({"transaction": transaction()});
+
+        assert_eq!(
+            schemas.violations(&Method::GET, "/v1/w3s/transactions/tx-1", &data),
+            Some(vec![])
+        );
+        assert!(schemas
+            .violations(&Method::GET, "/v1/w3s/transactions/tx-1/extra", &data)
+            .is_none());
+        assert!(schemas
+            .violations(&Method::DELETE, "/v1/w3s/transactions/tx-1", &data)
+            .is_none());
+    }
+
+    #[test]
+    fn test_violations_name_every_field() {
+        let mut tx = transaction();
+        tx["amounts"] = json!([1.5]);
+        tx["blockHeight"] = json!("123");
+        tx.as_object_mut().unwrap().remove("state");
+
+        let violations = ResponseSchemas::vendored()
+            .violations(
+                &Method::GET,
+                "/v1/w3s/transactions",
+                &json!({"transactions": [tx]}),
+            )
+            .unwrap();
+
+        assert_eq!(violations.len(), 3, "{:?}", violations);
+        assert!(violations
+            .iter()
+            .any(|v| v.starts_with("/transactions/0/amounts/0:")));
+        assert!(violations
+            .iter()
+            .any(|v| v.starts_with("/transactions/0/blockHeight:")));
+        assert!(violations
+            .iter()
+            .any(|v| v.starts_with("/transactions/0:") && v.contains("state")));
+    }
+
+    #[tokio::test]
+    async fn test_client_reports_schema_violations() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/v1/w3s/tokens/token-1")
+            .with_status(200)
+            .with_body(
+                json!({"data": {"token": {
+                    "id": "token-1",
+                    "blockchain": "ETH-SEPOLIA",
+                    "isNative": "false",
+                    "decimals": 6,
+                    "createDate": "2025-01-15T10:00:00Z",
+                    "updateDate": "2025-01-15T10:00:00Z"
+                }}})
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let client = HttpClient::new(&server.url()).unwrap();
+
+        // Without the opt-in the response is deserialized as usual
+        let request = client
+            .request(Method::GET, "/v1/w3s/tokens/token-1")
+            .unwrap();
+        let unchecked: CircleResult<serde_json::Value> = client.execute(request).await;
+        assert!(unchecked.is_ok());
+
+        let client = client.validate_schemas(true);
+        let request = client
+            .request(Method::GET, "/v1/w3s/tokens/token-1")
+            .unwrap();
+        let result: CircleResult<crate::dev_wallet::dto::TokenResponse> =
+            client.execute(request).await;
+
+        match result {
+            Err(CircleError::Schema {
+                endpoint,
+                violations,
+            }) => {
+                assert_eq!(endpoint, "GET /v1/w3s/tokens/token-1");
+                assert_eq!(violations.len(), 1);
+                assert!(violations[0].starts_with("/token/isNative:"));
+            }
+            other => panic!("expected schema error, got {:?}", other.map(|_| ())),
+        }
+    }
+}
@@ -0,0 +1,172 @@
+//! Solana Data Transfer Objects
+//!
+//! This module contains the data structures used for Solana operations,
+//! including network identifiers, public keys, instructions, balances and
+//! signed transactions.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::helper::{CircleError, CircleResult};
+
+/// Solana network identifier
+///
+/// `Custom` points at any JSON-RPC endpoint, e.g. a dedicated RPC provider.
+/// The public endpoints are heavily rate limited and not meant for production.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolanaNetwork {
+    Mainnet,
+    Devnet,
+    Custom(String),
+}
+
+impl SolanaNetwork {
+    /// Get the RPC endpoint URL for this network
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use inf_circle_sdk::solana::dto::SolanaNetwork;
+    ///
+    /// assert_eq!(SolanaNetwork::Mainnet.rpc_url(), "https://api.mainnet-beta.solana.com");
+    /// assert_eq!(SolanaNetwork::Devnet.rpc_url(), "https://api.devnet.solana.com");
+    /// ```
+    pub fn rpc_url(&self) -> &str {
+        match self {
+            SolanaNetwork::Mainnet => "https://api.mainnet-beta.solana.com",
+            SolanaNetwork::Devnet => "https://api.devnet.solana.com",
+            SolanaNetwork::Custom(url) => url,
+        }
+    }
+}
+
+/// 32-byte Solana public key (account address, program ID or mint)
+///
+/// Parsed from and displayed as base58.
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::solana::dto::SolanaPubkey;
+///
+/// let mint: SolanaPubkey = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".parse().unwrap();
+/// assert_eq!(mint.to_string(), "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SolanaPubkey([u8; 32]);
+
+impl SolanaPubkey {
+    /// Create a public key from its raw bytes
+    pub fn from_bytes(bytes: [u8; 32]) -> Self {
+        SolanaPubkey(bytes)
+    }
+
+    /// Return the raw bytes of this public key
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl FromStr for SolanaPubkey {
+    type Err = CircleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        decode_base58_32("public key", s).map(SolanaPubkey)
+    }
+}
+
+impl fmt::Display for SolanaPubkey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&bs58::encode(self.0).into_string())
+    }
+}
+
+/// Decode a base58 string that must hold exactly 32 bytes
+pub(crate) fn decode_base58_32(field: &str, value: &str) -> CircleResult<[u8; 32]> {
+    let bytes = bs58::decode(value)
+        .into_vec()
+        .map_err(|e| CircleError::Config(format!("Invalid Solana {} {}: {}", field, value, e)))?;
+    bytes.try_into().map_err(|bytes: Vec<u8>| {
+        CircleError::Config(format!(
+            "Invalid Solana {} {}: expected 32 bytes, got {}",
+            field,
+            value,
+            bytes.len()
+        ))
+    })
+}
+
+/// An account referenced by an instruction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SolanaAccountMeta {
+    pub pubkey: SolanaPubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl SolanaAccountMeta {
+    /// A writable account
+    pub fn writable(pubkey: SolanaPubkey, is_signer: bool) -> Self {
+        Self {
+            pubkey,
+            is_signer,
+            is_writable: true,
+        }
+    }
+
+    /// A read-only account
+    pub fn readonly(pubkey: SolanaPubkey, is_signer: bool) -> Self {
+        Self {
+            pubkey,
+            is_signer,
+            is_writable: false,
+        }
+    }
+}
+
+/// A single program instruction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolanaInstruction {
+    pub program_id: SolanaPubkey,
+    pub accounts: Vec<SolanaAccountMeta>,
+    pub data: Vec<u8>,
+}
+
+/// Native SOL balance of an account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SolanaAccountBalance {
+    /// Balance in lamports
+    pub lamports: u64,
+    /// Balance in SOL (as string to preserve precision)
+    pub sol: String,
+    /// Slot the balance was read at
+    pub slot: u64,
+}
+
+/// SPL token balance held in one token account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SolanaTokenBalance {
+    /// Token account address
+    pub token_account: String,
+    /// Mint address of the token
+    pub mint: String,
+    /// Raw amount in base units (as string to preserve precision)
+    pub amount: String,
+    /// Number of decimals of the mint
+    pub decimals: u8,
+    /// Amount in whole tokens
+    pub ui_amount: String,
+}
+
+/// A signed transaction ready to be submitted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedSolanaTransaction {
+    /// Fee payer signature (base58), which is also the transaction ID
+    pub signature: String,
+    /// All signatures (base58), in account order
+    pub signatures: Vec<String>,
+    /// Wire transaction, base64 encoded for `sendTransaction`
+    pub wire_transaction: String,
+}
@@ -0,0 +1,298 @@
+//! Solana RPC Helper Functions
+//!
+//! This module queries balances and blockhashes from a Solana JSON-RPC node
+//! and submits signed transactions.
+
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+
+use crate::helper::{CircleError, CircleResult};
+
+use super::dto::{
+    SignedSolanaTransaction, SolanaAccountBalance, SolanaNetwork, SolanaPubkey, SolanaTokenBalance,
+};
+use super::transaction::TOKEN_PROGRAM_ID;
+
+/// Convert lamports (1e-9 SOL) to a SOL string with full precision
+fn format_lamports_to_sol(lamports: u64) -> String {
+    const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+    let whole = lamports / LAMPORTS_PER_SOL;
+    let fractional = lamports % LAMPORTS_PER_SOL;
+    if fractional == 0 {
+        whole.to_string()
+    } else {
+        let fractional = format!("{:09}", fractional);
+        format!("{}.{}", whole, fractional.trim_end_matches('0'))
+    }
+}
+
+/// Call a JSON-RPC method and deserialize its `result`
+async fn rpc_call<T: DeserializeOwned>(
+    network: &SolanaNetwork,
+    method: &str,
+    params: Value,
+) -> CircleResult<T> {
+    let body = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": method,
+        "params": params,
+    });
+
+    let response: Value = reqwest::Client::new()
+        .post(network.rpc_url())
+        .json(&body)
+        .send()
+        .await?
+        .json()
+        .await?;
+
+    if let Some(error) = response.get("error") {
+        return Err(CircleError::Api {
+            status: 500,
+            request_id: None,
+            message: format!(
+                "Solana RPC error calling {}: {}",
+                method,
+                error["message"].as_str().unwrap_or("unknown error")
+            ),
+        });
+    }
+
+    serde_json::from_value(response["result"].clone()).map_err(CircleError::Json)
+}
+
+/// Get the native SOL balance of an account
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use inf_circle_sdk::solana::{get_solana_balance, dto::SolanaNetwork};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let balance = get_solana_balance(
+///     "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM",
+///     &SolanaNetwork::Devnet,
+/// ).await?;
+/// println!("Balance: {} SOL", balance.sol);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_solana_balance(
+    address: &str,
+    network: &SolanaNetwork,
+) -> CircleResult<SolanaAccountBalance> {
+    let address: SolanaPubkey = address.parse()?;
+    let result: Value = rpc_call(
+        network,
+        "getBalance",
+        json!([address.to_string(), { "commitment": "confirmed" }]),
+    )
+    .await?;
+
+    let lamports = result["value"].as_u64().ok_or_else(|| CircleError::Api {
+        status: 500,
+        request_id: None,
+        message: "Unexpected getBalance response from Solana RPC".to_string(),
+    })?;
+
+    Ok(SolanaAccountBalance {
+        lamports,
+        sol: format_lamports_to_sol(lamports),
+        slot: result["context"]["slot"].as_u64().unwrap_or_default(),
+    })
+}
+
+/// Get the SPL token balances of every token account owned by `owner`
+///
+/// Pass `mint` to only return accounts holding that token.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use inf_circle_sdk::solana::{get_solana_token_balances, dto::SolanaNetwork};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let balances = get_solana_token_balances(
+///     "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM",
+///     None,
+///     &SolanaNetwork::Devnet,
+/// ).await?;
+/// for balance in balances {
+///     println!("{}: {}", balance.mint, balance.ui_amount);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_solana_token_balances(
+    owner: &str,
+    mint: Option<&str>,
+    network: &SolanaNetwork,
+) -> CircleResult<Vec<SolanaTokenBalance>> {
+    let owner: SolanaPubkey = owner.parse()?;
+    let filter = match mint {
+        Some(mint) => json!({ "mint": mint.parse::<SolanaPubkey>()?.to_string() }),
+        None => json!({ "programId": TOKEN_PROGRAM_ID }),
+    };
+    let result: Value = rpc_call(
+        network,
+        "getTokenAccountsByOwner",
+        json!([owner.to_string(), filter, { "encoding": "jsonParsed" }]),
+    )
+    .await?;
+
+    let accounts = result["value"].as_array().ok_or_else(|| CircleError::Api {
+        status: 500,
+        request_id: None,
+        message: "Unexpected getTokenAccountsByOwner response from Solana RPC".to_string(),
+    })?;
+
+    Ok(accounts
+        .iter()
+        .filter_map(|account| {
+            let info = &account["account"]["data"]["parsed"]["info"];
+            let amount = &info["tokenAmount"];
+            Some(SolanaTokenBalance {
+                token_account: account["pubkey"].as_str()?.to_string(),
+                mint: info["mint"].as_str()?.to_string(),
+                amount: amount["amount"].as_str()?.to_string(),
+                decimals: amount["decimals"].as_u64()? as u8,
+                ui_amount: amount["uiAmountString"].as_str()?.to_string(),
+            })
+        })
+        .collect())
+}
+
+/// Get a recent blockhash (base58) for building a transaction
+///
+/// Transactions must be signed and submitted within roughly a minute of the
+/// blockhash being produced.
+pub async fn get_solana_latest_blockhash(network: &SolanaNetwork) -> CircleResult<String> {
+    let result: Value = rpc_call(
+        network,
+        "getLatestBlockhash",
+        json!([{ "commitment": "confirmed" }]),
+    )
+    .await?;
+
+    result["value"]["blockhash"]
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| CircleError::Api {
+            status: 500,
+            request_id: None,
+            message: "Unexpected getLatestBlockhash response from Solana RPC".to_string(),
+        })
+}
+
+/// Submit a signed transaction, returning its signature
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use inf_circle_sdk::solana::{
+///     dto::SolanaNetwork, parse_signed_solana_transaction, send_solana_transaction,
+/// };
+///
+/// # async fn example(signed_transaction: &str) -> Result<(), Box<dyn std::error::Error>> {
+/// let signed = parse_signed_solana_transaction(signed_transaction)?;
+/// let signature = send_solana_transaction(&signed, &SolanaNetwork::Devnet).await?;
+/// println!("Submitted: {}", signature);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn send_solana_transaction(
+    transaction: &SignedSolanaTransaction,
+    network: &SolanaNetwork,
+) -> CircleResult<String> {
+    rpc_call(
+        network,
+        "sendTransaction",
+        json!([transaction.wire_transaction, { "encoding": "base64" }]),
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_lamports_to_sol() {
+        assert_eq!(format_lamports_to_sol(0), "0");
+        assert_eq!(format_lamports_to_sol(2_000_000_000), "2");
+        assert_eq!(format_lamports_to_sol(890_880), "0.00089088");
+        assert_eq!(format_lamports_to_sol(1_000_000_001), "1.000000001");
+    }
+
+    #[tokio::test]
+    async fn test_get_solana_token_balances() {
+        let mut server = mockito::Server::new_async().await;
+        let owner = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+        let mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::PartialJson(
+                json!({ "method": "getTokenAccountsByOwner" }),
+            ))
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": 1,
+                    "result": {
+                        "context": { "slot": 1 },
+                        "value": [{
+                            "pubkey": "3emsAVdmGKERbHjmGfQ6oZ1e35dkf5iYcS6U4CPKFVaa",
+                            "account": { "data": { "parsed": { "info": {
+                                "mint": "4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU",
+                                "tokenAmount": {
+                                    "amount": "2500000",
+                                    "decimals": 6,
+                                    "uiAmountString": "2.5"
+                                }
+                            }}}}
+                        }]
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let network = SolanaNetwork::Custom(server.url());
+        let balances = get_solana_token_balances(owner, None, &network)
+            .await
+            .unwrap();
+
+        assert_eq!(balances.len(), 1);
+        assert_eq!(balances[0].amount, "2500000");
+        assert_eq!(balances[0].decimals, 6);
+        assert_eq!(balances[0].ui_amount, "2.5");
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_rpc_error_surfaces_as_api_error() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", "/")
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": 1,
+                    "error": { "code": -32002, "message": "Blockhash not found" }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let network = SolanaNetwork::Custom(server.url());
+        let result = get_solana_latest_blockhash(&network).await;
+        assert!(
+            matches!(result, Err(CircleError::Api { message, .. }) if message.contains("Blockhash not found"))
+        );
+    }
+}
@@ -0,0 +1,78 @@
+//! Solana Support
+//!
+//! This module provides building blocks for Solana wallets: SOL and SPL token
+//! balance queries over JSON-RPC, unsigned transaction building for Circle's
+//! sign transaction endpoint, and parsing of the signed transaction into a
+//! wire transaction that can be submitted to the network.
+//!
+//! # Main Components
+//!
+//! - [`dto`]: Data transfer objects (network identifiers, public keys, instructions, balances)
+//! - [`handler`]: RPC helpers for balances, blockhashes and submission
+//! - [`transaction`]: Transaction building, common instructions and signed transaction parsing
+//!
+//! # Example - Get Token Balances
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::solana::{get_solana_token_balances, dto::SolanaNetwork};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let balances = get_solana_token_balances(
+//!     "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM",
+//!     Some("4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU"), // devnet USDC
+//!     &SolanaNetwork::Devnet,
+//! ).await?;
+//! for balance in balances {
+//!     println!("{} ({}): {}", balance.mint, balance.token_account, balance.ui_amount);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Example - Sign and Submit a Transfer
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::{
+//!     circle_ops::circler_ops::CircleOps,
+//!     solana::{
+//!         dto::SolanaNetwork, get_solana_latest_blockhash, parse_signed_solana_transaction,
+//!         send_solana_transaction, system_transfer, SolanaTransactionBuilder,
+//!     },
+//! };
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let ops = CircleOps::new(None)?;
+//! let network = SolanaNetwork::Devnet;
+//! let wallet = "4Nd1mBQtrMJVYVfKf2PJy9NZUZdTAsp7D4xWLs4gDB4T".parse()?;
+//! let recipient = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM".parse()?;
+//!
+//! let request = SolanaTransactionBuilder::new(wallet)
+//!     .recent_blockhash(get_solana_latest_blockhash(&network).await?)
+//!     .instruction(system_transfer(wallet, recipient, 1_000_000))
+//!     .into_sign_request("wallet-id".to_string())?;
+//!
+//! let response = ops.dev_sign_transaction(request).await?;
+//! let signed = parse_signed_solana_transaction(&response.signed_transaction)?;
+//! let signature = send_solana_transaction(&signed, &network).await?;
+//! println!("Submitted: {}", signature);
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod dto;
+pub mod handler;
+pub mod transaction;
+
+// Re-export commonly used items
+pub use dto::{
+    SignedSolanaTransaction, SolanaAccountBalance, SolanaAccountMeta, SolanaInstruction,
+    SolanaNetwork, SolanaPubkey, SolanaTokenBalance,
+};
+pub use handler::{
+    get_solana_balance, get_solana_latest_blockhash, get_solana_token_balances,
+    send_solana_transaction,
+};
+pub use transaction::{
+    memo, parse_signed_solana_transaction, spl_transfer_checked, system_transfer,
+    SolanaTransactionBuilder,
+};
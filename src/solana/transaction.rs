@@ -0,0 +1,470 @@
+//! Solana Transaction Building
+//!
+//! This module builds unsigned legacy Solana transactions for Circle's
+//! sign transaction endpoint and parses the signed transaction it returns.
+//! Instructions for the most common programs (System, SPL Token, Memo) are
+//! provided; any other program can be called through [`SolanaInstruction`].
+
+use base64::{engine::general_purpose, Engine};
+
+use crate::{
+    dev_wallet::ops::sign_transaction::SignTransactionRequestBuilder,
+    helper::{CircleError, CircleResult},
+};
+
+use super::dto::{
+    decode_base58_32, SignedSolanaTransaction, SolanaAccountMeta, SolanaInstruction, SolanaPubkey,
+};
+
+/// System program ID
+pub const SYSTEM_PROGRAM_ID: &str = "11111111111111111111111111111111";
+
+/// SPL Token program ID
+pub const TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+
+/// SPL Memo program ID
+pub const MEMO_PROGRAM_ID: &str = "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr";
+
+/// Signature length in bytes
+const SIGNATURE_LEN: usize = 64;
+
+/// Parse one of the program ID constants
+fn program_id(id: &str) -> SolanaPubkey {
+    id.parse().expect("program ID constants are valid base58")
+}
+
+/// Transfer `lamports` of SOL from `from` to `to`
+pub fn system_transfer(from: SolanaPubkey, to: SolanaPubkey, lamports: u64) -> SolanaInstruction {
+    // SystemInstruction::Transfer is variant 2, encoded as a little-endian u32
+    let mut data = Vec::with_capacity(12);
+    data.extend_from_slice(&2u32.to_le_bytes());
+    data.extend_from_slice(&lamports.to_le_bytes());
+
+    SolanaInstruction {
+        program_id: program_id(SYSTEM_PROGRAM_ID),
+        accounts: vec![
+            SolanaAccountMeta::writable(from, true),
+            SolanaAccountMeta::writable(to, false),
+        ],
+        data,
+    }
+}
+
+/// Transfer `amount` base units of an SPL token between token accounts
+///
+/// Uses `TransferChecked`, so the program rejects the transfer if `mint` or
+/// `decimals` do not match the source account.
+///
+/// # Arguments
+/// * `source` - Token account to debit
+/// * `mint` - Mint of the token
+/// * `destination` - Token account to credit
+/// * `owner` - Owner of the source token account (signs the transaction)
+/// * `amount` - Raw amount in base units
+/// * `decimals` - Decimals of the mint
+pub fn spl_transfer_checked(
+    source: SolanaPubkey,
+    mint: SolanaPubkey,
+    destination: SolanaPubkey,
+    owner: SolanaPubkey,
+    amount: u64,
+    decimals: u8,
+) -> SolanaInstruction {
+    // TokenInstruction::TransferChecked is tag 12
+    let mut data = Vec::with_capacity(10);
+    data.push(12);
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.push(decimals);
+
+    SolanaInstruction {
+        program_id: program_id(TOKEN_PROGRAM_ID),
+        accounts: vec![
+            SolanaAccountMeta::writable(source, false),
+            SolanaAccountMeta::readonly(mint, false),
+            SolanaAccountMeta::writable(destination, false),
+            SolanaAccountMeta::readonly(owner, true),
+        ],
+        data,
+    }
+}
+
+/// Attach a UTF-8 memo to the transaction
+pub fn memo(text: &str) -> SolanaInstruction {
+    SolanaInstruction {
+        program_id: program_id(MEMO_PROGRAM_ID),
+        accounts: Vec::new(),
+        data: text.as_bytes().to_vec(),
+    }
+}
+
+/// Builder for an unsigned legacy Solana transaction
+///
+/// The fee payer is always the first account and must be the Circle wallet
+/// that signs the transaction.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use inf_circle_sdk::solana::{
+///     dto::SolanaNetwork, get_solana_latest_blockhash, system_transfer, SolanaTransactionBuilder,
+/// };
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let payer = "4Nd1mBQtrMJVYVfKf2PJy9NZUZdTAsp7D4xWLs4gDB4T".parse()?;
+/// let recipient = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM".parse()?;
+/// let blockhash = get_solana_latest_blockhash(&SolanaNetwork::Devnet).await?;
+///
+/// let sign_request = SolanaTransactionBuilder::new(payer)
+///     .recent_blockhash(blockhash)
+///     .instruction(system_transfer(payer, recipient, 1_000_000))
+///     .into_sign_request("wallet-id".to_string())?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SolanaTransactionBuilder {
+    pub fee_payer: SolanaPubkey,
+    pub recent_blockhash: Option<String>,
+    pub instructions: Vec<SolanaInstruction>,
+}
+
+impl SolanaTransactionBuilder {
+    /// Create a new builder paid for by `fee_payer`
+    pub fn new(fee_payer: SolanaPubkey) -> Self {
+        Self {
+            fee_payer,
+            recent_blockhash: None,
+            instructions: Vec::new(),
+        }
+    }
+
+    /// Set the recent blockhash (base58)
+    pub fn recent_blockhash(mut self, blockhash: String) -> Self {
+        self.recent_blockhash = Some(blockhash);
+        self
+    }
+
+    /// Append an instruction
+    pub fn instruction(mut self, instruction: SolanaInstruction) -> Self {
+        self.instructions.push(instruction);
+        self
+    }
+
+    /// Serialize the transaction message
+    pub fn serialize_message(&self) -> CircleResult<Vec<u8>> {
+        let blockhash = self.recent_blockhash.as_deref().ok_or_else(|| {
+            CircleError::Config("Solana transaction requires a recent blockhash".to_string())
+        })?;
+        let blockhash = decode_base58_32("blockhash", blockhash)?;
+        if self.instructions.is_empty() {
+            return Err(CircleError::Config(
+                "Solana transaction requires at least one instruction".to_string(),
+            ));
+        }
+
+        let accounts = self.ordered_accounts();
+        if accounts.len() > u8::MAX as usize {
+            return Err(CircleError::Config(format!(
+                "Solana transaction references {} accounts, at most 255 are supported",
+                accounts.len()
+            )));
+        }
+        let index_of = |pubkey: &SolanaPubkey| {
+            accounts
+                .iter()
+                .position(|meta| meta.pubkey == *pubkey)
+                .expect("every instruction account is in the account list") as u8
+        };
+
+        let required_signatures = accounts.iter().filter(|meta| meta.is_signer).count();
+        let readonly_signed = accounts
+            .iter()
+            .filter(|meta| meta.is_signer && !meta.is_writable)
+            .count();
+        let readonly_unsigned = accounts
+            .iter()
+            .filter(|meta| !meta.is_signer && !meta.is_writable)
+            .count();
+
+        let mut message = vec![
+            required_signatures as u8,
+            readonly_signed as u8,
+            readonly_unsigned as u8,
+        ];
+        encode_compact_u16(accounts.len(), &mut message);
+        for meta in &accounts {
+            message.extend_from_slice(meta.pubkey.as_bytes());
+        }
+        message.extend_from_slice(&blockhash);
+
+        encode_compact_u16(self.instructions.len(), &mut message);
+        for instruction in &self.instructions {
+            message.push(index_of(&instruction.program_id));
+            encode_compact_u16(instruction.accounts.len(), &mut message);
+            for meta in &instruction.accounts {
+                message.push(index_of(&meta.pubkey));
+            }
+            encode_compact_u16(instruction.data.len(), &mut message);
+            message.extend_from_slice(&instruction.data);
+        }
+
+        Ok(message)
+    }
+
+    /// Serialize the unsigned transaction with zeroed signature slots
+    pub fn serialize(&self) -> CircleResult<Vec<u8>> {
+        let message = self.serialize_message()?;
+        let required_signatures = message[0] as usize;
+
+        let mut transaction =
+            Vec::with_capacity(3 + required_signatures * SIGNATURE_LEN + message.len());
+        encode_compact_u16(required_signatures, &mut transaction);
+        transaction.resize(transaction.len() + required_signatures * SIGNATURE_LEN, 0);
+        transaction.extend_from_slice(&message);
+        Ok(transaction)
+    }
+
+    /// Serialize the unsigned transaction as base64, the format Circle expects
+    pub fn to_base64(&self) -> CircleResult<String> {
+        Ok(general_purpose::STANDARD.encode(self.serialize()?))
+    }
+
+    /// Create a sign transaction request for the Circle wallet `wallet_id`
+    pub fn into_sign_request(
+        self,
+        wallet_id: String,
+    ) -> CircleResult<SignTransactionRequestBuilder> {
+        SignTransactionRequestBuilder::new(wallet_id, Some(self.to_base64()?), None)
+    }
+
+    /// Accounts in message order: writable signers (fee payer first), read-only
+    /// signers, writable non-signers, read-only non-signers
+    fn ordered_accounts(&self) -> Vec<SolanaAccountMeta> {
+        let mut accounts = vec![SolanaAccountMeta::writable(self.fee_payer, true)];
+        let mut add = |candidate: SolanaAccountMeta| match accounts
+            .iter_mut()
+            .find(|meta| meta.pubkey == candidate.pubkey)
+        {
+            Some(existing) => {
+                existing.is_signer |= candidate.is_signer;
+                existing.is_writable |= candidate.is_writable;
+            }
+            None => accounts.push(candidate),
+        };
+
+        for instruction in &self.instructions {
+            for meta in &instruction.accounts {
+                add(*meta);
+            }
+            add(SolanaAccountMeta::readonly(instruction.program_id, false));
+        }
+
+        // Stable sort keeps the fee payer first and preserves first-use order
+        accounts.sort_by_key(|meta| match (meta.is_signer, meta.is_writable) {
+            (true, true) => 0,
+            (true, false) => 1,
+            (false, true) => 2,
+            (false, false) => 3,
+        });
+        accounts
+    }
+}
+
+/// Parse the `signedTransaction` returned by Circle into a submittable transaction
+///
+/// Accepts legacy and versioned transactions, and fails if any required
+/// signature is missing.
+///
+/// # Arguments
+/// * `signed_transaction` - Base64 signed transaction from `SignTransactionResponse`
+pub fn parse_signed_solana_transaction(
+    signed_transaction: &str,
+) -> CircleResult<SignedSolanaTransaction> {
+    let bytes = general_purpose::STANDARD
+        .decode(signed_transaction.trim())
+        .map_err(|e| CircleError::Config(format!("Invalid base64 Solana transaction: {}", e)))?;
+
+    let invalid =
+        |reason: &str| CircleError::Config(format!("Invalid Solana transaction: {}", reason));
+
+    let (count, mut offset) = decode_compact_u16(&bytes).ok_or_else(|| invalid("truncated"))?;
+    if count == 0 {
+        return Err(invalid("no signatures"));
+    }
+    let signatures_end = offset + count * SIGNATURE_LEN;
+    if bytes.len() <= signatures_end {
+        return Err(invalid("truncated"));
+    }
+
+    let mut signatures = Vec::with_capacity(count);
+    while offset < signatures_end {
+        let signature = &bytes[offset..offset + SIGNATURE_LEN];
+        if signature.iter().all(|byte| *byte == 0) {
+            return Err(invalid("missing signature"));
+        }
+        signatures.push(bs58::encode(signature).into_string());
+        offset += SIGNATURE_LEN;
+    }
+
+    // Versioned messages start with a prefix byte that has the high bit set
+    let mut header = signatures_end;
+    if bytes[header] & 0x80 != 0 {
+        header += 1;
+    }
+    match bytes.get(header) {
+        Some(&required) if required as usize == count => {}
+        Some(&required) => {
+            return Err(invalid(&format!(
+                "{} signatures present, {} required",
+                count, required
+            )))
+        }
+        None => return Err(invalid("truncated")),
+    }
+
+    Ok(SignedSolanaTransaction {
+        signature: signatures[0].clone(),
+        signatures,
+        wire_transaction: general_purpose::STANDARD.encode(&bytes),
+    })
+}
+
+/// Append `value` in Solana's compact-u16 (short vec) encoding
+fn encode_compact_u16(mut value: usize, out: &mut Vec<u8>) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+/// Read a compact-u16 value, returning it with the number of bytes consumed
+fn decode_compact_u16(bytes: &[u8]) -> Option<(usize, usize)> {
+    let mut value = 0usize;
+    for (i, byte) in bytes.iter().take(3).enumerate() {
+        value |= ((byte & 0x7f) as usize) << (7 * i);
+        if byte & 0x80 == 0 {
+            return Some((value, i + 1));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BLOCKHASH: &str = "EkSnNWid2cvwEVnVx9aBqawnmiCNiDgp3gUdkDPTKN1N";
+
+    fn key(byte: u8) -> SolanaPubkey {
+        SolanaPubkey::from_bytes([byte; 32])
+    }
+
+    #[test]
+    fn test_compact_u16_round_trip() {
+        for (value, encoded) in [
+            (0usize, vec![0x00]),
+            (0x7f, vec![0x7f]),
+            (0x80, vec![0x80, 0x01]),
+            (0x3fff, vec![0xff, 0x7f]),
+            (0x4000, vec![0x80, 0x80, 0x01]),
+            (0xffff, vec![0xff, 0xff, 0x03]),
+        ] {
+            let mut out = Vec::new();
+            encode_compact_u16(value, &mut out);
+            assert_eq!(out, encoded);
+            assert_eq!(decode_compact_u16(&out), Some((value, encoded.len())));
+        }
+    }
+
+    #[test]
+    fn test_serialize_system_transfer() {
+        let payer = key(1);
+        let recipient = key(2);
+        let builder = SolanaTransactionBuilder::new(payer)
+            .recent_blockhash(BLOCKHASH.to_string())
+            .instruction(system_transfer(payer, recipient, 5_000));
+
+        let transaction = builder.serialize().unwrap();
+        assert_eq!(transaction[0], 1);
+        assert!(transaction[1..65].iter().all(|byte| *byte == 0));
+
+        let message = &transaction[65..];
+        assert_eq!(message, builder.serialize_message().unwrap().as_slice());
+        // 1 signer, 0 read-only signers, 1 read-only non-signer (system program)
+        assert_eq!(&message[..3], &[1, 0, 1]);
+        assert_eq!(message[3], 3);
+        assert_eq!(&message[4..36], payer.as_bytes());
+        assert_eq!(&message[36..68], recipient.as_bytes());
+        assert_eq!(&message[68..100], program_id(SYSTEM_PROGRAM_ID).as_bytes());
+        assert_eq!(
+            &message[100..132],
+            decode_base58_32("blockhash", BLOCKHASH).unwrap().as_slice()
+        );
+        // One instruction: program index 2, accounts [0, 1], 12 bytes of data
+        assert_eq!(&message[132..138], &[1, 2, 2, 0, 1, 12]);
+        assert_eq!(&message[138..142], &2u32.to_le_bytes());
+        assert_eq!(&message[142..], &5_000u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_account_ordering_merges_flags() {
+        let payer = key(1);
+        let owner = key(9);
+        let builder = SolanaTransactionBuilder::new(payer)
+            .recent_blockhash(BLOCKHASH.to_string())
+            .instruction(spl_transfer_checked(key(3), key(4), key(5), owner, 1, 6))
+            .instruction(memo("invoice-42"));
+
+        let accounts = builder.ordered_accounts();
+        let order: Vec<SolanaPubkey> = accounts.iter().map(|meta| meta.pubkey).collect();
+        assert_eq!(
+            order,
+            vec![
+                payer,
+                owner,
+                key(3),
+                key(5),
+                key(4),
+                program_id(TOKEN_PROGRAM_ID),
+                program_id(MEMO_PROGRAM_ID),
+            ]
+        );
+
+        let message = builder.serialize_message().unwrap();
+        assert_eq!(&message[..3], &[2, 1, 3]);
+    }
+
+    #[test]
+    fn test_serialize_requires_blockhash_and_instructions() {
+        let builder = SolanaTransactionBuilder::new(key(1));
+        assert!(matches!(builder.serialize(), Err(CircleError::Config(_))));
+
+        let builder = builder.recent_blockhash(BLOCKHASH.to_string());
+        assert!(matches!(builder.serialize(), Err(CircleError::Config(_))));
+    }
+
+    #[test]
+    fn test_parse_signed_transaction() {
+        let payer = key(1);
+        let builder = SolanaTransactionBuilder::new(payer)
+            .recent_blockhash(BLOCKHASH.to_string())
+            .instruction(system_transfer(payer, key(2), 5_000));
+
+        let unsigned = builder.to_base64().unwrap();
+        assert!(parse_signed_solana_transaction(&unsigned).is_err());
+
+        let mut signed = builder.serialize().unwrap();
+        signed[1..65].copy_from_slice(&[7u8; 64]);
+        let signed = general_purpose::STANDARD.encode(&signed);
+
+        let parsed = parse_signed_solana_transaction(&signed).unwrap();
+        assert_eq!(parsed.signature, bs58::encode([7u8; 64]).into_string());
+        assert_eq!(parsed.signatures.len(), 1);
+        assert_eq!(parsed.wire_transaction, signed);
+    }
+}
@@ -0,0 +1,107 @@
+//! Request/response tracing (`tracing` feature)
+//!
+//! Every call made through `HttpClient` runs inside a `circle.request` span
+//! carrying the HTTP method, path, status code, Circle request ID and latency.
+//! Request bodies are emitted as a `DEBUG` event with the entity secret
+//! ciphertext redacted; failed calls emit a `WARN` event with the error.
+
+use reqwest::{Request, Response};
+use serde_json::Value;
+use tracing::{field, Span};
+
+use crate::helper::{response_request_id, CircleError, Instant};
+
+/// Replacement for redacted body fields
+const REDACTED: &str = "[REDACTED]";
+
+/// Body fields that must never reach a log
+const SECRET_FIELDS: &[&str] = &["entitySecretCiphertext"];
+
+/// Open the span for an outgoing request and log its redacted body
+pub(crate) fn request_span(request: &Request) -> Span {
+    let span = tracing::info_span!(
+        "circle.request",
+        http.method = %request.method(),
+        http.path = %request.url().path(),
+        http.status_code = field::Empty,
+        circle.request_id = field::Empty,
+        latency_ms = field::Empty,
+    );
+
+    if let Some(body) = request.body().and_then(|body| body.as_bytes()) {
+        span.in_scope(|| tracing::debug!(body = %redact_body(body), "request body"));
+    }
+
+    span
+}
+
+/// Record status, request ID and latency on the current span
+pub(crate) fn record_response(response: &Response, started: Instant) {
+    let span = Span::current();
+    span.record("http.status_code", response.status().as_u16());
+    span.record("latency_ms", started.elapsed().as_millis() as u64);
+    if let Some(request_id) = response_request_id(response) {
+        span.record("circle.request_id", request_id);
+    }
+}
+
+/// Record a request that never produced a response
+pub(crate) fn record_transport_failure(started: Instant, error: &CircleError) {
+    Span::current().record("latency_ms", started.elapsed().as_millis() as u64);
+    record_failure(error);
+}
+
+/// Log a failed request
+pub(crate) fn record_failure(error: &CircleError) {
+    tracing::warn!(error = %error, "Circle API request failed");
+}
+
+/// Render a request body with secret fields replaced
+fn redact_body(body: &[u8]) -> String {
+    match serde_json::from_slice::<Value>(body) {
+        Ok(mut value) => {
+            redact(&mut value);
+            value.to_string()
+        }
+        Err(_) => format!("<{} bytes>", body.len()),
+    }
+}
+
+fn redact(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, field) in map.iter_mut() {
+                if SECRET_FIELDS.contains(&key.as_str()) {
+                    *field = Value::String(REDACTED.to_string());
+                } else {
+                    redact(field);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(redact),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_redact_body_hides_ciphertext() {
+        let body = json!({
+            "idempotencyKey": "key-1",
+            "entitySecretCiphertext": "c2VjcmV0",
+            "nested": [{"entitySecretCiphertext": "c2VjcmV0"}]
+        })
+        .to_string();
+
+        let rendered = redact_body(body.as_bytes());
+        assert!(!rendered.contains("c2VjcmV0"));
+        assert!(rendered.contains("key-1"));
+        assert_eq!(rendered.matches(REDACTED).count(), 2);
+
+        assert_eq!(redact_body(b"not json"), "<8 bytes>");
+    }
+}
@@ -0,0 +1,230 @@
+//! Canned response fixtures
+//!
+//! Every fixture is a fully populated DTO with deterministic IDs, built from
+//! the JSON Circle returns for the corresponding resource. Adjust the public
+//! fields to fit a test case before mounting the fixture on a
+//! [`MockCircle`](super::MockCircle).
+
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+
+use crate::contract::dto::{Contract, EventLog, EventMonitor, NotificationSubscription};
+use crate::dev_wallet::dto::{
+    CreateTransferTransactionResponse, DevWallet, EstimatedFee, FeeEstimate, Nft,
+    SignatureResponse, Token, TokenBalance, Transaction, WalletWithBalances,
+};
+
+/// Wallet ID used by the fixtures
+pub const WALLET_ID: &str = "01234567-89ab-cdef-0123-456789abcdef";
+
+/// Wallet set ID used by the fixtures
+pub const WALLET_SET_ID: &str = "11111111-2222-3333-4444-555555555555";
+
+/// Wallet address used by the fixtures
+pub const WALLET_ADDRESS: &str = "0x1111111111111111111111111111111111111111";
+
+/// Transaction ID used by the fixtures
+pub const TRANSACTION_ID: &str = "aaaaaaaa-bbbb-cccc-dddd-eeeeeeeeeeee";
+
+/// Token ID used by the fixtures (USDC on ETH-SEPOLIA)
+pub const TOKEN_ID: &str = "5797fbd6-3795-519d-84ca-ec4c5f80c3b1";
+
+/// Contract ID used by the fixtures
+pub const CONTRACT_ID: &str = "0189db84-72b7-7fcc-832b-5bf886b9d0c6";
+
+/// Contract address used by the fixtures
+pub const CONTRACT_ADDRESS: &str = "0x2222222222222222222222222222222222222222";
+
+const TIMESTAMP: &str = "2025-01-15T10:00:00Z";
+
+fn from_json<T: DeserializeOwned>(value: Value) -> T {
+    serde_json::from_value(value).expect("fixture matches its DTO")
+}
+
+/// An EOA wallet on ETH-SEPOLIA
+pub fn dev_wallet() -> DevWallet {
+    from_json(json!({
+        "id": WALLET_ID,
+        "address": WALLET_ADDRESS,
+        "blockchain": "ETH-SEPOLIA",
+        "createDate": TIMESTAMP,
+        "updateDate": TIMESTAMP,
+        "custodyType": "DEVELOPER",
+        "name": "Test wallet",
+        "refId": "test-wallet",
+        "state": "LIVE",
+        "walletSetId": WALLET_SET_ID,
+        "accountType": "EOA"
+    }))
+}
+
+/// USDC on ETH-SEPOLIA
+pub fn token() -> Token {
+    from_json(json!({
+        "id": TOKEN_ID,
+        "name": "USD Coin",
+        "standard": "ERC20",
+        "blockchain": "ETH-SEPOLIA",
+        "decimals": 6,
+        "isNative": false,
+        "symbol": "USDC",
+        "tokenAddress": "0x1c7d4b196cb0c7b01d743fbc6116a902379c7238",
+        "createDate": TIMESTAMP,
+        "updateDate": TIMESTAMP
+    }))
+}
+
+/// A balance of 100 USDC
+pub fn token_balance() -> TokenBalance {
+    from_json(json!({
+        "amount": "100",
+        "token": token(),
+        "updateDate": TIMESTAMP
+    }))
+}
+
+/// The [`dev_wallet`] with a [`token_balance`]
+pub fn wallet_with_balances() -> WalletWithBalances {
+    let mut wallet = serde_json::to_value(dev_wallet()).expect("fixture serializes");
+    wallet["tokenBalances"] = json!([token_balance()]);
+    from_json(wallet)
+}
+
+/// An ERC-721 token held by the fixture wallet
+pub fn nft() -> Nft {
+    let mut token = serde_json::to_value(token()).expect("fixture serializes");
+    token["standard"] = json!("ERC721");
+    token["decimals"] = json!(0);
+    from_json(json!({
+        "amount": "1",
+        "nftTokenId": "1",
+        "token": token,
+        "updateDate": TIMESTAMP
+    }))
+}
+
+/// A medium fee estimate on ETH-SEPOLIA
+pub fn estimated_fee() -> EstimatedFee {
+    from_json(json!({
+        "gasLimit": "21000",
+        "baseFee": "1.5",
+        "priorityFee": "1.2",
+        "maxFee": "4.2",
+        "networkFee": "0.0000882"
+    }))
+}
+
+/// Low, medium and high fee estimates
+pub fn fee_estimate() -> FeeEstimate {
+    from_json(json!({
+        "low": estimated_fee(),
+        "medium": estimated_fee(),
+        "high": estimated_fee()
+    }))
+}
+
+/// A completed 1 USDC outbound transfer from the fixture wallet
+pub fn transaction() -> Transaction {
+    from_json(json!({
+        "id": TRANSACTION_ID,
+        "amounts": ["1"],
+        "blockchain": "ETH-SEPOLIA",
+        "blockHash": "0x3333333333333333333333333333333333333333333333333333333333333333",
+        "blockHeight": 7000000,
+        "createDate": TIMESTAMP,
+        "custodyType": "DEVELOPER",
+        "destinationAddress": "0x4444444444444444444444444444444444444444",
+        "estimatedFee": estimated_fee(),
+        "firstConfirmDate": TIMESTAMP,
+        "networkFee": "0.0000882",
+        "operation": "TRANSFER",
+        "refId": "test-transfer",
+        "sourceAddress": WALLET_ADDRESS,
+        "state": "COMPLETE",
+        "tokenId": TOKEN_ID,
+        "transactionType": "OUTBOUND",
+        "txHash": "0x5555555555555555555555555555555555555555555555555555555555555555",
+        "updateDate": TIMESTAMP,
+        "walletId": WALLET_ID
+    }))
+}
+
+/// Response to creating a transfer, contract execution or wallet upgrade
+pub fn create_transaction_response() -> CreateTransferTransactionResponse {
+    from_json(json!({"id": TRANSACTION_ID, "state": "INITIATED"}))
+}
+
+/// A signature returned by the sign endpoints
+pub fn signature() -> SignatureResponse {
+    from_json(json!({
+        "signature": format!("0x{}", "ab".repeat(65))
+    }))
+}
+
+/// A deployed contract owned by the fixture wallet
+pub fn contract() -> Contract {
+    from_json(json!({
+        "id": CONTRACT_ID,
+        "contractAddress": CONTRACT_ADDRESS,
+        "blockchain": "ETH-SEPOLIA",
+        "createDate": TIMESTAMP,
+        "updateDate": TIMESTAMP,
+        "name": "Test contract",
+        "status": "COMPLETE",
+        "deployerWalletId": WALLET_ID,
+        "deploymentTransactionId": TRANSACTION_ID,
+        "contractInputType": "BYTECODE",
+        "archived": false
+    }))
+}
+
+/// An enabled monitor for ERC-20 `Transfer` events on the fixture contract
+pub fn event_monitor() -> EventMonitor {
+    from_json(json!({
+        "id": "66666666-7777-8888-9999-000000000000",
+        "blockchain": "ETH-SEPOLIA",
+        "contractAddress": CONTRACT_ADDRESS,
+        "eventSignature": "Transfer(address,address,uint256)",
+        "eventSignatureHash": "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+        "isEnabled": true,
+        "createDate": TIMESTAMP,
+        "updateDate": TIMESTAMP
+    }))
+}
+
+/// A `Transfer` event log emitted by the fixture contract
+pub fn event_log() -> EventLog {
+    from_json(json!({
+        "id": "77777777-8888-9999-0000-111111111111",
+        "blockHash": "0x3333333333333333333333333333333333333333333333333333333333333333",
+        "blockHeight": 7000000,
+        "blockchain": "ETH-SEPOLIA",
+        "contractAddress": CONTRACT_ADDRESS,
+        "data": format!("0x{:064x}", 1_000_000u64),
+        "eventSignature": "Transfer(address,address,uint256)",
+        "eventSignatureHash": "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+        "logIndex": "0",
+        "topics": [
+            "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+            format!("0x{:0>64}", &WALLET_ADDRESS[2..]),
+            format!("0x{:0>64}", "4444444444444444444444444444444444444444")
+        ],
+        "txHash": "0x5555555555555555555555555555555555555555555555555555555555555555",
+        "userOpHash": "",
+        "firstConfirmDate": TIMESTAMP
+    }))
+}
+
+/// An enabled webhook subscription for all notifications
+pub fn notification_subscription() -> NotificationSubscription {
+    from_json(json!({
+        "id": "88888888-9999-0000-1111-222222222222",
+        "name": "Test webhook",
+        "endpoint": "https://example.com/webhooks/circle",
+        "enabled": true,
+        "createDate": TIMESTAMP,
+        "updateDate": TIMESTAMP,
+        "notificationTypes": ["*"],
+        "restricted": false
+    }))
+}
@@ -0,0 +1,289 @@
+//! Test Doubles
+//!
+//! Available with the `testing` feature. [`MockCircle`] runs a local mock of
+//! the Circle API and hands out real [`CircleOps`] and [`CircleView`]
+//! instances pointed at it, so downstream crates can unit-test wallet flows
+//! without credentials, network access or sandbox rate limits. Responses are
+//! mounted from the canned DTOs in [`fixtures`].
+//!
+//! # Main Components
+//!
+//! - [`MockCircle`]: Local mock server with helpers for common endpoints
+//! - [`fixtures`]: Canned responses for the SDK's DTOs
+//!
+//! # Example
+//!
+//! ```rust
+//! use inf_circle_sdk::testing::{fixtures, MockCircle};
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut circle = MockCircle::start().await;
+//! let wallet = fixtures::dev_wallet();
+//! let mock = circle.mock_wallet(&wallet).await;
+//!
+//! let fetched = circle.view().get_wallet(&wallet.id).await?;
+//! assert_eq!(fetched.wallet.address, wallet.address);
+//! mock.assert_async().await;
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod fixtures;
+
+use serde::Serialize;
+use serde_json::json;
+
+use crate::circle_ops::circler_ops::CircleOps;
+use crate::circle_view::circle_view::CircleView;
+use crate::dev_wallet::dto::{
+    CreateTransferTransactionResponse, DevWallet, TokenBalance, Transaction,
+};
+use crate::helper::HttpClient;
+
+// Re-exported so tests can add custom expectations without a direct dependency
+pub use mockito;
+
+/// Entity secret used by mock clients (hex-encoded 32 bytes)
+pub const MOCK_ENTITY_SECRET: &str =
+    "0000000000000000000000000000000000000000000000000000000000000001";
+
+/// RSA public key used by mock clients to encrypt the entity secret
+pub const MOCK_PUBLIC_KEY: &str = "-----BEGIN PUBLIC KEY-----
+MIIBIjANBgkqhkiG9w0BAQEFAAOCAQ8AMIIBCgKCAQEA4+OE7RamOi1UY2C8oTfM
+jhQ8Nskqi+197LwCrx3hL6RINVxVwtSaWwIw9X17p/zhW7iSIGxa9+4ZmDA17b8P
+b68D5HpCmw0vEAMmiXt7Njk6grpR9UKq3b5+ZY0zs62ToLZNZWgKAANePyLWqLjw
+ZBQ7Ri1GggL9KK8P0NDNKi+tw7OavvX1bE8bDTygyQA01WirjF83bVbRI/axdKsb
+JFi3idXHNRb2qybXA8h/Y52tQ0oea8Zk54YGU9wFkt+sn98q3lMmHnmR2/t8X5B+
+XjqmyZ3fYmiKH4JMBu7EkbH7QrAfNL6rygeUABDGutI7QYOv+48yihAwrSCRDfOn
+KQIDAQAB
+-----END PUBLIC KEY-----";
+
+/// API key sent by mock clients
+pub const MOCK_API_KEY: &str = "TEST_API_KEY:mock:mock";
+
+/// A local mock of the Circle API
+///
+/// Each instance owns its own server, so tests can run in parallel. Unmatched
+/// requests get a 501 response, which surfaces as `CircleError::Api`.
+pub struct MockCircle {
+    server: mockito::ServerGuard,
+}
+
+impl MockCircle {
+    /// Start a mock server
+    pub async fn start() -> Self {
+        Self {
+            server: mockito::Server::new_async().await,
+        }
+    }
+
+    /// Base URL of the mock server
+    pub fn url(&self) -> String {
+        self.server.url()
+    }
+
+    /// Write client pointed at the mock server
+    pub fn ops(&self) -> CircleOps {
+        CircleOps::from_parts(
+            self.client(),
+            MOCK_ENTITY_SECRET.to_string(),
+            MOCK_PUBLIC_KEY.to_string(),
+        )
+    }
+
+    /// Read client pointed at the mock server
+    pub fn view(&self) -> CircleView {
+        CircleView::from_client(self.client())
+    }
+
+    /// Underlying server, for expectations the helpers below do not cover
+    pub fn server(&mut self) -> &mut mockito::ServerGuard {
+        &mut self.server
+    }
+
+    /// Respond to `method path` with `data` wrapped in Circle's `{"data": ...}` envelope
+    ///
+    /// POST requests answer with 201, everything else with 200. The query
+    /// string is ignored.
+    pub async fn respond<T: Serialize>(
+        &mut self,
+        method: &str,
+        path: &str,
+        data: &T,
+    ) -> mockito::Mock {
+        let status = if method.eq_ignore_ascii_case("POST") {
+            201
+        } else {
+            200
+        };
+        self.server
+            .mock(method, path)
+            .match_query(mockito::Matcher::Any)
+            .with_status(status)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "data": data }).to_string())
+            .create_async()
+            .await
+    }
+
+    /// Respond to `method path` with a Circle error body
+    pub async fn respond_error(
+        &mut self,
+        method: &str,
+        path: &str,
+        status: usize,
+        message: &str,
+    ) -> mockito::Mock {
+        self.server
+            .mock(method, path)
+            .match_query(mockito::Matcher::Any)
+            .with_status(status)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "code": status, "message": message }).to_string())
+            .create_async()
+            .await
+    }
+
+    /// Serve `GET /v1/w3s/wallets/{id}` for `wallet`
+    pub async fn mock_wallet(&mut self, wallet: &DevWallet) -> mockito::Mock {
+        let path = format!("/v1/w3s/wallets/{}", wallet.id);
+        self.respond("GET", &path, &json!({ "wallet": wallet }))
+            .await
+    }
+
+    /// Serve `GET /v1/w3s/wallets` with `wallets`
+    pub async fn mock_wallets(&mut self, wallets: &[DevWallet]) -> mockito::Mock {
+        self.respond("GET", "/v1/w3s/wallets", &json!({ "wallets": wallets }))
+            .await
+    }
+
+    /// Serve `POST /v1/w3s/developer/wallets` with `wallets`
+    pub async fn mock_create_wallets(&mut self, wallets: &[DevWallet]) -> mockito::Mock {
+        self.respond(
+            "POST",
+            "/v1/w3s/developer/wallets",
+            &json!({ "wallets": wallets }),
+        )
+        .await
+    }
+
+    /// Serve `GET /v1/w3s/wallets/{wallet_id}/balances` with `balances`
+    pub async fn mock_balances(
+        &mut self,
+        wallet_id: &str,
+        balances: &[TokenBalance],
+    ) -> mockito::Mock {
+        let path = format!("/v1/w3s/wallets/{}/balances", wallet_id);
+        self.respond("GET", &path, &json!({ "tokenBalances": balances }))
+            .await
+    }
+
+    /// Serve `GET /v1/w3s/transactions/{id}` for `transaction`
+    pub async fn mock_transaction(&mut self, transaction: &Transaction) -> mockito::Mock {
+        let path = format!("/v1/w3s/transactions/{}", transaction.id);
+        self.respond("GET", &path, &json!({ "transaction": transaction }))
+            .await
+    }
+
+    /// Serve `GET /v1/w3s/transactions` with `transactions`
+    pub async fn mock_transactions(&mut self, transactions: &[Transaction]) -> mockito::Mock {
+        self.respond(
+            "GET",
+            "/v1/w3s/transactions",
+            &json!({ "transactions": transactions }),
+        )
+        .await
+    }
+
+    /// Serve `POST /v1/w3s/developer/transactions/transfer` with `response`
+    pub async fn mock_transfer(
+        &mut self,
+        response: &CreateTransferTransactionResponse,
+    ) -> mockito::Mock {
+        self.respond("POST", "/v1/w3s/developer/transactions/transfer", response)
+            .await
+    }
+
+    fn client(&self) -> HttpClient {
+        HttpClient::with_api_key(&self.server.url(), MOCK_API_KEY.to_string())
+            .expect("mock server URL is valid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dev_wallet::ops::create_transfer_transaction::CreateTransferTransactionRequestBuilder;
+    use crate::helper::CircleError;
+    use crate::types::Blockchain;
+
+    #[test]
+    fn test_fixtures_deserialize() {
+        assert_eq!(fixtures::dev_wallet().id, fixtures::WALLET_ID);
+        assert_eq!(
+            fixtures::transaction().wallet_id.as_deref(),
+            Some(fixtures::WALLET_ID)
+        );
+        assert_eq!(fixtures::wallet_with_balances().token_balances.len(), 1);
+        assert_eq!(fixtures::nft().token.standard.as_deref(), Some("ERC721"));
+        assert_eq!(
+            fixtures::fee_estimate().high.gas_limit.as_deref(),
+            Some("21000")
+        );
+        assert_eq!(
+            fixtures::contract().id.as_deref(),
+            Some(fixtures::CONTRACT_ID)
+        );
+        assert!(fixtures::event_monitor().is_enabled);
+        assert_eq!(fixtures::event_log().topics.len(), 3);
+        assert!(fixtures::notification_subscription().enabled);
+        assert!(fixtures::signature().signature.starts_with("0x"));
+        assert_eq!(fixtures::create_transaction_response().state, "INITIATED");
+    }
+
+    #[tokio::test]
+    async fn test_mock_transfer_flow() {
+        let mut circle = MockCircle::start().await;
+        let transfer = circle
+            .mock_transfer(&fixtures::create_transaction_response())
+            .await;
+        let lookup = circle.mock_transaction(&fixtures::transaction()).await;
+
+        let builder = CreateTransferTransactionRequestBuilder::new()
+            .wallet_id(fixtures::WALLET_ID.to_string())
+            .destination_address("0x4444444444444444444444444444444444444444".to_string())
+            .amounts(vec!["1".to_string()])
+            .token_id(fixtures::TOKEN_ID.to_string())
+            .blockchain(Blockchain::EthSepolia)
+            .build();
+        let created = circle
+            .ops()
+            .create_dev_transfer_transaction(builder)
+            .await
+            .unwrap();
+        let transaction = circle.view().get_transaction(&created.id).await.unwrap();
+
+        assert_eq!(transaction.transaction.state, "COMPLETE");
+        transfer.assert_async().await;
+        lookup.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_respond_error_surfaces_api_error() {
+        let mut circle = MockCircle::start().await;
+        circle
+            .respond_error("GET", "/v1/w3s/wallets/missing", 404, "Cannot find wallet")
+            .await;
+
+        match circle.view().get_wallet("missing").await {
+            Err(CircleError::Api {
+                status, message, ..
+            }) => {
+                assert_eq!(status, 404);
+                assert_eq!(message, "Cannot find wallet");
+            }
+            other => panic!("expected API error, got {:?}", other.map(|_| ())),
+        }
+    }
+}
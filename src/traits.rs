@@ -0,0 +1,915 @@
+//! Client Traits
+//!
+//! Trait views of [`CircleOps`] and [`CircleView`] so application code can
+//! depend on the operations it uses instead of the concrete clients. That
+//! makes it possible to inject mocks in unit tests, or wrap a client in a
+//! decorator (caching, metrics, rate limiting) without touching call sites.
+//!
+//! Every method delegates to the inherent method of the same name, so the
+//! behavior, errors and idempotency handling are exactly those documented on
+//! the concrete clients.
+//!
+//! # Main Components
+//!
+//! - [`WalletOperations`]: Wallet creation, signing and transaction writes (`CircleOps`)
+//! - [`ContractOperations`]: Contract deployment, import and execution (`CircleOps`)
+//! - [`WalletQueries`]: Wallet, balance, transaction and fee reads (`CircleView`, `CachedCircleView`)
+//! - [`ContractQueries`]: Contract reads and deployment fee estimates (`CircleView`, `CachedCircleView`)
+//! - [`EventMonitoring`]: Event monitor management and event logs (`CircleView`)
+//!
+//! The methods return `Send` futures, so generic code can spawn them on a
+//! multi-threaded runtime. Browser fetches are not `Send`, so this module is
+//! not built for `wasm32`.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::circle_view::circle_view::CircleView;
+//! use inf_circle_sdk::helper::CircleResult;
+//! use inf_circle_sdk::traits::WalletQueries;
+//!
+//! // Works with CircleView, a test mock, or a caching decorator
+//! async fn wallet_address(queries: &impl WalletQueries, wallet_id: &str) -> CircleResult<String> {
+//!     Ok(queries.get_wallet(wallet_id).await?.wallet.address)
+//! }
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let view = CircleView::new()?;
+//! let address = wallet_address(&view, "wallet-id").await?;
+//! println!("Address: {}", address);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+
+use crate::circle_ops::circler_ops::CircleOps;
+use crate::circle_view::cache::CachedCircleView;
+use crate::circle_view::circle_view::CircleView;
+use crate::contract::dto::{
+    Contract, ContractDeploymentResponse, ContractResponse, EventLog, EventMonitor,
+    EventMonitorResponse, ListContractsParams, ListEventLogsParams, ListEventMonitorsParams,
+    QueryContractResponse, TemplateContractDeploymentResponse, UpdateContractRequest,
+};
+use crate::contract::ops::deploy_contract::DeployContractRequestBuilder;
+use crate::contract::ops::deploy_contract_from_template::DeployContractFromTemplateRequestBuilder;
+use crate::contract::ops::import_contract::ImportContractRequestBuilder;
+use crate::contract::views::create_event_monitor::CreateEventMonitorBodyBuilder;
+use crate::contract::views::estimate_contract_deployment::EstimateContractDeploymentBodyBuilder;
+use crate::contract::views::estimate_template_deployment_fee::EstimateTemplateDeploymentFeeBodyBuilder;
+use crate::contract::views::query_contract_view::QueryContractViewBodyBuilder;
+use crate::contract::views::update_event_monitor::UpdateEventMonitorBodyBuilder;
+use crate::dev_wallet::dto::{
+    AccelerateTransactionResponse, AccelerationFeeEstimate, CancelTransactionResponse,
+    CreateContractExecutionTransactionResponse, CreateTransferTransactionResponse,
+    CreateWalletUpgradeTransactionResponse, DevWallet, DevWalletResponse, DevWalletsResponse,
+    EstimateContractExecutionFeeBody, EstimateTransferFeeRequest, ListDevWalletsParams,
+    ListTransactionsParams, ListWalletsWithBalancesParams, NftsResponse, QueryParams,
+    SignDelegateResponse, SignTransactionResponse, SignatureResponse, TokenBalancesResponse,
+    TokenResponse, Transaction, TransactionResponse, UpdateDevWalletRequest, ValidateAddressBody,
+    ValidateAddressResponse, WalletWithBalances,
+};
+use crate::dev_wallet::ops::{
+    accelerate_transaction::AccelerateTransactionRequestBuilder,
+    cancel_transaction::CancelTransactionRequestBuilder,
+    create_contract_transaction::CreateContractExecutionTransactionRequestBuilder,
+    create_dev_wallet::CreateDevWalletRequestBuilder,
+    create_transfer_transaction::CreateTransferTransactionRequestBuilder,
+    create_wallet_upgrade_transaction::CreateWalletUpgradeTransactionRequestBuilder,
+    sign_data::SignDataRequestBuilder, sign_delegate::SignDelegateRequestBuilder,
+    sign_message::SignMessageRequestBuilder, sign_transaction::SignTransactionRequestBuilder,
+};
+use crate::fees::FeeEstimate;
+use crate::helper::{page::Page, CircleResult};
+
+/// Developer-controlled wallet writes
+///
+/// Implemented by [`CircleOps`]. See the inherent methods for request details.
+pub trait WalletOperations: Send + Sync {
+    /// Create one or more wallets
+    fn create_dev_wallet(
+        &self,
+        builder: CreateDevWalletRequestBuilder,
+    ) -> impl Future<Output = CircleResult<DevWalletsResponse>> + Send;
+
+    /// Update a wallet's name or ref ID
+    fn update_dev_wallet(
+        &self,
+        wallet_id: &str,
+        request: UpdateDevWalletRequest,
+    ) -> impl Future<Output = CircleResult<DevWalletResponse>> + Send;
+
+    /// Sign a message
+    fn dev_sign_message(
+        &self,
+        builder: SignMessageRequestBuilder,
+    ) -> impl Future<Output = CircleResult<SignatureResponse>> + Send;
+
+    /// Sign EIP-712 typed data
+    fn dev_sign_data(
+        &self,
+        builder: SignDataRequestBuilder,
+    ) -> impl Future<Output = CircleResult<SignatureResponse>> + Send;
+
+    /// Sign a raw transaction
+    fn dev_sign_transaction(
+        &self,
+        builder: SignTransactionRequestBuilder,
+    ) -> impl Future<Output = CircleResult<SignTransactionResponse>> + Send;
+
+    /// Sign a NEAR delegate action
+    fn dev_sign_delegate(
+        &self,
+        builder: SignDelegateRequestBuilder,
+    ) -> impl Future<Output = CircleResult<SignDelegateResponse>> + Send;
+
+    /// Create a token transfer
+    fn create_dev_transfer_transaction(
+        &self,
+        builder: CreateTransferTransactionRequestBuilder,
+    ) -> impl Future<Output = CircleResult<CreateTransferTransactionResponse>> + Send;
+
+    /// Upgrade a smart contract account wallet
+    fn create_dev_wallet_upgrade_transaction(
+        &self,
+        builder: CreateWalletUpgradeTransactionRequestBuilder,
+    ) -> impl Future<Output = CircleResult<CreateWalletUpgradeTransactionResponse>> + Send;
+
+    /// Cancel a pending transaction
+    fn cancel_dev_transaction(
+        &self,
+        builder: CancelTransactionRequestBuilder,
+    ) -> impl Future<Output = CircleResult<CancelTransactionResponse>> + Send;
+
+    /// Accelerate a pending transaction
+    fn accelerate_dev_transaction(
+        &self,
+        builder: AccelerateTransactionRequestBuilder,
+    ) -> impl Future<Output = CircleResult<AccelerateTransactionResponse>> + Send;
+}
+
+/// Smart contract writes
+///
+/// Implemented by [`CircleOps`]. See the inherent methods for request details.
+pub trait ContractOperations: Send + Sync {
+    /// Deploy a contract from a Circle template
+    fn deploy_contract_from_template(
+        &self,
+        builder: DeployContractFromTemplateRequestBuilder,
+    ) -> impl Future<Output = CircleResult<TemplateContractDeploymentResponse>> + Send;
+
+    /// Deploy a contract from ABI and bytecode
+    fn deploy_contract(
+        &self,
+        builder: DeployContractRequestBuilder,
+    ) -> impl Future<Output = CircleResult<ContractDeploymentResponse>> + Send;
+
+    /// Import an existing contract
+    fn import_contract(
+        &self,
+        builder: ImportContractRequestBuilder,
+    ) -> impl Future<Output = CircleResult<ContractResponse>> + Send;
+
+    /// Execute a contract function from a wallet
+    fn create_dev_contract_execution_transaction(
+        &self,
+        builder: CreateContractExecutionTransactionRequestBuilder,
+    ) -> impl Future<Output = CircleResult<CreateContractExecutionTransactionResponse>> + Send;
+}
+
+/// Wallet, balance, transaction and fee reads
+///
+/// Implemented by [`CircleView`] and [`CachedCircleView`]. See the inherent methods for request details.
+pub trait WalletQueries: Send + Sync {
+    /// List wallets
+    fn list_wallets(
+        &self,
+        params: ListDevWalletsParams,
+    ) -> impl Future<Output = CircleResult<Page<DevWallet>>> + Send;
+
+    /// List wallets together with their token balances
+    fn list_wallets_with_token_balances(
+        &self,
+        params: ListWalletsWithBalancesParams,
+    ) -> impl Future<Output = CircleResult<Page<WalletWithBalances>>> + Send;
+
+    /// Get a wallet by ID
+    fn get_wallet(
+        &self,
+        wallet_id: &str,
+    ) -> impl Future<Output = CircleResult<DevWalletResponse>> + Send;
+
+    /// Get a wallet's token balances
+    fn get_token_balances(
+        &self,
+        wallet_id: &str,
+        params: QueryParams,
+    ) -> impl Future<Output = CircleResult<TokenBalancesResponse>> + Send;
+
+    /// Get a wallet's NFTs
+    fn get_nfts(
+        &self,
+        wallet_id: &str,
+        params: QueryParams,
+    ) -> impl Future<Output = CircleResult<NftsResponse>> + Send;
+
+    /// List transactions
+    fn list_transactions(
+        &self,
+        params: ListTransactionsParams,
+    ) -> impl Future<Output = CircleResult<Page<Transaction>>> + Send;
+
+    /// Get a transaction by ID
+    fn get_transaction(
+        &self,
+        tx_id: &str,
+    ) -> impl Future<Output = CircleResult<TransactionResponse>> + Send;
+
+    /// Get a token by ID
+    fn get_token(&self, token_id: &str)
+        -> impl Future<Output = CircleResult<TokenResponse>> + Send;
+
+    /// Validate an address for a blockchain
+    fn validate_address(
+        &self,
+        body: ValidateAddressBody,
+    ) -> impl Future<Output = CircleResult<ValidateAddressResponse>> + Send;
+
+    /// Estimate the fee of a contract execution
+    fn estimate_contract_execution_fee(
+        &self,
+        request: EstimateContractExecutionFeeBody,
+    ) -> impl Future<Output = CircleResult<FeeEstimate>> + Send;
+
+    /// Estimate the fee of a transfer
+    fn estimate_transfer_fee(
+        &self,
+        request: EstimateTransferFeeRequest,
+    ) -> impl Future<Output = CircleResult<FeeEstimate>> + Send;
+
+    /// Estimate the fee to accelerate a transaction
+    fn estimate_transaction_acceleration_fee(
+        &self,
+        tx_id: &str,
+    ) -> impl Future<Output = CircleResult<AccelerationFeeEstimate>> + Send;
+}
+
+/// Smart contract reads
+///
+/// Implemented by [`CircleView`] and [`CachedCircleView`]. See the inherent methods for request details.
+pub trait ContractQueries: Send + Sync {
+    /// List contracts
+    fn list_contracts(
+        &self,
+        params: Option<ListContractsParams>,
+    ) -> impl Future<Output = CircleResult<Page<Contract>>> + Send;
+
+    /// Get a contract by ID
+    fn get_contract(
+        &self,
+        contract_id: &str,
+    ) -> impl Future<Output = CircleResult<ContractResponse>> + Send;
+
+    /// Update a contract's name or archive state
+    fn update_contract(
+        &self,
+        contract_id: &str,
+        request: UpdateContractRequest,
+    ) -> impl Future<Output = CircleResult<ContractResponse>> + Send;
+
+    /// Call a read-only contract function
+    fn query_contract(
+        &self,
+        builder: QueryContractViewBodyBuilder,
+    ) -> impl Future<Output = CircleResult<QueryContractResponse>> + Send;
+
+    /// Estimate the fee of a bytecode deployment
+    fn estimate_contract_deployment_fee(
+        &self,
+        builder: EstimateContractDeploymentBodyBuilder,
+    ) -> impl Future<Output = CircleResult<FeeEstimate>> + Send;
+
+    /// Estimate the fee of a template deployment
+    fn estimate_template_deployment_fee(
+        &self,
+        builder: EstimateTemplateDeploymentFeeBodyBuilder,
+    ) -> impl Future<Output = CircleResult<FeeEstimate>> + Send;
+}
+
+/// Contract event monitors and event logs
+///
+/// Implemented by [`CircleView`]. See the inherent methods for request details.
+pub trait EventMonitoring: Send + Sync {
+    /// Create an event monitor
+    fn create_event_monitor(
+        &self,
+        builder: CreateEventMonitorBodyBuilder,
+    ) -> impl Future<Output = CircleResult<EventMonitorResponse>> + Send;
+
+    /// Enable or disable an event monitor
+    fn update_event_monitor(
+        &self,
+        builder: UpdateEventMonitorBodyBuilder,
+    ) -> impl Future<Output = CircleResult<EventMonitorResponse>> + Send;
+
+    /// Delete an event monitor
+    fn delete_event_monitor(
+        &self,
+        monitor_id: &str,
+    ) -> impl Future<Output = CircleResult<()>> + Send;
+
+    /// List event monitors
+    fn list_event_monitors(
+        &self,
+        params: Option<ListEventMonitorsParams>,
+    ) -> impl Future<Output = CircleResult<Page<EventMonitor>>> + Send;
+
+    /// List event logs captured by monitors
+    fn list_event_logs(
+        &self,
+        params: Option<ListEventLogsParams>,
+    ) -> impl Future<Output = CircleResult<Page<EventLog>>> + Send;
+}
+
+impl WalletOperations for CircleOps {
+    fn create_dev_wallet(
+        &self,
+        builder: CreateDevWalletRequestBuilder,
+    ) -> impl Future<Output = CircleResult<DevWalletsResponse>> + Send {
+        CircleOps::create_dev_wallet(self, builder)
+    }
+
+    fn update_dev_wallet(
+        &self,
+        wallet_id: &str,
+        request: UpdateDevWalletRequest,
+    ) -> impl Future<Output = CircleResult<DevWalletResponse>> + Send {
+        CircleOps::update_dev_wallet(self, wallet_id, request)
+    }
+
+    fn dev_sign_message(
+        &self,
+        builder: SignMessageRequestBuilder,
+    ) -> impl Future<Output = CircleResult<SignatureResponse>> + Send {
+        CircleOps::dev_sign_message(self, builder)
+    }
+
+    fn dev_sign_data(
+        &self,
+        builder: SignDataRequestBuilder,
+    ) -> impl Future<Output = CircleResult<SignatureResponse>> + Send {
+        CircleOps::dev_sign_data(self, builder)
+    }
+
+    fn dev_sign_transaction(
+        &self,
+        builder: SignTransactionRequestBuilder,
+    ) -> impl Future<Output = CircleResult<SignTransactionResponse>> + Send {
+        CircleOps::dev_sign_transaction(self, builder)
+    }
+
+    fn dev_sign_delegate(
+        &self,
+        builder: SignDelegateRequestBuilder,
+    ) -> impl Future<Output = CircleResult<SignDelegateResponse>> + Send {
+        CircleOps::dev_sign_delegate(self, builder)
+    }
+
+    fn create_dev_transfer_transaction(
+        &self,
+        builder: CreateTransferTransactionRequestBuilder,
+    ) -> impl Future<Output = CircleResult<CreateTransferTransactionResponse>> + Send {
+        CircleOps::create_dev_transfer_transaction(self, builder)
+    }
+
+    fn create_dev_wallet_upgrade_transaction(
+        &self,
+        builder: CreateWalletUpgradeTransactionRequestBuilder,
+    ) -> impl Future<Output = CircleResult<CreateWalletUpgradeTransactionResponse>> + Send {
+        CircleOps::create_dev_wallet_upgrade_transaction(self, builder)
+    }
+
+    fn cancel_dev_transaction(
+        &self,
+        builder: CancelTransactionRequestBuilder,
+    ) -> impl Future<Output = CircleResult<CancelTransactionResponse>> + Send {
+        CircleOps::cancel_dev_transaction(self, builder)
+    }
+
+    fn accelerate_dev_transaction(
+        &self,
+        builder: AccelerateTransactionRequestBuilder,
+    ) -> impl Future<Output = CircleResult<AccelerateTransactionResponse>> + Send {
+        CircleOps::accelerate_dev_transaction(self, builder)
+    }
+}
+
+impl ContractOperations for CircleOps {
+    fn deploy_contract_from_template(
+        &self,
+        builder: DeployContractFromTemplateRequestBuilder,
+    ) -> impl Future<Output = CircleResult<TemplateContractDeploymentResponse>> + Send {
+        CircleOps::deploy_contract_from_template(self, builder)
+    }
+
+    fn deploy_contract(
+        &self,
+        builder: DeployContractRequestBuilder,
+    ) -> impl Future<Output = CircleResult<ContractDeploymentResponse>> + Send {
+        CircleOps::deploy_contract(self, builder)
+    }
+
+    fn import_contract(
+        &self,
+        builder: ImportContractRequestBuilder,
+    ) -> impl Future<Output = CircleResult<ContractResponse>> + Send {
+        CircleOps::import_contract(self, builder)
+    }
+
+    fn create_dev_contract_execution_transaction(
+        &self,
+        builder: CreateContractExecutionTransactionRequestBuilder,
+    ) -> impl Future<Output = CircleResult<CreateContractExecutionTransactionResponse>> + Send {
+        CircleOps::create_dev_contract_execution_transaction(self, builder)
+    }
+}
+
+impl WalletQueries for CircleView {
+    fn list_wallets(
+        &self,
+        params: ListDevWalletsParams,
+    ) -> impl Future<Output = CircleResult<Page<DevWallet>>> + Send {
+        CircleView::list_wallets(self, params)
+    }
+
+    fn list_wallets_with_token_balances(
+        &self,
+        params: ListWalletsWithBalancesParams,
+    ) -> impl Future<Output = CircleResult<Page<WalletWithBalances>>> + Send {
+        CircleView::list_wallets_with_token_balances(self, params)
+    }
+
+    fn get_wallet(
+        &self,
+        wallet_id: &str,
+    ) -> impl Future<Output = CircleResult<DevWalletResponse>> + Send {
+        CircleView::get_wallet(self, wallet_id)
+    }
+
+    fn get_token_balances(
+        &self,
+        wallet_id: &str,
+        params: QueryParams,
+    ) -> impl Future<Output = CircleResult<TokenBalancesResponse>> + Send {
+        CircleView::get_token_balances(self, wallet_id, params)
+    }
+
+    fn get_nfts(
+        &self,
+        wallet_id: &str,
+        params: QueryParams,
+    ) -> impl Future<Output = CircleResult<NftsResponse>> + Send {
+        CircleView::get_nfts(self, wallet_id, params)
+    }
+
+    fn list_transactions(
+        &self,
+        params: ListTransactionsParams,
+    ) -> impl Future<Output = CircleResult<Page<Transaction>>> + Send {
+        CircleView::list_transactions(self, params)
+    }
+
+    fn get_transaction(
+        &self,
+        tx_id: &str,
+    ) -> impl Future<Output = CircleResult<TransactionResponse>> + Send {
+        CircleView::get_transaction(self, tx_id)
+    }
+
+    fn get_token(
+        &self,
+        token_id: &str,
+    ) -> impl Future<Output = CircleResult<TokenResponse>> + Send {
+        CircleView::get_token(self, token_id)
+    }
+
+    fn validate_address(
+        &self,
+        body: ValidateAddressBody,
+    ) -> impl Future<Output = CircleResult<ValidateAddressResponse>> + Send {
+        CircleView::validate_address(self, body)
+    }
+
+    fn estimate_contract_execution_fee(
+        &self,
+        request: EstimateContractExecutionFeeBody,
+    ) -> impl Future<Output = CircleResult<FeeEstimate>> + Send {
+        CircleView::estimate_contract_execution_fee(self, request)
+    }
+
+    fn estimate_transfer_fee(
+        &self,
+        request: EstimateTransferFeeRequest,
+    ) -> impl Future<Output = CircleResult<FeeEstimate>> + Send {
+        CircleView::estimate_transfer_fee(self, request)
+    }
+
+    fn estimate_transaction_acceleration_fee(
+        &self,
+        tx_id: &str,
+    ) -> impl Future<Output = CircleResult<AccelerationFeeEstimate>> + Send {
+        CircleView::estimate_transaction_acceleration_fee(self, tx_id)
+    }
+}
+
+impl ContractQueries for CircleView {
+    fn list_contracts(
+        &self,
+        params: Option<ListContractsParams>,
+    ) -> impl Future<Output = CircleResult<Page<Contract>>> + Send {
+        CircleView::list_contracts(self, params)
+    }
+
+    fn get_contract(
+        &self,
+        contract_id: &str,
+    ) -> impl Future<Output = CircleResult<ContractResponse>> + Send {
+        CircleView::get_contract(self, contract_id)
+    }
+
+    fn update_contract(
+        &self,
+        contract_id: &str,
+        request: UpdateContractRequest,
+    ) -> impl Future<Output = CircleResult<ContractResponse>> + Send {
+        CircleView::update_contract(self, contract_id, request)
+    }
+
+    fn query_contract(
+        &self,
+        builder: QueryContractViewBodyBuilder,
+    ) -> impl Future<Output = CircleResult<QueryContractResponse>> + Send {
+        CircleView::query_contract(self, builder)
+    }
+
+    fn estimate_contract_deployment_fee(
+        &self,
+        builder: EstimateContractDeploymentBodyBuilder,
+    ) -> impl Future<Output = CircleResult<FeeEstimate>> + Send {
+        CircleView::estimate_contract_deployment_fee(self, builder)
+    }
+
+    fn estimate_template_deployment_fee(
+        &self,
+        builder: EstimateTemplateDeploymentFeeBodyBuilder,
+    ) -> impl Future<Output = CircleResult<FeeEstimate>> + Send {
+        CircleView::estimate_template_deployment_fee(self, builder)
+    }
+}
+
+impl WalletQueries for CachedCircleView {
+    fn list_wallets(
+        &self,
+        params: ListDevWalletsParams,
+    ) -> impl Future<Output = CircleResult<Page<DevWallet>>> + Send {
+        CircleView::list_wallets(self.view(), params)
+    }
+
+    fn list_wallets_with_token_balances(
+        &self,
+        params: ListWalletsWithBalancesParams,
+    ) -> impl Future<Output = CircleResult<Page<WalletWithBalances>>> + Send {
+        CircleView::list_wallets_with_token_balances(self.view(), params)
+    }
+
+    fn get_wallet(
+        &self,
+        wallet_id: &str,
+    ) -> impl Future<Output = CircleResult<DevWalletResponse>> + Send {
+        CachedCircleView::get_wallet(self, wallet_id)
+    }
+
+    fn get_token_balances(
+        &self,
+        wallet_id: &str,
+        params: QueryParams,
+    ) -> impl Future<Output = CircleResult<TokenBalancesResponse>> + Send {
+        CircleView::get_token_balances(self.view(), wallet_id, params)
+    }
+
+    fn get_nfts(
+        &self,
+        wallet_id: &str,
+        params: QueryParams,
+    ) -> impl Future<Output = CircleResult<NftsResponse>> + Send {
+        CircleView::get_nfts(self.view(), wallet_id, params)
+    }
+
+    fn list_transactions(
+        &self,
+        params: ListTransactionsParams,
+    ) -> impl Future<Output = CircleResult<Page<Transaction>>> + Send {
+        CircleView::list_transactions(self.view(), params)
+    }
+
+    fn get_transaction(
+        &self,
+        tx_id: &str,
+    ) -> impl Future<Output = CircleResult<TransactionResponse>> + Send {
+        CircleView::get_transaction(self.view(), tx_id)
+    }
+
+    fn get_token(
+        &self,
+        token_id: &str,
+    ) -> impl Future<Output = CircleResult<TokenResponse>> + Send {
+        CachedCircleView::get_token(self, token_id)
+    }
+
+    fn validate_address(
+        &self,
+        body: ValidateAddressBody,
+    ) -> impl Future<Output = CircleResult<ValidateAddressResponse>> + Send {
+        CircleView::validate_address(self.view(), body)
+    }
+
+    fn estimate_contract_execution_fee(
+        &self,
+        request: EstimateContractExecutionFeeBody,
+    ) -> impl Future<Output = CircleResult<FeeEstimate>> + Send {
+        CircleView::estimate_contract_execution_fee(self.view(), request)
+    }
+
+    fn estimate_transfer_fee(
+        &self,
+        request: EstimateTransferFeeRequest,
+    ) -> impl Future<Output = CircleResult<FeeEstimate>> + Send {
+        CircleView::estimate_transfer_fee(self.view(), request)
+    }
+
+    fn estimate_transaction_acceleration_fee(
+        &self,
+        tx_id: &str,
+    ) -> impl Future<Output = CircleResult<AccelerationFeeEstimate>> + Send {
+        CircleView::estimate_transaction_acceleration_fee(self.view(), tx_id)
+    }
+}
+
+impl ContractQueries for CachedCircleView {
+    fn list_contracts(
+        &self,
+        params: Option<ListContractsParams>,
+    ) -> impl Future<Output = CircleResult<Page<Contract>>> + Send {
+        CircleView::list_contracts(self.view(), params)
+    }
+
+    fn get_contract(
+        &self,
+        contract_id: &str,
+    ) -> impl Future<Output = CircleResult<ContractResponse>> + Send {
+        CachedCircleView::get_contract(self, contract_id)
+    }
+
+    fn update_contract(
+        &self,
+        contract_id: &str,
+        request: UpdateContractRequest,
+    ) -> impl Future<Output = CircleResult<ContractResponse>> + Send {
+        CachedCircleView::update_contract(self, contract_id, request)
+    }
+
+    fn query_contract(
+        &self,
+        builder: QueryContractViewBodyBuilder,
+    ) -> impl Future<Output = CircleResult<QueryContractResponse>> + Send {
+        CircleView::query_contract(self.view(), builder)
+    }
+
+    fn estimate_contract_deployment_fee(
+        &self,
+        builder: EstimateContractDeploymentBodyBuilder,
+    ) -> impl Future<Output = CircleResult<FeeEstimate>> + Send {
+        CircleView::estimate_contract_deployment_fee(self.view(), builder)
+    }
+
+    fn estimate_template_deployment_fee(
+        &self,
+        builder: EstimateTemplateDeploymentFeeBodyBuilder,
+    ) -> impl Future<Output = CircleResult<FeeEstimate>> + Send {
+        CircleView::estimate_template_deployment_fee(self.view(), builder)
+    }
+}
+
+impl EventMonitoring for CircleView {
+    fn create_event_monitor(
+        &self,
+        builder: CreateEventMonitorBodyBuilder,
+    ) -> impl Future<Output = CircleResult<EventMonitorResponse>> + Send {
+        CircleView::create_event_monitor(self, builder)
+    }
+
+    fn update_event_monitor(
+        &self,
+        builder: UpdateEventMonitorBodyBuilder,
+    ) -> impl Future<Output = CircleResult<EventMonitorResponse>> + Send {
+        CircleView::update_event_monitor(self, builder)
+    }
+
+    fn delete_event_monitor(
+        &self,
+        monitor_id: &str,
+    ) -> impl Future<Output = CircleResult<()>> + Send {
+        CircleView::delete_event_monitor(self, monitor_id)
+    }
+
+    fn list_event_monitors(
+        &self,
+        params: Option<ListEventMonitorsParams>,
+    ) -> impl Future<Output = CircleResult<Page<EventMonitor>>> + Send {
+        CircleView::list_event_monitors(self, params)
+    }
+
+    fn list_event_logs(
+        &self,
+        params: Option<ListEventLogsParams>,
+    ) -> impl Future<Output = CircleResult<Page<EventLog>>> + Send {
+        CircleView::list_event_logs(self, params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::dev_wallet::dto::DevWallet;
+    use crate::helper::CircleError;
+    use crate::testing::{fixtures, MockCircle};
+
+    /// Decorator that caches `get_wallet` and forwards everything else
+    struct CachingQueries<Q> {
+        inner: Q,
+        wallets: Mutex<HashMap<String, DevWallet>>,
+        misses: AtomicUsize,
+    }
+
+    impl<Q: WalletQueries> CachingQueries<Q> {
+        fn new(inner: Q) -> Self {
+            Self {
+                inner,
+                wallets: Mutex::new(HashMap::new()),
+                misses: AtomicUsize::new(0),
+            }
+        }
+
+        async fn cached_wallet(&self, wallet_id: &str) -> CircleResult<DevWalletResponse> {
+            if let Some(wallet) = self.wallets.lock().unwrap().get(wallet_id) {
+                return Ok(DevWalletResponse {
+                    wallet: wallet.clone(),
+                });
+            }
+            self.misses.fetch_add(1, Ordering::SeqCst);
+            let response = self.inner.get_wallet(wallet_id).await?;
+            self.wallets
+                .lock()
+                .unwrap()
+                .insert(wallet_id.to_string(), response.wallet.clone());
+            Ok(response)
+        }
+    }
+
+    impl<Q: WalletQueries> WalletQueries for CachingQueries<Q> {
+        fn list_wallets(
+            &self,
+            params: ListDevWalletsParams,
+        ) -> impl Future<Output = CircleResult<Page<DevWallet>>> + Send {
+            self.inner.list_wallets(params)
+        }
+
+        fn list_wallets_with_token_balances(
+            &self,
+            params: ListWalletsWithBalancesParams,
+        ) -> impl Future<Output = CircleResult<Page<WalletWithBalances>>> + Send {
+            self.inner.list_wallets_with_token_balances(params)
+        }
+
+        fn get_wallet(
+            &self,
+            wallet_id: &str,
+        ) -> impl Future<Output = CircleResult<DevWalletResponse>> + Send {
+            self.cached_wallet(wallet_id)
+        }
+
+        fn get_token_balances(
+            &self,
+            wallet_id: &str,
+            params: QueryParams,
+        ) -> impl Future<Output = CircleResult<TokenBalancesResponse>> + Send {
+            self.inner.get_token_balances(wallet_id, params)
+        }
+
+        fn get_nfts(
+            &self,
+            wallet_id: &str,
+            params: QueryParams,
+        ) -> impl Future<Output = CircleResult<NftsResponse>> + Send {
+            self.inner.get_nfts(wallet_id, params)
+        }
+
+        fn list_transactions(
+            &self,
+            params: ListTransactionsParams,
+        ) -> impl Future<Output = CircleResult<Page<Transaction>>> + Send {
+            self.inner.list_transactions(params)
+        }
+
+        fn get_transaction(
+            &self,
+            tx_id: &str,
+        ) -> impl Future<Output = CircleResult<TransactionResponse>> + Send {
+            self.inner.get_transaction(tx_id)
+        }
+
+        fn get_token(
+            &self,
+            token_id: &str,
+        ) -> impl Future<Output = CircleResult<TokenResponse>> + Send {
+            self.inner.get_token(token_id)
+        }
+
+        fn validate_address(
+            &self,
+            body: ValidateAddressBody,
+        ) -> impl Future<Output = CircleResult<ValidateAddressResponse>> + Send {
+            self.inner.validate_address(body)
+        }
+
+        fn estimate_contract_execution_fee(
+            &self,
+            request: EstimateContractExecutionFeeBody,
+        ) -> impl Future<Output = CircleResult<FeeEstimate>> + Send {
+            self.inner.estimate_contract_execution_fee(request)
+        }
+
+        fn estimate_transfer_fee(
+            &self,
+            request: EstimateTransferFeeRequest,
+        ) -> impl Future<Output = CircleResult<FeeEstimate>> + Send {
+            self.inner.estimate_transfer_fee(request)
+        }
+
+        fn estimate_transaction_acceleration_fee(
+            &self,
+            tx_id: &str,
+        ) -> impl Future<Output = CircleResult<AccelerationFeeEstimate>> + Send {
+            self.inner.estimate_transaction_acceleration_fee(tx_id)
+        }
+    }
+
+    async fn wallet_address(queries: &impl WalletQueries, wallet_id: &str) -> CircleResult<String> {
+        Ok(queries.get_wallet(wallet_id).await?.wallet.address)
+    }
+
+    #[tokio::test]
+    async fn test_generic_code_runs_against_circle_view() {
+        let mut circle = MockCircle::start().await;
+        let wallet = fixtures::dev_wallet();
+        let mock = circle.mock_wallet(&wallet).await;
+
+        let address = wallet_address(&circle.view(), &wallet.id).await.unwrap();
+
+        assert_eq!(address, wallet.address);
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_decorator_wraps_circle_view() {
+        let mut circle = MockCircle::start().await;
+        let wallet = fixtures::dev_wallet();
+        let mock = circle
+            .server()
+            .mock("GET", format!("/v1/w3s/wallets/{}", wallet.id).as_str())
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::json!({ "data": { "wallet": wallet } }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let cached = CachingQueries::new(circle.view());
+        for _ in 0..3 {
+            assert_eq!(
+                wallet_address(&cached, &wallet.id).await.unwrap(),
+                wallet.address
+            );
+        }
+
+        assert_eq!(cached.misses.load(Ordering::SeqCst), 1);
+        mock.assert_async().await;
+
+        let missing = cached.get_transaction("missing").await;
+        assert!(matches!(missing, Err(CircleError::Api { status: 501, .. })));
+    }
+}
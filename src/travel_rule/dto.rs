@@ -0,0 +1,235 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::contract::dto::NotificationType;
+
+/// Whether a travel rule party is a natural person or a legal entity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TravelRuleIdentityType {
+    Individual,
+    Business,
+}
+
+/// Postal address of a travel rule party
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TravelRuleAddress {
+    /// Street address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub street: Option<String>,
+
+    /// City
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+
+    /// State, province or region
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+
+    /// Postal code
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<String>,
+
+    /// ISO 3166-1 alpha-2 country code
+    pub country: String,
+}
+
+/// Identity of the originator or beneficiary of a transfer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TravelRuleIdentity {
+    /// Individual or business
+    #[serde(rename = "type")]
+    pub identity_type: TravelRuleIdentityType,
+
+    /// Full legal name
+    pub name: String,
+
+    /// Postal address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<TravelRuleAddress>,
+
+    /// Blockchain address the party sends from or receives to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wallet_address: Option<String>,
+
+    /// Name of the VASP hosting the party's wallet, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vasp_name: Option<String>,
+}
+
+impl TravelRuleIdentity {
+    /// Identity of a natural person
+    pub fn individual(name: impl Into<String>) -> Self {
+        Self::new(TravelRuleIdentityType::Individual, name)
+    }
+
+    /// Identity of a legal entity
+    pub fn business(name: impl Into<String>) -> Self {
+        Self::new(TravelRuleIdentityType::Business, name)
+    }
+
+    fn new(identity_type: TravelRuleIdentityType, name: impl Into<String>) -> Self {
+        Self {
+            identity_type,
+            name: name.into(),
+            address: None,
+            wallet_address: None,
+            vasp_name: None,
+        }
+    }
+
+    /// Set the postal address
+    pub fn address(mut self, address: TravelRuleAddress) -> Self {
+        self.address = Some(address);
+        self
+    }
+
+    /// Set the blockchain address
+    pub fn wallet_address(mut self, wallet_address: impl Into<String>) -> Self {
+        self.wallet_address = Some(wallet_address.into());
+        self
+    }
+
+    /// Set the hosting VASP's name
+    pub fn vasp_name(mut self, vasp_name: impl Into<String>) -> Self {
+        self.vasp_name = Some(vasp_name.into());
+        self
+    }
+}
+
+/// Request structure for submitting travel rule information
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmitTravelRuleRequest {
+    /// Sender of the transfer
+    pub originator: TravelRuleIdentity,
+
+    /// Recipient of the transfer
+    pub beneficiary: TravelRuleIdentity,
+
+    /// UUID v4 for idempotency
+    pub idempotency_key: String,
+}
+
+/// Review status of a transfer's travel rule information
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TravelRuleStatus {
+    /// Submitted and awaiting review
+    Pending,
+    /// Accepted; the transfer may proceed
+    Approved,
+    /// Rejected; the transfer will not be broadcast
+    Denied,
+    /// A status this SDK version does not know about
+    #[serde(other)]
+    Unknown,
+}
+
+/// Travel rule information attached to a transaction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TravelRuleRecord {
+    /// System-generated unique identifier of the resource
+    pub id: String,
+
+    /// Transaction the information is attached to
+    pub transaction_id: String,
+
+    /// Review status
+    pub status: TravelRuleStatus,
+
+    /// Sender of the transfer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub originator: Option<TravelRuleIdentity>,
+
+    /// Recipient of the transfer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub beneficiary: Option<TravelRuleIdentity>,
+
+    /// Reason given for a denial
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+
+    /// Creation timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_date: Option<DateTime<Utc>>,
+
+    /// Last update timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update_date: Option<DateTime<Utc>>,
+}
+
+/// Response structure for travel rule submissions and lookups
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TravelRuleResponse {
+    pub travel_rule: TravelRuleRecord,
+}
+
+/// Envelope of a `travelRule.*` webhook notification
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TravelRuleNotification {
+    /// Notification subscription that produced this notification
+    pub subscription_id: String,
+
+    /// Unique identifier of the notification
+    pub notification_id: String,
+
+    /// Type of the notification
+    pub notification_type: NotificationType,
+
+    /// Notification payload
+    pub notification: Value,
+
+    /// Timestamp of the notification
+    pub timestamp: DateTime<Utc>,
+
+    /// Payload version
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<u32>,
+}
+
+/// Payload of a `travelRule.statusUpdate`, `travelRule.approve` or `travelRule.deny` notification
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TravelRuleStatusEvent {
+    /// Identifier of the travel rule record
+    pub id: String,
+
+    /// Transaction the information is attached to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_id: Option<String>,
+
+    /// Review status after the change
+    pub status: TravelRuleStatus,
+
+    /// Reason given for a denial
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+
+    /// Fields not modelled above
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Typed payload of a travel rule notification
+#[derive(Debug, Clone)]
+pub enum TravelRuleEvent {
+    StatusUpdate(TravelRuleStatusEvent),
+    Approve(TravelRuleStatusEvent),
+    Deny(TravelRuleStatusEvent),
+}
+
+impl TravelRuleEvent {
+    /// The payload, whichever event it came from
+    pub fn status_event(&self) -> &TravelRuleStatusEvent {
+        match self {
+            Self::StatusUpdate(event) | Self::Approve(event) | Self::Deny(event) => event,
+        }
+    }
+}
@@ -0,0 +1,74 @@
+//! Travel Rule Compliance
+//!
+//! Transfers above a jurisdiction's threshold must carry identity data for
+//! the originator and beneficiary (the FATF "Travel Rule"). This module
+//! submits that data for developer-controlled wallet transfers, reads back the
+//! review status, and provides typed payloads for the `travelRule.*` webhook
+//! notifications.
+//!
+//! # Main Components
+//!
+//! - [`travel_rule_ops`]: Write operations (submit identity data, transfer with identity data)
+//! - [`travel_rule_view`]: Read operations (travel rule status of a transaction)
+//! - [`notification`]: [`parse_travel_rule_notification`] for webhook bodies
+//! - [`dto`]: Data transfer objects (identities, status records, notifications)
+//! - [`ops`]: Builder modules for write operations
+//!
+//! # Example - Transfer with Travel Rule Data
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::{
+//!     circle_ops::circler_ops::CircleOps,
+//!     dev_wallet::{dto::FeeLevel, ops::create_transfer_transaction::CreateTransferTransactionRequestBuilder},
+//!     travel_rule::dto::{TravelRuleAddress, TravelRuleIdentity},
+//! };
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let ops = CircleOps::new(None)?;
+//!
+//! let transfer = CreateTransferTransactionRequestBuilder::new()
+//!     .wallet_id("wallet-id".to_string())
+//!     .destination_address("0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb0".to_string())
+//!     .amounts(["2500"])
+//!     .token_id("token-id".to_string())
+//!     .fee_level(FeeLevel::Medium)
+//!     .build();
+//!
+//! let originator = TravelRuleIdentity::business("Acme Ltd").address(TravelRuleAddress {
+//!     city: Some("London".to_string()),
+//!     country: "GB".to_string(),
+//!     ..Default::default()
+//! });
+//! let beneficiary = TravelRuleIdentity::individual("Jane Doe").vasp_name("Example Exchange");
+//!
+//! let (transfer, travel_rule) = ops
+//!     .create_dev_transfer_with_travel_rule(transfer, originator, beneficiary)
+//!     .await?;
+//! println!("{}: {:?}", transfer.id, travel_rule.travel_rule.status);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Example - Handle a Webhook
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::travel_rule::{dto::TravelRuleEvent, parse_travel_rule_notification};
+//!
+//! # fn example(body: &str) -> Result<(), Box<dyn std::error::Error>> {
+//! match parse_travel_rule_notification(body)?.1 {
+//!     TravelRuleEvent::Approve(e) => println!("Approved {:?}", e.transaction_id),
+//!     TravelRuleEvent::Deny(e) => println!("Denied {:?}: {:?}", e.transaction_id, e.reason),
+//!     TravelRuleEvent::StatusUpdate(e) => println!("{} is now {:?}", e.id, e.status),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod dto;
+pub mod notification;
+pub mod ops;
+pub mod travel_rule_ops;
+pub mod travel_rule_view;
+
+// Re-export commonly used items
+pub use notification::parse_travel_rule_notification;
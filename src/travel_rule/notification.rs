@@ -0,0 +1,101 @@
+//! Travel Rule Webhook Parsing
+
+use crate::contract::dto::NotificationType;
+use crate::helper::{CircleError, CircleResult};
+use crate::travel_rule::dto::{TravelRuleEvent, TravelRuleNotification};
+
+/// Parse a `travelRule.*` webhook body into its envelope and typed payload
+///
+/// # Errors
+///
+/// Returns `CircleError::Config` if the notification is not a travel rule
+/// notification, or `CircleError::Json` if the payload does not match its type.
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::travel_rule::{parse_travel_rule_notification, dto::{TravelRuleEvent, TravelRuleStatus}};
+///
+/// let body = r#"{
+///     "subscriptionId": "sub-1",
+///     "notificationId": "n-1",
+///     "notificationType": "travelRule.deny",
+///     "notification": {"id": "tr-1", "transactionId": "tx-1", "status": "DENIED", "reason": "Missing address"},
+///     "timestamp": "2025-01-01T00:00:00Z",
+///     "version": 2
+/// }"#;
+///
+/// let (_, event) = parse_travel_rule_notification(body)?;
+/// assert!(matches!(event, TravelRuleEvent::Deny(ref e) if e.status == TravelRuleStatus::Denied));
+/// # Ok::<(), inf_circle_sdk::helper::CircleError>(())
+/// ```
+pub fn parse_travel_rule_notification(
+    body: &str,
+) -> CircleResult<(TravelRuleNotification, TravelRuleEvent)> {
+    let notification: TravelRuleNotification = serde_json::from_str(body)?;
+    let payload = notification.notification.clone();
+
+    let event = match notification.notification_type {
+        NotificationType::TravelRuleStatusUpdate => {
+            TravelRuleEvent::StatusUpdate(serde_json::from_value(payload)?)
+        }
+        NotificationType::TravelRuleApprove => {
+            TravelRuleEvent::Approve(serde_json::from_value(payload)?)
+        }
+        NotificationType::TravelRuleDeny => TravelRuleEvent::Deny(serde_json::from_value(payload)?),
+        ref other => {
+            return Err(CircleError::Config(format!(
+                "Not a travel rule notification: {}",
+                other.as_str()
+            )))
+        }
+    };
+
+    Ok((notification, event))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::travel_rule::dto::TravelRuleStatus;
+
+    #[test]
+    fn test_parse_status_update_notification() {
+        let body = r#"{
+            "subscriptionId": "sub-1",
+            "notificationId": "n-1",
+            "notificationType": "travelRule.statusUpdate",
+            "notification": {
+                "id": "tr-1",
+                "transactionId": "tx-1",
+                "status": "APPROVED",
+                "counterpartyVasp": "Example Exchange"
+            },
+            "timestamp": "2025-01-01T00:00:00Z"
+        }"#;
+
+        let (envelope, event) = parse_travel_rule_notification(body).unwrap();
+        assert_eq!(envelope.notification_id, "n-1");
+        let TravelRuleEvent::StatusUpdate(update) = event else {
+            panic!("expected status update event");
+        };
+        assert_eq!(update.status, TravelRuleStatus::Approved);
+        assert_eq!(update.transaction_id.as_deref(), Some("tx-1"));
+        assert_eq!(update.extra["counterpartyVasp"], "Example Exchange");
+    }
+
+    #[test]
+    fn test_rejects_other_notification_types() {
+        let body = r#"{
+            "subscriptionId": "sub-1",
+            "notificationId": "n-1",
+            "notificationType": "transactions.outbound",
+            "notification": {},
+            "timestamp": "2025-01-01T00:00:00Z"
+        }"#;
+        assert!(matches!(
+            parse_travel_rule_notification(body),
+            Err(CircleError::Config(_))
+        ));
+    }
+}
@@ -0,0 +1 @@
+pub mod submit_travel_rule;
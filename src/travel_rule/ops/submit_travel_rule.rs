@@ -0,0 +1,88 @@
+use crate::helper::{preview_request, CircleResult, GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER};
+use crate::travel_rule::dto::{SubmitTravelRuleRequest, TravelRuleIdentity};
+
+/// Builder for submitting travel rule information for a transaction
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use inf_circle_sdk::travel_rule::{
+///     dto::TravelRuleIdentity, ops::submit_travel_rule::SubmitTravelRuleRequestBuilder,
+/// };
+///
+/// let builder = SubmitTravelRuleRequestBuilder::new(
+///     "transaction-id".to_string(),
+///     TravelRuleIdentity::business("Acme Ltd"),
+///     TravelRuleIdentity::individual("Jane Doe").vasp_name("Example Exchange"),
+/// )
+/// .build();
+/// ```
+#[derive(Clone, Debug)]
+pub struct SubmitTravelRuleRequestBuilder {
+    pub transaction_id: String,
+    pub originator: TravelRuleIdentity,
+    pub beneficiary: TravelRuleIdentity,
+    pub idempotency_key: Option<String>,
+}
+
+impl SubmitTravelRuleRequestBuilder {
+    /// Create a new builder with required fields
+    ///
+    /// # Arguments
+    /// * `transaction_id` - The transfer the information belongs to
+    /// * `originator` - Sender of the transfer
+    /// * `beneficiary` - Recipient of the transfer
+    pub fn new(
+        transaction_id: String,
+        originator: TravelRuleIdentity,
+        beneficiary: TravelRuleIdentity,
+    ) -> Self {
+        Self {
+            transaction_id,
+            originator,
+            beneficiary,
+            idempotency_key: None,
+        }
+    }
+
+    /// Set a custom idempotency key
+    ///
+    /// If not set, a UUID is generated when the request is sent.
+    pub fn idempotency_key(mut self, key: String) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
+
+    /// Convert the builder into the API request body
+    ///
+    /// `generate_idempotency_key` is only called when no custom key was set.
+    pub(crate) fn into_request(
+        self,
+        generate_idempotency_key: impl FnOnce() -> String,
+    ) -> SubmitTravelRuleRequest {
+        SubmitTravelRuleRequest {
+            originator: self.originator,
+            beneficiary: self.beneficiary,
+            idempotency_key: self
+                .idempotency_key
+                .unwrap_or_else(generate_idempotency_key),
+        }
+    }
+
+    /// Preview the request body as pretty JSON
+    ///
+    /// When no idempotency key was set, the key is shown as
+    /// [`GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER`].
+    pub fn preview(&self) -> CircleResult<String> {
+        preview_request(
+            &self
+                .clone()
+                .into_request(|| GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER.to_string()),
+        )
+    }
+
+    /// Build the SubmitTravelRuleRequestBuilder
+    pub fn build(self) -> SubmitTravelRuleRequestBuilder {
+        self
+    }
+}
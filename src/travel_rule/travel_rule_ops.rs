@@ -0,0 +1,199 @@
+//! Travel rule write operations for CircleOps
+
+use crate::{
+    circle_ops::circler_ops::CircleOps,
+    dev_wallet::{
+        dto::CreateTransferTransactionResponse,
+        ops::create_transfer_transaction::CreateTransferTransactionRequestBuilder,
+    },
+    helper::{generate_uuid, CircleResult},
+    travel_rule::{
+        dto::{TravelRuleIdentity, TravelRuleResponse},
+        ops::submit_travel_rule::SubmitTravelRuleRequestBuilder,
+    },
+};
+
+impl CircleOps {
+    /// Submit travel rule information for a transfer
+    ///
+    /// Attaches originator and beneficiary identity data to an existing
+    /// transaction. The review outcome arrives through the `travelRule.*`
+    /// webhooks or [`CircleView::get_travel_rule`](crate::circle_view::circle_view::CircleView::get_travel_rule).
+    ///
+    /// # Arguments
+    ///
+    /// * `builder` - Transaction ID, identities and optional idempotency key
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+    /// use inf_circle_sdk::travel_rule::{
+    ///     dto::TravelRuleIdentity, ops::submit_travel_rule::SubmitTravelRuleRequestBuilder,
+    /// };
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ops = CircleOps::new(None)?;
+    ///
+    /// let builder = SubmitTravelRuleRequestBuilder::new(
+    ///     "transaction-id".to_string(),
+    ///     TravelRuleIdentity::business("Acme Ltd"),
+    ///     TravelRuleIdentity::individual("Jane Doe"),
+    /// )
+    /// .build();
+    ///
+    /// let response = ops.submit_travel_rule(builder).await?;
+    /// println!("Travel rule status: {:?}", response.travel_rule.status);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn submit_travel_rule(
+        &self,
+        builder: SubmitTravelRuleRequestBuilder,
+    ) -> CircleResult<TravelRuleResponse> {
+        let path = format!("/v1/w3s/transactions/{}/travelRule", builder.transaction_id);
+        let request = builder.into_request(generate_uuid);
+        self.post(&path, &request).await
+    }
+
+    /// Create a transfer and attach travel rule information to it
+    ///
+    /// Submits the transfer, then submits `originator` and `beneficiary` for
+    /// the new transaction. If the travel rule submission fails the transfer
+    /// has already been created, so retry with [`Self::submit_travel_rule`]
+    /// rather than calling this method again.
+    ///
+    /// # Arguments
+    ///
+    /// * `builder` - The transfer to create
+    /// * `originator` - Sender of the transfer
+    /// * `beneficiary` - Recipient of the transfer
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_ops::circler_ops::CircleOps;
+    /// use inf_circle_sdk::dev_wallet::ops::create_transfer_transaction::CreateTransferTransactionRequestBuilder;
+    /// use inf_circle_sdk::travel_rule::dto::TravelRuleIdentity;
+    ///
+    /// # async fn example(transfer: CreateTransferTransactionRequestBuilder) -> Result<(), Box<dyn std::error::Error>> {
+    /// let ops = CircleOps::new(None)?;
+    ///
+    /// let (transfer, travel_rule) = ops
+    ///     .create_dev_transfer_with_travel_rule(
+    ///         transfer,
+    ///         TravelRuleIdentity::business("Acme Ltd"),
+    ///         TravelRuleIdentity::individual("Jane Doe").vasp_name("Example Exchange"),
+    ///     )
+    ///     .await?;
+    /// println!("Transfer {} travel rule {:?}", transfer.id, travel_rule.travel_rule.status);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_dev_transfer_with_travel_rule(
+        &self,
+        builder: CreateTransferTransactionRequestBuilder,
+        originator: TravelRuleIdentity,
+        beneficiary: TravelRuleIdentity,
+    ) -> CircleResult<(CreateTransferTransactionResponse, TravelRuleResponse)> {
+        let transfer = self.create_dev_transfer_transaction(builder).await?;
+        let travel_rule = self
+            .submit_travel_rule(SubmitTravelRuleRequestBuilder::new(
+                transfer.id.clone(),
+                originator,
+                beneficiary,
+            ))
+            .await?;
+        Ok((transfer, travel_rule))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        dev_wallet::{
+            dto::{CreateTransferTransactionResponse, FeeLevel},
+            ops::create_transfer_transaction::CreateTransferTransactionRequestBuilder,
+        },
+        helper::GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER,
+        testing::{fixtures, MockCircle},
+        travel_rule::{
+            dto::{TravelRuleAddress, TravelRuleIdentity, TravelRuleStatus},
+            ops::submit_travel_rule::SubmitTravelRuleRequestBuilder,
+        },
+    };
+    use mockito::Matcher;
+    use serde_json::json;
+
+    #[test]
+    fn test_preview_submit_travel_rule() {
+        let builder = SubmitTravelRuleRequestBuilder::new(
+            "tx-1".to_string(),
+            TravelRuleIdentity::business("Acme Ltd").address(TravelRuleAddress {
+                country: "GB".to_string(),
+                ..Default::default()
+            }),
+            TravelRuleIdentity::individual("Jane Doe"),
+        );
+
+        let preview = builder.preview().unwrap();
+        assert!(preview.contains(GENERATED_IDEMPOTENCY_KEY_PLACEHOLDER));
+        assert!(preview.contains("\"type\": \"BUSINESS\""));
+        assert!(preview.contains("\"country\": \"GB\""));
+        assert!(!preview.contains("transactionId"));
+    }
+
+    #[tokio::test]
+    async fn test_create_transfer_with_travel_rule() {
+        let mut circle = MockCircle::start().await;
+        circle
+            .mock_transfer(&CreateTransferTransactionResponse {
+                id: "tx-1".to_string(),
+                state: "INITIATED".to_string(),
+            })
+            .await;
+        let mock = circle
+            .server()
+            .mock("POST", "/v1/w3s/transactions/tx-1/travelRule")
+            .match_body(Matcher::PartialJson(json!({
+                "originator": { "type": "BUSINESS", "name": "Acme Ltd" },
+                "beneficiary": { "type": "INDIVIDUAL", "name": "Jane Doe" },
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "data": {
+                        "travelRule": {
+                            "id": "tr-1",
+                            "transactionId": "tx-1",
+                            "status": "PENDING"
+                        }
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let transfer = CreateTransferTransactionRequestBuilder::new()
+            .wallet_id(fixtures::WALLET_ID.to_string())
+            .destination_address(fixtures::WALLET_ADDRESS.to_string())
+            .amounts(["1"])
+            .fee_level(FeeLevel::Medium)
+            .build();
+        let (transfer, travel_rule) = circle
+            .ops()
+            .create_dev_transfer_with_travel_rule(
+                transfer,
+                TravelRuleIdentity::business("Acme Ltd"),
+                TravelRuleIdentity::individual("Jane Doe"),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(transfer.id, "tx-1");
+        assert_eq!(travel_rule.travel_rule.status, TravelRuleStatus::Pending);
+        mock.assert_async().await;
+    }
+}
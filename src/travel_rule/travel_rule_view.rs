@@ -0,0 +1,77 @@
+//! Travel rule read operations for CircleView
+
+use crate::{
+    circle_view::circle_view::CircleView, helper::CircleResult,
+    travel_rule::dto::TravelRuleResponse,
+};
+
+impl CircleView {
+    /// Get the travel rule information attached to a transaction
+    ///
+    /// # Arguments
+    ///
+    /// * `transaction_id` - The transaction the information was submitted for
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use inf_circle_sdk::circle_view::circle_view::CircleView;
+    /// use inf_circle_sdk::travel_rule::dto::TravelRuleStatus;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let view = CircleView::new()?;
+    ///
+    /// let response = view.get_travel_rule("transaction-id").await?;
+    /// if response.travel_rule.status == TravelRuleStatus::Denied {
+    ///     println!("Denied: {:?}", response.travel_rule.reason);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_travel_rule(&self, transaction_id: &str) -> CircleResult<TravelRuleResponse> {
+        let path = format!("/v1/w3s/transactions/{}/travelRule", transaction_id);
+        self.get(&path).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{fixtures, MockCircle};
+    use crate::travel_rule::dto::TravelRuleStatus;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_get_travel_rule() {
+        let mut circle = MockCircle::start().await;
+        let path = format!(
+            "/v1/w3s/transactions/{}/travelRule",
+            fixtures::TRANSACTION_ID
+        );
+        let mock = circle
+            .respond(
+                "GET",
+                &path,
+                &json!({
+                    "travelRule": {
+                        "id": "tr-1",
+                        "transactionId": fixtures::TRANSACTION_ID,
+                        "status": "DENIED",
+                        "reason": "Beneficiary VASP unreachable",
+                        "beneficiary": { "type": "INDIVIDUAL", "name": "Jane Doe" }
+                    }
+                }),
+            )
+            .await;
+
+        let record = circle
+            .view()
+            .get_travel_rule(fixtures::TRANSACTION_ID)
+            .await
+            .unwrap()
+            .travel_rule;
+        assert_eq!(record.status, TravelRuleStatus::Denied);
+        assert_eq!(record.beneficiary.unwrap().name, "Jane Doe");
+        assert!(record.originator.is_none());
+        mock.assert_async().await;
+    }
+}
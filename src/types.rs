@@ -2,8 +2,16 @@
 //!
 //! This module defines shared types, enums, and structures used throughout the SDK.
 
+use std::fmt;
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    abi::U256,
+    helper::{CircleError, CircleResult},
+};
+
 /// Supported blockchain networks
 ///
 /// Circle supports multiple blockchain networks for wallet and contract operations.
@@ -37,6 +45,9 @@ use serde::{Deserialize, Serialize};
 /// - `ArcTestnet` - Arc testnet
 /// - `MonadTestnet` - Monad testnet
 ///
+/// Identifiers added by Circle after this SDK version deserialize as
+/// `Other` instead of failing the whole response.
+///
 /// # Example
 ///
 /// ```rust
@@ -45,58 +56,36 @@ use serde::{Deserialize, Serialize};
 /// let blockchain = Blockchain::EthSepolia;
 /// println!("Using blockchain: {}", blockchain.as_str());  // "ETH-SEPOLIA"
 /// ```
-#[derive(Debug, Clone, Deserialize, Eq, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Eq, PartialEq, Hash)]
+#[serde(from = "String")]
 pub enum Blockchain {
-    #[serde(rename = "ETH")]
     Eth,
-    #[serde(rename = "ETH-SEPOLIA")]
     EthSepolia,
-    #[serde(rename = "AVAX")]
     Avax,
-    #[serde(rename = "AVAX-FUJI")]
     AvaxFuji,
-    #[serde(rename = "MATIC")]
     Matic,
-    #[serde(rename = "MATIC-AMOY")]
     MaticAmoy,
-    #[serde(rename = "SOL")]
     Sol,
-    #[serde(rename = "SOL-DEVNET")]
     SolDevnet,
-    #[serde(rename = "ARB")]
     Arb,
-    #[serde(rename = "ARB-SEPOLIA")]
     ArbSepolia,
-    #[serde(rename = "NEAR")]
     Near,
-    #[serde(rename = "NEAR-TESTNET")]
     NearTestnet,
-    #[serde(rename = "MONAD")]
     Monad,
-    #[serde(rename = "MONAD-TESTNET")]
     MonadTestnet,
-    #[serde(rename = "EVM")]
     Evm,
-    #[serde(rename = "EVM-TESTNET")]
     EvmTestnet,
-    #[serde(rename = "UNI")]
     Uni,
-    #[serde(rename = "UNI-SEPOLIA")]
     UniSepolia,
-    #[serde(rename = "BASE")]
     Base,
-    #[serde(rename = "BASE-SEPOLIA")]
     BaseSepolia,
-    #[serde(rename = "OP")]
     Op,
-    #[serde(rename = "OP-SEPOLIA")]
     OpSepolia,
-    #[serde(rename = "APTOS")]
     Aptos,
-    #[serde(rename = "APTOS-TESTNET")]
     AptosTestnet,
-    #[serde(rename = "ARC-TESTNET")]
     ArcTestnet,
+    /// A blockchain identifier this SDK version does not know yet
+    Other(String),
 }
 
 impl Blockchain {
@@ -119,7 +108,7 @@ impl Blockchain {
     /// let blockchain = Blockchain::Avax;
     /// assert_eq!(blockchain.as_str(), "AVAX");
     /// ```
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             Blockchain::Eth => "ETH",
             Blockchain::EthSepolia => "ETH-SEPOLIA",
@@ -146,10 +135,379 @@ impl Blockchain {
             Blockchain::Aptos => "APTOS",
             Blockchain::AptosTestnet => "APTOS-TESTNET",
             Blockchain::ArcTestnet => "ARC-TESTNET",
+            Blockchain::Other(identifier) => identifier,
+        }
+    }
+
+    /// Whether this is a test network
+    ///
+    /// Unknown identifiers count as testnets only if their name says so
+    /// (contains `TESTNET`, `SEPOLIA`, `DEVNET`, `FUJI` or `AMOY`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use inf_circle_sdk::types::Blockchain;
+    ///
+    /// assert!(Blockchain::BaseSepolia.is_testnet());
+    /// assert!(!Blockchain::Base.is_testnet());
+    /// ```
+    pub fn is_testnet(&self) -> bool {
+        match self {
+            Blockchain::EthSepolia
+            | Blockchain::AvaxFuji
+            | Blockchain::MaticAmoy
+            | Blockchain::SolDevnet
+            | Blockchain::ArbSepolia
+            | Blockchain::NearTestnet
+            | Blockchain::MonadTestnet
+            | Blockchain::EvmTestnet
+            | Blockchain::UniSepolia
+            | Blockchain::BaseSepolia
+            | Blockchain::OpSepolia
+            | Blockchain::AptosTestnet
+            | Blockchain::ArcTestnet => true,
+            Blockchain::Other(identifier) => ["TESTNET", "SEPOLIA", "DEVNET", "FUJI", "AMOY"]
+                .iter()
+                .any(|marker| identifier.contains(marker)),
+            _ => false,
+        }
+    }
+
+    /// Smallest native token amount that can be transferred on this blockchain
+    ///
+    /// Amounts are expressed in whole native units (e.g., ETH, SOL), the same
+    /// format used by the transfer API. This is one base unit of the native
+    /// token (1 wei, 1 lamport, 1 yoctoNEAR, 1 octa); anything smaller rounds
+    /// to zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use inf_circle_sdk::types::Blockchain;
+    ///
+    /// assert_eq!(Blockchain::Sol.min_native_transfer_amount(), "0.000000001");
+    /// assert_eq!(Blockchain::EthSepolia.min_native_transfer_amount(), "0.000000000000000001");
+    /// ```
+    pub fn min_native_transfer_amount(&self) -> &'static str {
+        match self {
+            Blockchain::Sol | Blockchain::SolDevnet => "0.000000001",
+            Blockchain::Near | Blockchain::NearTestnet => "0.000000000000000000000001",
+            Blockchain::Aptos | Blockchain::AptosTestnet => "0.00000001",
+            _ => "0.000000000000000001",
+        }
+    }
+
+    /// Smallest native token amount that can fund a new account on this blockchain
+    ///
+    /// Only applies when the recipient does not exist yet; transfers to funded
+    /// accounts may be smaller. Returns `None` on chains without such a minimum.
+    ///
+    /// - Solana: rent-exempt minimum for a new system account (890,880 lamports)
+    /// - NEAR: storage staking for a new implicit account (182 bytes)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use inf_circle_sdk::types::Blockchain;
+    ///
+    /// assert_eq!(Blockchain::Sol.new_account_minimum(), Some("0.00089088"));
+    /// assert_eq!(Blockchain::EthSepolia.new_account_minimum(), None);
+    /// ```
+    pub fn new_account_minimum(&self) -> Option<&'static str> {
+        match self {
+            Blockchain::Sol | Blockchain::SolDevnet => Some("0.00089088"),
+            Blockchain::Near | Blockchain::NearTestnet => Some("0.00182"),
+            _ => None,
+        }
+    }
+
+    /// Smallest fungible token amount accepted for transfers of a token with `decimals`
+    ///
+    /// Amounts below one base unit of the token are treated as dust.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use inf_circle_sdk::types::Blockchain;
+    ///
+    /// assert_eq!(Blockchain::Base.token_dust_threshold(6), "0.000001");
+    /// assert_eq!(Blockchain::Base.token_dust_threshold(18), "0.000000000000000001");
+    /// ```
+    pub fn token_dust_threshold(&self, decimals: u8) -> String {
+        TokenAmount::new(U256::from(1u64), decimals).to_decimal_string()
+    }
+
+    /// EVM chain ID (EIP-155) of this blockchain
+    ///
+    /// Returns `None` for non-EVM chains, the generic `EVM`/`EVM-TESTNET`
+    /// identifiers and unknown identifiers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use inf_circle_sdk::types::Blockchain;
+    ///
+    /// assert_eq!(Blockchain::BaseSepolia.chain_id(), Some(84532));
+    /// assert_eq!(Blockchain::Sol.chain_id(), None);
+    /// ```
+    pub fn chain_id(&self) -> Option<u64> {
+        match self {
+            Blockchain::Eth => Some(1),
+            Blockchain::EthSepolia => Some(11_155_111),
+            Blockchain::Avax => Some(43_114),
+            Blockchain::AvaxFuji => Some(43_113),
+            Blockchain::Matic => Some(137),
+            Blockchain::MaticAmoy => Some(80_002),
+            Blockchain::Arb => Some(42_161),
+            Blockchain::ArbSepolia => Some(421_614),
+            Blockchain::Monad => Some(143),
+            Blockchain::MonadTestnet => Some(10_143),
+            Blockchain::Uni => Some(130),
+            Blockchain::UniSepolia => Some(1_301),
+            Blockchain::Base => Some(8_453),
+            Blockchain::BaseSepolia => Some(84_532),
+            Blockchain::Op => Some(10),
+            Blockchain::OpSepolia => Some(11_155_420),
+            Blockchain::ArcTestnet => Some(5_042_002),
+            _ => None,
+        }
+    }
+
+    /// Ticker of the token used to pay fees on this blockchain
+    ///
+    /// Returns `None` for the generic `EVM`/`EVM-TESTNET` identifiers and
+    /// unknown identifiers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use inf_circle_sdk::types::Blockchain;
+    ///
+    /// assert_eq!(Blockchain::ArbSepolia.native_symbol(), Some("ETH"));
+    /// assert_eq!(Blockchain::ArcTestnet.native_symbol(), Some("USDC"));
+    /// ```
+    pub fn native_symbol(&self) -> Option<&'static str> {
+        match self {
+            Blockchain::Eth
+            | Blockchain::EthSepolia
+            | Blockchain::Arb
+            | Blockchain::ArbSepolia
+            | Blockchain::Uni
+            | Blockchain::UniSepolia
+            | Blockchain::Base
+            | Blockchain::BaseSepolia
+            | Blockchain::Op
+            | Blockchain::OpSepolia => Some("ETH"),
+            Blockchain::Avax | Blockchain::AvaxFuji => Some("AVAX"),
+            Blockchain::Matic | Blockchain::MaticAmoy => Some("POL"),
+            Blockchain::Sol | Blockchain::SolDevnet => Some("SOL"),
+            Blockchain::Near | Blockchain::NearTestnet => Some("NEAR"),
+            Blockchain::Monad | Blockchain::MonadTestnet => Some("MON"),
+            Blockchain::Aptos | Blockchain::AptosTestnet => Some("APT"),
+            Blockchain::ArcTestnet => Some("USDC"),
+            Blockchain::Evm | Blockchain::EvmTestnet | Blockchain::Other(_) => None,
+        }
+    }
+
+    /// Block explorer page for a transaction hash
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use inf_circle_sdk::types::Blockchain;
+    ///
+    /// assert_eq!(
+    ///     Blockchain::BaseSepolia.explorer_tx_url("0xabc").as_deref(),
+    ///     Some("https://sepolia.basescan.org/tx/0xabc")
+    /// );
+    /// assert_eq!(
+    ///     Blockchain::SolDevnet.explorer_tx_url("5Vf").as_deref(),
+    ///     Some("https://explorer.solana.com/tx/5Vf?cluster=devnet")
+    /// );
+    /// ```
+    pub fn explorer_tx_url(&self, tx_hash: &str) -> Option<String> {
+        let explorer = self.explorer()?;
+        Some(format!(
+            "{}/{}/{}{}",
+            explorer.base, explorer.tx_path, tx_hash, explorer.suffix
+        ))
+    }
+
+    /// Block explorer page for an account address
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use inf_circle_sdk::types::Blockchain;
+    ///
+    /// assert_eq!(
+    ///     Blockchain::Eth.explorer_address_url("0x1111").as_deref(),
+    ///     Some("https://etherscan.io/address/0x1111")
+    /// );
+    /// assert_eq!(Blockchain::Evm.explorer_address_url("0x1111"), None);
+    /// ```
+    pub fn explorer_address_url(&self, address: &str) -> Option<String> {
+        let explorer = self.explorer()?;
+        Some(format!(
+            "{}/{}/{}{}",
+            explorer.base, explorer.address_path, address, explorer.suffix
+        ))
+    }
+
+    fn explorer(&self) -> Option<Explorer> {
+        let explorer = match self {
+            Blockchain::Eth => Explorer::etherscan("https://etherscan.io"),
+            Blockchain::EthSepolia => Explorer::etherscan("https://sepolia.etherscan.io"),
+            Blockchain::Avax => Explorer::etherscan("https://snowtrace.io"),
+            Blockchain::AvaxFuji => Explorer::etherscan("https://testnet.snowtrace.io"),
+            Blockchain::Matic => Explorer::etherscan("https://polygonscan.com"),
+            Blockchain::MaticAmoy => Explorer::etherscan("https://amoy.polygonscan.com"),
+            Blockchain::Arb => Explorer::etherscan("https://arbiscan.io"),
+            Blockchain::ArbSepolia => Explorer::etherscan("https://sepolia.arbiscan.io"),
+            Blockchain::Monad => Explorer::etherscan("https://monadscan.com"),
+            Blockchain::MonadTestnet => Explorer::etherscan("https://testnet.monadexplorer.com"),
+            Blockchain::Uni => Explorer::etherscan("https://uniscan.xyz"),
+            Blockchain::UniSepolia => Explorer::etherscan("https://sepolia.uniscan.xyz"),
+            Blockchain::Base => Explorer::etherscan("https://basescan.org"),
+            Blockchain::BaseSepolia => Explorer::etherscan("https://sepolia.basescan.org"),
+            Blockchain::Op => Explorer::etherscan("https://optimistic.etherscan.io"),
+            Blockchain::OpSepolia => Explorer::etherscan("https://sepolia-optimism.etherscan.io"),
+            Blockchain::ArcTestnet => Explorer::etherscan("https://testnet.arcscan.app"),
+            Blockchain::Sol => Explorer {
+                suffix: "",
+                ..Explorer::etherscan("https://explorer.solana.com")
+            },
+            Blockchain::SolDevnet => Explorer {
+                suffix: "?cluster=devnet",
+                ..Explorer::etherscan("https://explorer.solana.com")
+            },
+            Blockchain::Near => Explorer {
+                tx_path: "txns",
+                ..Explorer::etherscan("https://nearblocks.io")
+            },
+            Blockchain::NearTestnet => Explorer {
+                tx_path: "txns",
+                ..Explorer::etherscan("https://testnet.nearblocks.io")
+            },
+            Blockchain::Aptos | Blockchain::AptosTestnet => Explorer {
+                base: "https://explorer.aptoslabs.com",
+                tx_path: "txn",
+                address_path: "account",
+                suffix: if self.is_testnet() {
+                    "?network=testnet"
+                } else {
+                    "?network=mainnet"
+                },
+            },
+            Blockchain::Evm | Blockchain::EvmTestnet | Blockchain::Other(_) => return None,
+        };
+        Some(explorer)
+    }
+}
+
+/// URL layout of a block explorer
+struct Explorer {
+    base: &'static str,
+    tx_path: &'static str,
+    address_path: &'static str,
+    suffix: &'static str,
+}
+
+impl Explorer {
+    /// Etherscan-style layout: `/tx/<hash>` and `/address/<address>`
+    fn etherscan(base: &'static str) -> Self {
+        Self {
+            base,
+            tx_path: "tx",
+            address_path: "address",
+            suffix: "",
+        }
+    }
+}
+
+impl From<&str> for Blockchain {
+    /// Parse an API identifier, falling back to [`Blockchain::Other`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use inf_circle_sdk::types::Blockchain;
+    ///
+    /// assert_eq!(Blockchain::from("BASE-SEPOLIA"), Blockchain::BaseSepolia);
+    /// assert_eq!(Blockchain::from("HYPE"), Blockchain::Other("HYPE".to_string()));
+    /// ```
+    fn from(identifier: &str) -> Self {
+        match identifier {
+            "ETH" => Blockchain::Eth,
+            "ETH-SEPOLIA" => Blockchain::EthSepolia,
+            "AVAX" => Blockchain::Avax,
+            "AVAX-FUJI" => Blockchain::AvaxFuji,
+            "MATIC" => Blockchain::Matic,
+            "MATIC-AMOY" => Blockchain::MaticAmoy,
+            "SOL" => Blockchain::Sol,
+            "SOL-DEVNET" => Blockchain::SolDevnet,
+            "ARB" => Blockchain::Arb,
+            "ARB-SEPOLIA" => Blockchain::ArbSepolia,
+            "NEAR" => Blockchain::Near,
+            "NEAR-TESTNET" => Blockchain::NearTestnet,
+            "MONAD" => Blockchain::Monad,
+            "MONAD-TESTNET" => Blockchain::MonadTestnet,
+            "EVM" => Blockchain::Evm,
+            "EVM-TESTNET" => Blockchain::EvmTestnet,
+            "UNI" => Blockchain::Uni,
+            "UNI-SEPOLIA" => Blockchain::UniSepolia,
+            "BASE" => Blockchain::Base,
+            "BASE-SEPOLIA" => Blockchain::BaseSepolia,
+            "OP" => Blockchain::Op,
+            "OP-SEPOLIA" => Blockchain::OpSepolia,
+            "APTOS" => Blockchain::Aptos,
+            "APTOS-TESTNET" => Blockchain::AptosTestnet,
+            "ARC-TESTNET" => Blockchain::ArcTestnet,
+            other => Blockchain::Other(other.to_string()),
         }
     }
 }
 
+impl From<String> for Blockchain {
+    fn from(identifier: String) -> Self {
+        Blockchain::from(identifier.as_str())
+    }
+}
+
+impl FromStr for Blockchain {
+    type Err = CircleError;
+
+    /// Parse a Circle identifier, rejecting identifiers this SDK does not know
+    ///
+    /// Surrounding whitespace and case are ignored. Use [`Blockchain::from`]
+    /// to keep unknown identifiers as [`Blockchain::Other`] instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use inf_circle_sdk::types::Blockchain;
+    ///
+    /// assert_eq!("eth-sepolia".parse::<Blockchain>().unwrap(), Blockchain::EthSepolia);
+    /// assert!("HYPE".parse::<Blockchain>().is_err());
+    /// ```
+    fn from_str(identifier: &str) -> Result<Self, Self::Err> {
+        match Blockchain::from(identifier.trim().to_ascii_uppercase()) {
+            Blockchain::Other(identifier) => Err(CircleError::Config(format!(
+                "Unknown blockchain identifier: {}",
+                identifier
+            ))),
+            blockchain => Ok(blockchain),
+        }
+    }
+}
+
+impl fmt::Display for Blockchain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 impl Serialize for Blockchain {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -158,3 +516,363 @@ impl Serialize for Blockchain {
         serializer.serialize_str(self.as_str())
     }
 }
+
+/// Circle-issued stablecoins with known deployments
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::types::{Blockchain, Stablecoin};
+///
+/// assert_eq!(
+///     Stablecoin::Eurc.address(&Blockchain::EthSepolia),
+///     Some("0x08210F9170F89Ab7658F0B5E3fF39b0E03C594D4")
+/// );
+/// assert_eq!(Stablecoin::Eurc.address(&Blockchain::NearTestnet), None);
+/// assert_eq!(Stablecoin::Eurc.decimals(), 6);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stablecoin {
+    /// USD Coin
+    Usdc,
+    /// Euro Coin
+    Eurc,
+}
+
+impl Stablecoin {
+    /// All supported stablecoins
+    pub const ALL: [Stablecoin; 2] = [Stablecoin::Usdc, Stablecoin::Eurc];
+
+    /// Ticker symbol (e.g., "USDC")
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Stablecoin::Usdc => "USDC",
+            Stablecoin::Eurc => "EURC",
+        }
+    }
+
+    /// Number of decimals used by token amounts
+    pub fn decimals(&self) -> u8 {
+        6
+    }
+
+    /// Token contract address (or mint / asset id) on `blockchain`
+    ///
+    /// Returns `None` if Circle has not issued the stablecoin on that blockchain.
+    pub fn address(&self, blockchain: &Blockchain) -> Option<&'static str> {
+        match self {
+            Stablecoin::Usdc => match blockchain {
+                Blockchain::Eth => Some("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"),
+                Blockchain::EthSepolia => Some("0x1c7D4B196Cb0C7B01d743Fbc6116a902379C7238"),
+                Blockchain::Avax => Some("0xB97EF9Ef8734C71904D8002F8b6Bc66Dd9c48a6E"),
+                Blockchain::AvaxFuji => Some("0x5425890298aed601595a70AB815c96711a31Bc65"),
+                Blockchain::Matic => Some("0x3c499c542cEF5E3811e1192ce70d8cC03d5c3359"),
+                Blockchain::MaticAmoy => Some("0x41E94Eb019C0762f9Bfcf9Fb1E58725BfB0e7582"),
+                Blockchain::Arb => Some("0xaf88d065e77c8cC2239327C5EDb3A432268e5831"),
+                Blockchain::ArbSepolia => Some("0x75faf114eafb1BDbe2F0316DF893fd58CE46AA4d"),
+                Blockchain::Base => Some("0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913"),
+                Blockchain::BaseSepolia => Some("0x036CbD53842c5426634e7929541eC2318f3dCF7e"),
+                Blockchain::Op => Some("0x0b2C639c533813f4Aa9D7837CAf62653d097Ff85"),
+                Blockchain::OpSepolia => Some("0x5fd84259d66Cd46123540766Be93DFE6D43130D7"),
+                Blockchain::Uni => Some("0x078D782b760474a361dDA0AF3839290b0EF57AD6"),
+                Blockchain::UniSepolia => Some("0x31d0220469e10c4E71834a79b1f276d740d3768F"),
+                Blockchain::Sol => Some("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"),
+                Blockchain::SolDevnet => Some("4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU"),
+                Blockchain::Near => {
+                    Some("17208628f84f5d6ad33f0da3bbbeb27ffcb398eac501a31bd6ad2011e36133a1")
+                }
+                Blockchain::NearTestnet => {
+                    Some("3e2210e1184b45b64c8a434c0a7e7b23cc04ea7eb7a6c3c32520d03d4afcb8af")
+                }
+                Blockchain::Aptos => {
+                    Some("0xbae207659db88bea0cbead6da0ed00aac12edcdda169e591cd41c94180b46f3b")
+                }
+                Blockchain::AptosTestnet => {
+                    Some("0x69091fbab5f7d635ee7ac5098cf0c1efbe31d68fec0f2cd565e8d168daf52832")
+                }
+                Blockchain::ArcTestnet => Some("0x3600000000000000000000000000000000000000"),
+                _ => None,
+            },
+            Stablecoin::Eurc => match blockchain {
+                Blockchain::Eth => Some("0x1aBaEA1f7C830bD89Acc67eC4af516284b1bC33c"),
+                Blockchain::EthSepolia => Some("0x08210F9170F89Ab7658F0B5E3fF39b0E03C594D4"),
+                Blockchain::Avax => Some("0xC891EB4cbdEFf6e073e859e987815Ed1505c2ACD"),
+                Blockchain::AvaxFuji => Some("0x5E44db7996c682E92a960b65AC713a54AD815c6B"),
+                Blockchain::Base => Some("0x60a3E35Cc302bFA44Cb288Bc5a4F316Fdb1adb42"),
+                Blockchain::BaseSepolia => Some("0x808456652fdb597867f38412077A9182bf77359F"),
+                Blockchain::Sol | Blockchain::SolDevnet => {
+                    Some("HzwqbKZw8HxMN6bF2yFZNrht3c2iXXzpKcFu7uBEDKtr")
+                }
+                Blockchain::ArcTestnet => Some("0x89B50855Aa3bE2F677cD6303Cec089B5F319D72a"),
+                _ => None,
+            },
+        }
+    }
+
+    /// Identify the stablecoin deployed at `token_address` on `blockchain`
+    ///
+    /// Hex addresses are compared case-insensitively.
+    pub fn from_token_address(blockchain: &Blockchain, token_address: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|coin| {
+            coin.address(blockchain).is_some_and(|address| {
+                if address.starts_with("0x") {
+                    address.eq_ignore_ascii_case(token_address)
+                } else {
+                    address == token_address
+                }
+            })
+        })
+    }
+}
+
+/// Token amount stored as raw base units plus the token's decimals
+///
+/// Circle's transfer APIs take amounts as human-readable decimal strings
+/// (`"1.5"` USDC), while on-chain values are integers in base units
+/// (`1500000`). Mixing the two up sends amounts that are off by a factor of
+/// `10^decimals`. `TokenAmount` keeps both pieces together and converts to the
+/// decimal string the API expects, so it can be passed anywhere a transfer
+/// amount is accepted.
+///
+/// # Example
+///
+/// ```rust
+/// use inf_circle_sdk::abi::U256;
+/// use inf_circle_sdk::types::TokenAmount;
+///
+/// let amount = TokenAmount::from_decimal_str("1.5", 6)?;
+/// assert_eq!(amount.raw(), U256::from(1_500_000u64));
+/// assert_eq!(amount.to_string(), "1.5");
+///
+/// let wei = TokenAmount::new(U256::from(1_000_000_000_000_000u64), 18);
+/// assert_eq!(wei.to_string(), "0.001");
+/// # Ok::<(), inf_circle_sdk::helper::CircleError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TokenAmount {
+    raw: U256,
+    decimals: u8,
+}
+
+impl TokenAmount {
+    /// Create an amount from raw base units (e.g., wei, lamports, USDC micro-units)
+    pub fn new(raw: U256, decimals: u8) -> Self {
+        Self { raw, decimals }
+    }
+
+    /// Parse a human-readable decimal string (e.g., `"1.5"`) for a token with `decimals`
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Config` if the string is not a plain non-negative
+    /// decimal number or has more fractional digits than the token supports.
+    /// Excess precision is rejected rather than truncated.
+    pub fn from_decimal_str(amount: &str, decimals: u8) -> CircleResult<Self> {
+        let amount = amount.trim();
+        let (whole, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+        let invalid = || CircleError::Config(format!("Invalid token amount: {}", amount));
+
+        if whole.is_empty() && fraction.is_empty() {
+            return Err(invalid());
+        }
+        if !whole
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        let fraction = fraction.trim_end_matches('0');
+        if fraction.len() > decimals as usize {
+            return Err(CircleError::Config(format!(
+                "Token amount {} has more than {} decimal places",
+                amount, decimals
+            )));
+        }
+
+        let digits = format!(
+            "{}{}{}",
+            whole,
+            fraction,
+            "0".repeat(decimals as usize - fraction.len())
+        );
+        let digits = digits.trim_start_matches('0');
+        let raw = if digits.is_empty() {
+            U256::ZERO
+        } else {
+            U256::from_dec_str(digits).map_err(|_| {
+                CircleError::Config(format!("Token amount {} overflows uint256", amount))
+            })?
+        };
+
+        Ok(Self { raw, decimals })
+    }
+
+    /// Raw amount in base units
+    pub fn raw(&self) -> U256 {
+        self.raw
+    }
+
+    /// Number of decimals of the token
+    pub fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    /// Human-readable decimal string without trailing zeros (e.g., `"1.5"`)
+    pub fn to_decimal_string(&self) -> String {
+        let digits = self.raw.to_string();
+        let decimals = self.decimals as usize;
+        if decimals == 0 {
+            return digits;
+        }
+
+        let padded = format!("{:0>width$}", digits, width = decimals + 1);
+        let (whole, fraction) = padded.split_at(padded.len() - decimals);
+        let fraction = fraction.trim_end_matches('0');
+        if fraction.is_empty() {
+            whole.to_string()
+        } else {
+            format!("{}.{}", whole, fraction)
+        }
+    }
+}
+
+impl fmt::Display for TokenAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_decimal_string())
+    }
+}
+
+impl From<TokenAmount> for String {
+    fn from(amount: TokenAmount) -> Self {
+        amount.to_decimal_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_amount_round_trip() {
+        let amount = TokenAmount::from_decimal_str("0.000000000000000001", 18).unwrap();
+        assert_eq!(amount.raw(), U256::from(1u64));
+        assert_eq!(amount.to_string(), "0.000000000000000001");
+
+        let amount = TokenAmount::from_decimal_str("1200.50", 6).unwrap();
+        assert_eq!(amount.raw(), U256::from(1_200_500_000u64));
+        assert_eq!(amount.to_string(), "1200.5");
+
+        assert_eq!(TokenAmount::new(U256::ZERO, 6).to_string(), "0");
+        assert_eq!(TokenAmount::new(U256::from(42u64), 0).to_string(), "42");
+    }
+
+    #[test]
+    fn test_token_amount_rejects_excess_precision() {
+        assert!(matches!(
+            TokenAmount::from_decimal_str("1.0000001", 6),
+            Err(CircleError::Config(_))
+        ));
+        assert!(TokenAmount::from_decimal_str("1.0000000", 6).is_ok());
+        assert!(TokenAmount::from_decimal_str("-1", 6).is_err());
+        assert!(TokenAmount::from_decimal_str(".", 6).is_err());
+    }
+
+    #[test]
+    fn test_stablecoin_lookup() {
+        assert_eq!(
+            Stablecoin::from_token_address(
+                &Blockchain::EthSepolia,
+                "0x08210f9170f89ab7658f0b5e3ff39b0e03c594d4"
+            ),
+            Some(Stablecoin::Eurc)
+        );
+        assert_eq!(
+            Stablecoin::from_token_address(
+                &Blockchain::Sol,
+                "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"
+            ),
+            Some(Stablecoin::Usdc)
+        );
+        assert_eq!(
+            Stablecoin::from_token_address(
+                &Blockchain::Eth,
+                "0x0000000000000000000000000000000000000000"
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_blockchain_tables_cover_known_chains() {
+        let known = [
+            Blockchain::Eth,
+            Blockchain::EthSepolia,
+            Blockchain::Avax,
+            Blockchain::AvaxFuji,
+            Blockchain::Matic,
+            Blockchain::MaticAmoy,
+            Blockchain::Sol,
+            Blockchain::SolDevnet,
+            Blockchain::Arb,
+            Blockchain::ArbSepolia,
+            Blockchain::Near,
+            Blockchain::NearTestnet,
+            Blockchain::Monad,
+            Blockchain::MonadTestnet,
+            Blockchain::Uni,
+            Blockchain::UniSepolia,
+            Blockchain::Base,
+            Blockchain::BaseSepolia,
+            Blockchain::Op,
+            Blockchain::OpSepolia,
+            Blockchain::Aptos,
+            Blockchain::AptosTestnet,
+            Blockchain::ArcTestnet,
+        ];
+        for blockchain in known {
+            assert_eq!(
+                blockchain.as_str().parse::<Blockchain>().unwrap(),
+                blockchain
+            );
+            assert!(blockchain.native_symbol().is_some(), "{}", blockchain);
+            assert!(blockchain.explorer_tx_url("h").is_some(), "{}", blockchain);
+            let non_evm = matches!(
+                blockchain,
+                Blockchain::Sol
+                    | Blockchain::SolDevnet
+                    | Blockchain::Near
+                    | Blockchain::NearTestnet
+                    | Blockchain::Aptos
+                    | Blockchain::AptosTestnet
+            );
+            assert_eq!(blockchain.chain_id().is_none(), non_evm, "{}", blockchain);
+        }
+
+        assert_eq!(
+            Blockchain::NearTestnet.explorer_tx_url("abc").as_deref(),
+            Some("https://testnet.nearblocks.io/txns/abc")
+        );
+        assert_eq!(
+            Blockchain::Aptos.explorer_address_url("0x1").as_deref(),
+            Some("https://explorer.aptoslabs.com/account/0x1?network=mainnet")
+        );
+        assert_eq!(
+            Blockchain::Other("X".to_string()).explorer_tx_url("h"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_blockchain_round_trip_and_fallback() {
+        let known: Blockchain = serde_json::from_str("\"BASE-SEPOLIA\"").unwrap();
+        assert_eq!(known, Blockchain::BaseSepolia);
+        assert_eq!(serde_json::to_string(&known).unwrap(), "\"BASE-SEPOLIA\"");
+
+        let unknown: Blockchain = serde_json::from_str("\"NEW-CHAIN\"").unwrap();
+        assert_eq!(unknown, Blockchain::Other("NEW-CHAIN".to_string()));
+        assert_eq!(unknown.to_string(), "NEW-CHAIN");
+        assert_eq!(serde_json::to_string(&unknown).unwrap(), "\"NEW-CHAIN\"");
+    }
+}
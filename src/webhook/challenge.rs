@@ -0,0 +1,328 @@
+//! Waiting for user-controlled wallet challenges to finish
+//!
+//! Actions on user-controlled wallets (setting a PIN, creating a wallet,
+//! signing a transfer, ...) return a challenge ID that the user completes in
+//! the Circle SDK on their device. Circle reports the outcome with a
+//! `challenges.*` webhook. A [`ChallengeTracker`] connects the two: backend
+//! code awaits [`await_challenge`](ChallengeTracker::await_challenge) while
+//! the webhook handler feeds notifications to
+//! [`handle`](ChallengeTracker::handle).
+//!
+//! Call [`track`](ChallengeTracker::track) as soon as a challenge is created,
+//! so an outcome that arrives before anyone awaits it is kept instead of
+//! dropped. Outcomes of challenges nobody tracks are ignored.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//! use inf_circle_sdk::webhook::{ChallengeTracker, WebhookNotification};
+//!
+//! # async fn example(challenge_id: String) -> Result<(), Box<dyn std::error::Error>> {
+//! let tracker = ChallengeTracker::new().timeout(Duration::from_secs(300));
+//! tracker.track(&challenge_id);
+//!
+//! // In the webhook handler
+//! let webhook_tracker = tracker.clone();
+//! let on_notification = move |notification: WebhookNotification| {
+//!     webhook_tracker.handle(&notification)
+//! };
+//!
+//! // In the request that started the challenge
+//! let challenge = tracker.await_challenge(&challenge_id).await?;
+//! if challenge.is_complete() {
+//!     println!("User approved {}", challenge.id);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures_util::future::{select, Either};
+use serde::{Deserialize, Serialize};
+use tokio::sync::oneshot;
+
+use super::WebhookNotification;
+use crate::helper::{sleep, CircleError, CircleResult};
+
+/// Status of a user-controlled wallet challenge
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ChallengeStatus {
+    Pending,
+    InProgress,
+    Complete,
+    Failed,
+    Expired,
+    /// A status this SDK does not know yet
+    #[serde(other)]
+    Unknown,
+}
+
+impl ChallengeStatus {
+    /// Whether the challenge will not change any more
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Complete | Self::Failed | Self::Expired)
+    }
+}
+
+/// Payload of a `challenges.*` notification
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Challenge {
+    /// Challenge ID returned when the challenge was created
+    pub id: String,
+
+    /// User the challenge was issued to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+
+    /// Challenge type (e.g., `SET_PIN`, `CREATE_TRANSACTION`)
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub challenge_type: Option<String>,
+
+    /// Current status
+    pub status: ChallengeStatus,
+
+    /// IDs of the resources the challenge created (wallets, transactions, ...)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub correlation_ids: Vec<String>,
+
+    /// Error code of a failed challenge
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<i64>,
+
+    /// Error message of a failed challenge
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+}
+
+impl Challenge {
+    /// Whether the user completed the challenge
+    pub fn is_complete(&self) -> bool {
+        self.status == ChallengeStatus::Complete
+    }
+}
+
+/// A tracked challenge: still waited on, or finished before anyone awaited it
+enum Slot {
+    Waiting(Vec<oneshot::Sender<Challenge>>),
+    Finished(Challenge),
+}
+
+/// Correlates created challenges with their webhook outcomes
+///
+/// Cheap to clone; clones share the tracked challenges.
+#[derive(Clone)]
+pub struct ChallengeTracker {
+    slots: Arc<Mutex<HashMap<String, Slot>>>,
+    timeout: Duration,
+}
+
+impl Default for ChallengeTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChallengeTracker {
+    /// Create a tracker whose waits time out after 15 minutes
+    pub fn new() -> Self {
+        Self {
+            slots: Arc::new(Mutex::new(HashMap::new())),
+            timeout: Duration::from_secs(15 * 60),
+        }
+    }
+
+    /// How long [`await_challenge`](Self::await_challenge) waits for an outcome
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Start tracking a challenge so an early outcome is kept until awaited
+    pub fn track(&self, challenge_id: &str) {
+        if let Ok(mut slots) = self.slots.lock() {
+            slots
+                .entry(challenge_id.to_string())
+                .or_insert_with(|| Slot::Waiting(Vec::new()));
+        }
+    }
+
+    /// Wait until the challenge completes, fails or expires
+    ///
+    /// Tracks the challenge if it is not tracked yet. Check
+    /// [`Challenge::is_complete`] on the result.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Config` if no outcome arrives within the timeout.
+    pub async fn await_challenge(&self, challenge_id: &str) -> CircleResult<Challenge> {
+        let receiver = {
+            let mut slots = self.slots()?;
+            let mut senders = match slots.remove(challenge_id) {
+                Some(Slot::Finished(challenge)) => return Ok(challenge),
+                Some(Slot::Waiting(senders)) => senders,
+                None => Vec::new(),
+            };
+            let (sender, receiver) = oneshot::channel();
+            senders.push(sender);
+            slots.insert(challenge_id.to_string(), Slot::Waiting(senders));
+            receiver
+        };
+
+        let timeout = Box::pin(sleep(self.timeout));
+        if let Either::Left((Ok(challenge), _)) = select(receiver, timeout).await {
+            return Ok(challenge);
+        }
+        // The receiver is dropped by now, so its sender counts as closed
+        self.drop_closed(challenge_id);
+        Err(CircleError::Config(format!(
+            "Timed out waiting for challenge {}",
+            challenge_id
+        )))
+    }
+
+    /// Feed a webhook notification to the tracker
+    ///
+    /// Returns whether it finished a tracked challenge. Notifications that
+    /// are not `challenges.*`, or report a challenge still in progress, are
+    /// ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CircleError::Json` if a `challenges.*` payload does not parse.
+    pub fn handle(&self, notification: &WebhookNotification) -> CircleResult<bool> {
+        if !notification
+            .notification_type
+            .as_str()
+            .starts_with("challenges.")
+        {
+            return Ok(false);
+        }
+        let challenge: Challenge = serde_json::from_value(notification.notification.clone())?;
+        self.resolve(challenge)
+    }
+
+    /// Report a challenge outcome obtained some other way (e.g., by polling)
+    ///
+    /// Returns whether it finished a tracked challenge.
+    pub fn resolve(&self, challenge: Challenge) -> CircleResult<bool> {
+        if !challenge.status.is_terminal() {
+            return Ok(false);
+        }
+
+        let mut slots = self.slots()?;
+        let Some(Slot::Waiting(senders)) = slots.remove(&challenge.id) else {
+            // Untracked, or an outcome is already stored
+            return Ok(false);
+        };
+
+        let mut delivered = false;
+        for sender in senders {
+            delivered |= sender.send(challenge.clone()).is_ok();
+        }
+        if !delivered {
+            slots.insert(challenge.id.clone(), Slot::Finished(challenge));
+        }
+        Ok(true)
+    }
+
+    /// Stop tracking a challenge and discard its outcome
+    pub fn forget(&self, challenge_id: &str) {
+        if let Ok(mut slots) = self.slots.lock() {
+            slots.remove(challenge_id);
+        }
+    }
+
+    /// Remove waiters that gave up, and the challenge if none are left
+    fn drop_closed(&self, challenge_id: &str) {
+        let Ok(mut slots) = self.slots.lock() else {
+            return;
+        };
+        if let Some(Slot::Waiting(senders)) = slots.get_mut(challenge_id) {
+            senders.retain(|sender| !sender.is_closed());
+            if senders.is_empty() {
+                slots.remove(challenge_id);
+            }
+        }
+    }
+
+    fn slots(&self) -> CircleResult<std::sync::MutexGuard<'_, HashMap<String, Slot>>> {
+        self.slots
+            .lock()
+            .map_err(|_| CircleError::Config("Challenge tracker lock poisoned".to_string()))
+    }
+}
+
+impl std::fmt::Debug for ChallengeTracker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChallengeTracker")
+            .field("timeout", &self.timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn notification(challenge_id: &str, status: &str) -> WebhookNotification {
+        serde_json::from_value(json!({
+            "subscriptionId": "sub-1",
+            "notificationId": format!("n-{}-{}", challenge_id, status),
+            "notificationType": "challenges.setPin",
+            "notification": {
+                "id": challenge_id,
+                "userId": "user-1",
+                "type": "SET_PIN",
+                "status": status,
+                "correlationIds": []
+            },
+            "timestamp": "2025-01-01T00:00:00Z",
+            "version": 2
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_await_challenge_resolves_from_webhook() {
+        let tracker = ChallengeTracker::new();
+
+        let waiter = {
+            let tracker = tracker.clone();
+            tokio::spawn(async move { tracker.await_challenge("ch-1").await })
+        };
+        tokio::task::yield_now().await;
+
+        assert!(!tracker
+            .handle(&notification("ch-1", "IN_PROGRESS"))
+            .unwrap());
+        assert!(tracker.handle(&notification("ch-1", "COMPLETE")).unwrap());
+        assert!(waiter.await.unwrap().unwrap().is_complete());
+
+        // Outcomes of untracked challenges are dropped
+        assert!(!tracker.handle(&notification("ch-2", "FAILED")).unwrap());
+
+        // An outcome that arrives before the wait is kept
+        tracker.track("ch-3");
+        assert!(tracker.handle(&notification("ch-3", "EXPIRED")).unwrap());
+        let challenge = tracker.await_challenge("ch-3").await.unwrap();
+        assert_eq!(challenge.status, ChallengeStatus::Expired);
+    }
+
+    #[tokio::test]
+    async fn test_await_challenge_times_out() {
+        let tracker = ChallengeTracker::new().timeout(Duration::from_millis(10));
+        assert!(matches!(
+            tracker.await_challenge("ch-1").await,
+            Err(CircleError::Config(_))
+        ));
+        // The timed out challenge is no longer tracked
+        assert!(!tracker.handle(&notification("ch-1", "COMPLETE")).unwrap());
+    }
+}
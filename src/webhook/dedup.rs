@@ -0,0 +1,281 @@
+//! Exactly-once processing of at-least-once webhook deliveries
+//!
+//! Circle redelivers a notification until the endpoint acknowledges it, so
+//! the same `notificationId` can arrive more than once, possibly at several
+//! replicas at the same time. A [`NotificationDeduplicator`] claims each ID
+//! before the handler runs and skips IDs that are already claimed. A handler
+//! that fails releases its claim, so the next redelivery is processed.
+//!
+//! Claims live in a [`DeduplicationStore`]. The default
+//! [`InMemoryDeduplicationStore`] is local to the process; implement the
+//! trait on a shared store to deduplicate across replicas. With Redis,
+//! `claim` is `SET <key> 1 NX PX <ttl>` (claimed when the reply is `OK`) and
+//! `release` is `DEL <key>`.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use inf_circle_sdk::helper::CircleError;
+//! use inf_circle_sdk::webhook::{NotificationDeduplicator, WebhookNotification};
+//!
+//! # async fn credit_deposit(_: &serde_json::Value) -> Result<(), CircleError> { Ok(()) }
+//! # async fn example(notification: WebhookNotification) -> Result<(), CircleError> {
+//! let dedup = NotificationDeduplicator::new();
+//!
+//! let processed = dedup
+//!     .process(&notification.notification_id, || credit_deposit(&notification.notification))
+//!     .await?;
+//! if processed.is_none() {
+//!     println!("{} was already handled", notification.notification_id);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::helper::{CircleError, CircleResult, Instant};
+
+/// Storage for claimed notification IDs
+///
+/// `claim` must be atomic: when several callers claim the same live ID,
+/// exactly one of them gets `true`. Claims expire after their TTL.
+pub trait DeduplicationStore: Send + Sync {
+    /// Claim `notification_id` for `ttl`; `false` if it is already claimed
+    fn claim(&self, notification_id: &str, ttl: Duration) -> CircleResult<bool>;
+
+    /// Drop the claim on `notification_id`
+    fn release(&self, notification_id: &str) -> CircleResult<()>;
+}
+
+/// Process-local [`DeduplicationStore`] holding up to a fixed number of claims
+///
+/// When full, the oldest claim is evicted to make room.
+#[derive(Debug)]
+pub struct InMemoryDeduplicationStore {
+    capacity: usize,
+    claims: Mutex<Claims>,
+}
+
+#[derive(Debug, Default)]
+struct Claims {
+    /// Expiry and claim sequence number per ID
+    expiries: HashMap<String, (Instant, u64)>,
+    /// IDs in claim order, possibly including released or reclaimed ones
+    order: VecDeque<(u64, String)>,
+    next: u64,
+}
+
+impl Default for InMemoryDeduplicationStore {
+    fn default() -> Self {
+        Self::with_capacity(10_000)
+    }
+}
+
+impl InMemoryDeduplicationStore {
+    /// Create a store holding up to 10,000 claims
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a store holding up to `capacity` claims
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            claims: Mutex::new(Claims::default()),
+        }
+    }
+
+    /// Number of stored claims, including expired ones not yet evicted
+    pub fn len(&self) -> usize {
+        self.claims.lock().map_or(0, |claims| claims.expiries.len())
+    }
+
+    /// Whether the store holds no claims
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn claims(&self) -> CircleResult<std::sync::MutexGuard<'_, Claims>> {
+        self.claims
+            .lock()
+            .map_err(|_| CircleError::Config("Deduplication store lock poisoned".to_string()))
+    }
+}
+
+impl DeduplicationStore for InMemoryDeduplicationStore {
+    fn claim(&self, notification_id: &str, ttl: Duration) -> CircleResult<bool> {
+        let mut claims = self.claims()?;
+        let now = Instant::now();
+        if matches!(claims.expiries.get(notification_id), Some((expires, _)) if *expires > now) {
+            return Ok(false);
+        }
+
+        // Evict the oldest claims, skipping queue entries that were released or reclaimed
+        while claims.expiries.len() >= self.capacity {
+            let Some((seq, id)) = claims.order.pop_front() else {
+                break;
+            };
+            if matches!(claims.expiries.get(&id), Some((_, current)) if *current == seq) {
+                claims.expiries.remove(&id);
+            }
+        }
+
+        let seq = claims.next;
+        claims.next += 1;
+        claims
+            .expiries
+            .insert(notification_id.to_string(), (now + ttl, seq));
+        claims.order.push_back((seq, notification_id.to_string()));
+
+        // Drop stale queue entries left behind by releases and reclaims
+        if claims.order.len() > 2 * self.capacity {
+            let Claims {
+                expiries, order, ..
+            } = &mut *claims;
+            order.retain(
+                |(seq, id)| matches!(expiries.get(id), Some((_, current)) if current == seq),
+            );
+        }
+        Ok(true)
+    }
+
+    fn release(&self, notification_id: &str) -> CircleResult<()> {
+        let mut claims = self.claims()?;
+        claims.expiries.remove(notification_id);
+        if claims.expiries.is_empty() {
+            claims.order.clear();
+        }
+        Ok(())
+    }
+}
+
+/// Runs each webhook notification's handler at most once
+///
+/// Cheap to clone; clones share the store.
+#[derive(Clone)]
+pub struct NotificationDeduplicator {
+    store: Arc<dyn DeduplicationStore>,
+    ttl: Duration,
+}
+
+impl Default for NotificationDeduplicator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NotificationDeduplicator {
+    /// Create a deduplicator with an [`InMemoryDeduplicationStore`] and a 24 hour TTL
+    pub fn new() -> Self {
+        Self::with_store(Arc::new(InMemoryDeduplicationStore::new()))
+    }
+
+    /// Create a deduplicator keeping claims in `store`, with a 24 hour TTL
+    pub fn with_store(store: Arc<dyn DeduplicationStore>) -> Self {
+        Self {
+            store,
+            ttl: Duration::from_secs(24 * 60 * 60),
+        }
+    }
+
+    /// How long a processed notification ID is remembered
+    ///
+    /// Should exceed the period over which Circle retries a delivery.
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Claim a notification ID; `false` if it was already claimed
+    pub fn claim(&self, notification_id: &str) -> CircleResult<bool> {
+        self.store.claim(notification_id, self.ttl)
+    }
+
+    /// Release a claim so the next delivery of the notification is processed
+    pub fn release(&self, notification_id: &str) -> CircleResult<()> {
+        self.store.release(notification_id)
+    }
+
+    /// Run `handler` unless `notification_id` was already claimed
+    ///
+    /// Returns `Ok(None)` for a duplicate. If the handler fails, the claim is
+    /// released before its error is returned.
+    pub async fn process<F, Fut, T, E>(
+        &self,
+        notification_id: &str,
+        handler: F,
+    ) -> Result<Option<T>, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        E: From<CircleError>,
+    {
+        if !self.claim(notification_id)? {
+            return Ok(None);
+        }
+
+        match handler().await {
+            Ok(value) => Ok(Some(value)),
+            Err(e) => {
+                self.release(notification_id)?;
+                Err(e)
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for NotificationDeduplicator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NotificationDeduplicator")
+            .field("ttl", &self.ttl)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_memory_store_evicts_oldest_claims() {
+        let store = InMemoryDeduplicationStore::with_capacity(2);
+        let ttl = Duration::from_secs(60);
+
+        assert!(store.claim("n-1", ttl).unwrap());
+        assert!(!store.claim("n-1", ttl).unwrap());
+        assert!(store.claim("n-2", ttl).unwrap());
+        assert!(store.claim("n-3", ttl).unwrap());
+        assert_eq!(store.len(), 2);
+        // n-1 was evicted, so it can be claimed again
+        assert!(store.claim("n-1", ttl).unwrap());
+        assert!(!store.claim("n-3", ttl).unwrap());
+
+        store.release("n-3").unwrap();
+        assert!(store.claim("n-3", ttl).unwrap());
+
+        // Expired claims don't block
+        assert!(store.claim("n-4", Duration::ZERO).unwrap());
+        assert!(store.claim("n-4", ttl).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_process_runs_once_and_retries_after_failure() {
+        let dedup = NotificationDeduplicator::new();
+
+        let failed: Result<Option<()>, CircleError> = dedup
+            .process("n-1", || async {
+                Err(CircleError::Config("ledger unavailable".to_string()))
+            })
+            .await;
+        assert!(failed.is_err());
+
+        let first: CircleResult<_> = dedup.process("n-1", || async { Ok(1) }).await;
+        assert_eq!(first.unwrap(), Some(1));
+        let duplicate: CircleResult<_> = dedup.clone().process("n-1", || async { Ok(2) }).await;
+        assert_eq!(duplicate.unwrap(), None);
+    }
+}
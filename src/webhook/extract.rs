@@ -0,0 +1,153 @@
+//! `axum` extractor for Circle webhooks
+
+use ::axum::{
+    body::Bytes,
+    extract::{FromRef, FromRequest, Request},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use serde::de::DeserializeOwned;
+
+use super::{WebhookError, WebhookNotification, WebhookVerifier, KEY_ID_HEADER, SIGNATURE_HEADER};
+
+/// A verified, replay-checked Circle notification with its payload parsed as `T`
+///
+/// Needs a [`WebhookVerifier`] in the router state (directly, or through
+/// `FromRef`). Requests are rejected with:
+///
+/// - `400 Bad Request` for missing headers or a body that cannot be read or parsed as `T`
+/// - `401 Unauthorized` for an invalid signature
+/// - `503 Service Unavailable` when the signing key cannot be fetched, so Circle retries
+///
+/// Authentic notifications that were already received, or that fall outside
+/// an opt-in [`ReplayGuard::tolerance`](super::ReplayGuard::tolerance), are
+/// acknowledged with `200 OK` without reaching the handler. Circle treats any
+/// other status as a failed delivery and keeps redelivering.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use axum::{extract::State, http::StatusCode, routing::post, Router};
+/// use inf_circle_sdk::circle_view::circle_view::CircleView;
+/// use inf_circle_sdk::webhook::{CircleWebhook, WebhookVerifier};
+///
+/// # async fn process(_: &serde_json::Value) -> Result<(), ()> { Ok(()) }
+/// async fn notification(
+///     State(verifier): State<WebhookVerifier>,
+///     CircleWebhook(notification): CircleWebhook<serde_json::Value>,
+/// ) -> StatusCode {
+///     if process(&notification.notification).await.is_err() {
+///         // Let Circle's redelivery through
+///         verifier.replay_guard().forget(&notification.notification_id);
+///         return StatusCode::INTERNAL_SERVER_ERROR;
+///     }
+///     StatusCode::OK
+/// }
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let app: Router = Router::new()
+///     .route("/circle/webhook", post(notification))
+///     .with_state(WebhookVerifier::new(CircleView::new()?));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CircleWebhook<T = serde_json::Value>(pub WebhookNotification<T>);
+
+impl<S, T> FromRequest<S> for CircleWebhook<T>
+where
+    S: Send + Sync,
+    WebhookVerifier: FromRef<S>,
+    T: DeserializeOwned,
+{
+    type Rejection = WebhookError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let key_id = header(req.headers(), KEY_ID_HEADER)?;
+        let signature = header(req.headers(), SIGNATURE_HEADER)?;
+        let body = Bytes::from_request(req, state)
+            .await
+            .map_err(|e| WebhookError::UnreadableBody(e.body_text()))?;
+
+        let verifier = WebhookVerifier::from_ref(state);
+        let notification = verifier.receive(&key_id, &signature, &body).await?;
+        Ok(Self(notification))
+    }
+}
+
+impl IntoResponse for WebhookError {
+    fn into_response(self) -> Response {
+        let status = match self {
+            WebhookError::MissingHeader(_)
+            | WebhookError::UnreadableBody(_)
+            | WebhookError::InvalidPayload(_) => StatusCode::BAD_REQUEST,
+            WebhookError::InvalidSignature => StatusCode::UNAUTHORIZED,
+            // Authentic but already handled or deliberately ignored: stop redeliveries
+            WebhookError::Replayed(_) | WebhookError::Stale(_) => StatusCode::OK,
+            WebhookError::KeyUnavailable { .. } => StatusCode::SERVICE_UNAVAILABLE,
+        };
+        (status, self.to_string()).into_response()
+    }
+}
+
+fn header(headers: &HeaderMap, name: &'static str) -> Result<String, WebhookError> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+        .ok_or(WebhookError::MissingHeader(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockCircle;
+    use ::axum::body::Body;
+
+    #[tokio::test]
+    async fn test_rejections() {
+        let mut circle = MockCircle::start().await;
+        let _key = circle
+            .respond_error(
+                "GET",
+                "/v2/notifications/publicKey/unknown-key",
+                404,
+                "Not found",
+            )
+            .await;
+        let verifier = WebhookVerifier::new(circle.view());
+
+        let unsigned = Request::builder()
+            .header(KEY_ID_HEADER, "unknown-key")
+            .body(Body::from("{}"))
+            .unwrap();
+        let rejection = CircleWebhook::<serde_json::Value>::from_request(unsigned, &verifier)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            rejection,
+            WebhookError::MissingHeader(SIGNATURE_HEADER)
+        ));
+        assert_eq!(rejection.into_response().status(), StatusCode::BAD_REQUEST);
+
+        // A key Circle does not know cannot have signed the notification
+        let forged = Request::builder()
+            .header(KEY_ID_HEADER, "unknown-key")
+            .header(SIGNATURE_HEADER, "MEQCIBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQAiAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEA==")
+            .body(Body::from("{}"))
+            .unwrap();
+        let rejection = CircleWebhook::<serde_json::Value>::from_request(forged, &verifier)
+            .await
+            .unwrap_err();
+        assert!(matches!(rejection, WebhookError::InvalidSignature));
+        assert_eq!(rejection.into_response().status(), StatusCode::UNAUTHORIZED);
+
+        // Duplicates are acknowledged so Circle stops redelivering them
+        assert_eq!(
+            WebhookError::Replayed("n-1".to_string())
+                .into_response()
+                .status(),
+            StatusCode::OK
+        );
+    }
+}
@@ -0,0 +1,166 @@
+//! Webhook receiving: signature verification, replay protection and typed payloads
+//!
+//! Circle signs every notification it delivers. The `X-Circle-Key-Id` header
+//! names the signing key (see
+//! [`get_notification_sig_pub_key`](crate::circle_view::circle_view::CircleView::get_notification_sig_pub_key))
+//! and `X-Circle-Signature` carries the base64 ECDSA P-256 SHA-256
+//! signature of the raw request body.
+//!
+//! # Main Components
+//!
+//! - [`WebhookNotification`]: Notification envelope with a typed payload
+//! - [`ReplayGuard`]: Rejects notifications that are too old or already received
+//! - [`ChallengeTracker`]: Awaits the `challenges.*` outcome of user-controlled wallet challenges
+//! - [`NotificationDeduplicator`]: Runs each notification's handler once despite
+//!   redeliveries, with a pluggable [`DeduplicationStore`]
+//! - `WebhookVerifier`: Fetches and caches Circle's public keys, verifies the
+//!   signature and runs the replay checks (`webhook-verify` feature)
+//! - `CircleWebhook`: `axum` extractor built on `WebhookVerifier` (`axum` feature)
+//!
+//! # Example
+//!
+//! With the `axum` feature, a notification endpoint only needs a verifier in
+//! the router state:
+//!
+//! ```rust,ignore
+//! use axum::{routing::post, Router};
+//! use inf_circle_sdk::circle_view::circle_view::CircleView;
+//! use inf_circle_sdk::ramp::dto::RampSession;
+//! use inf_circle_sdk::webhook::{CircleWebhook, WebhookVerifier};
+//!
+//! async fn ramp_session(CircleWebhook(notification): CircleWebhook<RampSession>) {
+//!     println!("{} is {:?}", notification.notification.id, notification.notification.status);
+//! }
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let verifier = WebhookVerifier::new(CircleView::new()?);
+//! let app: Router = Router::new()
+//!     .route("/circle/webhook", post(ramp_session))
+//!     .with_state(verifier);
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod challenge;
+pub mod dedup;
+#[cfg(feature = "axum")]
+pub mod extract;
+pub mod replay;
+#[cfg(feature = "webhook-verify")]
+pub mod verifier;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::contract::dto::NotificationType;
+use crate::helper::CircleError;
+
+pub use challenge::{Challenge, ChallengeStatus, ChallengeTracker};
+pub use dedup::{DeduplicationStore, InMemoryDeduplicationStore, NotificationDeduplicator};
+#[cfg(feature = "axum")]
+pub use extract::CircleWebhook;
+pub use replay::ReplayGuard;
+#[cfg(feature = "webhook-verify")]
+pub use verifier::{verify_webhook_signature, WebhookVerifier};
+
+/// Header naming the key Circle signed the notification with
+pub const KEY_ID_HEADER: &str = "X-Circle-Key-Id";
+
+/// Header carrying the base64 signature of the request body
+pub const SIGNATURE_HEADER: &str = "X-Circle-Signature";
+
+/// Envelope of a webhook notification with its payload parsed as `T`
+///
+/// Use `serde_json::Value` for `T` to accept any notification type.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookNotification<T = serde_json::Value> {
+    /// Notification subscription that produced this notification
+    pub subscription_id: String,
+
+    /// Unique identifier of the notification, used as the replay nonce
+    pub notification_id: String,
+
+    /// Type of the notification
+    pub notification_type: NotificationType,
+
+    /// Notification payload
+    pub notification: T,
+
+    /// Time Circle created the notification
+    pub timestamp: DateTime<Utc>,
+
+    /// Payload version
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<u32>,
+}
+
+/// Why a webhook request was rejected
+#[derive(Error, Debug)]
+pub enum WebhookError {
+    /// A required header is missing or not valid UTF-8
+    #[error("Missing or invalid {0} header")]
+    MissingHeader(&'static str),
+
+    /// The request body could not be read
+    #[error("Failed to read webhook body: {0}")]
+    UnreadableBody(String),
+
+    /// The signature does not match the body, or names a key Circle does not know
+    #[error("Invalid webhook signature")]
+    InvalidSignature,
+
+    /// The signing key could not be fetched or parsed
+    #[error("Webhook signing key {key_id} is unavailable: {source}")]
+    KeyUnavailable {
+        key_id: String,
+        #[source]
+        source: CircleError,
+    },
+
+    /// The body is not a notification with the expected payload
+    #[error("Invalid webhook payload: {0}")]
+    InvalidPayload(#[from] serde_json::Error),
+
+    /// The notification timestamp is outside the accepted window
+    #[error("Webhook notification from {0} is outside the accepted time window")]
+    Stale(DateTime<Utc>),
+
+    /// The notification ID was already received
+    #[error("Webhook notification {0} was already received")]
+    Replayed(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_unknown_notification_types_parse() {
+        let notification: WebhookNotification = serde_json::from_value(json!({
+            "subscriptionId": "sub-1",
+            "notificationId": "n-1",
+            "notificationType": "webhooks.test",
+            "notification": {},
+            "timestamp": "2025-01-01T00:00:00Z"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            notification.notification_type,
+            NotificationType::Other("webhooks.test".to_string())
+        );
+        assert_eq!(notification.notification_type.as_str(), "webhooks.test");
+        assert_eq!(
+            serde_json::to_value(&notification.notification_type).unwrap(),
+            "webhooks.test"
+        );
+        // Known types keep their variant
+        assert_eq!(
+            serde_json::from_value::<NotificationType>(json!("transactions.inbound")).unwrap(),
+            NotificationType::TransactionsInbound
+        );
+    }
+}
@@ -0,0 +1,161 @@
+//! Replay protection for webhook notifications
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::{DateTime, TimeDelta, Utc};
+
+use super::WebhookError;
+
+/// Rejects notifications that were already received, and optionally old ones
+///
+/// A notification is accepted when its `notificationId` was not accepted
+/// within the retention period (24 hours by default). IDs are remembered
+/// from the time they arrive, so a redelivery Circle sends hours later is
+/// still recognised; a durable [`NotificationDeduplicator`] covers longer
+/// gaps and restarts.
+///
+/// Timestamps are not checked by default. Circle keeps redelivering a
+/// notification until an endpoint acknowledges it, so a fixed age limit would
+/// drop redeliveries that arrive after an outage for good. Set a
+/// [`tolerance`](Self::tolerance) to reject notifications further than that
+/// from the current time (in either direction, to allow for clock skew).
+///
+/// Cheap to clone; clones share the set of received IDs. The set lives in
+/// memory, so replicas behind a load balancer each keep their own.
+///
+/// Call [`forget`](Self::forget) when processing fails after the check so
+/// Circle's redelivery, which carries the same ID, is accepted.
+///
+/// [`NotificationDeduplicator`]: super::NotificationDeduplicator
+#[derive(Debug, Clone)]
+pub struct ReplayGuard {
+    tolerance: Option<TimeDelta>,
+    retention: TimeDelta,
+    seen: Arc<Mutex<HashMap<String, DateTime<Utc>>>>,
+}
+
+impl Default for ReplayGuard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReplayGuard {
+    /// Create a guard remembering IDs for 24 hours, without an age check
+    pub fn new() -> Self {
+        Self {
+            tolerance: None,
+            retention: TimeDelta::hours(24),
+            seen: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Reject notifications more than `tolerance` away from the current time
+    ///
+    /// Redeliveries arriving after the tolerance are rejected as well, so
+    /// keep it well above the longest outage the endpoint should recover
+    /// from. IDs are remembered for at least this long.
+    pub fn tolerance(mut self, tolerance: Duration) -> Self {
+        self.tolerance = Some(TimeDelta::from_std(tolerance).unwrap_or(TimeDelta::MAX));
+        self
+    }
+
+    /// Remember received IDs for `retention` after they arrive (24 hours by default)
+    pub fn retention(mut self, retention: Duration) -> Self {
+        self.retention = TimeDelta::from_std(retention).unwrap_or(TimeDelta::MAX);
+        self
+    }
+
+    /// Accept a notification, or reject it as stale or replayed
+    pub fn check(
+        &self,
+        notification_id: &str,
+        timestamp: DateTime<Utc>,
+    ) -> Result<(), WebhookError> {
+        self.check_at(notification_id, timestamp, Utc::now())
+    }
+
+    /// Forget a received notification ID so a redelivery is accepted
+    pub fn forget(&self, notification_id: &str) {
+        if let Ok(mut seen) = self.seen.lock() {
+            seen.remove(notification_id);
+        }
+    }
+
+    fn check_at(
+        &self,
+        notification_id: &str,
+        timestamp: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Result<(), WebhookError> {
+        if let Some(tolerance) = self.tolerance {
+            if (now - timestamp).abs() > tolerance {
+                return Err(WebhookError::Stale(timestamp));
+            }
+        }
+
+        let retention = self.retention.max(self.tolerance.unwrap_or_default());
+        let mut seen = self.seen.lock().unwrap_or_else(|e| e.into_inner());
+        seen.retain(|_, received| now - *received <= retention);
+        if seen.contains_key(notification_id) {
+            return Err(WebhookError::Replayed(notification_id.to_string()));
+        }
+        seen.insert(notification_id.to_string(), now);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejects_replayed_notifications() {
+        let guard = ReplayGuard::new().retention(Duration::from_secs(3600));
+        let now: DateTime<Utc> = "2025-01-01T00:05:00Z".parse().unwrap();
+        let recent = now - TimeDelta::seconds(30);
+
+        guard.check_at("n-1", recent, now).unwrap();
+        assert!(matches!(
+            guard.clone().check_at("n-1", recent, now),
+            Err(WebhookError::Replayed(id)) if id == "n-1"
+        ));
+        // Old notifications pass without a tolerance, e.g. redeliveries after an outage
+        guard
+            .check_at("n-2", now - TimeDelta::hours(6), now)
+            .unwrap();
+
+        // A failed delivery can be retried
+        guard.forget("n-1");
+        guard.check_at("n-1", recent, now).unwrap();
+
+        // A redelivery within the retention period is still recognised
+        let later = now + TimeDelta::minutes(50);
+        assert!(matches!(
+            guard.check_at("n-1", recent, later),
+            Err(WebhookError::Replayed(_))
+        ));
+
+        // IDs are dropped once the retention period has passed
+        let much_later = now + TimeDelta::hours(2);
+        guard.check_at("n-3", much_later, much_later).unwrap();
+        assert_eq!(guard.seen.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_tolerance_rejects_stale_notifications() {
+        let guard = ReplayGuard::new().tolerance(Duration::from_secs(60));
+        let now: DateTime<Utc> = "2025-01-01T00:05:00Z".parse().unwrap();
+
+        assert!(matches!(
+            guard.check_at("n-1", now - TimeDelta::minutes(2), now),
+            Err(WebhookError::Stale(_))
+        ));
+        // Small clock skew the other way is fine
+        guard
+            .check_at("n-2", now + TimeDelta::seconds(10), now)
+            .unwrap();
+    }
+}
@@ -0,0 +1,324 @@
+//! Webhook signature verification against Circle's notification public keys
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use base64::{engine::general_purpose, Engine};
+use p256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+use p256::pkcs8::DecodePublicKey;
+use serde::de::DeserializeOwned;
+
+use super::{ReplayGuard, WebhookError, WebhookNotification};
+use crate::circle_view::circle_view::CircleView;
+use crate::helper::{CircleError, CircleResult, Instant};
+
+/// How long a failed key lookup is remembered before Circle is asked again
+const FAILED_LOOKUP_TTL: Duration = Duration::from_secs(60);
+
+/// Window over which lookups of unknown key IDs are counted
+const KEY_FETCH_WINDOW: Duration = Duration::from_secs(60);
+
+/// Verify a webhook body against a base64 DER public key and base64 signature
+///
+/// Returns `Ok(false)` for a well-formed signature that does not match, and
+/// `CircleError::Config` if the key or signature cannot be decoded.
+pub fn verify_webhook_signature(
+    public_key: &str,
+    signature: &str,
+    body: &[u8],
+) -> CircleResult<bool> {
+    let key = parse_public_key(public_key)?;
+    let signature = parse_signature(signature)?;
+    Ok(key.verify(body, &signature).is_ok())
+}
+
+/// Verifies, parses and replay-checks incoming Circle webhooks
+///
+/// Public keys are fetched with `get_notification_sig_pub_key` the first
+/// time a key ID is seen and kept for the life of the verifier. Key IDs come
+/// from unauthenticated requests, so a failed lookup is remembered for a
+/// minute and at most 10 unknown key IDs are looked up per minute (see
+/// [`max_key_fetches_per_minute`](Self::max_key_fetches_per_minute));
+/// beyond that, requests fail with [`WebhookError::KeyUnavailable`] until
+/// the window passes. Cheap to clone; clones share the key cache and the
+/// [`ReplayGuard`].
+#[derive(Clone)]
+pub struct WebhookVerifier {
+    view: CircleView,
+    keys: Arc<Mutex<KeyCache>>,
+    max_key_fetches: u32,
+    replay: ReplayGuard,
+}
+
+/// Fetched keys, recently failed lookups and the lookup budget
+struct KeyCache {
+    keys: HashMap<String, VerifyingKey>,
+    failed: HashMap<String, (Instant, LookupFailure)>,
+    window_start: Instant,
+    fetches: u32,
+}
+
+/// Outcome of a failed key lookup, replayed while it is cached
+#[derive(Clone)]
+enum LookupFailure {
+    /// Circle does not know the key ID
+    Unknown,
+    /// The key could not be fetched or parsed
+    Unavailable(String),
+}
+
+impl LookupFailure {
+    fn into_error(self, key_id: &str) -> WebhookError {
+        match self {
+            LookupFailure::Unknown => WebhookError::InvalidSignature,
+            LookupFailure::Unavailable(reason) => WebhookError::KeyUnavailable {
+                key_id: key_id.to_string(),
+                source: CircleError::Config(reason),
+            },
+        }
+    }
+}
+
+impl WebhookVerifier {
+    /// Create a verifier fetching keys through `view`, with the default [`ReplayGuard`]
+    pub fn new(view: CircleView) -> Self {
+        Self {
+            view,
+            keys: Arc::new(Mutex::new(KeyCache {
+                keys: HashMap::new(),
+                failed: HashMap::new(),
+                window_start: Instant::now(),
+                fetches: 0,
+            })),
+            max_key_fetches: 10,
+            replay: ReplayGuard::new(),
+        }
+    }
+
+    /// Look up at most `max` key IDs that are not cached per minute (10 by default)
+    ///
+    /// Circle rotates signing keys rarely, so a burst of unknown key IDs
+    /// means someone is probing the endpoint. Capping the lookups keeps them
+    /// from spending the API key's rate limit.
+    pub fn max_key_fetches_per_minute(mut self, max: u32) -> Self {
+        self.max_key_fetches = max;
+        self
+    }
+
+    /// Use `replay` for the timestamp and notification ID checks
+    pub fn with_replay_guard(mut self, replay: ReplayGuard) -> Self {
+        self.replay = replay;
+        self
+    }
+
+    /// The replay guard, e.g. to [`forget`](ReplayGuard::forget) a notification that failed processing
+    pub fn replay_guard(&self) -> &ReplayGuard {
+        &self.replay
+    }
+
+    /// Check that `signature` is Circle's signature of `body` with the key `key_id`
+    pub async fn verify_signature(
+        &self,
+        key_id: &str,
+        signature: &str,
+        body: &[u8],
+    ) -> Result<(), WebhookError> {
+        let signature = parse_signature(signature).map_err(|_| WebhookError::InvalidSignature)?;
+        let key = self.key(key_id).await?;
+        key.verify(body, &signature)
+            .map_err(|_| WebhookError::InvalidSignature)
+    }
+
+    /// Verify the signature, parse the notification and run the replay checks
+    ///
+    /// The notification ID is only recorded once the signature is valid and
+    /// the body parses.
+    pub async fn receive<T: DeserializeOwned>(
+        &self,
+        key_id: &str,
+        signature: &str,
+        body: &[u8],
+    ) -> Result<WebhookNotification<T>, WebhookError> {
+        self.verify_signature(key_id, signature, body).await?;
+        let notification: WebhookNotification<T> = serde_json::from_slice(body)?;
+        self.replay
+            .check(&notification.notification_id, notification.timestamp)?;
+        Ok(notification)
+    }
+
+    async fn key(&self, key_id: &str) -> Result<VerifyingKey, WebhookError> {
+        if let Some(key) = self.cached_or_reserve(key_id)? {
+            return Ok(key);
+        }
+
+        let failure = match self.view.get_notification_sig_pub_key(key_id).await {
+            Ok(public_key) => match parse_public_key(&public_key) {
+                Ok(key) => {
+                    let mut cache = self.keys.lock().unwrap_or_else(|e| e.into_inner());
+                    cache.keys.insert(key_id.to_string(), key);
+                    return Ok(key);
+                }
+                Err(e) => LookupFailure::Unavailable(e.to_string()),
+            },
+            // Not a key Circle signs with, so not a Circle notification
+            Err(CircleError::Api { status: 404, .. }) => LookupFailure::Unknown,
+            Err(e) => LookupFailure::Unavailable(e.to_string()),
+        };
+
+        let mut cache = self.keys.lock().unwrap_or_else(|e| e.into_inner());
+        cache
+            .failed
+            .insert(key_id.to_string(), (Instant::now(), failure.clone()));
+        Err(failure.into_error(key_id))
+    }
+
+    /// Return the cached key or failure for `key_id`, or take a lookup from the budget
+    fn cached_or_reserve(&self, key_id: &str) -> Result<Option<VerifyingKey>, WebhookError> {
+        let mut cache = self.keys.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(key) = cache.keys.get(key_id) {
+            return Ok(Some(*key));
+        }
+
+        let now = Instant::now();
+
+        cache
+            .failed
+            .retain(|_, (failed_at, _)| now.duration_since(*failed_at) < FAILED_LOOKUP_TTL);
+        if let Some((_, failure)) = cache.failed.get(key_id) {
+            return Err(failure.clone().into_error(key_id));
+        }
+
+        if now.duration_since(cache.window_start) >= KEY_FETCH_WINDOW {
+            cache.window_start = now;
+            cache.fetches = 0;
+        }
+        if cache.fetches >= self.max_key_fetches {
+            return Err(WebhookError::KeyUnavailable {
+                key_id: key_id.to_string(),
+                source: CircleError::Config(
+                    "Too many unknown signing key IDs, try again later".to_string(),
+                ),
+            });
+        }
+        cache.fetches += 1;
+        Ok(None)
+    }
+}
+
+impl std::fmt::Debug for WebhookVerifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookVerifier")
+            .field("replay", &self.replay)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Parse a base64 DER (SubjectPublicKeyInfo) P-256 public key
+fn parse_public_key(public_key: &str) -> CircleResult<VerifyingKey> {
+    let der = general_purpose::STANDARD
+        .decode(public_key.trim())
+        .map_err(|e| CircleError::Config(format!("Public key is not base64: {}", e)))?;
+    VerifyingKey::from_public_key_der(&der)
+        .map_err(|e| CircleError::Config(format!("Invalid P-256 public key: {}", e)))
+}
+
+/// Parse a base64 DER ECDSA signature
+fn parse_signature(signature: &str) -> CircleResult<Signature> {
+    let der = general_purpose::STANDARD
+        .decode(signature.trim())
+        .map_err(|e| CircleError::Config(format!("Signature is not base64: {}", e)))?;
+    Signature::from_der(&der)
+        .map_err(|e| CircleError::Config(format!("Invalid ECDSA signature: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockCircle;
+    use p256::ecdsa::{signature::Signer, SigningKey};
+    use p256::pkcs8::EncodePublicKey;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_receive_verifies_and_rejects_replays() {
+        let signing_key = SigningKey::random(&mut rand::rngs::OsRng);
+        let public_key = general_purpose::STANDARD.encode(
+            signing_key
+                .verifying_key()
+                .to_public_key_der()
+                .unwrap()
+                .as_bytes(),
+        );
+
+        let mut circle = MockCircle::start().await;
+        let key_mock = circle
+            .respond("GET", "/v2/notifications/publicKey/key-1", &public_key)
+            .await;
+
+        let body = json!({
+            "subscriptionId": "sub-1",
+            "notificationId": "n-1",
+            "notificationType": "transactions.inbound",
+            "notification": { "hello": "world" },
+            "timestamp": chrono::Utc::now(),
+            "version": 2
+        })
+        .to_string();
+        let signature: Signature = signing_key.sign(body.as_bytes());
+        let signature = general_purpose::STANDARD.encode(signature.to_der().as_bytes());
+
+        let verifier = WebhookVerifier::new(circle.view());
+        let notification: WebhookNotification = verifier
+            .receive("key-1", &signature, body.as_bytes())
+            .await
+            .unwrap();
+        assert_eq!(notification.notification["hello"], "world");
+        assert!(verify_webhook_signature(&public_key, &signature, body.as_bytes()).unwrap());
+
+        assert!(matches!(
+            verifier
+                .receive::<serde_json::Value>("key-1", &signature, body.as_bytes())
+                .await,
+            Err(WebhookError::Replayed(_))
+        ));
+        let tampered = body.replace("world", "mars");
+        assert!(matches!(
+            verifier
+                .receive::<serde_json::Value>("key-1", &signature, tampered.as_bytes())
+                .await,
+            Err(WebhookError::InvalidSignature)
+        ));
+        // The key was fetched once
+        key_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_unknown_key_lookups_are_cached_and_capped() {
+        let mut circle = MockCircle::start().await;
+        let mut unknown = Vec::new();
+        for key_id in ["key-a", "key-b"] {
+            let path = format!("/v2/notifications/publicKey/{}", key_id);
+            unknown.push(circle.respond_error("GET", &path, 404, "Not found").await);
+        }
+        let verifier = WebhookVerifier::new(circle.view()).max_key_fetches_per_minute(2);
+        let signature = "MEQCIBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQAiAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEA==";
+
+        for key_id in ["key-a", "key-a", "key-b"] {
+            assert!(matches!(
+                verifier.verify_signature(key_id, signature, b"{}").await,
+                Err(WebhookError::InvalidSignature)
+            ));
+        }
+        // The lookup budget is spent, so a third unknown key is not fetched
+        assert!(matches!(
+            verifier.verify_signature("key-c", signature, b"{}").await,
+            Err(WebhookError::KeyUnavailable { key_id, .. }) if key_id == "key-c"
+        ));
+
+        // Each unknown key was looked up once
+        for mock in unknown {
+            mock.assert_async().await;
+        }
+    }
+}
@@ -5,10 +5,12 @@ use inf_circle_sdk::{
     circle_ops::circler_ops::CircleOps,
     circle_view::circle_view::CircleView,
     contract::{
+        abi::extract_event_signatures,
         dto::{
             ListContractsParams, ListEventLogsParams, ListEventMonitorsParams, NotificationType,
             UpdateContractRequest,
         },
+        erc20::Erc20Client,
         ops::{
             deploy_contract::DeployContractRequestBuilder,
             deploy_contract_from_template::DeployContractFromTemplateRequestBuilder,
@@ -24,6 +26,7 @@ use inf_circle_sdk::{
             update_notification_subscription::UpdateNotificationSubscriptionBodyBuilder,
         },
     },
+    dev_wallet::dto::FeeLevel,
     helper::PaginationParams,
     types::Blockchain,
 };
@@ -56,7 +59,7 @@ async fn test_list_contracts() {
         .await
         .expect("Failed to list contracts");
 
-    println!("✅ Found {} contracts", contracts.contracts.len());
+    println!("✅ Found {} contracts", contracts.items.len());
 
     // Test with params
     let params = ListContractsParams {
@@ -80,7 +83,7 @@ async fn test_list_contracts() {
 
     println!(
         "✅ Found {} contracts on ETH-SEPOLIA",
-        filtered_contracts.contracts.len()
+        filtered_contracts.items.len()
     );
 }
 
@@ -98,7 +101,7 @@ async fn test_get_contract() {
         .await
         .expect("Failed to list contracts");
 
-    if let Some(contract) = contracts.contracts.first() {
+    if let Some(contract) = contracts.items.first() {
         let contract_id = contract.id.as_ref().expect("Contract should have an ID");
         println!("📝 Getting contract with ID: {}", contract_id);
 
@@ -135,7 +138,7 @@ async fn test_update_contract() {
         .await
         .expect("Failed to list contracts");
 
-    if let Some(contract) = contracts.contracts.first() {
+    if let Some(contract) = contracts.items.first() {
         let contract_id = contract.id.as_ref().expect("Contract should have an ID");
         println!("📝 Updating contract with ID: {}", contract_id);
 
@@ -177,7 +180,6 @@ async fn test_estimate_template_deployment_fee() {
     // Get or create a test wallet
     let wallet = get_or_create_test_wallet(
         &ops,
-        &view,
         &wallet_set_id,
         &Blockchain::EthSepolia,
         "Contract Test",
@@ -210,21 +212,33 @@ async fn test_estimate_template_deployment_fee() {
 
     println!("✅ Fee estimated:");
     println!(
-        "   Low:    gas_limit={}, max_fee={:?}",
+        "   Low:    gas_limit={:?}, max_fee={:?}",
         fee_estimation.low.gas_limit, fee_estimation.low.max_fee
     );
     println!(
-        "   Medium: gas_limit={}, max_fee={:?}",
+        "   Medium: gas_limit={:?}, max_fee={:?}",
         fee_estimation.medium.gas_limit, fee_estimation.medium.max_fee
     );
     println!(
-        "   High:   gas_limit={}, max_fee={:?}",
+        "   High:   gas_limit={:?}, max_fee={:?}",
         fee_estimation.high.gas_limit, fee_estimation.high.max_fee
     );
 
-    assert!(!fee_estimation.low.gas_limit.is_empty());
-    assert!(!fee_estimation.medium.gas_limit.is_empty());
-    assert!(!fee_estimation.high.gas_limit.is_empty());
+    assert!(fee_estimation
+        .low
+        .gas_limit
+        .as_deref()
+        .is_some_and(|gas_limit| !gas_limit.is_empty()));
+    assert!(fee_estimation
+        .medium
+        .gas_limit
+        .as_deref()
+        .is_some_and(|gas_limit| !gas_limit.is_empty()));
+    assert!(fee_estimation
+        .high
+        .gas_limit
+        .as_deref()
+        .is_some_and(|gas_limit| !gas_limit.is_empty()));
 }
 
 #[tokio::test]
@@ -238,7 +252,6 @@ async fn test_estimate_contract_deployment_fee() {
     // Get or create a test wallet
     let wallet = get_or_create_test_wallet(
         &ops,
-        &view,
         &wallet_set_id,
         &Blockchain::EthSepolia,
         "Contract Test",
@@ -265,21 +278,33 @@ async fn test_estimate_contract_deployment_fee() {
 
     println!("✅ Fee estimated for contract deployment:");
     println!(
-        "   Low:    gas_limit={}, network_fee={:?}",
+        "   Low:    gas_limit={:?}, network_fee={:?}",
         fee_estimation.low.gas_limit, fee_estimation.low.network_fee
     );
     println!(
-        "   Medium: gas_limit={}, network_fee={:?}",
+        "   Medium: gas_limit={:?}, network_fee={:?}",
         fee_estimation.medium.gas_limit, fee_estimation.medium.network_fee
     );
     println!(
-        "   High:   gas_limit={}, network_fee={:?}",
+        "   High:   gas_limit={:?}, network_fee={:?}",
         fee_estimation.high.gas_limit, fee_estimation.high.network_fee
     );
 
-    assert!(!fee_estimation.low.gas_limit.is_empty());
-    assert!(!fee_estimation.medium.gas_limit.is_empty());
-    assert!(!fee_estimation.high.gas_limit.is_empty());
+    assert!(fee_estimation
+        .low
+        .gas_limit
+        .as_deref()
+        .is_some_and(|gas_limit| !gas_limit.is_empty()));
+    assert!(fee_estimation
+        .medium
+        .gas_limit
+        .as_deref()
+        .is_some_and(|gas_limit| !gas_limit.is_empty()));
+    assert!(fee_estimation
+        .high
+        .gas_limit
+        .as_deref()
+        .is_some_and(|gas_limit| !gas_limit.is_empty()));
 }
 
 #[tokio::test]
@@ -293,7 +318,6 @@ async fn test_deploy_contract_from_template() {
     // Get or create a test wallet
     let wallet = get_or_create_test_wallet(
         &ops,
-        &view,
         &wallet_set_id,
         &Blockchain::EthSepolia,
         "Contract Test",
@@ -331,7 +355,7 @@ async fn test_deploy_contract_from_template() {
     )
     .expect("Failed to create builder")
     .template_parameters(template_params)
-    .fee_level("MEDIUM".to_string())
+    .fee_level(FeeLevel::Medium)
     .ref_id(format!("test-contract-{}", uuid::Uuid::new_v4()))
     .idempotency_key(uuid::Uuid::new_v4().to_string())
     .build();
@@ -360,7 +384,6 @@ async fn test_deploy_contract_from_bytecode() {
     // Get or create a test wallet
     let wallet = get_or_create_test_wallet(
         &ops,
-        &view,
         &wallet_set_id,
         &Blockchain::EthSepolia,
         "Contract Test",
@@ -400,7 +423,7 @@ async fn test_deploy_contract_from_bytecode() {
         Blockchain::EthSepolia,
     )
     .description("Test contract deployment from bytecode".to_string())
-    .fee_level("MEDIUM".to_string())
+    .fee_level(FeeLevel::Medium)
     .ref_id(format!("test-deploy-{}", uuid::Uuid::new_v4()))
     .idempotency_key(uuid::Uuid::new_v4().to_string());
 
@@ -684,9 +707,9 @@ async fn test_list_contracts_with_pagination() {
 
     println!(
         "✅ Retrieved page with {} contracts (max 5)",
-        contracts.contracts.len()
+        contracts.items.len()
     );
-    assert!(contracts.contracts.len() <= 5);
+    assert!(contracts.items.len() <= 5);
 }
 
 #[tokio::test]
@@ -721,14 +744,14 @@ async fn test_list_contracts_by_blockchain() {
 
         println!(
             "✅ Found {} contracts on {}",
-            contracts.contracts.len(),
+            contracts.items.len(),
             blockchain.as_str()
         );
 
         // Verify all contracts are on the correct blockchain
-        for contract in &contracts.contracts {
+        for contract in &contracts.items {
             if let Some(ref bc) = contract.blockchain {
-                assert_eq!(bc.to_uppercase(), blockchain.as_str().to_uppercase());
+                assert_eq!(bc, &blockchain);
             }
         }
     }
@@ -846,6 +869,38 @@ async fn test_query_contract() {
     println!("\n🎉 All USDC contract queries successful!");
 }
 
+#[tokio::test]
+async fn test_erc20_client_reads() {
+    dotenv::dotenv().ok();
+
+    let view = CircleView::new().expect("Failed to create CircleView");
+
+    let usdc = Erc20Client::new(
+        Blockchain::EthSepolia,
+        "0x1c7D4B196Cb0C7B01d743Fbc6116a902379C7238".to_string(),
+    );
+
+    let metadata = usdc
+        .metadata(&view)
+        .await
+        .expect("Failed to get USDC metadata");
+    println!("✅ USDC metadata: {:?}", metadata);
+    assert_eq!(metadata.symbol, "USDC");
+    assert_eq!(metadata.decimals, 6);
+
+    let total_supply = usdc
+        .total_supply(&view)
+        .await
+        .expect("Failed to get USDC total supply");
+    assert!(!total_supply.is_zero());
+
+    let balance = usdc
+        .balance_of(&view, "0x0000000000000000000000000000000000000001")
+        .await
+        .expect("Failed to get USDC balance");
+    println!("✅ Balance of 0x...01: {}", balance);
+}
+
 #[tokio::test]
 async fn test_list_event_logs() {
     dotenv::dotenv().ok();
@@ -863,10 +918,10 @@ async fn test_list_event_logs() {
         .await
         .expect("Failed to list all event logs");
 
-    println!("✅ Found {} event logs total", all_logs.event_logs.len());
+    println!("✅ Found {} event logs total", all_logs.items.len());
 
-    if !all_logs.event_logs.is_empty() {
-        let log = &all_logs.event_logs[0];
+    if !all_logs.items.is_empty() {
+        let log = &all_logs.items[0];
         println!("\n   Sample event log:");
         println!("   - ID: {}", log.id);
         println!("   - Contract: {}", log.contract_address);
@@ -894,11 +949,11 @@ async fn test_list_event_logs() {
 
     println!(
         "✅ Found {} event logs on ETH-SEPOLIA",
-        blockchain_logs.event_logs.len()
+        blockchain_logs.items.len()
     );
 
     // Verify all logs are on the correct blockchain
-    for log in &blockchain_logs.event_logs {
+    for log in &blockchain_logs.items {
         // The blockchain should match ETH-SEPOLIA
         println!(
             "   - Event: {} at block {}",
@@ -907,10 +962,10 @@ async fn test_list_event_logs() {
     }
 
     // Test 3: List event logs filtered by contract address (if we have logs)
-    if !all_logs.event_logs.is_empty() {
+    if !all_logs.items.is_empty() {
         println!("\n3️⃣  Listing event logs filtered by contract address...");
 
-        let contract_addr = &all_logs.event_logs[0].contract_address;
+        let contract_addr = &all_logs.items[0].contract_address;
 
         let params2 = ListEventLogsParams {
             contract_address: Some(contract_addr.clone()),
@@ -927,12 +982,12 @@ async fn test_list_event_logs() {
 
         println!(
             "✅ Found {} event logs for contract {}",
-            contract_logs.event_logs.len(),
+            contract_logs.items.len(),
             contract_addr
         );
 
         // All filtered logs should be for the correct contract
-        for log in &contract_logs.event_logs {
+        for log in &contract_logs.items {
             assert_eq!(
                 log.contract_address.to_lowercase(),
                 contract_addr.to_lowercase()
@@ -943,7 +998,7 @@ async fn test_list_event_logs() {
     println!("\n🎉 All event log tests passed successfully!");
     println!("   ✅ Listed all event logs");
     println!("   ✅ Listed event logs by blockchain");
-    if !all_logs.event_logs.is_empty() {
+    if !all_logs.items.is_empty() {
         println!("   ✅ Listed event logs by contract address");
     }
 }
@@ -968,19 +1023,12 @@ async fn test_create_event_monitor() {
         .expect("Failed to list contracts");
 
     // Find a contract that has events in its ABI (required for event monitoring)
-    let test_contract = all_contracts.contracts.iter().find(|c| {
+    let test_contract = all_contracts.items.iter().find(|c| {
         c.contract_address.is_some()
-            && c.events.is_some()
-            && !c.events.as_ref().unwrap().is_null()
-            && c.events
-                .as_ref()
-                .unwrap()
-                .as_array()
-                .map(|arr| !arr.is_empty())
-                .unwrap_or(false)
+            && !extract_event_signatures(c).is_empty()
             && c.blockchain
                 .as_ref()
-                .map(|b| b.contains("SEPOLIA"))
+                .map(|b| b.as_str().ends_with("-SEPOLIA"))
                 .unwrap_or(false)
     });
 
@@ -992,29 +1040,11 @@ async fn test_create_event_monitor() {
                 .clone()
                 .unwrap_or_else(|| "Unknown".to_string());
 
-            // Extract event signatures from the contract's events
-            let events = contract.events.as_ref().unwrap().as_array().unwrap();
-            let mut sigs = Vec::new();
-
-            for event in events {
-                if let Some(event_name) = event.get("name").and_then(|n| n.as_str()) {
-                    // Try to construct the event signature from the event definition
-                    if let Some(inputs) = event.get("inputs").and_then(|i| i.as_array()) {
-                        let params: Vec<String> = inputs
-                            .iter()
-                            .filter_map(|input| {
-                                input
-                                    .get("type")
-                                    .and_then(|t| t.as_str())
-                                    .map(|s| s.to_string())
-                            })
-                            .collect();
-
-                        let signature = format!("{}({})", event_name, params.join(","));
-                        sigs.push(signature);
-                    }
-                }
-            }
+            // Extract event signatures from the contract's ABI
+            let sigs: Vec<String> = extract_event_signatures(contract)
+                .into_iter()
+                .map(|event| event.canonical)
+                .collect();
 
             println!("✅ Found contract with events:");
             println!("   Name: {}", name);
@@ -1223,18 +1253,15 @@ async fn test_create_event_monitor() {
         .await
         .expect("Failed to list all event monitors");
 
-    println!(
-        "✅ Found {} event monitors total",
-        all_monitors.event_monitors.len()
-    );
+    println!("✅ Found {} event monitors total", all_monitors.items.len());
 
     // Verify our monitors are in the list
     let has_transfer = all_monitors
-        .event_monitors
+        .items
         .iter()
         .any(|m| m.id == response.event_monitor.id);
     let has_approval = all_monitors
-        .event_monitors
+        .items
         .iter()
         .any(|m| m.id == response2.event_monitor.id);
 
@@ -1260,12 +1287,12 @@ async fn test_create_event_monitor() {
 
     println!(
         "✅ Found {} event monitors for contract {}",
-        filtered_monitors.event_monitors.len(),
+        filtered_monitors.items.len(),
         contract_address
     );
 
     // All filtered monitors should be for the correct contract
-    for monitor in &filtered_monitors.event_monitors {
+    for monitor in &filtered_monitors.items {
         assert_eq!(
             monitor.contract_address.to_lowercase(),
             contract_address.to_lowercase()
@@ -1291,7 +1318,7 @@ async fn test_create_event_monitor() {
 
     println!(
         "✅ Found {} event monitors on ETH-SEPOLIA",
-        blockchain_filtered.event_monitors.len()
+        blockchain_filtered.items.len()
     );
 
     // Test 9: Delete the Transfer event monitor
@@ -1,7 +1,12 @@
 mod common;
 
 use base64::{engine::general_purpose, Engine as _};
-use common::{get_or_create_destination_wallet, get_or_create_test_wallet, retry_on_rate_limit};
+use common::{
+    ensure_wallet_funded, get_or_create_destination_wallet, get_or_create_test_wallet,
+    retry_on_rate_limit,
+};
+#[cfg(feature = "near")]
+use inf_circle_sdk::near::{parse_near_public_key, serialize_near_delegate_action_to_base64};
 use inf_circle_sdk::{
     circle_ops::circler_ops::CircleOps,
     circle_view::circle_view::CircleView,
@@ -9,9 +14,9 @@ use inf_circle_sdk::{
     dev_wallet::{
         dto::{
             AbiParameter, AccountType, DevWallet, DevWalletMetadata,
-            EstimateContractExecutionFeeBody, EstimateTransferFeeRequest, FeeLevel,
-            ListDevWalletsParams, QueryContractRequest, QueryParams, RequestTestnetTokensRequest,
-            ScaCore, UpdateDevWalletRequest,
+            EstimateContractExecutionFeeBody, EstimateTransferFeeRequest, FeeConfig, FeeLevel,
+            QueryContractRequest, QueryParams, RequestTestnetTokensRequest, ScaCore,
+            UpdateDevWalletRequest,
         },
         ops::{
             accelerate_transaction::AccelerateTransactionRequestBuilder,
@@ -31,11 +36,10 @@ use inf_circle_sdk::{
             query::QueryParamsBuilder,
         },
     },
-    helper::PaginationParams,
-    near::{parse_near_public_key, serialize_near_delegate_action_to_base64},
+    helper::{await_visible, PaginationParams},
     types::Blockchain,
 };
-use std::env;
+use std::{env, time::Duration};
 
 /// Helper function to get or create an SCA wallet for testing
 ///
@@ -50,21 +54,13 @@ async fn get_or_create_sca_wallet(
     let ref_id = format!("test-sca-wallet-{}", blockchain_str);
 
     // Try to find existing wallet by ref_id
-    let list_params = ListDevWalletsParams {
-        address: None,
-        blockchain: None,
-        sca_core: None,
-        wallet_set_id: None,
-        ref_id: Some(ref_id.clone()),
-        from: None,
-        to: None,
-        pagination: PaginationParams::default(),
-        order: None,
-    };
+    let list_params = ListDevWalletsParamsBuilder::new()
+        .ref_id(ref_id.clone())
+        .build();
 
     match view.list_wallets(list_params).await {
-        Ok(response) if !response.wallets.is_empty() => {
-            let wallet = response.wallets.into_iter().next().unwrap();
+        Ok(response) if !response.items.is_empty() => {
+            let wallet = response.items.into_iter().next().unwrap();
             println!(
                 "♻  Reusing existing SCA wallet: {} ({})",
                 wallet.id, wallet.address
@@ -108,6 +104,7 @@ async fn get_or_create_sca_wallet(
 /// or fall back to a known USDC contract address.
 async fn get_or_deploy_test_contract(
     ops: &CircleOps,
+    view: &CircleView,
     wallet: &DevWallet,
     blockchain: &Blockchain,
 ) -> Result<String, Box<dyn std::error::Error>> {
@@ -127,21 +124,23 @@ async fn get_or_deploy_test_contract(
                 ) {
                     Ok(builder) => {
                         let response = ops
-                            .deploy_contract_from_template(
+                            .deploy_contract_from_template_and_wait(
+                                view,
                                 builder
                                     .ref_id("test-contract-deployment".to_string())
                                     .build(),
+                                Duration::from_secs(180),
                             )
                             .await;
 
                         match response {
-                            Ok(deployment) => {
-                                println!(
-                                    "✅ Contract deployed, transaction ID: {}",
-                                    deployment.transaction_id
-                                );
-                                // Note: Can't return contract address directly anymore, need to wait for transaction
-                                return Ok("0x0000000000000000000000000000000000000000".to_string());
+                            Ok(contracts) => {
+                                let address = contracts
+                                    .first()
+                                    .and_then(|c| c.contract_address.clone())
+                                    .ok_or("Template deployment returned no contracts")?;
+                                println!("✅ Contract deployed at {}", address);
+                                return Ok(address);
                             }
                             Err(e) => {
                                 println!("⚠️  Template deployment failed: {}", e);
@@ -167,80 +166,8 @@ async fn get_or_deploy_test_contract(
     }
 }
 
-/// Helper function to ensure a wallet has testnet tokens
-///
-/// Checks if the wallet has a balance, and if not (or balance is very low),
-/// requests testnet tokens from the faucet.
-async fn ensure_wallet_funded(
-    view: &CircleView,
-    wallet: &DevWallet,
-    blockchain: &Blockchain,
-) -> Result<(), Box<dyn std::error::Error>> {
-    // Check if this is a testnet blockchain
-    let is_testnet = matches!(
-        blockchain,
-        Blockchain::EthSepolia
-            | Blockchain::AvaxFuji
-            | Blockchain::MaticAmoy
-            | Blockchain::SolDevnet
-            | Blockchain::ArbSepolia
-            | Blockchain::NearTestnet
-            | Blockchain::UniSepolia
-            | Blockchain::BaseSepolia
-            | Blockchain::OpSepolia
-            | Blockchain::AptosTestnet
-    );
-
-    if !is_testnet {
-        println!("⚠️  Wallet is on mainnet, skipping faucet funding");
-        return Ok(());
-    }
-
-    // Try to get the balance
-    let query_params = QueryParamsBuilder::new().build();
-    match view.get_token_balances(&wallet.id, query_params).await {
-        Ok(balances) => {
-            // Find native token balance
-            let has_native_balance = balances
-                .token_balances
-                .iter()
-                .any(|b| b.token.is_native && b.amount.parse::<f64>().unwrap_or(0.0) > 0.001);
-
-            if has_native_balance {
-                println!("✅ Wallet {} already has native tokens", wallet.address);
-                return Ok(());
-            }
-        }
-        Err(_) => {
-            // If we can't get balance, try to fund anyway
-            println!("⚠️  Could not check balance, requesting tokens anyway");
-        }
-    }
-
-    // Request tokens from faucet
-    println!(
-        "💰 Requesting testnet tokens for wallet: {}",
-        wallet.address
-    );
-
-    let request = RequestTestnetTokensRequest {
-        blockchain: blockchain.clone(),
-        address: wallet.address.clone(),
-        native: Some(true),
-        usdc: Some(true),
-        eurc: None,
-    };
-
-    retry_on_rate_limit(|| async { view.request_testnet_tokens(request.clone()).await }).await?;
-
-    println!("✅ Successfully requested testnet tokens!");
-    println!("   ⏳ Waiting 5 seconds for tokens to arrive...");
-    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
-
-    Ok(())
-}
-
 // NEAR Protocol types (official)
+#[cfg(feature = "near")]
 use near_primitives::{
     action::{
         delegate::{DelegateAction, NonDelegateAction},
@@ -257,7 +184,6 @@ async fn test_setup_wallets_for_funding() {
     dotenv::dotenv().ok();
 
     let ops = CircleOps::new(None).expect("Failed to create CircleOps");
-    let view = CircleView::new().expect("Failed to create CircleView");
     let wallet_set_id = env::var("CIRCLE_WALLET_SET_ID").expect("CIRCLE_WALLET_SET_ID not set");
 
     println!("\n═══════════════════════════════════════════════════════════════");
@@ -267,7 +193,6 @@ async fn test_setup_wallets_for_funding() {
     // Get or create source ETH-SEPOLIA wallet
     if let Ok(source_wallet) = get_or_create_test_wallet(
         &ops,
-        &view,
         &wallet_set_id,
         &Blockchain::EthSepolia,
         "ETH Sepolia Test",
@@ -289,7 +214,7 @@ async fn test_setup_wallets_for_funding() {
 
     // Get or create destination ETH-SEPOLIA wallet
     if let Ok(dest_wallet) =
-        get_or_create_destination_wallet(&ops, &view, &wallet_set_id, &Blockchain::EthSepolia).await
+        get_or_create_destination_wallet(&ops, &wallet_set_id, &Blockchain::EthSepolia).await
     {
         println!("📍 DESTINATION Wallet (ETH-SEPOLIA):");
         println!("   Address: {}", dest_wallet.address);
@@ -376,7 +301,7 @@ async fn test_wallet_lifecycle() {
         .await
         .expect("Failed to list wallets");
     let wallet_in_list = list_response
-        .wallets
+        .items
         .iter()
         .find(|w| w.id == new_wallet.id)
         .expect("Wallet not found in list");
@@ -407,20 +332,20 @@ async fn test_list_wallets_with_token_balances() {
 
     // Verify response structure
     assert!(
-        !response_with_params.wallets.is_empty(),
+        !response_with_params.items.is_empty(),
         "Should have at least one wallet"
     );
 
     // Check that each wallet has token_balances field
-    for wallet in &response_with_params.wallets {
+    for wallet in &response_with_params.items {
         assert!(!wallet.id.is_empty(), "Wallet ID should not be empty");
         assert!(
             !wallet.address.is_empty(),
             "Wallet address should not be empty"
         );
         assert!(
-            !wallet.blockchain.is_empty(),
-            "Blockchain should not be empty"
+            !matches!(wallet.blockchain, Blockchain::Other(_)),
+            "Blockchain should be a known network"
         );
         // token_balances can be empty, that's okay
     }
@@ -563,7 +488,6 @@ async fn test_query_params_builder() {
             page_size: Some(25),
             ..Default::default()
         },
-        ..Default::default()
     };
 
     // Verify all fields are set correctly
@@ -584,7 +508,6 @@ async fn test_sign_message() {
 
     // Initialize CircleOps and CircleView
     let ops = CircleOps::new(None).expect("Failed to create CircleOps");
-    let view = CircleView::new().expect("Failed to create CircleView");
 
     // Get required environment variables
     let wallet_set_id = env::var("CIRCLE_WALLET_SET_ID").expect("CIRCLE_WALLET_SET_ID not set");
@@ -592,7 +515,6 @@ async fn test_sign_message() {
     // Get or create a test wallet (reuses existing to avoid rate limits)
     let test_wallet = get_or_create_test_wallet(
         &ops,
-        &view,
         &wallet_set_id,
         &Blockchain::EthSepolia,
         "Sign Message Test",
@@ -805,6 +727,7 @@ async fn test_sign_transaction() {
     }
 }
 
+#[cfg(feature = "near")]
 #[tokio::test]
 async fn test_sign_delegate_near() {
     // Load environment variables from .env file
@@ -932,7 +855,6 @@ async fn test_sign_delegate_non_near_should_fail() {
 
     // Initialize CircleOps and CircleView
     let ops = CircleOps::new(None).expect("Failed to create CircleOps");
-    let view = CircleView::new().expect("Failed to create CircleView");
 
     // Get required environment variables
     let wallet_set_id = env::var("CIRCLE_WALLET_SET_ID").expect("CIRCLE_WALLET_SET_ID not set");
@@ -940,7 +862,6 @@ async fn test_sign_delegate_non_near_should_fail() {
     // Get or create a non-NEAR test wallet (Ethereum)
     let test_wallet = get_or_create_test_wallet(
         &ops,
-        &view,
         &wallet_set_id,
         &Blockchain::EthSepolia,
         "Sign Delegate ETH Test",
@@ -996,27 +917,23 @@ async fn test_list_transactions() {
     match result {
         Ok(transactions_response) => {
             println!("✅ Successfully listed transactions!");
-            println!(
-                "Found {} transactions",
-                transactions_response.transactions.len()
-            );
+            println!("Found {} transactions", transactions_response.items.len());
 
             // Verify the response structure
             assert!(
-                !transactions_response.transactions.is_empty()
-                    || transactions_response.transactions.is_empty(),
+                !transactions_response.items.is_empty() || transactions_response.items.is_empty(),
                 "Transactions response should be valid regardless of empty results"
             );
 
             // If we have transactions, verify some basic fields
-            if let Some(first_tx) = transactions_response.transactions.first() {
+            if let Some(first_tx) = transactions_response.items.first() {
                 assert!(
                     !first_tx.id.is_empty(),
                     "Transaction ID should not be empty"
                 );
                 assert!(
-                    !first_tx.blockchain.is_empty(),
-                    "Blockchain should not be empty"
+                    !matches!(first_tx.blockchain, Blockchain::Other(_)),
+                    "Blockchain should be a known network"
                 );
                 assert!(!first_tx.state.is_empty(), "State should not be empty");
                 assert!(
@@ -1064,13 +981,14 @@ async fn test_list_transactions_with_filters() {
             println!("✅ Successfully listed filtered transactions!");
             println!(
                 "Found {} filtered transactions",
-                transactions_response.transactions.len()
+                transactions_response.items.len()
             );
 
             // Verify filtered results match criteria
-            for tx in &transactions_response.transactions {
+            for tx in &transactions_response.items {
                 assert_eq!(
-                    tx.blockchain, "ETH-SEPOLIA",
+                    tx.blockchain,
+                    Blockchain::EthSepolia,
                     "Blockchain should match filter"
                 );
                 if let Some(custody_type) = &tx.custody_type {
@@ -1110,7 +1028,6 @@ async fn test_get_transaction() {
     // 1. Get or create source wallet
     let source_wallet = get_or_create_test_wallet(
         &ops,
-        &view,
         &wallet_set_id,
         &Blockchain::EthSepolia,
         "Get Transaction Test",
@@ -1122,7 +1039,7 @@ async fn test_get_transaction() {
 
     // 2. Get destination wallet
     let destination_wallet =
-        get_or_create_destination_wallet(&ops, &view, &wallet_set_id, &Blockchain::EthSepolia)
+        get_or_create_destination_wallet(&ops, &wallet_set_id, &Blockchain::EthSepolia)
             .await
             .expect("Failed to get or create destination wallet");
 
@@ -1145,8 +1062,19 @@ async fn test_get_transaction() {
     let tx_id = transfer_response.id;
     println!("✅ Created transfer transaction: {}", tx_id);
 
-    // 4. Wait a moment for the transaction to be processed
-    tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
+    // 4. Wait for the transaction to be indexed
+    await_visible(
+        &tx_id,
+        || async {
+            let params = ListTransactionsParamsBuilder::new()
+                .wallet_ids(source_wallet.id.clone())
+                .build();
+            Ok(view.list_transactions(params).await?.items)
+        },
+        std::time::Duration::from_secs(30),
+    )
+    .await
+    .expect("Transaction never became visible");
 
     // 5. Get the specific transaction
     let transaction_response = view
@@ -1162,7 +1090,8 @@ async fn test_get_transaction() {
         "Retrieved transaction ID should match"
     );
     assert_eq!(
-        transaction_response.transaction.blockchain, "ETH-SEPOLIA",
+        transaction_response.transaction.blockchain,
+        Blockchain::EthSepolia,
         "Blockchain should be ETH-SEPOLIA"
     );
 
@@ -1241,7 +1170,6 @@ async fn test_create_transfer_transaction_with_fee_level() {
 
     // Initialize CircleOps and CircleView
     let ops = CircleOps::new(None).expect("Failed to create CircleOps");
-    let view = CircleView::new().expect("Failed to create CircleView");
 
     // Get required environment variables
     let wallet_set_id = env::var("CIRCLE_WALLET_SET_ID").expect("CIRCLE_WALLET_SET_ID not set");
@@ -1249,7 +1177,6 @@ async fn test_create_transfer_transaction_with_fee_level() {
     // Get or create a test wallet (reuses existing to avoid rate limits)
     let test_wallet = get_or_create_test_wallet(
         &ops,
-        &view,
         &wallet_set_id,
         &Blockchain::EthSepolia,
         "Transfer Test",
@@ -1262,7 +1189,7 @@ async fn test_create_transfer_transaction_with_fee_level() {
 
     // Get destination wallet
     let destination_wallet =
-        get_or_create_destination_wallet(&ops, &view, &wallet_set_id, &Blockchain::EthSepolia)
+        get_or_create_destination_wallet(&ops, &wallet_set_id, &Blockchain::EthSepolia)
             .await
             .expect("Failed to get or create destination wallet");
 
@@ -1323,7 +1250,6 @@ async fn test_create_transfer_transaction_with_gas_settings() {
 
     // Initialize CircleOps and CircleView
     let ops = CircleOps::new(None).expect("Failed to create CircleOps");
-    let view = CircleView::new().expect("Failed to create CircleView");
 
     // Get required environment variables
     let wallet_set_id = env::var("CIRCLE_WALLET_SET_ID").expect("CIRCLE_WALLET_SET_ID not set");
@@ -1331,7 +1257,6 @@ async fn test_create_transfer_transaction_with_gas_settings() {
     // Get or create a test wallet (reuses existing to avoid rate limits)
     let test_wallet = get_or_create_test_wallet(
         &ops,
-        &view,
         &wallet_set_id,
         &Blockchain::EthSepolia,
         "Transfer Gas Test",
@@ -1343,7 +1268,7 @@ async fn test_create_transfer_transaction_with_gas_settings() {
 
     // Get destination wallet
     let destination_wallet =
-        get_or_create_destination_wallet(&ops, &view, &wallet_set_id, &Blockchain::EthSepolia)
+        get_or_create_destination_wallet(&ops, &wallet_set_id, &Blockchain::EthSepolia)
             .await
             .expect("Failed to get or create destination wallet");
 
@@ -1360,9 +1285,11 @@ async fn test_create_transfer_transaction_with_gas_settings() {
             .amounts(vec!["0.001".to_string()])
             .blockchain(Blockchain::EthSepolia) // Required for native token transfers
             .idempotency_key(uuid::Uuid::new_v4().to_string())
-            .gas_limit("21000".to_string())
-            .max_fee("50".to_string()) // 50 gwei max fee
-            .priority_fee("2".to_string()) // 2 gwei priority fee
+            .fee(FeeConfig::eip1559(
+                "50".to_string(), // 50 gwei max fee
+                "2".to_string(),  // 2 gwei priority fee
+                "21000".to_string(),
+            ))
             .ref_id("test-transfer-gas-settings".to_string())
             .build();
 
@@ -1388,7 +1315,6 @@ async fn test_create_token_transfer_transaction() {
 
     // Initialize CircleOps and CircleView
     let ops = CircleOps::new(None).expect("Failed to create CircleOps");
-    let view = CircleView::new().expect("Failed to create CircleView");
 
     // Get required environment variables
     let wallet_set_id = env::var("CIRCLE_WALLET_SET_ID").expect("CIRCLE_WALLET_SET_ID not set");
@@ -1396,7 +1322,6 @@ async fn test_create_token_transfer_transaction() {
     // Get or create a test wallet (reuses existing to avoid rate limits)
     let test_wallet = get_or_create_test_wallet(
         &ops,
-        &view,
         &wallet_set_id,
         &Blockchain::EthSepolia,
         "Token Transfer Test",
@@ -1409,7 +1334,7 @@ async fn test_create_token_transfer_transaction() {
 
     // Get destination wallet
     let destination_wallet =
-        get_or_create_destination_wallet(&ops, &view, &wallet_set_id, &Blockchain::EthSepolia)
+        get_or_create_destination_wallet(&ops, &wallet_set_id, &Blockchain::EthSepolia)
             .await
             .expect("Failed to get or create destination wallet");
 
@@ -1479,7 +1404,6 @@ async fn test_create_transfer_transaction_all_fee_levels() {
 
     // Initialize CircleOps and CircleView
     let ops = CircleOps::new(None).expect("Failed to create CircleOps");
-    let view = CircleView::new().expect("Failed to create CircleView");
 
     // Get required environment variables
     let wallet_set_id = env::var("CIRCLE_WALLET_SET_ID").expect("CIRCLE_WALLET_SET_ID not set");
@@ -1487,7 +1411,6 @@ async fn test_create_transfer_transaction_all_fee_levels() {
     // Get or create a test wallet (reuses existing to avoid rate limits)
     let test_wallet = get_or_create_test_wallet(
         &ops,
-        &view,
         &wallet_set_id,
         &Blockchain::EthSepolia,
         "Fee Level Test",
@@ -1499,7 +1422,7 @@ async fn test_create_transfer_transaction_all_fee_levels() {
 
     // Get destination wallet
     let destination_wallet =
-        get_or_create_destination_wallet(&ops, &view, &wallet_set_id, &Blockchain::EthSepolia)
+        get_or_create_destination_wallet(&ops, &wallet_set_id, &Blockchain::EthSepolia)
             .await
             .expect("Failed to get or create destination wallet");
 
@@ -1533,10 +1456,7 @@ async fn test_create_transfer_transaction_all_fee_levels() {
             ops.create_dev_transfer_transaction(transfer_builder).await
         })
         .await
-        .expect(&format!(
-            "Failed to create {} fee level transaction",
-            level_name
-        ));
+        .unwrap_or_else(|_| panic!("Failed to create {} fee level transaction", level_name));
 
         println!(
             "  ✅ {} fee level transaction created: {}",
@@ -1555,15 +1475,10 @@ async fn test_request_testnet_tokens() {
     let view = CircleView::new().expect("Failed to create CircleView");
     let wallet_set_id = env::var("CIRCLE_WALLET_SET_ID").expect("CIRCLE_WALLET_SET_ID not set");
 
-    let test_wallet = get_or_create_test_wallet(
-        &ops,
-        &view,
-        &wallet_set_id,
-        &Blockchain::EthSepolia,
-        "Faucet Test",
-    )
-    .await
-    .expect("Failed to get or create test wallet");
+    let test_wallet =
+        get_or_create_test_wallet(&ops, &wallet_set_id, &Blockchain::EthSepolia, "Faucet Test")
+            .await
+            .expect("Failed to get or create test wallet");
 
     println!("Testing faucet for wallet: {}", test_wallet.address);
 
@@ -1596,7 +1511,6 @@ async fn test_estimate_contract_execution_fee() {
     // Get a test wallet to use for estimation
     let test_wallet = get_or_create_test_wallet(
         &ops,
-        &view,
         &wallet_set_id,
         &Blockchain::EthSepolia,
         "Fee Estimate Test",
@@ -1641,7 +1555,6 @@ async fn test_estimate_transfer_fee() {
 
     let test_wallet = get_or_create_test_wallet(
         &ops,
-        &view,
         &wallet_set_id,
         &Blockchain::EthSepolia,
         "Fee Estimate Test",
@@ -1650,7 +1563,7 @@ async fn test_estimate_transfer_fee() {
     .expect("Failed to get or create test wallet");
 
     let destination_wallet =
-        get_or_create_destination_wallet(&ops, &view, &wallet_set_id, &Blockchain::EthSepolia)
+        get_or_create_destination_wallet(&ops, &wallet_set_id, &Blockchain::EthSepolia)
             .await
             .expect("Failed to get or create destination wallet");
 
@@ -1723,7 +1636,6 @@ async fn test_create_contract_execution_transaction() {
 
     let test_wallet = get_or_create_test_wallet(
         &ops,
-        &view,
         &wallet_set_id,
         &Blockchain::EthSepolia,
         "Contract Execution Test",
@@ -1737,16 +1649,16 @@ async fn test_create_contract_execution_transaction() {
         .expect("Failed to ensure wallet is funded");
 
     // Deploy or get a test contract
-    let contract_address = get_or_deploy_test_contract(&ops, &test_wallet, &Blockchain::EthSepolia)
-        .await
-        .expect("Failed to get or deploy test contract");
+    let contract_address =
+        get_or_deploy_test_contract(&ops, &view, &test_wallet, &Blockchain::EthSepolia)
+            .await
+            .expect("Failed to get or deploy test contract");
 
     println!("🎯 Using contract: {}", contract_address);
 
     let builder = CreateContractExecutionTransactionRequestBuilder::new(
         test_wallet.id.clone(),
         contract_address.clone(),
-        uuid::Uuid::new_v4().to_string(),
     )
     .abi_function_signature("approve(address,uint256)".to_string())
     .abi_parameters(vec![
@@ -1797,7 +1709,6 @@ async fn test_create_wallet_upgrade_transaction() {
     let builder = CreateWalletUpgradeTransactionRequestBuilder::new(
         sca_wallet.id.clone(),
         ScaCore::Circle6900SingleownerV3,
-        uuid::Uuid::new_v4().to_string(),
     )
     .fee_level(FeeLevel::Medium)
     .ref_id("test-wallet-upgrade".to_string())
@@ -1824,15 +1735,10 @@ async fn test_cancel_transaction() {
     let wallet_set_id = env::var("CIRCLE_WALLET_SET_ID").expect("CIRCLE_WALLET_SET_ID not set");
 
     // First, create a transaction that we can cancel
-    let test_wallet = get_or_create_test_wallet(
-        &ops,
-        &view,
-        &wallet_set_id,
-        &Blockchain::EthSepolia,
-        "Cancel Test",
-    )
-    .await
-    .expect("Failed to get or create test wallet");
+    let test_wallet =
+        get_or_create_test_wallet(&ops, &wallet_set_id, &Blockchain::EthSepolia, "Cancel Test")
+            .await
+            .expect("Failed to get or create test wallet");
 
     // Ensure wallet is funded
     ensure_wallet_funded(&view, &test_wallet, &Blockchain::EthSepolia)
@@ -1840,7 +1746,7 @@ async fn test_cancel_transaction() {
         .expect("Failed to ensure wallet is funded");
 
     let destination_wallet =
-        get_or_create_destination_wallet(&ops, &view, &wallet_set_id, &Blockchain::EthSepolia)
+        get_or_create_destination_wallet(&ops, &wallet_set_id, &Blockchain::EthSepolia)
             .await
             .expect("Failed to get or create destination wallet");
 
@@ -1874,11 +1780,7 @@ async fn test_cancel_transaction() {
 
     // Now try to cancel the transaction
     println!("🚫 Attempting to cancel transaction...");
-    let cancel_builder = CancelTransactionRequestBuilder::new(
-        transaction_id.clone(),
-        uuid::Uuid::new_v4().to_string(),
-    )
-    .build();
+    let cancel_builder = CancelTransactionRequestBuilder::new(transaction_id.clone()).build();
 
     match ops.cancel_dev_transaction(cancel_builder).await {
         Ok(response) => {
@@ -1904,7 +1806,6 @@ async fn test_accelerate_transaction() {
     // First, create a transaction that we can accelerate
     let test_wallet = get_or_create_test_wallet(
         &ops,
-        &view,
         &wallet_set_id,
         &Blockchain::EthSepolia,
         "Accelerate Test",
@@ -1918,7 +1819,7 @@ async fn test_accelerate_transaction() {
         .expect("Failed to ensure wallet is funded");
 
     let destination_wallet =
-        get_or_create_destination_wallet(&ops, &view, &wallet_set_id, &Blockchain::EthSepolia)
+        get_or_create_destination_wallet(&ops, &wallet_set_id, &Blockchain::EthSepolia)
             .await
             .expect("Failed to get or create destination wallet");
 
@@ -1952,11 +1853,8 @@ async fn test_accelerate_transaction() {
 
     // Now try to accelerate the transaction
     println!("⚡ Attempting to accelerate transaction...");
-    let accelerate_builder = AccelerateTransactionRequestBuilder::new(
-        transaction_id.clone(),
-        uuid::Uuid::new_v4().to_string(),
-    )
-    .build();
+    let accelerate_builder =
+        AccelerateTransactionRequestBuilder::new(transaction_id.clone()).build();
 
     match ops.accelerate_dev_transaction(accelerate_builder).await {
         Ok(response) => {